@@ -7,7 +7,7 @@ import (
 	"fmt"
 	"time"
 
-	as "github.com/aerospike/aerospike-client-go/v7"
+	as "github.com/aerospike/aerospike-client-go/v8"
 )
 
 // AerospikeConfig represents the intermediate configuration for an Aerospike
@@ -40,9 +40,11 @@ type AerospikeConfig struct {
 	TendInterval                time.Duration
 	UseServicesAlternate        bool
 	RackAware                   bool
+	RackId                      int
 	RackIds                     []int
 	IgnoreOtherSubnetAliases    bool
 	SeedOnlyCluster             bool
+	LuaPath                     string
 }
 
 // NewDefaultAerospikeConfig creates a new default AerospikeConfig instance.
@@ -75,6 +77,7 @@ func (ac *AerospikeConfig) NewClientPolicy() (*as.ClientPolicy, error) {
 	clientPolicy.TendInterval = ac.TendInterval
 	clientPolicy.UseServicesAlternate = ac.UseServicesAlternate
 	clientPolicy.RackAware = ac.RackAware
+	clientPolicy.RackId = ac.RackId
 	clientPolicy.RackIds = ac.RackIds
 	clientPolicy.IgnoreOtherSubnetAliases = ac.IgnoreOtherSubnetAliases
 	clientPolicy.SeedOnlyCluster = ac.SeedOnlyCluster
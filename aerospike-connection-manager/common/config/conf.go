@@ -198,6 +198,10 @@ func Read(configFile string) (map[string]*client.AerospikeConfig, error) {
 			f.RackAware = v.(bool)
 		}
 
+		if v, exists := valMap["rack-id"]; exists {
+			f.RackId = int(v.(int64))
+		}
+
 		if v, exists := valMap["rack-ids"]; exists {
 			v := v.([]any)
 			res := make([]int, len(v))
@@ -215,6 +219,10 @@ func Read(configFile string) (map[string]*client.AerospikeConfig, error) {
 			f.SeedOnlyCluster = v.(bool)
 		}
 
+		if v, exists := valMap["lua-path"]; exists {
+			f.LuaPath = v.(string)
+		}
+
 		res[section] = f.NewAerospikeConfig()
 	}
 
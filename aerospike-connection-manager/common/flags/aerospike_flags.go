@@ -3,7 +3,7 @@ package flags
 import (
 	"time"
 
-	as "github.com/aerospike/aerospike-client-go/v7"
+	as "github.com/aerospike/aerospike-client-go/v8"
 	"github.com/aerospike/php-client/asld/common/client"
 )
 
@@ -38,9 +38,11 @@ type AerospikeFlags struct {
 	TendInterval                time.Duration `toml:"tend-interval"`
 	UseServicesAlternate        bool          `toml:"use-services-alternate"`
 	RackAware                   bool          `toml:"rack-aware"`
+	RackId                      int           `toml:"rack-id"`
 	RackIds                     []int         `toml:"rack-ids"`
 	IgnoreOtherSubnetAliases    bool          `toml:"ignore-other-subnet-aliases"`
 	SeedOnlyCluster             bool          `toml:"seed-only-cluster"`
+	LuaPath                     string        `toml:"lua-path"`
 }
 
 func NewDefaultAerospikeFlags() *AerospikeFlags {
@@ -73,9 +75,11 @@ func (af *AerospikeFlags) NewAerospikeConfig() *client.AerospikeConfig {
 	aerospikeConf.TendInterval = af.TendInterval
 	aerospikeConf.UseServicesAlternate = af.UseServicesAlternate
 	aerospikeConf.RackAware = af.RackAware
+	aerospikeConf.RackId = af.RackId
 	aerospikeConf.RackIds = af.RackIds
 	aerospikeConf.IgnoreOtherSubnetAliases = af.IgnoreOtherSubnetAliases
 	aerospikeConf.SeedOnlyCluster = af.SeedOnlyCluster
+	aerospikeConf.LuaPath = af.LuaPath
 
 	if af.TLSEnable {
 		aerospikeConf.Cert = af.TLSCertFile
@@ -4,7 +4,7 @@ import (
 	"fmt"
 	"strings"
 
-	as "github.com/aerospike/aerospike-client-go/v7"
+	as "github.com/aerospike/aerospike-client-go/v8"
 )
 
 // AuthModeFlag defines a Cobra compatible flag for the
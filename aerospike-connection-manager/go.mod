@@ -1,17 +1,15 @@
 module github.com/aerospike/php-client/asld
 
-go 1.21.3
+go 1.23
 
 require (
-	github.com/aerospike/aerospike-client-go/v7 v7.9.0
+	github.com/aerospike/aerospike-client-go/v8 v8.0.0
 	github.com/grpc-ecosystem/go-grpc-middleware/v2 v2.0.1
 	github.com/pelletier/go-toml/v2 v2.1.1
 	google.golang.org/grpc v1.63.3
 	google.golang.org/protobuf v1.34.2
 )
 
-// replace github.com/aerospike/aerospike-client-go/v7 => /home/khosrow/virt/src/github.com/aerospike/aerospike-client-go
-
 require (
 	github.com/yuin/gopher-lua v1.1.1 // indirect
 	golang.org/x/net v0.26.0 // indirect
@@ -2,10 +2,17 @@ package main
 
 import (
 	"context"
+	"fmt"
 	"log/slog"
+	"reflect"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
 	"time"
 
-	aero "github.com/aerospike/aerospike-client-go/v7"
+	aero "github.com/aerospike/aerospike-client-go/v8"
+	"github.com/aerospike/aerospike-client-go/v8/types"
 
 	"github.com/aerospike/php-client/asld/proto"
 	pb "github.com/aerospike/php-client/asld/proto"
@@ -13,6 +20,11 @@ import (
 
 const UNREACHABLE = "UNREACHABLE"
 
+// invalidArgument is panicked with when a request can not be converted for the Go client in a
+// function which has no way to return an error. The panic recovery handler returns it as an
+// InvalidArgument status, which the client reports with the PARAMETER_ERROR result code.
+type invalidArgument string
+
 type server struct {
 	pb.UnimplementedKVSServer
 
@@ -155,6 +167,22 @@ func (s *server) Touch(ctx context.Context, in *pb.AerospikeTouchRequest) (*pb.E
 	}, nil
 }
 
+func (s *server) Operate(ctx context.Context, in *pb.AerospikeOperateRequest) (*pb.AerospikeSingleResponse, error) {
+	policy := toWritePolicy(in.Policy)
+	key := toKey(in.Key)
+	rec, err := s.client.Operate(policy, key, toOps(in.Operations)...)
+	if err != nil {
+		return &pb.AerospikeSingleResponse{
+			Error:  fromError(err),
+			Record: fromRecord(rec),
+		}, nil
+	}
+
+	return &pb.AerospikeSingleResponse{
+		Record: fromRecord(rec),
+	}, nil
+}
+
 func (s *server) BatchOperate(ctx context.Context, in *pb.AerospikeBatchOperateRequest) (*pb.AerospikeBatchOperateResponse, error) {
 	brecs := toBatchRecords(in.Records)
 	err := s.client.BatchOperate(toBatchPolicy(in.Policy), brecs)
@@ -171,7 +199,7 @@ func (s *server) BatchOperate(ctx context.Context, in *pb.AerospikeBatchOperateR
 }
 
 func (s *server) CreateIndex(ctx context.Context, in *pb.AerospikeCreateIndexRequest) (*pb.AerospikeCreateIndexResponse, error) {
-	// TODO(Khosrow): return the task
+	// the task is tracked by the caller through IndexTaskStatus
 	_, err := s.client.CreateComplexIndex(toWritePolicy(in.Policy), in.Namespace, in.SetName, in.IndexName, in.BinName, toIndexType(in.IndexType), toIndexCollectionType(in.IndexCollectionType))
 	if err != nil {
 		return &pb.AerospikeCreateIndexResponse{
@@ -427,6 +455,710 @@ func (s *server) Query(in *pb.AerospikeQueryRequest, stream pb.KVS_QueryServer)
 	return nil
 }
 
+func (s *server) Info(ctx context.Context, in *pb.AerospikeInfoRequest) (*pb.AerospikeInfoResponse, error) {
+	var node *aero.Node
+	var err aero.Error
+	if in.Node != nil {
+		node, err = s.client.Cluster().GetNodeByName(*in.Node)
+	} else {
+		node, err = s.client.Cluster().GetRandomNode()
+	}
+
+	if err != nil {
+		return &pb.AerospikeInfoResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	results, err := node.RequestInfo(toInfoPolicy(in.Policy), in.Commands...)
+	if err != nil {
+		return &pb.AerospikeInfoResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	return &pb.AerospikeInfoResponse{
+		Results: results,
+	}, nil
+}
+
+func (s *server) Nodes(ctx context.Context, in *pb.AerospikeNodesRequest) (*pb.AerospikeNodesResponse, error) {
+	nodes := s.client.GetNodes()
+	res := make([]*pb.Node, 0, len(nodes))
+	for _, node := range nodes {
+		// the node racks are only tracked by the Go client when it is rack aware,
+		// so they are read from the node directly
+		racks := map[string]int32{}
+		if node.IsActive() {
+			responseMap, err := node.RequestInfo(nil, "racks:")
+			if err != nil {
+				return &pb.AerospikeNodesResponse{
+					Error: fromError(err),
+				}, nil
+			}
+			racks = parseRacks(responseMap["racks:"], node.GetName())
+		}
+
+		res = append(res, &pb.Node{
+			Name:    node.GetName(),
+			Address: node.GetHost().String(),
+			RackId:  defaultRack(racks),
+			Racks:   racks,
+			Active:  node.IsActive(),
+		})
+	}
+
+	return &pb.AerospikeNodesResponse{
+		Nodes: res,
+	}, nil
+}
+
+// defaultRack returns the rack id of the first namespace in alphabetical order, or 0 if there are none.
+func defaultRack(racks map[string]int32) int32 {
+	namespaces := make([]string, 0, len(racks))
+	for ns := range racks {
+		namespaces = append(namespaces, ns)
+	}
+	if len(namespaces) == 0 {
+		return 0
+	}
+	sort.Strings(namespaces)
+	return racks[namespaces[0]]
+}
+
+// parseRacks parses the response of the `racks:` info command, and returns the rack id of the
+// node for each namespace. The response is in the format:
+// ns=<namespace>:rack_<id>=<node>,<node>:rack_<id>=<node>;ns=<namespace>:...
+func parseRacks(response, nodeName string) map[string]int32 {
+	res := map[string]int32{}
+	for _, ns := range strings.Split(response, ";") {
+		var namespace string
+		for _, part := range strings.Split(ns, ":") {
+			k, v, found := strings.Cut(part, "=")
+			if !found {
+				continue
+			}
+
+			if k == "ns" {
+				namespace = v
+				continue
+			}
+
+			id, found := strings.CutPrefix(k, "rack_")
+			if !found || namespace == "" {
+				continue
+			}
+
+			for _, name := range strings.Split(v, ",") {
+				if name != nodeName {
+					continue
+				}
+
+				if rackId, err := strconv.Atoi(id); err == nil {
+					res[namespace] = int32(rackId)
+				}
+			}
+		}
+	}
+	return res
+}
+
+func (s *server) Namespaces(ctx context.Context, in *pb.AerospikeNamespacesRequest) (*pb.AerospikeNamespacesResponse, error) {
+	policy := toInfoPolicy(in.Policy)
+
+	names, err := s.requestInfoAny(policy, "namespaces")
+	if err != nil {
+		return &pb.AerospikeNamespacesResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	res := []*pb.NamespaceInfo{}
+	for _, ns := range splitInfo(names, ";") {
+		responses, err := s.requestInfoAll(policy, "namespace/"+ns)
+		if err != nil {
+			return &pb.AerospikeNamespacesResponse{
+				Error: fromError(err),
+			}, nil
+		}
+
+		info := &pb.NamespaceInfo{Name: ns}
+		for _, response := range responses {
+			stats := parseInfoPairs(response, ";")
+
+			if rf := infoUint(stats, "replication-factor", "effective_replication_factor"); rf > 0 {
+				info.ReplicationFactor = int32(rf)
+			}
+			if engine := stats["storage-engine"]; engine != "" {
+				info.StorageEngine = engine
+			}
+
+			info.Objects += infoUint(stats, "objects")
+			info.MasterObjects += infoUint(stats, "master_objects")
+			info.Tombstones += infoUint(stats, "tombstones")
+			info.StopWrites = info.StopWrites || stats["stop_writes"] == "true"
+		}
+		res = append(res, info)
+	}
+
+	return &pb.AerospikeNamespacesResponse{
+		Namespaces: res,
+	}, nil
+}
+
+func (s *server) Sets(ctx context.Context, in *pb.AerospikeSetsRequest) (*pb.AerospikeSetsResponse, error) {
+	responses, err := s.requestInfoAll(toInfoPolicy(in.Policy), "sets/"+in.Namespace)
+	if err != nil {
+		return &pb.AerospikeSetsResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	sets := map[string]*pb.SetInfo{}
+	for _, response := range responses {
+		for _, set := range splitInfo(response, ";") {
+			stats := parseInfoPairs(set, ":")
+			name := stats["set"]
+			if name == "" {
+				name = stats["set_name"]
+			}
+
+			info, exists := sets[name]
+			if !exists {
+				info = &pb.SetInfo{Namespace: in.Namespace, Name: name}
+				sets[name] = info
+			}
+
+			info.Objects += infoUint(stats, "objects", "n_objects")
+			info.Tombstones += infoUint(stats, "tombstones")
+			info.MemoryDataBytes += infoUint(stats, "memory_data_bytes")
+			info.DeviceDataBytes += infoUint(stats, "device_data_bytes")
+			info.DataUsedBytes += infoUint(stats, "data_used_bytes")
+			info.DisableEviction = info.DisableEviction || stats["disable-eviction"] == "true"
+		}
+	}
+
+	names := make([]string, 0, len(sets))
+	for name := range sets {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	res := make([]*pb.SetInfo, 0, len(sets))
+	for _, name := range names {
+		res = append(res, sets[name])
+	}
+
+	return &pb.AerospikeSetsResponse{
+		Sets: res,
+	}, nil
+}
+
+func (s *server) Indexes(ctx context.Context, in *pb.AerospikeIndexesRequest) (*pb.AerospikeIndexesResponse, error) {
+	responses, err := s.requestInfoAll(toInfoPolicy(in.Policy), "sindex-list:ns="+in.Namespace)
+	if err != nil {
+		return &pb.AerospikeIndexesResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	res := []*pb.IndexInfo{}
+	indexes := map[string]*pb.IndexInfo{}
+	for _, response := range responses {
+		for _, index := range splitInfo(response, ";") {
+			stats := parseInfoPairs(index, ":")
+			name := stats["indexname"]
+
+			// index definitions are the same on all nodes, only the state is collected
+			if info, exists := indexes[name]; exists {
+				if info.State == "RW" {
+					info.State = stats["state"]
+				}
+				continue
+			}
+
+			binName := stats["bin"]
+			if binName == "" {
+				binName = stats["bins"]
+			}
+
+			setName := stats["set"]
+			if setName == "NULL" {
+				setName = ""
+			}
+
+			info := &pb.IndexInfo{
+				Namespace:      in.Namespace,
+				SetName:        setName,
+				Name:           name,
+				BinName:        binName,
+				IndexType:      parseIndexType(stats["type"]),
+				CollectionType: parseIndexCollectionType(stats["indextype"]),
+				State:          stats["state"],
+			}
+
+			if b64 := stats["context"]; b64 != "" && strings.ToLower(b64) != "null" {
+				ctxl, err := aero.Base64ToCDTContext(b64)
+				if err != nil {
+					return &pb.AerospikeIndexesResponse{
+						Error: fromError(err),
+					}, nil
+				}
+				info.Ctx = fromCDTContexts(ctxl)
+			}
+
+			indexes[name] = info
+			res = append(res, info)
+		}
+	}
+
+	return &pb.AerospikeIndexesResponse{
+		Indexes: res,
+	}, nil
+}
+
+// requestInfoAny sends the info command to a random node in the cluster and returns the response.
+func (s *server) requestInfoAny(policy *aero.InfoPolicy, command string) (string, aero.Error) {
+	node, err := s.client.Cluster().GetRandomNode()
+	if err != nil {
+		return "", err
+	}
+
+	responseMap, err := node.RequestInfo(policy, command)
+	if err != nil {
+		return "", err
+	}
+	return responseMap[command], nil
+}
+
+// requestInfoAll sends the info command to all the nodes in the cluster and returns the responses.
+func (s *server) requestInfoAll(policy *aero.InfoPolicy, command string) ([]string, aero.Error) {
+	nodes := s.client.GetNodes()
+	if len(nodes) == 0 {
+		// returns the cluster empty error
+		_, err := s.client.Cluster().GetRandomNode()
+		return nil, err
+	}
+
+	res := make([]string, 0, len(nodes))
+	for _, node := range nodes {
+		responseMap, err := node.RequestInfo(policy, command)
+		if err != nil {
+			return nil, err
+		}
+		res = append(res, responseMap[command])
+	}
+	return res, nil
+}
+
+// splitInfo splits an info response on sep, dropping the empty entries.
+func splitInfo(response, sep string) []string {
+	res := []string{}
+	for _, v := range strings.Split(strings.TrimSpace(response), sep) {
+		if v != "" {
+			res = append(res, v)
+		}
+	}
+	return res
+}
+
+// parseInfoPairs parses a `name=value` list separated by sep.
+func parseInfoPairs(response, sep string) map[string]string {
+	res := map[string]string{}
+	for _, pair := range splitInfo(response, sep) {
+		if k, v, found := strings.Cut(pair, "="); found {
+			res[k] = v
+		}
+	}
+	return res
+}
+
+// infoUint returns the value of the first of the names that exists in the stats as an uint64.
+func infoUint(stats map[string]string, names ...string) uint64 {
+	for _, name := range names {
+		if v, exists := stats[name]; exists {
+			res, _ := strconv.ParseUint(v, 10, 64)
+			return res
+		}
+	}
+	return 0
+}
+
+func parseIndexType(in string) pb.IndexType {
+	switch strings.ToLower(in) {
+	case "string":
+		return pb.IndexType_IndexTypeString
+	case "blob":
+		return pb.IndexType_IndexTypeBlob
+	case "geo2dsphere", "geojson":
+		return pb.IndexType_IndexTypeGeo2DSphere
+	}
+	return pb.IndexType_IndexTypeNumeric
+}
+
+func parseIndexCollectionType(in string) pb.IndexCollectionType {
+	switch strings.ToLower(in) {
+	case "list":
+		return pb.IndexCollectionType_IndexCollectionTypeList
+	case "mapkeys":
+		return pb.IndexCollectionType_IndexCollectionTypeMapKeys
+	case "mapvalues":
+		return pb.IndexCollectionType_IndexCollectionTypeMapValues
+	}
+	return pb.IndexCollectionType_IndexCollectionTypeDefault
+}
+
+func (s *server) IndexTaskStatus(ctx context.Context, in *pb.AerospikeIndexTaskStatusRequest) (*pb.AerospikeTaskProgressResponse, error) {
+	command := "sindex-stat:namespace=" + in.Namespace + ";indexname=" + in.IndexName
+	return s.taskProgress(toInfoPolicy(in.Policy), command, func(response string) uint32 {
+		// index not found
+		if strings.HasPrefix(strings.ToUpper(response), "FAIL:201") || strings.HasPrefix(strings.ToUpper(response), "ERROR:201") {
+			if in.Drop {
+				return 100
+			}
+			return 0
+		}
+
+		if in.Drop {
+			return 0
+		}
+
+		find := "load_pct="
+		index := strings.Index(response, find)
+		if index < 0 {
+			return 0
+		}
+
+		begin := index + len(find)
+		end := strings.Index(response[begin:], ";")
+		if end < 0 {
+			end = len(response) - begin
+		}
+
+		pct, err := strconv.Atoi(response[begin : begin+end])
+		if err != nil || pct < 0 {
+			return 0
+		}
+		return uint32(min(pct, 100))
+	})
+}
+
+func (s *server) UDFTaskStatus(ctx context.Context, in *pb.AerospikeUDFTaskStatusRequest) (*pb.AerospikeTaskProgressResponse, error) {
+	return s.taskProgress(toInfoPolicy(in.Policy), "udf-list", func(response string) uint32 {
+		if udfListContains(response, in.PackageName) != in.Drop {
+			return 100
+		}
+		return 0
+	})
+}
+
+// udfListContains returns whether the `udf-list` info response lists the package. The
+// response has an entry like `filename=x.lua,hash=...,type=LUA` for each package.
+func udfListContains(response, packageName string) bool {
+	for _, entry := range splitInfo(response, ";") {
+		if parseInfoPairs(entry, ",")["filename"] == packageName {
+			return true
+		}
+	}
+	return false
+}
+
+// taskProgress sends the info command to all the nodes in the cluster and reports the
+// progress of the task on each node, as computed by `percent` from the info response.
+func (s *server) taskProgress(policy *aero.InfoPolicy, command string, percent func(response string) uint32) (*pb.AerospikeTaskProgressResponse, error) {
+	nodes := s.client.GetNodes()
+	if len(nodes) == 0 {
+		return &pb.AerospikeTaskProgressResponse{
+			Error: &pb.Error{ResultCode: int32(types.SERVER_NOT_AVAILABLE)},
+		}, nil
+	}
+
+	status := pb.TaskStatus_COMPLETE
+	progress := make([]*pb.NodeTaskProgress, 0, len(nodes))
+	for _, node := range nodes {
+		responseMap, err := node.RequestInfo(policy, command)
+		if err != nil {
+			return &pb.AerospikeTaskProgressResponse{
+				Error: fromError(err),
+			}, nil
+		}
+
+		pct := percent(responseMap[command])
+		if pct < 100 {
+			status = pb.TaskStatus_IN_PROGRESS
+		}
+
+		progress = append(progress, &pb.NodeTaskProgress{
+			Node:    node.GetName(),
+			Percent: pct,
+		})
+	}
+
+	return &pb.AerospikeTaskProgressResponse{
+		Status:   status,
+		Progress: progress,
+	}, nil
+}
+
+func (s *server) QueryAggregate(in *pb.AerospikeQueryAggregateRequest, stream pb.KVS_QueryAggregateServer) error {
+	udf := in.Statement.GetUdfCall()
+	if udf == nil {
+		return stream.Send(&pb.AerospikeAggregateResponse{
+			Error: &pb.Error{ResultCode: int32(types.PARAMETER_ERROR)},
+		})
+	}
+
+	rs, err := s.client.QueryAggregate(toQueryPolicy(in.Policy), toStatement(in.Statement), udf.PackageName, udf.FunctionName, toValues(udf.FunctionArgs)...)
+	if err != nil {
+		// the error is reported in the stream, so that the client gets its result code
+		return stream.Send(&pb.AerospikeAggregateResponse{
+			Error: fromError(err),
+		})
+	}
+
+	for res := range rs.Results() {
+		if res.Err != nil {
+			if err := stream.Send(&pb.AerospikeAggregateResponse{Error: fromError(res.Err)}); err != nil {
+				rs.Close()
+				return err
+			}
+			continue
+		}
+
+		// the reduced values are returned in the `SUCCESS` bin of the records
+		if err := stream.Send(&pb.AerospikeAggregateResponse{Value: fromValue(res.Record.Bins["SUCCESS"])}); err != nil {
+			rs.Close()
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (s *server) QueryExecute(ctx context.Context, in *pb.AerospikeQueryExecuteRequest) (*pb.AerospikeQueryExecuteResponse, error) {
+	udf := in.Statement.GetUdfCall()
+	if udf != nil && len(in.Operations) > 0 {
+		// a background query applies either the operations or the UDF, not both
+		return &pb.AerospikeQueryExecuteResponse{
+			Error: &pb.Error{ResultCode: int32(types.PARAMETER_ERROR)},
+		}, nil
+	}
+
+	policy, writePolicy := toBackgroundExecutePolicy(in.Policy)
+	stmt := toStatement(in.Statement)
+
+	var err aero.Error
+	if udf != nil {
+		_, err = s.client.ExecuteUDF(policy, stmt, udf.PackageName, udf.FunctionName, toValues(udf.FunctionArgs)...)
+	} else {
+		_, err = s.client.QueryExecute(policy, writePolicy, stmt, toOps(in.Operations)...)
+	}
+
+	if err != nil {
+		return &pb.AerospikeQueryExecuteResponse{
+			Error: fromError(err),
+		}, nil
+	}
+
+	return &pb.AerospikeQueryExecuteResponse{
+		TaskId: stmt.TaskId,
+		Scan:   stmt.IsScan(),
+	}, nil
+}
+
+func (s *server) ExecuteTaskStatus(ctx context.Context, in *pb.AerospikeExecuteTaskStatusRequest) (*pb.AerospikeExecuteTaskStatusResponse, error) {
+	nodes := s.client.GetNodes()
+	if len(nodes) == 0 {
+		return &pb.AerospikeExecuteTaskStatusResponse{
+			Error: &pb.Error{ResultCode: int32(types.SERVER_NOT_AVAILABLE)},
+		}, nil
+	}
+
+	// the task is complete when it is done on all the nodes
+	job := backgroundJobs.poll(in.TaskId)
+	status := pb.TaskStatus_COMPLETE
+	seen := false
+	for _, node := range nodes {
+		jobStatus, found, err := backgroundJobStatus(node, in.TaskId, in.Scan)
+		if err != nil {
+			return &pb.AerospikeExecuteTaskStatusResponse{
+				Error: fromError(err),
+			}, nil
+		}
+
+		if !found {
+			// the job has not started on the node yet, or has finished and been purged from its
+			// job list. Like the Go client, assume the latter once the job has been seen or
+			// after enough polls.
+			if !job.seen && job.polls <= jobNotFoundPolls {
+				status = pb.TaskStatus_IN_PROGRESS
+			}
+			continue
+		}
+
+		seen = true
+		if !strings.HasPrefix(jobStatus, "done") {
+			status = pb.TaskStatus_IN_PROGRESS
+			break
+		}
+	}
+	backgroundJobs.update(in.TaskId, seen, status == pb.TaskStatus_COMPLETE)
+
+	return &pb.AerospikeExecuteTaskStatusResponse{
+		Status: status,
+	}, nil
+}
+
+// backgroundJobStatus returns the status of the background query or scan with the task id on
+// the node, like `done(ok)`, and whether the node knows the job.
+func backgroundJobStatus(node *aero.Node, taskId uint64, scan bool) (string, bool, aero.Error) {
+	trid := strconv.FormatUint(taskId, 10)
+
+	// servers 6.0+ run scans as queries, older servers list them separately
+	commands := []string{"query-show:trid=" + trid}
+	if scan {
+		commands = append(commands, "scan-show:trid="+trid)
+	}
+
+	for _, command := range commands {
+		responseMap, err := node.RequestInfo(nil, command)
+		if err != nil {
+			return "", false, err
+		}
+
+		// the response is a list of `name=value` pairs separated by `:`
+		_, status, found := strings.Cut(responseMap[command], "status=")
+		if !found {
+			continue
+		}
+		status, _, _ = strings.Cut(status, ":")
+		return strings.ToLower(status), true, nil
+	}
+	return "", false, nil
+}
+
+// jobNotFoundPolls is how many status polls of a background job which no node has reported yet
+// are answered as in progress.
+const jobNotFoundPolls = 20
+
+// jobIdleTimeout is how long the polls of a background job which is no longer polled are kept.
+const jobIdleTimeout = 10 * time.Minute
+
+type jobEntry struct {
+	seen     bool
+	polls    int
+	lastUsed time.Time
+}
+
+// jobRegistry keeps the status polls of the background jobs between requests, to tell a job
+// which has not started on a node yet from one which has finished and been purged.
+type jobRegistry struct {
+	mu   sync.Mutex
+	jobs map[uint64]*jobEntry
+}
+
+var backgroundJobs = &jobRegistry{jobs: map[uint64]*jobEntry{}}
+
+// poll counts a status poll of the job and returns what is known about it.
+func (r *jobRegistry) poll(taskId uint64) jobEntry {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	now := time.Now()
+	e, exists := r.jobs[taskId]
+	if !exists {
+		r.prune(now)
+
+		e = &jobEntry{}
+		r.jobs[taskId] = e
+	}
+	e.polls++
+	e.lastUsed = now
+	return *e
+}
+
+// update records whether a node reported the job, and forgets the job once it is complete.
+func (r *jobRegistry) update(taskId uint64, seen, done bool) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	if done {
+		delete(r.jobs, taskId)
+	} else if e, exists := r.jobs[taskId]; exists && seen {
+		e.seen = true
+	}
+}
+
+// prune forgets the jobs which are no longer polled.
+func (r *jobRegistry) prune(now time.Time) {
+	for id, e := range r.jobs {
+		if now.Sub(e.lastUsed) > jobIdleTimeout {
+			delete(r.jobs, id)
+		}
+	}
+}
+
+func (s *server) Commit(ctx context.Context, in *pb.AerospikeCommitRequest) (*pb.AerospikeCommitResponse, error) {
+	txn, exists := transactions.lookup(in.Txn.GetId())
+	if !exists {
+		// the transaction was never used, has already been committed or aborted, or has expired
+		return &pb.AerospikeCommitResponse{
+			Error: &pb.Error{ResultCode: int32(types.PARAMETER_ERROR)},
+		}, nil
+	}
+
+	status, err := s.client.Commit(txn)
+	transactions.removeIfDone(in.Txn.Id, txn)
+	if err != nil {
+		return &pb.AerospikeCommitResponse{
+			Error: fromError(err),
+			State: fromTxnState(txn.State()),
+		}, nil
+	}
+
+	return &pb.AerospikeCommitResponse{
+		Status: fromCommitStatus(status),
+		State:  fromTxnState(txn.State()),
+	}, nil
+}
+
+func (s *server) Abort(ctx context.Context, in *pb.AerospikeAbortRequest) (*pb.AerospikeAbortResponse, error) {
+	txn, exists := transactions.lookup(in.Txn.GetId())
+	if !exists {
+		// the transaction was never used, has already been committed or aborted, or has expired
+		return &pb.AerospikeAbortResponse{
+			Error: &pb.Error{ResultCode: int32(types.PARAMETER_ERROR)},
+		}, nil
+	}
+
+	status, err := s.client.Abort(txn)
+	transactions.removeIfDone(in.Txn.Id, txn)
+	if err != nil {
+		return &pb.AerospikeAbortResponse{
+			Error: fromError(err),
+			State: fromTxnState(txn.State()),
+		}, nil
+	}
+
+	return &pb.AerospikeAbortResponse{
+		Status: fromAbortStatus(status),
+		State:  fromTxnState(txn.State()),
+	}, nil
+}
+
+func (s *server) ExpressionToBase64(ctx context.Context, in *pb.AerospikeExpressionToBase64Request) (*pb.AerospikeExpressionToBase64Response, error) {
+	b64, err := toExpression(in.Expression).Base64()
+	if err != nil {
+		return &pb.AerospikeExpressionToBase64Response{
+			Error: fromError(err),
+		}, nil
+	}
+
+	return &pb.AerospikeExpressionToBase64Response{
+		Base64: b64,
+	}, nil
+}
+
 func toStatement(in *pb.Statement) *aero.Statement {
 	if in != nil {
 		var idxName string
@@ -457,6 +1189,14 @@ func toCDTContexts(in []*pb.CDTContext) []*aero.CDTContext {
 	return res
 }
 
+func fromCDTContexts(in []*aero.CDTContext) []*pb.CDTContext {
+	res := make([]*pb.CDTContext, len(in))
+	for i := range in {
+		res[i] = &pb.CDTContext{Id: int32(in[i].Id), Value: fromValue(in[i].Value)}
+	}
+	return res
+}
+
 func toCDTContext(in *pb.CDTContext) *aero.CDTContext {
 	if in != nil {
 		return &aero.CDTContext{Id: int(in.Id), Value: toValue(in.Value)}
@@ -685,6 +1425,147 @@ func toIndexCollectionType(in pb.IndexCollectionType) aero.IndexCollectionType {
 	panic(UNREACHABLE)
 }
 
+// toReplicaPolicy maps the protobuf enum, whose ordinals differ from the Go client's, to the Go client value.
+func toReplicaPolicy(in pb.ReplicaPolicy) aero.ReplicaPolicy {
+	switch in {
+	case pb.ReplicaPolicy_SEQUENCE:
+		return aero.SEQUENCE
+	case pb.ReplicaPolicy_MASTER:
+		return aero.MASTER
+	case pb.ReplicaPolicy_MASTER_PROLES:
+		return aero.MASTER_PROLES
+	case pb.ReplicaPolicy_PREFER_RACK:
+		return aero.PREFER_RACK
+	case pb.ReplicaPolicy_RANDOM:
+		return aero.RANDOM
+	}
+
+	panic(UNREACHABLE)
+}
+
+// txnIdleTimeout is how long a transaction which is neither used, committed nor aborted is
+// kept by the connection manager.
+const txnIdleTimeout = 10 * time.Minute
+
+type txnEntry struct {
+	txn      *aero.Txn
+	lastUsed time.Time
+}
+
+// txnRegistry keeps the multi-record transactions between requests. The PHP client only knows
+// the id of a transaction, so the client side state of the transaction lives here.
+type txnRegistry struct {
+	mu   sync.Mutex
+	txns map[int64]*txnEntry
+}
+
+var transactions = &txnRegistry{txns: map[int64]*txnEntry{}}
+
+// get returns the transaction with the id, creating it the first time the id is seen.
+func (r *txnRegistry) get(in *pb.Txn) *aero.Txn {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	now := time.Now()
+	e, exists := r.txns[in.Id]
+	if !exists {
+		r.prune(now)
+
+		txn := aero.NewTxn()
+		if in.Timeout > 0 {
+			txn.SetTimeout(int(in.Timeout))
+		}
+		e = &txnEntry{txn: txn}
+		r.txns[in.Id] = e
+	}
+	e.lastUsed = now
+	return e.txn
+}
+
+// lookup returns the transaction with the id, if it is known.
+func (r *txnRegistry) lookup(id int64) (*aero.Txn, bool) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	e, exists := r.txns[id]
+	if !exists {
+		return nil, false
+	}
+	e.lastUsed = time.Now()
+	return e.txn, true
+}
+
+// removeIfDone forgets the transaction once it has been committed or aborted.
+func (r *txnRegistry) removeIfDone(id int64, txn *aero.Txn) {
+	switch txn.State() {
+	case aero.TxnStateCommitted, aero.TxnStateAborted:
+		r.mu.Lock()
+		delete(r.txns, id)
+		r.mu.Unlock()
+	}
+}
+
+// prune forgets abandoned transactions. The server aborts them on its own after their timeout.
+func (r *txnRegistry) prune(now time.Time) {
+	for id, e := range r.txns {
+		if now.Sub(e.lastUsed) > txnIdleTimeout {
+			delete(r.txns, id)
+		}
+	}
+}
+
+func toTxn(in *pb.Txn) *aero.Txn {
+	if in != nil {
+		return transactions.get(in)
+	}
+	return nil
+}
+
+func fromTxnState(in aero.TxnState) pb.TxnState {
+	switch in {
+	case aero.TxnStateOpen:
+		return pb.TxnState_TxnStateOpen
+	case aero.TxnStateVerified:
+		return pb.TxnState_TxnStateVerified
+	case aero.TxnStateCommitted:
+		return pb.TxnState_TxnStateCommitted
+	case aero.TxnStateAborted:
+		return pb.TxnState_TxnStateAborted
+	}
+
+	panic(UNREACHABLE)
+}
+
+func fromCommitStatus(in aero.CommitStatus) pb.CommitStatus {
+	switch in {
+	case aero.CommitStatusOK:
+		return pb.CommitStatus_CommitStatusOk
+	case aero.CommitStatusAlreadyCommitted:
+		return pb.CommitStatus_CommitStatusAlreadyCommitted
+	case aero.CommitStatusRollForwardAbandoned:
+		return pb.CommitStatus_CommitStatusRollForwardAbandoned
+	case aero.CommitStatusCloseAbandoned:
+		return pb.CommitStatus_CommitStatusCloseAbandoned
+	}
+
+	panic(UNREACHABLE)
+}
+
+func fromAbortStatus(in aero.AbortStatus) pb.AbortStatus {
+	switch in {
+	case aero.AbortStatusOK:
+		return pb.AbortStatus_AbortStatusOk
+	case aero.AbortStatusAlreadyAborted:
+		return pb.AbortStatus_AbortStatusAlreadyAborted
+	case aero.AbortStatusRollBackAbandoned:
+		return pb.AbortStatus_AbortStatusRollBackAbandoned
+	case aero.AbortStatusCloseAbandoned:
+		return pb.AbortStatus_AbortStatusCloseAbandoned
+	}
+
+	panic(UNREACHABLE)
+}
+
 func toReadPolicy(in *pb.ReadPolicy) *aero.BasePolicy {
 	if in != nil {
 		return &aero.BasePolicy{
@@ -699,8 +1580,9 @@ func toReadPolicy(in *pb.ReadPolicy) *aero.BasePolicy {
 			ExitFastOnExhaustedConnectionPool: in.ExitFastOnExhaustedConnectionPool,
 			SendKey:                           in.SendKey,
 			UseCompression:                    in.UseCompression,
-			ReplicaPolicy:                     aero.ReplicaPolicy(in.ReplicaPolicy),
+			ReplicaPolicy:                     toReplicaPolicy(in.ReplicaPolicy),
 			ReadTouchTTLPercent:               in.ReadTouchTTLPercent,
+			Txn:                               toTxn(in.Txn),
 		}
 	}
 	return nil
@@ -756,6 +1638,50 @@ func toQueryPolicy(in *pb.QueryPolicy) *aero.QueryPolicy {
 	return nil
 }
 
+func toBackgroundExecutePolicy(in *pb.BackgroundExecutePolicy) (*aero.QueryPolicy, *aero.WritePolicy) {
+	policy := aero.NewQueryPolicy()
+	writePolicy := aero.NewWritePolicy(0, 0)
+	if in == nil {
+		return policy, writePolicy
+	}
+
+	writePolicy.ReplicaPolicy = toReplicaPolicy(in.Replica)
+	writePolicy.ReadModeAP = aero.ReadModeAP(in.ReadModeAP)
+	writePolicy.ReadModeSC = aero.ReadModeSC(in.ReadModeSC)
+	writePolicy.UseCompression = in.Compress
+	writePolicy.FilterExpression = toExpression(in.FilterExpression)
+	if in.TotalTimeout != nil {
+		writePolicy.TotalTimeout = time.Duration(*in.TotalTimeout) * time.Millisecond
+	}
+	if in.SendKey != nil {
+		writePolicy.SendKey = *in.SendKey
+	}
+	if in.RecordExistsAction != nil {
+		writePolicy.RecordExistsAction = aero.RecordExistsAction(*in.RecordExistsAction)
+	}
+	if in.GenerationPolicy != nil {
+		writePolicy.GenerationPolicy = aero.GenerationPolicy(*in.GenerationPolicy)
+	}
+	if in.CommitLevel != nil {
+		writePolicy.CommitLevel = aero.CommitLevel(*in.CommitLevel)
+	}
+	if in.Generation != nil {
+		writePolicy.Generation = *in.Generation
+	}
+	if in.Expiration != nil {
+		writePolicy.Expiration = *in.Expiration
+	}
+	if in.RespondAllOps != nil {
+		writePolicy.RespondPerEachOp = *in.RespondAllOps
+	}
+	if in.DurableDelete != nil {
+		writePolicy.DurableDelete = *in.DurableDelete
+	}
+
+	policy.BasePolicy = writePolicy.BasePolicy
+	return policy, writePolicy
+}
+
 func toAdminPolicy(in *pb.AdminPolicy) *aero.AdminPolicy {
 	if in != nil {
 		return &aero.AdminPolicy{Timeout: time.Duration(in.Timeout * uint32(time.Millisecond))}
@@ -971,7 +1897,7 @@ func toCdtMapOp(in *pb.CdtMapOperation) *aero.Operation {
 			return aero.MapSetPolicyOp(toCdtMapPolicy(in.Policy), in.BinName, toCDTContexts(in.Ctx)...)
 		case pb.CdtMapCommandOp_CdtMapCommandOpPutItems:
 			// implement ordered maps
-			m := map[interface{}]interface{}(toValue(in.Args[0]).(aero.MapValue))
+			m := toGoMap(in.Args[0])
 			return aero.MapPutItemsOp(toCdtMapPolicy(in.Policy), in.BinName, m, toCDTContexts(in.Ctx)...)
 		case pb.CdtMapCommandOp_CdtMapCommandOpIncrement:
 			key := toValue(in.Args[0])
@@ -1450,7 +2376,22 @@ func toOp(in *pb.Operation) *aero.Operation {
 		return toCdtHLLOp(in.Hll)
 	case *pb.Operation_Bitwise:
 		return toCdtBitwiseOp(in.Bitwise)
+	case *pb.Operation_Exp:
+		return toExpOp(in.Exp)
+	}
+	panic(UNREACHABLE)
+}
+
+func toExpOp(in *pb.ExpOperation) *aero.Operation {
+	if in != nil {
+		switch in.OpType {
+		case pb.ExpOperationType_ExpOperationTypeRead:
+			return aero.ExpReadOp(in.Name, toExpression(in.Exp), aero.ExpReadFlags(in.Flags))
+		case pb.ExpOperationType_ExpOperationTypeWrite:
+			return aero.ExpWriteOp(in.Name, toExpression(in.Exp), aero.ExpWriteFlags(in.Flags))
+		}
 	}
+
 	panic(UNREACHABLE)
 }
 
@@ -1553,11 +2494,19 @@ func toValue(in *pb.Value) aero.Value {
 
 	if m, ok := in.V.(*pb.Value_M); ok {
 		mp := m.M.GetM()
-		m := make(map[interface{}]interface{}, len(mp))
+
+		// maps are packed directly from the entries, to keep the order of the PHP array.
+		// Go maps would lose it, and can not have list, blob or map keys either.
+		if isPackable(in) {
+			return aero.NewMapperValue(mapEntries(mp))
+		}
 		for i := range mp {
-			m[toValue(mp[i].K)] = toValue(mp[i].V)
+			if !isComparableKey(mp[i].K) {
+				return aero.NewMapperValue(mapEntries(mp))
+			}
 		}
-		return aero.MapValue(m)
+
+		return aero.MapValue(toGoMap(in))
 	}
 
 	if l, ok := in.V.(*pb.Value_L); ok {
@@ -1572,6 +2521,114 @@ func toValue(in *pb.Value) aero.Value {
 	panic(UNREACHABLE)
 }
 
+// toGoMap converts the map to a Go map, for the Go client functions which need one.
+// The order of the entries is lost.
+func toGoMap(in *pb.Value) map[interface{}]interface{} {
+	mp := in.GetM().GetM()
+	m := make(map[interface{}]interface{}, len(mp))
+	for i := range mp {
+		m[toValue(mp[i].K)] = toValue(mp[i].V)
+	}
+	return m
+}
+
+// isPackable reports whether packValue supports the value and all the values nested in it.
+func isPackable(in *pb.Value) bool {
+	switch v := in.GetV().(type) {
+	case nil, *pb.Value_Nil, *pb.Value_I, *pb.Value_F, *pb.Value_S, *pb.Value_B, *pb.Value_Blob:
+		return true
+	case *pb.Value_L:
+		for _, e := range v.L.L {
+			if !isPackable(e) {
+				return false
+			}
+		}
+		return true
+	case *pb.Value_M:
+		for _, e := range v.M.M {
+			if !isPackable(e.K) || !isPackable(e.V) {
+				return false
+			}
+		}
+		return true
+	}
+	return false
+}
+
+func isComparableKey(in *pb.Value) bool {
+	switch in.GetV().(type) {
+	case *pb.Value_L, *pb.Value_M, *pb.Value_Json, *pb.Value_Blob, *pb.Value_Hll:
+		return false
+	}
+	return true
+}
+
+// mapEntries packs the map entries in order, without converting them to a Go map.
+type mapEntries []*pb.MapEntry
+
+func (me mapEntries) Len() int {
+	return len(me)
+}
+
+func (me mapEntries) PackMap(buf aero.BufferEx) (int, error) {
+	size := 0
+	for _, e := range me {
+		n, err := packValue(buf, e.K)
+		size += n
+		if err != nil {
+			return size, err
+		}
+
+		n, err = packValue(buf, e.V)
+		size += n
+		if err != nil {
+			return size, err
+		}
+	}
+	return size, nil
+}
+
+// listEntries packs the list values in order.
+type listEntries []*pb.Value
+
+func (le listEntries) Len() int {
+	return len(le)
+}
+
+func (le listEntries) PackList(buf aero.BufferEx) (int, error) {
+	size := 0
+	for _, v := range le {
+		n, err := packValue(buf, v)
+		size += n
+		if err != nil {
+			return size, err
+		}
+	}
+	return size, nil
+}
+
+func packValue(buf aero.BufferEx, in *pb.Value) (int, error) {
+	switch v := in.GetV().(type) {
+	case nil, *pb.Value_Nil:
+		return aero.PackNil(buf)
+	case *pb.Value_I:
+		return aero.PackInt64(buf, v.I)
+	case *pb.Value_F:
+		return aero.PackFloat64(buf, v.F)
+	case *pb.Value_S:
+		return aero.PackString(buf, v.S)
+	case *pb.Value_B:
+		return aero.PackBool(buf, v.B)
+	case *pb.Value_Blob:
+		return aero.PackBytes(buf, v.Blob)
+	case *pb.Value_L:
+		return aero.PackList(buf, listEntries(v.L.L))
+	case *pb.Value_M:
+		return aero.PackMap(buf, mapEntries(v.M.M))
+	}
+	return 0, fmt.Errorf("value of type %T is not supported in maps with list, blob or map keys", in.GetV())
+}
+
 func toListValue(in *pb.Value) []aero.Value {
 	lst := in.GetL().L
 	if len(lst) > 0 {
@@ -1680,6 +2737,8 @@ func fromValue(in any) *pb.Value {
 	case int:
 		i64 := int64(v)
 		return &pb.Value{V: &pb.Value_I{I: i64}}
+	case int64:
+		return &pb.Value{V: &pb.Value_I{I: v}}
 	case float64:
 		return &pb.Value{V: &pb.Value_F{F: v}}
 	case string:
@@ -1695,13 +2754,13 @@ func fromValue(in any) *pb.Value {
 		}
 		return &pb.Value{V: &pb.Value_L{L: &pb.List{L: l}}}
 	case map[any]any:
-		m := make([]*pb.MapEntry, len(v))
+		m := make([]*pb.MapEntry, 0, len(v))
 		for k, v := range v {
 			m = append(m, &proto.MapEntry{K: fromValue(k), V: fromValue(v)})
 		}
 		return &pb.Value{V: &pb.Value_M{M: &pb.Map{M: m}}}
 	case []aero.MapPair:
-		m := make([]*pb.MapEntry, len(v))
+		m := make([]*pb.MapEntry, 0, len(v))
 		for _, mp := range v {
 			m = append(m, &proto.MapEntry{K: fromValue(mp.Key), V: fromValue(mp.Value)})
 		}
@@ -1721,7 +2780,7 @@ func fromValue(in any) *pb.Value {
 	case aero.BytesValue:
 		return &pb.Value{V: &pb.Value_Blob{Blob: []byte(v)}}
 	case aero.JsonValue:
-		m := make([]*pb.JsonEntry, len(v))
+		m := make([]*pb.JsonEntry, 0, len(v))
 		for k, v := range v {
 			m = append(m, &proto.JsonEntry{K: k, V: fromValue(v)})
 		}
@@ -1742,6 +2801,13 @@ func fromValue(in any) *pb.Value {
 		return &pb.Value{V: &pb.Value_L{L: &pb.List{L: l}}}
 	}
 
+	// the Go client returns blob map keys as byte arrays, since slices can not be map keys
+	if rv := reflect.ValueOf(in); rv.Kind() == reflect.Array && rv.Type().Elem().Kind() == reflect.Uint8 {
+		b := make([]byte, rv.Len())
+		reflect.Copy(reflect.ValueOf(b), rv)
+		return &pb.Value{V: &pb.Value_Blob{Blob: b}}
+	}
+
 	panic(UNREACHABLE)
 }
 
@@ -1757,6 +2823,14 @@ func toExpression(in *pb.Expression) *aero.Expression {
 		return nil
 	}
 
+	if in.Base64 != nil {
+		exp, err := aero.ExpFromBase64(*in.Base64)
+		if err != nil {
+			panic(invalidArgument("invalid base64 expression: " + err.Error()))
+		}
+		return exp
+	}
+
 	if in.Cmd == nil {
 		val := toValue(in.Val)
 		switch v := val.(type) {
@@ -1776,8 +2850,8 @@ func toExpression(in *pb.Expression) *aero.Expression {
 			return aero.ExpBlobVal([]byte(v))
 		case aero.ListValue:
 			return aero.ExpListVal(toListValue(in.Val)...)
-		case aero.MapValue:
-			return aero.ExpMapVal(toValue(in.Val).(aero.MapValue))
+		case aero.MapValue, *aero.MapperValue:
+			return aero.ExpMapVal(aero.MapValue(toGoMap(in.Val)))
 			// case .aero.ExpJsonValue:
 			// 	return aero.ExpJsonVal(toValue(in.Val.Json))
 		case aero.GeoJSONValue:
@@ -1907,8 +2981,8 @@ func toExpression(in *pb.Expression) *aero.Expression {
 			return aero.ExpFloatBin(in.Val.V.(*pb.Value_S).S)
 		case pb.ExpType_ExpTypeGeo:
 			return aero.ExpGeoBin(in.Val.V.(*pb.Value_S).S)
-			// case pb.ExpType_ExpTypeHll:
-			// 	return aero.ExpHllBin(toValue(in.Val).String())
+		case pb.ExpType_ExpTypeHll:
+			return aero.ExpHLLBin(in.Val.V.(*pb.Value_S).S)
 		}
 		panic(UNREACHABLE)
 	case pb.ExpOp_ExpOpBinType:
@@ -1921,6 +2995,172 @@ func toExpression(in *pb.Expression) *aero.Expression {
 		return aero.ExpLet(toExpressions(in.Exps)...)
 	case pb.ExpOp_ExpOpQuoted:
 		return aero.ExpListVal(toValue(in.Val))
+	case pb.ExpOp_ExpOpCall:
+		switch *in.Module {
+		case pb.ExpType_ExpTypeList:
+			return toListExp(in)
+		case pb.ExpType_ExpTypeMap:
+			return toMapExp(in)
+		case pb.ExpType_ExpTypeHll:
+			return toHLLExp(in)
+		case pb.ExpType_ExpTypeBlob:
+			return toBitExp(in)
+		}
+		panic(UNREACHABLE)
+	}
+	panic(UNREACHABLE)
+}
+
+// toHLLExp compiles an HLL module call. The HLL command is in Val and the arguments in Exps.
+func toHLLExp(in *pb.Expression) *aero.Expression {
+	bin := toExpression(in.Bin)
+	args := toExpressions(in.Exps)
+	switch pb.CdtHLLCommandOp(in.Val.GetI()) {
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpGetCount:
+		return aero.ExpHLLGetCount(bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpGetUnion:
+		return aero.ExpHLLGetUnion(args[0], bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpGetUnionCount:
+		return aero.ExpHLLGetUnionCount(args[0], bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpGetIntersectCount:
+		return aero.ExpHLLGetIntersectCount(args[0], bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpGetSimilarity:
+		return aero.ExpHLLGetSimilarity(args[0], bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpDescribe:
+		return aero.ExpHLLDescribe(bin)
+	case pb.CdtHLLCommandOp_CdtHLLCommandOpMayContain:
+		return aero.ExpHLLMayContain(args[0], bin)
+	}
+	panic(UNREACHABLE)
+}
+
+// toBitExp compiles a bit module call on a blob bin. The bit command is in Val, the arguments
+// in Exps and the signed flag of GetInt in Flags.
+func toBitExp(in *pb.Expression) *aero.Expression {
+	bin := toExpression(in.Bin)
+	args := toExpressions(in.Exps)
+	switch pb.CdtBitwiseCommandOp(in.Val.GetI()) {
+	case pb.CdtBitwiseCommandOp_CdtBitwiseCommandOpGet:
+		return aero.ExpBitGet(args[0], args[1], bin)
+	case pb.CdtBitwiseCommandOp_CdtBitwiseCommandOpCount:
+		return aero.ExpBitCount(args[0], args[1], bin)
+	case pb.CdtBitwiseCommandOp_CdtBitwiseCommandOpLScan:
+		return aero.ExpBitLScan(args[0], args[1], args[2], bin)
+	case pb.CdtBitwiseCommandOp_CdtBitwiseCommandOpRScan:
+		return aero.ExpBitRScan(args[0], args[1], args[2], bin)
+	case pb.CdtBitwiseCommandOp_CdtBitwiseCommandOpGetInt:
+		signed := in.Flags != nil && *in.Flags != 0
+		return aero.ExpBitGetInt(args[0], args[1], signed, bin)
+	}
+	panic(UNREACHABLE)
+}
+
+// rangeEnd returns the end of a range argument list, or nil for a range without an upper bound.
+func rangeEnd(args []*aero.Expression) *aero.Expression {
+	if len(args) > 1 {
+		return args[1]
+	}
+	return nil
+}
+
+// toMapExp compiles a map module call. The map command is in Val, the return type in Flags
+// and the arguments in Exps, in the order of the Go client's ExpMap* functions.
+func toMapExp(in *pb.Expression) *aero.Expression {
+	bin := toExpression(in.Bin)
+	ctx := toCDTContexts(in.Ctx)
+
+	var returnType aero.MapReturnTypes
+	if in.Flags != nil {
+		returnType = aero.MapReturnTypes(*in.Flags)
+	}
+
+	var valueType aero.ExpType
+	if in.ValueType != nil {
+		valueType = aero.ExpType(*in.ValueType)
+	}
+
+	args := toExpressions(in.Exps)
+	switch pb.CdtMapCommandOp(in.Val.GetI()) {
+	case pb.CdtMapCommandOp_CdtMapCommandOpSize:
+		return aero.ExpMapSize(bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByKey:
+		return aero.ExpMapGetByKey(returnType, valueType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByKeyRange:
+		return aero.ExpMapGetByKeyRange(returnType, args[0], rangeEnd(args), bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByKeyList:
+		return aero.ExpMapGetByKeyList(returnType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByKeyRelativeIndexRange:
+		return aero.ExpMapGetByKeyRelativeIndexRange(returnType, args[0], args[1], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByKeyRelativeIndexRangeCount:
+		return aero.ExpMapGetByKeyRelativeIndexRangeCount(returnType, args[0], args[1], args[2], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByValue:
+		return aero.ExpMapGetByValue(returnType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByValueRange:
+		return aero.ExpMapGetByValueRange(returnType, args[0], rangeEnd(args), bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByValueList:
+		return aero.ExpMapGetByValueList(returnType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByValueRelativeRankRange:
+		return aero.ExpMapGetByValueRelativeRankRange(returnType, args[0], args[1], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByValueRelativeRankRangeCount:
+		return aero.ExpMapGetByValueRelativeRankRangeCount(returnType, args[0], args[1], args[2], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByIndex:
+		return aero.ExpMapGetByIndex(returnType, valueType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByIndexRange:
+		return aero.ExpMapGetByIndexRange(returnType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByIndexRangeCount:
+		return aero.ExpMapGetByIndexRangeCount(returnType, args[0], args[1], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByRank:
+		return aero.ExpMapGetByRank(returnType, valueType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByRankRange:
+		return aero.ExpMapGetByRankRange(returnType, args[0], bin, ctx...)
+	case pb.CdtMapCommandOp_CdtMapCommandOpGetByRankRangeCount:
+		return aero.ExpMapGetByRankRangeCount(returnType, args[0], args[1], bin, ctx...)
+	}
+	panic(UNREACHABLE)
+}
+
+// toListExp compiles a list module call. The list command is in Val, the return type in Flags
+// and the arguments in Exps, in the order of the Go client's ExpList* functions.
+func toListExp(in *pb.Expression) *aero.Expression {
+	bin := toExpression(in.Bin)
+	ctx := toCDTContexts(in.Ctx)
+
+	var returnType aero.ListReturnType
+	if in.Flags != nil {
+		returnType = aero.ListReturnType(*in.Flags)
+	}
+
+	var valueType aero.ExpType
+	if in.ValueType != nil {
+		valueType = aero.ExpType(*in.ValueType)
+	}
+
+	args := toExpressions(in.Exps)
+	switch pb.CdtListCommandOp(in.Val.GetI()) {
+	case pb.CdtListCommandOp_CdtListCommandOpSize:
+		return aero.ExpListSize(bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByValue:
+		return aero.ExpListGetByValue(returnType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByValueRange:
+		return aero.ExpListGetByValueRange(returnType, args[0], rangeEnd(args), bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByValueList:
+		return aero.ExpListGetByValueList(returnType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByValueRelativeRankRange:
+		return aero.ExpListGetByValueRelativeRankRange(returnType, args[0], args[1], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByValueRelativeRankRangeCount:
+		return aero.ExpListGetByValueRelativeRankRangeCount(returnType, args[0], args[1], args[2], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByIndex:
+		return aero.ExpListGetByIndex(returnType, valueType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByIndexRange:
+		return aero.ExpListGetByIndexRange(returnType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByIndexRangeCount:
+		return aero.ExpListGetByIndexRangeCount(returnType, args[0], args[1], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByRank:
+		return aero.ExpListGetByRank(returnType, valueType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByRankRange:
+		return aero.ExpListGetByRankRange(returnType, args[0], bin, ctx...)
+	case pb.CdtListCommandOp_CdtListCommandOpGetByRankRangeCount:
+		return aero.ExpListGetByRankRangeCount(returnType, args[0], args[1], bin, ctx...)
 	}
 	panic(UNREACHABLE)
 }
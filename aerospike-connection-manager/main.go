@@ -19,7 +19,7 @@ import (
 	"google.golang.org/grpc/reflection"
 	"google.golang.org/grpc/status"
 
-	aero "github.com/aerospike/aerospike-client-go/v7"
+	aero "github.com/aerospike/aerospike-client-go/v8"
 
 	"github.com/aerospike/php-client/asld/common/client"
 	"github.com/aerospike/php-client/asld/common/config"
@@ -96,6 +96,12 @@ func launchServer(name string, ac *client.AerospikeConfig) {
 		cp.ConnectionQueueSize = 32
 	}
 
+	// The lua path is global to the Go client; it is used to run the reduce phase of
+	// aggregation queries locally.
+	if ac.LuaPath != "" {
+		aero.SetLuaPath(ac.LuaPath)
+	}
+
 	seeds := ac.NewHosts()
 
 	client, err := aero.NewClientWithPolicyAndHost(cp, seeds...)
@@ -119,6 +125,10 @@ func launchServer(name string, ac *client.AerospikeConfig) {
 	// }
 
 	grpcPanicRecoveryHandler := func(p any) (err error) {
+		if msg, ok := p.(invalidArgument); ok {
+			return status.Error(codes.InvalidArgument, string(msg))
+		}
+
 		log.Println("recovered from panic", "panic", p, "stack", string(debug.Stack()))
 		return status.Errorf(codes.Internal, "%s", p)
 	}
@@ -924,6 +924,225 @@ func (MapOrderType) EnumDescriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{12}
 }
 
+// TxnState is the state of a multi-record transaction.
+type TxnState int32
+
+const (
+	TxnState_TxnStateOpen      TxnState = 0
+	TxnState_TxnStateVerified  TxnState = 1
+	TxnState_TxnStateCommitted TxnState = 2
+	TxnState_TxnStateAborted   TxnState = 3
+)
+
+// Enum value maps for TxnState.
+var (
+	TxnState_name = map[int32]string{
+		0: "TxnStateOpen",
+		1: "TxnStateVerified",
+		2: "TxnStateCommitted",
+		3: "TxnStateAborted",
+	}
+	TxnState_value = map[string]int32{
+		"TxnStateOpen":      0,
+		"TxnStateVerified":  1,
+		"TxnStateCommitted": 2,
+		"TxnStateAborted":   3,
+	}
+)
+
+func (x TxnState) Enum() *TxnState {
+	p := new(TxnState)
+	*p = x
+	return p
+}
+
+func (x TxnState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (TxnState) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[13].Descriptor()
+}
+
+func (TxnState) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[13]
+}
+
+func (x TxnState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use TxnState.Descriptor instead.
+func (TxnState) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{13}
+}
+
+// CommitStatus is the result of a successful commit of a multi-record transaction.
+type CommitStatus int32
+
+const (
+	// Commit succeeded.
+	CommitStatus_CommitStatusOk CommitStatus = 0
+	// Transaction has already been committed.
+	CommitStatus_CommitStatusAlreadyCommitted CommitStatus = 1
+	// Transaction verify succeeded, but commit failed on the client side.
+	// The server will eventually roll the transaction forward.
+	CommitStatus_CommitStatusRollForwardAbandoned CommitStatus = 2
+	// Transaction has been rolled forward, but the client failed to close the transaction.
+	// The server will eventually close it.
+	CommitStatus_CommitStatusCloseAbandoned CommitStatus = 3
+)
+
+// Enum value maps for CommitStatus.
+var (
+	CommitStatus_name = map[int32]string{
+		0: "CommitStatusOk",
+		1: "CommitStatusAlreadyCommitted",
+		2: "CommitStatusRollForwardAbandoned",
+		3: "CommitStatusCloseAbandoned",
+	}
+	CommitStatus_value = map[string]int32{
+		"CommitStatusOk":                   0,
+		"CommitStatusAlreadyCommitted":     1,
+		"CommitStatusRollForwardAbandoned": 2,
+		"CommitStatusCloseAbandoned":       3,
+	}
+)
+
+func (x CommitStatus) Enum() *CommitStatus {
+	p := new(CommitStatus)
+	*p = x
+	return p
+}
+
+func (x CommitStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (CommitStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[14].Descriptor()
+}
+
+func (CommitStatus) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[14]
+}
+
+func (x CommitStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use CommitStatus.Descriptor instead.
+func (CommitStatus) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{14}
+}
+
+// AbortStatus is the result of a successful abort of a multi-record transaction.
+type AbortStatus int32
+
+const (
+	// Abort succeeded.
+	AbortStatus_AbortStatusOk AbortStatus = 0
+	// Transaction has already been aborted.
+	AbortStatus_AbortStatusAlreadyAborted AbortStatus = 1
+	// Client roll back abandoned. The server will eventually abort the transaction.
+	AbortStatus_AbortStatusRollBackAbandoned AbortStatus = 2
+	// Transaction has been rolled back, but the client failed to close the transaction.
+	// The server will eventually close it.
+	AbortStatus_AbortStatusCloseAbandoned AbortStatus = 3
+)
+
+// Enum value maps for AbortStatus.
+var (
+	AbortStatus_name = map[int32]string{
+		0: "AbortStatusOk",
+		1: "AbortStatusAlreadyAborted",
+		2: "AbortStatusRollBackAbandoned",
+		3: "AbortStatusCloseAbandoned",
+	}
+	AbortStatus_value = map[string]int32{
+		"AbortStatusOk":                0,
+		"AbortStatusAlreadyAborted":    1,
+		"AbortStatusRollBackAbandoned": 2,
+		"AbortStatusCloseAbandoned":    3,
+	}
+)
+
+func (x AbortStatus) Enum() *AbortStatus {
+	p := new(AbortStatus)
+	*p = x
+	return p
+}
+
+func (x AbortStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (AbortStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[15].Descriptor()
+}
+
+func (AbortStatus) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[15]
+}
+
+func (x AbortStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use AbortStatus.Descriptor instead.
+func (AbortStatus) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{15}
+}
+
+// TaskStatus is the status of an asynchronous task on the server.
+type TaskStatus int32
+
+const (
+	// The task is still running on at least one node.
+	TaskStatus_IN_PROGRESS TaskStatus = 0
+	// The task has finished on all nodes.
+	TaskStatus_COMPLETE TaskStatus = 1
+)
+
+// Enum value maps for TaskStatus.
+var (
+	TaskStatus_name = map[int32]string{
+		0: "IN_PROGRESS",
+		1: "COMPLETE",
+	}
+	TaskStatus_value = map[string]int32{
+		"IN_PROGRESS": 0,
+		"COMPLETE":    1,
+	}
+)
+
+func (x TaskStatus) Enum() *TaskStatus {
+	p := new(TaskStatus)
+	*p = x
+	return p
+}
+
+func (x TaskStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (TaskStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[16].Descriptor()
+}
+
+func (TaskStatus) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[16]
+}
+
+func (x TaskStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use TaskStatus.Descriptor instead.
+func (TaskStatus) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{16}
+}
+
 type OperationType int32
 
 const (
@@ -972,11 +1191,11 @@ func (x OperationType) String() string {
 }
 
 func (OperationType) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[13].Descriptor()
+	return file_asld_kvs_proto_enumTypes[17].Descriptor()
 }
 
 func (OperationType) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[13]
+	return &file_asld_kvs_proto_enumTypes[17]
 }
 
 func (x OperationType) Number() protoreflect.EnumNumber {
@@ -985,7 +1204,7 @@ func (x OperationType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use OperationType.Descriptor instead.
 func (OperationType) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{13}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{17}
 }
 
 type RecordExistsAction int32
@@ -1038,11 +1257,11 @@ func (x RecordExistsAction) String() string {
 }
 
 func (RecordExistsAction) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[14].Descriptor()
+	return file_asld_kvs_proto_enumTypes[18].Descriptor()
 }
 
 func (RecordExistsAction) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[14]
+	return &file_asld_kvs_proto_enumTypes[18]
 }
 
 func (x RecordExistsAction) Number() protoreflect.EnumNumber {
@@ -1051,7 +1270,7 @@ func (x RecordExistsAction) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use RecordExistsAction.Descriptor instead.
 func (RecordExistsAction) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{14}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{18}
 }
 
 type GenerationPolicy int32
@@ -1091,11 +1310,11 @@ func (x GenerationPolicy) String() string {
 }
 
 func (GenerationPolicy) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[15].Descriptor()
+	return file_asld_kvs_proto_enumTypes[19].Descriptor()
 }
 
 func (GenerationPolicy) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[15]
+	return &file_asld_kvs_proto_enumTypes[19]
 }
 
 func (x GenerationPolicy) Number() protoreflect.EnumNumber {
@@ -1104,7 +1323,7 @@ func (x GenerationPolicy) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use GenerationPolicy.Descriptor instead.
 func (GenerationPolicy) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{15}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{19}
 }
 
 type CommitLevel int32
@@ -1139,11 +1358,11 @@ func (x CommitLevel) String() string {
 }
 
 func (CommitLevel) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[16].Descriptor()
+	return file_asld_kvs_proto_enumTypes[20].Descriptor()
 }
 
 func (CommitLevel) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[16]
+	return &file_asld_kvs_proto_enumTypes[20]
 }
 
 func (x CommitLevel) Number() protoreflect.EnumNumber {
@@ -1152,7 +1371,7 @@ func (x CommitLevel) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CommitLevel.Descriptor instead.
 func (CommitLevel) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{16}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{20}
 }
 
 // QueryDuration defines the expected query duration. The server treats the query in different ways depending on the expected duration.
@@ -1208,11 +1427,11 @@ func (x QueryDuration) String() string {
 }
 
 func (QueryDuration) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[17].Descriptor()
+	return file_asld_kvs_proto_enumTypes[21].Descriptor()
 }
 
 func (QueryDuration) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[17]
+	return &file_asld_kvs_proto_enumTypes[21]
 }
 
 func (x QueryDuration) Number() protoreflect.EnumNumber {
@@ -1221,7 +1440,7 @@ func (x QueryDuration) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use QueryDuration.Descriptor instead.
 func (QueryDuration) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{17}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{21}
 }
 
 type CdtMapCommandOp int32
@@ -1371,11 +1590,11 @@ func (x CdtMapCommandOp) String() string {
 }
 
 func (CdtMapCommandOp) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[18].Descriptor()
+	return file_asld_kvs_proto_enumTypes[22].Descriptor()
 }
 
 func (CdtMapCommandOp) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[18]
+	return &file_asld_kvs_proto_enumTypes[22]
 }
 
 func (x CdtMapCommandOp) Number() protoreflect.EnumNumber {
@@ -1384,7 +1603,7 @@ func (x CdtMapCommandOp) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtMapCommandOp.Descriptor instead.
 func (CdtMapCommandOp) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{18}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{22}
 }
 
 type CdtMapReturnType int32
@@ -1480,11 +1699,11 @@ func (x CdtMapReturnType) String() string {
 }
 
 func (CdtMapReturnType) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[19].Descriptor()
+	return file_asld_kvs_proto_enumTypes[23].Descriptor()
 }
 
 func (CdtMapReturnType) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[19]
+	return &file_asld_kvs_proto_enumTypes[23]
 }
 
 func (x CdtMapReturnType) Number() protoreflect.EnumNumber {
@@ -1493,7 +1712,7 @@ func (x CdtMapReturnType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtMapReturnType.Descriptor instead.
 func (CdtMapReturnType) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{19}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{23}
 }
 
 type CdtMapWriteMode int32
@@ -1529,11 +1748,11 @@ func (x CdtMapWriteMode) String() string {
 }
 
 func (CdtMapWriteMode) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[20].Descriptor()
+	return file_asld_kvs_proto_enumTypes[24].Descriptor()
 }
 
 func (CdtMapWriteMode) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[20]
+	return &file_asld_kvs_proto_enumTypes[24]
 }
 
 func (x CdtMapWriteMode) Number() protoreflect.EnumNumber {
@@ -1542,7 +1761,7 @@ func (x CdtMapWriteMode) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtMapWriteMode.Descriptor instead.
 func (CdtMapWriteMode) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{20}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{24}
 }
 
 type CdtMapWriteFlags int32
@@ -1592,11 +1811,11 @@ func (x CdtMapWriteFlags) String() string {
 }
 
 func (CdtMapWriteFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[21].Descriptor()
+	return file_asld_kvs_proto_enumTypes[25].Descriptor()
 }
 
 func (CdtMapWriteFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[21]
+	return &file_asld_kvs_proto_enumTypes[25]
 }
 
 func (x CdtMapWriteFlags) Number() protoreflect.EnumNumber {
@@ -1605,7 +1824,7 @@ func (x CdtMapWriteFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtMapWriteFlags.Descriptor instead.
 func (CdtMapWriteFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{21}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{25}
 }
 
 type CdtListCommandOp int32
@@ -1646,6 +1865,7 @@ const (
 	CdtListCommandOp_CdtListCommandOpRemoveByRank                        CdtListCommandOp = 32
 	CdtListCommandOp_CdtListCommandOpRemoveByRankRange                   CdtListCommandOp = 33
 	CdtListCommandOp_CdtListCommandOpRemoveByRankRangeCount              CdtListCommandOp = 34
+	CdtListCommandOp_CdtListCommandOpGetByValue                          CdtListCommandOp = 35
 )
 
 // Enum value maps for CdtListCommandOp.
@@ -1686,6 +1906,7 @@ var (
 		32: "CdtListCommandOpRemoveByRank",
 		33: "CdtListCommandOpRemoveByRankRange",
 		34: "CdtListCommandOpRemoveByRankRangeCount",
+		35: "CdtListCommandOpGetByValue",
 	}
 	CdtListCommandOp_value = map[string]int32{
 		"CdtListCommandOpCreate":                              0,
@@ -1723,6 +1944,7 @@ var (
 		"CdtListCommandOpRemoveByRank":                        32,
 		"CdtListCommandOpRemoveByRankRange":                   33,
 		"CdtListCommandOpRemoveByRankRangeCount":              34,
+		"CdtListCommandOpGetByValue":                          35,
 	}
 )
 
@@ -1737,11 +1959,11 @@ func (x CdtListCommandOp) String() string {
 }
 
 func (CdtListCommandOp) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[22].Descriptor()
+	return file_asld_kvs_proto_enumTypes[26].Descriptor()
 }
 
 func (CdtListCommandOp) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[22]
+	return &file_asld_kvs_proto_enumTypes[26]
 }
 
 func (x CdtListCommandOp) Number() protoreflect.EnumNumber {
@@ -1750,7 +1972,7 @@ func (x CdtListCommandOp) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtListCommandOp.Descriptor instead.
 func (CdtListCommandOp) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{22}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{26}
 }
 
 type CdtListReturnType int32
@@ -1820,11 +2042,11 @@ func (x CdtListReturnType) String() string {
 }
 
 func (CdtListReturnType) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[23].Descriptor()
+	return file_asld_kvs_proto_enumTypes[27].Descriptor()
 }
 
 func (CdtListReturnType) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[23]
+	return &file_asld_kvs_proto_enumTypes[27]
 }
 
 func (x CdtListReturnType) Number() protoreflect.EnumNumber {
@@ -1833,7 +2055,7 @@ func (x CdtListReturnType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtListReturnType.Descriptor instead.
 func (CdtListReturnType) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{23}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{27}
 }
 
 type CdtListSortFlags int32
@@ -1872,11 +2094,11 @@ func (x CdtListSortFlags) String() string {
 }
 
 func (CdtListSortFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[24].Descriptor()
+	return file_asld_kvs_proto_enumTypes[28].Descriptor()
 }
 
 func (CdtListSortFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[24]
+	return &file_asld_kvs_proto_enumTypes[28]
 }
 
 func (x CdtListSortFlags) Number() protoreflect.EnumNumber {
@@ -1885,7 +2107,7 @@ func (x CdtListSortFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtListSortFlags.Descriptor instead.
 func (CdtListSortFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{24}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{28}
 }
 
 type CdtListWriteFlags int32
@@ -1934,11 +2156,11 @@ func (x CdtListWriteFlags) String() string {
 }
 
 func (CdtListWriteFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[25].Descriptor()
+	return file_asld_kvs_proto_enumTypes[29].Descriptor()
 }
 
 func (CdtListWriteFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[25]
+	return &file_asld_kvs_proto_enumTypes[29]
 }
 
 func (x CdtListWriteFlags) Number() protoreflect.EnumNumber {
@@ -1947,7 +2169,7 @@ func (x CdtListWriteFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtListWriteFlags.Descriptor instead.
 func (CdtListWriteFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{25}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{29}
 }
 
 type CdtHLLCommandOp int32
@@ -1964,6 +2186,7 @@ const (
 	CdtHLLCommandOp_CdtHLLCommandOpGetIntersectCount CdtHLLCommandOp = 8
 	CdtHLLCommandOp_CdtHLLCommandOpGetSimilarity     CdtHLLCommandOp = 9
 	CdtHLLCommandOp_CdtHLLCommandOpDescribe          CdtHLLCommandOp = 10
+	CdtHLLCommandOp_CdtHLLCommandOpMayContain        CdtHLLCommandOp = 11
 )
 
 // Enum value maps for CdtHLLCommandOp.
@@ -1980,6 +2203,7 @@ var (
 		8:  "CdtHLLCommandOpGetIntersectCount",
 		9:  "CdtHLLCommandOpGetSimilarity",
 		10: "CdtHLLCommandOpDescribe",
+		11: "CdtHLLCommandOpMayContain",
 	}
 	CdtHLLCommandOp_value = map[string]int32{
 		"CdtHLLCommandOpInit":              0,
@@ -1993,6 +2217,7 @@ var (
 		"CdtHLLCommandOpGetIntersectCount": 8,
 		"CdtHLLCommandOpGetSimilarity":     9,
 		"CdtHLLCommandOpDescribe":          10,
+		"CdtHLLCommandOpMayContain":        11,
 	}
 )
 
@@ -2007,11 +2232,11 @@ func (x CdtHLLCommandOp) String() string {
 }
 
 func (CdtHLLCommandOp) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[26].Descriptor()
+	return file_asld_kvs_proto_enumTypes[30].Descriptor()
 }
 
 func (CdtHLLCommandOp) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[26]
+	return &file_asld_kvs_proto_enumTypes[30]
 }
 
 func (x CdtHLLCommandOp) Number() protoreflect.EnumNumber {
@@ -2020,7 +2245,7 @@ func (x CdtHLLCommandOp) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtHLLCommandOp.Descriptor instead.
 func (CdtHLLCommandOp) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{26}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{30}
 }
 
 type CdtHLLWriteFlags int32
@@ -2073,11 +2298,11 @@ func (x CdtHLLWriteFlags) String() string {
 }
 
 func (CdtHLLWriteFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[27].Descriptor()
+	return file_asld_kvs_proto_enumTypes[31].Descriptor()
 }
 
 func (CdtHLLWriteFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[27]
+	return &file_asld_kvs_proto_enumTypes[31]
 }
 
 func (x CdtHLLWriteFlags) Number() protoreflect.EnumNumber {
@@ -2086,7 +2311,7 @@ func (x CdtHLLWriteFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtHLLWriteFlags.Descriptor instead.
 func (CdtHLLWriteFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{27}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{31}
 }
 
 type CdtBitwiseCommandOp int32
@@ -2167,11 +2392,11 @@ func (x CdtBitwiseCommandOp) String() string {
 }
 
 func (CdtBitwiseCommandOp) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[28].Descriptor()
+	return file_asld_kvs_proto_enumTypes[32].Descriptor()
 }
 
 func (CdtBitwiseCommandOp) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[28]
+	return &file_asld_kvs_proto_enumTypes[32]
 }
 
 func (x CdtBitwiseCommandOp) Number() protoreflect.EnumNumber {
@@ -2180,7 +2405,7 @@ func (x CdtBitwiseCommandOp) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtBitwiseCommandOp.Descriptor instead.
 func (CdtBitwiseCommandOp) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{28}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{32}
 }
 
 type CdtBitwiseWriteFlags int32
@@ -2232,11 +2457,11 @@ func (x CdtBitwiseWriteFlags) String() string {
 }
 
 func (CdtBitwiseWriteFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[29].Descriptor()
+	return file_asld_kvs_proto_enumTypes[33].Descriptor()
 }
 
 func (CdtBitwiseWriteFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[29]
+	return &file_asld_kvs_proto_enumTypes[33]
 }
 
 func (x CdtBitwiseWriteFlags) Number() protoreflect.EnumNumber {
@@ -2245,7 +2470,7 @@ func (x CdtBitwiseWriteFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtBitwiseWriteFlags.Descriptor instead.
 func (CdtBitwiseWriteFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{29}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{33}
 }
 
 type CdtBitwiseResizeFlags int32
@@ -2288,11 +2513,11 @@ func (x CdtBitwiseResizeFlags) String() string {
 }
 
 func (CdtBitwiseResizeFlags) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[30].Descriptor()
+	return file_asld_kvs_proto_enumTypes[34].Descriptor()
 }
 
 func (CdtBitwiseResizeFlags) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[30]
+	return &file_asld_kvs_proto_enumTypes[34]
 }
 
 func (x CdtBitwiseResizeFlags) Number() protoreflect.EnumNumber {
@@ -2301,7 +2526,7 @@ func (x CdtBitwiseResizeFlags) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtBitwiseResizeFlags.Descriptor instead.
 func (CdtBitwiseResizeFlags) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{30}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{34}
 }
 
 type CdtBitwiseOverflowAction int32
@@ -2342,11 +2567,11 @@ func (x CdtBitwiseOverflowAction) String() string {
 }
 
 func (CdtBitwiseOverflowAction) Descriptor() protoreflect.EnumDescriptor {
-	return file_asld_kvs_proto_enumTypes[31].Descriptor()
+	return file_asld_kvs_proto_enumTypes[35].Descriptor()
 }
 
 func (CdtBitwiseOverflowAction) Type() protoreflect.EnumType {
-	return &file_asld_kvs_proto_enumTypes[31]
+	return &file_asld_kvs_proto_enumTypes[35]
 }
 
 func (x CdtBitwiseOverflowAction) Number() protoreflect.EnumNumber {
@@ -2355,48 +2580,221 @@ func (x CdtBitwiseOverflowAction) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CdtBitwiseOverflowAction.Descriptor instead.
 func (CdtBitwiseOverflowAction) EnumDescriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{31}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{35}
 }
 
-// Expression which can be applied to most commands, to control which records are
-// affected by the command.
-type Expression struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+// ExpReadFlags defines the expression read operation flags.
+type ExpReadFlags int32
 
-	// The Operation code
-	Cmd *ExpOp `protobuf:"varint,1,opt,name=cmd,proto3,enum=com.aerospike.daemon.ExpOp,oneof" json:"cmd,omitempty"`
-	// The Primary Value of the Operation
-	Val *Value `protobuf:"bytes,2,opt,name=val,proto3,oneof" json:"val,omitempty"`
-	// The Bin to use it on (REGEX for example)
-	Bin *Expression `protobuf:"bytes,3,opt,name=bin,proto3,oneof" json:"bin,omitempty"`
-	// The additional flags for the Operation (REGEX or return_type of Module for example)
-	Flags *int64 `protobuf:"varint,4,opt,name=flags,proto3,oneof" json:"flags,omitempty"`
-	// The optional Module flag for Module operations or Bin Types
-	Module *ExpType `protobuf:"varint,5,opt,name=module,proto3,enum=com.aerospike.daemon.ExpType,oneof" json:"module,omitempty"`
-	// Sub commands for the CmdExp operation
-	Exps []*Expression `protobuf:"bytes,6,rep,name=exps,proto3" json:"exps,omitempty"`
-}
+const (
+	// ExpReadFlagsDefault is the default.
+	ExpReadFlags_ExpReadFlagsDefault ExpReadFlags = 0
+	// ExpReadFlagsEvalNoFail ignores failures caused by the expression resolving to unknown or a
+	// non-bin type.
+	ExpReadFlags_ExpReadFlagsEvalNoFail ExpReadFlags = 16
+)
 
-func (x *Expression) Reset() {
-	*x = Expression{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[0]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+// Enum value maps for ExpReadFlags.
+var (
+	ExpReadFlags_name = map[int32]string{
+		0:  "ExpReadFlagsDefault",
+		16: "ExpReadFlagsEvalNoFail",
+	}
+	ExpReadFlags_value = map[string]int32{
+		"ExpReadFlagsDefault":    0,
+		"ExpReadFlagsEvalNoFail": 16,
 	}
+)
+
+func (x ExpReadFlags) Enum() *ExpReadFlags {
+	p := new(ExpReadFlags)
+	*p = x
+	return p
 }
 
-func (x *Expression) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x ExpReadFlags) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
 }
 
-func (*Expression) ProtoMessage() {}
+func (ExpReadFlags) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[36].Descriptor()
+}
 
-func (x *Expression) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[0]
-	if protoimpl.UnsafeEnabled && x != nil {
+func (ExpReadFlags) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[36]
+}
+
+func (x ExpReadFlags) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ExpReadFlags.Descriptor instead.
+func (ExpReadFlags) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{36}
+}
+
+// ExpWriteFlags defines the expression write operation flags.
+type ExpWriteFlags int32
+
+const (
+	// ExpWriteFlagsDefault is the default. Allows create or update.
+	ExpWriteFlags_ExpWriteFlagsDefault ExpWriteFlags = 0
+	// ExpWriteFlagsCreateOnly: If bin does not exist, a new bin will be created.
+	// If bin exists, the operation will be denied.
+	ExpWriteFlags_ExpWriteFlagsCreateOnly ExpWriteFlags = 1
+	// ExpWriteFlagsUpdateOnly: If bin exists, the bin will be overwritten.
+	// If bin does not exist, the operation will be denied.
+	ExpWriteFlags_ExpWriteFlagsUpdateOnly ExpWriteFlags = 2
+	// ExpWriteFlagsAllowDelete: If expression results in nil value, then delete the bin.
+	ExpWriteFlags_ExpWriteFlagsAllowDelete ExpWriteFlags = 4
+	// ExpWriteFlagsPolicyNoFail: Do not raise error if operation is denied.
+	ExpWriteFlags_ExpWriteFlagsPolicyNoFail ExpWriteFlags = 8
+	// ExpWriteFlagsEvalNoFail: Ignore failures caused by the expression resolving to unknown or a
+	// non-bin type.
+	ExpWriteFlags_ExpWriteFlagsEvalNoFail ExpWriteFlags = 16
+)
+
+// Enum value maps for ExpWriteFlags.
+var (
+	ExpWriteFlags_name = map[int32]string{
+		0:  "ExpWriteFlagsDefault",
+		1:  "ExpWriteFlagsCreateOnly",
+		2:  "ExpWriteFlagsUpdateOnly",
+		4:  "ExpWriteFlagsAllowDelete",
+		8:  "ExpWriteFlagsPolicyNoFail",
+		16: "ExpWriteFlagsEvalNoFail",
+	}
+	ExpWriteFlags_value = map[string]int32{
+		"ExpWriteFlagsDefault":      0,
+		"ExpWriteFlagsCreateOnly":   1,
+		"ExpWriteFlagsUpdateOnly":   2,
+		"ExpWriteFlagsAllowDelete":  4,
+		"ExpWriteFlagsPolicyNoFail": 8,
+		"ExpWriteFlagsEvalNoFail":   16,
+	}
+)
+
+func (x ExpWriteFlags) Enum() *ExpWriteFlags {
+	p := new(ExpWriteFlags)
+	*p = x
+	return p
+}
+
+func (x ExpWriteFlags) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ExpWriteFlags) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[37].Descriptor()
+}
+
+func (ExpWriteFlags) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[37]
+}
+
+func (x ExpWriteFlags) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ExpWriteFlags.Descriptor instead.
+func (ExpWriteFlags) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{37}
+}
+
+// ExpOperationType determines if the expression operation reads or writes.
+type ExpOperationType int32
+
+const (
+	ExpOperationType_ExpOperationTypeRead  ExpOperationType = 0
+	ExpOperationType_ExpOperationTypeWrite ExpOperationType = 1
+)
+
+// Enum value maps for ExpOperationType.
+var (
+	ExpOperationType_name = map[int32]string{
+		0: "ExpOperationTypeRead",
+		1: "ExpOperationTypeWrite",
+	}
+	ExpOperationType_value = map[string]int32{
+		"ExpOperationTypeRead":  0,
+		"ExpOperationTypeWrite": 1,
+	}
+)
+
+func (x ExpOperationType) Enum() *ExpOperationType {
+	p := new(ExpOperationType)
+	*p = x
+	return p
+}
+
+func (x ExpOperationType) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ExpOperationType) Descriptor() protoreflect.EnumDescriptor {
+	return file_asld_kvs_proto_enumTypes[38].Descriptor()
+}
+
+func (ExpOperationType) Type() protoreflect.EnumType {
+	return &file_asld_kvs_proto_enumTypes[38]
+}
+
+func (x ExpOperationType) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ExpOperationType.Descriptor instead.
+func (ExpOperationType) EnumDescriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{38}
+}
+
+// Expression which can be applied to most commands, to control which records are
+// affected by the command.
+type Expression struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The Operation code
+	Cmd *ExpOp `protobuf:"varint,1,opt,name=cmd,proto3,enum=com.aerospike.daemon.ExpOp,oneof" json:"cmd,omitempty"`
+	// The Primary Value of the Operation
+	Val *Value `protobuf:"bytes,2,opt,name=val,proto3,oneof" json:"val,omitempty"`
+	// The Bin to use it on (REGEX for example)
+	Bin *Expression `protobuf:"bytes,3,opt,name=bin,proto3,oneof" json:"bin,omitempty"`
+	// The additional flags for the Operation (REGEX or return_type of Module for example)
+	Flags *int64 `protobuf:"varint,4,opt,name=flags,proto3,oneof" json:"flags,omitempty"`
+	// The optional Module flag for Module operations or Bin Types
+	Module *ExpType `protobuf:"varint,5,opt,name=module,proto3,enum=com.aerospike.daemon.ExpType,oneof" json:"module,omitempty"`
+	// Sub commands for the CmdExp operation
+	Exps []*Expression `protobuf:"bytes,6,rep,name=exps,proto3" json:"exps,omitempty"`
+	// The context of the nested list or map for ExpOpCall (module) operations
+	Ctx []*CDTContext `protobuf:"bytes,7,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	// The type of the value returned by single item ExpOpCall (module) operations,
+	// like ListExp::getByIndex when the return type is VALUE
+	ValueType *ExpType `protobuf:"varint,8,opt,name=valueType,proto3,enum=com.aerospike.daemon.ExpType,oneof" json:"valueType,omitempty"`
+	// The expression in the server wire format, base64 encoded, as shared with the
+	// other Aerospike clients. Set instead of the other fields.
+	Base64 *string `protobuf:"bytes,9,opt,name=base64,proto3,oneof" json:"base64,omitempty"`
+}
+
+func (x *Expression) Reset() {
+	*x = Expression{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[0]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Expression) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Expression) ProtoMessage() {}
+
+func (x *Expression) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[0]
+	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
@@ -2453,6 +2851,27 @@ func (x *Expression) GetExps() []*Expression {
 	return nil
 }
 
+func (x *Expression) GetCtx() []*CDTContext {
+	if x != nil {
+		return x.Ctx
+	}
+	return nil
+}
+
+func (x *Expression) GetValueType() ExpType {
+	if x != nil && x.ValueType != nil {
+		return *x.ValueType
+	}
+	return ExpType_ExpTypeNil
+}
+
+func (x *Expression) GetBase64() string {
+	if x != nil && x.Base64 != nil {
+		return *x.Base64
+	}
+	return ""
+}
+
 // ReadPolicy encapsulates parameters for transaction policy attributes
 // used in all database operation calls.
 type ReadPolicy struct {
@@ -2557,6 +2976,9 @@ type ReadPolicy struct {
 	// 1 - 100 : Reset record TTL on reads when within this percentage of the most recent write TTL.
 	// Default: 0
 	ReadTouchTTLPercent int32 `protobuf:"varint,13,opt,name=readTouchTTLPercent,proto3" json:"readTouchTTLPercent,omitempty"`
+	// Multi-record transaction the command belongs to. The command is not part of a transaction
+	// if not set.
+	Txn *Txn `protobuf:"bytes,14,opt,name=txn,proto3,oneof" json:"txn,omitempty"`
 }
 
 func (x *ReadPolicy) Reset() {
@@ -2682,6 +3104,13 @@ func (x *ReadPolicy) GetReadTouchTTLPercent() int32 {
 	return 0
 }
 
+func (x *ReadPolicy) GetTxn() *Txn {
+	if x != nil {
+		return x.Txn
+	}
+	return nil
+}
+
 // Write policy attributes used in write database commands that are not part of
 // the wire protocol.
 type WritePolicy struct {
@@ -4459,6 +4888,7 @@ type Operation struct {
 	//	*Operation_List
 	//	*Operation_Hll
 	//	*Operation_Bitwise
+	//	*Operation_Exp
 	Op isOperation_Op `protobuf_oneof:"op"`
 }
 
@@ -4536,6 +4966,13 @@ func (x *Operation) GetBitwise() *CdtBitwiseOperation {
 	return nil
 }
 
+func (x *Operation) GetExp() *ExpOperation {
+	if x, ok := x.GetOp().(*Operation_Exp); ok {
+		return x.Exp
+	}
+	return nil
+}
+
 type isOperation_Op interface {
 	isOperation_Op()
 }
@@ -4560,6 +4997,10 @@ type Operation_Bitwise struct {
 	Bitwise *CdtBitwiseOperation `protobuf:"bytes,5,opt,name=bitwise,proto3,oneof"`
 }
 
+type Operation_Exp struct {
+	Exp *ExpOperation `protobuf:"bytes,6,opt,name=exp,proto3,oneof"`
+}
+
 func (*Operation_Std) isOperation_Op() {}
 
 func (*Operation_Map) isOperation_Op() {}
@@ -4570,6 +5011,8 @@ func (*Operation_Hll) isOperation_Op() {}
 
 func (*Operation_Bitwise) isOperation_Op() {}
 
+func (*Operation_Exp) isOperation_Op() {}
+
 // Operation contains operation definition.
 // This struct is used in client's operate() method.
 type StdOperation struct {
@@ -5834,6 +6277,69 @@ func (x *AerospikeTouchRequest) GetKey() *Key {
 	return nil
 }
 
+type AerospikeOperateRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Policy     *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Key        *Key         `protobuf:"bytes,2,opt,name=key,proto3" json:"key,omitempty"`
+	Operations []*Operation `protobuf:"bytes,3,rep,name=operations,proto3" json:"operations,omitempty"`
+}
+
+func (x *AerospikeOperateRequest) Reset() {
+	*x = AerospikeOperateRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[42]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeOperateRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeOperateRequest) ProtoMessage() {}
+
+func (x *AerospikeOperateRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[42]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeOperateRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeOperateRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{42}
+}
+
+func (x *AerospikeOperateRequest) GetPolicy() *WritePolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *AerospikeOperateRequest) GetKey() *Key {
+	if x != nil {
+		return x.Key
+	}
+	return nil
+}
+
+func (x *AerospikeOperateRequest) GetOperations() []*Operation {
+	if x != nil {
+		return x.Operations
+	}
+	return nil
+}
+
 type AerospikePutRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -5847,7 +6353,7 @@ type AerospikePutRequest struct {
 func (x *AerospikePutRequest) Reset() {
 	*x = AerospikePutRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[42]
+		mi := &file_asld_kvs_proto_msgTypes[43]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -5860,7 +6366,7 @@ func (x *AerospikePutRequest) String() string {
 func (*AerospikePutRequest) ProtoMessage() {}
 
 func (x *AerospikePutRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[42]
+	mi := &file_asld_kvs_proto_msgTypes[43]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5873,7 +6379,7 @@ func (x *AerospikePutRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikePutRequest.ProtoReflect.Descriptor instead.
 func (*AerospikePutRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{42}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{43}
 }
 
 func (x *AerospikePutRequest) GetPolicy() *WritePolicy {
@@ -5911,7 +6417,7 @@ type BatchOperate struct {
 func (x *BatchOperate) Reset() {
 	*x = BatchOperate{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[43]
+		mi := &file_asld_kvs_proto_msgTypes[44]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -5924,7 +6430,7 @@ func (x *BatchOperate) String() string {
 func (*BatchOperate) ProtoMessage() {}
 
 func (x *BatchOperate) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[43]
+	mi := &file_asld_kvs_proto_msgTypes[44]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5937,7 +6443,7 @@ func (x *BatchOperate) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BatchOperate.ProtoReflect.Descriptor instead.
 func (*BatchOperate) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{43}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{44}
 }
 
 func (x *BatchOperate) GetBr() *BatchRead {
@@ -5980,7 +6486,7 @@ type AerospikeBatchOperateRequest struct {
 func (x *AerospikeBatchOperateRequest) Reset() {
 	*x = AerospikeBatchOperateRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[44]
+		mi := &file_asld_kvs_proto_msgTypes[45]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -5993,7 +6499,7 @@ func (x *AerospikeBatchOperateRequest) String() string {
 func (*AerospikeBatchOperateRequest) ProtoMessage() {}
 
 func (x *AerospikeBatchOperateRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[44]
+	mi := &file_asld_kvs_proto_msgTypes[45]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6006,7 +6512,7 @@ func (x *AerospikeBatchOperateRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeBatchOperateRequest.ProtoReflect.Descriptor instead.
 func (*AerospikeBatchOperateRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{44}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{45}
 }
 
 func (x *AerospikeBatchOperateRequest) GetPolicy() *BatchPolicy {
@@ -6035,7 +6541,7 @@ type AerospikeBatchOperateResponse struct {
 func (x *AerospikeBatchOperateResponse) Reset() {
 	*x = AerospikeBatchOperateResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[45]
+		mi := &file_asld_kvs_proto_msgTypes[46]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -6048,7 +6554,7 @@ func (x *AerospikeBatchOperateResponse) String() string {
 func (*AerospikeBatchOperateResponse) ProtoMessage() {}
 
 func (x *AerospikeBatchOperateResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[45]
+	mi := &file_asld_kvs_proto_msgTypes[46]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6061,7 +6567,7 @@ func (x *AerospikeBatchOperateResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeBatchOperateResponse.ProtoReflect.Descriptor instead.
 func (*AerospikeBatchOperateResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{45}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{46}
 }
 
 func (x *AerospikeBatchOperateResponse) GetError() *Error {
@@ -6096,7 +6602,7 @@ type AerospikeCreateIndexRequest struct {
 func (x *AerospikeCreateIndexRequest) Reset() {
 	*x = AerospikeCreateIndexRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[46]
+		mi := &file_asld_kvs_proto_msgTypes[47]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -6109,7 +6615,7 @@ func (x *AerospikeCreateIndexRequest) String() string {
 func (*AerospikeCreateIndexRequest) ProtoMessage() {}
 
 func (x *AerospikeCreateIndexRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[46]
+	mi := &file_asld_kvs_proto_msgTypes[47]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6122,7 +6628,7 @@ func (x *AerospikeCreateIndexRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeCreateIndexRequest.ProtoReflect.Descriptor instead.
 func (*AerospikeCreateIndexRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{46}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{47}
 }
 
 func (x *AerospikeCreateIndexRequest) GetPolicy() *WritePolicy {
@@ -6192,7 +6698,7 @@ type AerospikeCreateIndexResponse struct {
 func (x *AerospikeCreateIndexResponse) Reset() {
 	*x = AerospikeCreateIndexResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[47]
+		mi := &file_asld_kvs_proto_msgTypes[48]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -6205,7 +6711,7 @@ func (x *AerospikeCreateIndexResponse) String() string {
 func (*AerospikeCreateIndexResponse) ProtoMessage() {}
 
 func (x *AerospikeCreateIndexResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[47]
+	mi := &file_asld_kvs_proto_msgTypes[48]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6218,7 +6724,7 @@ func (x *AerospikeCreateIndexResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeCreateIndexResponse.ProtoReflect.Descriptor instead.
 func (*AerospikeCreateIndexResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{47}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{48}
 }
 
 func (x *AerospikeCreateIndexResponse) GetError() *Error {
@@ -6242,7 +6748,7 @@ type AerospikeDropIndexRequest struct {
 func (x *AerospikeDropIndexRequest) Reset() {
 	*x = AerospikeDropIndexRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[48]
+		mi := &file_asld_kvs_proto_msgTypes[49]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -6255,7 +6761,7 @@ func (x *AerospikeDropIndexRequest) String() string {
 func (*AerospikeDropIndexRequest) ProtoMessage() {}
 
 func (x *AerospikeDropIndexRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[48]
+	mi := &file_asld_kvs_proto_msgTypes[49]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6268,7 +6774,7 @@ func (x *AerospikeDropIndexRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeDropIndexRequest.ProtoReflect.Descriptor instead.
 func (*AerospikeDropIndexRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{48}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{49}
 }
 
 func (x *AerospikeDropIndexRequest) GetPolicy() *WritePolicy {
@@ -6310,7 +6816,7 @@ type AerospikeDropIndexResponse struct {
 func (x *AerospikeDropIndexResponse) Reset() {
 	*x = AerospikeDropIndexResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[49]
+		mi := &file_asld_kvs_proto_msgTypes[50]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -6323,7 +6829,7 @@ func (x *AerospikeDropIndexResponse) String() string {
 func (*AerospikeDropIndexResponse) ProtoMessage() {}
 
 func (x *AerospikeDropIndexResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[49]
+	mi := &file_asld_kvs_proto_msgTypes[50]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6336,7 +6842,7 @@ func (x *AerospikeDropIndexResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AerospikeDropIndexResponse.ProtoReflect.Descriptor instead.
 func (*AerospikeDropIndexResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{49}
+	return file_asld_kvs_proto_rawDescGZIP(), []int{50}
 }
 
 func (x *AerospikeDropIndexResponse) GetError() *Error {
@@ -6346,34 +6852,37 @@ func (x *AerospikeDropIndexResponse) GetError() *Error {
 	return nil
 }
 
-type AerospikeTruncateRequest struct {
+// Txn identifies a multi-record transaction. The connection manager keeps the state of the
+// transaction between requests, keyed by its id.
+type Txn struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy      *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	Namespace   string      `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
-	SetName     string      `protobuf:"bytes,3,opt,name=setName,proto3" json:"setName,omitempty"`
-	BeforeNanos *int64      `protobuf:"varint,4,opt,name=before_nanos,json=beforeNanos,proto3,oneof" json:"before_nanos,omitempty"`
+	// Id of the transaction, generated by the client.
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+	// Timeout of the transaction in seconds, counted from the first write in the transaction.
+	// Zero means the server's mrt-duration config is used.
+	Timeout uint32 `protobuf:"varint,2,opt,name=timeout,proto3" json:"timeout,omitempty"`
 }
 
-func (x *AerospikeTruncateRequest) Reset() {
-	*x = AerospikeTruncateRequest{}
+func (x *Txn) Reset() {
+	*x = Txn{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[50]
+		mi := &file_asld_kvs_proto_msgTypes[51]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AerospikeTruncateRequest) String() string {
+func (x *Txn) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeTruncateRequest) ProtoMessage() {}
+func (*Txn) ProtoMessage() {}
 
-func (x *AerospikeTruncateRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[50]
+func (x *Txn) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[51]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6384,64 +6893,50 @@ func (x *AerospikeTruncateRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeTruncateRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeTruncateRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{50}
-}
-
-func (x *AerospikeTruncateRequest) GetPolicy() *InfoPolicy {
-	if x != nil {
-		return x.Policy
-	}
-	return nil
+// Deprecated: Use Txn.ProtoReflect.Descriptor instead.
+func (*Txn) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{51}
 }
 
-func (x *AerospikeTruncateRequest) GetNamespace() string {
+func (x *Txn) GetId() int64 {
 	if x != nil {
-		return x.Namespace
+		return x.Id
 	}
-	return ""
+	return 0
 }
 
-func (x *AerospikeTruncateRequest) GetSetName() string {
+func (x *Txn) GetTimeout() uint32 {
 	if x != nil {
-		return x.SetName
+		return x.Timeout
 	}
-	return ""
+	return 0
 }
 
-func (x *AerospikeTruncateRequest) GetBeforeNanos() int64 {
-	if x != nil && x.BeforeNanos != nil {
-		return *x.BeforeNanos
-	}
-	return 0
-}
-
-type AerospikeTruncateResponse struct {
+type AerospikeCommitRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Txn *Txn `protobuf:"bytes,1,opt,name=txn,proto3" json:"txn,omitempty"`
 }
 
-func (x *AerospikeTruncateResponse) Reset() {
-	*x = AerospikeTruncateResponse{}
+func (x *AerospikeCommitRequest) Reset() {
+	*x = AerospikeCommitRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[51]
+		mi := &file_asld_kvs_proto_msgTypes[52]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AerospikeTruncateResponse) String() string {
+func (x *AerospikeCommitRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeTruncateResponse) ProtoMessage() {}
+func (*AerospikeCommitRequest) ProtoMessage() {}
 
-func (x *AerospikeTruncateResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[51]
+func (x *AerospikeCommitRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[52]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6452,46 +6947,45 @@ func (x *AerospikeTruncateResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeTruncateResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeTruncateResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{51}
+// Deprecated: Use AerospikeCommitRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeCommitRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{52}
 }
 
-func (x *AerospikeTruncateResponse) GetError() *Error {
+func (x *AerospikeCommitRequest) GetTxn() *Txn {
 	if x != nil {
-		return x.Error
+		return x.Txn
 	}
 	return nil
 }
 
-type AerospikeRegisterUDFRequest struct {
+type AerospikeCommitResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy      *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	PackageName string       `protobuf:"bytes,2,opt,name=packageName,proto3" json:"packageName,omitempty"`
-	UdfBody     []byte       `protobuf:"bytes,3,opt,name=udfBody,proto3" json:"udfBody,omitempty"`
-	Language    UDFLanguage  `protobuf:"varint,4,opt,name=language,proto3,enum=com.aerospike.daemon.UDFLanguage" json:"language,omitempty"`
+	Error  *Error       `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Status CommitStatus `protobuf:"varint,2,opt,name=status,proto3,enum=com.aerospike.daemon.CommitStatus" json:"status,omitempty"`
+	State  TxnState     `protobuf:"varint,3,opt,name=state,proto3,enum=com.aerospike.daemon.TxnState" json:"state,omitempty"`
 }
 
-func (x *AerospikeRegisterUDFRequest) Reset() {
-	*x = AerospikeRegisterUDFRequest{}
+func (x *AerospikeCommitResponse) Reset() {
+	*x = AerospikeCommitResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[52]
+		mi := &file_asld_kvs_proto_msgTypes[53]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AerospikeRegisterUDFRequest) String() string {
+func (x *AerospikeCommitResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRegisterUDFRequest) ProtoMessage() {}
+func (*AerospikeCommitResponse) ProtoMessage() {}
 
-func (x *AerospikeRegisterUDFRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[52]
+func (x *AerospikeCommitResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[53]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6502,64 +6996,57 @@ func (x *AerospikeRegisterUDFRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRegisterUDFRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeRegisterUDFRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{52}
+// Deprecated: Use AerospikeCommitResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeCommitResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{53}
 }
 
-func (x *AerospikeRegisterUDFRequest) GetPolicy() *WritePolicy {
+func (x *AerospikeCommitResponse) GetError() *Error {
 	if x != nil {
-		return x.Policy
+		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeRegisterUDFRequest) GetPackageName() string {
-	if x != nil {
-		return x.PackageName
-	}
-	return ""
-}
-
-func (x *AerospikeRegisterUDFRequest) GetUdfBody() []byte {
+func (x *AerospikeCommitResponse) GetStatus() CommitStatus {
 	if x != nil {
-		return x.UdfBody
+		return x.Status
 	}
-	return nil
+	return CommitStatus_CommitStatusOk
 }
 
-func (x *AerospikeRegisterUDFRequest) GetLanguage() UDFLanguage {
+func (x *AerospikeCommitResponse) GetState() TxnState {
 	if x != nil {
-		return x.Language
+		return x.State
 	}
-	return UDFLanguage_LUA
+	return TxnState_TxnStateOpen
 }
 
-type AerospikeRegisterUDFResponse struct {
+type AerospikeAbortRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Txn *Txn `protobuf:"bytes,1,opt,name=txn,proto3" json:"txn,omitempty"`
 }
 
-func (x *AerospikeRegisterUDFResponse) Reset() {
-	*x = AerospikeRegisterUDFResponse{}
+func (x *AerospikeAbortRequest) Reset() {
+	*x = AerospikeAbortRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[53]
+		mi := &file_asld_kvs_proto_msgTypes[54]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AerospikeRegisterUDFResponse) String() string {
+func (x *AerospikeAbortRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRegisterUDFResponse) ProtoMessage() {}
+func (*AerospikeAbortRequest) ProtoMessage() {}
 
-func (x *AerospikeRegisterUDFResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[53]
+func (x *AerospikeAbortRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[54]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6570,44 +7057,45 @@ func (x *AerospikeRegisterUDFResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRegisterUDFResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeRegisterUDFResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{53}
+// Deprecated: Use AerospikeAbortRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeAbortRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{54}
 }
 
-func (x *AerospikeRegisterUDFResponse) GetError() *Error {
+func (x *AerospikeAbortRequest) GetTxn() *Txn {
 	if x != nil {
-		return x.Error
+		return x.Txn
 	}
 	return nil
 }
 
-type AerospikeDropUDFRequest struct {
+type AerospikeAbortResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy      *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	PackageName string       `protobuf:"bytes,2,opt,name=packageName,proto3" json:"packageName,omitempty"`
+	Error  *Error      `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Status AbortStatus `protobuf:"varint,2,opt,name=status,proto3,enum=com.aerospike.daemon.AbortStatus" json:"status,omitempty"`
+	State  TxnState    `protobuf:"varint,3,opt,name=state,proto3,enum=com.aerospike.daemon.TxnState" json:"state,omitempty"`
 }
 
-func (x *AerospikeDropUDFRequest) Reset() {
-	*x = AerospikeDropUDFRequest{}
+func (x *AerospikeAbortResponse) Reset() {
+	*x = AerospikeAbortResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[54]
+		mi := &file_asld_kvs_proto_msgTypes[55]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AerospikeDropUDFRequest) String() string {
+func (x *AerospikeAbortResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeDropUDFRequest) ProtoMessage() {}
+func (*AerospikeAbortResponse) ProtoMessage() {}
 
-func (x *AerospikeDropUDFRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[54]
+func (x *AerospikeAbortResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[55]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6618,82 +7106,42 @@ func (x *AerospikeDropUDFRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeDropUDFRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeDropUDFRequest) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{54}
+// Deprecated: Use AerospikeAbortResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeAbortResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{55}
 }
 
-func (x *AerospikeDropUDFRequest) GetPolicy() *WritePolicy {
+func (x *AerospikeAbortResponse) GetError() *Error {
 	if x != nil {
-		return x.Policy
+		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeDropUDFRequest) GetPackageName() string {
+func (x *AerospikeAbortResponse) GetStatus() AbortStatus {
 	if x != nil {
-		return x.PackageName
-	}
-	return ""
-}
-
-type AerospikeDropUDFResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-}
-
-func (x *AerospikeDropUDFResponse) Reset() {
-	*x = AerospikeDropUDFResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_asld_kvs_proto_msgTypes[55]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
-}
-
-func (x *AerospikeDropUDFResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
-}
-
-func (*AerospikeDropUDFResponse) ProtoMessage() {}
-
-func (x *AerospikeDropUDFResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_asld_kvs_proto_msgTypes[55]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+		return x.Status
 	}
-	return mi.MessageOf(x)
-}
-
-// Deprecated: Use AerospikeDropUDFResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeDropUDFResponse) Descriptor() ([]byte, []int) {
-	return file_asld_kvs_proto_rawDescGZIP(), []int{55}
+	return AbortStatus_AbortStatusOk
 }
 
-func (x *AerospikeDropUDFResponse) GetError() *Error {
+func (x *AerospikeAbortResponse) GetState() TxnState {
 	if x != nil {
-		return x.Error
+		return x.State
 	}
-	return nil
+	return TxnState_TxnStateOpen
 }
 
-type AerospikeListUDFRequest struct {
+type AerospikeExpressionToBase64Request struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy *ReadPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Expression *Expression `protobuf:"bytes,1,opt,name=expression,proto3" json:"expression,omitempty"`
 }
 
-func (x *AerospikeListUDFRequest) Reset() {
-	*x = AerospikeListUDFRequest{}
+func (x *AerospikeExpressionToBase64Request) Reset() {
+	*x = AerospikeExpressionToBase64Request{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[56]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6701,13 +7149,13 @@ func (x *AerospikeListUDFRequest) Reset() {
 	}
 }
 
-func (x *AerospikeListUDFRequest) String() string {
+func (x *AerospikeExpressionToBase64Request) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeListUDFRequest) ProtoMessage() {}
+func (*AerospikeExpressionToBase64Request) ProtoMessage() {}
 
-func (x *AerospikeListUDFRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeExpressionToBase64Request) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[56]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6719,29 +7167,29 @@ func (x *AerospikeListUDFRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeListUDFRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeListUDFRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeExpressionToBase64Request.ProtoReflect.Descriptor instead.
+func (*AerospikeExpressionToBase64Request) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{56}
 }
 
-func (x *AerospikeListUDFRequest) GetPolicy() *ReadPolicy {
+func (x *AerospikeExpressionToBase64Request) GetExpression() *Expression {
 	if x != nil {
-		return x.Policy
+		return x.Expression
 	}
 	return nil
 }
 
-type AerospikeListUDFResponse struct {
+type AerospikeExpressionToBase64Response struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error   *Error     `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-	UdfList []*UDFMeta `protobuf:"bytes,2,rep,name=udfList,proto3" json:"udfList,omitempty"`
+	Error  *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Base64 string `protobuf:"bytes,2,opt,name=base64,proto3" json:"base64,omitempty"`
 }
 
-func (x *AerospikeListUDFResponse) Reset() {
-	*x = AerospikeListUDFResponse{}
+func (x *AerospikeExpressionToBase64Response) Reset() {
+	*x = AerospikeExpressionToBase64Response{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[57]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6749,13 +7197,13 @@ func (x *AerospikeListUDFResponse) Reset() {
 	}
 }
 
-func (x *AerospikeListUDFResponse) String() string {
+func (x *AerospikeExpressionToBase64Response) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeListUDFResponse) ProtoMessage() {}
+func (*AerospikeExpressionToBase64Response) ProtoMessage() {}
 
-func (x *AerospikeListUDFResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeExpressionToBase64Response) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[57]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6767,39 +7215,38 @@ func (x *AerospikeListUDFResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeListUDFResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeListUDFResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeExpressionToBase64Response.ProtoReflect.Descriptor instead.
+func (*AerospikeExpressionToBase64Response) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{57}
 }
 
-func (x *AerospikeListUDFResponse) GetError() *Error {
+func (x *AerospikeExpressionToBase64Response) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeListUDFResponse) GetUdfList() []*UDFMeta {
+func (x *AerospikeExpressionToBase64Response) GetBase64() string {
 	if x != nil {
-		return x.UdfList
+		return x.Base64
 	}
-	return nil
+	return ""
 }
 
-type AerospikeUDFExecuteRequest struct {
+type AerospikeTruncateRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy       *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	Key          *Key         `protobuf:"bytes,2,opt,name=key,proto3" json:"key,omitempty"`
-	PackageName  string       `protobuf:"bytes,3,opt,name=packageName,proto3" json:"packageName,omitempty"`
-	FunctionName string       `protobuf:"bytes,4,opt,name=functionName,proto3" json:"functionName,omitempty"`
-	Args         []*Value     `protobuf:"bytes,5,rep,name=args,proto3" json:"args,omitempty"`
+	Policy      *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Namespace   string      `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	SetName     string      `protobuf:"bytes,3,opt,name=setName,proto3" json:"setName,omitempty"`
+	BeforeNanos *int64      `protobuf:"varint,4,opt,name=before_nanos,json=beforeNanos,proto3,oneof" json:"before_nanos,omitempty"`
 }
 
-func (x *AerospikeUDFExecuteRequest) Reset() {
-	*x = AerospikeUDFExecuteRequest{}
+func (x *AerospikeTruncateRequest) Reset() {
+	*x = AerospikeTruncateRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[58]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6807,13 +7254,13 @@ func (x *AerospikeUDFExecuteRequest) Reset() {
 	}
 }
 
-func (x *AerospikeUDFExecuteRequest) String() string {
+func (x *AerospikeTruncateRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeUDFExecuteRequest) ProtoMessage() {}
+func (*AerospikeTruncateRequest) ProtoMessage() {}
 
-func (x *AerospikeUDFExecuteRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeTruncateRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[58]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6825,57 +7272,49 @@ func (x *AerospikeUDFExecuteRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeUDFExecuteRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeUDFExecuteRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeTruncateRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeTruncateRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{58}
 }
 
-func (x *AerospikeUDFExecuteRequest) GetPolicy() *WritePolicy {
+func (x *AerospikeTruncateRequest) GetPolicy() *InfoPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeUDFExecuteRequest) GetKey() *Key {
-	if x != nil {
-		return x.Key
-	}
-	return nil
-}
-
-func (x *AerospikeUDFExecuteRequest) GetPackageName() string {
+func (x *AerospikeTruncateRequest) GetNamespace() string {
 	if x != nil {
-		return x.PackageName
+		return x.Namespace
 	}
 	return ""
 }
 
-func (x *AerospikeUDFExecuteRequest) GetFunctionName() string {
+func (x *AerospikeTruncateRequest) GetSetName() string {
 	if x != nil {
-		return x.FunctionName
+		return x.SetName
 	}
 	return ""
 }
 
-func (x *AerospikeUDFExecuteRequest) GetArgs() []*Value {
-	if x != nil {
-		return x.Args
+func (x *AerospikeTruncateRequest) GetBeforeNanos() int64 {
+	if x != nil && x.BeforeNanos != nil {
+		return *x.BeforeNanos
 	}
-	return nil
+	return 0
 }
 
-type AerospikeUDFExecuteResponse struct {
+type AerospikeTruncateResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error  *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-	Result *Value `protobuf:"bytes,2,opt,name=result,proto3" json:"result,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeUDFExecuteResponse) Reset() {
-	*x = AerospikeUDFExecuteResponse{}
+func (x *AerospikeTruncateResponse) Reset() {
+	*x = AerospikeTruncateResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[59]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6883,13 +7322,13 @@ func (x *AerospikeUDFExecuteResponse) Reset() {
 	}
 }
 
-func (x *AerospikeUDFExecuteResponse) String() string {
+func (x *AerospikeTruncateResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeUDFExecuteResponse) ProtoMessage() {}
+func (*AerospikeTruncateResponse) ProtoMessage() {}
 
-func (x *AerospikeUDFExecuteResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeTruncateResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[59]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6901,38 +7340,31 @@ func (x *AerospikeUDFExecuteResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeUDFExecuteResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeUDFExecuteResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeTruncateResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeTruncateResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{59}
 }
 
-func (x *AerospikeUDFExecuteResponse) GetError() *Error {
+func (x *AerospikeTruncateResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeUDFExecuteResponse) GetResult() *Value {
-	if x != nil {
-		return x.Result
-	}
-	return nil
-}
-
-type AerospikeCreateUserRequest struct {
+type AerospikeRegisterUDFRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User     string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
-	Password string       `protobuf:"bytes,3,opt,name=password,proto3" json:"password,omitempty"`
-	Roles    []string     `protobuf:"bytes,4,rep,name=roles,proto3" json:"roles,omitempty"`
+	Policy      *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	PackageName string       `protobuf:"bytes,2,opt,name=packageName,proto3" json:"packageName,omitempty"`
+	UdfBody     []byte       `protobuf:"bytes,3,opt,name=udfBody,proto3" json:"udfBody,omitempty"`
+	Language    UDFLanguage  `protobuf:"varint,4,opt,name=language,proto3,enum=com.aerospike.daemon.UDFLanguage" json:"language,omitempty"`
 }
 
-func (x *AerospikeCreateUserRequest) Reset() {
-	*x = AerospikeCreateUserRequest{}
+func (x *AerospikeRegisterUDFRequest) Reset() {
+	*x = AerospikeRegisterUDFRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[60]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6940,13 +7372,13 @@ func (x *AerospikeCreateUserRequest) Reset() {
 	}
 }
 
-func (x *AerospikeCreateUserRequest) String() string {
+func (x *AerospikeRegisterUDFRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeCreateUserRequest) ProtoMessage() {}
+func (*AerospikeRegisterUDFRequest) ProtoMessage() {}
 
-func (x *AerospikeCreateUserRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRegisterUDFRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[60]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6958,40 +7390,40 @@ func (x *AerospikeCreateUserRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeCreateUserRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeCreateUserRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRegisterUDFRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeRegisterUDFRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{60}
 }
 
-func (x *AerospikeCreateUserRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeRegisterUDFRequest) GetPolicy() *WritePolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeCreateUserRequest) GetUser() string {
+func (x *AerospikeRegisterUDFRequest) GetPackageName() string {
 	if x != nil {
-		return x.User
+		return x.PackageName
 	}
 	return ""
 }
 
-func (x *AerospikeCreateUserRequest) GetPassword() string {
+func (x *AerospikeRegisterUDFRequest) GetUdfBody() []byte {
 	if x != nil {
-		return x.Password
+		return x.UdfBody
 	}
-	return ""
+	return nil
 }
 
-func (x *AerospikeCreateUserRequest) GetRoles() []string {
+func (x *AerospikeRegisterUDFRequest) GetLanguage() UDFLanguage {
 	if x != nil {
-		return x.Roles
+		return x.Language
 	}
-	return nil
+	return UDFLanguage_LUA
 }
 
-type AerospikeCreateUserResponse struct {
+type AerospikeRegisterUDFResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -6999,8 +7431,8 @@ type AerospikeCreateUserResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeCreateUserResponse) Reset() {
-	*x = AerospikeCreateUserResponse{}
+func (x *AerospikeRegisterUDFResponse) Reset() {
+	*x = AerospikeRegisterUDFResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[61]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7008,13 +7440,13 @@ func (x *AerospikeCreateUserResponse) Reset() {
 	}
 }
 
-func (x *AerospikeCreateUserResponse) String() string {
+func (x *AerospikeRegisterUDFResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeCreateUserResponse) ProtoMessage() {}
+func (*AerospikeRegisterUDFResponse) ProtoMessage() {}
 
-func (x *AerospikeCreateUserResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRegisterUDFResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[61]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7026,29 +7458,29 @@ func (x *AerospikeCreateUserResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeCreateUserResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeCreateUserResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRegisterUDFResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeRegisterUDFResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{61}
 }
 
-func (x *AerospikeCreateUserResponse) GetError() *Error {
+func (x *AerospikeRegisterUDFResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeDropUserRequest struct {
+type AerospikeDropUDFRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	Policy      *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	PackageName string       `protobuf:"bytes,2,opt,name=packageName,proto3" json:"packageName,omitempty"`
 }
 
-func (x *AerospikeDropUserRequest) Reset() {
-	*x = AerospikeDropUserRequest{}
+func (x *AerospikeDropUDFRequest) Reset() {
+	*x = AerospikeDropUDFRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[62]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7056,13 +7488,13 @@ func (x *AerospikeDropUserRequest) Reset() {
 	}
 }
 
-func (x *AerospikeDropUserRequest) String() string {
+func (x *AerospikeDropUDFRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeDropUserRequest) ProtoMessage() {}
+func (*AerospikeDropUDFRequest) ProtoMessage() {}
 
-func (x *AerospikeDropUserRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropUDFRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[62]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7074,26 +7506,26 @@ func (x *AerospikeDropUserRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeDropUserRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeDropUserRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropUDFRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeDropUDFRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{62}
 }
 
-func (x *AerospikeDropUserRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeDropUDFRequest) GetPolicy() *WritePolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeDropUserRequest) GetUser() string {
+func (x *AerospikeDropUDFRequest) GetPackageName() string {
 	if x != nil {
-		return x.User
+		return x.PackageName
 	}
 	return ""
 }
 
-type AerospikeDropUserResponse struct {
+type AerospikeDropUDFResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -7101,8 +7533,8 @@ type AerospikeDropUserResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeDropUserResponse) Reset() {
-	*x = AerospikeDropUserResponse{}
+func (x *AerospikeDropUDFResponse) Reset() {
+	*x = AerospikeDropUDFResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[63]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7110,13 +7542,13 @@ func (x *AerospikeDropUserResponse) Reset() {
 	}
 }
 
-func (x *AerospikeDropUserResponse) String() string {
+func (x *AerospikeDropUDFResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeDropUserResponse) ProtoMessage() {}
+func (*AerospikeDropUDFResponse) ProtoMessage() {}
 
-func (x *AerospikeDropUserResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropUDFResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[63]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7128,30 +7560,28 @@ func (x *AerospikeDropUserResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeDropUserResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeDropUserResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropUDFResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeDropUDFResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{63}
 }
 
-func (x *AerospikeDropUserResponse) GetError() *Error {
+func (x *AerospikeDropUDFResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeChangePasswordRequest struct {
+type AerospikeListUDFRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User     string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
-	Password string       `protobuf:"bytes,3,opt,name=password,proto3" json:"password,omitempty"`
+	Policy *ReadPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
 }
 
-func (x *AerospikeChangePasswordRequest) Reset() {
-	*x = AerospikeChangePasswordRequest{}
+func (x *AerospikeListUDFRequest) Reset() {
+	*x = AerospikeListUDFRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[64]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7159,13 +7589,13 @@ func (x *AerospikeChangePasswordRequest) Reset() {
 	}
 }
 
-func (x *AerospikeChangePasswordRequest) String() string {
+func (x *AerospikeListUDFRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeChangePasswordRequest) ProtoMessage() {}
+func (*AerospikeListUDFRequest) ProtoMessage() {}
 
-func (x *AerospikeChangePasswordRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeListUDFRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[64]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7177,42 +7607,29 @@ func (x *AerospikeChangePasswordRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeChangePasswordRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeChangePasswordRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeListUDFRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeListUDFRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{64}
 }
 
-func (x *AerospikeChangePasswordRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeListUDFRequest) GetPolicy() *ReadPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeChangePasswordRequest) GetUser() string {
-	if x != nil {
-		return x.User
-	}
-	return ""
-}
-
-func (x *AerospikeChangePasswordRequest) GetPassword() string {
-	if x != nil {
-		return x.Password
-	}
-	return ""
-}
-
-type AerospikeChangePasswordResponse struct {
+type AerospikeListUDFResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Error   *Error     `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	UdfList []*UDFMeta `protobuf:"bytes,2,rep,name=udfList,proto3" json:"udfList,omitempty"`
 }
 
-func (x *AerospikeChangePasswordResponse) Reset() {
-	*x = AerospikeChangePasswordResponse{}
+func (x *AerospikeListUDFResponse) Reset() {
+	*x = AerospikeListUDFResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[65]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7220,13 +7637,13 @@ func (x *AerospikeChangePasswordResponse) Reset() {
 	}
 }
 
-func (x *AerospikeChangePasswordResponse) String() string {
+func (x *AerospikeListUDFResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeChangePasswordResponse) ProtoMessage() {}
+func (*AerospikeListUDFResponse) ProtoMessage() {}
 
-func (x *AerospikeChangePasswordResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeListUDFResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[65]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7238,30 +7655,39 @@ func (x *AerospikeChangePasswordResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeChangePasswordResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeChangePasswordResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeListUDFResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeListUDFResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{65}
 }
 
-func (x *AerospikeChangePasswordResponse) GetError() *Error {
+func (x *AerospikeListUDFResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeGrantRolesRequest struct {
+func (x *AerospikeListUDFResponse) GetUdfList() []*UDFMeta {
+	if x != nil {
+		return x.UdfList
+	}
+	return nil
+}
+
+type AerospikeUDFExecuteRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
-	Roles  []string     `protobuf:"bytes,3,rep,name=roles,proto3" json:"roles,omitempty"`
+	Policy       *WritePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Key          *Key         `protobuf:"bytes,2,opt,name=key,proto3" json:"key,omitempty"`
+	PackageName  string       `protobuf:"bytes,3,opt,name=packageName,proto3" json:"packageName,omitempty"`
+	FunctionName string       `protobuf:"bytes,4,opt,name=functionName,proto3" json:"functionName,omitempty"`
+	Args         []*Value     `protobuf:"bytes,5,rep,name=args,proto3" json:"args,omitempty"`
 }
 
-func (x *AerospikeGrantRolesRequest) Reset() {
-	*x = AerospikeGrantRolesRequest{}
+func (x *AerospikeUDFExecuteRequest) Reset() {
+	*x = AerospikeUDFExecuteRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[66]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7269,13 +7695,13 @@ func (x *AerospikeGrantRolesRequest) Reset() {
 	}
 }
 
-func (x *AerospikeGrantRolesRequest) String() string {
+func (x *AerospikeUDFExecuteRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeGrantRolesRequest) ProtoMessage() {}
+func (*AerospikeUDFExecuteRequest) ProtoMessage() {}
 
-func (x *AerospikeGrantRolesRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeUDFExecuteRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[66]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7287,42 +7713,57 @@ func (x *AerospikeGrantRolesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeGrantRolesRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeGrantRolesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeUDFExecuteRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeUDFExecuteRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{66}
 }
 
-func (x *AerospikeGrantRolesRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeUDFExecuteRequest) GetPolicy() *WritePolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeGrantRolesRequest) GetUser() string {
+func (x *AerospikeUDFExecuteRequest) GetKey() *Key {
 	if x != nil {
-		return x.User
+		return x.Key
+	}
+	return nil
+}
+
+func (x *AerospikeUDFExecuteRequest) GetPackageName() string {
+	if x != nil {
+		return x.PackageName
 	}
 	return ""
 }
 
-func (x *AerospikeGrantRolesRequest) GetRoles() []string {
+func (x *AerospikeUDFExecuteRequest) GetFunctionName() string {
 	if x != nil {
-		return x.Roles
+		return x.FunctionName
+	}
+	return ""
+}
+
+func (x *AerospikeUDFExecuteRequest) GetArgs() []*Value {
+	if x != nil {
+		return x.Args
 	}
 	return nil
 }
 
-type AerospikeGrantRolesResponse struct {
+type AerospikeUDFExecuteResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Error  *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Result *Value `protobuf:"bytes,2,opt,name=result,proto3" json:"result,omitempty"`
 }
 
-func (x *AerospikeGrantRolesResponse) Reset() {
-	*x = AerospikeGrantRolesResponse{}
+func (x *AerospikeUDFExecuteResponse) Reset() {
+	*x = AerospikeUDFExecuteResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[67]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7330,13 +7771,13 @@ func (x *AerospikeGrantRolesResponse) Reset() {
 	}
 }
 
-func (x *AerospikeGrantRolesResponse) String() string {
+func (x *AerospikeUDFExecuteResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeGrantRolesResponse) ProtoMessage() {}
+func (*AerospikeUDFExecuteResponse) ProtoMessage() {}
 
-func (x *AerospikeGrantRolesResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeUDFExecuteResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[67]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7348,30 +7789,38 @@ func (x *AerospikeGrantRolesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeGrantRolesResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeGrantRolesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeUDFExecuteResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeUDFExecuteResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{67}
 }
 
-func (x *AerospikeGrantRolesResponse) GetError() *Error {
+func (x *AerospikeUDFExecuteResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeRevokeRolesRequest struct {
+func (x *AerospikeUDFExecuteResponse) GetResult() *Value {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+type AerospikeCreateUserRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
-	Roles  []string     `protobuf:"bytes,3,rep,name=roles,proto3" json:"roles,omitempty"`
+	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	User     string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	Password string       `protobuf:"bytes,3,opt,name=password,proto3" json:"password,omitempty"`
+	Roles    []string     `protobuf:"bytes,4,rep,name=roles,proto3" json:"roles,omitempty"`
 }
 
-func (x *AerospikeRevokeRolesRequest) Reset() {
-	*x = AerospikeRevokeRolesRequest{}
+func (x *AerospikeCreateUserRequest) Reset() {
+	*x = AerospikeCreateUserRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[68]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7379,13 +7828,13 @@ func (x *AerospikeRevokeRolesRequest) Reset() {
 	}
 }
 
-func (x *AerospikeRevokeRolesRequest) String() string {
+func (x *AerospikeCreateUserRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRevokeRolesRequest) ProtoMessage() {}
+func (*AerospikeCreateUserRequest) ProtoMessage() {}
 
-func (x *AerospikeRevokeRolesRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeCreateUserRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[68]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7397,33 +7846,40 @@ func (x *AerospikeRevokeRolesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRevokeRolesRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeRevokeRolesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeCreateUserRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeCreateUserRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{68}
 }
 
-func (x *AerospikeRevokeRolesRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeCreateUserRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeRevokeRolesRequest) GetUser() string {
+func (x *AerospikeCreateUserRequest) GetUser() string {
 	if x != nil {
 		return x.User
 	}
 	return ""
 }
 
-func (x *AerospikeRevokeRolesRequest) GetRoles() []string {
+func (x *AerospikeCreateUserRequest) GetPassword() string {
+	if x != nil {
+		return x.Password
+	}
+	return ""
+}
+
+func (x *AerospikeCreateUserRequest) GetRoles() []string {
 	if x != nil {
 		return x.Roles
 	}
 	return nil
 }
 
-type AerospikeRevokeRolesResponse struct {
+type AerospikeCreateUserResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -7431,8 +7887,8 @@ type AerospikeRevokeRolesResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeRevokeRolesResponse) Reset() {
-	*x = AerospikeRevokeRolesResponse{}
+func (x *AerospikeCreateUserResponse) Reset() {
+	*x = AerospikeCreateUserResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[69]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7440,13 +7896,13 @@ func (x *AerospikeRevokeRolesResponse) Reset() {
 	}
 }
 
-func (x *AerospikeRevokeRolesResponse) String() string {
+func (x *AerospikeCreateUserResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRevokeRolesResponse) ProtoMessage() {}
+func (*AerospikeCreateUserResponse) ProtoMessage() {}
 
-func (x *AerospikeRevokeRolesResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeCreateUserResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[69]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7458,29 +7914,29 @@ func (x *AerospikeRevokeRolesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRevokeRolesResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeRevokeRolesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeCreateUserResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeCreateUserResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{69}
 }
 
-func (x *AerospikeRevokeRolesResponse) GetError() *Error {
+func (x *AerospikeCreateUserResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeQueryUsersRequest struct {
+type AerospikeDropUserRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	User   *string      `protobuf:"bytes,2,opt,name=user,proto3,oneof" json:"user,omitempty"`
+	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
 }
 
-func (x *AerospikeQueryUsersRequest) Reset() {
-	*x = AerospikeQueryUsersRequest{}
+func (x *AerospikeDropUserRequest) Reset() {
+	*x = AerospikeDropUserRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[70]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7488,13 +7944,13 @@ func (x *AerospikeQueryUsersRequest) Reset() {
 	}
 }
 
-func (x *AerospikeQueryUsersRequest) String() string {
+func (x *AerospikeDropUserRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeQueryUsersRequest) ProtoMessage() {}
+func (*AerospikeDropUserRequest) ProtoMessage() {}
 
-func (x *AerospikeQueryUsersRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropUserRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[70]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7506,36 +7962,35 @@ func (x *AerospikeQueryUsersRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeQueryUsersRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeQueryUsersRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropUserRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeDropUserRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{70}
 }
 
-func (x *AerospikeQueryUsersRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeDropUserRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeQueryUsersRequest) GetUser() string {
-	if x != nil && x.User != nil {
-		return *x.User
+func (x *AerospikeDropUserRequest) GetUser() string {
+	if x != nil {
+		return x.User
 	}
 	return ""
 }
 
-type AerospikeQueryUsersResponse struct {
+type AerospikeDropUserResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error     *Error      `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-	UserRoles []*UserRole `protobuf:"bytes,2,rep,name=userRoles,proto3" json:"userRoles,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeQueryUsersResponse) Reset() {
-	*x = AerospikeQueryUsersResponse{}
+func (x *AerospikeDropUserResponse) Reset() {
+	*x = AerospikeDropUserResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[71]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7543,13 +7998,13 @@ func (x *AerospikeQueryUsersResponse) Reset() {
 	}
 }
 
-func (x *AerospikeQueryUsersResponse) String() string {
+func (x *AerospikeDropUserResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeQueryUsersResponse) ProtoMessage() {}
+func (*AerospikeDropUserResponse) ProtoMessage() {}
 
-func (x *AerospikeQueryUsersResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropUserResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[71]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7561,36 +8016,30 @@ func (x *AerospikeQueryUsersResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeQueryUsersResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeQueryUsersResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropUserResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeDropUserResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{71}
 }
 
-func (x *AerospikeQueryUsersResponse) GetError() *Error {
+func (x *AerospikeDropUserResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeQueryUsersResponse) GetUserRoles() []*UserRole {
-	if x != nil {
-		return x.UserRoles
-	}
-	return nil
-}
-
-type AerospikeQueryRolesRequest struct {
+type AerospikeChangePasswordRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName *string      `protobuf:"bytes,2,opt,name=roleName,proto3,oneof" json:"roleName,omitempty"`
+	User     string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	Password string       `protobuf:"bytes,3,opt,name=password,proto3" json:"password,omitempty"`
 }
 
-func (x *AerospikeQueryRolesRequest) Reset() {
-	*x = AerospikeQueryRolesRequest{}
+func (x *AerospikeChangePasswordRequest) Reset() {
+	*x = AerospikeChangePasswordRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[72]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7598,13 +8047,13 @@ func (x *AerospikeQueryRolesRequest) Reset() {
 	}
 }
 
-func (x *AerospikeQueryRolesRequest) String() string {
+func (x *AerospikeChangePasswordRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeQueryRolesRequest) ProtoMessage() {}
+func (*AerospikeChangePasswordRequest) ProtoMessage() {}
 
-func (x *AerospikeQueryRolesRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeChangePasswordRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[72]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7616,36 +8065,42 @@ func (x *AerospikeQueryRolesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeQueryRolesRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeQueryRolesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeChangePasswordRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeChangePasswordRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{72}
 }
 
-func (x *AerospikeQueryRolesRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeChangePasswordRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeQueryRolesRequest) GetRoleName() string {
-	if x != nil && x.RoleName != nil {
-		return *x.RoleName
+func (x *AerospikeChangePasswordRequest) GetUser() string {
+	if x != nil {
+		return x.User
 	}
 	return ""
 }
 
-type AerospikeQueryRolesResponse struct {
+func (x *AerospikeChangePasswordRequest) GetPassword() string {
+	if x != nil {
+		return x.Password
+	}
+	return ""
+}
+
+type AerospikeChangePasswordResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error  `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-	Roles []*Role `protobuf:"bytes,2,rep,name=roles,proto3" json:"roles,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeQueryRolesResponse) Reset() {
-	*x = AerospikeQueryRolesResponse{}
+func (x *AerospikeChangePasswordResponse) Reset() {
+	*x = AerospikeChangePasswordResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[73]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7653,13 +8108,13 @@ func (x *AerospikeQueryRolesResponse) Reset() {
 	}
 }
 
-func (x *AerospikeQueryRolesResponse) String() string {
+func (x *AerospikeChangePasswordResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeQueryRolesResponse) ProtoMessage() {}
+func (*AerospikeChangePasswordResponse) ProtoMessage() {}
 
-func (x *AerospikeQueryRolesResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeChangePasswordResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[73]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7671,40 +8126,30 @@ func (x *AerospikeQueryRolesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeQueryRolesResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeQueryRolesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeChangePasswordResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeChangePasswordResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{73}
 }
 
-func (x *AerospikeQueryRolesResponse) GetError() *Error {
+func (x *AerospikeChangePasswordResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeQueryRolesResponse) GetRoles() []*Role {
-	if x != nil {
-		return x.Roles
-	}
-	return nil
-}
-
-type AerospikeCreateRoleRequest struct {
+type AerospikeGrantRolesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
-	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
-	Allowlist  []string     `protobuf:"bytes,4,rep,name=allowlist,proto3" json:"allowlist,omitempty"`
-	ReadQuota  uint32       `protobuf:"varint,5,opt,name=readQuota,proto3" json:"readQuota,omitempty"`
-	WriteQuota uint32       `protobuf:"varint,6,opt,name=writeQuota,proto3" json:"writeQuota,omitempty"`
+	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	Roles  []string     `protobuf:"bytes,3,rep,name=roles,proto3" json:"roles,omitempty"`
 }
 
-func (x *AerospikeCreateRoleRequest) Reset() {
-	*x = AerospikeCreateRoleRequest{}
+func (x *AerospikeGrantRolesRequest) Reset() {
+	*x = AerospikeGrantRolesRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[74]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7712,13 +8157,13 @@ func (x *AerospikeCreateRoleRequest) Reset() {
 	}
 }
 
-func (x *AerospikeCreateRoleRequest) String() string {
+func (x *AerospikeGrantRolesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeCreateRoleRequest) ProtoMessage() {}
+func (*AerospikeGrantRolesRequest) ProtoMessage() {}
 
-func (x *AerospikeCreateRoleRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeGrantRolesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[74]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7730,54 +8175,33 @@ func (x *AerospikeCreateRoleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeCreateRoleRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeCreateRoleRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeGrantRolesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeGrantRolesRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{74}
 }
 
-func (x *AerospikeCreateRoleRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeGrantRolesRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeCreateRoleRequest) GetRoleName() string {
+func (x *AerospikeGrantRolesRequest) GetUser() string {
 	if x != nil {
-		return x.RoleName
+		return x.User
 	}
 	return ""
 }
 
-func (x *AerospikeCreateRoleRequest) GetPrivileges() []*Privilege {
-	if x != nil {
-		return x.Privileges
-	}
-	return nil
-}
-
-func (x *AerospikeCreateRoleRequest) GetAllowlist() []string {
+func (x *AerospikeGrantRolesRequest) GetRoles() []string {
 	if x != nil {
-		return x.Allowlist
+		return x.Roles
 	}
 	return nil
 }
 
-func (x *AerospikeCreateRoleRequest) GetReadQuota() uint32 {
-	if x != nil {
-		return x.ReadQuota
-	}
-	return 0
-}
-
-func (x *AerospikeCreateRoleRequest) GetWriteQuota() uint32 {
-	if x != nil {
-		return x.WriteQuota
-	}
-	return 0
-}
-
-type AerospikeCreateRoleResponse struct {
+type AerospikeGrantRolesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -7785,8 +8209,8 @@ type AerospikeCreateRoleResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeCreateRoleResponse) Reset() {
-	*x = AerospikeCreateRoleResponse{}
+func (x *AerospikeGrantRolesResponse) Reset() {
+	*x = AerospikeGrantRolesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[75]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7794,13 +8218,13 @@ func (x *AerospikeCreateRoleResponse) Reset() {
 	}
 }
 
-func (x *AerospikeCreateRoleResponse) String() string {
+func (x *AerospikeGrantRolesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeCreateRoleResponse) ProtoMessage() {}
+func (*AerospikeGrantRolesResponse) ProtoMessage() {}
 
-func (x *AerospikeCreateRoleResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeGrantRolesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[75]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7812,29 +8236,30 @@ func (x *AerospikeCreateRoleResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeCreateRoleResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeCreateRoleResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeGrantRolesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeGrantRolesResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{75}
 }
 
-func (x *AerospikeCreateRoleResponse) GetError() *Error {
+func (x *AerospikeGrantRolesResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeDropRoleRequest struct {
+type AerospikeRevokeRolesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	User   string       `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	Roles  []string     `protobuf:"bytes,3,rep,name=roles,proto3" json:"roles,omitempty"`
 }
 
-func (x *AerospikeDropRoleRequest) Reset() {
-	*x = AerospikeDropRoleRequest{}
+func (x *AerospikeRevokeRolesRequest) Reset() {
+	*x = AerospikeRevokeRolesRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[76]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7842,13 +8267,13 @@ func (x *AerospikeDropRoleRequest) Reset() {
 	}
 }
 
-func (x *AerospikeDropRoleRequest) String() string {
+func (x *AerospikeRevokeRolesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeDropRoleRequest) ProtoMessage() {}
+func (*AerospikeRevokeRolesRequest) ProtoMessage() {}
 
-func (x *AerospikeDropRoleRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRevokeRolesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[76]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7860,35 +8285,42 @@ func (x *AerospikeDropRoleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeDropRoleRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeDropRoleRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRevokeRolesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeRevokeRolesRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{76}
 }
 
-func (x *AerospikeDropRoleRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeRevokeRolesRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeDropRoleRequest) GetRoleName() string {
+func (x *AerospikeRevokeRolesRequest) GetUser() string {
 	if x != nil {
-		return x.RoleName
+		return x.User
 	}
 	return ""
 }
 
-type AerospikeDropRoleResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
-}
-
-func (x *AerospikeDropRoleResponse) Reset() {
-	*x = AerospikeDropRoleResponse{}
+func (x *AerospikeRevokeRolesRequest) GetRoles() []string {
+	if x != nil {
+		return x.Roles
+	}
+	return nil
+}
+
+type AerospikeRevokeRolesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *AerospikeRevokeRolesResponse) Reset() {
+	*x = AerospikeRevokeRolesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[77]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7896,13 +8328,13 @@ func (x *AerospikeDropRoleResponse) Reset() {
 	}
 }
 
-func (x *AerospikeDropRoleResponse) String() string {
+func (x *AerospikeRevokeRolesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeDropRoleResponse) ProtoMessage() {}
+func (*AerospikeRevokeRolesResponse) ProtoMessage() {}
 
-func (x *AerospikeDropRoleResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRevokeRolesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[77]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7914,30 +8346,29 @@ func (x *AerospikeDropRoleResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeDropRoleResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeDropRoleResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRevokeRolesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeRevokeRolesResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{77}
 }
 
-func (x *AerospikeDropRoleResponse) GetError() *Error {
+func (x *AerospikeRevokeRolesResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeGrantPrivilegesRequest struct {
+type AerospikeQueryUsersRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
-	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
+	Policy *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	User   *string      `protobuf:"bytes,2,opt,name=user,proto3,oneof" json:"user,omitempty"`
 }
 
-func (x *AerospikeGrantPrivilegesRequest) Reset() {
-	*x = AerospikeGrantPrivilegesRequest{}
+func (x *AerospikeQueryUsersRequest) Reset() {
+	*x = AerospikeQueryUsersRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[78]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7945,13 +8376,13 @@ func (x *AerospikeGrantPrivilegesRequest) Reset() {
 	}
 }
 
-func (x *AerospikeGrantPrivilegesRequest) String() string {
+func (x *AerospikeQueryUsersRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeGrantPrivilegesRequest) ProtoMessage() {}
+func (*AerospikeQueryUsersRequest) ProtoMessage() {}
 
-func (x *AerospikeGrantPrivilegesRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryUsersRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[78]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -7963,42 +8394,36 @@ func (x *AerospikeGrantPrivilegesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeGrantPrivilegesRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeGrantPrivilegesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryUsersRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryUsersRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{78}
 }
 
-func (x *AerospikeGrantPrivilegesRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeQueryUsersRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeGrantPrivilegesRequest) GetRoleName() string {
-	if x != nil {
-		return x.RoleName
+func (x *AerospikeQueryUsersRequest) GetUser() string {
+	if x != nil && x.User != nil {
+		return *x.User
 	}
 	return ""
 }
 
-func (x *AerospikeGrantPrivilegesRequest) GetPrivileges() []*Privilege {
-	if x != nil {
-		return x.Privileges
-	}
-	return nil
-}
-
-type AerospikeGrantPrivilegesResponse struct {
+type AerospikeQueryUsersResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Error     *Error      `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	UserRoles []*UserRole `protobuf:"bytes,2,rep,name=userRoles,proto3" json:"userRoles,omitempty"`
 }
 
-func (x *AerospikeGrantPrivilegesResponse) Reset() {
-	*x = AerospikeGrantPrivilegesResponse{}
+func (x *AerospikeQueryUsersResponse) Reset() {
+	*x = AerospikeQueryUsersResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[79]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8006,13 +8431,13 @@ func (x *AerospikeGrantPrivilegesResponse) Reset() {
 	}
 }
 
-func (x *AerospikeGrantPrivilegesResponse) String() string {
+func (x *AerospikeQueryUsersResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeGrantPrivilegesResponse) ProtoMessage() {}
+func (*AerospikeQueryUsersResponse) ProtoMessage() {}
 
-func (x *AerospikeGrantPrivilegesResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryUsersResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[79]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8024,30 +8449,36 @@ func (x *AerospikeGrantPrivilegesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeGrantPrivilegesResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeGrantPrivilegesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryUsersResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryUsersResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{79}
 }
 
-func (x *AerospikeGrantPrivilegesResponse) GetError() *Error {
+func (x *AerospikeQueryUsersResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeRevokePrivilegesRequest struct {
+func (x *AerospikeQueryUsersResponse) GetUserRoles() []*UserRole {
+	if x != nil {
+		return x.UserRoles
+	}
+	return nil
+}
+
+type AerospikeQueryRolesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
-	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
+	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName *string      `protobuf:"bytes,2,opt,name=roleName,proto3,oneof" json:"roleName,omitempty"`
 }
 
-func (x *AerospikeRevokePrivilegesRequest) Reset() {
-	*x = AerospikeRevokePrivilegesRequest{}
+func (x *AerospikeQueryRolesRequest) Reset() {
+	*x = AerospikeQueryRolesRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[80]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8055,13 +8486,13 @@ func (x *AerospikeRevokePrivilegesRequest) Reset() {
 	}
 }
 
-func (x *AerospikeRevokePrivilegesRequest) String() string {
+func (x *AerospikeQueryRolesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRevokePrivilegesRequest) ProtoMessage() {}
+func (*AerospikeQueryRolesRequest) ProtoMessage() {}
 
-func (x *AerospikeRevokePrivilegesRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryRolesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[80]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8073,42 +8504,36 @@ func (x *AerospikeRevokePrivilegesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRevokePrivilegesRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeRevokePrivilegesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryRolesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryRolesRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{80}
 }
 
-func (x *AerospikeRevokePrivilegesRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeQueryRolesRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeRevokePrivilegesRequest) GetRoleName() string {
-	if x != nil {
-		return x.RoleName
+func (x *AerospikeQueryRolesRequest) GetRoleName() string {
+	if x != nil && x.RoleName != nil {
+		return *x.RoleName
 	}
 	return ""
 }
 
-func (x *AerospikeRevokePrivilegesRequest) GetPrivileges() []*Privilege {
-	if x != nil {
-		return x.Privileges
-	}
-	return nil
-}
-
-type AerospikeRevokePrivilegesResponse struct {
+type AerospikeQueryRolesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Error *Error  `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Roles []*Role `protobuf:"bytes,2,rep,name=roles,proto3" json:"roles,omitempty"`
 }
 
-func (x *AerospikeRevokePrivilegesResponse) Reset() {
-	*x = AerospikeRevokePrivilegesResponse{}
+func (x *AerospikeQueryRolesResponse) Reset() {
+	*x = AerospikeQueryRolesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[81]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8116,13 +8541,13 @@ func (x *AerospikeRevokePrivilegesResponse) Reset() {
 	}
 }
 
-func (x *AerospikeRevokePrivilegesResponse) String() string {
+func (x *AerospikeQueryRolesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeRevokePrivilegesResponse) ProtoMessage() {}
+func (*AerospikeQueryRolesResponse) ProtoMessage() {}
 
-func (x *AerospikeRevokePrivilegesResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryRolesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[81]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8134,30 +8559,40 @@ func (x *AerospikeRevokePrivilegesResponse) ProtoReflect() protoreflect.Message
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeRevokePrivilegesResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeRevokePrivilegesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryRolesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryRolesResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{81}
 }
 
-func (x *AerospikeRevokePrivilegesResponse) GetError() *Error {
+func (x *AerospikeQueryRolesResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeSetAllowlistRequest struct {
+func (x *AerospikeQueryRolesResponse) GetRoles() []*Role {
+	if x != nil {
+		return x.Roles
+	}
+	return nil
+}
+
+type AerospikeCreateRoleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy    *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName  string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
-	Allowlist []string     `protobuf:"bytes,3,rep,name=allowlist,proto3" json:"allowlist,omitempty"`
+	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
+	Allowlist  []string     `protobuf:"bytes,4,rep,name=allowlist,proto3" json:"allowlist,omitempty"`
+	ReadQuota  uint32       `protobuf:"varint,5,opt,name=readQuota,proto3" json:"readQuota,omitempty"`
+	WriteQuota uint32       `protobuf:"varint,6,opt,name=writeQuota,proto3" json:"writeQuota,omitempty"`
 }
 
-func (x *AerospikeSetAllowlistRequest) Reset() {
-	*x = AerospikeSetAllowlistRequest{}
+func (x *AerospikeCreateRoleRequest) Reset() {
+	*x = AerospikeCreateRoleRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[82]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8165,13 +8600,13 @@ func (x *AerospikeSetAllowlistRequest) Reset() {
 	}
 }
 
-func (x *AerospikeSetAllowlistRequest) String() string {
+func (x *AerospikeCreateRoleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeSetAllowlistRequest) ProtoMessage() {}
+func (*AerospikeCreateRoleRequest) ProtoMessage() {}
 
-func (x *AerospikeSetAllowlistRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeCreateRoleRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[82]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8183,33 +8618,54 @@ func (x *AerospikeSetAllowlistRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeSetAllowlistRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeSetAllowlistRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeCreateRoleRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeCreateRoleRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{82}
 }
 
-func (x *AerospikeSetAllowlistRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeCreateRoleRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeSetAllowlistRequest) GetRoleName() string {
+func (x *AerospikeCreateRoleRequest) GetRoleName() string {
 	if x != nil {
 		return x.RoleName
 	}
 	return ""
 }
 
-func (x *AerospikeSetAllowlistRequest) GetAllowlist() []string {
+func (x *AerospikeCreateRoleRequest) GetPrivileges() []*Privilege {
+	if x != nil {
+		return x.Privileges
+	}
+	return nil
+}
+
+func (x *AerospikeCreateRoleRequest) GetAllowlist() []string {
 	if x != nil {
 		return x.Allowlist
 	}
 	return nil
 }
 
-type AerospikeSetAllowlistResponse struct {
+func (x *AerospikeCreateRoleRequest) GetReadQuota() uint32 {
+	if x != nil {
+		return x.ReadQuota
+	}
+	return 0
+}
+
+func (x *AerospikeCreateRoleRequest) GetWriteQuota() uint32 {
+	if x != nil {
+		return x.WriteQuota
+	}
+	return 0
+}
+
+type AerospikeCreateRoleResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -8217,8 +8673,8 @@ type AerospikeSetAllowlistResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeSetAllowlistResponse) Reset() {
-	*x = AerospikeSetAllowlistResponse{}
+func (x *AerospikeCreateRoleResponse) Reset() {
+	*x = AerospikeCreateRoleResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[83]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8226,13 +8682,13 @@ func (x *AerospikeSetAllowlistResponse) Reset() {
 	}
 }
 
-func (x *AerospikeSetAllowlistResponse) String() string {
+func (x *AerospikeCreateRoleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeSetAllowlistResponse) ProtoMessage() {}
+func (*AerospikeCreateRoleResponse) ProtoMessage() {}
 
-func (x *AerospikeSetAllowlistResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeCreateRoleResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[83]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8244,31 +8700,29 @@ func (x *AerospikeSetAllowlistResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeSetAllowlistResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeSetAllowlistResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeCreateRoleResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeCreateRoleResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{83}
 }
 
-func (x *AerospikeSetAllowlistResponse) GetError() *Error {
+func (x *AerospikeCreateRoleResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeSetQuotasRequest struct {
+type AerospikeDropRoleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
-	ReadQuota  uint32       `protobuf:"varint,3,opt,name=readQuota,proto3" json:"readQuota,omitempty"`
-	WriteQuota uint32       `protobuf:"varint,4,opt,name=writeQuota,proto3" json:"writeQuota,omitempty"`
+	Policy   *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
 }
 
-func (x *AerospikeSetQuotasRequest) Reset() {
-	*x = AerospikeSetQuotasRequest{}
+func (x *AerospikeDropRoleRequest) Reset() {
+	*x = AerospikeDropRoleRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[84]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8276,13 +8730,13 @@ func (x *AerospikeSetQuotasRequest) Reset() {
 	}
 }
 
-func (x *AerospikeSetQuotasRequest) String() string {
+func (x *AerospikeDropRoleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeSetQuotasRequest) ProtoMessage() {}
+func (*AerospikeDropRoleRequest) ProtoMessage() {}
 
-func (x *AerospikeSetQuotasRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropRoleRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[84]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8294,40 +8748,26 @@ func (x *AerospikeSetQuotasRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeSetQuotasRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeSetQuotasRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropRoleRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeDropRoleRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{84}
 }
 
-func (x *AerospikeSetQuotasRequest) GetPolicy() *AdminPolicy {
+func (x *AerospikeDropRoleRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeSetQuotasRequest) GetRoleName() string {
+func (x *AerospikeDropRoleRequest) GetRoleName() string {
 	if x != nil {
 		return x.RoleName
 	}
 	return ""
 }
 
-func (x *AerospikeSetQuotasRequest) GetReadQuota() uint32 {
-	if x != nil {
-		return x.ReadQuota
-	}
-	return 0
-}
-
-func (x *AerospikeSetQuotasRequest) GetWriteQuota() uint32 {
-	if x != nil {
-		return x.WriteQuota
-	}
-	return 0
-}
-
-type AerospikeSetQuotasResponse struct {
+type AerospikeDropRoleResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -8335,8 +8775,8 @@ type AerospikeSetQuotasResponse struct {
 	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeSetQuotasResponse) Reset() {
-	*x = AerospikeSetQuotasResponse{}
+func (x *AerospikeDropRoleResponse) Reset() {
+	*x = AerospikeDropRoleResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[85]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8344,13 +8784,13 @@ func (x *AerospikeSetQuotasResponse) Reset() {
 	}
 }
 
-func (x *AerospikeSetQuotasResponse) String() string {
+func (x *AerospikeDropRoleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeSetQuotasResponse) ProtoMessage() {}
+func (*AerospikeDropRoleResponse) ProtoMessage() {}
 
-func (x *AerospikeSetQuotasResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeDropRoleResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[85]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8362,32 +8802,30 @@ func (x *AerospikeSetQuotasResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeSetQuotasResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeSetQuotasResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeDropRoleResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeDropRoleResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{85}
 }
 
-func (x *AerospikeSetQuotasResponse) GetError() *Error {
+func (x *AerospikeDropRoleResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-type AerospikeScanRequest struct {
+type AerospikeGrantPrivilegesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy          *ScanPolicy      `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	Namespace       string           `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
-	SetName         string           `protobuf:"bytes,3,opt,name=setName,proto3" json:"setName,omitempty"`
-	BinNames        []string         `protobuf:"bytes,4,rep,name=binNames,proto3" json:"binNames,omitempty"`
-	PartitionFilter *PartitionFilter `protobuf:"bytes,5,opt,name=partitionFilter,proto3,oneof" json:"partitionFilter,omitempty"`
+	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
 }
 
-func (x *AerospikeScanRequest) Reset() {
-	*x = AerospikeScanRequest{}
+func (x *AerospikeGrantPrivilegesRequest) Reset() {
+	*x = AerospikeGrantPrivilegesRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[86]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8395,13 +8833,13 @@ func (x *AerospikeScanRequest) Reset() {
 	}
 }
 
-func (x *AerospikeScanRequest) String() string {
+func (x *AerospikeGrantPrivilegesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeScanRequest) ProtoMessage() {}
+func (*AerospikeGrantPrivilegesRequest) ProtoMessage() {}
 
-func (x *AerospikeScanRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeGrantPrivilegesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[86]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8413,58 +8851,42 @@ func (x *AerospikeScanRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeScanRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeScanRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeGrantPrivilegesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeGrantPrivilegesRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{86}
 }
 
-func (x *AerospikeScanRequest) GetPolicy() *ScanPolicy {
+func (x *AerospikeGrantPrivilegesRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeScanRequest) GetNamespace() string {
-	if x != nil {
-		return x.Namespace
-	}
-	return ""
-}
-
-func (x *AerospikeScanRequest) GetSetName() string {
+func (x *AerospikeGrantPrivilegesRequest) GetRoleName() string {
 	if x != nil {
-		return x.SetName
+		return x.RoleName
 	}
 	return ""
 }
 
-func (x *AerospikeScanRequest) GetBinNames() []string {
-	if x != nil {
-		return x.BinNames
-	}
-	return nil
-}
-
-func (x *AerospikeScanRequest) GetPartitionFilter() *PartitionFilter {
+func (x *AerospikeGrantPrivilegesRequest) GetPrivileges() []*Privilege {
 	if x != nil {
-		return x.PartitionFilter
+		return x.Privileges
 	}
 	return nil
 }
 
-type AerospikeStreamResponse struct {
+type AerospikeGrantPrivilegesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Record *Record `protobuf:"bytes,1,opt,name=record,proto3" json:"record,omitempty"`
-	Error  *Error  `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
-	Bval   *int64  `protobuf:"varint,3,opt,name=bval,proto3,oneof" json:"bval,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeStreamResponse) Reset() {
-	*x = AerospikeStreamResponse{}
+func (x *AerospikeGrantPrivilegesResponse) Reset() {
+	*x = AerospikeGrantPrivilegesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[87]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8472,13 +8894,13 @@ func (x *AerospikeStreamResponse) Reset() {
 	}
 }
 
-func (x *AerospikeStreamResponse) String() string {
+func (x *AerospikeGrantPrivilegesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeStreamResponse) ProtoMessage() {}
+func (*AerospikeGrantPrivilegesResponse) ProtoMessage() {}
 
-func (x *AerospikeStreamResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeGrantPrivilegesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[87]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8490,44 +8912,30 @@ func (x *AerospikeStreamResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeStreamResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeStreamResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeGrantPrivilegesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeGrantPrivilegesResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{87}
 }
 
-func (x *AerospikeStreamResponse) GetRecord() *Record {
-	if x != nil {
-		return x.Record
-	}
-	return nil
-}
-
-func (x *AerospikeStreamResponse) GetError() *Error {
+func (x *AerospikeGrantPrivilegesResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-func (x *AerospikeStreamResponse) GetBval() int64 {
-	if x != nil && x.Bval != nil {
-		return *x.Bval
-	}
-	return 0
-}
-
-type AerospikeQueryRequest struct {
+type AerospikeRevokePrivilegesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Policy          *QueryPolicy     `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
-	Statement       *Statement       `protobuf:"bytes,2,opt,name=statement,proto3" json:"statement,omitempty"`
-	PartitionFilter *PartitionFilter `protobuf:"bytes,5,opt,name=partitionFilter,proto3,oneof" json:"partitionFilter,omitempty"`
+	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	Privileges []*Privilege `protobuf:"bytes,3,rep,name=privileges,proto3" json:"privileges,omitempty"`
 }
 
-func (x *AerospikeQueryRequest) Reset() {
-	*x = AerospikeQueryRequest{}
+func (x *AerospikeRevokePrivilegesRequest) Reset() {
+	*x = AerospikeRevokePrivilegesRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[88]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8535,13 +8943,13 @@ func (x *AerospikeQueryRequest) Reset() {
 	}
 }
 
-func (x *AerospikeQueryRequest) String() string {
+func (x *AerospikeRevokePrivilegesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeQueryRequest) ProtoMessage() {}
+func (*AerospikeRevokePrivilegesRequest) ProtoMessage() {}
 
-func (x *AerospikeQueryRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRevokePrivilegesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[88]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8553,40 +8961,42 @@ func (x *AerospikeQueryRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeQueryRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeQueryRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRevokePrivilegesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeRevokePrivilegesRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{88}
 }
 
-func (x *AerospikeQueryRequest) GetPolicy() *QueryPolicy {
+func (x *AerospikeRevokePrivilegesRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *AerospikeQueryRequest) GetStatement() *Statement {
+func (x *AerospikeRevokePrivilegesRequest) GetRoleName() string {
 	if x != nil {
-		return x.Statement
+		return x.RoleName
 	}
-	return nil
+	return ""
 }
 
-func (x *AerospikeQueryRequest) GetPartitionFilter() *PartitionFilter {
+func (x *AerospikeRevokePrivilegesRequest) GetPrivileges() []*Privilege {
 	if x != nil {
-		return x.PartitionFilter
+		return x.Privileges
 	}
 	return nil
 }
 
-type AerospikeVersionRequest struct {
+type AerospikeRevokePrivilegesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AerospikeVersionRequest) Reset() {
-	*x = AerospikeVersionRequest{}
+func (x *AerospikeRevokePrivilegesResponse) Reset() {
+	*x = AerospikeRevokePrivilegesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[89]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8594,13 +9004,13 @@ func (x *AerospikeVersionRequest) Reset() {
 	}
 }
 
-func (x *AerospikeVersionRequest) String() string {
+func (x *AerospikeRevokePrivilegesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeVersionRequest) ProtoMessage() {}
+func (*AerospikeRevokePrivilegesResponse) ProtoMessage() {}
 
-func (x *AerospikeVersionRequest) ProtoReflect() protoreflect.Message {
+func (x *AerospikeRevokePrivilegesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[89]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8612,21 +9022,30 @@ func (x *AerospikeVersionRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeVersionRequest.ProtoReflect.Descriptor instead.
-func (*AerospikeVersionRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeRevokePrivilegesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeRevokePrivilegesResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{89}
 }
 
-type AerospikeVersionResponse struct {
+func (x *AerospikeRevokePrivilegesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+type AerospikeSetAllowlistRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Version string `protobuf:"bytes,1,opt,name=version,proto3" json:"version,omitempty"`
+	Policy    *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName  string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	Allowlist []string     `protobuf:"bytes,3,rep,name=allowlist,proto3" json:"allowlist,omitempty"`
 }
 
-func (x *AerospikeVersionResponse) Reset() {
-	*x = AerospikeVersionResponse{}
+func (x *AerospikeSetAllowlistRequest) Reset() {
+	*x = AerospikeSetAllowlistRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[90]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8634,13 +9053,13 @@ func (x *AerospikeVersionResponse) Reset() {
 	}
 }
 
-func (x *AerospikeVersionResponse) String() string {
+func (x *AerospikeSetAllowlistRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AerospikeVersionResponse) ProtoMessage() {}
+func (*AerospikeSetAllowlistRequest) ProtoMessage() {}
 
-func (x *AerospikeVersionResponse) ProtoReflect() protoreflect.Message {
+func (x *AerospikeSetAllowlistRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[90]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8652,115 +9071,42 @@ func (x *AerospikeVersionResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AerospikeVersionResponse.ProtoReflect.Descriptor instead.
-func (*AerospikeVersionResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeSetAllowlistRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeSetAllowlistRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{90}
 }
 
-func (x *AerospikeVersionResponse) GetVersion() string {
+func (x *AerospikeSetAllowlistRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
-		return x.Version
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *AerospikeSetAllowlistRequest) GetRoleName() string {
+	if x != nil {
+		return x.RoleName
 	}
 	return ""
 }
 
-type BackgroundExecutePolicy struct {
+func (x *AerospikeSetAllowlistRequest) GetAllowlist() []string {
+	if x != nil {
+		return x.Allowlist
+	}
+	return nil
+}
+
+type AerospikeSetAllowlistResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Read policy for AP (availability) namespaces.
-	Replica ReplicaPolicy `protobuf:"varint,1,opt,name=replica,proto3,enum=com.aerospike.daemon.ReplicaPolicy" json:"replica,omitempty"`
-	// Read policy for SC (strong consistency) namespaces.
-	ReadModeAP ReadModeAP `protobuf:"varint,2,opt,name=readModeAP,proto3,enum=com.aerospike.daemon.ReadModeAP" json:"readModeAP,omitempty"`
-	// Replica algorithm used to determine the target node
-	// for a single record command.
-	// Scan and scan are not affected by replica algorithms.
-	ReadModeSC ReadModeSC `protobuf:"varint,3,opt,name=readModeSC,proto3,enum=com.aerospike.daemon.ReadModeSC" json:"readModeSC,omitempty"`
-	// Use zlib compression on command buffers sent to the server and responses received
-	// from the server when the buffer size is greater than 128 bytes.
-	// This option will increase cpu and memory usage (for extra compressed buffers),but
-	// decrease the size of data sent over the network.
-	Compress bool `protobuf:"varint,4,opt,name=compress,proto3" json:"compress,omitempty"`
-	// Optional expression filter. If filterExp exists and evaluates to false, the
-	// transaction is ignored.
-	Expression []byte `protobuf:"bytes,5,opt,name=expression,proto3,oneof" json:"expression,omitempty"`
-	// Total transaction timeout in milliseconds.
-	// Default for all other commands: 1000ms
-	TotalTimeout *uint32 `protobuf:"varint,6,opt,name=totalTimeout,proto3,oneof" json:"totalTimeout,omitempty"`
-	// Send user defined key in addition to hash digest on both reads and writes.
-	// If the key is sent on a write, the key will be stored with the record on
-	// the server.
-	// <p>
-	// Default: false (do not send the user defined key)
-	SendKey *bool `protobuf:"varint,7,opt,name=sendKey,proto3,oneof" json:"sendKey,omitempty"`
-	// Qualify how to handle writes where the record already exists.
-	//
-	// Default: RecordExistsAction.UPDATE
-	RecordExistsAction *RecordExistsAction `protobuf:"varint,8,opt,name=recordExistsAction,proto3,enum=com.aerospike.daemon.RecordExistsAction,oneof" json:"recordExistsAction,omitempty"`
-	// Qualify how to handle record writes based on record generation. The default (NONE)
-	// indicates that the generation is not used to restrict writes.
-	//
-	// The server does not support this field for UDF execute() calls. The read-modify-write
-	// usage model can still be enforced inside the UDF code itself.
-	//
-	// Default: GenerationPolicy.NONE
-	GenerationPolicy *GenerationPolicy `protobuf:"varint,9,opt,name=generationPolicy,proto3,enum=com.aerospike.daemon.GenerationPolicy,oneof" json:"generationPolicy,omitempty"`
-	// Desired consistency guarantee when committing a transaction on the server. The default
-	// (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
-	// be successful before returning success to the client.
-	//
-	// Default: CommitLevel.COMMIT_ALL
-	CommitLevel *CommitLevel `protobuf:"varint,10,opt,name=commitLevel,proto3,enum=com.aerospike.daemon.CommitLevel,oneof" json:"commitLevel,omitempty"`
-	// Expected generation. Generation is the number of times a record has been modified
-	// (including creation) on the server. If a write operation is creating a record,
-	// the expected generation would be <code>0</code>. This field is only relevant when
-	// generationPolicy is not NONE.
-	//
-	// The server does not support this field for UDF execute() calls. The read-modify-write
-	// usage model can still be enforced inside the UDF code itself.
-	//
-	// Default: 0
-	Generation *uint32 `protobuf:"varint,11,opt,name=generation,proto3,oneof" json:"generation,omitempty"`
-	// Record expiration. Also known as ttl (time to live).
-	// Seconds record will live before being removed by the server.
-	//
-	// Expiration values:
-	//
-	//   - -2: Do not change ttl when record is updated.
-	//   - -1: Never expire.
-	//   - 0: Default to namespace configuration variable "default-ttl" on the server.
-	//   - &gt; 0: Actual ttl in seconds.<br>
-	//
-	// Default: 0
-	Expiration *uint32 `protobuf:"varint,12,opt,name=expiration,proto3,oneof" json:"expiration,omitempty"`
-	// For client operate(), return a result for every operation.
-	//
-	// Some operations do not return results by default (ListOperation.clear() for example).
-	// This can make it difficult to determine the desired result offset in the returned
-	// bin's result list.
-	//
-	// Setting respondAllOps to true makes it easier to identify the desired result offset
-	// (result offset equals bin's operate sequence).  If there is a map operation in operate(),
-	// respondAllOps will be forced to true for that operate() call.
-	//
-	// Default: false
-	RespondAllOps *bool `protobuf:"varint,13,opt,name=respondAllOps,proto3,oneof" json:"respondAllOps,omitempty"`
-	// If the transaction results in a record deletion, leave a tombstone for the record.
-	// This prevents deleted records from reappearing after node failures.
-	// Valid for Aerospike Server Enterprise Edition 3.10+ only.
-	//
-	// Default: false (do not tombstone deleted records).
-	DurableDelete *bool `protobuf:"varint,14,opt,name=durableDelete,proto3,oneof" json:"durableDelete,omitempty"`
-	// Operate in XDR mode.  Some external connectors may need to emulate an XDR client.
-	// If enabled, an XDR bit is set for writes in the wire protocol.
-	//
-	// Default: false.
-	Xdr *bool `protobuf:"varint,15,opt,name=xdr,proto3,oneof" json:"xdr,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *BackgroundExecutePolicy) Reset() {
-	*x = BackgroundExecutePolicy{}
+func (x *AerospikeSetAllowlistResponse) Reset() {
+	*x = AerospikeSetAllowlistResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[91]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8768,13 +9114,13 @@ func (x *BackgroundExecutePolicy) Reset() {
 	}
 }
 
-func (x *BackgroundExecutePolicy) String() string {
+func (x *AerospikeSetAllowlistResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*BackgroundExecutePolicy) ProtoMessage() {}
+func (*AerospikeSetAllowlistResponse) ProtoMessage() {}
 
-func (x *BackgroundExecutePolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeSetAllowlistResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[91]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8786,155 +9132,31 @@ func (x *BackgroundExecutePolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use BackgroundExecutePolicy.ProtoReflect.Descriptor instead.
-func (*BackgroundExecutePolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeSetAllowlistResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeSetAllowlistResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{91}
 }
 
-func (x *BackgroundExecutePolicy) GetReplica() ReplicaPolicy {
-	if x != nil {
-		return x.Replica
-	}
-	return ReplicaPolicy_SEQUENCE
-}
-
-func (x *BackgroundExecutePolicy) GetReadModeAP() ReadModeAP {
-	if x != nil {
-		return x.ReadModeAP
-	}
-	return ReadModeAP_ReadModeAPOne
-}
-
-func (x *BackgroundExecutePolicy) GetReadModeSC() ReadModeSC {
-	if x != nil {
-		return x.ReadModeSC
-	}
-	return ReadModeSC_ReadModeSCSession
-}
-
-func (x *BackgroundExecutePolicy) GetCompress() bool {
-	if x != nil {
-		return x.Compress
-	}
-	return false
-}
-
-func (x *BackgroundExecutePolicy) GetExpression() []byte {
+func (x *AerospikeSetAllowlistResponse) GetError() *Error {
 	if x != nil {
-		return x.Expression
+		return x.Error
 	}
 	return nil
 }
 
-func (x *BackgroundExecutePolicy) GetTotalTimeout() uint32 {
-	if x != nil && x.TotalTimeout != nil {
-		return *x.TotalTimeout
-	}
-	return 0
-}
-
-func (x *BackgroundExecutePolicy) GetSendKey() bool {
-	if x != nil && x.SendKey != nil {
-		return *x.SendKey
-	}
-	return false
-}
-
-func (x *BackgroundExecutePolicy) GetRecordExistsAction() RecordExistsAction {
-	if x != nil && x.RecordExistsAction != nil {
-		return *x.RecordExistsAction
-	}
-	return RecordExistsAction_UPDATE
-}
-
-func (x *BackgroundExecutePolicy) GetGenerationPolicy() GenerationPolicy {
-	if x != nil && x.GenerationPolicy != nil {
-		return *x.GenerationPolicy
-	}
-	return GenerationPolicy_NONE
-}
-
-func (x *BackgroundExecutePolicy) GetCommitLevel() CommitLevel {
-	if x != nil && x.CommitLevel != nil {
-		return *x.CommitLevel
-	}
-	return CommitLevel_COMMIT_ALL
-}
-
-func (x *BackgroundExecutePolicy) GetGeneration() uint32 {
-	if x != nil && x.Generation != nil {
-		return *x.Generation
-	}
-	return 0
-}
-
-func (x *BackgroundExecutePolicy) GetExpiration() uint32 {
-	if x != nil && x.Expiration != nil {
-		return *x.Expiration
-	}
-	return 0
-}
-
-func (x *BackgroundExecutePolicy) GetRespondAllOps() bool {
-	if x != nil && x.RespondAllOps != nil {
-		return *x.RespondAllOps
-	}
-	return false
-}
-
-func (x *BackgroundExecutePolicy) GetDurableDelete() bool {
-	if x != nil && x.DurableDelete != nil {
-		return *x.DurableDelete
-	}
-	return false
-}
-
-func (x *BackgroundExecutePolicy) GetXdr() bool {
-	if x != nil && x.Xdr != nil {
-		return *x.Xdr
-	}
-	return false
-}
-
-// MultiPolicy contains parameters for policy attributes used in
-// query and scan operations.
-type MultiPolicy struct {
+type AerospikeSetQuotasRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	ReadPolicy *ReadPolicy `protobuf:"bytes,1,opt,name=readPolicy,proto3" json:"readPolicy,omitempty"`
-	// Maximum number of concurrent requests to server nodes at any point in time.
-	// If there are 16 nodes in the cluster and maxConcurrentNodes is 8, then queries
-	// will be made to 8 nodes in parallel.  When a query completes, a new query will
-	// be issued until all 16 nodes have been queried.
-	// Default (0) is to issue requests to all server nodes in parallel.
-	// 1 will to issue requests to server nodes one by one avoiding parallel queries.
-	MaxConcurrentNodes uint32 `protobuf:"varint,2,opt,name=maxConcurrentNodes,proto3" json:"maxConcurrentNodes,omitempty"`
-	// MaxRecords approximates the number of records to return to the client. This number is divided by the
-	// number of nodes involved in the query. The actual number of records returned
-	// may be less than MaxRecords if node record counts are small and unbalanced across
-	// nodes.
-	//
-	// This field is supported on server versions >= 4.9.
-	//
-	// Default: 0 (do not limit record count)
-	MaxRecords uint64 `protobuf:"varint,3,opt,name=maxRecords,proto3" json:"maxRecords,omitempty"`
-	// RecordsPerSecond limits returned records per second (rps) rate for each server.
-	// Will not apply rps limit if recordsPerSecond is zero (default).
-	// Currently only applicable to a query without a defined filter.
-	RecordsPerSecond uint64 `protobuf:"varint,4,opt,name=recordsPerSecond,proto3" json:"recordsPerSecond,omitempty"`
-	// Number of records to place in queue before blocking.
-	// Records received from multiple server nodes will be placed in a queue.
-	// A separate goroutine consumes these records in parallel.
-	// If the queue is full, the producer goroutines will block until records are consumed.
-	RecordQueueSize uint32 `protobuf:"varint,5,opt,name=recordQueueSize,proto3" json:"recordQueueSize,omitempty"`
-	// Indicates if bin data is retrieved. If false, only record digests are retrieved.
-	IncludeBinData bool `protobuf:"varint,6,opt,name=includeBinData,proto3" json:"includeBinData,omitempty"`
+	Policy     *AdminPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	RoleName   string       `protobuf:"bytes,2,opt,name=roleName,proto3" json:"roleName,omitempty"`
+	ReadQuota  uint32       `protobuf:"varint,3,opt,name=readQuota,proto3" json:"readQuota,omitempty"`
+	WriteQuota uint32       `protobuf:"varint,4,opt,name=writeQuota,proto3" json:"writeQuota,omitempty"`
 }
 
-func (x *MultiPolicy) Reset() {
-	*x = MultiPolicy{}
+func (x *AerospikeSetQuotasRequest) Reset() {
+	*x = AerospikeSetQuotasRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[92]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8942,13 +9164,13 @@ func (x *MultiPolicy) Reset() {
 	}
 }
 
-func (x *MultiPolicy) String() string {
+func (x *AerospikeSetQuotasRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*MultiPolicy) ProtoMessage() {}
+func (*AerospikeSetQuotasRequest) ProtoMessage() {}
 
-func (x *MultiPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeSetQuotasRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[92]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -8960,64 +9182,49 @@ func (x *MultiPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use MultiPolicy.ProtoReflect.Descriptor instead.
-func (*MultiPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeSetQuotasRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeSetQuotasRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{92}
 }
 
-func (x *MultiPolicy) GetReadPolicy() *ReadPolicy {
+func (x *AerospikeSetQuotasRequest) GetPolicy() *AdminPolicy {
 	if x != nil {
-		return x.ReadPolicy
+		return x.Policy
 	}
 	return nil
 }
 
-func (x *MultiPolicy) GetMaxConcurrentNodes() uint32 {
-	if x != nil {
-		return x.MaxConcurrentNodes
-	}
-	return 0
-}
-
-func (x *MultiPolicy) GetMaxRecords() uint64 {
+func (x *AerospikeSetQuotasRequest) GetRoleName() string {
 	if x != nil {
-		return x.MaxRecords
+		return x.RoleName
 	}
-	return 0
+	return ""
 }
 
-func (x *MultiPolicy) GetRecordsPerSecond() uint64 {
+func (x *AerospikeSetQuotasRequest) GetReadQuota() uint32 {
 	if x != nil {
-		return x.RecordsPerSecond
+		return x.ReadQuota
 	}
 	return 0
 }
 
-func (x *MultiPolicy) GetRecordQueueSize() uint32 {
+func (x *AerospikeSetQuotasRequest) GetWriteQuota() uint32 {
 	if x != nil {
-		return x.RecordQueueSize
+		return x.WriteQuota
 	}
 	return 0
 }
 
-func (x *MultiPolicy) GetIncludeBinData() bool {
-	if x != nil {
-		return x.IncludeBinData
-	}
-	return false
-}
-
-// ScanPolicy encapsulates parameters used in scan operations.
-type ScanPolicy struct {
+type AerospikeSetQuotasResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	MultiPolicy *MultiPolicy `protobuf:"bytes,1,opt,name=multiPolicy,proto3" json:"multiPolicy,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *ScanPolicy) Reset() {
-	*x = ScanPolicy{}
+func (x *AerospikeSetQuotasResponse) Reset() {
+	*x = AerospikeSetQuotasResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[93]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9025,13 +9232,13 @@ func (x *ScanPolicy) Reset() {
 	}
 }
 
-func (x *ScanPolicy) String() string {
+func (x *AerospikeSetQuotasResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ScanPolicy) ProtoMessage() {}
+func (*AerospikeSetQuotasResponse) ProtoMessage() {}
 
-func (x *ScanPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeSetQuotasResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[93]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9043,36 +9250,32 @@ func (x *ScanPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ScanPolicy.ProtoReflect.Descriptor instead.
-func (*ScanPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeSetQuotasResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeSetQuotasResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{93}
 }
 
-func (x *ScanPolicy) GetMultiPolicy() *MultiPolicy {
+func (x *AerospikeSetQuotasResponse) GetError() *Error {
 	if x != nil {
-		return x.MultiPolicy
+		return x.Error
 	}
 	return nil
 }
 
-// QueryPolicy encapsulates parameters for policy attributes used in query operations.
-type QueryPolicy struct {
+type AerospikeScanRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	MultiPolicy *MultiPolicy `protobuf:"bytes,1,opt,name=multiPolicy,proto3" json:"multiPolicy,omitempty"`
-	// ShortQuery detemines wether query expected to return less than 100 records.
-	// If true, the server will optimize the query for a small record set.
-	// This field is ignored for aggregation queries, background queries
-	// and server versions 6.0+.
-	//
-	// Default: false
-	ExpectedDuration QueryDuration `protobuf:"varint,2,opt,name=expectedDuration,proto3,enum=com.aerospike.daemon.QueryDuration" json:"expectedDuration,omitempty"`
+	Policy          *ScanPolicy      `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Namespace       string           `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	SetName         string           `protobuf:"bytes,3,opt,name=setName,proto3" json:"setName,omitempty"`
+	BinNames        []string         `protobuf:"bytes,4,rep,name=binNames,proto3" json:"binNames,omitempty"`
+	PartitionFilter *PartitionFilter `protobuf:"bytes,5,opt,name=partitionFilter,proto3,oneof" json:"partitionFilter,omitempty"`
 }
 
-func (x *QueryPolicy) Reset() {
-	*x = QueryPolicy{}
+func (x *AerospikeScanRequest) Reset() {
+	*x = AerospikeScanRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[94]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9080,13 +9283,13 @@ func (x *QueryPolicy) Reset() {
 	}
 }
 
-func (x *QueryPolicy) String() string {
+func (x *AerospikeScanRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*QueryPolicy) ProtoMessage() {}
+func (*AerospikeScanRequest) ProtoMessage() {}
 
-func (x *QueryPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeScanRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[94]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9098,38 +9301,58 @@ func (x *QueryPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use QueryPolicy.ProtoReflect.Descriptor instead.
-func (*QueryPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeScanRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeScanRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{94}
 }
 
-func (x *QueryPolicy) GetMultiPolicy() *MultiPolicy {
+func (x *AerospikeScanRequest) GetPolicy() *ScanPolicy {
 	if x != nil {
-		return x.MultiPolicy
+		return x.Policy
 	}
 	return nil
 }
 
-func (x *QueryPolicy) GetExpectedDuration() QueryDuration {
+func (x *AerospikeScanRequest) GetNamespace() string {
 	if x != nil {
-		return x.ExpectedDuration
+		return x.Namespace
 	}
-	return QueryDuration_Long
+	return ""
 }
 
-// Statement encapsulates query statement parameters.
-type UDFCall struct {
+func (x *AerospikeScanRequest) GetSetName() string {
+	if x != nil {
+		return x.SetName
+	}
+	return ""
+}
+
+func (x *AerospikeScanRequest) GetBinNames() []string {
+	if x != nil {
+		return x.BinNames
+	}
+	return nil
+}
+
+func (x *AerospikeScanRequest) GetPartitionFilter() *PartitionFilter {
+	if x != nil {
+		return x.PartitionFilter
+	}
+	return nil
+}
+
+type AerospikeStreamResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	PackageName  string   `protobuf:"bytes,6,opt,name=packageName,proto3" json:"packageName,omitempty"`
-	FunctionName string   `protobuf:"bytes,7,opt,name=functionName,proto3" json:"functionName,omitempty"`
-	FunctionArgs []*Value `protobuf:"bytes,8,rep,name=functionArgs,proto3" json:"functionArgs,omitempty"`
+	Record *Record `protobuf:"bytes,1,opt,name=record,proto3" json:"record,omitempty"`
+	Error  *Error  `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
+	Bval   *int64  `protobuf:"varint,3,opt,name=bval,proto3,oneof" json:"bval,omitempty"`
 }
 
-func (x *UDFCall) Reset() {
-	*x = UDFCall{}
+func (x *AerospikeStreamResponse) Reset() {
+	*x = AerospikeStreamResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[95]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9137,13 +9360,13 @@ func (x *UDFCall) Reset() {
 	}
 }
 
-func (x *UDFCall) String() string {
+func (x *AerospikeStreamResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UDFCall) ProtoMessage() {}
+func (*AerospikeStreamResponse) ProtoMessage() {}
 
-func (x *UDFCall) ProtoReflect() protoreflect.Message {
+func (x *AerospikeStreamResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[95]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9155,61 +9378,44 @@ func (x *UDFCall) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UDFCall.ProtoReflect.Descriptor instead.
-func (*UDFCall) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeStreamResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeStreamResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{95}
 }
 
-func (x *UDFCall) GetPackageName() string {
+func (x *AerospikeStreamResponse) GetRecord() *Record {
 	if x != nil {
-		return x.PackageName
+		return x.Record
 	}
-	return ""
+	return nil
 }
 
-func (x *UDFCall) GetFunctionName() string {
+func (x *AerospikeStreamResponse) GetError() *Error {
 	if x != nil {
-		return x.FunctionName
+		return x.Error
 	}
-	return ""
+	return nil
 }
 
-func (x *UDFCall) GetFunctionArgs() []*Value {
-	if x != nil {
-		return x.FunctionArgs
+func (x *AerospikeStreamResponse) GetBval() int64 {
+	if x != nil && x.Bval != nil {
+		return *x.Bval
 	}
-	return nil
+	return 0
 }
 
-// Statement encapsulates query statement parameters.
-type Statement struct {
+type AerospikeQueryRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Namespace determines query Namespace
-	Namespace string `protobuf:"bytes,1,opt,name=Namespace,proto3" json:"Namespace,omitempty"`
-	// SetName determines query Set name (Optional)
-	SetName string `protobuf:"bytes,2,opt,name=SetName,proto3" json:"SetName,omitempty"`
-	// IndexName determines query index name (Optional)
-	// If not set, the server will determine the index from the filter's bin name.
-	IndexName *string `protobuf:"bytes,3,opt,name=IndexName,proto3,oneof" json:"IndexName,omitempty"`
-	// BinNames detemines bin names (optional)
-	BinNames []string `protobuf:"bytes,4,rep,name=BinNames,proto3" json:"BinNames,omitempty"`
-	// Filter determines query index filter (Optional).
-	// This filter is applied to the secondary index on query.
-	// Query index filters must reference a bin which has a secondary index defined.
-	Filter *QueryFilter `protobuf:"bytes,5,opt,name=filter,proto3" json:"filter,omitempty"`
-	// TaskId determines query task id. (Optional)
-	// This value is not used anymore and will be removed later.
-	TaskId uint64 `protobuf:"varint,6,opt,name=taskId,proto3" json:"taskId,omitempty"`
-	// determines if the query should return data
-	ReturnData bool     `protobuf:"varint,7,opt,name=returnData,proto3" json:"returnData,omitempty"`
-	UdfCall    *UDFCall `protobuf:"bytes,8,opt,name=udfCall,proto3,oneof" json:"udfCall,omitempty"`
+	Policy          *QueryPolicy     `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Statement       *Statement       `protobuf:"bytes,2,opt,name=statement,proto3" json:"statement,omitempty"`
+	PartitionFilter *PartitionFilter `protobuf:"bytes,5,opt,name=partitionFilter,proto3,oneof" json:"partitionFilter,omitempty"`
 }
 
-func (x *Statement) Reset() {
-	*x = Statement{}
+func (x *AerospikeQueryRequest) Reset() {
+	*x = AerospikeQueryRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[96]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9217,13 +9423,13 @@ func (x *Statement) Reset() {
 	}
 }
 
-func (x *Statement) String() string {
+func (x *AerospikeQueryRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*Statement) ProtoMessage() {}
+func (*AerospikeQueryRequest) ProtoMessage() {}
 
-func (x *Statement) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[96]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9235,83 +9441,44 @@ func (x *Statement) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use Statement.ProtoReflect.Descriptor instead.
-func (*Statement) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{96}
 }
 
-func (x *Statement) GetNamespace() string {
-	if x != nil {
-		return x.Namespace
-	}
-	return ""
-}
-
-func (x *Statement) GetSetName() string {
-	if x != nil {
-		return x.SetName
-	}
-	return ""
-}
-
-func (x *Statement) GetIndexName() string {
-	if x != nil && x.IndexName != nil {
-		return *x.IndexName
-	}
-	return ""
-}
-
-func (x *Statement) GetBinNames() []string {
+func (x *AerospikeQueryRequest) GetPolicy() *QueryPolicy {
 	if x != nil {
-		return x.BinNames
+		return x.Policy
 	}
 	return nil
 }
 
-func (x *Statement) GetFilter() *QueryFilter {
+func (x *AerospikeQueryRequest) GetStatement() *Statement {
 	if x != nil {
-		return x.Filter
+		return x.Statement
 	}
 	return nil
 }
 
-func (x *Statement) GetTaskId() uint64 {
-	if x != nil {
-		return x.TaskId
-	}
-	return 0
-}
-
-func (x *Statement) GetReturnData() bool {
-	if x != nil {
-		return x.ReturnData
-	}
-	return false
-}
-
-func (x *Statement) GetUdfCall() *UDFCall {
+func (x *AerospikeQueryRequest) GetPartitionFilter() *PartitionFilter {
 	if x != nil {
-		return x.UdfCall
+		return x.PartitionFilter
 	}
 	return nil
 }
 
-// QueryFilter specifies a query filter definition.
-type QueryFilter struct {
+// Run an aggregation query. The stream UDF is taken from the statement's udfCall.
+type AerospikeQueryAggregateRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Name              string              `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
-	IdxType           IndexCollectionType `protobuf:"varint,2,opt,name=idxType,proto3,enum=com.aerospike.daemon.IndexCollectionType" json:"idxType,omitempty"`
-	ValueParticleType uint32              `protobuf:"varint,3,opt,name=valueParticleType,proto3" json:"valueParticleType,omitempty"`
-	Begin             *Value              `protobuf:"bytes,4,opt,name=begin,proto3" json:"begin,omitempty"`
-	End               *Value              `protobuf:"bytes,5,opt,name=end,proto3" json:"end,omitempty"`
-	Ctx               []*CDTContext       `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	Policy    *QueryPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Statement *Statement   `protobuf:"bytes,2,opt,name=statement,proto3" json:"statement,omitempty"`
 }
 
-func (x *QueryFilter) Reset() {
-	*x = QueryFilter{}
+func (x *AerospikeQueryAggregateRequest) Reset() {
+	*x = AerospikeQueryAggregateRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[97]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9319,13 +9486,13 @@ func (x *QueryFilter) Reset() {
 	}
 }
 
-func (x *QueryFilter) String() string {
+func (x *AerospikeQueryAggregateRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*QueryFilter) ProtoMessage() {}
+func (*AerospikeQueryAggregateRequest) ProtoMessage() {}
 
-func (x *QueryFilter) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryAggregateRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[97]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9337,65 +9504,36 @@ func (x *QueryFilter) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use QueryFilter.ProtoReflect.Descriptor instead.
-func (*QueryFilter) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryAggregateRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryAggregateRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{97}
 }
 
-func (x *QueryFilter) GetName() string {
-	if x != nil {
-		return x.Name
-	}
-	return ""
-}
-
-func (x *QueryFilter) GetIdxType() IndexCollectionType {
-	if x != nil {
-		return x.IdxType
-	}
-	return IndexCollectionType_IndexCollectionTypeDefault
-}
-
-func (x *QueryFilter) GetValueParticleType() uint32 {
-	if x != nil {
-		return x.ValueParticleType
-	}
-	return 0
-}
-
-func (x *QueryFilter) GetBegin() *Value {
-	if x != nil {
-		return x.Begin
-	}
-	return nil
-}
-
-func (x *QueryFilter) GetEnd() *Value {
+func (x *AerospikeQueryAggregateRequest) GetPolicy() *QueryPolicy {
 	if x != nil {
-		return x.End
+		return x.Policy
 	}
 	return nil
 }
 
-func (x *QueryFilter) GetCtx() []*CDTContext {
+func (x *AerospikeQueryAggregateRequest) GetStatement() *Statement {
 	if x != nil {
-		return x.Ctx
+		return x.Statement
 	}
 	return nil
 }
 
-type CdtMapPolicy struct {
+type AerospikeAggregateResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	MapOrder       MapOrderType     `protobuf:"varint,1,opt,name=mapOrder,proto3,enum=com.aerospike.daemon.MapOrderType" json:"mapOrder,omitempty"`
-	Flags          CdtMapWriteFlags `protobuf:"varint,3,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtMapWriteFlags" json:"flags,omitempty"`
-	PersistedIndex bool             `protobuf:"varint,4,opt,name=PersistedIndex,proto3" json:"PersistedIndex,omitempty"`
+	Value *Value `protobuf:"bytes,1,opt,name=value,proto3" json:"value,omitempty"`
+	Error *Error `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
 }
 
-func (x *CdtMapPolicy) Reset() {
-	*x = CdtMapPolicy{}
+func (x *AerospikeAggregateResponse) Reset() {
+	*x = AerospikeAggregateResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[98]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9403,13 +9541,13 @@ func (x *CdtMapPolicy) Reset() {
 	}
 }
 
-func (x *CdtMapPolicy) String() string {
+func (x *AerospikeAggregateResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtMapPolicy) ProtoMessage() {}
+func (*AerospikeAggregateResponse) ProtoMessage() {}
 
-func (x *CdtMapPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeAggregateResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[98]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9421,47 +9559,39 @@ func (x *CdtMapPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtMapPolicy.ProtoReflect.Descriptor instead.
-func (*CdtMapPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeAggregateResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeAggregateResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{98}
 }
 
-func (x *CdtMapPolicy) GetMapOrder() MapOrderType {
-	if x != nil {
-		return x.MapOrder
-	}
-	return MapOrderType_MapOrderTypeUnordered
-}
-
-func (x *CdtMapPolicy) GetFlags() CdtMapWriteFlags {
+func (x *AerospikeAggregateResponse) GetValue() *Value {
 	if x != nil {
-		return x.Flags
+		return x.Value
 	}
-	return CdtMapWriteFlags_CdtMapWriteFlagsDefault
+	return nil
 }
 
-func (x *CdtMapPolicy) GetPersistedIndex() bool {
+func (x *AerospikeAggregateResponse) GetError() *Error {
 	if x != nil {
-		return x.PersistedIndex
+		return x.Error
 	}
-	return false
+	return nil
 }
 
-type CdtMapOperation struct {
+// Run a background query on the server. If the statement has a UDF set, the UDF
+// will be applied to every record, otherwise the operations will be.
+type AerospikeQueryExecuteRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Op         CdtMapCommandOp   `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtMapCommandOp" json:"op,omitempty"`
-	Policy     *CdtMapPolicy     `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
-	BinName    string            `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
-	Args       []*Value          `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
-	ReturnType *CdtMapReturnType `protobuf:"varint,5,opt,name=returnType,proto3,enum=com.aerospike.daemon.CdtMapReturnType,oneof" json:"returnType,omitempty"`
-	Ctx        []*CDTContext     `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	Policy     *BackgroundExecutePolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Statement  *Statement               `protobuf:"bytes,2,opt,name=statement,proto3" json:"statement,omitempty"`
+	Operations []*Operation             `protobuf:"bytes,3,rep,name=operations,proto3" json:"operations,omitempty"`
 }
 
-func (x *CdtMapOperation) Reset() {
-	*x = CdtMapOperation{}
+func (x *AerospikeQueryExecuteRequest) Reset() {
+	*x = AerospikeQueryExecuteRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[99]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9469,13 +9599,13 @@ func (x *CdtMapOperation) Reset() {
 	}
 }
 
-func (x *CdtMapOperation) String() string {
+func (x *AerospikeQueryExecuteRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtMapOperation) ProtoMessage() {}
+func (*AerospikeQueryExecuteRequest) ProtoMessage() {}
 
-func (x *CdtMapOperation) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryExecuteRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[99]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9487,64 +9617,46 @@ func (x *CdtMapOperation) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtMapOperation.ProtoReflect.Descriptor instead.
-func (*CdtMapOperation) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryExecuteRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryExecuteRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{99}
 }
 
-func (x *CdtMapOperation) GetOp() CdtMapCommandOp {
-	if x != nil {
-		return x.Op
-	}
-	return CdtMapCommandOp_CdtMapCommandOpCreate
-}
-
-func (x *CdtMapOperation) GetPolicy() *CdtMapPolicy {
+func (x *AerospikeQueryExecuteRequest) GetPolicy() *BackgroundExecutePolicy {
 	if x != nil {
 		return x.Policy
 	}
 	return nil
 }
 
-func (x *CdtMapOperation) GetBinName() string {
-	if x != nil {
-		return x.BinName
-	}
-	return ""
-}
-
-func (x *CdtMapOperation) GetArgs() []*Value {
+func (x *AerospikeQueryExecuteRequest) GetStatement() *Statement {
 	if x != nil {
-		return x.Args
+		return x.Statement
 	}
 	return nil
 }
 
-func (x *CdtMapOperation) GetReturnType() CdtMapReturnType {
-	if x != nil && x.ReturnType != nil {
-		return *x.ReturnType
-	}
-	return CdtMapReturnType_CdtMapReturnTypeNone
-}
-
-func (x *CdtMapOperation) GetCtx() []*CDTContext {
+func (x *AerospikeQueryExecuteRequest) GetOperations() []*Operation {
 	if x != nil {
-		return x.Ctx
+		return x.Operations
 	}
 	return nil
 }
 
-type CdtListPolicy struct {
+type AerospikeQueryExecuteResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Order ListOrderType     `protobuf:"varint,1,opt,name=order,proto3,enum=com.aerospike.daemon.ListOrderType" json:"order,omitempty"`
-	Flags CdtListWriteFlags `protobuf:"varint,2,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtListWriteFlags" json:"flags,omitempty"`
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	// The id of the background task on the server
+	TaskId uint64 `protobuf:"varint,2,opt,name=taskId,proto3" json:"taskId,omitempty"`
+	// If the background task was run as a scan (no filter was set on the statement)
+	Scan bool `protobuf:"varint,3,opt,name=scan,proto3" json:"scan,omitempty"`
 }
 
-func (x *CdtListPolicy) Reset() {
-	*x = CdtListPolicy{}
+func (x *AerospikeQueryExecuteResponse) Reset() {
+	*x = AerospikeQueryExecuteResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[100]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9552,13 +9664,13 @@ func (x *CdtListPolicy) Reset() {
 	}
 }
 
-func (x *CdtListPolicy) String() string {
+func (x *AerospikeQueryExecuteResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtListPolicy) ProtoMessage() {}
+func (*AerospikeQueryExecuteResponse) ProtoMessage() {}
 
-func (x *CdtListPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeQueryExecuteResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[100]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9570,40 +9682,45 @@ func (x *CdtListPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtListPolicy.ProtoReflect.Descriptor instead.
-func (*CdtListPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeQueryExecuteResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeQueryExecuteResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{100}
 }
 
-func (x *CdtListPolicy) GetOrder() ListOrderType {
+func (x *AerospikeQueryExecuteResponse) GetError() *Error {
 	if x != nil {
-		return x.Order
+		return x.Error
 	}
-	return ListOrderType_ListOrderTypeUnordered
+	return nil
 }
 
-func (x *CdtListPolicy) GetFlags() CdtListWriteFlags {
+func (x *AerospikeQueryExecuteResponse) GetTaskId() uint64 {
 	if x != nil {
-		return x.Flags
+		return x.TaskId
 	}
-	return CdtListWriteFlags_CdtListWriteFlagsDefault
+	return 0
 }
 
-type CdtListOperation struct {
+func (x *AerospikeQueryExecuteResponse) GetScan() bool {
+	if x != nil {
+		return x.Scan
+	}
+	return false
+}
+
+// The progress of an asynchronous task on a single node.
+type NodeTaskProgress struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Op         CdtListCommandOp   `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtListCommandOp" json:"op,omitempty"`
-	Policy     *CdtListPolicy     `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
-	BinName    string             `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
-	Args       []*Value           `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
-	ReturnType *CdtListReturnType `protobuf:"varint,5,opt,name=returnType,proto3,enum=com.aerospike.daemon.CdtListReturnType,oneof" json:"returnType,omitempty"`
-	Ctx        []*CDTContext      `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	Node string `protobuf:"bytes,1,opt,name=node,proto3" json:"node,omitempty"`
+	// Percent of the task done on the node, between 0 and 100.
+	Percent uint32 `protobuf:"varint,2,opt,name=percent,proto3" json:"percent,omitempty"`
 }
 
-func (x *CdtListOperation) Reset() {
-	*x = CdtListOperation{}
+func (x *NodeTaskProgress) Reset() {
+	*x = NodeTaskProgress{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[101]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9611,13 +9728,13 @@ func (x *CdtListOperation) Reset() {
 	}
 }
 
-func (x *CdtListOperation) String() string {
+func (x *NodeTaskProgress) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtListOperation) ProtoMessage() {}
+func (*NodeTaskProgress) ProtoMessage() {}
 
-func (x *CdtListOperation) ProtoReflect() protoreflect.Message {
+func (x *NodeTaskProgress) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[101]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9629,64 +9746,38 @@ func (x *CdtListOperation) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtListOperation.ProtoReflect.Descriptor instead.
-func (*CdtListOperation) Descriptor() ([]byte, []int) {
+// Deprecated: Use NodeTaskProgress.ProtoReflect.Descriptor instead.
+func (*NodeTaskProgress) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{101}
 }
 
-func (x *CdtListOperation) GetOp() CdtListCommandOp {
-	if x != nil {
-		return x.Op
-	}
-	return CdtListCommandOp_CdtListCommandOpCreate
-}
-
-func (x *CdtListOperation) GetPolicy() *CdtListPolicy {
-	if x != nil {
-		return x.Policy
-	}
-	return nil
-}
-
-func (x *CdtListOperation) GetBinName() string {
+func (x *NodeTaskProgress) GetNode() string {
 	if x != nil {
-		return x.BinName
+		return x.Node
 	}
 	return ""
 }
 
-func (x *CdtListOperation) GetArgs() []*Value {
-	if x != nil {
-		return x.Args
-	}
-	return nil
-}
-
-func (x *CdtListOperation) GetReturnType() CdtListReturnType {
-	if x != nil && x.ReturnType != nil {
-		return *x.ReturnType
-	}
-	return CdtListReturnType_CdtListReturnTypeNone
-}
-
-func (x *CdtListOperation) GetCtx() []*CDTContext {
+func (x *NodeTaskProgress) GetPercent() uint32 {
 	if x != nil {
-		return x.Ctx
+		return x.Percent
 	}
-	return nil
+	return 0
 }
 
-// HLLPolicy determines the HyperLogLog operation policy.
-type CdtHLLPolicy struct {
+// The response for task status commands which can report their progress on each node.
+type AerospikeTaskProgressResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Flags CdtHLLWriteFlags `protobuf:"varint,1,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtHLLWriteFlags" json:"flags,omitempty"`
+	Error    *Error              `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Status   TaskStatus          `protobuf:"varint,2,opt,name=status,proto3,enum=com.aerospike.daemon.TaskStatus" json:"status,omitempty"`
+	Progress []*NodeTaskProgress `protobuf:"bytes,3,rep,name=progress,proto3" json:"progress,omitempty"`
 }
 
-func (x *CdtHLLPolicy) Reset() {
-	*x = CdtHLLPolicy{}
+func (x *AerospikeTaskProgressResponse) Reset() {
+	*x = AerospikeTaskProgressResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[102]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9694,13 +9785,13 @@ func (x *CdtHLLPolicy) Reset() {
 	}
 }
 
-func (x *CdtHLLPolicy) String() string {
+func (x *AerospikeTaskProgressResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtHLLPolicy) ProtoMessage() {}
+func (*AerospikeTaskProgressResponse) ProtoMessage() {}
 
-func (x *CdtHLLPolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeTaskProgressResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[102]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9712,31 +9803,47 @@ func (x *CdtHLLPolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtHLLPolicy.ProtoReflect.Descriptor instead.
-func (*CdtHLLPolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeTaskProgressResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeTaskProgressResponse) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{102}
 }
 
-func (x *CdtHLLPolicy) GetFlags() CdtHLLWriteFlags {
+func (x *AerospikeTaskProgressResponse) GetError() *Error {
 	if x != nil {
-		return x.Flags
+		return x.Error
 	}
-	return CdtHLLWriteFlags_CdtHLLWriteFlagsDefault
+	return nil
 }
 
-type CdtHLLOperation struct {
+func (x *AerospikeTaskProgressResponse) GetStatus() TaskStatus {
+	if x != nil {
+		return x.Status
+	}
+	return TaskStatus_IN_PROGRESS
+}
+
+func (x *AerospikeTaskProgressResponse) GetProgress() []*NodeTaskProgress {
+	if x != nil {
+		return x.Progress
+	}
+	return nil
+}
+
+// Check the status of a secondary index creation or removal.
+type AerospikeIndexTaskStatusRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Op      CdtHLLCommandOp `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtHLLCommandOp" json:"op,omitempty"`
-	Policy  *CdtHLLPolicy   `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
-	BinName string          `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
-	Args    []*Value        `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
+	Policy    *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Namespace string      `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	IndexName string      `protobuf:"bytes,3,opt,name=indexName,proto3" json:"indexName,omitempty"`
+	// if the task is an index removal
+	Drop bool `protobuf:"varint,4,opt,name=drop,proto3" json:"drop,omitempty"`
 }
 
-func (x *CdtHLLOperation) Reset() {
-	*x = CdtHLLOperation{}
+func (x *AerospikeIndexTaskStatusRequest) Reset() {
+	*x = AerospikeIndexTaskStatusRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[103]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9744,13 +9851,13 @@ func (x *CdtHLLOperation) Reset() {
 	}
 }
 
-func (x *CdtHLLOperation) String() string {
+func (x *AerospikeIndexTaskStatusRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtHLLOperation) ProtoMessage() {}
+func (*AerospikeIndexTaskStatusRequest) ProtoMessage() {}
 
-func (x *CdtHLLOperation) ProtoReflect() protoreflect.Message {
+func (x *AerospikeIndexTaskStatusRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[103]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9762,50 +9869,53 @@ func (x *CdtHLLOperation) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtHLLOperation.ProtoReflect.Descriptor instead.
-func (*CdtHLLOperation) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeIndexTaskStatusRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeIndexTaskStatusRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{103}
 }
 
-func (x *CdtHLLOperation) GetOp() CdtHLLCommandOp {
+func (x *AerospikeIndexTaskStatusRequest) GetPolicy() *InfoPolicy {
 	if x != nil {
-		return x.Op
+		return x.Policy
 	}
-	return CdtHLLCommandOp_CdtHLLCommandOpInit
+	return nil
 }
 
-func (x *CdtHLLOperation) GetPolicy() *CdtHLLPolicy {
+func (x *AerospikeIndexTaskStatusRequest) GetNamespace() string {
 	if x != nil {
-		return x.Policy
+		return x.Namespace
 	}
-	return nil
+	return ""
 }
 
-func (x *CdtHLLOperation) GetBinName() string {
+func (x *AerospikeIndexTaskStatusRequest) GetIndexName() string {
 	if x != nil {
-		return x.BinName
+		return x.IndexName
 	}
 	return ""
 }
 
-func (x *CdtHLLOperation) GetArgs() []*Value {
+func (x *AerospikeIndexTaskStatusRequest) GetDrop() bool {
 	if x != nil {
-		return x.Args
+		return x.Drop
 	}
-	return nil
+	return false
 }
 
-// BitwisePolicy determines the HyperLogLog operation policy.
-type CdtBitwisePolicy struct {
+// Check the status of a UDF package registration or removal.
+type AerospikeUDFTaskStatusRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Flags CdtBitwiseWriteFlags `protobuf:"varint,1,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtBitwiseWriteFlags" json:"flags,omitempty"`
+	Policy      *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	PackageName string      `protobuf:"bytes,2,opt,name=packageName,proto3" json:"packageName,omitempty"`
+	// if the task is a package removal
+	Drop bool `protobuf:"varint,3,opt,name=drop,proto3" json:"drop,omitempty"`
 }
 
-func (x *CdtBitwisePolicy) Reset() {
-	*x = CdtBitwisePolicy{}
+func (x *AerospikeUDFTaskStatusRequest) Reset() {
+	*x = AerospikeUDFTaskStatusRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[104]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9813,13 +9923,13 @@ func (x *CdtBitwisePolicy) Reset() {
 	}
 }
 
-func (x *CdtBitwisePolicy) String() string {
+func (x *AerospikeUDFTaskStatusRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtBitwisePolicy) ProtoMessage() {}
+func (*AerospikeUDFTaskStatusRequest) ProtoMessage() {}
 
-func (x *CdtBitwisePolicy) ProtoReflect() protoreflect.Message {
+func (x *AerospikeUDFTaskStatusRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[104]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9831,32 +9941,43 @@ func (x *CdtBitwisePolicy) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtBitwisePolicy.ProtoReflect.Descriptor instead.
-func (*CdtBitwisePolicy) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeUDFTaskStatusRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeUDFTaskStatusRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{104}
 }
 
-func (x *CdtBitwisePolicy) GetFlags() CdtBitwiseWriteFlags {
+func (x *AerospikeUDFTaskStatusRequest) GetPolicy() *InfoPolicy {
 	if x != nil {
-		return x.Flags
+		return x.Policy
 	}
-	return CdtBitwiseWriteFlags_CdtBitwiseWriteFlagsDefault
+	return nil
 }
 
-type CdtBitwiseOperation struct {
+func (x *AerospikeUDFTaskStatusRequest) GetPackageName() string {
+	if x != nil {
+		return x.PackageName
+	}
+	return ""
+}
+
+func (x *AerospikeUDFTaskStatusRequest) GetDrop() bool {
+	if x != nil {
+		return x.Drop
+	}
+	return false
+}
+
+type AerospikeExecuteTaskStatusRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Op      CdtBitwiseCommandOp `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtBitwiseCommandOp" json:"op,omitempty"`
-	Policy  *CdtBitwisePolicy   `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
-	BinName string              `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
-	Args    []*Value            `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
-	Ctx     []*CDTContext       `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	TaskId uint64 `protobuf:"varint,1,opt,name=taskId,proto3" json:"taskId,omitempty"`
+	Scan   bool   `protobuf:"varint,2,opt,name=scan,proto3" json:"scan,omitempty"`
 }
 
-func (x *CdtBitwiseOperation) Reset() {
-	*x = CdtBitwiseOperation{}
+func (x *AerospikeExecuteTaskStatusRequest) Reset() {
+	*x = AerospikeExecuteTaskStatusRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_asld_kvs_proto_msgTypes[105]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9864,13 +9985,13 @@ func (x *CdtBitwiseOperation) Reset() {
 	}
 }
 
-func (x *CdtBitwiseOperation) String() string {
+func (x *AerospikeExecuteTaskStatusRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CdtBitwiseOperation) ProtoMessage() {}
+func (*AerospikeExecuteTaskStatusRequest) ProtoMessage() {}
 
-func (x *CdtBitwiseOperation) ProtoReflect() protoreflect.Message {
+func (x *AerospikeExecuteTaskStatusRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_asld_kvs_proto_msgTypes[105]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -9882,52 +10003,2438 @@ func (x *CdtBitwiseOperation) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CdtBitwiseOperation.ProtoReflect.Descriptor instead.
-func (*CdtBitwiseOperation) Descriptor() ([]byte, []int) {
+// Deprecated: Use AerospikeExecuteTaskStatusRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeExecuteTaskStatusRequest) Descriptor() ([]byte, []int) {
 	return file_asld_kvs_proto_rawDescGZIP(), []int{105}
 }
 
-func (x *CdtBitwiseOperation) GetOp() CdtBitwiseCommandOp {
+func (x *AerospikeExecuteTaskStatusRequest) GetTaskId() uint64 {
 	if x != nil {
-		return x.Op
+		return x.TaskId
 	}
-	return CdtBitwiseCommandOp_CdtBitwiseCommandOpResize
+	return 0
 }
 
-func (x *CdtBitwiseOperation) GetPolicy() *CdtBitwisePolicy {
+func (x *AerospikeExecuteTaskStatusRequest) GetScan() bool {
 	if x != nil {
-		return x.Policy
+		return x.Scan
 	}
-	return nil
+	return false
 }
 
-func (x *CdtBitwiseOperation) GetBinName() string {
-	if x != nil {
-		return x.BinName
-	}
-	return ""
+type AerospikeExecuteTaskStatusResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error  *Error     `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Status TaskStatus `protobuf:"varint,2,opt,name=status,proto3,enum=com.aerospike.daemon.TaskStatus" json:"status,omitempty"`
 }
 
-func (x *CdtBitwiseOperation) GetArgs() []*Value {
-	if x != nil {
-		return x.Args
+func (x *AerospikeExecuteTaskStatusResponse) Reset() {
+	*x = AerospikeExecuteTaskStatusResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[106]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
 }
 
-func (x *CdtBitwiseOperation) GetCtx() []*CDTContext {
-	if x != nil {
+func (x *AerospikeExecuteTaskStatusResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeExecuteTaskStatusResponse) ProtoMessage() {}
+
+func (x *AerospikeExecuteTaskStatusResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[106]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeExecuteTaskStatusResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeExecuteTaskStatusResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{106}
+}
+
+func (x *AerospikeExecuteTaskStatusResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeExecuteTaskStatusResponse) GetStatus() TaskStatus {
+	if x != nil {
+		return x.Status
+	}
+	return TaskStatus_IN_PROGRESS
+}
+
+type AerospikeInfoRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Policy   *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Commands []string    `protobuf:"bytes,2,rep,name=commands,proto3" json:"commands,omitempty"`
+	// The name of the node to send the commands to. A random node is used if not set.
+	Node *string `protobuf:"bytes,3,opt,name=node,proto3,oneof" json:"node,omitempty"`
+}
+
+func (x *AerospikeInfoRequest) Reset() {
+	*x = AerospikeInfoRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[107]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeInfoRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeInfoRequest) ProtoMessage() {}
+
+func (x *AerospikeInfoRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[107]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeInfoRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeInfoRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{107}
+}
+
+func (x *AerospikeInfoRequest) GetPolicy() *InfoPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *AerospikeInfoRequest) GetCommands() []string {
+	if x != nil {
+		return x.Commands
+	}
+	return nil
+}
+
+func (x *AerospikeInfoRequest) GetNode() string {
+	if x != nil && x.Node != nil {
+		return *x.Node
+	}
+	return ""
+}
+
+type AerospikeInfoResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	// Info command responses keyed by the command
+	Results map[string]string `protobuf:"bytes,2,rep,name=results,proto3" json:"results,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+}
+
+func (x *AerospikeInfoResponse) Reset() {
+	*x = AerospikeInfoResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[108]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeInfoResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeInfoResponse) ProtoMessage() {}
+
+func (x *AerospikeInfoResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[108]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeInfoResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeInfoResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{108}
+}
+
+func (x *AerospikeInfoResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeInfoResponse) GetResults() map[string]string {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+// Node is a server node in the cluster.
+type Node struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name    string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Address string `protobuf:"bytes,2,opt,name=address,proto3" json:"address,omitempty"`
+	// Rack id of the node. If the node is in different racks for different namespaces,
+	// the rack id of the first namespace in alphabetical order is used. 0 if no racks are configured.
+	RackId int32 `protobuf:"varint,3,opt,name=rackId,proto3" json:"rackId,omitempty"`
+	// Rack id of the node for each namespace which has racks configured
+	Racks  map[string]int32 `protobuf:"bytes,4,rep,name=racks,proto3" json:"racks,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"varint,2,opt,name=value,proto3"`
+	Active bool             `protobuf:"varint,5,opt,name=active,proto3" json:"active,omitempty"`
+}
+
+func (x *Node) Reset() {
+	*x = Node{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[109]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Node) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Node) ProtoMessage() {}
+
+func (x *Node) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[109]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Node.ProtoReflect.Descriptor instead.
+func (*Node) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{109}
+}
+
+func (x *Node) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *Node) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *Node) GetRackId() int32 {
+	if x != nil {
+		return x.RackId
+	}
+	return 0
+}
+
+func (x *Node) GetRacks() map[string]int32 {
+	if x != nil {
+		return x.Racks
+	}
+	return nil
+}
+
+func (x *Node) GetActive() bool {
+	if x != nil {
+		return x.Active
+	}
+	return false
+}
+
+type AerospikeNodesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *AerospikeNodesRequest) Reset() {
+	*x = AerospikeNodesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[110]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeNodesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeNodesRequest) ProtoMessage() {}
+
+func (x *AerospikeNodesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[110]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeNodesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeNodesRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{110}
+}
+
+type AerospikeNodesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error *Error  `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Nodes []*Node `protobuf:"bytes,2,rep,name=nodes,proto3" json:"nodes,omitempty"`
+}
+
+func (x *AerospikeNodesResponse) Reset() {
+	*x = AerospikeNodesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[111]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeNodesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeNodesResponse) ProtoMessage() {}
+
+func (x *AerospikeNodesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[111]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeNodesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeNodesResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{111}
+}
+
+func (x *AerospikeNodesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeNodesResponse) GetNodes() []*Node {
+	if x != nil {
+		return x.Nodes
+	}
+	return nil
+}
+
+// NamespaceInfo contains the configuration and the statistics of a namespace.
+// Counters are summed over all the nodes in the cluster.
+type NamespaceInfo struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name              string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	ReplicationFactor int32  `protobuf:"varint,2,opt,name=replicationFactor,proto3" json:"replicationFactor,omitempty"`
+	// memory, device or pmem
+	StorageEngine string `protobuf:"bytes,3,opt,name=storageEngine,proto3" json:"storageEngine,omitempty"`
+	// Number of records, including the replicas.
+	Objects uint64 `protobuf:"varint,4,opt,name=objects,proto3" json:"objects,omitempty"`
+	// Number of master records.
+	MasterObjects uint64 `protobuf:"varint,5,opt,name=masterObjects,proto3" json:"masterObjects,omitempty"`
+	Tombstones    uint64 `protobuf:"varint,6,opt,name=tombstones,proto3" json:"tombstones,omitempty"`
+	// True if any node in the cluster has stopped accepting writes for the namespace.
+	StopWrites bool `protobuf:"varint,7,opt,name=stopWrites,proto3" json:"stopWrites,omitempty"`
+}
+
+func (x *NamespaceInfo) Reset() {
+	*x = NamespaceInfo{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[112]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *NamespaceInfo) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NamespaceInfo) ProtoMessage() {}
+
+func (x *NamespaceInfo) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[112]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NamespaceInfo.ProtoReflect.Descriptor instead.
+func (*NamespaceInfo) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{112}
+}
+
+func (x *NamespaceInfo) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *NamespaceInfo) GetReplicationFactor() int32 {
+	if x != nil {
+		return x.ReplicationFactor
+	}
+	return 0
+}
+
+func (x *NamespaceInfo) GetStorageEngine() string {
+	if x != nil {
+		return x.StorageEngine
+	}
+	return ""
+}
+
+func (x *NamespaceInfo) GetObjects() uint64 {
+	if x != nil {
+		return x.Objects
+	}
+	return 0
+}
+
+func (x *NamespaceInfo) GetMasterObjects() uint64 {
+	if x != nil {
+		return x.MasterObjects
+	}
+	return 0
+}
+
+func (x *NamespaceInfo) GetTombstones() uint64 {
+	if x != nil {
+		return x.Tombstones
+	}
+	return 0
+}
+
+func (x *NamespaceInfo) GetStopWrites() bool {
+	if x != nil {
+		return x.StopWrites
+	}
+	return false
+}
+
+type AerospikeNamespacesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Policy *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+}
+
+func (x *AerospikeNamespacesRequest) Reset() {
+	*x = AerospikeNamespacesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[113]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeNamespacesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeNamespacesRequest) ProtoMessage() {}
+
+func (x *AerospikeNamespacesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[113]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeNamespacesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeNamespacesRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{113}
+}
+
+func (x *AerospikeNamespacesRequest) GetPolicy() *InfoPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+type AerospikeNamespacesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error      *Error           `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Namespaces []*NamespaceInfo `protobuf:"bytes,2,rep,name=namespaces,proto3" json:"namespaces,omitempty"`
+}
+
+func (x *AerospikeNamespacesResponse) Reset() {
+	*x = AerospikeNamespacesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[114]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeNamespacesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeNamespacesResponse) ProtoMessage() {}
+
+func (x *AerospikeNamespacesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[114]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeNamespacesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeNamespacesResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{114}
+}
+
+func (x *AerospikeNamespacesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeNamespacesResponse) GetNamespaces() []*NamespaceInfo {
+	if x != nil {
+		return x.Namespaces
+	}
+	return nil
+}
+
+// SetInfo contains the configuration and the statistics of a set.
+// Counters are summed over all the nodes in the cluster.
+type SetInfo struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Namespace string `protobuf:"bytes,1,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	Name      string `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	// Number of records, including the replicas.
+	Objects    uint64 `protobuf:"varint,3,opt,name=objects,proto3" json:"objects,omitempty"`
+	Tombstones uint64 `protobuf:"varint,4,opt,name=tombstones,proto3" json:"tombstones,omitempty"`
+	// Reported by servers prior to 7.0
+	MemoryDataBytes uint64 `protobuf:"varint,5,opt,name=memoryDataBytes,proto3" json:"memoryDataBytes,omitempty"`
+	// Reported by servers prior to 7.0
+	DeviceDataBytes uint64 `protobuf:"varint,6,opt,name=deviceDataBytes,proto3" json:"deviceDataBytes,omitempty"`
+	// Reported by servers 7.0+
+	DataUsedBytes   uint64 `protobuf:"varint,7,opt,name=dataUsedBytes,proto3" json:"dataUsedBytes,omitempty"`
+	DisableEviction bool   `protobuf:"varint,8,opt,name=disableEviction,proto3" json:"disableEviction,omitempty"`
+}
+
+func (x *SetInfo) Reset() {
+	*x = SetInfo{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[115]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SetInfo) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetInfo) ProtoMessage() {}
+
+func (x *SetInfo) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[115]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetInfo.ProtoReflect.Descriptor instead.
+func (*SetInfo) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{115}
+}
+
+func (x *SetInfo) GetNamespace() string {
+	if x != nil {
+		return x.Namespace
+	}
+	return ""
+}
+
+func (x *SetInfo) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *SetInfo) GetObjects() uint64 {
+	if x != nil {
+		return x.Objects
+	}
+	return 0
+}
+
+func (x *SetInfo) GetTombstones() uint64 {
+	if x != nil {
+		return x.Tombstones
+	}
+	return 0
+}
+
+func (x *SetInfo) GetMemoryDataBytes() uint64 {
+	if x != nil {
+		return x.MemoryDataBytes
+	}
+	return 0
+}
+
+func (x *SetInfo) GetDeviceDataBytes() uint64 {
+	if x != nil {
+		return x.DeviceDataBytes
+	}
+	return 0
+}
+
+func (x *SetInfo) GetDataUsedBytes() uint64 {
+	if x != nil {
+		return x.DataUsedBytes
+	}
+	return 0
+}
+
+func (x *SetInfo) GetDisableEviction() bool {
+	if x != nil {
+		return x.DisableEviction
+	}
+	return false
+}
+
+type AerospikeSetsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Policy    *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Namespace string      `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
+}
+
+func (x *AerospikeSetsRequest) Reset() {
+	*x = AerospikeSetsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[116]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeSetsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeSetsRequest) ProtoMessage() {}
+
+func (x *AerospikeSetsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[116]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeSetsRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeSetsRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{116}
+}
+
+func (x *AerospikeSetsRequest) GetPolicy() *InfoPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *AerospikeSetsRequest) GetNamespace() string {
+	if x != nil {
+		return x.Namespace
+	}
+	return ""
+}
+
+type AerospikeSetsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error *Error     `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Sets  []*SetInfo `protobuf:"bytes,2,rep,name=sets,proto3" json:"sets,omitempty"`
+}
+
+func (x *AerospikeSetsResponse) Reset() {
+	*x = AerospikeSetsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[117]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeSetsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeSetsResponse) ProtoMessage() {}
+
+func (x *AerospikeSetsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[117]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeSetsResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeSetsResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{117}
+}
+
+func (x *AerospikeSetsResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeSetsResponse) GetSets() []*SetInfo {
+	if x != nil {
+		return x.Sets
+	}
+	return nil
+}
+
+// IndexInfo contains the definition and the state of a secondary index.
+type IndexInfo struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Namespace      string              `protobuf:"bytes,1,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	SetName        string              `protobuf:"bytes,2,opt,name=setName,proto3" json:"setName,omitempty"`
+	Name           string              `protobuf:"bytes,3,opt,name=name,proto3" json:"name,omitempty"`
+	BinName        string              `protobuf:"bytes,4,opt,name=binName,proto3" json:"binName,omitempty"`
+	IndexType      IndexType           `protobuf:"varint,5,opt,name=indexType,proto3,enum=com.aerospike.daemon.IndexType" json:"indexType,omitempty"`
+	CollectionType IndexCollectionType `protobuf:"varint,6,opt,name=collectionType,proto3,enum=com.aerospike.daemon.IndexCollectionType" json:"collectionType,omitempty"`
+	Ctx            []*CDTContext       `protobuf:"bytes,7,rep,name=ctx,proto3" json:"ctx,omitempty"`
+	// RW if the index is loaded on all the nodes in the cluster, otherwise the state
+	// reported by the first node on which it is not loaded (e.g. WO).
+	State string `protobuf:"bytes,8,opt,name=state,proto3" json:"state,omitempty"`
+}
+
+func (x *IndexInfo) Reset() {
+	*x = IndexInfo{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[118]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *IndexInfo) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*IndexInfo) ProtoMessage() {}
+
+func (x *IndexInfo) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[118]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use IndexInfo.ProtoReflect.Descriptor instead.
+func (*IndexInfo) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{118}
+}
+
+func (x *IndexInfo) GetNamespace() string {
+	if x != nil {
+		return x.Namespace
+	}
+	return ""
+}
+
+func (x *IndexInfo) GetSetName() string {
+	if x != nil {
+		return x.SetName
+	}
+	return ""
+}
+
+func (x *IndexInfo) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *IndexInfo) GetBinName() string {
+	if x != nil {
+		return x.BinName
+	}
+	return ""
+}
+
+func (x *IndexInfo) GetIndexType() IndexType {
+	if x != nil {
+		return x.IndexType
+	}
+	return IndexType_IndexTypeNumeric
+}
+
+func (x *IndexInfo) GetCollectionType() IndexCollectionType {
+	if x != nil {
+		return x.CollectionType
+	}
+	return IndexCollectionType_IndexCollectionTypeDefault
+}
+
+func (x *IndexInfo) GetCtx() []*CDTContext {
+	if x != nil {
+		return x.Ctx
+	}
+	return nil
+}
+
+func (x *IndexInfo) GetState() string {
+	if x != nil {
+		return x.State
+	}
+	return ""
+}
+
+type AerospikeIndexesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Policy    *InfoPolicy `protobuf:"bytes,1,opt,name=policy,proto3" json:"policy,omitempty"`
+	Namespace string      `protobuf:"bytes,2,opt,name=namespace,proto3" json:"namespace,omitempty"`
+}
+
+func (x *AerospikeIndexesRequest) Reset() {
+	*x = AerospikeIndexesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[119]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeIndexesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeIndexesRequest) ProtoMessage() {}
+
+func (x *AerospikeIndexesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[119]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeIndexesRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeIndexesRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{119}
+}
+
+func (x *AerospikeIndexesRequest) GetPolicy() *InfoPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *AerospikeIndexesRequest) GetNamespace() string {
+	if x != nil {
+		return x.Namespace
+	}
+	return ""
+}
+
+type AerospikeIndexesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error   *Error       `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Indexes []*IndexInfo `protobuf:"bytes,2,rep,name=indexes,proto3" json:"indexes,omitempty"`
+}
+
+func (x *AerospikeIndexesResponse) Reset() {
+	*x = AerospikeIndexesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[120]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeIndexesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeIndexesResponse) ProtoMessage() {}
+
+func (x *AerospikeIndexesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[120]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeIndexesResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeIndexesResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{120}
+}
+
+func (x *AerospikeIndexesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AerospikeIndexesResponse) GetIndexes() []*IndexInfo {
+	if x != nil {
+		return x.Indexes
+	}
+	return nil
+}
+
+type AerospikeVersionRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *AerospikeVersionRequest) Reset() {
+	*x = AerospikeVersionRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[121]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeVersionRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeVersionRequest) ProtoMessage() {}
+
+func (x *AerospikeVersionRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[121]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeVersionRequest.ProtoReflect.Descriptor instead.
+func (*AerospikeVersionRequest) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{121}
+}
+
+type AerospikeVersionResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Version string `protobuf:"bytes,1,opt,name=version,proto3" json:"version,omitempty"`
+}
+
+func (x *AerospikeVersionResponse) Reset() {
+	*x = AerospikeVersionResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[122]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AerospikeVersionResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AerospikeVersionResponse) ProtoMessage() {}
+
+func (x *AerospikeVersionResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[122]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AerospikeVersionResponse.ProtoReflect.Descriptor instead.
+func (*AerospikeVersionResponse) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{122}
+}
+
+func (x *AerospikeVersionResponse) GetVersion() string {
+	if x != nil {
+		return x.Version
+	}
+	return ""
+}
+
+type BackgroundExecutePolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Read policy for AP (availability) namespaces.
+	Replica ReplicaPolicy `protobuf:"varint,1,opt,name=replica,proto3,enum=com.aerospike.daemon.ReplicaPolicy" json:"replica,omitempty"`
+	// Read policy for SC (strong consistency) namespaces.
+	ReadModeAP ReadModeAP `protobuf:"varint,2,opt,name=readModeAP,proto3,enum=com.aerospike.daemon.ReadModeAP" json:"readModeAP,omitempty"`
+	// Replica algorithm used to determine the target node
+	// for a single record command.
+	// Scan and scan are not affected by replica algorithms.
+	ReadModeSC ReadModeSC `protobuf:"varint,3,opt,name=readModeSC,proto3,enum=com.aerospike.daemon.ReadModeSC" json:"readModeSC,omitempty"`
+	// Use zlib compression on command buffers sent to the server and responses received
+	// from the server when the buffer size is greater than 128 bytes.
+	// This option will increase cpu and memory usage (for extra compressed buffers),but
+	// decrease the size of data sent over the network.
+	Compress bool `protobuf:"varint,4,opt,name=compress,proto3" json:"compress,omitempty"`
+	// Optional expression filter. If filterExp exists and evaluates to false, the
+	// transaction is ignored.
+	FilterExpression *Expression `protobuf:"bytes,5,opt,name=filterExpression,proto3,oneof" json:"filterExpression,omitempty"`
+	// Total transaction timeout in milliseconds.
+	// Default for all other commands: 1000ms
+	TotalTimeout *uint32 `protobuf:"varint,6,opt,name=totalTimeout,proto3,oneof" json:"totalTimeout,omitempty"`
+	// Send user defined key in addition to hash digest on both reads and writes.
+	// If the key is sent on a write, the key will be stored with the record on
+	// the server.
+	// <p>
+	// Default: false (do not send the user defined key)
+	SendKey *bool `protobuf:"varint,7,opt,name=sendKey,proto3,oneof" json:"sendKey,omitempty"`
+	// Qualify how to handle writes where the record already exists.
+	//
+	// Default: RecordExistsAction.UPDATE
+	RecordExistsAction *RecordExistsAction `protobuf:"varint,8,opt,name=recordExistsAction,proto3,enum=com.aerospike.daemon.RecordExistsAction,oneof" json:"recordExistsAction,omitempty"`
+	// Qualify how to handle record writes based on record generation. The default (NONE)
+	// indicates that the generation is not used to restrict writes.
+	//
+	// The server does not support this field for UDF execute() calls. The read-modify-write
+	// usage model can still be enforced inside the UDF code itself.
+	//
+	// Default: GenerationPolicy.NONE
+	GenerationPolicy *GenerationPolicy `protobuf:"varint,9,opt,name=generationPolicy,proto3,enum=com.aerospike.daemon.GenerationPolicy,oneof" json:"generationPolicy,omitempty"`
+	// Desired consistency guarantee when committing a transaction on the server. The default
+	// (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
+	// be successful before returning success to the client.
+	//
+	// Default: CommitLevel.COMMIT_ALL
+	CommitLevel *CommitLevel `protobuf:"varint,10,opt,name=commitLevel,proto3,enum=com.aerospike.daemon.CommitLevel,oneof" json:"commitLevel,omitempty"`
+	// Expected generation. Generation is the number of times a record has been modified
+	// (including creation) on the server. If a write operation is creating a record,
+	// the expected generation would be <code>0</code>. This field is only relevant when
+	// generationPolicy is not NONE.
+	//
+	// The server does not support this field for UDF execute() calls. The read-modify-write
+	// usage model can still be enforced inside the UDF code itself.
+	//
+	// Default: 0
+	Generation *uint32 `protobuf:"varint,11,opt,name=generation,proto3,oneof" json:"generation,omitempty"`
+	// Record expiration. Also known as ttl (time to live).
+	// Seconds record will live before being removed by the server.
+	//
+	// Expiration values:
+	//
+	//   - -2: Do not change ttl when record is updated.
+	//   - -1: Never expire.
+	//   - 0: Default to namespace configuration variable "default-ttl" on the server.
+	//   - &gt; 0: Actual ttl in seconds.<br>
+	//
+	// Default: 0
+	Expiration *uint32 `protobuf:"varint,12,opt,name=expiration,proto3,oneof" json:"expiration,omitempty"`
+	// For client operate(), return a result for every operation.
+	//
+	// Some operations do not return results by default (ListOperation.clear() for example).
+	// This can make it difficult to determine the desired result offset in the returned
+	// bin's result list.
+	//
+	// Setting respondAllOps to true makes it easier to identify the desired result offset
+	// (result offset equals bin's operate sequence).  If there is a map operation in operate(),
+	// respondAllOps will be forced to true for that operate() call.
+	//
+	// Default: false
+	RespondAllOps *bool `protobuf:"varint,13,opt,name=respondAllOps,proto3,oneof" json:"respondAllOps,omitempty"`
+	// If the transaction results in a record deletion, leave a tombstone for the record.
+	// This prevents deleted records from reappearing after node failures.
+	// Valid for Aerospike Server Enterprise Edition 3.10+ only.
+	//
+	// Default: false (do not tombstone deleted records).
+	DurableDelete *bool `protobuf:"varint,14,opt,name=durableDelete,proto3,oneof" json:"durableDelete,omitempty"`
+	// Operate in XDR mode.  Some external connectors may need to emulate an XDR client.
+	// If enabled, an XDR bit is set for writes in the wire protocol.
+	//
+	// Default: false.
+	Xdr *bool `protobuf:"varint,15,opt,name=xdr,proto3,oneof" json:"xdr,omitempty"`
+}
+
+func (x *BackgroundExecutePolicy) Reset() {
+	*x = BackgroundExecutePolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[123]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *BackgroundExecutePolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BackgroundExecutePolicy) ProtoMessage() {}
+
+func (x *BackgroundExecutePolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[123]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BackgroundExecutePolicy.ProtoReflect.Descriptor instead.
+func (*BackgroundExecutePolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{123}
+}
+
+func (x *BackgroundExecutePolicy) GetReplica() ReplicaPolicy {
+	if x != nil {
+		return x.Replica
+	}
+	return ReplicaPolicy_SEQUENCE
+}
+
+func (x *BackgroundExecutePolicy) GetReadModeAP() ReadModeAP {
+	if x != nil {
+		return x.ReadModeAP
+	}
+	return ReadModeAP_ReadModeAPOne
+}
+
+func (x *BackgroundExecutePolicy) GetReadModeSC() ReadModeSC {
+	if x != nil {
+		return x.ReadModeSC
+	}
+	return ReadModeSC_ReadModeSCSession
+}
+
+func (x *BackgroundExecutePolicy) GetCompress() bool {
+	if x != nil {
+		return x.Compress
+	}
+	return false
+}
+
+func (x *BackgroundExecutePolicy) GetFilterExpression() *Expression {
+	if x != nil {
+		return x.FilterExpression
+	}
+	return nil
+}
+
+func (x *BackgroundExecutePolicy) GetTotalTimeout() uint32 {
+	if x != nil && x.TotalTimeout != nil {
+		return *x.TotalTimeout
+	}
+	return 0
+}
+
+func (x *BackgroundExecutePolicy) GetSendKey() bool {
+	if x != nil && x.SendKey != nil {
+		return *x.SendKey
+	}
+	return false
+}
+
+func (x *BackgroundExecutePolicy) GetRecordExistsAction() RecordExistsAction {
+	if x != nil && x.RecordExistsAction != nil {
+		return *x.RecordExistsAction
+	}
+	return RecordExistsAction_UPDATE
+}
+
+func (x *BackgroundExecutePolicy) GetGenerationPolicy() GenerationPolicy {
+	if x != nil && x.GenerationPolicy != nil {
+		return *x.GenerationPolicy
+	}
+	return GenerationPolicy_NONE
+}
+
+func (x *BackgroundExecutePolicy) GetCommitLevel() CommitLevel {
+	if x != nil && x.CommitLevel != nil {
+		return *x.CommitLevel
+	}
+	return CommitLevel_COMMIT_ALL
+}
+
+func (x *BackgroundExecutePolicy) GetGeneration() uint32 {
+	if x != nil && x.Generation != nil {
+		return *x.Generation
+	}
+	return 0
+}
+
+func (x *BackgroundExecutePolicy) GetExpiration() uint32 {
+	if x != nil && x.Expiration != nil {
+		return *x.Expiration
+	}
+	return 0
+}
+
+func (x *BackgroundExecutePolicy) GetRespondAllOps() bool {
+	if x != nil && x.RespondAllOps != nil {
+		return *x.RespondAllOps
+	}
+	return false
+}
+
+func (x *BackgroundExecutePolicy) GetDurableDelete() bool {
+	if x != nil && x.DurableDelete != nil {
+		return *x.DurableDelete
+	}
+	return false
+}
+
+func (x *BackgroundExecutePolicy) GetXdr() bool {
+	if x != nil && x.Xdr != nil {
+		return *x.Xdr
+	}
+	return false
+}
+
+// MultiPolicy contains parameters for policy attributes used in
+// query and scan operations.
+type MultiPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ReadPolicy *ReadPolicy `protobuf:"bytes,1,opt,name=readPolicy,proto3" json:"readPolicy,omitempty"`
+	// Maximum number of concurrent requests to server nodes at any point in time.
+	// If there are 16 nodes in the cluster and maxConcurrentNodes is 8, then queries
+	// will be made to 8 nodes in parallel.  When a query completes, a new query will
+	// be issued until all 16 nodes have been queried.
+	// Default (0) is to issue requests to all server nodes in parallel.
+	// 1 will to issue requests to server nodes one by one avoiding parallel queries.
+	MaxConcurrentNodes uint32 `protobuf:"varint,2,opt,name=maxConcurrentNodes,proto3" json:"maxConcurrentNodes,omitempty"`
+	// MaxRecords approximates the number of records to return to the client. This number is divided by the
+	// number of nodes involved in the query. The actual number of records returned
+	// may be less than MaxRecords if node record counts are small and unbalanced across
+	// nodes.
+	//
+	// This field is supported on server versions >= 4.9.
+	//
+	// Default: 0 (do not limit record count)
+	MaxRecords uint64 `protobuf:"varint,3,opt,name=maxRecords,proto3" json:"maxRecords,omitempty"`
+	// RecordsPerSecond limits returned records per second (rps) rate for each server.
+	// Will not apply rps limit if recordsPerSecond is zero (default).
+	// Currently only applicable to a query without a defined filter.
+	RecordsPerSecond uint64 `protobuf:"varint,4,opt,name=recordsPerSecond,proto3" json:"recordsPerSecond,omitempty"`
+	// Number of records to place in queue before blocking.
+	// Records received from multiple server nodes will be placed in a queue.
+	// A separate goroutine consumes these records in parallel.
+	// If the queue is full, the producer goroutines will block until records are consumed.
+	RecordQueueSize uint32 `protobuf:"varint,5,opt,name=recordQueueSize,proto3" json:"recordQueueSize,omitempty"`
+	// Indicates if bin data is retrieved. If false, only record digests are retrieved.
+	IncludeBinData bool `protobuf:"varint,6,opt,name=includeBinData,proto3" json:"includeBinData,omitempty"`
+}
+
+func (x *MultiPolicy) Reset() {
+	*x = MultiPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[124]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *MultiPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MultiPolicy) ProtoMessage() {}
+
+func (x *MultiPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[124]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MultiPolicy.ProtoReflect.Descriptor instead.
+func (*MultiPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{124}
+}
+
+func (x *MultiPolicy) GetReadPolicy() *ReadPolicy {
+	if x != nil {
+		return x.ReadPolicy
+	}
+	return nil
+}
+
+func (x *MultiPolicy) GetMaxConcurrentNodes() uint32 {
+	if x != nil {
+		return x.MaxConcurrentNodes
+	}
+	return 0
+}
+
+func (x *MultiPolicy) GetMaxRecords() uint64 {
+	if x != nil {
+		return x.MaxRecords
+	}
+	return 0
+}
+
+func (x *MultiPolicy) GetRecordsPerSecond() uint64 {
+	if x != nil {
+		return x.RecordsPerSecond
+	}
+	return 0
+}
+
+func (x *MultiPolicy) GetRecordQueueSize() uint32 {
+	if x != nil {
+		return x.RecordQueueSize
+	}
+	return 0
+}
+
+func (x *MultiPolicy) GetIncludeBinData() bool {
+	if x != nil {
+		return x.IncludeBinData
+	}
+	return false
+}
+
+// ScanPolicy encapsulates parameters used in scan operations.
+type ScanPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	MultiPolicy *MultiPolicy `protobuf:"bytes,1,opt,name=multiPolicy,proto3" json:"multiPolicy,omitempty"`
+}
+
+func (x *ScanPolicy) Reset() {
+	*x = ScanPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[125]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ScanPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ScanPolicy) ProtoMessage() {}
+
+func (x *ScanPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[125]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ScanPolicy.ProtoReflect.Descriptor instead.
+func (*ScanPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{125}
+}
+
+func (x *ScanPolicy) GetMultiPolicy() *MultiPolicy {
+	if x != nil {
+		return x.MultiPolicy
+	}
+	return nil
+}
+
+// QueryPolicy encapsulates parameters for policy attributes used in query operations.
+type QueryPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	MultiPolicy *MultiPolicy `protobuf:"bytes,1,opt,name=multiPolicy,proto3" json:"multiPolicy,omitempty"`
+	// ShortQuery detemines wether query expected to return less than 100 records.
+	// If true, the server will optimize the query for a small record set.
+	// This field is ignored for aggregation queries, background queries
+	// and server versions 6.0+.
+	//
+	// Default: false
+	ExpectedDuration QueryDuration `protobuf:"varint,2,opt,name=expectedDuration,proto3,enum=com.aerospike.daemon.QueryDuration" json:"expectedDuration,omitempty"`
+}
+
+func (x *QueryPolicy) Reset() {
+	*x = QueryPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[126]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *QueryPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*QueryPolicy) ProtoMessage() {}
+
+func (x *QueryPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[126]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use QueryPolicy.ProtoReflect.Descriptor instead.
+func (*QueryPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{126}
+}
+
+func (x *QueryPolicy) GetMultiPolicy() *MultiPolicy {
+	if x != nil {
+		return x.MultiPolicy
+	}
+	return nil
+}
+
+func (x *QueryPolicy) GetExpectedDuration() QueryDuration {
+	if x != nil {
+		return x.ExpectedDuration
+	}
+	return QueryDuration_Long
+}
+
+// Statement encapsulates query statement parameters.
+type UDFCall struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	PackageName  string   `protobuf:"bytes,6,opt,name=packageName,proto3" json:"packageName,omitempty"`
+	FunctionName string   `protobuf:"bytes,7,opt,name=functionName,proto3" json:"functionName,omitempty"`
+	FunctionArgs []*Value `protobuf:"bytes,8,rep,name=functionArgs,proto3" json:"functionArgs,omitempty"`
+}
+
+func (x *UDFCall) Reset() {
+	*x = UDFCall{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[127]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UDFCall) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UDFCall) ProtoMessage() {}
+
+func (x *UDFCall) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[127]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UDFCall.ProtoReflect.Descriptor instead.
+func (*UDFCall) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{127}
+}
+
+func (x *UDFCall) GetPackageName() string {
+	if x != nil {
+		return x.PackageName
+	}
+	return ""
+}
+
+func (x *UDFCall) GetFunctionName() string {
+	if x != nil {
+		return x.FunctionName
+	}
+	return ""
+}
+
+func (x *UDFCall) GetFunctionArgs() []*Value {
+	if x != nil {
+		return x.FunctionArgs
+	}
+	return nil
+}
+
+// Statement encapsulates query statement parameters.
+type Statement struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Namespace determines query Namespace
+	Namespace string `protobuf:"bytes,1,opt,name=Namespace,proto3" json:"Namespace,omitempty"`
+	// SetName determines query Set name (Optional)
+	SetName string `protobuf:"bytes,2,opt,name=SetName,proto3" json:"SetName,omitempty"`
+	// IndexName determines query index name (Optional)
+	// If not set, the server will determine the index from the filter's bin name.
+	IndexName *string `protobuf:"bytes,3,opt,name=IndexName,proto3,oneof" json:"IndexName,omitempty"`
+	// BinNames detemines bin names (optional)
+	BinNames []string `protobuf:"bytes,4,rep,name=BinNames,proto3" json:"BinNames,omitempty"`
+	// Filter determines query index filter (Optional).
+	// This filter is applied to the secondary index on query.
+	// Query index filters must reference a bin which has a secondary index defined.
+	Filter *QueryFilter `protobuf:"bytes,5,opt,name=filter,proto3" json:"filter,omitempty"`
+	// TaskId determines query task id. (Optional)
+	// This value is not used anymore and will be removed later.
+	TaskId uint64 `protobuf:"varint,6,opt,name=taskId,proto3" json:"taskId,omitempty"`
+	// determines if the query should return data
+	ReturnData bool     `protobuf:"varint,7,opt,name=returnData,proto3" json:"returnData,omitempty"`
+	UdfCall    *UDFCall `protobuf:"bytes,8,opt,name=udfCall,proto3,oneof" json:"udfCall,omitempty"`
+}
+
+func (x *Statement) Reset() {
+	*x = Statement{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[128]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Statement) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Statement) ProtoMessage() {}
+
+func (x *Statement) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[128]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Statement.ProtoReflect.Descriptor instead.
+func (*Statement) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{128}
+}
+
+func (x *Statement) GetNamespace() string {
+	if x != nil {
+		return x.Namespace
+	}
+	return ""
+}
+
+func (x *Statement) GetSetName() string {
+	if x != nil {
+		return x.SetName
+	}
+	return ""
+}
+
+func (x *Statement) GetIndexName() string {
+	if x != nil && x.IndexName != nil {
+		return *x.IndexName
+	}
+	return ""
+}
+
+func (x *Statement) GetBinNames() []string {
+	if x != nil {
+		return x.BinNames
+	}
+	return nil
+}
+
+func (x *Statement) GetFilter() *QueryFilter {
+	if x != nil {
+		return x.Filter
+	}
+	return nil
+}
+
+func (x *Statement) GetTaskId() uint64 {
+	if x != nil {
+		return x.TaskId
+	}
+	return 0
+}
+
+func (x *Statement) GetReturnData() bool {
+	if x != nil {
+		return x.ReturnData
+	}
+	return false
+}
+
+func (x *Statement) GetUdfCall() *UDFCall {
+	if x != nil {
+		return x.UdfCall
+	}
+	return nil
+}
+
+// QueryFilter specifies a query filter definition.
+type QueryFilter struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name              string              `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	IdxType           IndexCollectionType `protobuf:"varint,2,opt,name=idxType,proto3,enum=com.aerospike.daemon.IndexCollectionType" json:"idxType,omitempty"`
+	ValueParticleType uint32              `protobuf:"varint,3,opt,name=valueParticleType,proto3" json:"valueParticleType,omitempty"`
+	Begin             *Value              `protobuf:"bytes,4,opt,name=begin,proto3" json:"begin,omitempty"`
+	End               *Value              `protobuf:"bytes,5,opt,name=end,proto3" json:"end,omitempty"`
+	Ctx               []*CDTContext       `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+}
+
+func (x *QueryFilter) Reset() {
+	*x = QueryFilter{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[129]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *QueryFilter) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*QueryFilter) ProtoMessage() {}
+
+func (x *QueryFilter) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[129]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use QueryFilter.ProtoReflect.Descriptor instead.
+func (*QueryFilter) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{129}
+}
+
+func (x *QueryFilter) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *QueryFilter) GetIdxType() IndexCollectionType {
+	if x != nil {
+		return x.IdxType
+	}
+	return IndexCollectionType_IndexCollectionTypeDefault
+}
+
+func (x *QueryFilter) GetValueParticleType() uint32 {
+	if x != nil {
+		return x.ValueParticleType
+	}
+	return 0
+}
+
+func (x *QueryFilter) GetBegin() *Value {
+	if x != nil {
+		return x.Begin
+	}
+	return nil
+}
+
+func (x *QueryFilter) GetEnd() *Value {
+	if x != nil {
+		return x.End
+	}
+	return nil
+}
+
+func (x *QueryFilter) GetCtx() []*CDTContext {
+	if x != nil {
+		return x.Ctx
+	}
+	return nil
+}
+
+type CdtMapPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	MapOrder       MapOrderType     `protobuf:"varint,1,opt,name=mapOrder,proto3,enum=com.aerospike.daemon.MapOrderType" json:"mapOrder,omitempty"`
+	Flags          CdtMapWriteFlags `protobuf:"varint,3,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtMapWriteFlags" json:"flags,omitempty"`
+	PersistedIndex bool             `protobuf:"varint,4,opt,name=PersistedIndex,proto3" json:"PersistedIndex,omitempty"`
+}
+
+func (x *CdtMapPolicy) Reset() {
+	*x = CdtMapPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[130]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtMapPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtMapPolicy) ProtoMessage() {}
+
+func (x *CdtMapPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[130]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtMapPolicy.ProtoReflect.Descriptor instead.
+func (*CdtMapPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{130}
+}
+
+func (x *CdtMapPolicy) GetMapOrder() MapOrderType {
+	if x != nil {
+		return x.MapOrder
+	}
+	return MapOrderType_MapOrderTypeUnordered
+}
+
+func (x *CdtMapPolicy) GetFlags() CdtMapWriteFlags {
+	if x != nil {
+		return x.Flags
+	}
+	return CdtMapWriteFlags_CdtMapWriteFlagsDefault
+}
+
+func (x *CdtMapPolicy) GetPersistedIndex() bool {
+	if x != nil {
+		return x.PersistedIndex
+	}
+	return false
+}
+
+type CdtMapOperation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Op         CdtMapCommandOp   `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtMapCommandOp" json:"op,omitempty"`
+	Policy     *CdtMapPolicy     `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
+	BinName    string            `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
+	Args       []*Value          `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
+	ReturnType *CdtMapReturnType `protobuf:"varint,5,opt,name=returnType,proto3,enum=com.aerospike.daemon.CdtMapReturnType,oneof" json:"returnType,omitempty"`
+	Ctx        []*CDTContext     `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+}
+
+func (x *CdtMapOperation) Reset() {
+	*x = CdtMapOperation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[131]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtMapOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtMapOperation) ProtoMessage() {}
+
+func (x *CdtMapOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[131]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtMapOperation.ProtoReflect.Descriptor instead.
+func (*CdtMapOperation) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{131}
+}
+
+func (x *CdtMapOperation) GetOp() CdtMapCommandOp {
+	if x != nil {
+		return x.Op
+	}
+	return CdtMapCommandOp_CdtMapCommandOpCreate
+}
+
+func (x *CdtMapOperation) GetPolicy() *CdtMapPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *CdtMapOperation) GetBinName() string {
+	if x != nil {
+		return x.BinName
+	}
+	return ""
+}
+
+func (x *CdtMapOperation) GetArgs() []*Value {
+	if x != nil {
+		return x.Args
+	}
+	return nil
+}
+
+func (x *CdtMapOperation) GetReturnType() CdtMapReturnType {
+	if x != nil && x.ReturnType != nil {
+		return *x.ReturnType
+	}
+	return CdtMapReturnType_CdtMapReturnTypeNone
+}
+
+func (x *CdtMapOperation) GetCtx() []*CDTContext {
+	if x != nil {
+		return x.Ctx
+	}
+	return nil
+}
+
+type CdtListPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Order ListOrderType     `protobuf:"varint,1,opt,name=order,proto3,enum=com.aerospike.daemon.ListOrderType" json:"order,omitempty"`
+	Flags CdtListWriteFlags `protobuf:"varint,2,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtListWriteFlags" json:"flags,omitempty"`
+}
+
+func (x *CdtListPolicy) Reset() {
+	*x = CdtListPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[132]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtListPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtListPolicy) ProtoMessage() {}
+
+func (x *CdtListPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[132]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtListPolicy.ProtoReflect.Descriptor instead.
+func (*CdtListPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{132}
+}
+
+func (x *CdtListPolicy) GetOrder() ListOrderType {
+	if x != nil {
+		return x.Order
+	}
+	return ListOrderType_ListOrderTypeUnordered
+}
+
+func (x *CdtListPolicy) GetFlags() CdtListWriteFlags {
+	if x != nil {
+		return x.Flags
+	}
+	return CdtListWriteFlags_CdtListWriteFlagsDefault
+}
+
+type CdtListOperation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Op         CdtListCommandOp   `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtListCommandOp" json:"op,omitempty"`
+	Policy     *CdtListPolicy     `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
+	BinName    string             `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
+	Args       []*Value           `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
+	ReturnType *CdtListReturnType `protobuf:"varint,5,opt,name=returnType,proto3,enum=com.aerospike.daemon.CdtListReturnType,oneof" json:"returnType,omitempty"`
+	Ctx        []*CDTContext      `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+}
+
+func (x *CdtListOperation) Reset() {
+	*x = CdtListOperation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[133]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtListOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtListOperation) ProtoMessage() {}
+
+func (x *CdtListOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[133]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtListOperation.ProtoReflect.Descriptor instead.
+func (*CdtListOperation) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{133}
+}
+
+func (x *CdtListOperation) GetOp() CdtListCommandOp {
+	if x != nil {
+		return x.Op
+	}
+	return CdtListCommandOp_CdtListCommandOpCreate
+}
+
+func (x *CdtListOperation) GetPolicy() *CdtListPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *CdtListOperation) GetBinName() string {
+	if x != nil {
+		return x.BinName
+	}
+	return ""
+}
+
+func (x *CdtListOperation) GetArgs() []*Value {
+	if x != nil {
+		return x.Args
+	}
+	return nil
+}
+
+func (x *CdtListOperation) GetReturnType() CdtListReturnType {
+	if x != nil && x.ReturnType != nil {
+		return *x.ReturnType
+	}
+	return CdtListReturnType_CdtListReturnTypeNone
+}
+
+func (x *CdtListOperation) GetCtx() []*CDTContext {
+	if x != nil {
+		return x.Ctx
+	}
+	return nil
+}
+
+// HLLPolicy determines the HyperLogLog operation policy.
+type CdtHLLPolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Flags CdtHLLWriteFlags `protobuf:"varint,1,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtHLLWriteFlags" json:"flags,omitempty"`
+}
+
+func (x *CdtHLLPolicy) Reset() {
+	*x = CdtHLLPolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[134]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtHLLPolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtHLLPolicy) ProtoMessage() {}
+
+func (x *CdtHLLPolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[134]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtHLLPolicy.ProtoReflect.Descriptor instead.
+func (*CdtHLLPolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{134}
+}
+
+func (x *CdtHLLPolicy) GetFlags() CdtHLLWriteFlags {
+	if x != nil {
+		return x.Flags
+	}
+	return CdtHLLWriteFlags_CdtHLLWriteFlagsDefault
+}
+
+type CdtHLLOperation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Op      CdtHLLCommandOp `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtHLLCommandOp" json:"op,omitempty"`
+	Policy  *CdtHLLPolicy   `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
+	BinName string          `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
+	Args    []*Value        `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
+}
+
+func (x *CdtHLLOperation) Reset() {
+	*x = CdtHLLOperation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[135]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtHLLOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtHLLOperation) ProtoMessage() {}
+
+func (x *CdtHLLOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[135]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtHLLOperation.ProtoReflect.Descriptor instead.
+func (*CdtHLLOperation) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{135}
+}
+
+func (x *CdtHLLOperation) GetOp() CdtHLLCommandOp {
+	if x != nil {
+		return x.Op
+	}
+	return CdtHLLCommandOp_CdtHLLCommandOpInit
+}
+
+func (x *CdtHLLOperation) GetPolicy() *CdtHLLPolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *CdtHLLOperation) GetBinName() string {
+	if x != nil {
+		return x.BinName
+	}
+	return ""
+}
+
+func (x *CdtHLLOperation) GetArgs() []*Value {
+	if x != nil {
+		return x.Args
+	}
+	return nil
+}
+
+// BitwisePolicy determines the HyperLogLog operation policy.
+type CdtBitwisePolicy struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Flags CdtBitwiseWriteFlags `protobuf:"varint,1,opt,name=flags,proto3,enum=com.aerospike.daemon.CdtBitwiseWriteFlags" json:"flags,omitempty"`
+}
+
+func (x *CdtBitwisePolicy) Reset() {
+	*x = CdtBitwisePolicy{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[136]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtBitwisePolicy) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtBitwisePolicy) ProtoMessage() {}
+
+func (x *CdtBitwisePolicy) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[136]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtBitwisePolicy.ProtoReflect.Descriptor instead.
+func (*CdtBitwisePolicy) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{136}
+}
+
+func (x *CdtBitwisePolicy) GetFlags() CdtBitwiseWriteFlags {
+	if x != nil {
+		return x.Flags
+	}
+	return CdtBitwiseWriteFlags_CdtBitwiseWriteFlagsDefault
+}
+
+type CdtBitwiseOperation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Op      CdtBitwiseCommandOp `protobuf:"varint,1,opt,name=op,proto3,enum=com.aerospike.daemon.CdtBitwiseCommandOp" json:"op,omitempty"`
+	Policy  *CdtBitwisePolicy   `protobuf:"bytes,2,opt,name=policy,proto3" json:"policy,omitempty"`
+	BinName string              `protobuf:"bytes,3,opt,name=binName,proto3" json:"binName,omitempty"`
+	Args    []*Value            `protobuf:"bytes,4,rep,name=args,proto3" json:"args,omitempty"`
+	Ctx     []*CDTContext       `protobuf:"bytes,6,rep,name=ctx,proto3" json:"ctx,omitempty"`
+}
+
+func (x *CdtBitwiseOperation) Reset() {
+	*x = CdtBitwiseOperation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[137]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CdtBitwiseOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CdtBitwiseOperation) ProtoMessage() {}
+
+func (x *CdtBitwiseOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[137]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CdtBitwiseOperation.ProtoReflect.Descriptor instead.
+func (*CdtBitwiseOperation) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{137}
+}
+
+func (x *CdtBitwiseOperation) GetOp() CdtBitwiseCommandOp {
+	if x != nil {
+		return x.Op
+	}
+	return CdtBitwiseCommandOp_CdtBitwiseCommandOpResize
+}
+
+func (x *CdtBitwiseOperation) GetPolicy() *CdtBitwisePolicy {
+	if x != nil {
+		return x.Policy
+	}
+	return nil
+}
+
+func (x *CdtBitwiseOperation) GetBinName() string {
+	if x != nil {
+		return x.BinName
+	}
+	return ""
+}
+
+func (x *CdtBitwiseOperation) GetArgs() []*Value {
+	if x != nil {
+		return x.Args
+	}
+	return nil
+}
+
+func (x *CdtBitwiseOperation) GetCtx() []*CDTContext {
+	if x != nil {
 		return x.Ctx
 	}
 	return nil
 }
 
+// ExpOperation evaluates a server side expression and either returns the result
+// under the given name (read) or writes it to the given bin (write).
+type ExpOperation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	OpType ExpOperationType `protobuf:"varint,1,opt,name=opType,proto3,enum=com.aerospike.daemon.ExpOperationType" json:"opType,omitempty"`
+	// The bin name for write, or the result name for read operations
+	Name string      `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	Exp  *Expression `protobuf:"bytes,3,opt,name=exp,proto3" json:"exp,omitempty"`
+	// ExpReadFlags or ExpWriteFlags, depending on opType
+	Flags int32 `protobuf:"varint,4,opt,name=flags,proto3" json:"flags,omitempty"`
+}
+
+func (x *ExpOperation) Reset() {
+	*x = ExpOperation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_asld_kvs_proto_msgTypes[138]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ExpOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExpOperation) ProtoMessage() {}
+
+func (x *ExpOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_asld_kvs_proto_msgTypes[138]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExpOperation.ProtoReflect.Descriptor instead.
+func (*ExpOperation) Descriptor() ([]byte, []int) {
+	return file_asld_kvs_proto_rawDescGZIP(), []int{138}
+}
+
+func (x *ExpOperation) GetOpType() ExpOperationType {
+	if x != nil {
+		return x.OpType
+	}
+	return ExpOperationType_ExpOperationTypeRead
+}
+
+func (x *ExpOperation) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *ExpOperation) GetExp() *Expression {
+	if x != nil {
+		return x.Exp
+	}
+	return nil
+}
+
+func (x *ExpOperation) GetFlags() int32 {
+	if x != nil {
+		return x.Flags
+	}
+	return 0
+}
+
 var File_asld_kvs_proto protoreflect.FileDescriptor
 
 var file_asld_kvs_proto_rawDesc = []byte{
 	0x0a, 0x0e, 0x61, 0x73, 0x6c, 0x64, 0x5f, 0x6b, 0x76, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
 	0x12, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x22, 0xe7, 0x02, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x72, 0x65,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x22, 0x93, 0x04, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x72, 0x65,
 	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x6d, 0x64, 0x18, 0x01, 0x20, 0x01,
 	0x28, 0x0e, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
 	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x48,
@@ -9947,163 +12454,154 @@ var file_asld_kvs_proto_rawDesc = []byte{
 	0x04, 0x65, 0x78, 0x70, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
 	0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x04, 0x65,
-	0x78, 0x70, 0x73, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x63, 0x6d, 0x64, 0x42, 0x06, 0x0a, 0x04, 0x5f,
-	0x76, 0x61, 0x6c, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x62, 0x69, 0x6e, 0x42, 0x08, 0x0a, 0x06, 0x5f,
-	0x66, 0x6c, 0x61, 0x67, 0x73, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x22, 0xcb, 0x05, 0x0a, 0x0a, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x51, 0x0a, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73,
-	0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66,
-	0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88,
-	0x01, 0x01, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65,
-	0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f,
-	0x64, 0x65, 0x41, 0x50, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65,
-	0x53, 0x43, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64,
-	0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12, 0x22, 0x0a, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54,
-	0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c, 0x74, 0x6f,
-	0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x12, 0x24, 0x0a, 0x0d, 0x73, 0x6f,
-	0x63, 0x6b, 0x65, 0x74, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28,
-	0x04, 0x52, 0x0d, 0x73, 0x6f, 0x63, 0x6b, 0x65, 0x74, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74,
-	0x12, 0x1e, 0x0a, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73, 0x18, 0x06,
-	0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73,
-	0x12, 0x30, 0x0a, 0x13, 0x73, 0x6c, 0x65, 0x65, 0x70, 0x42, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e,
-	0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x13, 0x73,
-	0x6c, 0x65, 0x65, 0x70, 0x42, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x52, 0x65, 0x74, 0x72, 0x69,
-	0x65, 0x73, 0x12, 0x28, 0x0a, 0x0f, 0x73, 0x6c, 0x65, 0x65, 0x70, 0x4d, 0x75, 0x6c, 0x74, 0x69,
-	0x70, 0x6c, 0x69, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x01, 0x52, 0x0f, 0x73, 0x6c, 0x65,
-	0x65, 0x70, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x70, 0x6c, 0x69, 0x65, 0x72, 0x12, 0x4c, 0x0a, 0x21,
-	0x65, 0x78, 0x69, 0x74, 0x46, 0x61, 0x73, 0x74, 0x4f, 0x6e, 0x45, 0x78, 0x68, 0x61, 0x75, 0x73,
-	0x74, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6f,
-	0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x08, 0x52, 0x21, 0x65, 0x78, 0x69, 0x74, 0x46, 0x61, 0x73,
-	0x74, 0x4f, 0x6e, 0x45, 0x78, 0x68, 0x61, 0x75, 0x73, 0x74, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x6e,
-	0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6f, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65,
-	0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e,
-	0x64, 0x4b, 0x65, 0x79, 0x12, 0x26, 0x0a, 0x0e, 0x75, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x70, 0x72,
-	0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x75, 0x73,
-	0x65, 0x43, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x49, 0x0a, 0x0d,
-	0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x0c, 0x20,
-	0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x70, 0x6c, 0x69,
-	0x63, 0x61, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0d, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x63,
-	0x61, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x30, 0x0a, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54,
-	0x6f, 0x75, 0x63, 0x68, 0x54, 0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x18, 0x0d,
-	0x20, 0x01, 0x28, 0x05, 0x52, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x54,
-	0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x66, 0x69,
-	0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xcc,
-	0x03, 0x0a, 0x0b, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x38,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x58, 0x0a, 0x12, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12,
-	0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x12, 0x52, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x52, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x43, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
-	0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x0b,
-	0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x1e, 0x0a, 0x0a, 0x67,
-	0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52,
-	0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x0a, 0x65,
-	0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x52,
-	0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2a, 0x0a, 0x10, 0x72,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x50, 0x65, 0x72, 0x45, 0x61, 0x63, 0x68, 0x4f, 0x70, 0x18,
-	0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x10, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x50, 0x65,
-	0x72, 0x45, 0x61, 0x63, 0x68, 0x4f, 0x70, 0x12, 0x24, 0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62,
-	0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d,
-	0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x22, 0x27, 0x0a,
-	0x0b, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07,
-	0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x74,
-	0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x22, 0x26, 0x0a, 0x0a, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x22, 0xb1,
-	0x02, 0x0a, 0x0f, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x12, 0x52, 0x0a, 0x11, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70,
-	0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48,
-	0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73,
-	0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f,
-	0x64, 0x65, 0x41, 0x50, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x52, 0x0a, 0x72, 0x65,
-	0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64,
-	0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x52, 0x0a,
-	0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12, 0x30, 0x0a, 0x13, 0x72, 0x65,
-	0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x54, 0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e,
-	0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x52, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54, 0x6f, 0x75,
-	0x63, 0x68, 0x54, 0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x42, 0x14, 0x0a, 0x12,
-	0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69,
-	0x6f, 0x6e, 0x22, 0xef, 0x03, 0x0a, 0x10, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72, 0x69, 0x74,
-	0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x52, 0x0a, 0x11, 0x66, 0x69, 0x6c, 0x74, 0x65,
-	0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73,
-	0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78,
-	0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x58, 0x0a, 0x12, 0x52,
-	0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f,
-	0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52,
-	0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f,
-	0x6e, 0x52, 0x12, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41,
-	0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x43, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c,
-	0x65, 0x76, 0x65, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
+	0x78, 0x70, 0x73, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65,
+	0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x12, 0x40, 0x0a, 0x09, 0x76, 0x61, 0x6c, 0x75, 0x65,
+	0x54, 0x79, 0x70, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d,
 	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x0b, 0x63,
-	0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x52, 0x0a, 0x10, 0x67, 0x65,
-	0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x04,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65,
-	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x10, 0x67, 0x65,
-	0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1e,
-	0x0a, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01,
-	0x28, 0x0d, 0x52, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e,
-	0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01,
-	0x28, 0x0d, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24,
-	0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18,
-	0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18,
-	0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42, 0x14,
-	0x0a, 0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73,
-	0x73, 0x69, 0x6f, 0x6e, 0x22, 0xf6, 0x02, 0x0a, 0x11, 0x42, 0x61, 0x74, 0x63, 0x68, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x52, 0x0a, 0x11, 0x66, 0x69,
-	0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+	0x6e, 0x2e, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x48, 0x05, 0x52, 0x09, 0x76, 0x61, 0x6c,
+	0x75, 0x65, 0x54, 0x79, 0x70, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1b, 0x0a, 0x06, 0x62, 0x61, 0x73,
+	0x65, 0x36, 0x34, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x48, 0x06, 0x52, 0x06, 0x62, 0x61, 0x73,
+	0x65, 0x36, 0x34, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x63, 0x6d, 0x64, 0x42, 0x06,
+	0x0a, 0x04, 0x5f, 0x76, 0x61, 0x6c, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x62, 0x69, 0x6e, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x6d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x54, 0x79, 0x70,
+	0x65, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x22, 0x85, 0x06, 0x0a,
+	0x0a, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x51, 0x0a, 0x10, 0x66,
+	0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18,
 	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
 	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70,
 	0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65,
-	0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x43,
-	0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69,
-	0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65,
-	0x76, 0x65, 0x6c, 0x12, 0x52, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
-	0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e,
+	0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x40,
+	0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f,
+	0x64, 0x65, 0x41, 0x50, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50,
+	0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64,
+	0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65,
+	0x53, 0x43, 0x12, 0x22, 0x0a, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f,
+	0x75, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54,
+	0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x12, 0x24, 0x0a, 0x0d, 0x73, 0x6f, 0x63, 0x6b, 0x65, 0x74,
+	0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x73,
+	0x6f, 0x63, 0x6b, 0x65, 0x74, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x12, 0x1e, 0x0a, 0x0a,
+	0x6d, 0x61, 0x78, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73, 0x12, 0x30, 0x0a, 0x13,
+	0x73, 0x6c, 0x65, 0x65, 0x70, 0x42, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x52, 0x65, 0x74, 0x72,
+	0x69, 0x65, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x13, 0x73, 0x6c, 0x65, 0x65, 0x70,
+	0x42, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x73, 0x12, 0x28,
+	0x0a, 0x0f, 0x73, 0x6c, 0x65, 0x65, 0x70, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x70, 0x6c, 0x69, 0x65,
+	0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x01, 0x52, 0x0f, 0x73, 0x6c, 0x65, 0x65, 0x70, 0x4d, 0x75,
+	0x6c, 0x74, 0x69, 0x70, 0x6c, 0x69, 0x65, 0x72, 0x12, 0x4c, 0x0a, 0x21, 0x65, 0x78, 0x69, 0x74,
+	0x46, 0x61, 0x73, 0x74, 0x4f, 0x6e, 0x45, 0x78, 0x68, 0x61, 0x75, 0x73, 0x74, 0x65, 0x64, 0x43,
+	0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6f, 0x6c, 0x18, 0x09, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x21, 0x65, 0x78, 0x69, 0x74, 0x46, 0x61, 0x73, 0x74, 0x4f, 0x6e, 0x45,
+	0x78, 0x68, 0x61, 0x75, 0x73, 0x74, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x50, 0x6f, 0x6f, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65,
+	0x79, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79,
+	0x12, 0x26, 0x0a, 0x0e, 0x75, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69,
+	0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x75, 0x73, 0x65, 0x43, 0x6f, 0x6d,
+	0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x49, 0x0a, 0x0d, 0x72, 0x65, 0x70, 0x6c,
+	0x69, 0x63, 0x61, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x52, 0x0d, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x30, 0x0a, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68,
+	0x54, 0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x05,
+	0x52, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x54, 0x54, 0x4c, 0x50, 0x65,
+	0x72, 0x63, 0x65, 0x6e, 0x74, 0x12, 0x30, 0x0a, 0x03, 0x74, 0x78, 0x6e, 0x18, 0x0e, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54, 0x78, 0x6e, 0x48, 0x01, 0x52,
+	0x03, 0x74, 0x78, 0x6e, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x66, 0x69, 0x6c, 0x74,
+	0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x06, 0x0a, 0x04,
+	0x5f, 0x74, 0x78, 0x6e, 0x22, 0xcc, 0x03, 0x0a, 0x0b, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x58,
+	0x0a, 0x12, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73,
+	0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x52, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x10, 0x67, 0x65, 0x6e, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x43, 0x0a, 0x0b,
+	0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65,
+	0x6c, 0x12, 0x1e, 0x0a, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+	0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x12, 0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+	0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x12, 0x2a, 0x0a, 0x10, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x50, 0x65, 0x72, 0x45,
+	0x61, 0x63, 0x68, 0x4f, 0x70, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x10, 0x72, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x64, 0x50, 0x65, 0x72, 0x45, 0x61, 0x63, 0x68, 0x4f, 0x70, 0x12, 0x24, 0x0a,
+	0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x08,
+	0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c,
+	0x65, 0x74, 0x65, 0x22, 0x27, 0x0a, 0x0b, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0d, 0x52, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x22, 0x26, 0x0a, 0x0a,
+	0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x74, 0x69,
+	0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x74, 0x69, 0x6d,
+	0x65, 0x6f, 0x75, 0x74, 0x22, 0xb1, 0x02, 0x0a, 0x0f, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
+	0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x52, 0x0a, 0x11, 0x66, 0x69, 0x6c, 0x74,
+	0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45,
+	0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x40, 0x0a, 0x0a,
+	0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e,
+	0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65,
+	0x41, 0x50, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x12, 0x40,
+	0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f,
+	0x64, 0x65, 0x53, 0x43, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43,
+	0x12, 0x30, 0x0a, 0x13, 0x72, 0x65, 0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x54, 0x54, 0x4c,
+	0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x52, 0x13, 0x72,
+	0x65, 0x61, 0x64, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x54, 0x54, 0x4c, 0x50, 0x65, 0x72, 0x63, 0x65,
+	0x6e, 0x74, 0x42, 0x14, 0x0a, 0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78,
+	0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xef, 0x03, 0x0a, 0x10, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x52, 0x0a,
+	0x11, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69,
+	0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69,
+	0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01,
+	0x01, 0x12, 0x58, 0x0a, 0x12, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74,
+	0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e,
 	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
-	0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1e, 0x0a, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x67, 0x65, 0x6e,
-	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24, 0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62,
-	0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d,
-	0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x18, 0x0a,
-	0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07,
-	0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42, 0x14, 0x0a, 0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74,
-	0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x9f, 0x02,
-	0x0a, 0x0e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74,
+	0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45,
+	0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x43, 0x0a, 0x0b, 0x63,
+	0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e,
+	0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65,
+	0x76, 0x65, 0x6c, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c,
+	0x12, 0x52, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x52, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x12, 0x1e, 0x0a, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24, 0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x64, 0x75, 0x72,
+	0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65,
+	0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e,
+	0x64, 0x4b, 0x65, 0x79, 0x42, 0x14, 0x0a, 0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f,
+	0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xf6, 0x02, 0x0a, 0x11, 0x42,
+	0x61, 0x74, 0x63, 0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
 	0x12, 0x52, 0x0a, 0x11, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65,
 	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
@@ -10113,1801 +12611,2305 @@ var file_asld_kvs_proto_rawDesc = []byte{
 	0x76, 0x65, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
 	0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x0b, 0x63, 0x6f,
-	0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x70,
-	0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x65,
-	0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24, 0x0a, 0x0d, 0x64, 0x75, 0x72,
-	0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08,
-	0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12,
-	0x18, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08,
-	0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42, 0x14, 0x0a, 0x12, 0x5f, 0x66, 0x69,
-	0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22,
-	0xae, 0x02, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63, 0x68, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2d, 0x0a, 0x0f, 0x63, 0x6f, 0x6e,
-	0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x05, 0x48, 0x00, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74,
-	0x4e, 0x6f, 0x64, 0x65, 0x73, 0x88, 0x01, 0x01, 0x12, 0x20, 0x0a, 0x0b, 0x61, 0x6c, 0x6c, 0x6f,
-	0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x61,
-	0x6c, 0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x26, 0x0a, 0x0e, 0x61, 0x6c,
-	0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x53, 0x53, 0x44, 0x18, 0x04, 0x20, 0x01,
-	0x28, 0x08, 0x52, 0x0e, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x53,
-	0x53, 0x44, 0x12, 0x26, 0x0a, 0x0e, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c,
-	0x4b, 0x65, 0x79, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x72, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4b, 0x65, 0x79, 0x73, 0x12, 0x30, 0x0a, 0x13, 0x41, 0x6c,
-	0x6c, 0x6f, 0x77, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74,
-	0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x13, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x50, 0x61,
-	0x72, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x42, 0x12, 0x0a, 0x10,
-	0x5f, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73,
-	0x22, 0x4c, 0x0a, 0x03, 0x42, 0x69, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x31, 0x0a, 0x05, 0x76,
-	0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x31,
-	0x0a, 0x04, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x29, 0x0a, 0x01, 0x6c, 0x18, 0x01, 0x20, 0x03, 0x28,
-	0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x01,
-	0x6c, 0x22, 0x60, 0x0a, 0x08, 0x4d, 0x61, 0x70, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x29, 0x0a,
-	0x01, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x01, 0x6b, 0x12, 0x29, 0x0a, 0x01, 0x76, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65,
-	0x52, 0x01, 0x76, 0x22, 0x33, 0x0a, 0x03, 0x4d, 0x61, 0x70, 0x12, 0x2c, 0x0a, 0x01, 0x6d, 0x18,
-	0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x61, 0x70,
-	0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x01, 0x6d, 0x22, 0x44, 0x0a, 0x09, 0x4a, 0x73, 0x6f, 0x6e,
-	0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x0c, 0x0a, 0x01, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x01, 0x6b, 0x12, 0x29, 0x0a, 0x01, 0x76, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
+	0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x52, 0x0a, 0x10, 0x67, 0x65, 0x6e,
+	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x10, 0x67, 0x65, 0x6e,
+	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1e, 0x0a,
+	0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x0d, 0x52, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24, 0x0a,
+	0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x05,
+	0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c,
+	0x65, 0x74, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x18, 0x06,
+	0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42, 0x14, 0x0a,
+	0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73,
+	0x69, 0x6f, 0x6e, 0x22, 0x9f, 0x02, 0x0a, 0x0e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x52, 0x0a, 0x11, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72,
+	0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73,
+	0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70,
+	0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x43, 0x0a, 0x0b, 0x63, 0x6f,
+	0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76,
+	0x65, 0x6c, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12,
+	0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0d, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+	0x24, 0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42,
+	0x14, 0x0a, 0x12, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xae, 0x02, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63, 0x68, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61,
+	0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
+	0x2d, 0x0a, 0x0f, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64,
+	0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x63,
+	0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x88, 0x01, 0x01, 0x12, 0x20,
+	0x0a, 0x0b, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x0b, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65,
+	0x12, 0x26, 0x0a, 0x0e, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x49, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x53,
+	0x53, 0x44, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x49,
+	0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x53, 0x53, 0x44, 0x12, 0x26, 0x0a, 0x0e, 0x72, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4b, 0x65, 0x79, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08,
+	0x52, 0x0e, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4b, 0x65, 0x79, 0x73,
+	0x12, 0x30, 0x0a, 0x13, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c,
+	0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x13, 0x41,
+	0x6c, 0x6c, 0x6f, 0x77, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x73, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e,
+	0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x22, 0x4c, 0x0a, 0x03, 0x42, 0x69, 0x6e, 0x12, 0x12, 0x0a,
+	0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+	0x65, 0x12, 0x31, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x22, 0x31, 0x0a, 0x04, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x29, 0x0a, 0x01,
+	0x6c, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56,
+	0x61, 0x6c, 0x75, 0x65, 0x52, 0x01, 0x6c, 0x22, 0x60, 0x0a, 0x08, 0x4d, 0x61, 0x70, 0x45, 0x6e,
+	0x74, 0x72, 0x79, 0x12, 0x29, 0x0a, 0x01, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x01, 0x76, 0x22, 0x35,
-	0x0a, 0x04, 0x4a, 0x73, 0x6f, 0x6e, 0x12, 0x2d, 0x0a, 0x01, 0x6a, 0x18, 0x01, 0x20, 0x03, 0x28,
-	0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4a, 0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x74,
-	0x72, 0x79, 0x52, 0x01, 0x6a, 0x22, 0x7e, 0x0a, 0x07, 0x55, 0x44, 0x46, 0x4d, 0x65, 0x74, 0x61,
-	0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61,
-	0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x3d, 0x0a, 0x08, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61,
-	0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x52, 0x08, 0x6c, 0x61, 0x6e,
-	0x67, 0x75, 0x61, 0x67, 0x65, 0x22, 0xe3, 0x02, 0x0a, 0x05, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x12,
-	0x12, 0x0a, 0x03, 0x6e, 0x69, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x03,
-	0x6e, 0x69, 0x6c, 0x12, 0x0e, 0x0a, 0x01, 0x69, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00,
-	0x52, 0x01, 0x69, 0x12, 0x0e, 0x0a, 0x01, 0x66, 0x18, 0x03, 0x20, 0x01, 0x28, 0x01, 0x48, 0x00,
-	0x52, 0x01, 0x66, 0x12, 0x0e, 0x0a, 0x01, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00,
-	0x52, 0x01, 0x73, 0x12, 0x0e, 0x0a, 0x01, 0x62, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00,
-	0x52, 0x01, 0x62, 0x12, 0x14, 0x0a, 0x04, 0x62, 0x6c, 0x6f, 0x62, 0x18, 0x06, 0x20, 0x01, 0x28,
-	0x0c, 0x48, 0x00, 0x52, 0x04, 0x62, 0x6c, 0x6f, 0x62, 0x12, 0x2a, 0x0a, 0x01, 0x6c, 0x18, 0x07,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4c, 0x69, 0x73, 0x74,
-	0x48, 0x00, 0x52, 0x01, 0x6c, 0x12, 0x29, 0x0a, 0x01, 0x6d, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x61, 0x70, 0x48, 0x00, 0x52, 0x01, 0x6d,
-	0x12, 0x30, 0x0a, 0x04, 0x6a, 0x73, 0x6f, 0x6e, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x01, 0x6b, 0x12, 0x29,
+	0x0a, 0x01, 0x76, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x01, 0x76, 0x22, 0x33, 0x0a, 0x03, 0x4d, 0x61, 0x70,
+	0x12, 0x2c, 0x0a, 0x01, 0x6d, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x4d, 0x61, 0x70, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x01, 0x6d, 0x22, 0x44,
+	0x0a, 0x09, 0x4a, 0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x0c, 0x0a, 0x01, 0x6b,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x6b, 0x12, 0x29, 0x0a, 0x01, 0x76, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x52, 0x01, 0x76, 0x22, 0x35, 0x0a, 0x04, 0x4a, 0x73, 0x6f, 0x6e, 0x12, 0x2d, 0x0a, 0x01,
+	0x6a, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4a,
+	0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x01, 0x6a, 0x22, 0x7e, 0x0a, 0x07, 0x55,
+	0x44, 0x46, 0x4d, 0x65, 0x74, 0x61, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63,
+	0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x3d, 0x0a, 0x08,
+	0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4a, 0x73, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x04, 0x6a, 0x73,
-	0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x03, 0x67, 0x65, 0x6f, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x09, 0x48,
-	0x00, 0x52, 0x03, 0x67, 0x65, 0x6f, 0x12, 0x12, 0x0a, 0x03, 0x68, 0x6c, 0x6c, 0x18, 0x0b, 0x20,
-	0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x03, 0x68, 0x6c, 0x6c, 0x12, 0x1c, 0x0a, 0x08, 0x77, 0x69,
-	0x6c, 0x64, 0x63, 0x61, 0x72, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x08,
-	0x77, 0x69, 0x6c, 0x64, 0x63, 0x61, 0x72, 0x64, 0x12, 0x1c, 0x0a, 0x08, 0x69, 0x6e, 0x66, 0x69,
-	0x6e, 0x69, 0x74, 0x79, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x08, 0x69, 0x6e,
-	0x66, 0x69, 0x6e, 0x69, 0x74, 0x79, 0x42, 0x03, 0x0a, 0x01, 0x76, 0x22, 0xbf, 0x01, 0x0a, 0x03,
-	0x4b, 0x65, 0x79, 0x12, 0x1b, 0x0a, 0x06, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x06, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x88, 0x01, 0x01,
-	0x12, 0x21, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
-	0x88, 0x01, 0x01, 0x12, 0x15, 0x0a, 0x03, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
-	0x48, 0x02, 0x52, 0x03, 0x73, 0x65, 0x74, 0x88, 0x01, 0x01, 0x12, 0x36, 0x0a, 0x05, 0x76, 0x61,
-	0x6c, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67,
+	0x65, 0x52, 0x08, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x22, 0xe3, 0x02, 0x0a, 0x05,
+	0x56, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x12, 0x0a, 0x03, 0x6e, 0x69, 0x6c, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x08, 0x48, 0x00, 0x52, 0x03, 0x6e, 0x69, 0x6c, 0x12, 0x0e, 0x0a, 0x01, 0x69, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x01, 0x69, 0x12, 0x0e, 0x0a, 0x01, 0x66, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x01, 0x48, 0x00, 0x52, 0x01, 0x66, 0x12, 0x0e, 0x0a, 0x01, 0x73, 0x18, 0x04,
+	0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x01, 0x73, 0x12, 0x0e, 0x0a, 0x01, 0x62, 0x18, 0x05,
+	0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x01, 0x62, 0x12, 0x14, 0x0a, 0x04, 0x62, 0x6c, 0x6f,
+	0x62, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x04, 0x62, 0x6c, 0x6f, 0x62, 0x12,
+	0x2a, 0x0a, 0x01, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x48, 0x00, 0x52, 0x01, 0x6c, 0x12, 0x29, 0x0a, 0x01, 0x6d,
+	0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x61,
+	0x70, 0x48, 0x00, 0x52, 0x01, 0x6d, 0x12, 0x30, 0x0a, 0x04, 0x6a, 0x73, 0x6f, 0x6e, 0x18, 0x09,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4a, 0x73, 0x6f, 0x6e,
+	0x48, 0x00, 0x52, 0x04, 0x6a, 0x73, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x03, 0x67, 0x65, 0x6f, 0x18,
+	0x0a, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x03, 0x67, 0x65, 0x6f, 0x12, 0x12, 0x0a, 0x03,
+	0x68, 0x6c, 0x6c, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x03, 0x68, 0x6c, 0x6c,
+	0x12, 0x1c, 0x0a, 0x08, 0x77, 0x69, 0x6c, 0x64, 0x63, 0x61, 0x72, 0x64, 0x18, 0x0c, 0x20, 0x01,
+	0x28, 0x08, 0x48, 0x00, 0x52, 0x08, 0x77, 0x69, 0x6c, 0x64, 0x63, 0x61, 0x72, 0x64, 0x12, 0x1c,
+	0x0a, 0x08, 0x69, 0x6e, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x79, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08,
+	0x48, 0x00, 0x52, 0x08, 0x69, 0x6e, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x79, 0x42, 0x03, 0x0a, 0x01,
+	0x76, 0x22, 0xbf, 0x01, 0x0a, 0x03, 0x4b, 0x65, 0x79, 0x12, 0x1b, 0x0a, 0x06, 0x64, 0x69, 0x67,
+	0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x06, 0x64, 0x69, 0x67,
+	0x65, 0x73, 0x74, 0x88, 0x01, 0x01, 0x12, 0x21, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x09, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x88, 0x01, 0x01, 0x12, 0x15, 0x0a, 0x03, 0x73, 0x65, 0x74,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x02, 0x52, 0x03, 0x73, 0x65, 0x74, 0x88, 0x01, 0x01,
+	0x12, 0x36, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x48, 0x03, 0x52, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x88, 0x01, 0x01, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x64, 0x69, 0x67,
+	0x65, 0x73, 0x74, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x73, 0x65, 0x74, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x22, 0x87, 0x02, 0x0a, 0x06, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x2b,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x1e, 0x0a, 0x0a, 0x47,
+	0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52,
+	0x0a, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x0a, 0x45,
+	0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52,
+	0x0a, 0x45, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3a, 0x0a, 0x04, 0x42,
+	0x69, 0x6e, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x48, 0x03, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x88,
-	0x01, 0x01, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x42, 0x0c, 0x0a,
-	0x0a, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x42, 0x06, 0x0a, 0x04, 0x5f,
-	0x73, 0x65, 0x74, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x87, 0x02,
-	0x0a, 0x06, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79,
-	0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x1e, 0x0a, 0x0a, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x47, 0x65, 0x6e, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x45, 0x78, 0x70, 0x69, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3a, 0x0a, 0x04, 0x42, 0x69, 0x6e, 0x73, 0x18, 0x04, 0x20,
-	0x03, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72,
-	0x64, 0x2e, 0x42, 0x69, 0x6e, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x04, 0x42, 0x69, 0x6e,
-	0x73, 0x1a, 0x54, 0x0a, 0x09, 0x42, 0x69, 0x6e, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10,
-	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x2e, 0x42, 0x69, 0x6e, 0x73, 0x45, 0x6e, 0x74, 0x72,
+	0x79, 0x52, 0x04, 0x42, 0x69, 0x6e, 0x73, 0x1a, 0x54, 0x0a, 0x09, 0x42, 0x69, 0x6e, 0x73, 0x45,
+	0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x31, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c,
+	0x75, 0x65, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x41, 0x0a,
+	0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x1e, 0x0a, 0x0a, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74,
+	0x43, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x52, 0x0a, 0x72, 0x65, 0x73, 0x75,
+	0x6c, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x69, 0x6e, 0x44, 0x6f, 0x75, 0x62,
+	0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x69, 0x6e, 0x44, 0x6f, 0x75, 0x62, 0x74,
+	0x22, 0xcf, 0x01, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64,
+	0x12, 0x30, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x48, 0x00, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x88,
+	0x01, 0x01, 0x12, 0x39, 0x0a, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64,
+	0x48, 0x01, 0x52, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x88, 0x01, 0x01, 0x12, 0x36, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x02, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x6b, 0x65, 0x79, 0x42, 0x09, 0x0a,
+	0x07, 0x5f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x22, 0x4f, 0x0a, 0x0a, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+	0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x52, 0x02, 0x69, 0x64,
 	0x12, 0x31, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
 	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
 	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x05, 0x76, 0x61,
-	0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x41, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72,
-	0x12, 0x1e, 0x0a, 0x0a, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x05, 0x52, 0x0a, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x43, 0x6f, 0x64, 0x65,
-	0x12, 0x18, 0x0a, 0x07, 0x69, 0x6e, 0x44, 0x6f, 0x75, 0x62, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x08, 0x52, 0x07, 0x69, 0x6e, 0x44, 0x6f, 0x75, 0x62, 0x74, 0x22, 0xcf, 0x01, 0x0a, 0x0b, 0x42,
-	0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x30, 0x0a, 0x03, 0x6b, 0x65,
-	0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b,
-	0x65, 0x79, 0x48, 0x00, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x12, 0x39, 0x0a, 0x06,
-	0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x48, 0x01, 0x52, 0x06, 0x72, 0x65,
-	0x63, 0x6f, 0x72, 0x64, 0x88, 0x01, 0x01, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
-	0x72, 0x6f, 0x72, 0x48, 0x02, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42,
-	0x06, 0x0a, 0x04, 0x5f, 0x6b, 0x65, 0x79, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x4f, 0x0a, 0x0a,
-	0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x52, 0x02, 0x69, 0x64, 0x12, 0x31, 0x0a, 0x05, 0x76, 0x61,
-	0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0xc4, 0x02,
-	0x0a, 0x09, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x36, 0x0a, 0x03, 0x73,
-	0x74, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x53, 0x74, 0x64, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x03,
-	0x73, 0x74, 0x64, 0x12, 0x39, 0x0a, 0x03, 0x6d, 0x61, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x4f, 0x70,
-	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x03, 0x6d, 0x61, 0x70, 0x12, 0x3c,
-	0x0a, 0x04, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x4f, 0x70, 0x65, 0x72, 0x61,
-	0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x04, 0x6c, 0x69, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x03,
-	0x68, 0x6c, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-	0x48, 0x00, 0x52, 0x03, 0x68, 0x6c, 0x6c, 0x12, 0x45, 0x0a, 0x07, 0x62, 0x69, 0x74, 0x77, 0x69,
-	0x73, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x07, 0x62, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x42, 0x04,
-	0x0a, 0x02, 0x6f, 0x70, 0x22, 0xa6, 0x02, 0x0a, 0x0c, 0x53, 0x74, 0x64, 0x4f, 0x70, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3b, 0x0a, 0x06, 0x6f, 0x70, 0x54, 0x79, 0x70, 0x65, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65,
-	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x06, 0x6f, 0x70, 0x54, 0x79,
-	0x70, 0x65, 0x12, 0x21, 0x0a, 0x09, 0x6f, 0x70, 0x53, 0x75, 0x62, 0x54, 0x79, 0x70, 0x65, 0x18,
-	0x03, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x52, 0x09, 0x6f, 0x70, 0x53, 0x75, 0x62, 0x54, 0x79,
-	0x70, 0x65, 0x88, 0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x04, 0x20, 0x03,
-	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e,
-	0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x12, 0x1d, 0x0a, 0x07, 0x62, 0x69, 0x6e,
-	0x4e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x07, 0x62, 0x69,
-	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x3c, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x56,
-	0x61, 0x6c, 0x75, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x48, 0x02, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x88, 0x01, 0x01, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6f, 0x70, 0x53, 0x75, 0x62,
-	0x54, 0x79, 0x70, 0x65, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65,
-	0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x62, 0x69, 0x6e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x22, 0xc4, 0x01,
-	0x0a, 0x0a, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72, 0x69, 0x74, 0x65, 0x12, 0x43, 0x0a, 0x0b,
-	0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
-	0x63, 0x6f, 0x72, 0x64, 0x52, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72,
-	0x64, 0x12, 0x3e, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x12, 0x31, 0x0a, 0x03, 0x6f, 0x70, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f,
+	0x6c, 0x75, 0x65, 0x22, 0xfc, 0x02, 0x0a, 0x09, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x12, 0x36, 0x0a, 0x03, 0x73, 0x74, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52,
-	0x03, 0x6f, 0x70, 0x73, 0x22, 0x80, 0x02, 0x0a, 0x09, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
-	0x61, 0x64, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72,
-	0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42,
-	0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x0b, 0x62, 0x61, 0x74, 0x63,
-	0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x3d, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42,
-	0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
-	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d,
-	0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d,
-	0x65, 0x73, 0x12, 0x20, 0x0a, 0x0b, 0x72, 0x65, 0x61, 0x64, 0x41, 0x6c, 0x6c, 0x42, 0x69, 0x6e,
-	0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x72, 0x65, 0x61, 0x64, 0x41, 0x6c, 0x6c,
-	0x42, 0x69, 0x6e, 0x73, 0x12, 0x31, 0x0a, 0x03, 0x6f, 0x70, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28,
-	0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69,
-	0x6f, 0x6e, 0x52, 0x03, 0x6f, 0x70, 0x73, 0x22, 0x93, 0x01, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63,
-	0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68,
-	0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52,
-	0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x3f, 0x0a, 0x06,
-	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0x94, 0x02,
-	0x0a, 0x08, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x64, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x48, 0x00, 0x52, 0x03, 0x73, 0x74, 0x64, 0x12, 0x39, 0x0a, 0x03, 0x6d, 0x61, 0x70,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64,
+	0x74, 0x4d, 0x61, 0x70, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52,
+	0x03, 0x6d, 0x61, 0x70, 0x12, 0x3c, 0x0a, 0x04, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x74, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x04, 0x6c, 0x69,
+	0x73, 0x74, 0x12, 0x39, 0x0a, 0x03, 0x68, 0x6c, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x4f, 0x70, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x03, 0x68, 0x6c, 0x6c, 0x12, 0x45, 0x0a,
+	0x07, 0x62, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65,
+	0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x07, 0x62, 0x69, 0x74,
+	0x77, 0x69, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x03, 0x65, 0x78, 0x70, 0x18, 0x06, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x03, 0x65, 0x78, 0x70, 0x42, 0x04, 0x0a, 0x02,
+	0x6f, 0x70, 0x22, 0xa6, 0x02, 0x0a, 0x0c, 0x53, 0x74, 0x64, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x12, 0x3b, 0x0a, 0x06, 0x6f, 0x70, 0x54, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x06, 0x6f, 0x70, 0x54, 0x79, 0x70, 0x65,
+	0x12, 0x21, 0x0a, 0x09, 0x6f, 0x70, 0x53, 0x75, 0x62, 0x54, 0x79, 0x70, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x05, 0x48, 0x00, 0x52, 0x09, 0x6f, 0x70, 0x53, 0x75, 0x62, 0x54, 0x79, 0x70, 0x65,
+	0x88, 0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65,
+	0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x12, 0x1d, 0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61,
+	0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x07, 0x62, 0x69, 0x6e, 0x4e,
+	0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x3c, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x56, 0x61, 0x6c,
+	0x75, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x56, 0x61, 0x6c, 0x75, 0x65, 0x48, 0x02, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x88, 0x01, 0x01, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6f, 0x70, 0x53, 0x75, 0x62, 0x54, 0x79,
+	0x70, 0x65, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x42, 0x0b,
+	0x0a, 0x09, 0x5f, 0x62, 0x69, 0x6e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x22, 0xc4, 0x01, 0x0a, 0x0a,
+	0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72, 0x69, 0x74, 0x65, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61,
 	0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
 	0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
 	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f,
 	0x72, 0x64, 0x52, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12,
-	0x3c, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x24, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x20, 0x0a,
-	0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12,
-	0x22, 0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18,
-	0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e,
-	0x61, 0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41,
-	0x72, 0x67, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-	0x41, 0x72, 0x67, 0x73, 0x22, 0x8e, 0x01, 0x0a, 0x08, 0x55, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c,
-	0x65, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x02,
-	0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x72,
-	0x65, 0x61, 0x64, 0x49, 0x6e, 0x66, 0x6f, 0x18, 0x03, 0x20, 0x03, 0x28, 0x04, 0x52, 0x08, 0x72,
-	0x65, 0x61, 0x64, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1c, 0x0a, 0x09, 0x77, 0x72, 0x69, 0x74, 0x65,
-	0x49, 0x6e, 0x66, 0x6f, 0x18, 0x04, 0x20, 0x03, 0x28, 0x04, 0x52, 0x09, 0x77, 0x72, 0x69, 0x74,
-	0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x6f, 0x6e, 0x6e, 0x73, 0x49, 0x6e,
-	0x55, 0x73, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x63, 0x6f, 0x6e, 0x6e, 0x73,
-	0x49, 0x6e, 0x55, 0x73, 0x65, 0x22, 0xb7, 0x01, 0x0a, 0x04, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x12,
-	0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61,
-	0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73,
-	0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72,
-	0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65,
-	0x67, 0x65, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74,
-	0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73,
-	0x74, 0x12, 0x1c, 0x0a, 0x09, 0x52, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x04,
-	0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x52, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12,
-	0x1e, 0x0a, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x05, 0x20,
-	0x01, 0x28, 0x0d, 0x52, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x22,
-	0x57, 0x0a, 0x09, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x12, 0x12, 0x0a, 0x04,
-	0x4e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x4e, 0x61, 0x6d, 0x65,
-	0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18,
-	0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x71, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74,
-	0x69, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x17, 0x0a, 0x04, 0x62,
-	0x76, 0x61, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x04, 0x62, 0x76, 0x61,
-	0x6c, 0x88, 0x01, 0x01, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d,
-	0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x65, 0x74, 0x72, 0x79, 0x18, 0x03, 0x20,
-	0x01, 0x28, 0x08, 0x52, 0x05, 0x72, 0x65, 0x74, 0x72, 0x79, 0x12, 0x16, 0x0a, 0x06, 0x64, 0x69,
-	0x67, 0x65, 0x73, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x64, 0x69, 0x67, 0x65,
-	0x73, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x62, 0x76, 0x61, 0x6c, 0x22, 0xc6, 0x01, 0x0a, 0x0f,
-	0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x12,
-	0x14, 0x0a, 0x05, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05,
-	0x62, 0x65, 0x67, 0x69, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x64,
-	0x69, 0x67, 0x65, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x64, 0x69, 0x67,
-	0x65, 0x73, 0x74, 0x12, 0x45, 0x0a, 0x0a, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e,
-	0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50,
-	0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x0a,
-	0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x6f,
-	0x6e, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x04, 0x64, 0x6f, 0x6e, 0x65, 0x12, 0x14,
-	0x0a, 0x05, 0x72, 0x65, 0x74, 0x72, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x72,
-	0x65, 0x74, 0x72, 0x79, 0x22, 0xa1, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x39, 0x0a, 0x06, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x48, 0x01, 0x52, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64,
-	0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x09, 0x0a,
-	0x07, 0x5f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x22, 0x98, 0x01, 0x0a, 0x13, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x3e, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72, 0x69, 0x74,
+	0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
+	0x31, 0x0a, 0x03, 0x6f, 0x70, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x03, 0x6f,
+	0x70, 0x73, 0x22, 0x80, 0x02, 0x0a, 0x09, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64,
+	0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52,
+	0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x3d, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73,
+	0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73,
+	0x12, 0x20, 0x0a, 0x0b, 0x72, 0x65, 0x61, 0x64, 0x41, 0x6c, 0x6c, 0x42, 0x69, 0x6e, 0x73, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x72, 0x65, 0x61, 0x64, 0x41, 0x6c, 0x6c, 0x42, 0x69,
+	0x6e, 0x73, 0x12, 0x31, 0x0a, 0x03, 0x6f, 0x70, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x52, 0x03, 0x6f, 0x70, 0x73, 0x22, 0x93, 0x01, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63, 0x68, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x0b, 0x62,
+	0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x3f, 0x0a, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0x94, 0x02, 0x0a, 0x08,
+	0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x12, 0x43, 0x0a, 0x0b, 0x62, 0x61, 0x74, 0x63,
+	0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64,
+	0x52, 0x0b, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x3c, 0x0a,
+	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70,
+	0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x22, 0x0a,
+	0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d,
+	0x65, 0x12, 0x3f, 0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72, 0x67,
+	0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56,
+	0x61, 0x6c, 0x75, 0x65, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72,
+	0x67, 0x73, 0x22, 0x8e, 0x01, 0x0a, 0x08, 0x55, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12,
+	0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75,
+	0x73, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03,
+	0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x61,
+	0x64, 0x49, 0x6e, 0x66, 0x6f, 0x18, 0x03, 0x20, 0x03, 0x28, 0x04, 0x52, 0x08, 0x72, 0x65, 0x61,
+	0x64, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1c, 0x0a, 0x09, 0x77, 0x72, 0x69, 0x74, 0x65, 0x49, 0x6e,
+	0x66, 0x6f, 0x18, 0x04, 0x20, 0x03, 0x28, 0x04, 0x52, 0x09, 0x77, 0x72, 0x69, 0x74, 0x65, 0x49,
+	0x6e, 0x66, 0x6f, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x6f, 0x6e, 0x6e, 0x73, 0x49, 0x6e, 0x55, 0x73,
+	0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x63, 0x6f, 0x6e, 0x6e, 0x73, 0x49, 0x6e,
+	0x55, 0x73, 0x65, 0x22, 0xb7, 0x01, 0x0a, 0x04, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x12, 0x0a, 0x04,
+	0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+	0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x18, 0x02,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x69, 0x76,
+	0x69, 0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65,
+	0x73, 0x12, 0x1c, 0x0a, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x03,
+	0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x12,
+	0x1c, 0x0a, 0x09, 0x52, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x0d, 0x52, 0x09, 0x52, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x1e, 0x0a,
+	0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x0d, 0x52, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x22, 0x57, 0x0a,
+	0x09, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x4e, 0x61,
+	0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c,
+	0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07,
+	0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73,
+	0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x71, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74,
+	0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x17, 0x0a, 0x04, 0x62, 0x76, 0x61,
+	0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x04, 0x62, 0x76, 0x61, 0x6c, 0x88,
+	0x01, 0x01, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x02,
+	0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x65, 0x74, 0x72, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x05, 0x72, 0x65, 0x74, 0x72, 0x79, 0x12, 0x16, 0x0a, 0x06, 0x64, 0x69, 0x67, 0x65,
+	0x73, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74,
+	0x42, 0x07, 0x0a, 0x05, 0x5f, 0x62, 0x76, 0x61, 0x6c, 0x22, 0xc6, 0x01, 0x0a, 0x0f, 0x50, 0x61,
+	0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x12, 0x14, 0x0a,
+	0x05, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x62, 0x65,
+	0x67, 0x69, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0d, 0x52, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x64, 0x69, 0x67,
+	0x65, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x64, 0x69, 0x67, 0x65, 0x73,
+	0x74, 0x12, 0x45, 0x0a, 0x0a, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18,
+	0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x61, 0x72,
+	0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x0a, 0x70, 0x61,
+	0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x6f, 0x6e, 0x65,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x04, 0x64, 0x6f, 0x6e, 0x65, 0x12, 0x14, 0x0a, 0x05,
+	0x72, 0x65, 0x74, 0x72, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x72, 0x65, 0x74,
+	0x72, 0x79, 0x22, 0xa1, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
+	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x39, 0x0a, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x48, 0x01, 0x52, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x88, 0x01,
+	0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x09, 0x0a, 0x07, 0x5f,
+	0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x22, 0x98, 0x01, 0x0a, 0x13, 0x41, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38,
+	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79,
+	0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x22, 0x82, 0x01, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47,
+	0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65,
+	0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x7f, 0x0a, 0x16, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
 	0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
 	0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
 	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69,
 	0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65,
 	0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
 	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b,
-	0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61,
-	0x6d, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61,
-	0x6d, 0x65, 0x73, 0x22, 0x82, 0x01, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x47, 0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x83, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48,
+	0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x1b, 0x0a, 0x06, 0x65,
+	0x78, 0x69, 0x73, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52, 0x06, 0x65,
+	0x78, 0x69, 0x73, 0x74, 0x73, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x65, 0x78, 0x69, 0x73, 0x74, 0x73, 0x22, 0x80, 0x01,
+	0x0a, 0x16, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74,
+	0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x22, 0x86, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x07, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52, 0x07, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x64,
+	0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x0a, 0x0a,
+	0x08, 0x5f, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x64, 0x22, 0x7f, 0x0a, 0x15, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a,
+	0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0xc2, 0x01, 0x0a, 0x17, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x3f,
+	0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x03, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22,
+	0xac, 0x01, 0x0a, 0x13, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12,
+	0x2d, 0x0a, 0x04, 0x42, 0x69, 0x6e, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x69, 0x6e, 0x52, 0x04, 0x42, 0x69, 0x6e, 0x73, 0x22, 0x84,
+	0x02, 0x0a, 0x0c, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x12,
+	0x34, 0x0a, 0x02, 0x62, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x48, 0x00, 0x52, 0x02,
+	0x62, 0x72, 0x88, 0x01, 0x01, 0x12, 0x35, 0x0a, 0x02, 0x62, 0x77, 0x18, 0x02, 0x20, 0x01, 0x28,
 	0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b,
-	0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x48, 0x01, 0x52, 0x02, 0x62, 0x77, 0x88, 0x01, 0x01, 0x12, 0x36, 0x0a, 0x02,
+	0x62, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x7f, 0x0a, 0x16, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03,
-	0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x42, 0x61, 0x74, 0x63, 0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x48, 0x02, 0x52, 0x02, 0x62,
+	0x64, 0x88, 0x01, 0x01, 0x12, 0x33, 0x0a, 0x02, 0x62, 0x75, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46,
+	0x48, 0x03, 0x52, 0x02, 0x62, 0x75, 0x88, 0x01, 0x01, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x62, 0x72,
+	0x42, 0x05, 0x0a, 0x03, 0x5f, 0x62, 0x77, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x62, 0x64, 0x42, 0x05,
+	0x0a, 0x03, 0x5f, 0x62, 0x75, 0x22, 0x97, 0x01, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61,
+	0x74, 0x63, 0x68, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x3c, 0x0a, 0x07, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f,
+	0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x07, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x22,
+	0x9e, 0x01, 0x0a, 0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x3b, 0x0a, 0x07, 0x72, 0x65, 0x63,
+	0x6f, 0x72, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x07, 0x72,
+	0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x22, 0x98, 0x03, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x74,
+	0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74, 0x4e,
+	0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d,
+	0x65, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3d, 0x0a, 0x09, 0x69,
+	0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1f,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x52,
+	0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x5b, 0x0a, 0x13, 0x69, 0x6e,
+	0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79,
+	0x70, 0x65, 0x52, 0x13, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x08,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43,
+	0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x22, 0x60, 0x0a, 0x1c, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x6e,
+	0x64, 0x65, 0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xac, 0x01,
+	0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c,
+	0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x5e, 0x0a, 0x1a,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x83, 0x01, 0x0a, 0x17, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x73,
+	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88,
+	0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x2f, 0x0a, 0x03,
+	0x54, 0x78, 0x6e, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x22, 0x45, 0x0a,
+	0x16, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2b, 0x0a, 0x03, 0x74, 0x78, 0x6e, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54, 0x78, 0x6e, 0x52,
+	0x03, 0x74, 0x78, 0x6e, 0x22, 0xcd, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x3a, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74,
+	0x75, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74,
+	0x61, 0x74, 0x75, 0x73, 0x12, 0x34, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54, 0x78, 0x6e, 0x53, 0x74,
+	0x61, 0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x22, 0x44, 0x0a, 0x15, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2b, 0x0a,
+	0x03, 0x74, 0x78, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x54, 0x78, 0x6e, 0x52, 0x03, 0x74, 0x78, 0x6e, 0x22, 0xcb, 0x01, 0x0a, 0x16, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x73,
 	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01,
 	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
 	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f,
-	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x1b, 0x0a,
-	0x06, 0x65, 0x78, 0x69, 0x73, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52,
-	0x06, 0x65, 0x78, 0x69, 0x73, 0x74, 0x73, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x65, 0x78, 0x69, 0x73, 0x74, 0x73, 0x22,
-	0x80, 0x01, 0x0a, 0x16, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x65, 0x6c,
-	0x65, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b,
-	0x65, 0x79, 0x22, 0x86, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
-	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
+	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x39, 0x0a,
+	0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e,
 	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x07, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65,
-	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52, 0x07, 0x65, 0x78, 0x69, 0x73, 0x74,
-	0x65, 0x64, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42,
-	0x0a, 0x0a, 0x08, 0x5f, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x64, 0x22, 0x7f, 0x0a, 0x15, 0x41,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74,
-	0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0xac, 0x01, 0x0a,
-	0x13, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74,
-	0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x2d, 0x0a, 0x04,
-	0x42, 0x69, 0x6e, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x42, 0x69, 0x6e, 0x52, 0x04, 0x42, 0x69, 0x6e, 0x73, 0x22, 0x84, 0x02, 0x0a, 0x0c,
-	0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x12, 0x34, 0x0a, 0x02,
-	0x62, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x48, 0x00, 0x52, 0x02, 0x62, 0x72, 0x88,
-	0x01, 0x01, 0x12, 0x35, 0x0a, 0x02, 0x62, 0x77, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
+	0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x34, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74,
+	0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54,
+	0x78, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x66, 0x0a, 0x22, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54,
+	0x6f, 0x42, 0x61, 0x73, 0x65, 0x36, 0x34, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x40,
+	0x0a, 0x0a, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73,
+	0x73, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+	0x22, 0x7f, 0x0a, 0x23, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x70,
+	0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f, 0x42, 0x61, 0x73, 0x65, 0x36, 0x34, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
+	0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12,
+	0x16, 0x0a, 0x06, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x06, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x22, 0xc5, 0x01, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54,
+	0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38,
+	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x57, 0x72, 0x69, 0x74, 0x65,
-	0x48, 0x01, 0x52, 0x02, 0x62, 0x77, 0x88, 0x01, 0x01, 0x12, 0x36, 0x0a, 0x02, 0x62, 0x64, 0x18,
-	0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74,
-	0x63, 0x68, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x48, 0x02, 0x52, 0x02, 0x62, 0x64, 0x88, 0x01,
-	0x01, 0x12, 0x33, 0x0a, 0x02, 0x62, 0x75, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1e, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x55, 0x44, 0x46, 0x48, 0x03, 0x52,
-	0x02, 0x62, 0x75, 0x88, 0x01, 0x01, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x62, 0x72, 0x42, 0x05, 0x0a,
-	0x03, 0x5f, 0x62, 0x77, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x62, 0x64, 0x42, 0x05, 0x0a, 0x03, 0x5f,
-	0x62, 0x75, 0x22, 0x97, 0x01, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3c,
-	0x0a, 0x07, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
-	0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72,
-	0x61, 0x74, 0x65, 0x52, 0x07, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x22, 0x9e, 0x01, 0x0a,
-	0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f,
-	0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
-	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x3b, 0x0a, 0x07, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64,
-	0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x42,
-	0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x07, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x98, 0x03,
-	0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74,
-	0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a,
-	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
 	0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65,
 	0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d,
 	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d,
 	0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65,
-	0x12, 0x1c, 0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x18,
-	0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3d, 0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65,
-	0x78, 0x54, 0x79, 0x70, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1f, 0x2e, 0x63, 0x6f,
+	0x12, 0x26, 0x0a, 0x0c, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x5f, 0x6e, 0x61, 0x6e, 0x6f, 0x73,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x0b, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65,
+	0x4e, 0x61, 0x6e, 0x6f, 0x73, 0x88, 0x01, 0x01, 0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x62, 0x65, 0x66,
+	0x6f, 0x72, 0x65, 0x5f, 0x6e, 0x61, 0x6e, 0x6f, 0x73, 0x22, 0x5d, 0x0a, 0x19, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xd3, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44,
+	0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61,
+	0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x75, 0x64, 0x66, 0x42, 0x6f, 0x64, 0x79,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x75, 0x64, 0x66, 0x42, 0x6f, 0x64, 0x79, 0x12,
+	0x3d, 0x0a, 0x08, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67,
+	0x75, 0x61, 0x67, 0x65, 0x52, 0x08, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x22, 0x60,
+	0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73,
+	0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
+	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x22, 0x76, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f,
+	0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x52, 0x09, 0x69, 0x6e,
-	0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x5b, 0x0a, 0x13, 0x69, 0x6e, 0x64, 0x65, 0x78,
-	0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x18, 0x07,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52,
-	0x13, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-	0x54, 0x79, 0x70, 0x65, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x08, 0x20, 0x03, 0x28,
+	0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63,
+	0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x5c, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
+	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06,
+	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x53, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
 	0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74,
-	0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x22, 0x60, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0x95, 0x01, 0x0a, 0x18,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46,
 	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f,
 	0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
 	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45,
 	0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01,
-	0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xac, 0x01, 0x0a, 0x19, 0x41,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x69,
-	0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
-	0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x5e, 0x0a, 0x1a, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52,
+	0x12, 0x37, 0x0a, 0x07, 0x75, 0x64, 0x66, 0x4c, 0x69, 0x73, 0x74, 0x18, 0x02, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x55, 0x44, 0x46, 0x4d, 0x65, 0x74, 0x61,
+	0x52, 0x07, 0x75, 0x64, 0x66, 0x4c, 0x69, 0x73, 0x74, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x22, 0xfb, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a,
+	0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61,
+	0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x22, 0x0a, 0x0c,
+	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65,
+	0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67,
+	0x73, 0x22, 0x94, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55,
+	0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x33, 0x0a, 0x06, 0x72, 0x65, 0x73,
+	0x75, 0x6c, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x9d, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
+	0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f,
+	0x72, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f,
+	0x72, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28,
+	0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x22, 0x5f, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x52,
 	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
 	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
 	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
 	0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42,
-	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xc5, 0x01, 0x0a, 0x18, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e,
-	0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18,
-	0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x26, 0x0a, 0x0c, 0x62, 0x65, 0x66, 0x6f,
-	0x72, 0x65, 0x5f, 0x6e, 0x61, 0x6e, 0x6f, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00,
-	0x52, 0x0b, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x4e, 0x61, 0x6e, 0x6f, 0x73, 0x88, 0x01, 0x01,
-	0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x5f, 0x6e, 0x61, 0x6e, 0x6f,
-	0x73, 0x22, 0x5d, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x72,
-	0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
-	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x22, 0xd3, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65,
-	0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x69, 0x0a, 0x18, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d,
+	0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04,
+	0x75, 0x73, 0x65, 0x72, 0x22, 0x5d, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x22, 0x8b, 0x01, 0x0a, 0x1e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64,
+	0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72,
+	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72,
+	0x64, 0x22, 0x63, 0x0a, 0x1f, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x68,
+	0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
+	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06,
+	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x81, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d,
+	0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04,
+	0x75, 0x73, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x03, 0x20,
+	0x03, 0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x22, 0x5f, 0x0a, 0x1b, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01,
+	0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x82, 0x01, 0x0a, 0x1b,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52,
+	0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f,
+	0x6c, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73,
+	0x22, 0x60, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76,
+	0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x22, 0x79, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
 	0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
 	0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70,
-	0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a,
-	0x07, 0x75, 0x64, 0x66, 0x42, 0x6f, 0x64, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
-	0x75, 0x64, 0x66, 0x42, 0x6f, 0x64, 0x79, 0x12, 0x3d, 0x0a, 0x08, 0x6c, 0x61, 0x6e, 0x67, 0x75,
-	0x61, 0x67, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x17, 0x0a, 0x04, 0x75,
+	0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x75, 0x73, 0x65,
+	0x72, 0x88, 0x01, 0x01, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x22, 0x9d, 0x01,
+	0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79,
+	0x55, 0x73, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x3c, 0x0a, 0x09, 0x75, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c,
+	0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x55, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x09, 0x75, 0x73, 0x65, 0x72, 0x52, 0x6f,
+	0x6c, 0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x85, 0x01,
+	0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79,
+	0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52,
+	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1f, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x08, 0x72, 0x6f, 0x6c,
+	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x72, 0x6f, 0x6c,
+	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x91, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f,
+	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x30, 0x0a,
+	0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x42,
+	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x90, 0x02, 0x0a, 0x1a, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c,
+	0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12,
+	0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x69,
+	0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73,
+	0x12, 0x1c, 0x0a, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x04, 0x20,
+	0x03, 0x28, 0x09, 0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x12, 0x1c,
+	0x0a, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x0d, 0x52, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x1e, 0x0a, 0x0a,
+	0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x22, 0x5f, 0x0a, 0x1b,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52,
+	0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x71, 0x0a,
+	0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f,
+	0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x52, 0x08, 0x6c, 0x61,
-	0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x22, 0x60, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72,
-	0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08,
-	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x76, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75,
+	0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65,
+	0x22, 0x5d, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f,
+	0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22,
+	0xb9, 0x01, 0x0a, 0x1f, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61,
+	0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75,
 	0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20,
 	0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x20,
-	0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65,
-	0x22, 0x5c, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f,
-	0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a,
+	0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72,
+	0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x52,
+	0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x22, 0x64, 0x0a, 0x20, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69,
+	0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x22, 0xba, 0x01, 0x0a, 0x20, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52,
+	0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64,
+	0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3f, 0x0a,
+	0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65,
+	0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x22, 0x65,
+	0x0a, 0x21, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b,
+	0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48,
+	0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x93, 0x01, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64,
+	0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a,
+	0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09,
+	0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x22, 0x61, 0x0a, 0x1d, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77,
+	0x6c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05,
 	0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
 	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x53,
-	0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x55,
-	0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x22, 0x95, 0x01, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x37, 0x0a, 0x07, 0x75, 0x64, 0x66, 0x4c,
-	0x69, 0x73, 0x74, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x55, 0x44, 0x46, 0x4d, 0x65, 0x74, 0x61, 0x52, 0x07, 0x75, 0x64, 0x66, 0x4c, 0x69, 0x73,
-	0x74, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xfb, 0x01, 0x0a, 0x1a,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63,
-	0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x19, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b,
-	0x65, 0x79, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d,
-	0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
-	0x4e, 0x61, 0x6d, 0x65, 0x12, 0x22, 0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-	0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63,
-	0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73,
-	0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x22, 0x94, 0x01, 0x0a, 0x1b, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74,
-	0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01,
-	0x01, 0x12, 0x33, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x06,
-	0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x22, 0x9d, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72,
-	0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73,
-	0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x1a,
-	0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f,
-	0x6c, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73,
-	0x22, 0x5f, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65,
-	0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xb0,
+	0x01, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x51,
+	0x75, 0x6f, 0x74, 0x61, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52,
+	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74,
+	0x61, 0x12, 0x1e, 0x0a, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74,
+	0x61, 0x22, 0x5e, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65,
+	0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
 	0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
 	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65,
 	0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x22, 0x69, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72,
-	0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a,
-	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x22, 0x5d, 0x0a, 0x19,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65,
-	0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01,
-	0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x8b, 0x01, 0x0a, 0x1e,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50,
-	0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21,
+	0x72, 0x22, 0x8e, 0x02, 0x0a, 0x14, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53,
+	0x63, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x53, 0x63, 0x61, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1a, 0x0a, 0x08,
+	0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08,
+	0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x12, 0x54, 0x0a, 0x0f, 0x70, 0x61, 0x72, 0x74,
+	0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69,
+	0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x48, 0x00, 0x52, 0x0f, 0x70, 0x61, 0x72, 0x74,
+	0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x88, 0x01, 0x01, 0x42, 0x12,
+	0x0a, 0x10, 0x5f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74,
+	0x65, 0x72, 0x22, 0xa4, 0x01, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x34,
+	0x0a, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65,
-	0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x1a, 0x0a,
-	0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x22, 0x63, 0x0a, 0x1f, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73,
-	0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x81,
-	0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e,
-	0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a,
-	0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05,
-	0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c,
-	0x65, 0x73, 0x22, 0x5f, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47,
-	0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52,
-	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x22, 0x82, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x52, 0x06, 0x72, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x12, 0x31, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
+	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x17, 0x0a, 0x04, 0x62, 0x76, 0x61, 0x6c, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x04, 0x62, 0x76, 0x61, 0x6c, 0x88, 0x01, 0x01,
+	0x42, 0x07, 0x0a, 0x05, 0x5f, 0x62, 0x76, 0x61, 0x6c, 0x22, 0xfb, 0x01, 0x0a, 0x15, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75,
 	0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20,
 	0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12,
-	0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73,
-	0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
-	0x09, 0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x22, 0x60, 0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45,
-	0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01,
-	0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x79, 0x0a, 0x1a, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72,
-	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3d,
+	0x0a, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65,
+	0x6e, 0x74, 0x52, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x54, 0x0a,
+	0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x61,
+	0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x48, 0x00, 0x52,
+	0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72,
+	0x88, 0x01, 0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f,
+	0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x22, 0x9a, 0x01, 0x0a, 0x1e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67,
+	0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3d, 0x0a, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65,
+	0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x12, 0x17, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x48, 0x00, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x88, 0x01, 0x01, 0x42, 0x07, 0x0a, 0x05,
-	0x5f, 0x75, 0x73, 0x65, 0x72, 0x22, 0x9d, 0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f,
-	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x3c, 0x0a,
-	0x09, 0x75, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
-	0x32, 0x1e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x55, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65,
-	0x52, 0x09, 0x75, 0x73, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x85, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69,
-	0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x1f, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x48, 0x00, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01,
-	0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x91, 0x01,
-	0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79,
-	0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a,
-	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x30, 0x0a, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x02,
-	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x6f, 0x6c, 0x65,
-	0x52, 0x05, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x22, 0x90, 0x02, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43,
-	0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72,
-	0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72,
-	0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69,
-	0x6c, 0x65, 0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72,
-	0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x61, 0x6c, 0x6c, 0x6f,
-	0x77, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x61, 0x6c, 0x6c,
-	0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51, 0x75,
-	0x6f, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x72, 0x65, 0x61, 0x64, 0x51,
-	0x75, 0x6f, 0x74, 0x61, 0x12, 0x1e, 0x0a, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f,
-	0x74, 0x61, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x77, 0x72, 0x69, 0x74, 0x65, 0x51,
-	0x75, 0x6f, 0x74, 0x61, 0x22, 0x5f, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x52, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65,
+	0x6d, 0x65, 0x6e, 0x74, 0x22, 0x82, 0x01, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52,
+	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x31, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xe5, 0x01, 0x0a, 0x1c, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x45, 0x78, 0x65, 0x63,
+	0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x45, 0x0a, 0x06, 0x70, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2d, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x42, 0x61, 0x63, 0x6b, 0x67, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x45, 0x78, 0x65, 0x63,
+	0x75, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x12, 0x3d, 0x0a, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74,
+	0x65, 0x6d, 0x65, 0x6e, 0x74, 0x52, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74,
+	0x12, 0x3f, 0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x03,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4f, 0x70, 0x65, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x73, 0x22, 0x8d, 0x01, 0x0a, 0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
 	0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01,
 	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
 	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48,
-	0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x71, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08,
-	0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
-	0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0x5d, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73,
+	0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x16, 0x0a, 0x06, 0x74,
+	0x61, 0x73, 0x6b, 0x49, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x74, 0x61, 0x73,
+	0x6b, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x63, 0x61, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x04, 0x73, 0x63, 0x61, 0x6e, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x22, 0x40, 0x0a, 0x10, 0x4e, 0x6f, 0x64, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x50, 0x72, 0x6f,
+	0x67, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x65, 0x72,
+	0x63, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x70, 0x65, 0x72, 0x63,
+	0x65, 0x6e, 0x74, 0x22, 0xdf, 0x01, 0x0a, 0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x54, 0x61, 0x73, 0x6b, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73,
 	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01,
 	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
 	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f,
-	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a,
-	0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xb9, 0x01, 0x0a, 0x1f, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c,
-	0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
-	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61,
-	0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61,
-	0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73,
-	0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x72,
-	0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65,
-	0x67, 0x65, 0x73, 0x22, 0x64, 0x0a, 0x20, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
-	0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42,
-	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xba, 0x01, 0x0a, 0x20, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69,
-	0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c,
-	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c,
-	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3f, 0x0a, 0x0a, 0x70, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65,
-	0x67, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x38, 0x0a,
+	0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52,
+	0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x42, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x67, 0x72,
+	0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x52, 0x0a, 0x70, 0x72, 0x69, 0x76,
-	0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x22, 0x65, 0x0a, 0x21, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65,
-	0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x93, 0x01,
-	0x0a, 0x1c, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c,
-	0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c,
-	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c,
-	0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69,
-	0x73, 0x74, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x6c,
-	0x69, 0x73, 0x74, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70,
+	0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73,
+	0x73, 0x52, 0x08, 0x70, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xab, 0x01, 0x0a, 0x1f, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74,
+	0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x12, 0x1c, 0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x12,
+	0x12, 0x0a, 0x04, 0x64, 0x72, 0x6f, 0x70, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x04, 0x64,
+	0x72, 0x6f, 0x70, 0x22, 0x8f, 0x01, 0x0a, 0x1d, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x55, 0x44, 0x46, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x66,
+	0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
+	0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d,
+	0x65, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x72, 0x6f, 0x70, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52,
+	0x04, 0x64, 0x72, 0x6f, 0x70, 0x22, 0x4f, 0x0a, 0x21, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61,
+	0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x74, 0x61,
+	0x73, 0x6b, 0x49, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x74, 0x61, 0x73, 0x6b,
+	0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x63, 0x61, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08,
+	0x52, 0x04, 0x73, 0x63, 0x61, 0x6e, 0x22, 0xa0, 0x01, 0x0a, 0x22, 0x41, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x53,
+	0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x38, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x54, 0x61, 0x73,
+	0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x42,
+	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x8e, 0x01, 0x0a, 0x14, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08,
+	0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08,
+	0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73, 0x12, 0x17, 0x0a, 0x04, 0x6e, 0x6f, 0x64, 0x65,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x6e, 0x6f, 0x64, 0x65, 0x88, 0x01,
+	0x01, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x6e, 0x6f, 0x64, 0x65, 0x22, 0xe9, 0x01, 0x0a, 0x15, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70,
 	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20,
 	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
 	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
-	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06,
-	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xb0, 0x01, 0x0a, 0x19, 0x41, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x64, 0x6d, 0x69,
-	0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
-	0x1a, 0x0a, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x08, 0x72, 0x6f, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x72,
-	0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09,
-	0x72, 0x65, 0x61, 0x64, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x1e, 0x0a, 0x0a, 0x77, 0x72, 0x69,
-	0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x77,
-	0x72, 0x69, 0x74, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x22, 0x5e, 0x0a, 0x1a, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
-	0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42,
-	0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x8e, 0x02, 0x0a, 0x14, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x63, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x53, 0x63, 0x61, 0x6e, 0x50, 0x6f,
-	0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09,
-	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x65,
-	0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x65, 0x74,
-	0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73,
-	0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73,
-	0x12, 0x54, 0x0a, 0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c,
-	0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x52, 0x0a, 0x07,
+	0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x38, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e,
+	0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x52, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73,
+	0x1a, 0x3a, 0x0a, 0x0c, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79,
+	0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b,
+	0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x08, 0x0a, 0x06,
+	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xdb, 0x01, 0x0a, 0x04, 0x4e, 0x6f, 0x64, 0x65, 0x12,
+	0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+	0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x16, 0x0a,
+	0x06, 0x72, 0x61, 0x63, 0x6b, 0x49, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05, 0x52, 0x06, 0x72,
+	0x61, 0x63, 0x6b, 0x49, 0x64, 0x12, 0x3b, 0x0a, 0x05, 0x72, 0x61, 0x63, 0x6b, 0x73, 0x18, 0x04,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4e, 0x6f, 0x64, 0x65,
+	0x2e, 0x52, 0x61, 0x63, 0x6b, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x05, 0x72, 0x61, 0x63,
+	0x6b, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x18, 0x05, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x06, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x1a, 0x38, 0x0a, 0x0a, 0x52, 0x61,
+	0x63, 0x6b, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
+	0x3a, 0x02, 0x38, 0x01, 0x22, 0x17, 0x0a, 0x15, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x8c, 0x01,
+	0x0a, 0x16, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x6f, 0x64, 0x65, 0x73,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45,
+	0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01,
+	0x12, 0x30, 0x0a, 0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x1a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52, 0x05, 0x6e, 0x6f, 0x64,
+	0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xf7, 0x01, 0x0a,
+	0x0d, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x12,
+	0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61,
+	0x6d, 0x65, 0x12, 0x2c, 0x0a, 0x11, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x46, 0x61, 0x63, 0x74, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x52, 0x11, 0x72,
+	0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x61, 0x63, 0x74, 0x6f, 0x72,
+	0x12, 0x24, 0x0a, 0x0d, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x45, 0x6e, 0x67, 0x69, 0x6e,
+	0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x45, 0x6e, 0x67, 0x69, 0x6e, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+	0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73,
+	0x12, 0x24, 0x0a, 0x0d, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+	0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x4f,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74,
+	0x6f, 0x6e, 0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x74, 0x6f, 0x6d, 0x62,
+	0x73, 0x74, 0x6f, 0x6e, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x73, 0x74, 0x6f, 0x70, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x73, 0x74, 0x6f, 0x70,
+	0x57, 0x72, 0x69, 0x74, 0x65, 0x73, 0x22, 0x56, 0x0a, 0x1a, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x66, 0x6f,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0xa4,
+	0x01, 0x0a, 0x1b, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36,
+	0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x43, 0x0a, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52,
+	0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x99, 0x02, 0x0a, 0x07, 0x53, 0x65, 0x74, 0x49, 0x6e, 0x66,
+	0x6f, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12,
+	0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+	0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x12, 0x1e, 0x0a,
+	0x0a, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x0a, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x73, 0x12, 0x28, 0x0a,
+	0x0f, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x44, 0x61, 0x74, 0x61, 0x42, 0x79, 0x74, 0x65, 0x73,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0f, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x44, 0x61,
+	0x74, 0x61, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x28, 0x0a, 0x0f, 0x64, 0x65, 0x76, 0x69, 0x63,
+	0x65, 0x44, 0x61, 0x74, 0x61, 0x42, 0x79, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x0f, 0x64, 0x65, 0x76, 0x69, 0x63, 0x65, 0x44, 0x61, 0x74, 0x61, 0x42, 0x79, 0x74, 0x65,
+	0x73, 0x12, 0x24, 0x0a, 0x0d, 0x64, 0x61, 0x74, 0x61, 0x55, 0x73, 0x65, 0x64, 0x42, 0x79, 0x74,
+	0x65, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x64, 0x61, 0x74, 0x61, 0x55, 0x73,
+	0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x28, 0x0a, 0x0f, 0x64, 0x69, 0x73, 0x61, 0x62,
+	0x6c, 0x65, 0x45, 0x76, 0x69, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08,
+	0x52, 0x0f, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x45, 0x76, 0x69, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x22, 0x6e, 0x0a, 0x14, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65,
+	0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72,
-	0x48, 0x00, 0x52, 0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c,
-	0x74, 0x65, 0x72, 0x88, 0x01, 0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x70, 0x61, 0x72, 0x74, 0x69,
-	0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x22, 0xa4, 0x01, 0x0a, 0x17, 0x41,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x34, 0x0a, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65,
-	0x63, 0x6f, 0x72, 0x64, 0x52, 0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x12, 0x31, 0x0a, 0x05,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f,
+	0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x22, 0x8c, 0x01, 0x0a, 0x15, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53,
+	0x65, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x88, 0x01, 0x01, 0x12, 0x31, 0x0a, 0x04, 0x73, 0x65, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x53, 0x65, 0x74, 0x49, 0x6e, 0x66, 0x6f,
+	0x52, 0x04, 0x73, 0x65, 0x74, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x22, 0xcd, 0x02, 0x0a, 0x09, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1c,
+	0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07,
+	0x73, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73,
+	0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x69,
+	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x69, 0x6e,
+	0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3d, 0x0a, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70,
+	0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x52, 0x09, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x54,
+	0x79, 0x70, 0x65, 0x12, 0x51, 0x0a, 0x0e, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x54, 0x79, 0x70, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12,
-	0x17, 0x0a, 0x04, 0x62, 0x76, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52,
-	0x04, 0x62, 0x76, 0x61, 0x6c, 0x88, 0x01, 0x01, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x62, 0x76, 0x61,
-	0x6c, 0x22, 0xfb, 0x01, 0x0a, 0x15, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51,
-	0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x06, 0x70,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
+	0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x0e, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x07, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f,
+	0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74,
+	0x61, 0x74, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65,
+	0x22, 0x71, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x38, 0x0a, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06,
-	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3d, 0x0a, 0x09, 0x73, 0x74, 0x61, 0x74, 0x65, 0x6d,
-	0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x66, 0x6f, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x22, 0x97, 0x01, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x36, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x12, 0x39, 0x0a, 0x07, 0x69, 0x6e, 0x64, 0x65,
+	0x78, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
 	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x52, 0x09, 0x73, 0x74, 0x61, 0x74,
-	0x65, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x54, 0x0a, 0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69,
-	0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x50, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46,
-	0x69, 0x6c, 0x74, 0x65, 0x72, 0x48, 0x00, 0x52, 0x0f, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69,
-	0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x88, 0x01, 0x01, 0x42, 0x12, 0x0a, 0x10, 0x5f,
-	0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x22,
-	0x19, 0x0a, 0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73,
-	0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x34, 0x0a, 0x18, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
-	0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
-	0x22, 0xd0, 0x07, 0x0a, 0x17, 0x42, 0x61, 0x63, 0x6b, 0x67, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x45,
-	0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3d, 0x0a, 0x07,
-	0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e,
+	0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x07, 0x69, 0x6e, 0x64, 0x65,
+	0x78, 0x65, 0x73, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x19, 0x0a,
+	0x17, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f,
+	0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x34, 0x0a, 0x18, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x84,
+	0x08, 0x0a, 0x17, 0x42, 0x61, 0x63, 0x6b, 0x67, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x45, 0x78, 0x65,
+	0x63, 0x75, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3d, 0x0a, 0x07, 0x72, 0x65,
+	0x70, 0x6c, 0x69, 0x63, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x52, 0x07, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61,
+	0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e,
 	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x52, 0x07, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x12, 0x40, 0x0a, 0x0a, 0x72,
-	0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x52,
+	0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x12, 0x40, 0x0a, 0x0a, 0x72,
+	0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32,
 	0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41,
-	0x50, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x12, 0x40, 0x0a,
-	0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x18, 0x03, 0x20, 0x01, 0x28,
-	0x0e, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64,
-	0x65, 0x53, 0x43, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12,
-	0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28,
-	0x08, 0x52, 0x08, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x12, 0x23, 0x0a, 0x0a, 0x65,
-	0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x48,
-	0x00, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01,
-	0x12, 0x27, 0x0a, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74,
-	0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x01, 0x52, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54,
-	0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x07, 0x73, 0x65, 0x6e,
-	0x64, 0x4b, 0x65, 0x79, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x48, 0x02, 0x52, 0x07, 0x73, 0x65,
-	0x6e, 0x64, 0x4b, 0x65, 0x79, 0x88, 0x01, 0x01, 0x12, 0x5d, 0x0a, 0x12, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x03,
-	0x52, 0x12, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63,
-	0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x57, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x09, 0x20, 0x01, 0x28,
-	0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x48, 0x04, 0x52, 0x10, 0x67, 0x65, 0x6e,
-	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x88, 0x01, 0x01,
-	0x12, 0x48, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18,
-	0x0a, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d,
-	0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x48, 0x05, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d,
-	0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x88, 0x01, 0x01, 0x12, 0x23, 0x0a, 0x0a, 0x67, 0x65,
-	0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x06,
-	0x52, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12,
-	0x23, 0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0c, 0x20,
-	0x01, 0x28, 0x0d, 0x48, 0x07, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f,
-	0x6e, 0x88, 0x01, 0x01, 0x12, 0x29, 0x0a, 0x0d, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41,
-	0x6c, 0x6c, 0x4f, 0x70, 0x73, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x48, 0x08, 0x52, 0x0d, 0x72,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4f, 0x70, 0x73, 0x88, 0x01, 0x01, 0x12,
-	0x29, 0x0a, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
-	0x18, 0x0e, 0x20, 0x01, 0x28, 0x08, 0x48, 0x09, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c,
-	0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x88, 0x01, 0x01, 0x12, 0x15, 0x0a, 0x03, 0x78, 0x64,
-	0x72, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x08, 0x48, 0x0a, 0x52, 0x03, 0x78, 0x64, 0x72, 0x88, 0x01,
-	0x01, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
-	0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75,
-	0x74, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42, 0x15, 0x0a,
-	0x13, 0x5f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63,
-	0x74, 0x69, 0x6f, 0x6e, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x42, 0x0e, 0x0a, 0x0c, 0x5f, 0x63, 0x6f,
-	0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x67, 0x65,
-	0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x65, 0x78, 0x70,
-	0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x0a, 0x0e, 0x5f, 0x72, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4f, 0x70, 0x73, 0x42, 0x10, 0x0a, 0x0e, 0x5f, 0x64, 0x75,
-	0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x42, 0x06, 0x0a, 0x04, 0x5f,
-	0x78, 0x64, 0x72, 0x22, 0x9d, 0x02, 0x0a, 0x0b, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52,
-	0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2e, 0x0a, 0x12, 0x6d, 0x61, 0x78, 0x43, 0x6f, 0x6e, 0x63,
-	0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0d, 0x52, 0x12, 0x6d, 0x61, 0x78, 0x43, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74,
-	0x4e, 0x6f, 0x64, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65,
-	0x63, 0x6f, 0x72, 0x64, 0x73, 0x12, 0x2a, 0x0a, 0x10, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73,
-	0x50, 0x65, 0x72, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x10, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x50, 0x65, 0x72, 0x53, 0x65, 0x63, 0x6f, 0x6e,
-	0x64, 0x12, 0x28, 0x0a, 0x0f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x51, 0x75, 0x65, 0x75, 0x65,
-	0x53, 0x69, 0x7a, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0f, 0x72, 0x65, 0x63, 0x6f,
-	0x72, 0x64, 0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x26, 0x0a, 0x0e, 0x69,
-	0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x42, 0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x18, 0x06, 0x20,
-	0x01, 0x28, 0x08, 0x52, 0x0e, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x42, 0x69, 0x6e, 0x44,
-	0x61, 0x74, 0x61, 0x22, 0x51, 0x0a, 0x0a, 0x53, 0x63, 0x61, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63,
-	0x79, 0x12, 0x43, 0x0a, 0x0b, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x75,
-	0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0b, 0x6d, 0x75, 0x6c, 0x74, 0x69,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0xa3, 0x01, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x72, 0x79,
-	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x43, 0x0a, 0x0b, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0b,
-	0x6d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x4f, 0x0a, 0x10, 0x65,
-	0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x51, 0x75, 0x65,
-	0x72, 0x79, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x10, 0x65, 0x78, 0x70, 0x65,
-	0x63, 0x74, 0x65, 0x64, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x90, 0x01, 0x0a,
-	0x07, 0x55, 0x44, 0x46, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61, 0x63, 0x6b,
-	0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x70,
-	0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x22, 0x0a, 0x0c, 0x66, 0x75,
-	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x3f,
-	0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72, 0x67, 0x73, 0x18, 0x08,
-	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75,
-	0x65, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72, 0x67, 0x73, 0x22,
-	0xcd, 0x02, 0x0a, 0x09, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x1c, 0x0a,
-	0x09, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x09, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x53,
-	0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x53, 0x65,
-	0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x21, 0x0a, 0x09, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x4e, 0x61,
-	0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1a, 0x0a, 0x08, 0x42, 0x69, 0x6e, 0x4e,
-	0x61, 0x6d, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x42, 0x69, 0x6e, 0x4e,
-	0x61, 0x6d, 0x65, 0x73, 0x12, 0x39, 0x0a, 0x06, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x18, 0x05,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x52, 0x06, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x12,
-	0x16, 0x0a, 0x06, 0x74, 0x61, 0x73, 0x6b, 0x49, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x06, 0x74, 0x61, 0x73, 0x6b, 0x49, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72,
-	0x6e, 0x44, 0x61, 0x74, 0x61, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x72, 0x65, 0x74,
-	0x75, 0x72, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x12, 0x3c, 0x0a, 0x07, 0x75, 0x64, 0x66, 0x43, 0x61,
-	0x6c, 0x6c, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53,
+	0x43, 0x52, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12, 0x1a, 0x0a,
+	0x08, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52,
+	0x08, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x12, 0x51, 0x0a, 0x10, 0x66, 0x69, 0x6c,
+	0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45,
+	0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x27, 0x0a, 0x0c,
+	0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x18, 0x06, 0x20, 0x01,
+	0x28, 0x0d, 0x48, 0x01, 0x52, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65, 0x6f,
+	0x75, 0x74, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79,
+	0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x48, 0x02, 0x52, 0x07, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65,
+	0x79, 0x88, 0x01, 0x01, 0x12, 0x5d, 0x0a, 0x12, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78,
+	0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e,
+	0x32, 0x28, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78,
+	0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x03, 0x52, 0x12, 0x72, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+	0x88, 0x01, 0x01, 0x12, 0x57, 0x0a, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x48, 0x04, 0x52, 0x10, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x88, 0x01, 0x01, 0x12, 0x48, 0x0a, 0x0b,
+	0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x0a, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x48, 0x05, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65,
+	0x76, 0x65, 0x6c, 0x88, 0x01, 0x01, 0x12, 0x23, 0x0a, 0x0a, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x06, 0x52, 0x0a, 0x67, 0x65,
+	0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x23, 0x0a, 0x0a, 0x65,
+	0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0d, 0x48,
+	0x07, 0x52, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01,
+	0x12, 0x29, 0x0a, 0x0d, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4f, 0x70,
+	0x73, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x48, 0x08, 0x52, 0x0d, 0x72, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4f, 0x70, 0x73, 0x88, 0x01, 0x01, 0x12, 0x29, 0x0a, 0x0d, 0x64,
+	0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x0e, 0x20, 0x01,
+	0x28, 0x08, 0x48, 0x09, 0x52, 0x0d, 0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c,
+	0x65, 0x74, 0x65, 0x88, 0x01, 0x01, 0x12, 0x15, 0x0a, 0x03, 0x78, 0x64, 0x72, 0x18, 0x0f, 0x20,
+	0x01, 0x28, 0x08, 0x48, 0x0a, 0x52, 0x03, 0x78, 0x64, 0x72, 0x88, 0x01, 0x01, 0x42, 0x13, 0x0a,
+	0x11, 0x5f, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69,
+	0x6f, 0x6e, 0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x54, 0x69, 0x6d, 0x65,
+	0x6f, 0x75, 0x74, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x73, 0x65, 0x6e, 0x64, 0x4b, 0x65, 0x79, 0x42,
+	0x15, 0x0a, 0x13, 0x5f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73,
+	0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x67, 0x65, 0x6e, 0x65, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x42, 0x0e, 0x0a, 0x0c, 0x5f,
+	0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x42, 0x0d, 0x0a, 0x0b, 0x5f,
+	0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x65,
+	0x78, 0x70, 0x69, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x0a, 0x0e, 0x5f, 0x72, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x64, 0x41, 0x6c, 0x6c, 0x4f, 0x70, 0x73, 0x42, 0x10, 0x0a, 0x0e, 0x5f,
+	0x64, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x42, 0x06, 0x0a,
+	0x04, 0x5f, 0x78, 0x64, 0x72, 0x22, 0x9d, 0x02, 0x0a, 0x0b, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0a, 0x72, 0x65, 0x61,
+	0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2e, 0x0a, 0x12, 0x6d, 0x61, 0x78, 0x43, 0x6f,
+	0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0d, 0x52, 0x12, 0x6d, 0x61, 0x78, 0x43, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65,
+	0x6e, 0x74, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x6d, 0x61, 0x78, 0x52, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x6d, 0x61, 0x78,
+	0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x12, 0x2a, 0x0a, 0x10, 0x72, 0x65, 0x63, 0x6f, 0x72,
+	0x64, 0x73, 0x50, 0x65, 0x72, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x10, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x50, 0x65, 0x72, 0x53, 0x65, 0x63,
+	0x6f, 0x6e, 0x64, 0x12, 0x28, 0x0a, 0x0f, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x51, 0x75, 0x65,
+	0x75, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0f, 0x72, 0x65,
+	0x63, 0x6f, 0x72, 0x64, 0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x26, 0x0a,
+	0x0e, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x42, 0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x18,
+	0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x42, 0x69,
+	0x6e, 0x44, 0x61, 0x74, 0x61, 0x22, 0x51, 0x0a, 0x0a, 0x53, 0x63, 0x61, 0x6e, 0x50, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x12, 0x43, 0x0a, 0x0b, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x55, 0x44, 0x46, 0x43, 0x61, 0x6c, 0x6c, 0x48, 0x01, 0x52, 0x07, 0x75, 0x64, 0x66, 0x43, 0x61,
-	0x6c, 0x6c, 0x88, 0x01, 0x01, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x4e,
-	0x61, 0x6d, 0x65, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x75, 0x64, 0x66, 0x43, 0x61, 0x6c, 0x6c, 0x22,
-	0xaa, 0x02, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x72, 0x79, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x12,
-	0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
-	0x61, 0x6d, 0x65, 0x12, 0x43, 0x0a, 0x07, 0x69, 0x64, 0x78, 0x54, 0x79, 0x70, 0x65, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52,
-	0x07, 0x69, 0x64, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x2c, 0x0a, 0x11, 0x76, 0x61, 0x6c, 0x75,
-	0x65, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x18, 0x03, 0x20,
-	0x01, 0x28, 0x0d, 0x52, 0x11, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63,
-	0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x31, 0x0a, 0x05, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x18,
-	0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c,
-	0x75, 0x65, 0x52, 0x05, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x12, 0x2d, 0x0a, 0x03, 0x65, 0x6e, 0x64,
-	0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x4d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0b, 0x6d, 0x75, 0x6c,
+	0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x22, 0xa3, 0x01, 0x0a, 0x0b, 0x51, 0x75, 0x65,
+	0x72, 0x79, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x43, 0x0a, 0x0b, 0x6d, 0x75, 0x6c, 0x74,
+	0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x52, 0x0b, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x4f, 0x0a,
+	0x10, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x10, 0x65, 0x78,
+	0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x90,
+	0x01, 0x0a, 0x07, 0x55, 0x44, 0x46, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x20, 0x0a, 0x0b, 0x70, 0x61,
+	0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0b, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x22, 0x0a, 0x0c,
+	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x07, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65,
+	0x12, 0x3f, 0x0a, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72, 0x67, 0x73,
+	0x18, 0x08, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
 	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x52, 0x03, 0x65, 0x6e, 0x64, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18,
-	0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54,
-	0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x22, 0xb4, 0x01, 0x0a,
-	0x0c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3e, 0x0a,
-	0x08, 0x6d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
-	0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54,
-	0x79, 0x70, 0x65, 0x52, 0x08, 0x6d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x12, 0x3c, 0x0a,
-	0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46,
-	0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x26, 0x0a, 0x0e, 0x50,
-	0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x64, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x04, 0x20,
-	0x01, 0x28, 0x08, 0x52, 0x0e, 0x50, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x64, 0x49, 0x6e,
-	0x64, 0x65, 0x78, 0x22, 0xdf, 0x02, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x4f, 0x70,
-	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x35, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0e, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70, 0x12, 0x3a,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x50, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x69,
-	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x69, 0x6e,
-	0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18, 0x04, 0x20, 0x03,
-	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52,
-	0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x4b, 0x0a, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
-	0x79, 0x70, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x48, 0x00, 0x52, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x88,
-	0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32,
-	0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78,
-	0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x72, 0x65, 0x74, 0x75, 0x72,
-	0x6e, 0x54, 0x79, 0x70, 0x65, 0x22, 0x89, 0x01, 0x0a, 0x0d, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
-	0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x39, 0x0a, 0x05, 0x6f, 0x72, 0x64, 0x65, 0x72,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4c, 0x69,
-	0x73, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x52, 0x05, 0x6f, 0x72, 0x64,
-	0x65, 0x72, 0x12, 0x3d, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0e, 0x32, 0x27, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
-	0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67,
-	0x73, 0x22, 0xe3, 0x02, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x4f, 0x70, 0x65,
-	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x36, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
-	0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70, 0x12, 0x3b,
-	0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x6f, 0x6c,
-	0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x62,
-	0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x69,
-	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18, 0x04, 0x20,
-	0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65,
-	0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x4c, 0x0a, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e,
-	0x54, 0x79, 0x70, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x27, 0x2e, 0x63, 0x6f, 0x6d,
+	0x6c, 0x75, 0x65, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x72, 0x67,
+	0x73, 0x22, 0xcd, 0x02, 0x0a, 0x09, 0x53, 0x74, 0x61, 0x74, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x12,
+	0x1c, 0x0a, 0x09, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x09, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x18, 0x0a,
+	0x07, 0x53, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07,
+	0x53, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x21, 0x0a, 0x09, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x49, 0x6e,
+	0x64, 0x65, 0x78, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1a, 0x0a, 0x08, 0x42, 0x69,
+	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x42, 0x69,
+	0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x12, 0x39, 0x0a, 0x06, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x51, 0x75,
+	0x65, 0x72, 0x79, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x52, 0x06, 0x66, 0x69, 0x6c, 0x74, 0x65,
+	0x72, 0x12, 0x16, 0x0a, 0x06, 0x74, 0x61, 0x73, 0x6b, 0x49, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x06, 0x74, 0x61, 0x73, 0x6b, 0x49, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x72, 0x65, 0x74,
+	0x75, 0x72, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x72,
+	0x65, 0x74, 0x75, 0x72, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x12, 0x3c, 0x0a, 0x07, 0x75, 0x64, 0x66,
+	0x43, 0x61, 0x6c, 0x6c, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x63, 0x6f, 0x6d,
 	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
-	0x79, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x88, 0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x06, 0x20, 0x03, 0x28,
-	0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74,
-	0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x72, 0x65, 0x74,
-	0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x22, 0x4c, 0x0a, 0x0c, 0x43, 0x64, 0x74, 0x48, 0x4c,
-	0x4c, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3c, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64,
-	0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05,
-	0x66, 0x6c, 0x61, 0x67, 0x73, 0x22, 0xcf, 0x01, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c,
+	0x6e, 0x2e, 0x55, 0x44, 0x46, 0x43, 0x61, 0x6c, 0x6c, 0x48, 0x01, 0x52, 0x07, 0x75, 0x64, 0x66,
+	0x43, 0x61, 0x6c, 0x6c, 0x88, 0x01, 0x01, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x49, 0x6e, 0x64, 0x65,
+	0x78, 0x4e, 0x61, 0x6d, 0x65, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x75, 0x64, 0x66, 0x43, 0x61, 0x6c,
+	0x6c, 0x22, 0xaa, 0x02, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x72, 0x79, 0x46, 0x69, 0x6c, 0x74, 0x65,
+	0x72, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x43, 0x0a, 0x07, 0x69, 0x64, 0x78, 0x54, 0x79, 0x70, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x49, 0x6e,
+	0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x52, 0x07, 0x69, 0x64, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x2c, 0x0a, 0x11, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x11, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x50, 0x61, 0x72, 0x74,
+	0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x31, 0x0a, 0x05, 0x62, 0x65, 0x67, 0x69,
+	0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56,
+	0x61, 0x6c, 0x75, 0x65, 0x52, 0x05, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x12, 0x2d, 0x0a, 0x03, 0x65,
+	0x6e, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x03, 0x65, 0x6e, 0x64, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74,
+	0x78, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43,
+	0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x22, 0xb4,
+	0x01, 0x0a, 0x0c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
+	0x3e, 0x0a, 0x08, 0x6d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65,
+	0x72, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x6d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x12,
+	0x3c, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74,
+	0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x26, 0x0a,
+	0x0e, 0x50, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x64, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x50, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x64,
+	0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xdf, 0x02, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
 	0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x35, 0x0a, 0x02, 0x6f, 0x70, 0x18,
 	0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
 	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74,
-	0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70,
+	0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70,
 	0x12, 0x3a, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
 	0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x50, 0x6f,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x50, 0x6f,
 	0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a, 0x07,
 	0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62,
 	0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18, 0x04,
 	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
 	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75,
-	0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x22, 0x54, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x42, 0x69,
-	0x74, 0x77, 0x69, 0x73, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x40, 0x0a, 0x05, 0x66,
-	0x6c, 0x61, 0x67, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2a, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74,
-	0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x22, 0x8f, 0x02,
-	0x0a, 0x13, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x70, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x39, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77,
-	0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70,
-	0x12, 0x3e, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
-	0x73, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
-	0x12, 0x18, 0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72,
-	0x67, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x4b, 0x0a, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72,
+	0x6e, 0x54, 0x79, 0x70, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
+	0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
+	0x79, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x88, 0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x06, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74,
+	0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x72, 0x65, 0x74,
+	0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x22, 0x89, 0x01, 0x0a, 0x0d, 0x43, 0x64, 0x74, 0x4c,
+	0x69, 0x73, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x39, 0x0a, 0x05, 0x6f, 0x72, 0x64,
+	0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x32, 0x0a, 0x03, 0x63,
-	0x74, 0x78, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x4c, 0x69, 0x73, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x52, 0x05, 0x6f,
+	0x72, 0x64, 0x65, 0x72, 0x12, 0x3d, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x27, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69,
+	0x73, 0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c,
+	0x61, 0x67, 0x73, 0x22, 0xe3, 0x02, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x4f,
+	0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x36, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c,
+	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02, 0x6f, 0x70,
+	0x12, 0x3b, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x23, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18, 0x0a,
+	0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07,
+	0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18,
+	0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61, 0x6c,
+	0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x4c, 0x0a, 0x0a, 0x72, 0x65, 0x74, 0x75,
+	0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x27, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72,
+	0x6e, 0x54, 0x79, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0a, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
+	0x79, 0x70, 0x65, 0x88, 0x01, 0x01, 0x12, 0x32, 0x0a, 0x03, 0x63, 0x74, 0x78, 0x18, 0x06, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f,
+	0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x72,
+	0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x22, 0x4c, 0x0a, 0x0c, 0x43, 0x64, 0x74,
+	0x48, 0x4c, 0x4c, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x3c, 0x0a, 0x05, 0x66, 0x6c, 0x61,
+	0x67, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74, 0x78, 0x2a,
-	0x9e, 0x02, 0x0a, 0x0c, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65,
-	0x12, 0x14, 0x0a, 0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65,
-	0x4e, 0x75, 0x6c, 0x6c, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63,
-	0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x10, 0x01, 0x12,
-	0x15, 0x0a, 0x11, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x46,
-	0x6c, 0x6f, 0x61, 0x74, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63,
-	0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x10, 0x03, 0x12, 0x14,
-	0x0a, 0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6c,
-	0x6f, 0x62, 0x10, 0x04, 0x12, 0x16, 0x0a, 0x12, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65,
-	0x54, 0x79, 0x70, 0x65, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x10, 0x06, 0x12, 0x14, 0x0a, 0x10,
-	0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6f, 0x6f, 0x6c,
-	0x10, 0x11, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79,
-	0x70, 0x65, 0x48, 0x4c, 0x4c, 0x10, 0x12, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69,
-	0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70, 0x10, 0x13, 0x12, 0x14, 0x0a, 0x10,
-	0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x4c, 0x69, 0x73, 0x74,
-	0x10, 0x14, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79,
-	0x70, 0x65, 0x4c, 0x44, 0x54, 0x10, 0x15, 0x12, 0x17, 0x0a, 0x13, 0x50, 0x61, 0x72, 0x74, 0x69,
-	0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x47, 0x65, 0x6f, 0x4a, 0x73, 0x6f, 0x6e, 0x10, 0x17,
-	0x2a, 0xb1, 0x01, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0e, 0x0a, 0x0a,
-	0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4e, 0x69, 0x6c, 0x10, 0x00, 0x12, 0x0f, 0x0a, 0x0b,
-	0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6f, 0x6f, 0x6c, 0x10, 0x01, 0x12, 0x0e, 0x0a,
-	0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x74, 0x10, 0x02, 0x12, 0x11, 0x0a,
-	0x0d, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x10, 0x03,
-	0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x10,
-	0x04, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70, 0x10,
-	0x05, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6c, 0x6f, 0x62,
-	0x10, 0x06, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x46, 0x6c, 0x6f,
-	0x61, 0x74, 0x10, 0x07, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x47,
-	0x65, 0x6f, 0x10, 0x08, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x48,
-	0x6c, 0x6c, 0x10, 0x09, 0x2a, 0x9f, 0x07, 0x0a, 0x05, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x12, 0x10,
-	0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x10, 0x00,
-	0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x45, 0x71, 0x10, 0x01, 0x12, 0x0b, 0x0a,
-	0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4e, 0x65, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78,
-	0x70, 0x4f, 0x70, 0x47, 0x74, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70,
-	0x47, 0x65, 0x10, 0x04, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x74, 0x10,
-	0x05, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x65, 0x10, 0x06, 0x12, 0x0e,
-	0x0a, 0x0a, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x52, 0x65, 0x67, 0x65, 0x78, 0x10, 0x07, 0x12, 0x0c,
-	0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x47, 0x65, 0x6f, 0x10, 0x08, 0x12, 0x0c, 0x0a, 0x08,
-	0x45, 0x78, 0x70, 0x4f, 0x70, 0x41, 0x6e, 0x64, 0x10, 0x10, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78,
-	0x70, 0x4f, 0x70, 0x4f, 0x72, 0x10, 0x11, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70,
-	0x4e, 0x6f, 0x74, 0x10, 0x12, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x45, 0x78,
-	0x63, 0x6c, 0x75, 0x73, 0x69, 0x76, 0x65, 0x10, 0x13, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x41, 0x64, 0x64, 0x10, 0x14, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70,
-	0x53, 0x75, 0x62, 0x10, 0x15, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x75,
-	0x6c, 0x10, 0x16, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x44, 0x69, 0x76, 0x10,
-	0x17, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x50, 0x6f, 0x77, 0x10, 0x18, 0x12,
-	0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x6f, 0x67, 0x10, 0x19, 0x12, 0x0c, 0x0a,
-	0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x6f, 0x64, 0x10, 0x1a, 0x12, 0x0c, 0x0a, 0x08, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x41, 0x62, 0x73, 0x10, 0x1b, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x46, 0x6c, 0x6f, 0x6f, 0x72, 0x10, 0x1c, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x43, 0x65, 0x69, 0x6c, 0x10, 0x1d, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x4f,
-	0x70, 0x54, 0x6f, 0x49, 0x6e, 0x74, 0x10, 0x1e, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f,
-	0x70, 0x54, 0x6f, 0x46, 0x6c, 0x6f, 0x61, 0x74, 0x10, 0x1f, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78,
-	0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x41, 0x6e, 0x64, 0x10, 0x20, 0x12, 0x0e, 0x0a, 0x0a, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4f, 0x72, 0x10, 0x21, 0x12, 0x0f, 0x0a, 0x0b, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x58, 0x6f, 0x72, 0x10, 0x22, 0x12, 0x0f, 0x0a, 0x0b,
-	0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4e, 0x6f, 0x74, 0x10, 0x23, 0x12, 0x12, 0x0a,
-	0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4c, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10,
-	0x24, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x52, 0x53, 0x68,
-	0x69, 0x66, 0x74, 0x10, 0x25, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e,
-	0x74, 0x41, 0x52, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x26, 0x12, 0x11, 0x0a, 0x0d, 0x45, 0x78,
-	0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x27, 0x12, 0x11, 0x0a,
-	0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4c, 0x53, 0x63, 0x61, 0x6e, 0x10, 0x28,
-	0x12, 0x11, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x52, 0x53, 0x63, 0x61,
-	0x6e, 0x10, 0x29, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x69, 0x6e, 0x10,
-	0x32, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x61, 0x78, 0x10, 0x33, 0x12,
-	0x15, 0x0a, 0x11, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x4d, 0x6f,
-	0x64, 0x75, 0x6c, 0x6f, 0x10, 0x40, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x44,
-	0x65, 0x76, 0x69, 0x63, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x41, 0x12, 0x13, 0x0a, 0x0f, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x4c, 0x61, 0x73, 0x74, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x42,
-	0x12, 0x14, 0x0a, 0x10, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x53, 0x69, 0x6e, 0x63, 0x65, 0x55, 0x70,
-	0x64, 0x61, 0x74, 0x65, 0x10, 0x43, 0x12, 0x11, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x56,
-	0x6f, 0x69, 0x64, 0x54, 0x69, 0x6d, 0x65, 0x10, 0x44, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x54, 0x74, 0x6c, 0x10, 0x45, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f, 0x70,
-	0x53, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x10, 0x46, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x4b, 0x65, 0x79, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x47, 0x12, 0x14, 0x0a,
-	0x10, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x73, 0x54, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e,
-	0x65, 0x10, 0x48, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x65, 0x6d, 0x6f,
-	0x72, 0x79, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x49, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f,
-	0x70, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x4a, 0x12, 0x0c, 0x0a,
-	0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4b, 0x65, 0x79, 0x10, 0x50, 0x12, 0x0c, 0x0a, 0x08, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x42, 0x69, 0x6e, 0x10, 0x51, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70,
-	0x4f, 0x70, 0x42, 0x69, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x10, 0x52, 0x12, 0x0d, 0x0a, 0x09, 0x45,
-	0x78, 0x70, 0x4f, 0x70, 0x43, 0x6f, 0x6e, 0x64, 0x10, 0x7b, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78,
-	0x70, 0x4f, 0x70, 0x56, 0x61, 0x72, 0x10, 0x7c, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f,
-	0x70, 0x4c, 0x65, 0x74, 0x10, 0x7d, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x51,
-	0x75, 0x6f, 0x74, 0x65, 0x64, 0x10, 0x7e, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78, 0x70, 0x4f, 0x70,
-	0x43, 0x61, 0x6c, 0x6c, 0x10, 0x7f, 0x2a, 0x32, 0x0a, 0x0a, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f,
-	0x64, 0x65, 0x41, 0x50, 0x12, 0x11, 0x0a, 0x0d, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65,
-	0x41, 0x50, 0x4f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x52, 0x65, 0x61, 0x64, 0x4d,
-	0x6f, 0x64, 0x65, 0x41, 0x50, 0x41, 0x6c, 0x6c, 0x10, 0x01, 0x2a, 0x78, 0x0a, 0x0a, 0x52, 0x65,
-	0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12, 0x15, 0x0a, 0x11, 0x52, 0x65, 0x61, 0x64,
-	0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x10, 0x00, 0x12,
-	0x17, 0x0a, 0x13, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x4c, 0x69, 0x6e,
-	0x65, 0x61, 0x72, 0x69, 0x7a, 0x65, 0x10, 0x01, 0x12, 0x1a, 0x0a, 0x16, 0x52, 0x65, 0x61, 0x64,
-	0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6c, 0x69,
-	0x63, 0x61, 0x10, 0x02, 0x12, 0x1e, 0x0a, 0x1a, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65,
-	0x53, 0x43, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x55, 0x6e, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62,
-	0x6c, 0x65, 0x10, 0x03, 0x2a, 0x59, 0x0a, 0x0d, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x50,
-	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x45, 0x51, 0x55, 0x45, 0x4e, 0x43,
-	0x45, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x41, 0x53, 0x54, 0x45, 0x52, 0x10, 0x01, 0x12,
-	0x11, 0x0a, 0x0d, 0x4d, 0x41, 0x53, 0x54, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4c, 0x45, 0x53,
-	0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x50, 0x52, 0x45, 0x46, 0x45, 0x52, 0x5f, 0x52, 0x41, 0x43,
-	0x4b, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x41, 0x4e, 0x44, 0x4f, 0x4d, 0x10, 0x04, 0x2a,
-	0x3a, 0x0a, 0x10, 0x43, 0x6f, 0x6e, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4c, 0x65,
-	0x76, 0x65, 0x6c, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x6f, 0x6e, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e,
-	0x63, 0x79, 0x4f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x6f, 0x6e, 0x73, 0x69,
-	0x73, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x41, 0x6c, 0x6c, 0x10, 0x01, 0x2a, 0x63, 0x0a, 0x09, 0x49,
-	0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x54, 0x79, 0x70, 0x65, 0x4e, 0x75, 0x6d, 0x65, 0x72, 0x69, 0x63, 0x10, 0x00, 0x12, 0x13,
-	0x0a, 0x0f, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69, 0x6e,
-	0x67, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65,
-	0x42, 0x6c, 0x6f, 0x62, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54,
-	0x79, 0x70, 0x65, 0x47, 0x65, 0x6f, 0x32, 0x44, 0x53, 0x70, 0x68, 0x65, 0x72, 0x65, 0x10, 0x03,
-	0x2a, 0x94, 0x01, 0x0a, 0x13, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63,
-	0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x44,
-	0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1b, 0x0a, 0x17, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4c,
-	0x69, 0x73, 0x74, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f,
-	0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70, 0x4b,
-	0x65, 0x79, 0x73, 0x10, 0x02, 0x12, 0x20, 0x0a, 0x1c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f,
-	0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70, 0x56,
-	0x61, 0x6c, 0x75, 0x65, 0x73, 0x10, 0x03, 0x2a, 0x47, 0x0a, 0x08, 0x41, 0x75, 0x74, 0x68, 0x4d,
-	0x6f, 0x64, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x41, 0x75, 0x74, 0x68, 0x4d, 0x6f, 0x64, 0x65, 0x49,
-	0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x41, 0x75, 0x74,
-	0x68, 0x4d, 0x6f, 0x64, 0x65, 0x45, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x10, 0x01, 0x12,
-	0x0f, 0x0a, 0x0b, 0x41, 0x75, 0x74, 0x68, 0x4d, 0x6f, 0x64, 0x65, 0x50, 0x4b, 0x49, 0x10, 0x02,
-	0x2a, 0x16, 0x0a, 0x0b, 0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x12,
-	0x07, 0x0a, 0x03, 0x4c, 0x55, 0x41, 0x10, 0x00, 0x2a, 0x45, 0x0a, 0x0d, 0x4c, 0x69, 0x73, 0x74,
-	0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x16, 0x4c, 0x69, 0x73,
-	0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x55, 0x6e, 0x6f, 0x72, 0x64, 0x65,
-	0x72, 0x65, 0x64, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x4f, 0x72, 0x64,
-	0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x01, 0x2a,
-	0x66, 0x0a, 0x0c, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x12,
-	0x19, 0x0a, 0x15, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x55,
-	0x6e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x4d, 0x61,
-	0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4b, 0x65, 0x79, 0x4f, 0x72, 0x64,
-	0x65, 0x72, 0x65, 0x64, 0x10, 0x01, 0x12, 0x1f, 0x0a, 0x1b, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64,
-	0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4b, 0x65, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4f, 0x72,
-	0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x02, 0x2a, 0xd1, 0x01, 0x0a, 0x0d, 0x4f, 0x70, 0x65, 0x72,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x4f, 0x70, 0x65,
-	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x47, 0x65, 0x74, 0x10, 0x00, 0x12,
-	0x1a, 0x0a, 0x16, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65,
-	0x47, 0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x4f,
-	0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x50, 0x75, 0x74, 0x10,
-	0x02, 0x12, 0x14, 0x0a, 0x10, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79,
-	0x70, 0x65, 0x41, 0x64, 0x64, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x4f, 0x70, 0x65, 0x72, 0x61,
-	0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x04,
-	0x12, 0x18, 0x0a, 0x14, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x50, 0x72, 0x65, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x05, 0x12, 0x16, 0x0a, 0x12, 0x4f, 0x70,
-	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x54, 0x6f, 0x75, 0x63, 0x68,
-	0x10, 0x06, 0x12, 0x17, 0x0a, 0x13, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54,
-	0x79, 0x70, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x07, 0x2a, 0x61, 0x0a, 0x12, 0x52,
-	0x65, 0x63, 0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f,
-	0x6e, 0x12, 0x0a, 0x0a, 0x06, 0x55, 0x50, 0x44, 0x41, 0x54, 0x45, 0x10, 0x00, 0x12, 0x0f, 0x0a,
-	0x0b, 0x55, 0x50, 0x44, 0x41, 0x54, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x01, 0x12, 0x0b,
-	0x0a, 0x07, 0x52, 0x45, 0x50, 0x4c, 0x41, 0x43, 0x45, 0x10, 0x02, 0x12, 0x10, 0x0a, 0x0c, 0x52,
-	0x45, 0x50, 0x4c, 0x41, 0x43, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x03, 0x12, 0x0f, 0x0a,
-	0x0b, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x04, 0x2a, 0x45,
-	0x0a, 0x10, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69,
-	0x63, 0x79, 0x12, 0x08, 0x0a, 0x04, 0x4e, 0x4f, 0x4e, 0x45, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10,
-	0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x5f, 0x47, 0x45, 0x4e, 0x5f, 0x45, 0x51, 0x55, 0x41, 0x4c,
-	0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x5f, 0x47, 0x45, 0x4e,
-	0x5f, 0x47, 0x54, 0x10, 0x02, 0x2a, 0x30, 0x0a, 0x0b, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c,
-	0x65, 0x76, 0x65, 0x6c, 0x12, 0x0e, 0x0a, 0x0a, 0x43, 0x4f, 0x4d, 0x4d, 0x49, 0x54, 0x5f, 0x41,
-	0x4c, 0x4c, 0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x43, 0x4f, 0x4d, 0x4d, 0x49, 0x54, 0x5f, 0x4d,
-	0x41, 0x53, 0x54, 0x45, 0x52, 0x10, 0x01, 0x2a, 0x35, 0x0a, 0x0d, 0x51, 0x75, 0x65, 0x72, 0x79,
-	0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x08, 0x0a, 0x04, 0x4c, 0x6f, 0x6e, 0x67,
-	0x10, 0x00, 0x12, 0x09, 0x0a, 0x05, 0x53, 0x68, 0x6f, 0x72, 0x74, 0x10, 0x01, 0x12, 0x0f, 0x0a,
-	0x0b, 0x4c, 0x6f, 0x6e, 0x67, 0x52, 0x65, 0x6c, 0x61, 0x78, 0x41, 0x50, 0x10, 0x02, 0x2a, 0x88,
-	0x0c, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x10, 0x00, 0x12, 0x22, 0x0a,
-	0x1e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x57, 0x69, 0x74, 0x68, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10,
-	0x01, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x10, 0x02, 0x12,
-	0x16, 0x0a, 0x12, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x50, 0x75, 0x74, 0x10, 0x03, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x50, 0x75, 0x74, 0x49, 0x74, 0x65,
-	0x6d, 0x73, 0x10, 0x04, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74,
-	0x10, 0x05, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x44, 0x65, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x10, 0x06,
-	0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x10, 0x07, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64,
-	0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d,
-	0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x10, 0x08, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64,
-	0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d,
-	0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x09, 0x12, 0x23,
-	0x0a, 0x1f, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
-	0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x61, 0x6e, 0x67,
-	0x65, 0x10, 0x0a, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
+	0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73,
+	0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x22, 0xcf, 0x01, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x48,
+	0x4c, 0x4c, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x35, 0x0a, 0x02, 0x6f,
+	0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x25, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43,
+	0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02,
+	0x6f, 0x70, 0x12, 0x3a, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x22, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x18,
+	0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73,
+	0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x56, 0x61,
+	0x6c, 0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x22, 0x54, 0x0a, 0x10, 0x43, 0x64, 0x74,
+	0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x40, 0x0a,
+	0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2a, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x22,
+	0x8f, 0x02, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x70,
+	0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x39, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69,
+	0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x02,
+	0x6f, 0x70, 0x12, 0x3e, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74,
+	0x77, 0x69, 0x73, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x69, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x2f, 0x0a, 0x04,
+	0x61, 0x72, 0x67, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x12, 0x32, 0x0a,
+	0x03, 0x63, 0x74, 0x78, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x43, 0x44, 0x54, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x03, 0x63, 0x74,
+	0x78, 0x22, 0xac, 0x01, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x12, 0x3e, 0x0a, 0x06, 0x6f, 0x70, 0x54, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x26, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x06, 0x6f, 0x70, 0x54, 0x79,
+	0x70, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x32, 0x0a, 0x03, 0x65, 0x78, 0x70, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x78, 0x70, 0x72, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x03, 0x65, 0x78, 0x70, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6c,
+	0x61, 0x67, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73,
+	0x2a, 0x9e, 0x02, 0x0a, 0x0c, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70,
+	0x65, 0x12, 0x14, 0x0a, 0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70,
+	0x65, 0x4e, 0x75, 0x6c, 0x6c, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x50, 0x61, 0x72, 0x74, 0x69,
+	0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x10, 0x01,
+	0x12, 0x15, 0x0a, 0x11, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65,
+	0x46, 0x6c, 0x6f, 0x61, 0x74, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x50, 0x61, 0x72, 0x74, 0x69,
+	0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x10, 0x03, 0x12,
+	0x14, 0x0a, 0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x42,
+	0x6c, 0x6f, 0x62, 0x10, 0x04, 0x12, 0x16, 0x0a, 0x12, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c,
+	0x65, 0x54, 0x79, 0x70, 0x65, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x10, 0x06, 0x12, 0x14, 0x0a,
+	0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6f, 0x6f,
+	0x6c, 0x10, 0x11, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54,
+	0x79, 0x70, 0x65, 0x48, 0x4c, 0x4c, 0x10, 0x12, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74,
+	0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70, 0x10, 0x13, 0x12, 0x14, 0x0a,
+	0x10, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x4c, 0x69, 0x73,
+	0x74, 0x10, 0x14, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x61, 0x72, 0x74, 0x69, 0x63, 0x6c, 0x65, 0x54,
+	0x79, 0x70, 0x65, 0x4c, 0x44, 0x54, 0x10, 0x15, 0x12, 0x17, 0x0a, 0x13, 0x50, 0x61, 0x72, 0x74,
+	0x69, 0x63, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x47, 0x65, 0x6f, 0x4a, 0x73, 0x6f, 0x6e, 0x10,
+	0x17, 0x2a, 0xb1, 0x01, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0e, 0x0a,
+	0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4e, 0x69, 0x6c, 0x10, 0x00, 0x12, 0x0f, 0x0a,
+	0x0b, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6f, 0x6f, 0x6c, 0x10, 0x01, 0x12, 0x0e,
+	0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x74, 0x10, 0x02, 0x12, 0x11,
+	0x0a, 0x0d, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x10,
+	0x03, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4c, 0x69, 0x73, 0x74,
+	0x10, 0x04, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70,
+	0x10, 0x05, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x42, 0x6c, 0x6f,
+	0x62, 0x10, 0x06, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65, 0x46, 0x6c,
+	0x6f, 0x61, 0x74, 0x10, 0x07, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65,
+	0x47, 0x65, 0x6f, 0x10, 0x08, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x54, 0x79, 0x70, 0x65,
+	0x48, 0x6c, 0x6c, 0x10, 0x09, 0x2a, 0x9f, 0x07, 0x0a, 0x05, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x12,
+	0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x10,
+	0x00, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x45, 0x71, 0x10, 0x01, 0x12, 0x0b,
+	0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4e, 0x65, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x45,
+	0x78, 0x70, 0x4f, 0x70, 0x47, 0x74, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f,
+	0x70, 0x47, 0x65, 0x10, 0x04, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x74,
+	0x10, 0x05, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x65, 0x10, 0x06, 0x12,
+	0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x52, 0x65, 0x67, 0x65, 0x78, 0x10, 0x07, 0x12,
+	0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x47, 0x65, 0x6f, 0x10, 0x08, 0x12, 0x0c, 0x0a,
+	0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x41, 0x6e, 0x64, 0x10, 0x10, 0x12, 0x0b, 0x0a, 0x07, 0x45,
+	0x78, 0x70, 0x4f, 0x70, 0x4f, 0x72, 0x10, 0x11, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f,
+	0x70, 0x4e, 0x6f, 0x74, 0x10, 0x12, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x45,
+	0x78, 0x63, 0x6c, 0x75, 0x73, 0x69, 0x76, 0x65, 0x10, 0x13, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x41, 0x64, 0x64, 0x10, 0x14, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f,
+	0x70, 0x53, 0x75, 0x62, 0x10, 0x15, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d,
+	0x75, 0x6c, 0x10, 0x16, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x44, 0x69, 0x76,
+	0x10, 0x17, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x50, 0x6f, 0x77, 0x10, 0x18,
+	0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x6f, 0x67, 0x10, 0x19, 0x12, 0x0c,
+	0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x6f, 0x64, 0x10, 0x1a, 0x12, 0x0c, 0x0a, 0x08,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x41, 0x62, 0x73, 0x10, 0x1b, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x46, 0x6c, 0x6f, 0x6f, 0x72, 0x10, 0x1c, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x43, 0x65, 0x69, 0x6c, 0x10, 0x1d, 0x12, 0x0e, 0x0a, 0x0a, 0x45, 0x78, 0x70,
+	0x4f, 0x70, 0x54, 0x6f, 0x49, 0x6e, 0x74, 0x10, 0x1e, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70,
+	0x4f, 0x70, 0x54, 0x6f, 0x46, 0x6c, 0x6f, 0x61, 0x74, 0x10, 0x1f, 0x12, 0x0f, 0x0a, 0x0b, 0x45,
+	0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x41, 0x6e, 0x64, 0x10, 0x20, 0x12, 0x0e, 0x0a, 0x0a,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4f, 0x72, 0x10, 0x21, 0x12, 0x0f, 0x0a, 0x0b,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x58, 0x6f, 0x72, 0x10, 0x22, 0x12, 0x0f, 0x0a,
+	0x0b, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4e, 0x6f, 0x74, 0x10, 0x23, 0x12, 0x12,
+	0x0a, 0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4c, 0x53, 0x68, 0x69, 0x66, 0x74,
+	0x10, 0x24, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x52, 0x53,
+	0x68, 0x69, 0x66, 0x74, 0x10, 0x25, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49,
+	0x6e, 0x74, 0x41, 0x52, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x26, 0x12, 0x11, 0x0a, 0x0d, 0x45,
+	0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x27, 0x12, 0x11,
+	0x0a, 0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x4c, 0x53, 0x63, 0x61, 0x6e, 0x10,
+	0x28, 0x12, 0x11, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x6e, 0x74, 0x52, 0x53, 0x63,
+	0x61, 0x6e, 0x10, 0x29, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x69, 0x6e,
+	0x10, 0x32, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x61, 0x78, 0x10, 0x33,
+	0x12, 0x15, 0x0a, 0x11, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x4d,
+	0x6f, 0x64, 0x75, 0x6c, 0x6f, 0x10, 0x40, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70,
+	0x44, 0x65, 0x76, 0x69, 0x63, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x41, 0x12, 0x13, 0x0a, 0x0f,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x4c, 0x61, 0x73, 0x74, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10,
+	0x42, 0x12, 0x14, 0x0a, 0x10, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x53, 0x69, 0x6e, 0x63, 0x65, 0x55,
+	0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x43, 0x12, 0x11, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x4f, 0x70,
+	0x56, 0x6f, 0x69, 0x64, 0x54, 0x69, 0x6d, 0x65, 0x10, 0x44, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x54, 0x74, 0x6c, 0x10, 0x45, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x4f,
+	0x70, 0x53, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x10, 0x46, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x4b, 0x65, 0x79, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x47, 0x12, 0x14,
+	0x0a, 0x10, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x49, 0x73, 0x54, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f,
+	0x6e, 0x65, 0x10, 0x48, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4d, 0x65, 0x6d,
+	0x6f, 0x72, 0x79, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x49, 0x12, 0x13, 0x0a, 0x0f, 0x45, 0x78, 0x70,
+	0x4f, 0x70, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x4a, 0x12, 0x0c,
+	0x0a, 0x08, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x4b, 0x65, 0x79, 0x10, 0x50, 0x12, 0x0c, 0x0a, 0x08,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x42, 0x69, 0x6e, 0x10, 0x51, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78,
+	0x70, 0x4f, 0x70, 0x42, 0x69, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x10, 0x52, 0x12, 0x0d, 0x0a, 0x09,
+	0x45, 0x78, 0x70, 0x4f, 0x70, 0x43, 0x6f, 0x6e, 0x64, 0x10, 0x7b, 0x12, 0x0c, 0x0a, 0x08, 0x45,
+	0x78, 0x70, 0x4f, 0x70, 0x56, 0x61, 0x72, 0x10, 0x7c, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x78, 0x70,
+	0x4f, 0x70, 0x4c, 0x65, 0x74, 0x10, 0x7d, 0x12, 0x0f, 0x0a, 0x0b, 0x45, 0x78, 0x70, 0x4f, 0x70,
+	0x51, 0x75, 0x6f, 0x74, 0x65, 0x64, 0x10, 0x7e, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78, 0x70, 0x4f,
+	0x70, 0x43, 0x61, 0x6c, 0x6c, 0x10, 0x7f, 0x2a, 0x32, 0x0a, 0x0a, 0x52, 0x65, 0x61, 0x64, 0x4d,
+	0x6f, 0x64, 0x65, 0x41, 0x50, 0x12, 0x11, 0x0a, 0x0d, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64,
+	0x65, 0x41, 0x50, 0x4f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x52, 0x65, 0x61, 0x64,
+	0x4d, 0x6f, 0x64, 0x65, 0x41, 0x50, 0x41, 0x6c, 0x6c, 0x10, 0x01, 0x2a, 0x78, 0x0a, 0x0a, 0x52,
+	0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x12, 0x15, 0x0a, 0x11, 0x52, 0x65, 0x61,
+	0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x10, 0x00,
+	0x12, 0x17, 0x0a, 0x13, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x4c, 0x69,
+	0x6e, 0x65, 0x61, 0x72, 0x69, 0x7a, 0x65, 0x10, 0x01, 0x12, 0x1a, 0x0a, 0x16, 0x52, 0x65, 0x61,
+	0x64, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x43, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6c,
+	0x69, 0x63, 0x61, 0x10, 0x02, 0x12, 0x1e, 0x0a, 0x1a, 0x52, 0x65, 0x61, 0x64, 0x4d, 0x6f, 0x64,
+	0x65, 0x53, 0x43, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x55, 0x6e, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61,
+	0x62, 0x6c, 0x65, 0x10, 0x03, 0x2a, 0x59, 0x0a, 0x0d, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x63, 0x61,
+	0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x45, 0x51, 0x55, 0x45, 0x4e,
+	0x43, 0x45, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x41, 0x53, 0x54, 0x45, 0x52, 0x10, 0x01,
+	0x12, 0x11, 0x0a, 0x0d, 0x4d, 0x41, 0x53, 0x54, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4c, 0x45,
+	0x53, 0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x50, 0x52, 0x45, 0x46, 0x45, 0x52, 0x5f, 0x52, 0x41,
+	0x43, 0x4b, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x41, 0x4e, 0x44, 0x4f, 0x4d, 0x10, 0x04,
+	0x2a, 0x3a, 0x0a, 0x10, 0x43, 0x6f, 0x6e, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x6f, 0x6e, 0x73, 0x69, 0x73, 0x74, 0x65,
+	0x6e, 0x63, 0x79, 0x4f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x6f, 0x6e, 0x73,
+	0x69, 0x73, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x41, 0x6c, 0x6c, 0x10, 0x01, 0x2a, 0x63, 0x0a, 0x09,
+	0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x4e, 0x75, 0x6d, 0x65, 0x72, 0x69, 0x63, 0x10, 0x00, 0x12,
+	0x13, 0x0a, 0x0f, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70, 0x65, 0x53, 0x74, 0x72, 0x69,
+	0x6e, 0x67, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x54, 0x79, 0x70,
+	0x65, 0x42, 0x6c, 0x6f, 0x62, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x54, 0x79, 0x70, 0x65, 0x47, 0x65, 0x6f, 0x32, 0x44, 0x53, 0x70, 0x68, 0x65, 0x72, 0x65, 0x10,
+	0x03, 0x2a, 0x94, 0x01, 0x0a, 0x13, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65,
+	0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65,
+	0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1b, 0x0a, 0x17, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65,
+	0x4c, 0x69, 0x73, 0x74, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43,
+	0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70,
+	0x4b, 0x65, 0x79, 0x73, 0x10, 0x02, 0x12, 0x20, 0x0a, 0x1c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x43,
+	0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4d, 0x61, 0x70,
+	0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x10, 0x03, 0x2a, 0x47, 0x0a, 0x08, 0x41, 0x75, 0x74, 0x68,
+	0x4d, 0x6f, 0x64, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x41, 0x75, 0x74, 0x68, 0x4d, 0x6f, 0x64, 0x65,
+	0x49, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x41, 0x75,
+	0x74, 0x68, 0x4d, 0x6f, 0x64, 0x65, 0x45, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x10, 0x01,
+	0x12, 0x0f, 0x0a, 0x0b, 0x41, 0x75, 0x74, 0x68, 0x4d, 0x6f, 0x64, 0x65, 0x50, 0x4b, 0x49, 0x10,
+	0x02, 0x2a, 0x16, 0x0a, 0x0b, 0x55, 0x44, 0x46, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65,
+	0x12, 0x07, 0x0a, 0x03, 0x4c, 0x55, 0x41, 0x10, 0x00, 0x2a, 0x45, 0x0a, 0x0d, 0x4c, 0x69, 0x73,
+	0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x16, 0x4c, 0x69,
+	0x73, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x55, 0x6e, 0x6f, 0x72, 0x64,
+	0x65, 0x72, 0x65, 0x64, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x4f, 0x72,
+	0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x01,
+	0x2a, 0x66, 0x0a, 0x0c, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+	0x12, 0x19, 0x0a, 0x15, 0x4d, 0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+	0x55, 0x6e, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x4d,
+	0x61, 0x70, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4b, 0x65, 0x79, 0x4f, 0x72,
+	0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x01, 0x12, 0x1f, 0x0a, 0x1b, 0x4d, 0x61, 0x70, 0x4f, 0x72,
+	0x64, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x4b, 0x65, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4f,
+	0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x10, 0x02, 0x2a, 0x5e, 0x0a, 0x08, 0x54, 0x78, 0x6e, 0x53,
+	0x74, 0x61, 0x74, 0x65, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x78, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65,
+	0x4f, 0x70, 0x65, 0x6e, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x54, 0x78, 0x6e, 0x53, 0x74, 0x61,
+	0x74, 0x65, 0x56, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65, 0x64, 0x10, 0x01, 0x12, 0x15, 0x0a, 0x11,
+	0x54, 0x78, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65,
+	0x64, 0x10, 0x02, 0x12, 0x13, 0x0a, 0x0f, 0x54, 0x78, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x41,
+	0x62, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x10, 0x03, 0x2a, 0x8a, 0x01, 0x0a, 0x0c, 0x43, 0x6f, 0x6d,
+	0x6d, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x12, 0x0a, 0x0e, 0x43, 0x6f, 0x6d,
+	0x6d, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x4f, 0x6b, 0x10, 0x00, 0x12, 0x20, 0x0a,
+	0x1c, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x41, 0x6c, 0x72,
+	0x65, 0x61, 0x64, 0x79, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x10, 0x01, 0x12,
+	0x24, 0x0a, 0x20, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52,
+	0x6f, 0x6c, 0x6c, 0x46, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f,
+	0x6e, 0x65, 0x64, 0x10, 0x02, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x53,
+	0x74, 0x61, 0x74, 0x75, 0x73, 0x43, 0x6c, 0x6f, 0x73, 0x65, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f,
+	0x6e, 0x65, 0x64, 0x10, 0x03, 0x2a, 0x80, 0x01, 0x0a, 0x0b, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x53,
+	0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x11, 0x0a, 0x0d, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x53, 0x74,
+	0x61, 0x74, 0x75, 0x73, 0x4f, 0x6b, 0x10, 0x00, 0x12, 0x1d, 0x0a, 0x19, 0x41, 0x62, 0x6f, 0x72,
+	0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x41, 0x6c, 0x72, 0x65, 0x61, 0x64, 0x79, 0x41, 0x62,
+	0x6f, 0x72, 0x74, 0x65, 0x64, 0x10, 0x01, 0x12, 0x20, 0x0a, 0x1c, 0x41, 0x62, 0x6f, 0x72, 0x74,
+	0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x6f, 0x6c, 0x6c, 0x42, 0x61, 0x63, 0x6b, 0x41, 0x62,
+	0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x10, 0x02, 0x12, 0x1d, 0x0a, 0x19, 0x41, 0x62, 0x6f,
+	0x72, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x43, 0x6c, 0x6f, 0x73, 0x65, 0x41, 0x62, 0x61,
+	0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x10, 0x03, 0x2a, 0x2b, 0x0a, 0x0a, 0x54, 0x61, 0x73, 0x6b,
+	0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0f, 0x0a, 0x0b, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f,
+	0x47, 0x52, 0x45, 0x53, 0x53, 0x10, 0x00, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x4f, 0x4d, 0x50, 0x4c,
+	0x45, 0x54, 0x45, 0x10, 0x01, 0x2a, 0xd1, 0x01, 0x0a, 0x0d, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14, 0x0a, 0x10, 0x4f, 0x70, 0x65, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x47, 0x65, 0x74, 0x10, 0x00, 0x12, 0x1a, 0x0a,
+	0x16, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x47, 0x65,
+	0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x4f, 0x70, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x50, 0x75, 0x74, 0x10, 0x02, 0x12,
+	0x14, 0x0a, 0x10, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65,
+	0x41, 0x64, 0x64, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x04, 0x12, 0x18,
+	0x0a, 0x14, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x50,
+	0x72, 0x65, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x05, 0x12, 0x16, 0x0a, 0x12, 0x4f, 0x70, 0x65, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x10, 0x06,
+	0x12, 0x17, 0x0a, 0x13, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x07, 0x2a, 0x61, 0x0a, 0x12, 0x52, 0x65, 0x63,
+	0x6f, 0x72, 0x64, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+	0x0a, 0x0a, 0x06, 0x55, 0x50, 0x44, 0x41, 0x54, 0x45, 0x10, 0x00, 0x12, 0x0f, 0x0a, 0x0b, 0x55,
+	0x50, 0x44, 0x41, 0x54, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07,
+	0x52, 0x45, 0x50, 0x4c, 0x41, 0x43, 0x45, 0x10, 0x02, 0x12, 0x10, 0x0a, 0x0c, 0x52, 0x45, 0x50,
+	0x4c, 0x41, 0x43, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x03, 0x12, 0x0f, 0x0a, 0x0b, 0x43,
+	0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x4f, 0x4e, 0x4c, 0x59, 0x10, 0x04, 0x2a, 0x45, 0x0a, 0x10,
+	0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+	0x12, 0x08, 0x0a, 0x04, 0x4e, 0x4f, 0x4e, 0x45, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x45, 0x58,
+	0x50, 0x45, 0x43, 0x54, 0x5f, 0x47, 0x45, 0x4e, 0x5f, 0x45, 0x51, 0x55, 0x41, 0x4c, 0x10, 0x01,
+	0x12, 0x11, 0x0a, 0x0d, 0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x5f, 0x47, 0x45, 0x4e, 0x5f, 0x47,
+	0x54, 0x10, 0x02, 0x2a, 0x30, 0x0a, 0x0b, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4c, 0x65, 0x76,
+	0x65, 0x6c, 0x12, 0x0e, 0x0a, 0x0a, 0x43, 0x4f, 0x4d, 0x4d, 0x49, 0x54, 0x5f, 0x41, 0x4c, 0x4c,
+	0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x43, 0x4f, 0x4d, 0x4d, 0x49, 0x54, 0x5f, 0x4d, 0x41, 0x53,
+	0x54, 0x45, 0x52, 0x10, 0x01, 0x2a, 0x35, 0x0a, 0x0d, 0x51, 0x75, 0x65, 0x72, 0x79, 0x44, 0x75,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x08, 0x0a, 0x04, 0x4c, 0x6f, 0x6e, 0x67, 0x10, 0x00,
+	0x12, 0x09, 0x0a, 0x05, 0x53, 0x68, 0x6f, 0x72, 0x74, 0x10, 0x01, 0x12, 0x0f, 0x0a, 0x0b, 0x4c,
+	0x6f, 0x6e, 0x67, 0x52, 0x65, 0x6c, 0x61, 0x78, 0x41, 0x50, 0x10, 0x02, 0x2a, 0x88, 0x0c, 0x0a,
+	0x0f, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
+	0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x10, 0x00, 0x12, 0x22, 0x0a, 0x1e, 0x43,
+	0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x57, 0x69, 0x74, 0x68, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x01, 0x12,
+	0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x53, 0x65, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x10, 0x02, 0x12, 0x16, 0x0a,
+	0x12, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
+	0x50, 0x75, 0x74, 0x10, 0x03, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x50, 0x75, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73,
+	0x10, 0x04, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x10, 0x05,
+	0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x44, 0x65, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x10, 0x06, 0x12, 0x18,
+	0x0a, 0x14, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x10, 0x07, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4d,
+	0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x10, 0x08, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4d,
+	0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x09, 0x12, 0x23, 0x0a, 0x1f,
+	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52,
+	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10,
+	0x0a, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x10, 0x0b, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
 	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x10, 0x0b, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43,
-	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79,
-	0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x0c, 0x12, 0x25, 0x0a, 0x21, 0x43,
-	0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65,
-	0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65,
-	0x10, 0x0d, 0x12, 0x31, 0x0a, 0x2d, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c,
-	0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61,
-	0x6e, 0x67, 0x65, 0x10, 0x0e, 0x12, 0x36, 0x0a, 0x32, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43,
-	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79,
-	0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e,
-	0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x0f, 0x12, 0x20, 0x0a,
-	0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x10, 0x12,
-	0x25, 0x0a, 0x21, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52,
-	0x61, 0x6e, 0x67, 0x65, 0x10, 0x11, 0x12, 0x2a, 0x0a, 0x26, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
-	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42,
-	0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74,
-	0x10, 0x12, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e,
-	0x6b, 0x10, 0x13, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61,
-	0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x14, 0x12, 0x29, 0x0a, 0x25, 0x43, 0x64, 0x74,
+	0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x0c, 0x12, 0x25, 0x0a, 0x21, 0x43, 0x64, 0x74,
 	0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f,
-	0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75,
-	0x6e, 0x74, 0x10, 0x15, 0x12, 0x30, 0x0a, 0x2c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
+	0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x0d,
+	0x12, 0x31, 0x0a, 0x2d, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65,
+	0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67,
+	0x65, 0x10, 0x0e, 0x12, 0x36, 0x0a, 0x32, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
+	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61,
+	0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52,
+	0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x0f, 0x12, 0x20, 0x0a, 0x1c, 0x43,
+	0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65,
+	0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x10, 0x12, 0x25, 0x0a,
+	0x21, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
+	0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e,
+	0x67, 0x65, 0x10, 0x11, 0x12, 0x2a, 0x0a, 0x26, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x12,
+	0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x10,
+	0x13, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b,
+	0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x14, 0x12, 0x29, 0x0a, 0x25, 0x43, 0x64, 0x74, 0x4d, 0x61,
+	0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65,
+	0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74,
+	0x10, 0x15, 0x12, 0x30, 0x0a, 0x2c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b, 0x65, 0x79,
+	0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e,
+	0x67, 0x65, 0x10, 0x16, 0x12, 0x35, 0x0a, 0x31, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
 	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x4b,
 	0x65, 0x79, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52,
-	0x61, 0x6e, 0x67, 0x65, 0x10, 0x16, 0x12, 0x35, 0x0a, 0x31, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
-	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42,
-	0x79, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x17, 0x12, 0x17, 0x0a,
-	0x13, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x53, 0x69, 0x7a, 0x65, 0x10, 0x18, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
+	0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x17, 0x12, 0x17, 0x0a, 0x13, 0x43,
+	0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x69,
+	0x7a, 0x65, 0x10, 0x18, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x10,
+	0x19, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x61, 0x6e, 0x67,
+	0x65, 0x10, 0x1a, 0x12, 0x2d, 0x0a, 0x29, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
+	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x65,
+	0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65,
+	0x10, 0x1b, 0x12, 0x32, 0x0a, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x6c,
+	0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43,
+	0x6f, 0x75, 0x6e, 0x74, 0x10, 0x1c, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
 	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65,
-	0x79, 0x10, 0x19, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52, 0x61,
-	0x6e, 0x67, 0x65, 0x10, 0x1a, 0x12, 0x2d, 0x0a, 0x29, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43,
-	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79,
-	0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e,
-	0x67, 0x65, 0x10, 0x1b, 0x12, 0x32, 0x0a, 0x2e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x4b, 0x65, 0x79, 0x52,
-	0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67,
-	0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x1c, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4d,
-	0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79,
-	0x4b, 0x65, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x1d, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74,
-	0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42,
-	0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x1e, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4d,
-	0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79,
-	0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1f, 0x12, 0x2e, 0x0a, 0x2a,
-	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47,
-	0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76,
-	0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x20, 0x12, 0x33, 0x0a, 0x2f,
-	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47,
-	0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76,
-	0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10,
-	0x21, 0x12, 0x21, 0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69,
-	0x73, 0x74, 0x10, 0x22, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x10, 0x23, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78,
-	0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x24, 0x12, 0x27, 0x0a, 0x23, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49,
-	0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x25,
-	0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x26, 0x12, 0x21,
-	0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
-	0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10,
-	0x27, 0x12, 0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e,
-	0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x28, 0x2a, 0x8d, 0x03, 0x0a, 0x10, 0x43, 0x64,
-	0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x18,
-	0x0a, 0x14, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79,
-	0x70, 0x65, 0x4e, 0x6f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d,
-	0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x10, 0x01, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74,
-	0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x49, 0x6e,
-	0x64, 0x65, 0x78, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52,
-	0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x03, 0x12,
-	0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
-	0x79, 0x70, 0x65, 0x52, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x04,
-	0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e,
-	0x54, 0x79, 0x70, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x17, 0x0a, 0x13, 0x43,
-	0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4b,
-	0x65, 0x79, 0x10, 0x06, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65,
-	0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x07, 0x12,
-	0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
-	0x79, 0x70, 0x65, 0x4b, 0x65, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x08, 0x12, 0x1a, 0x0a,
-	0x16, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x0d, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74,
-	0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x55, 0x6e, 0x6f,
-	0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x4d, 0x61, 0x70, 0x10, 0x10, 0x12, 0x1e, 0x0a, 0x1a, 0x43,
-	0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4f,
-	0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x4d, 0x61, 0x70, 0x10, 0x11, 0x12, 0x1c, 0x0a, 0x18, 0x43,
-	0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x49,
-	0x6e, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x10, 0x0c, 0x2a, 0x6a, 0x0a, 0x0f, 0x43, 0x64, 0x74,
-	0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x19, 0x0a, 0x15,
-	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x55,
-	0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x00, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
-	0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
-	0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f,
-	0x6e, 0x6c, 0x79, 0x10, 0x02, 0x2a, 0xa8, 0x01, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
-	0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64,
-	0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65,
-	0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x43, 0x72, 0x65, 0x61, 0x74,
-	0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61, 0x74,
-	0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4d, 0x61,
-	0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69,
-	0x6c, 0x10, 0x04, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69,
-	0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x10, 0x08,
-	0x2a, 0xf8, 0x09, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
-	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x10,
-	0x00, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x10, 0x01, 0x12,
-	0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43,
-	0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49,
-	0x6e, 0x73, 0x65, 0x72, 0x74, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x4c, 0x69,
-	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x50, 0x6f, 0x70, 0x10, 0x04,
-	0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x50, 0x6f, 0x70, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x05, 0x12, 0x20,
-	0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x50, 0x6f, 0x70, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x72, 0x6f, 0x6d, 0x10, 0x06,
-	0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x10, 0x07, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74,
-	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x54, 0x72, 0x69,
-	0x6d, 0x10, 0x08, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x10, 0x09, 0x12, 0x1d,
-	0x0a, 0x19, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x49, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x10, 0x0a, 0x12, 0x18, 0x0a,
-	0x14, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
-	0x70, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x0b, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4c, 0x69,
-	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x6f, 0x72, 0x74, 0x10,
-	0x0c, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c,
-	0x69, 0x73, 0x74, 0x10, 0x0d, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
+	0x79, 0x4c, 0x69, 0x73, 0x74, 0x10, 0x1d, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61,
+	0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56,
+	0x61, 0x6c, 0x75, 0x65, 0x10, 0x1e, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
 	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x0e, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64,
-	0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65,
-	0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x0f, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64,
-	0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65,
-	0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x10, 0x12,
-	0x28, 0x0a, 0x24, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e,
-	0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x11, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74,
-	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74,
-	0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x12, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42,
-	0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x13, 0x12, 0x27, 0x0a, 0x23,
+	0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1f, 0x12, 0x2e, 0x0a, 0x2a, 0x43, 0x64,
+	0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74,
+	0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52,
+	0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x20, 0x12, 0x33, 0x0a, 0x2f, 0x43, 0x64,
+	0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74,
+	0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52,
+	0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x21, 0x12,
+	0x21, 0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73, 0x74,
+	0x10, 0x22, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10,
+	0x23, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61,
+	0x6e, 0x67, 0x65, 0x10, 0x24, 0x12, 0x27, 0x0a, 0x23, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x25, 0x12, 0x1c,
+	0x0a, 0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x26, 0x12, 0x21, 0x0a, 0x1d,
+	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47,
+	0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x27, 0x12,
+	0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65,
+	0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x28, 0x2a, 0x8d, 0x03, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4d,
+	0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x18, 0x0a, 0x14,
+	0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65,
+	0x4e, 0x6f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70,
+	0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10,
+	0x01, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72,
+	0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x49, 0x6e, 0x64, 0x65,
+	0x78, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74,
+	0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x03, 0x12, 0x1f, 0x0a,
+	0x1b, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x52, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x04, 0x12, 0x19,
+	0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79,
+	0x70, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74,
+	0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4b, 0x65, 0x79,
+	0x10, 0x06, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75,
+	0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x07, 0x12, 0x1c, 0x0a,
+	0x18, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
+	0x65, 0x4b, 0x65, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x08, 0x12, 0x1a, 0x0a, 0x16, 0x43,
+	0x64, 0x74, 0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x45,
+	0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x0d, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4d, 0x61,
+	0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x55, 0x6e, 0x6f, 0x72, 0x64,
+	0x65, 0x72, 0x65, 0x64, 0x4d, 0x61, 0x70, 0x10, 0x10, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74,
+	0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4f, 0x72, 0x64,
+	0x65, 0x72, 0x65, 0x64, 0x4d, 0x61, 0x70, 0x10, 0x11, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74,
+	0x4d, 0x61, 0x70, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x49, 0x6e, 0x76,
+	0x65, 0x72, 0x74, 0x65, 0x64, 0x10, 0x0c, 0x2a, 0x6a, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x4d, 0x61,
+	0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64,
+	0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x55, 0x70, 0x64,
+	0x61, 0x74, 0x65, 0x10, 0x00, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f, 0x6e,
+	0x6c, 0x79, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c,
+	0x79, 0x10, 0x02, 0x2a, 0xa8, 0x01, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d,
+	0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61,
+	0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f,
+	0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f,
+	0x6e, 0x6c, 0x79, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10,
+	0x04, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4d, 0x61, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x10, 0x08, 0x2a, 0x98,
+	0x0a, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x10, 0x00, 0x12,
+	0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x10, 0x01, 0x12, 0x1a, 0x0a,
+	0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74,
+	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x73,
+	0x65, 0x72, 0x74, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
+	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x50, 0x6f, 0x70, 0x10, 0x04, 0x12, 0x1c,
+	0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x50, 0x6f, 0x70, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x05, 0x12, 0x20, 0x0a, 0x1c,
 	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x47, 0x65, 0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f,
-	0x75, 0x6e, 0x74, 0x10, 0x14, 0x12, 0x2f, 0x0a, 0x2b, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
-	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61,
-	0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52,
-	0x61, 0x6e, 0x67, 0x65, 0x10, 0x15, 0x12, 0x34, 0x0a, 0x30, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
-	0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56,
-	0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b,
-	0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x16, 0x12, 0x25, 0x0a, 0x21,
+	0x50, 0x6f, 0x70, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x72, 0x6f, 0x6d, 0x10, 0x06, 0x12, 0x17,
+	0x0a, 0x13, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x53, 0x65, 0x74, 0x10, 0x07, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4c, 0x69,
+	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x54, 0x72, 0x69, 0x6d, 0x10,
+	0x08, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x10, 0x09, 0x12, 0x1d, 0x0a, 0x19,
 	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73,
-	0x74, 0x10, 0x17, 0x12, 0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56,
-	0x61, 0x6c, 0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x18, 0x12, 0x32, 0x0a, 0x2e, 0x43,
-	0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52,
-	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61,
-	0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x19, 0x12,
-	0x37, 0x0a, 0x33, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65,
-	0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67,
-	0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x1a, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f,
-	0x76, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1b, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74,
-	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d,
-	0x6f, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x72, 0x6f, 0x6d, 0x10, 0x1c, 0x12, 0x21,
-	0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10,
-	0x1d, 0x12, 0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64,
-	0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1e, 0x12, 0x2b, 0x0a, 0x27, 0x43, 0x64, 0x74,
+	0x49, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x10, 0x0a, 0x12, 0x18, 0x0a, 0x14, 0x43,
+	0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53,
+	0x69, 0x7a, 0x65, 0x10, 0x0b, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
+	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x6f, 0x72, 0x74, 0x10, 0x0c, 0x12,
+	0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73,
+	0x74, 0x10, 0x0d, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x0e, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4c,
+	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42,
+	0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x0f, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x4c,
+	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42,
+	0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x10, 0x12, 0x28, 0x0a,
+	0x24, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65,
+	0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x11, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x4c, 0x69,
+	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79,
+	0x52, 0x61, 0x6e, 0x6b, 0x10, 0x12, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x52,
+	0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x13, 0x12, 0x27, 0x0a, 0x23, 0x43, 0x64,
+	0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65,
+	0x74, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e,
+	0x74, 0x10, 0x14, 0x12, 0x2f, 0x0a, 0x2b, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e,
+	0x67, 0x65, 0x10, 0x15, 0x12, 0x34, 0x0a, 0x30, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x42, 0x79, 0x56, 0x61, 0x6c,
+	0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61,
+	0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x16, 0x12, 0x25, 0x0a, 0x21, 0x43, 0x64,
+	0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65,
+	0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x10,
+	0x17, 0x12, 0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c,
+	0x75, 0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x18, 0x12, 0x32, 0x0a, 0x2e, 0x43, 0x64, 0x74,
 	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d,
-	0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43,
-	0x6f, 0x75, 0x6e, 0x74, 0x10, 0x1f, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65, 0x6c, 0x61, 0x74, 0x69,
+	0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x19, 0x12, 0x37, 0x0a,
+	0x33, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x52, 0x65,
+	0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43,
+	0x6f, 0x75, 0x6e, 0x74, 0x10, 0x1a, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
 	0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65,
-	0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x20, 0x12, 0x25, 0x0a, 0x21, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f,
-	0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x21, 0x12,
-	0x2a, 0x0a, 0x26, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52,
-	0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x22, 0x2a, 0xff, 0x01, 0x0a, 0x11,
-	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75,
-	0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x4e, 0x6f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16,
-	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70,
-	0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x01, 0x12, 0x21, 0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x76,
-	0x65, 0x72, 0x73, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x02, 0x12, 0x19, 0x0a, 0x15, 0x43,
-	0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65,
-	0x52, 0x61, 0x6e, 0x6b, 0x10, 0x03, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1b, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x4c, 0x69,
+	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x72, 0x6f, 0x6d, 0x10, 0x1c, 0x12, 0x21, 0x0a, 0x1d,
+	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
+	0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x1d, 0x12,
+	0x26, 0x0a, 0x22, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x1e, 0x12, 0x2b, 0x0a, 0x27, 0x43, 0x64, 0x74, 0x4c, 0x69,
+	0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x42, 0x79, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x43, 0x6f, 0x75,
+	0x6e, 0x74, 0x10, 0x1f, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79,
+	0x52, 0x61, 0x6e, 0x6b, 0x10, 0x20, 0x12, 0x25, 0x0a, 0x21, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65,
+	0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x10, 0x21, 0x12, 0x2a, 0x0a,
+	0x26, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
+	0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x42, 0x79, 0x52, 0x61, 0x6e, 0x6b, 0x52, 0x61, 0x6e,
+	0x67, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x22, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74,
+	0x4c, 0x69, 0x73, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74,
+	0x42, 0x79, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x23, 0x2a, 0xff, 0x01, 0x0a, 0x11, 0x43, 0x64,
+	0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12,
+	0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e,
+	0x54, 0x79, 0x70, 0x65, 0x4e, 0x6f, 0x6e, 0x65, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64,
+	0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x10, 0x01, 0x12, 0x21, 0x0a, 0x1d, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
 	0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x76, 0x65, 0x72,
-	0x73, 0x65, 0x52, 0x61, 0x6e, 0x6b, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x43, 0x6f, 0x75,
-	0x6e, 0x74, 0x10, 0x05, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52,
-	0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x07,
-	0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72,
-	0x6e, 0x54, 0x79, 0x70, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x0d, 0x2a, 0x73, 0x0a,
-	0x10, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61, 0x67,
-	0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74,
-	0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e,
-	0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61,
-	0x67, 0x73, 0x44, 0x65, 0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x22,
-	0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61,
-	0x67, 0x73, 0x44, 0x72, 0x6f, 0x70, 0x44, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73,
-	0x10, 0x02, 0x2a, 0xb0, 0x01, 0x0a, 0x11, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c,
-	0x69, 0x73, 0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66,
-	0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
-	0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x41, 0x64, 0x64, 0x55, 0x6e,
-	0x69, 0x71, 0x75, 0x65, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
-	0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x49, 0x6e, 0x73, 0x65, 0x72,
-	0x74, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x10, 0x02, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64,
-	0x74, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e,
-	0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x04, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69,
-	0x73, 0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74,
-	0x69, 0x61, 0x6c, 0x10, 0x08, 0x2a, 0xda, 0x02, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c,
-	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74,
-	0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x69, 0x74,
-	0x10, 0x00, 0x12, 0x16, 0x0a, 0x12, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x41, 0x64, 0x64, 0x10, 0x01, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64,
-	0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74,
-	0x55, 0x6e, 0x69, 0x6f, 0x6e, 0x10, 0x02, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x48, 0x4c,
-	0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x66, 0x72, 0x65, 0x73,
-	0x68, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x48,
-	0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x46, 0x6f, 0x6c, 0x64, 0x10,
-	0x04, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x1b,
-	0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
-	0x70, 0x47, 0x65, 0x74, 0x55, 0x6e, 0x69, 0x6f, 0x6e, 0x10, 0x06, 0x12, 0x20, 0x0a, 0x1c, 0x43,
+	0x73, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x10, 0x02, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74,
+	0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x61,
+	0x6e, 0x6b, 0x10, 0x03, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52,
+	0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65,
+	0x52, 0x61, 0x6e, 0x6b, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74,
+	0x10, 0x05, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74,
+	0x75, 0x72, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x10, 0x07, 0x12, 0x1b,
+	0x0a, 0x17, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x54,
+	0x79, 0x70, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x10, 0x0d, 0x2a, 0x73, 0x0a, 0x10, 0x43,
+	0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12,
+	0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c,
+	0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a,
+	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61, 0x67, 0x73,
+	0x44, 0x65, 0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e,
+	0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x6f, 0x72, 0x74, 0x46, 0x6c, 0x61, 0x67, 0x73,
+	0x44, 0x72, 0x6f, 0x70, 0x44, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x10, 0x02,
+	0x2a, 0xb0, 0x01, 0x0a, 0x11, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x72, 0x69, 0x74,
+	0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73,
+	0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75,
+	0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x41, 0x64, 0x64, 0x55, 0x6e, 0x69, 0x71,
+	0x75, 0x65, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x42,
+	0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x10, 0x02, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x4c,
+	0x69, 0x73, 0x74, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46,
+	0x61, 0x69, 0x6c, 0x10, 0x04, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x4c, 0x69, 0x73, 0x74,
+	0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61,
+	0x6c, 0x10, 0x08, 0x2a, 0xf9, 0x02, 0x0a, 0x0f, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x48, 0x4c,
+	0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x69, 0x74, 0x10, 0x00,
+	0x12, 0x16, 0x0a, 0x12, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x41, 0x64, 0x64, 0x10, 0x01, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48,
+	0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x55, 0x6e,
+	0x69, 0x6f, 0x6e, 0x10, 0x02, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x43,
+	0x6f, 0x75, 0x6e, 0x74, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c,
+	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x46, 0x6f, 0x6c, 0x64, 0x10, 0x04, 0x12,
+	0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x4f, 0x70, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x1b, 0x0a, 0x17,
+	0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47,
+	0x65, 0x74, 0x55, 0x6e, 0x69, 0x6f, 0x6e, 0x10, 0x06, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74,
+	0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x55,
+	0x6e, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x07, 0x12, 0x24, 0x0a, 0x20, 0x43,
 	0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65,
-	0x74, 0x55, 0x6e, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x07, 0x12, 0x24, 0x0a,
-	0x20, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x47, 0x65, 0x74, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x73, 0x65, 0x63, 0x74, 0x43, 0x6f, 0x75, 0x6e,
-	0x74, 0x10, 0x08, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x53, 0x69, 0x6d, 0x69, 0x6c, 0x61, 0x72,
-	0x69, 0x74, 0x79, 0x10, 0x09, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43,
-	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65,
-	0x10, 0x0a, 0x2a, 0xaa, 0x01, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69,
-	0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c,
-	0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75,
-	0x6c, 0x74, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f, 0x6e,
-	0x6c, 0x79, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f, 0x6e,
-	0x6c, 0x79, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x04,
-	0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46,
-	0x6c, 0x61, 0x67, 0x73, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x46, 0x6f, 0x6c, 0x64, 0x10, 0x08, 0x2a,
-	0xac, 0x04, 0x0a, 0x13, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69,
-	0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65,
-	0x73, 0x69, 0x7a, 0x65, 0x10, 0x00, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74,
-	0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x73,
-	0x65, 0x72, 0x74, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77,
-	0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f,
-	0x76, 0x65, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
-	0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x10, 0x03,
-	0x12, 0x19, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f,
-	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4f, 0x72, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x43,
+	0x74, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x73, 0x65, 0x63, 0x74, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10,
+	0x08, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x53, 0x69, 0x6d, 0x69, 0x6c, 0x61, 0x72, 0x69, 0x74,
+	0x79, 0x10, 0x09, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d,
+	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x10, 0x0a,
+	0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x4d, 0x61, 0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x10, 0x0b, 0x2a,
+	0xaa, 0x01, 0x0a, 0x10, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46,
+	0x6c, 0x61, 0x67, 0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72,
+	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10,
+	0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10,
+	0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10,
+	0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x04, 0x12, 0x1d, 0x0a,
+	0x19, 0x43, 0x64, 0x74, 0x48, 0x4c, 0x4c, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67,
+	0x73, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x46, 0x6f, 0x6c, 0x64, 0x10, 0x08, 0x2a, 0xac, 0x04, 0x0a,
+	0x13, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
+	0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x73, 0x69, 0x7a,
+	0x65, 0x10, 0x00, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73,
+	0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74,
+	0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65,
+	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x10,
+	0x02, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43,
+	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65, 0x74, 0x10, 0x03, 0x12, 0x19, 0x0a,
+	0x15, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x4f, 0x70, 0x4f, 0x72, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42,
+	0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x58,
+	0x6f, 0x72, 0x10, 0x05, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
+	0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x41, 0x6e, 0x64, 0x10, 0x06,
+	0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4e, 0x6f, 0x74, 0x10, 0x07, 0x12, 0x1d, 0x0a, 0x19,
+	0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x4c, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x08, 0x12, 0x1d, 0x0a, 0x19, 0x43,
 	0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
-	0x4f, 0x70, 0x58, 0x6f, 0x72, 0x10, 0x05, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69,
-	0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x41, 0x6e,
-	0x64, 0x10, 0x06, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73,
-	0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4e, 0x6f, 0x74, 0x10, 0x07, 0x12,
-	0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4c, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x08, 0x12, 0x1d,
-	0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d,
-	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x09, 0x12, 0x1a, 0x0a,
-	0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x4f, 0x70, 0x41, 0x64, 0x64, 0x10, 0x0a, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74,
-	0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x53, 0x75, 0x62, 0x74, 0x72, 0x61, 0x63, 0x74, 0x10, 0x0b, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64,
+	0x4f, 0x70, 0x52, 0x53, 0x68, 0x69, 0x66, 0x74, 0x10, 0x09, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64,
 	0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f,
-	0x70, 0x53, 0x65, 0x74, 0x49, 0x6e, 0x74, 0x10, 0x0c, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74,
-	0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70,
-	0x47, 0x65, 0x74, 0x10, 0x0d, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77,
-	0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x6f, 0x75, 0x6e,
-	0x74, 0x10, 0x0e, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73,
-	0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4c, 0x53, 0x63, 0x61, 0x6e, 0x10,
-	0x0f, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43,
-	0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x53, 0x63, 0x61, 0x6e, 0x10, 0x10, 0x12,
-	0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x74, 0x10, 0x11, 0x2a, 0xc0,
-	0x01, 0x0a, 0x14, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69,
-	0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x42, 0x69,
-	0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44,
-	0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x42,
-	0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e,
+	0x70, 0x41, 0x64, 0x64, 0x10, 0x0a, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74,
+	0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x75, 0x62,
+	0x74, 0x72, 0x61, 0x63, 0x74, 0x10, 0x0b, 0x12, 0x1d, 0x0a, 0x19, 0x43, 0x64, 0x74, 0x42, 0x69,
+	0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x53, 0x65,
+	0x74, 0x49, 0x6e, 0x74, 0x10, 0x0c, 0x12, 0x1a, 0x0a, 0x16, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74,
+	0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x47, 0x65, 0x74,
+	0x10, 0x0d, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65,
+	0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x0e,
+	0x12, 0x1c, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x4f, 0x70, 0x4c, 0x53, 0x63, 0x61, 0x6e, 0x10, 0x0f, 0x12, 0x1c,
+	0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x4f, 0x70, 0x52, 0x53, 0x63, 0x61, 0x6e, 0x10, 0x10, 0x12, 0x1d, 0x0a, 0x19,
+	0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x64, 0x4f, 0x70, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x74, 0x10, 0x11, 0x2a, 0xc0, 0x01, 0x0a, 0x14,
 	0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46,
-	0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x02,
-	0x12, 0x1e, 0x0a, 0x1a, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x04,
-	0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72,
-	0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x10,
-	0x08, 0x2a, 0xa5, 0x01, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65,
-	0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x20, 0x0a, 0x1c, 0x43,
-	0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x46,
-	0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x22, 0x0a,
-	0x1e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73, 0x69, 0x7a,
-	0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x46, 0x72, 0x6f, 0x6d, 0x46, 0x72, 0x6f, 0x6e, 0x74, 0x10,
-	0x01, 0x12, 0x21, 0x0a, 0x1d, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52,
-	0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x47, 0x72, 0x6f, 0x77, 0x4f, 0x6e,
-	0x6c, 0x79, 0x10, 0x02, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
-	0x73, 0x65, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x53, 0x68, 0x72,
-	0x69, 0x6e, 0x6b, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x04, 0x2a, 0x84, 0x01, 0x0a, 0x18, 0x43, 0x64,
-	0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77,
-	0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74,
-	0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x00, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x42,
-	0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63,
-	0x74, 0x69, 0x6f, 0x6e, 0x53, 0x61, 0x74, 0x75, 0x72, 0x61, 0x74, 0x65, 0x10, 0x02, 0x12, 0x20,
-	0x0a, 0x1c, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72,
-	0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x57, 0x72, 0x61, 0x70, 0x10, 0x04,
-	0x32, 0xb8, 0x1c, 0x0a, 0x03, 0x4b, 0x56, 0x53, 0x12, 0x6a, 0x0a, 0x07, 0x56, 0x65, 0x72, 0x73,
-	0x69, 0x6f, 0x6e, 0x12, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x22, 0x00, 0x12, 0x4f, 0x0a, 0x03, 0x50, 0x75, 0x74, 0x12, 0x29, 0x2e, 0x63, 0x6f,
+	0x6c, 0x61, 0x67, 0x73, 0x12, 0x1f, 0x0a, 0x1b, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69,
+	0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61,
+	0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77,
+	0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64, 0x74,
+	0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67,
+	0x73, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x02, 0x12, 0x1e, 0x0a,
+	0x1a, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c, 0x10, 0x04, 0x12, 0x1f, 0x0a,
+	0x1b, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x61, 0x72, 0x74, 0x69, 0x61, 0x6c, 0x10, 0x08, 0x2a, 0xa5,
+	0x01, 0x0a, 0x15, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73,
+	0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x42,
+	0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67,
+	0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x22, 0x0a, 0x1e, 0x43, 0x64,
+	0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c,
+	0x61, 0x67, 0x73, 0x46, 0x72, 0x6f, 0x6d, 0x46, 0x72, 0x6f, 0x6e, 0x74, 0x10, 0x01, 0x12, 0x21,
+	0x0a, 0x1d, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52, 0x65, 0x73, 0x69,
+	0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x47, 0x72, 0x6f, 0x77, 0x4f, 0x6e, 0x6c, 0x79, 0x10,
+	0x02, 0x12, 0x23, 0x0a, 0x1f, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x52,
+	0x65, 0x73, 0x69, 0x7a, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x53, 0x68, 0x72, 0x69, 0x6e, 0x6b,
+	0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x04, 0x2a, 0x84, 0x01, 0x0a, 0x18, 0x43, 0x64, 0x74, 0x42, 0x69,
+	0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x1c, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73,
+	0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x46,
+	0x61, 0x69, 0x6c, 0x10, 0x00, 0x12, 0x24, 0x0a, 0x20, 0x43, 0x64, 0x74, 0x42, 0x69, 0x74, 0x77,
+	0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f, 0x77, 0x41, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x53, 0x61, 0x74, 0x75, 0x72, 0x61, 0x74, 0x65, 0x10, 0x02, 0x12, 0x20, 0x0a, 0x1c, 0x43,
+	0x64, 0x74, 0x42, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f,
+	0x77, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x57, 0x72, 0x61, 0x70, 0x10, 0x04, 0x2a, 0x43, 0x0a,
+	0x0c, 0x45, 0x78, 0x70, 0x52, 0x65, 0x61, 0x64, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x12, 0x17, 0x0a,
+	0x13, 0x45, 0x78, 0x70, 0x52, 0x65, 0x61, 0x64, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66,
+	0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x45, 0x78, 0x70, 0x52, 0x65, 0x61,
+	0x64, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x45, 0x76, 0x61, 0x6c, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c,
+	0x10, 0x10, 0x2a, 0xbd, 0x01, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46,
+	0x6c, 0x61, 0x67, 0x73, 0x12, 0x18, 0x0a, 0x14, 0x45, 0x78, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x46, 0x6c, 0x61, 0x67, 0x73, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x10, 0x00, 0x12, 0x1b,
+	0x0a, 0x17, 0x45, 0x78, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x1b, 0x0a, 0x17, 0x45,
+	0x78, 0x70, 0x57, 0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x55, 0x70, 0x64, 0x61,
+	0x74, 0x65, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x02, 0x12, 0x1c, 0x0a, 0x18, 0x45, 0x78, 0x70, 0x57,
+	0x72, 0x69, 0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x10, 0x04, 0x12, 0x1d, 0x0a, 0x19, 0x45, 0x78, 0x70, 0x57, 0x72, 0x69,
+	0x74, 0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x4e, 0x6f, 0x46,
+	0x61, 0x69, 0x6c, 0x10, 0x08, 0x12, 0x1b, 0x0a, 0x17, 0x45, 0x78, 0x70, 0x57, 0x72, 0x69, 0x74,
+	0x65, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x45, 0x76, 0x61, 0x6c, 0x4e, 0x6f, 0x46, 0x61, 0x69, 0x6c,
+	0x10, 0x10, 0x2a, 0x47, 0x0a, 0x10, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x18, 0x0a, 0x14, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65,
+	0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x65, 0x61, 0x64, 0x10, 0x00,
+	0x12, 0x19, 0x0a, 0x15, 0x45, 0x78, 0x70, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x54, 0x79, 0x70, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x10, 0x01, 0x32, 0x8f, 0x29, 0x0a, 0x03,
+	0x4b, 0x56, 0x53, 0x12, 0x6a, 0x0a, 0x07, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x2d,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x56,
+	0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2e, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x56, 0x65,
+	0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12,
+	0x4f, 0x0a, 0x03, 0x50, 0x75, 0x74, 0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x00,
+	0x12, 0x4f, 0x0a, 0x03, 0x41, 0x64, 0x64, 0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22,
+	0x00, 0x12, 0x52, 0x0a, 0x06, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64, 0x12, 0x29, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
 	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74, 0x52,
 	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
 	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72,
-	0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x4f, 0x0a, 0x03, 0x41, 0x64, 0x64, 0x12, 0x29, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45,
-	0x72, 0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x52, 0x0a, 0x06, 0x41, 0x70, 0x70, 0x65, 0x6e, 0x64,
+	0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x53, 0x0a, 0x07, 0x50, 0x72, 0x65, 0x70, 0x65, 0x6e, 0x64,
 	0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
 	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
 	0x65, 0x50, 0x75, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x53, 0x0a, 0x07, 0x50, 0x72,
-	0x65, 0x70, 0x65, 0x6e, 0x64, 0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x50, 0x75, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x00, 0x12,
-	0x61, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x61, 0x0a, 0x03, 0x47, 0x65,
+	0x74, 0x12, 0x29, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
 	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x22, 0x00, 0x12, 0x6d, 0x0a, 0x09, 0x47, 0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12,
-	0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x47, 0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
-	0x65, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-	0x00, 0x12, 0x67, 0x0a, 0x06, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x12, 0x2c, 0x2e, 0x63, 0x6f,
+	0x6b, 0x65, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x69, 0x6e,
+	0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a,
+	0x09, 0x47, 0x65, 0x74, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12, 0x2f, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x65, 0x74, 0x48, 0x65,
+	0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73,
-	0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x67, 0x0a, 0x06, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x12, 0x2c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x69, 0x6e, 0x67,
+	0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x67, 0x0a, 0x06,
+	0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x12, 0x2c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
 	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x22, 0x00, 0x12, 0x53, 0x0a, 0x05, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x12, 0x2b, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x6f, 0x75,
-	0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x00, 0x12, 0x79, 0x0a, 0x0c, 0x42, 0x61, 0x74, 0x63,
-	0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x12, 0x32, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70,
-	0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x33, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x42, 0x61, 0x74,
-	0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x22, 0x00, 0x12, 0x76, 0x0a, 0x0b, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x6e, 0x64,
-	0x65, 0x78, 0x12, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x69, 0x73, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x67, 0x0a, 0x06, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12,
+	0x2c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x53,
+	0x0a, 0x05, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x12, 0x2b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x6f, 0x75, 0x63, 0x68, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x45, 0x72, 0x72, 0x6f,
+	0x72, 0x22, 0x00, 0x12, 0x69, 0x0a, 0x07, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x12, 0x2d,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4f,
+	0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x69,
+	0x6e, 0x67, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x79,
+	0x0a, 0x0c, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x12, 0x32,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x42,
+	0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
 	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x32, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x70, 0x0a, 0x09, 0x44,
-	0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x69, 0x6b, 0x65, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x65, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x76, 0x0a, 0x0b, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64,
-	0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e,
-	0x64, 0x65, 0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a,
-	0x08, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x12, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61,
-	0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
-	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
-	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61,
-	0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x76, 0x0a, 0x0b,
-	0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x12, 0x31, 0x2e, 0x63, 0x6f,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x49,
+	0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x32, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x67, 0x69,
-	0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x32,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52,
-	0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x22, 0x00, 0x12, 0x6a, 0x0a, 0x07, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x12,
-	0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2e,
+	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61,
+	0x74, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
+	0x00, 0x12, 0x70, 0x0a, 0x09, 0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x2f,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
 	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44,
-	0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
-	0x12, 0x6a, 0x0a, 0x07, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46, 0x12, 0x2d, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74,
-	0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2e, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x55,
-	0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a,
-	0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d,
-	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55, 0x44, 0x46, 0x45, 0x78,
-	0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55, 0x44, 0x46,
-	0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-	0x00, 0x12, 0x73, 0x0a, 0x0a, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x12,
+	0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
 	0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
 	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x44, 0x72, 0x6f, 0x70, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a, 0x08, 0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x12,
+	0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x54, 0x72, 0x75, 0x6e, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x22, 0x00, 0x12, 0x76, 0x0a, 0x0b, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44,
+	0x46, 0x12, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
 	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a, 0x08, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73,
-	0x65, 0x72, 0x12, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x32, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x55, 0x44, 0x46,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6a, 0x0a, 0x07, 0x44, 0x72,
+	0x6f, 0x70, 0x55, 0x44, 0x46, 0x12, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6a, 0x0a, 0x07, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44,
+	0x46, 0x12, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x44, 0x46, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65,
+	0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
 	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x7f, 0x0a, 0x0e, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50,
-	0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x12, 0x34, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x69, 0x6b, 0x65, 0x55, 0x44, 0x46, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x55, 0x73, 0x65, 0x72, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
 	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50, 0x61,
-	0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x35, 0x2e,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x55, 0x73,
+	0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a, 0x08,
+	0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65, 0x72, 0x12, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65,
+	0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x55, 0x73, 0x65,
+	0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x7f, 0x0a, 0x0e, 0x43,
+	0x68, 0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x12, 0x34, 0x2e,
 	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
 	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x68,
-	0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x52,
-	0x6f, 0x6c, 0x65, 0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
-	0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65,
-	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x76, 0x0a, 0x0b, 0x52,
-	0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x31, 0x2e, 0x63, 0x6f, 0x6d,
+	0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x35, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f,
+	0x72, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a,
+	0x47, 0x72, 0x61, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d,
 	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
-	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b,
-	0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x32, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65,
-	0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72,
-	0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74,
+	0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61,
+	0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
+	0x00, 0x12, 0x76, 0x0a, 0x0b, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73,
+	0x12, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x32, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x51, 0x75, 0x65,
+	0x72, 0x79, 0x55, 0x73, 0x65, 0x72, 0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65,
+	0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55,
+	0x73, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73,
+	0x0a, 0x0a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x30, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65,
+	0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c,
+	0x65, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
 	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75,
 	0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
 	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x55, 0x73, 0x65, 0x72, 0x73, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x52, 0x6f, 0x6c, 0x65, 0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x6f, 0x6c, 0x65,
-	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x6f,
-	0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a,
-	0x0a, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x30, 0x2e, 0x63, 0x6f,
+	0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x6d, 0x0a, 0x08, 0x44, 0x72, 0x6f, 0x70,
+	0x52, 0x6f, 0x6c, 0x65, 0x12, 0x2e, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x82, 0x01, 0x0a, 0x0f, 0x47, 0x72, 0x61, 0x6e,
+	0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x12, 0x35, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e,
+	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e,
+	0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x36, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67,
+	0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x85, 0x01, 0x0a,
+	0x10, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65,
+	0x73, 0x12, 0x36, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67,
+	0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x37, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65,
+	0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x22, 0x00, 0x12, 0x79, 0x0a, 0x0c, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77,
+	0x6c, 0x69, 0x73, 0x74, 0x12, 0x32, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73,
+	0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f,
+	0x77, 0x6c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12,
+	0x70, 0x0a, 0x09, 0x53, 0x65, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x12, 0x2f, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74,
+	0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x30, 0x2e,
 	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
-	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x72,
-	0x65, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x22, 0x00, 0x12, 0x6d, 0x0a, 0x08, 0x44, 0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x2e,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44,
-	0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f,
-	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
-	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x44,
-	0x72, 0x6f, 0x70, 0x52, 0x6f, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-	0x00, 0x12, 0x82, 0x01, 0x0a, 0x0f, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69,
-	0x6c, 0x65, 0x67, 0x65, 0x73, 0x12, 0x35, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65,
+	0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
+	0x00, 0x12, 0x65, 0x0a, 0x04, 0x53, 0x63, 0x61, 0x6e, 0x12, 0x2a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x63, 0x61, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
 	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61, 0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69,
-	0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x36, 0x2e, 0x63,
-	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
-	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x47, 0x72, 0x61,
-	0x6e, 0x74, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x85, 0x01, 0x0a, 0x10, 0x52, 0x65, 0x76, 0x6f, 0x6b,
-	0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x12, 0x36, 0x2e, 0x63, 0x6f,
-	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f,
-	0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x37, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x50, 0x72, 0x69, 0x76, 0x69, 0x6c,
-	0x65, 0x67, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x79,
-	0x0a, 0x0c, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x12, 0x32,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x30, 0x01, 0x12, 0x67, 0x0a, 0x05, 0x51, 0x75, 0x65, 0x72,
+	0x79, 0x12, 0x2b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d,
 	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
 	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53,
-	0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
-	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
-	0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x70, 0x0a, 0x09, 0x53, 0x65, 0x74,
-	0x51, 0x75, 0x6f, 0x74, 0x61, 0x73, 0x12, 0x2f, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
-	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
-	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x73,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
+	0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x30,
+	0x01, 0x12, 0x61, 0x0a, 0x04, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x2a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x22, 0x00, 0x12, 0x64, 0x0a, 0x05, 0x4e, 0x6f, 0x64, 0x65, 0x73, 0x12, 0x2b, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x6f,
+	0x64, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2c, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x6f, 0x64, 0x65, 0x73,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x73, 0x0a, 0x0a, 0x4e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x12, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12,
+	0x61, 0x0a, 0x04, 0x53, 0x65, 0x74, 0x73, 0x12, 0x2a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65,
 	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41,
-	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61,
-	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x65, 0x0a, 0x04, 0x53,
-	0x63, 0x61, 0x6e, 0x12, 0x2a, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70,
 	0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73,
-	0x70, 0x69, 0x6b, 0x65, 0x53, 0x63, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-	0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
-	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
-	0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
-	0x30, 0x01, 0x12, 0x67, 0x0a, 0x05, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x2b, 0x2e, 0x63, 0x6f,
+	0x70, 0x69, 0x6b, 0x65, 0x53, 0x65, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x22, 0x00, 0x12, 0x6a, 0x0a, 0x07, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x73, 0x12, 0x2d, 0x2e,
+	0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61,
+	0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e,
+	0x64, 0x65, 0x78, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2e, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x64,
+	0x65, 0x78, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x7c,
+	0x0a, 0x0e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65,
+	0x12, 0x34, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x30, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x30, 0x01, 0x12, 0x79, 0x0a, 0x0c,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x12, 0x32, 0x2e, 0x63,
+	0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65,
+	0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65,
+	0x72, 0x79, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x7f, 0x0a, 0x0f, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x35, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x7b, 0x0a, 0x0d, 0x55, 0x44, 0x46, 0x54,
+	0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x33, 0x2e, 0x63, 0x6f, 0x6d, 0x2e,
+	0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e,
+	0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x55, 0x44, 0x46, 0x54, 0x61, 0x73,
+	0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x33,
+	0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64,
+	0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x54,
+	0x61, 0x73, 0x6b, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x88, 0x01, 0x0a, 0x11, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74,
+	0x65, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x37, 0x2e, 0x63, 0x6f,
 	0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d,
-	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+	0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x65, 0x63,
+	0x75, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x38, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73,
+	0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f,
+	0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b,
+	0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
+	0x12, 0x67, 0x0a, 0x06, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x2c, 0x2e, 0x63, 0x6f, 0x6d,
+	0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f,
+	0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x69,
+	0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
 	0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e,
-	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x30, 0x01, 0x42, 0x0e, 0x5a, 0x0c, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x3b, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x33,
+	0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x64, 0x0a, 0x05, 0x41, 0x62, 0x6f,
+	0x72, 0x74, 0x12, 0x2b, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69,
+	0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70,
+	0x69, 0x6b, 0x65, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x2c, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e,
+	0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x41, 0x62, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12,
+	0x8b, 0x01, 0x0a, 0x12, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f,
+	0x42, 0x61, 0x73, 0x65, 0x36, 0x34, 0x12, 0x38, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72,
+	0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65,
+	0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f,
+	0x6e, 0x54, 0x6f, 0x42, 0x61, 0x73, 0x65, 0x36, 0x34, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x39, 0x2e, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b, 0x65,
+	0x2e, 0x64, 0x61, 0x65, 0x6d, 0x6f, 0x6e, 0x2e, 0x41, 0x65, 0x72, 0x6f, 0x73, 0x70, 0x69, 0x6b,
+	0x65, 0x45, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f, 0x42, 0x61, 0x73,
+	0x65, 0x36, 0x34, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x42, 0x0e, 0x5a,
+	0x0c, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x3b, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -11922,412 +14924,535 @@ func file_asld_kvs_proto_rawDescGZIP() []byte {
 	return file_asld_kvs_proto_rawDescData
 }
 
-var file_asld_kvs_proto_enumTypes = make([]protoimpl.EnumInfo, 32)
-var file_asld_kvs_proto_msgTypes = make([]protoimpl.MessageInfo, 107)
+var file_asld_kvs_proto_enumTypes = make([]protoimpl.EnumInfo, 39)
+var file_asld_kvs_proto_msgTypes = make([]protoimpl.MessageInfo, 142)
 var file_asld_kvs_proto_goTypes = []interface{}{
-	(ParticleType)(0),                         // 0: com.aerospike.daemon.ParticleType
-	(ExpType)(0),                              // 1: com.aerospike.daemon.ExpType
-	(ExpOp)(0),                                // 2: com.aerospike.daemon.ExpOp
-	(ReadModeAP)(0),                           // 3: com.aerospike.daemon.ReadModeAP
-	(ReadModeSC)(0),                           // 4: com.aerospike.daemon.ReadModeSC
-	(ReplicaPolicy)(0),                        // 5: com.aerospike.daemon.ReplicaPolicy
-	(ConsistencyLevel)(0),                     // 6: com.aerospike.daemon.ConsistencyLevel
-	(IndexType)(0),                            // 7: com.aerospike.daemon.IndexType
-	(IndexCollectionType)(0),                  // 8: com.aerospike.daemon.IndexCollectionType
-	(AuthMode)(0),                             // 9: com.aerospike.daemon.AuthMode
-	(UDFLanguage)(0),                          // 10: com.aerospike.daemon.UDFLanguage
-	(ListOrderType)(0),                        // 11: com.aerospike.daemon.ListOrderType
-	(MapOrderType)(0),                         // 12: com.aerospike.daemon.MapOrderType
-	(OperationType)(0),                        // 13: com.aerospike.daemon.OperationType
-	(RecordExistsAction)(0),                   // 14: com.aerospike.daemon.RecordExistsAction
-	(GenerationPolicy)(0),                     // 15: com.aerospike.daemon.GenerationPolicy
-	(CommitLevel)(0),                          // 16: com.aerospike.daemon.CommitLevel
-	(QueryDuration)(0),                        // 17: com.aerospike.daemon.QueryDuration
-	(CdtMapCommandOp)(0),                      // 18: com.aerospike.daemon.CdtMapCommandOp
-	(CdtMapReturnType)(0),                     // 19: com.aerospike.daemon.CdtMapReturnType
-	(CdtMapWriteMode)(0),                      // 20: com.aerospike.daemon.CdtMapWriteMode
-	(CdtMapWriteFlags)(0),                     // 21: com.aerospike.daemon.CdtMapWriteFlags
-	(CdtListCommandOp)(0),                     // 22: com.aerospike.daemon.CdtListCommandOp
-	(CdtListReturnType)(0),                    // 23: com.aerospike.daemon.CdtListReturnType
-	(CdtListSortFlags)(0),                     // 24: com.aerospike.daemon.CdtListSortFlags
-	(CdtListWriteFlags)(0),                    // 25: com.aerospike.daemon.CdtListWriteFlags
-	(CdtHLLCommandOp)(0),                      // 26: com.aerospike.daemon.CdtHLLCommandOp
-	(CdtHLLWriteFlags)(0),                     // 27: com.aerospike.daemon.CdtHLLWriteFlags
-	(CdtBitwiseCommandOp)(0),                  // 28: com.aerospike.daemon.CdtBitwiseCommandOp
-	(CdtBitwiseWriteFlags)(0),                 // 29: com.aerospike.daemon.CdtBitwiseWriteFlags
-	(CdtBitwiseResizeFlags)(0),                // 30: com.aerospike.daemon.CdtBitwiseResizeFlags
-	(CdtBitwiseOverflowAction)(0),             // 31: com.aerospike.daemon.CdtBitwiseOverflowAction
-	(*Expression)(nil),                        // 32: com.aerospike.daemon.Expression
-	(*ReadPolicy)(nil),                        // 33: com.aerospike.daemon.ReadPolicy
-	(*WritePolicy)(nil),                       // 34: com.aerospike.daemon.WritePolicy
-	(*AdminPolicy)(nil),                       // 35: com.aerospike.daemon.AdminPolicy
-	(*InfoPolicy)(nil),                        // 36: com.aerospike.daemon.InfoPolicy
-	(*BatchReadPolicy)(nil),                   // 37: com.aerospike.daemon.BatchReadPolicy
-	(*BatchWritePolicy)(nil),                  // 38: com.aerospike.daemon.BatchWritePolicy
-	(*BatchDeletePolicy)(nil),                 // 39: com.aerospike.daemon.BatchDeletePolicy
-	(*BatchUDFPolicy)(nil),                    // 40: com.aerospike.daemon.BatchUDFPolicy
-	(*BatchPolicy)(nil),                       // 41: com.aerospike.daemon.BatchPolicy
-	(*Bin)(nil),                               // 42: com.aerospike.daemon.Bin
-	(*List)(nil),                              // 43: com.aerospike.daemon.List
-	(*MapEntry)(nil),                          // 44: com.aerospike.daemon.MapEntry
-	(*Map)(nil),                               // 45: com.aerospike.daemon.Map
-	(*JsonEntry)(nil),                         // 46: com.aerospike.daemon.JsonEntry
-	(*Json)(nil),                              // 47: com.aerospike.daemon.Json
-	(*UDFMeta)(nil),                           // 48: com.aerospike.daemon.UDFMeta
-	(*Value)(nil),                             // 49: com.aerospike.daemon.Value
-	(*Key)(nil),                               // 50: com.aerospike.daemon.Key
-	(*Record)(nil),                            // 51: com.aerospike.daemon.Record
-	(*Error)(nil),                             // 52: com.aerospike.daemon.Error
-	(*BatchRecord)(nil),                       // 53: com.aerospike.daemon.BatchRecord
-	(*CDTContext)(nil),                        // 54: com.aerospike.daemon.CDTContext
-	(*Operation)(nil),                         // 55: com.aerospike.daemon.Operation
-	(*StdOperation)(nil),                      // 56: com.aerospike.daemon.StdOperation
-	(*BatchWrite)(nil),                        // 57: com.aerospike.daemon.BatchWrite
-	(*BatchRead)(nil),                         // 58: com.aerospike.daemon.BatchRead
-	(*BatchDelete)(nil),                       // 59: com.aerospike.daemon.BatchDelete
-	(*BatchUDF)(nil),                          // 60: com.aerospike.daemon.BatchUDF
-	(*UserRole)(nil),                          // 61: com.aerospike.daemon.UserRole
-	(*Role)(nil),                              // 62: com.aerospike.daemon.Role
-	(*Privilege)(nil),                         // 63: com.aerospike.daemon.Privilege
-	(*PartitionStatus)(nil),                   // 64: com.aerospike.daemon.PartitionStatus
-	(*PartitionFilter)(nil),                   // 65: com.aerospike.daemon.PartitionFilter
-	(*AerospikeSingleResponse)(nil),           // 66: com.aerospike.daemon.AerospikeSingleResponse
-	(*AerospikeGetRequest)(nil),               // 67: com.aerospike.daemon.AerospikeGetRequest
-	(*AerospikeGetHeaderRequest)(nil),         // 68: com.aerospike.daemon.AerospikeGetHeaderRequest
-	(*AerospikeExistsRequest)(nil),            // 69: com.aerospike.daemon.AerospikeExistsRequest
-	(*AerospikeExistsResponse)(nil),           // 70: com.aerospike.daemon.AerospikeExistsResponse
-	(*AerospikeDeleteRequest)(nil),            // 71: com.aerospike.daemon.AerospikeDeleteRequest
-	(*AerospikeDeleteResponse)(nil),           // 72: com.aerospike.daemon.AerospikeDeleteResponse
-	(*AerospikeTouchRequest)(nil),             // 73: com.aerospike.daemon.AerospikeTouchRequest
-	(*AerospikePutRequest)(nil),               // 74: com.aerospike.daemon.AerospikePutRequest
-	(*BatchOperate)(nil),                      // 75: com.aerospike.daemon.BatchOperate
-	(*AerospikeBatchOperateRequest)(nil),      // 76: com.aerospike.daemon.AerospikeBatchOperateRequest
-	(*AerospikeBatchOperateResponse)(nil),     // 77: com.aerospike.daemon.AerospikeBatchOperateResponse
-	(*AerospikeCreateIndexRequest)(nil),       // 78: com.aerospike.daemon.AerospikeCreateIndexRequest
-	(*AerospikeCreateIndexResponse)(nil),      // 79: com.aerospike.daemon.AerospikeCreateIndexResponse
-	(*AerospikeDropIndexRequest)(nil),         // 80: com.aerospike.daemon.AerospikeDropIndexRequest
-	(*AerospikeDropIndexResponse)(nil),        // 81: com.aerospike.daemon.AerospikeDropIndexResponse
-	(*AerospikeTruncateRequest)(nil),          // 82: com.aerospike.daemon.AerospikeTruncateRequest
-	(*AerospikeTruncateResponse)(nil),         // 83: com.aerospike.daemon.AerospikeTruncateResponse
-	(*AerospikeRegisterUDFRequest)(nil),       // 84: com.aerospike.daemon.AerospikeRegisterUDFRequest
-	(*AerospikeRegisterUDFResponse)(nil),      // 85: com.aerospike.daemon.AerospikeRegisterUDFResponse
-	(*AerospikeDropUDFRequest)(nil),           // 86: com.aerospike.daemon.AerospikeDropUDFRequest
-	(*AerospikeDropUDFResponse)(nil),          // 87: com.aerospike.daemon.AerospikeDropUDFResponse
-	(*AerospikeListUDFRequest)(nil),           // 88: com.aerospike.daemon.AerospikeListUDFRequest
-	(*AerospikeListUDFResponse)(nil),          // 89: com.aerospike.daemon.AerospikeListUDFResponse
-	(*AerospikeUDFExecuteRequest)(nil),        // 90: com.aerospike.daemon.AerospikeUDFExecuteRequest
-	(*AerospikeUDFExecuteResponse)(nil),       // 91: com.aerospike.daemon.AerospikeUDFExecuteResponse
-	(*AerospikeCreateUserRequest)(nil),        // 92: com.aerospike.daemon.AerospikeCreateUserRequest
-	(*AerospikeCreateUserResponse)(nil),       // 93: com.aerospike.daemon.AerospikeCreateUserResponse
-	(*AerospikeDropUserRequest)(nil),          // 94: com.aerospike.daemon.AerospikeDropUserRequest
-	(*AerospikeDropUserResponse)(nil),         // 95: com.aerospike.daemon.AerospikeDropUserResponse
-	(*AerospikeChangePasswordRequest)(nil),    // 96: com.aerospike.daemon.AerospikeChangePasswordRequest
-	(*AerospikeChangePasswordResponse)(nil),   // 97: com.aerospike.daemon.AerospikeChangePasswordResponse
-	(*AerospikeGrantRolesRequest)(nil),        // 98: com.aerospike.daemon.AerospikeGrantRolesRequest
-	(*AerospikeGrantRolesResponse)(nil),       // 99: com.aerospike.daemon.AerospikeGrantRolesResponse
-	(*AerospikeRevokeRolesRequest)(nil),       // 100: com.aerospike.daemon.AerospikeRevokeRolesRequest
-	(*AerospikeRevokeRolesResponse)(nil),      // 101: com.aerospike.daemon.AerospikeRevokeRolesResponse
-	(*AerospikeQueryUsersRequest)(nil),        // 102: com.aerospike.daemon.AerospikeQueryUsersRequest
-	(*AerospikeQueryUsersResponse)(nil),       // 103: com.aerospike.daemon.AerospikeQueryUsersResponse
-	(*AerospikeQueryRolesRequest)(nil),        // 104: com.aerospike.daemon.AerospikeQueryRolesRequest
-	(*AerospikeQueryRolesResponse)(nil),       // 105: com.aerospike.daemon.AerospikeQueryRolesResponse
-	(*AerospikeCreateRoleRequest)(nil),        // 106: com.aerospike.daemon.AerospikeCreateRoleRequest
-	(*AerospikeCreateRoleResponse)(nil),       // 107: com.aerospike.daemon.AerospikeCreateRoleResponse
-	(*AerospikeDropRoleRequest)(nil),          // 108: com.aerospike.daemon.AerospikeDropRoleRequest
-	(*AerospikeDropRoleResponse)(nil),         // 109: com.aerospike.daemon.AerospikeDropRoleResponse
-	(*AerospikeGrantPrivilegesRequest)(nil),   // 110: com.aerospike.daemon.AerospikeGrantPrivilegesRequest
-	(*AerospikeGrantPrivilegesResponse)(nil),  // 111: com.aerospike.daemon.AerospikeGrantPrivilegesResponse
-	(*AerospikeRevokePrivilegesRequest)(nil),  // 112: com.aerospike.daemon.AerospikeRevokePrivilegesRequest
-	(*AerospikeRevokePrivilegesResponse)(nil), // 113: com.aerospike.daemon.AerospikeRevokePrivilegesResponse
-	(*AerospikeSetAllowlistRequest)(nil),      // 114: com.aerospike.daemon.AerospikeSetAllowlistRequest
-	(*AerospikeSetAllowlistResponse)(nil),     // 115: com.aerospike.daemon.AerospikeSetAllowlistResponse
-	(*AerospikeSetQuotasRequest)(nil),         // 116: com.aerospike.daemon.AerospikeSetQuotasRequest
-	(*AerospikeSetQuotasResponse)(nil),        // 117: com.aerospike.daemon.AerospikeSetQuotasResponse
-	(*AerospikeScanRequest)(nil),              // 118: com.aerospike.daemon.AerospikeScanRequest
-	(*AerospikeStreamResponse)(nil),           // 119: com.aerospike.daemon.AerospikeStreamResponse
-	(*AerospikeQueryRequest)(nil),             // 120: com.aerospike.daemon.AerospikeQueryRequest
-	(*AerospikeVersionRequest)(nil),           // 121: com.aerospike.daemon.AerospikeVersionRequest
-	(*AerospikeVersionResponse)(nil),          // 122: com.aerospike.daemon.AerospikeVersionResponse
-	(*BackgroundExecutePolicy)(nil),           // 123: com.aerospike.daemon.BackgroundExecutePolicy
-	(*MultiPolicy)(nil),                       // 124: com.aerospike.daemon.MultiPolicy
-	(*ScanPolicy)(nil),                        // 125: com.aerospike.daemon.ScanPolicy
-	(*QueryPolicy)(nil),                       // 126: com.aerospike.daemon.QueryPolicy
-	(*UDFCall)(nil),                           // 127: com.aerospike.daemon.UDFCall
-	(*Statement)(nil),                         // 128: com.aerospike.daemon.Statement
-	(*QueryFilter)(nil),                       // 129: com.aerospike.daemon.QueryFilter
-	(*CdtMapPolicy)(nil),                      // 130: com.aerospike.daemon.CdtMapPolicy
-	(*CdtMapOperation)(nil),                   // 131: com.aerospike.daemon.CdtMapOperation
-	(*CdtListPolicy)(nil),                     // 132: com.aerospike.daemon.CdtListPolicy
-	(*CdtListOperation)(nil),                  // 133: com.aerospike.daemon.CdtListOperation
-	(*CdtHLLPolicy)(nil),                      // 134: com.aerospike.daemon.CdtHLLPolicy
-	(*CdtHLLOperation)(nil),                   // 135: com.aerospike.daemon.CdtHLLOperation
-	(*CdtBitwisePolicy)(nil),                  // 136: com.aerospike.daemon.CdtBitwisePolicy
-	(*CdtBitwiseOperation)(nil),               // 137: com.aerospike.daemon.CdtBitwiseOperation
-	nil,                                       // 138: com.aerospike.daemon.Record.BinsEntry
+	(ParticleType)(0),                           // 0: com.aerospike.daemon.ParticleType
+	(ExpType)(0),                                // 1: com.aerospike.daemon.ExpType
+	(ExpOp)(0),                                  // 2: com.aerospike.daemon.ExpOp
+	(ReadModeAP)(0),                             // 3: com.aerospike.daemon.ReadModeAP
+	(ReadModeSC)(0),                             // 4: com.aerospike.daemon.ReadModeSC
+	(ReplicaPolicy)(0),                          // 5: com.aerospike.daemon.ReplicaPolicy
+	(ConsistencyLevel)(0),                       // 6: com.aerospike.daemon.ConsistencyLevel
+	(IndexType)(0),                              // 7: com.aerospike.daemon.IndexType
+	(IndexCollectionType)(0),                    // 8: com.aerospike.daemon.IndexCollectionType
+	(AuthMode)(0),                               // 9: com.aerospike.daemon.AuthMode
+	(UDFLanguage)(0),                            // 10: com.aerospike.daemon.UDFLanguage
+	(ListOrderType)(0),                          // 11: com.aerospike.daemon.ListOrderType
+	(MapOrderType)(0),                           // 12: com.aerospike.daemon.MapOrderType
+	(TxnState)(0),                               // 13: com.aerospike.daemon.TxnState
+	(CommitStatus)(0),                           // 14: com.aerospike.daemon.CommitStatus
+	(AbortStatus)(0),                            // 15: com.aerospike.daemon.AbortStatus
+	(TaskStatus)(0),                             // 16: com.aerospike.daemon.TaskStatus
+	(OperationType)(0),                          // 17: com.aerospike.daemon.OperationType
+	(RecordExistsAction)(0),                     // 18: com.aerospike.daemon.RecordExistsAction
+	(GenerationPolicy)(0),                       // 19: com.aerospike.daemon.GenerationPolicy
+	(CommitLevel)(0),                            // 20: com.aerospike.daemon.CommitLevel
+	(QueryDuration)(0),                          // 21: com.aerospike.daemon.QueryDuration
+	(CdtMapCommandOp)(0),                        // 22: com.aerospike.daemon.CdtMapCommandOp
+	(CdtMapReturnType)(0),                       // 23: com.aerospike.daemon.CdtMapReturnType
+	(CdtMapWriteMode)(0),                        // 24: com.aerospike.daemon.CdtMapWriteMode
+	(CdtMapWriteFlags)(0),                       // 25: com.aerospike.daemon.CdtMapWriteFlags
+	(CdtListCommandOp)(0),                       // 26: com.aerospike.daemon.CdtListCommandOp
+	(CdtListReturnType)(0),                      // 27: com.aerospike.daemon.CdtListReturnType
+	(CdtListSortFlags)(0),                       // 28: com.aerospike.daemon.CdtListSortFlags
+	(CdtListWriteFlags)(0),                      // 29: com.aerospike.daemon.CdtListWriteFlags
+	(CdtHLLCommandOp)(0),                        // 30: com.aerospike.daemon.CdtHLLCommandOp
+	(CdtHLLWriteFlags)(0),                       // 31: com.aerospike.daemon.CdtHLLWriteFlags
+	(CdtBitwiseCommandOp)(0),                    // 32: com.aerospike.daemon.CdtBitwiseCommandOp
+	(CdtBitwiseWriteFlags)(0),                   // 33: com.aerospike.daemon.CdtBitwiseWriteFlags
+	(CdtBitwiseResizeFlags)(0),                  // 34: com.aerospike.daemon.CdtBitwiseResizeFlags
+	(CdtBitwiseOverflowAction)(0),               // 35: com.aerospike.daemon.CdtBitwiseOverflowAction
+	(ExpReadFlags)(0),                           // 36: com.aerospike.daemon.ExpReadFlags
+	(ExpWriteFlags)(0),                          // 37: com.aerospike.daemon.ExpWriteFlags
+	(ExpOperationType)(0),                       // 38: com.aerospike.daemon.ExpOperationType
+	(*Expression)(nil),                          // 39: com.aerospike.daemon.Expression
+	(*ReadPolicy)(nil),                          // 40: com.aerospike.daemon.ReadPolicy
+	(*WritePolicy)(nil),                         // 41: com.aerospike.daemon.WritePolicy
+	(*AdminPolicy)(nil),                         // 42: com.aerospike.daemon.AdminPolicy
+	(*InfoPolicy)(nil),                          // 43: com.aerospike.daemon.InfoPolicy
+	(*BatchReadPolicy)(nil),                     // 44: com.aerospike.daemon.BatchReadPolicy
+	(*BatchWritePolicy)(nil),                    // 45: com.aerospike.daemon.BatchWritePolicy
+	(*BatchDeletePolicy)(nil),                   // 46: com.aerospike.daemon.BatchDeletePolicy
+	(*BatchUDFPolicy)(nil),                      // 47: com.aerospike.daemon.BatchUDFPolicy
+	(*BatchPolicy)(nil),                         // 48: com.aerospike.daemon.BatchPolicy
+	(*Bin)(nil),                                 // 49: com.aerospike.daemon.Bin
+	(*List)(nil),                                // 50: com.aerospike.daemon.List
+	(*MapEntry)(nil),                            // 51: com.aerospike.daemon.MapEntry
+	(*Map)(nil),                                 // 52: com.aerospike.daemon.Map
+	(*JsonEntry)(nil),                           // 53: com.aerospike.daemon.JsonEntry
+	(*Json)(nil),                                // 54: com.aerospike.daemon.Json
+	(*UDFMeta)(nil),                             // 55: com.aerospike.daemon.UDFMeta
+	(*Value)(nil),                               // 56: com.aerospike.daemon.Value
+	(*Key)(nil),                                 // 57: com.aerospike.daemon.Key
+	(*Record)(nil),                              // 58: com.aerospike.daemon.Record
+	(*Error)(nil),                               // 59: com.aerospike.daemon.Error
+	(*BatchRecord)(nil),                         // 60: com.aerospike.daemon.BatchRecord
+	(*CDTContext)(nil),                          // 61: com.aerospike.daemon.CDTContext
+	(*Operation)(nil),                           // 62: com.aerospike.daemon.Operation
+	(*StdOperation)(nil),                        // 63: com.aerospike.daemon.StdOperation
+	(*BatchWrite)(nil),                          // 64: com.aerospike.daemon.BatchWrite
+	(*BatchRead)(nil),                           // 65: com.aerospike.daemon.BatchRead
+	(*BatchDelete)(nil),                         // 66: com.aerospike.daemon.BatchDelete
+	(*BatchUDF)(nil),                            // 67: com.aerospike.daemon.BatchUDF
+	(*UserRole)(nil),                            // 68: com.aerospike.daemon.UserRole
+	(*Role)(nil),                                // 69: com.aerospike.daemon.Role
+	(*Privilege)(nil),                           // 70: com.aerospike.daemon.Privilege
+	(*PartitionStatus)(nil),                     // 71: com.aerospike.daemon.PartitionStatus
+	(*PartitionFilter)(nil),                     // 72: com.aerospike.daemon.PartitionFilter
+	(*AerospikeSingleResponse)(nil),             // 73: com.aerospike.daemon.AerospikeSingleResponse
+	(*AerospikeGetRequest)(nil),                 // 74: com.aerospike.daemon.AerospikeGetRequest
+	(*AerospikeGetHeaderRequest)(nil),           // 75: com.aerospike.daemon.AerospikeGetHeaderRequest
+	(*AerospikeExistsRequest)(nil),              // 76: com.aerospike.daemon.AerospikeExistsRequest
+	(*AerospikeExistsResponse)(nil),             // 77: com.aerospike.daemon.AerospikeExistsResponse
+	(*AerospikeDeleteRequest)(nil),              // 78: com.aerospike.daemon.AerospikeDeleteRequest
+	(*AerospikeDeleteResponse)(nil),             // 79: com.aerospike.daemon.AerospikeDeleteResponse
+	(*AerospikeTouchRequest)(nil),               // 80: com.aerospike.daemon.AerospikeTouchRequest
+	(*AerospikeOperateRequest)(nil),             // 81: com.aerospike.daemon.AerospikeOperateRequest
+	(*AerospikePutRequest)(nil),                 // 82: com.aerospike.daemon.AerospikePutRequest
+	(*BatchOperate)(nil),                        // 83: com.aerospike.daemon.BatchOperate
+	(*AerospikeBatchOperateRequest)(nil),        // 84: com.aerospike.daemon.AerospikeBatchOperateRequest
+	(*AerospikeBatchOperateResponse)(nil),       // 85: com.aerospike.daemon.AerospikeBatchOperateResponse
+	(*AerospikeCreateIndexRequest)(nil),         // 86: com.aerospike.daemon.AerospikeCreateIndexRequest
+	(*AerospikeCreateIndexResponse)(nil),        // 87: com.aerospike.daemon.AerospikeCreateIndexResponse
+	(*AerospikeDropIndexRequest)(nil),           // 88: com.aerospike.daemon.AerospikeDropIndexRequest
+	(*AerospikeDropIndexResponse)(nil),          // 89: com.aerospike.daemon.AerospikeDropIndexResponse
+	(*Txn)(nil),                                 // 90: com.aerospike.daemon.Txn
+	(*AerospikeCommitRequest)(nil),              // 91: com.aerospike.daemon.AerospikeCommitRequest
+	(*AerospikeCommitResponse)(nil),             // 92: com.aerospike.daemon.AerospikeCommitResponse
+	(*AerospikeAbortRequest)(nil),               // 93: com.aerospike.daemon.AerospikeAbortRequest
+	(*AerospikeAbortResponse)(nil),              // 94: com.aerospike.daemon.AerospikeAbortResponse
+	(*AerospikeExpressionToBase64Request)(nil),  // 95: com.aerospike.daemon.AerospikeExpressionToBase64Request
+	(*AerospikeExpressionToBase64Response)(nil), // 96: com.aerospike.daemon.AerospikeExpressionToBase64Response
+	(*AerospikeTruncateRequest)(nil),            // 97: com.aerospike.daemon.AerospikeTruncateRequest
+	(*AerospikeTruncateResponse)(nil),           // 98: com.aerospike.daemon.AerospikeTruncateResponse
+	(*AerospikeRegisterUDFRequest)(nil),         // 99: com.aerospike.daemon.AerospikeRegisterUDFRequest
+	(*AerospikeRegisterUDFResponse)(nil),        // 100: com.aerospike.daemon.AerospikeRegisterUDFResponse
+	(*AerospikeDropUDFRequest)(nil),             // 101: com.aerospike.daemon.AerospikeDropUDFRequest
+	(*AerospikeDropUDFResponse)(nil),            // 102: com.aerospike.daemon.AerospikeDropUDFResponse
+	(*AerospikeListUDFRequest)(nil),             // 103: com.aerospike.daemon.AerospikeListUDFRequest
+	(*AerospikeListUDFResponse)(nil),            // 104: com.aerospike.daemon.AerospikeListUDFResponse
+	(*AerospikeUDFExecuteRequest)(nil),          // 105: com.aerospike.daemon.AerospikeUDFExecuteRequest
+	(*AerospikeUDFExecuteResponse)(nil),         // 106: com.aerospike.daemon.AerospikeUDFExecuteResponse
+	(*AerospikeCreateUserRequest)(nil),          // 107: com.aerospike.daemon.AerospikeCreateUserRequest
+	(*AerospikeCreateUserResponse)(nil),         // 108: com.aerospike.daemon.AerospikeCreateUserResponse
+	(*AerospikeDropUserRequest)(nil),            // 109: com.aerospike.daemon.AerospikeDropUserRequest
+	(*AerospikeDropUserResponse)(nil),           // 110: com.aerospike.daemon.AerospikeDropUserResponse
+	(*AerospikeChangePasswordRequest)(nil),      // 111: com.aerospike.daemon.AerospikeChangePasswordRequest
+	(*AerospikeChangePasswordResponse)(nil),     // 112: com.aerospike.daemon.AerospikeChangePasswordResponse
+	(*AerospikeGrantRolesRequest)(nil),          // 113: com.aerospike.daemon.AerospikeGrantRolesRequest
+	(*AerospikeGrantRolesResponse)(nil),         // 114: com.aerospike.daemon.AerospikeGrantRolesResponse
+	(*AerospikeRevokeRolesRequest)(nil),         // 115: com.aerospike.daemon.AerospikeRevokeRolesRequest
+	(*AerospikeRevokeRolesResponse)(nil),        // 116: com.aerospike.daemon.AerospikeRevokeRolesResponse
+	(*AerospikeQueryUsersRequest)(nil),          // 117: com.aerospike.daemon.AerospikeQueryUsersRequest
+	(*AerospikeQueryUsersResponse)(nil),         // 118: com.aerospike.daemon.AerospikeQueryUsersResponse
+	(*AerospikeQueryRolesRequest)(nil),          // 119: com.aerospike.daemon.AerospikeQueryRolesRequest
+	(*AerospikeQueryRolesResponse)(nil),         // 120: com.aerospike.daemon.AerospikeQueryRolesResponse
+	(*AerospikeCreateRoleRequest)(nil),          // 121: com.aerospike.daemon.AerospikeCreateRoleRequest
+	(*AerospikeCreateRoleResponse)(nil),         // 122: com.aerospike.daemon.AerospikeCreateRoleResponse
+	(*AerospikeDropRoleRequest)(nil),            // 123: com.aerospike.daemon.AerospikeDropRoleRequest
+	(*AerospikeDropRoleResponse)(nil),           // 124: com.aerospike.daemon.AerospikeDropRoleResponse
+	(*AerospikeGrantPrivilegesRequest)(nil),     // 125: com.aerospike.daemon.AerospikeGrantPrivilegesRequest
+	(*AerospikeGrantPrivilegesResponse)(nil),    // 126: com.aerospike.daemon.AerospikeGrantPrivilegesResponse
+	(*AerospikeRevokePrivilegesRequest)(nil),    // 127: com.aerospike.daemon.AerospikeRevokePrivilegesRequest
+	(*AerospikeRevokePrivilegesResponse)(nil),   // 128: com.aerospike.daemon.AerospikeRevokePrivilegesResponse
+	(*AerospikeSetAllowlistRequest)(nil),        // 129: com.aerospike.daemon.AerospikeSetAllowlistRequest
+	(*AerospikeSetAllowlistResponse)(nil),       // 130: com.aerospike.daemon.AerospikeSetAllowlistResponse
+	(*AerospikeSetQuotasRequest)(nil),           // 131: com.aerospike.daemon.AerospikeSetQuotasRequest
+	(*AerospikeSetQuotasResponse)(nil),          // 132: com.aerospike.daemon.AerospikeSetQuotasResponse
+	(*AerospikeScanRequest)(nil),                // 133: com.aerospike.daemon.AerospikeScanRequest
+	(*AerospikeStreamResponse)(nil),             // 134: com.aerospike.daemon.AerospikeStreamResponse
+	(*AerospikeQueryRequest)(nil),               // 135: com.aerospike.daemon.AerospikeQueryRequest
+	(*AerospikeQueryAggregateRequest)(nil),      // 136: com.aerospike.daemon.AerospikeQueryAggregateRequest
+	(*AerospikeAggregateResponse)(nil),          // 137: com.aerospike.daemon.AerospikeAggregateResponse
+	(*AerospikeQueryExecuteRequest)(nil),        // 138: com.aerospike.daemon.AerospikeQueryExecuteRequest
+	(*AerospikeQueryExecuteResponse)(nil),       // 139: com.aerospike.daemon.AerospikeQueryExecuteResponse
+	(*NodeTaskProgress)(nil),                    // 140: com.aerospike.daemon.NodeTaskProgress
+	(*AerospikeTaskProgressResponse)(nil),       // 141: com.aerospike.daemon.AerospikeTaskProgressResponse
+	(*AerospikeIndexTaskStatusRequest)(nil),     // 142: com.aerospike.daemon.AerospikeIndexTaskStatusRequest
+	(*AerospikeUDFTaskStatusRequest)(nil),       // 143: com.aerospike.daemon.AerospikeUDFTaskStatusRequest
+	(*AerospikeExecuteTaskStatusRequest)(nil),   // 144: com.aerospike.daemon.AerospikeExecuteTaskStatusRequest
+	(*AerospikeExecuteTaskStatusResponse)(nil),  // 145: com.aerospike.daemon.AerospikeExecuteTaskStatusResponse
+	(*AerospikeInfoRequest)(nil),                // 146: com.aerospike.daemon.AerospikeInfoRequest
+	(*AerospikeInfoResponse)(nil),               // 147: com.aerospike.daemon.AerospikeInfoResponse
+	(*Node)(nil),                                // 148: com.aerospike.daemon.Node
+	(*AerospikeNodesRequest)(nil),               // 149: com.aerospike.daemon.AerospikeNodesRequest
+	(*AerospikeNodesResponse)(nil),              // 150: com.aerospike.daemon.AerospikeNodesResponse
+	(*NamespaceInfo)(nil),                       // 151: com.aerospike.daemon.NamespaceInfo
+	(*AerospikeNamespacesRequest)(nil),          // 152: com.aerospike.daemon.AerospikeNamespacesRequest
+	(*AerospikeNamespacesResponse)(nil),         // 153: com.aerospike.daemon.AerospikeNamespacesResponse
+	(*SetInfo)(nil),                             // 154: com.aerospike.daemon.SetInfo
+	(*AerospikeSetsRequest)(nil),                // 155: com.aerospike.daemon.AerospikeSetsRequest
+	(*AerospikeSetsResponse)(nil),               // 156: com.aerospike.daemon.AerospikeSetsResponse
+	(*IndexInfo)(nil),                           // 157: com.aerospike.daemon.IndexInfo
+	(*AerospikeIndexesRequest)(nil),             // 158: com.aerospike.daemon.AerospikeIndexesRequest
+	(*AerospikeIndexesResponse)(nil),            // 159: com.aerospike.daemon.AerospikeIndexesResponse
+	(*AerospikeVersionRequest)(nil),             // 160: com.aerospike.daemon.AerospikeVersionRequest
+	(*AerospikeVersionResponse)(nil),            // 161: com.aerospike.daemon.AerospikeVersionResponse
+	(*BackgroundExecutePolicy)(nil),             // 162: com.aerospike.daemon.BackgroundExecutePolicy
+	(*MultiPolicy)(nil),                         // 163: com.aerospike.daemon.MultiPolicy
+	(*ScanPolicy)(nil),                          // 164: com.aerospike.daemon.ScanPolicy
+	(*QueryPolicy)(nil),                         // 165: com.aerospike.daemon.QueryPolicy
+	(*UDFCall)(nil),                             // 166: com.aerospike.daemon.UDFCall
+	(*Statement)(nil),                           // 167: com.aerospike.daemon.Statement
+	(*QueryFilter)(nil),                         // 168: com.aerospike.daemon.QueryFilter
+	(*CdtMapPolicy)(nil),                        // 169: com.aerospike.daemon.CdtMapPolicy
+	(*CdtMapOperation)(nil),                     // 170: com.aerospike.daemon.CdtMapOperation
+	(*CdtListPolicy)(nil),                       // 171: com.aerospike.daemon.CdtListPolicy
+	(*CdtListOperation)(nil),                    // 172: com.aerospike.daemon.CdtListOperation
+	(*CdtHLLPolicy)(nil),                        // 173: com.aerospike.daemon.CdtHLLPolicy
+	(*CdtHLLOperation)(nil),                     // 174: com.aerospike.daemon.CdtHLLOperation
+	(*CdtBitwisePolicy)(nil),                    // 175: com.aerospike.daemon.CdtBitwisePolicy
+	(*CdtBitwiseOperation)(nil),                 // 176: com.aerospike.daemon.CdtBitwiseOperation
+	(*ExpOperation)(nil),                        // 177: com.aerospike.daemon.ExpOperation
+	nil,                                         // 178: com.aerospike.daemon.Record.BinsEntry
+	nil,                                         // 179: com.aerospike.daemon.AerospikeInfoResponse.ResultsEntry
+	nil,                                         // 180: com.aerospike.daemon.Node.RacksEntry
 }
 var file_asld_kvs_proto_depIdxs = []int32{
 	2,   // 0: com.aerospike.daemon.Expression.cmd:type_name -> com.aerospike.daemon.ExpOp
-	49,  // 1: com.aerospike.daemon.Expression.val:type_name -> com.aerospike.daemon.Value
-	32,  // 2: com.aerospike.daemon.Expression.bin:type_name -> com.aerospike.daemon.Expression
+	56,  // 1: com.aerospike.daemon.Expression.val:type_name -> com.aerospike.daemon.Value
+	39,  // 2: com.aerospike.daemon.Expression.bin:type_name -> com.aerospike.daemon.Expression
 	1,   // 3: com.aerospike.daemon.Expression.module:type_name -> com.aerospike.daemon.ExpType
-	32,  // 4: com.aerospike.daemon.Expression.exps:type_name -> com.aerospike.daemon.Expression
-	32,  // 5: com.aerospike.daemon.ReadPolicy.filterExpression:type_name -> com.aerospike.daemon.Expression
-	3,   // 6: com.aerospike.daemon.ReadPolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
-	4,   // 7: com.aerospike.daemon.ReadPolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
-	5,   // 8: com.aerospike.daemon.ReadPolicy.replicaPolicy:type_name -> com.aerospike.daemon.ReplicaPolicy
-	33,  // 9: com.aerospike.daemon.WritePolicy.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	14,  // 10: com.aerospike.daemon.WritePolicy.recordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
-	15,  // 11: com.aerospike.daemon.WritePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
-	16,  // 12: com.aerospike.daemon.WritePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
-	32,  // 13: com.aerospike.daemon.BatchReadPolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
-	3,   // 14: com.aerospike.daemon.BatchReadPolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
-	4,   // 15: com.aerospike.daemon.BatchReadPolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
-	32,  // 16: com.aerospike.daemon.BatchWritePolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
-	14,  // 17: com.aerospike.daemon.BatchWritePolicy.RecordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
-	16,  // 18: com.aerospike.daemon.BatchWritePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
-	15,  // 19: com.aerospike.daemon.BatchWritePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
-	32,  // 20: com.aerospike.daemon.BatchDeletePolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
-	16,  // 21: com.aerospike.daemon.BatchDeletePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
-	15,  // 22: com.aerospike.daemon.BatchDeletePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
-	32,  // 23: com.aerospike.daemon.BatchUDFPolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
-	16,  // 24: com.aerospike.daemon.BatchUDFPolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
-	33,  // 25: com.aerospike.daemon.BatchPolicy.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	49,  // 26: com.aerospike.daemon.Bin.value:type_name -> com.aerospike.daemon.Value
-	49,  // 27: com.aerospike.daemon.List.l:type_name -> com.aerospike.daemon.Value
-	49,  // 28: com.aerospike.daemon.MapEntry.k:type_name -> com.aerospike.daemon.Value
-	49,  // 29: com.aerospike.daemon.MapEntry.v:type_name -> com.aerospike.daemon.Value
-	44,  // 30: com.aerospike.daemon.Map.m:type_name -> com.aerospike.daemon.MapEntry
-	49,  // 31: com.aerospike.daemon.JsonEntry.v:type_name -> com.aerospike.daemon.Value
-	46,  // 32: com.aerospike.daemon.Json.j:type_name -> com.aerospike.daemon.JsonEntry
-	10,  // 33: com.aerospike.daemon.UDFMeta.language:type_name -> com.aerospike.daemon.UDFLanguage
-	43,  // 34: com.aerospike.daemon.Value.l:type_name -> com.aerospike.daemon.List
-	45,  // 35: com.aerospike.daemon.Value.m:type_name -> com.aerospike.daemon.Map
-	47,  // 36: com.aerospike.daemon.Value.json:type_name -> com.aerospike.daemon.Json
-	49,  // 37: com.aerospike.daemon.Key.value:type_name -> com.aerospike.daemon.Value
-	50,  // 38: com.aerospike.daemon.Record.key:type_name -> com.aerospike.daemon.Key
-	138, // 39: com.aerospike.daemon.Record.Bins:type_name -> com.aerospike.daemon.Record.BinsEntry
-	50,  // 40: com.aerospike.daemon.BatchRecord.key:type_name -> com.aerospike.daemon.Key
-	51,  // 41: com.aerospike.daemon.BatchRecord.record:type_name -> com.aerospike.daemon.Record
-	52,  // 42: com.aerospike.daemon.BatchRecord.error:type_name -> com.aerospike.daemon.Error
-	49,  // 43: com.aerospike.daemon.CDTContext.value:type_name -> com.aerospike.daemon.Value
-	56,  // 44: com.aerospike.daemon.Operation.std:type_name -> com.aerospike.daemon.StdOperation
-	131, // 45: com.aerospike.daemon.Operation.map:type_name -> com.aerospike.daemon.CdtMapOperation
-	133, // 46: com.aerospike.daemon.Operation.list:type_name -> com.aerospike.daemon.CdtListOperation
-	135, // 47: com.aerospike.daemon.Operation.hll:type_name -> com.aerospike.daemon.CdtHLLOperation
-	137, // 48: com.aerospike.daemon.Operation.bitwise:type_name -> com.aerospike.daemon.CdtBitwiseOperation
-	13,  // 49: com.aerospike.daemon.StdOperation.opType:type_name -> com.aerospike.daemon.OperationType
-	54,  // 50: com.aerospike.daemon.StdOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
-	49,  // 51: com.aerospike.daemon.StdOperation.binValue:type_name -> com.aerospike.daemon.Value
-	53,  // 52: com.aerospike.daemon.BatchWrite.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
-	38,  // 53: com.aerospike.daemon.BatchWrite.policy:type_name -> com.aerospike.daemon.BatchWritePolicy
-	55,  // 54: com.aerospike.daemon.BatchWrite.ops:type_name -> com.aerospike.daemon.Operation
-	53,  // 55: com.aerospike.daemon.BatchRead.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
-	37,  // 56: com.aerospike.daemon.BatchRead.policy:type_name -> com.aerospike.daemon.BatchReadPolicy
-	55,  // 57: com.aerospike.daemon.BatchRead.ops:type_name -> com.aerospike.daemon.Operation
-	53,  // 58: com.aerospike.daemon.BatchDelete.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
-	39,  // 59: com.aerospike.daemon.BatchDelete.policy:type_name -> com.aerospike.daemon.BatchDeletePolicy
-	53,  // 60: com.aerospike.daemon.BatchUDF.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
-	40,  // 61: com.aerospike.daemon.BatchUDF.policy:type_name -> com.aerospike.daemon.BatchUDFPolicy
-	49,  // 62: com.aerospike.daemon.BatchUDF.functionArgs:type_name -> com.aerospike.daemon.Value
-	63,  // 63: com.aerospike.daemon.Role.privileges:type_name -> com.aerospike.daemon.Privilege
-	64,  // 64: com.aerospike.daemon.PartitionFilter.partitions:type_name -> com.aerospike.daemon.PartitionStatus
-	52,  // 65: com.aerospike.daemon.AerospikeSingleResponse.error:type_name -> com.aerospike.daemon.Error
-	51,  // 66: com.aerospike.daemon.AerospikeSingleResponse.record:type_name -> com.aerospike.daemon.Record
-	33,  // 67: com.aerospike.daemon.AerospikeGetRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	50,  // 68: com.aerospike.daemon.AerospikeGetRequest.key:type_name -> com.aerospike.daemon.Key
-	33,  // 69: com.aerospike.daemon.AerospikeGetHeaderRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	50,  // 70: com.aerospike.daemon.AerospikeGetHeaderRequest.key:type_name -> com.aerospike.daemon.Key
-	33,  // 71: com.aerospike.daemon.AerospikeExistsRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	50,  // 72: com.aerospike.daemon.AerospikeExistsRequest.key:type_name -> com.aerospike.daemon.Key
-	52,  // 73: com.aerospike.daemon.AerospikeExistsResponse.error:type_name -> com.aerospike.daemon.Error
-	34,  // 74: com.aerospike.daemon.AerospikeDeleteRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	50,  // 75: com.aerospike.daemon.AerospikeDeleteRequest.key:type_name -> com.aerospike.daemon.Key
-	52,  // 76: com.aerospike.daemon.AerospikeDeleteResponse.error:type_name -> com.aerospike.daemon.Error
-	34,  // 77: com.aerospike.daemon.AerospikeTouchRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	50,  // 78: com.aerospike.daemon.AerospikeTouchRequest.key:type_name -> com.aerospike.daemon.Key
-	34,  // 79: com.aerospike.daemon.AerospikePutRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	50,  // 80: com.aerospike.daemon.AerospikePutRequest.key:type_name -> com.aerospike.daemon.Key
-	42,  // 81: com.aerospike.daemon.AerospikePutRequest.Bins:type_name -> com.aerospike.daemon.Bin
-	58,  // 82: com.aerospike.daemon.BatchOperate.br:type_name -> com.aerospike.daemon.BatchRead
-	57,  // 83: com.aerospike.daemon.BatchOperate.bw:type_name -> com.aerospike.daemon.BatchWrite
-	59,  // 84: com.aerospike.daemon.BatchOperate.bd:type_name -> com.aerospike.daemon.BatchDelete
-	60,  // 85: com.aerospike.daemon.BatchOperate.bu:type_name -> com.aerospike.daemon.BatchUDF
-	41,  // 86: com.aerospike.daemon.AerospikeBatchOperateRequest.policy:type_name -> com.aerospike.daemon.BatchPolicy
-	75,  // 87: com.aerospike.daemon.AerospikeBatchOperateRequest.records:type_name -> com.aerospike.daemon.BatchOperate
-	52,  // 88: com.aerospike.daemon.AerospikeBatchOperateResponse.error:type_name -> com.aerospike.daemon.Error
-	53,  // 89: com.aerospike.daemon.AerospikeBatchOperateResponse.records:type_name -> com.aerospike.daemon.BatchRecord
-	34,  // 90: com.aerospike.daemon.AerospikeCreateIndexRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	7,   // 91: com.aerospike.daemon.AerospikeCreateIndexRequest.indexType:type_name -> com.aerospike.daemon.IndexType
-	8,   // 92: com.aerospike.daemon.AerospikeCreateIndexRequest.indexCollectionType:type_name -> com.aerospike.daemon.IndexCollectionType
-	54,  // 93: com.aerospike.daemon.AerospikeCreateIndexRequest.ctx:type_name -> com.aerospike.daemon.CDTContext
-	52,  // 94: com.aerospike.daemon.AerospikeCreateIndexResponse.error:type_name -> com.aerospike.daemon.Error
-	34,  // 95: com.aerospike.daemon.AerospikeDropIndexRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	52,  // 96: com.aerospike.daemon.AerospikeDropIndexResponse.error:type_name -> com.aerospike.daemon.Error
-	36,  // 97: com.aerospike.daemon.AerospikeTruncateRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
-	52,  // 98: com.aerospike.daemon.AerospikeTruncateResponse.error:type_name -> com.aerospike.daemon.Error
-	34,  // 99: com.aerospike.daemon.AerospikeRegisterUDFRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	10,  // 100: com.aerospike.daemon.AerospikeRegisterUDFRequest.language:type_name -> com.aerospike.daemon.UDFLanguage
-	52,  // 101: com.aerospike.daemon.AerospikeRegisterUDFResponse.error:type_name -> com.aerospike.daemon.Error
-	34,  // 102: com.aerospike.daemon.AerospikeDropUDFRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	52,  // 103: com.aerospike.daemon.AerospikeDropUDFResponse.error:type_name -> com.aerospike.daemon.Error
-	33,  // 104: com.aerospike.daemon.AerospikeListUDFRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
-	52,  // 105: com.aerospike.daemon.AerospikeListUDFResponse.error:type_name -> com.aerospike.daemon.Error
-	48,  // 106: com.aerospike.daemon.AerospikeListUDFResponse.udfList:type_name -> com.aerospike.daemon.UDFMeta
-	34,  // 107: com.aerospike.daemon.AerospikeUDFExecuteRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
-	50,  // 108: com.aerospike.daemon.AerospikeUDFExecuteRequest.key:type_name -> com.aerospike.daemon.Key
-	49,  // 109: com.aerospike.daemon.AerospikeUDFExecuteRequest.args:type_name -> com.aerospike.daemon.Value
-	52,  // 110: com.aerospike.daemon.AerospikeUDFExecuteResponse.error:type_name -> com.aerospike.daemon.Error
-	49,  // 111: com.aerospike.daemon.AerospikeUDFExecuteResponse.result:type_name -> com.aerospike.daemon.Value
-	35,  // 112: com.aerospike.daemon.AerospikeCreateUserRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 113: com.aerospike.daemon.AerospikeCreateUserResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 114: com.aerospike.daemon.AerospikeDropUserRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 115: com.aerospike.daemon.AerospikeDropUserResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 116: com.aerospike.daemon.AerospikeChangePasswordRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 117: com.aerospike.daemon.AerospikeChangePasswordResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 118: com.aerospike.daemon.AerospikeGrantRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 119: com.aerospike.daemon.AerospikeGrantRolesResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 120: com.aerospike.daemon.AerospikeRevokeRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 121: com.aerospike.daemon.AerospikeRevokeRolesResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 122: com.aerospike.daemon.AerospikeQueryUsersRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 123: com.aerospike.daemon.AerospikeQueryUsersResponse.error:type_name -> com.aerospike.daemon.Error
-	61,  // 124: com.aerospike.daemon.AerospikeQueryUsersResponse.userRoles:type_name -> com.aerospike.daemon.UserRole
-	35,  // 125: com.aerospike.daemon.AerospikeQueryRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 126: com.aerospike.daemon.AerospikeQueryRolesResponse.error:type_name -> com.aerospike.daemon.Error
-	62,  // 127: com.aerospike.daemon.AerospikeQueryRolesResponse.roles:type_name -> com.aerospike.daemon.Role
-	35,  // 128: com.aerospike.daemon.AerospikeCreateRoleRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	63,  // 129: com.aerospike.daemon.AerospikeCreateRoleRequest.privileges:type_name -> com.aerospike.daemon.Privilege
-	52,  // 130: com.aerospike.daemon.AerospikeCreateRoleResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 131: com.aerospike.daemon.AerospikeDropRoleRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 132: com.aerospike.daemon.AerospikeDropRoleResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 133: com.aerospike.daemon.AerospikeGrantPrivilegesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	63,  // 134: com.aerospike.daemon.AerospikeGrantPrivilegesRequest.privileges:type_name -> com.aerospike.daemon.Privilege
-	52,  // 135: com.aerospike.daemon.AerospikeGrantPrivilegesResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 136: com.aerospike.daemon.AerospikeRevokePrivilegesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	63,  // 137: com.aerospike.daemon.AerospikeRevokePrivilegesRequest.privileges:type_name -> com.aerospike.daemon.Privilege
-	52,  // 138: com.aerospike.daemon.AerospikeRevokePrivilegesResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 139: com.aerospike.daemon.AerospikeSetAllowlistRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 140: com.aerospike.daemon.AerospikeSetAllowlistResponse.error:type_name -> com.aerospike.daemon.Error
-	35,  // 141: com.aerospike.daemon.AerospikeSetQuotasRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
-	52,  // 142: com.aerospike.daemon.AerospikeSetQuotasResponse.error:type_name -> com.aerospike.daemon.Error
-	125, // 143: com.aerospike.daemon.AerospikeScanRequest.policy:type_name -> com.aerospike.daemon.ScanPolicy
-	65,  // 144: com.aerospike.daemon.AerospikeScanRequest.partitionFilter:type_name -> com.aerospike.daemon.PartitionFilter
-	51,  // 145: com.aerospike.daemon.AerospikeStreamResponse.record:type_name -> com.aerospike.daemon.Record
-	52,  // 146: com.aerospike.daemon.AerospikeStreamResponse.error:type_name -> com.aerospike.daemon.Error
-	126, // 147: com.aerospike.daemon.AerospikeQueryRequest.policy:type_name -> com.aerospike.daemon.QueryPolicy
-	128, // 148: com.aerospike.daemon.AerospikeQueryRequest.statement:type_name -> com.aerospike.daemon.Statement
-	65,  // 149: com.aerospike.daemon.AerospikeQueryRequest.partitionFilter:type_name -> com.aerospike.daemon.PartitionFilter
-	5,   // 150: com.aerospike.daemon.BackgroundExecutePolicy.replica:type_name -> com.aerospike.daemon.ReplicaPolicy
-	3,   // 151: com.aerospike.daemon.BackgroundExecutePolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
-	4,   // 152: com.aerospike.daemon.BackgroundExecutePolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
-	14,  // 153: com.aerospike.daemon.BackgroundExecutePolicy.recordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
-	15,  // 154: com.aerospike.daemon.BackgroundExecutePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
-	16,  // 155: com.aerospike.daemon.BackgroundExecutePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
-	33,  // 156: com.aerospike.daemon.MultiPolicy.readPolicy:type_name -> com.aerospike.daemon.ReadPolicy
-	124, // 157: com.aerospike.daemon.ScanPolicy.multiPolicy:type_name -> com.aerospike.daemon.MultiPolicy
-	124, // 158: com.aerospike.daemon.QueryPolicy.multiPolicy:type_name -> com.aerospike.daemon.MultiPolicy
-	17,  // 159: com.aerospike.daemon.QueryPolicy.expectedDuration:type_name -> com.aerospike.daemon.QueryDuration
-	49,  // 160: com.aerospike.daemon.UDFCall.functionArgs:type_name -> com.aerospike.daemon.Value
-	129, // 161: com.aerospike.daemon.Statement.filter:type_name -> com.aerospike.daemon.QueryFilter
-	127, // 162: com.aerospike.daemon.Statement.udfCall:type_name -> com.aerospike.daemon.UDFCall
-	8,   // 163: com.aerospike.daemon.QueryFilter.idxType:type_name -> com.aerospike.daemon.IndexCollectionType
-	49,  // 164: com.aerospike.daemon.QueryFilter.begin:type_name -> com.aerospike.daemon.Value
-	49,  // 165: com.aerospike.daemon.QueryFilter.end:type_name -> com.aerospike.daemon.Value
-	54,  // 166: com.aerospike.daemon.QueryFilter.ctx:type_name -> com.aerospike.daemon.CDTContext
-	12,  // 167: com.aerospike.daemon.CdtMapPolicy.mapOrder:type_name -> com.aerospike.daemon.MapOrderType
-	21,  // 168: com.aerospike.daemon.CdtMapPolicy.flags:type_name -> com.aerospike.daemon.CdtMapWriteFlags
-	18,  // 169: com.aerospike.daemon.CdtMapOperation.op:type_name -> com.aerospike.daemon.CdtMapCommandOp
-	130, // 170: com.aerospike.daemon.CdtMapOperation.policy:type_name -> com.aerospike.daemon.CdtMapPolicy
-	49,  // 171: com.aerospike.daemon.CdtMapOperation.args:type_name -> com.aerospike.daemon.Value
-	19,  // 172: com.aerospike.daemon.CdtMapOperation.returnType:type_name -> com.aerospike.daemon.CdtMapReturnType
-	54,  // 173: com.aerospike.daemon.CdtMapOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
-	11,  // 174: com.aerospike.daemon.CdtListPolicy.order:type_name -> com.aerospike.daemon.ListOrderType
-	25,  // 175: com.aerospike.daemon.CdtListPolicy.flags:type_name -> com.aerospike.daemon.CdtListWriteFlags
-	22,  // 176: com.aerospike.daemon.CdtListOperation.op:type_name -> com.aerospike.daemon.CdtListCommandOp
-	132, // 177: com.aerospike.daemon.CdtListOperation.policy:type_name -> com.aerospike.daemon.CdtListPolicy
-	49,  // 178: com.aerospike.daemon.CdtListOperation.args:type_name -> com.aerospike.daemon.Value
-	23,  // 179: com.aerospike.daemon.CdtListOperation.returnType:type_name -> com.aerospike.daemon.CdtListReturnType
-	54,  // 180: com.aerospike.daemon.CdtListOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
-	27,  // 181: com.aerospike.daemon.CdtHLLPolicy.flags:type_name -> com.aerospike.daemon.CdtHLLWriteFlags
-	26,  // 182: com.aerospike.daemon.CdtHLLOperation.op:type_name -> com.aerospike.daemon.CdtHLLCommandOp
-	134, // 183: com.aerospike.daemon.CdtHLLOperation.policy:type_name -> com.aerospike.daemon.CdtHLLPolicy
-	49,  // 184: com.aerospike.daemon.CdtHLLOperation.args:type_name -> com.aerospike.daemon.Value
-	29,  // 185: com.aerospike.daemon.CdtBitwisePolicy.flags:type_name -> com.aerospike.daemon.CdtBitwiseWriteFlags
-	28,  // 186: com.aerospike.daemon.CdtBitwiseOperation.op:type_name -> com.aerospike.daemon.CdtBitwiseCommandOp
-	136, // 187: com.aerospike.daemon.CdtBitwiseOperation.policy:type_name -> com.aerospike.daemon.CdtBitwisePolicy
-	49,  // 188: com.aerospike.daemon.CdtBitwiseOperation.args:type_name -> com.aerospike.daemon.Value
-	54,  // 189: com.aerospike.daemon.CdtBitwiseOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
-	49,  // 190: com.aerospike.daemon.Record.BinsEntry.value:type_name -> com.aerospike.daemon.Value
-	121, // 191: com.aerospike.daemon.KVS.Version:input_type -> com.aerospike.daemon.AerospikeVersionRequest
-	74,  // 192: com.aerospike.daemon.KVS.Put:input_type -> com.aerospike.daemon.AerospikePutRequest
-	74,  // 193: com.aerospike.daemon.KVS.Add:input_type -> com.aerospike.daemon.AerospikePutRequest
-	74,  // 194: com.aerospike.daemon.KVS.Append:input_type -> com.aerospike.daemon.AerospikePutRequest
-	74,  // 195: com.aerospike.daemon.KVS.Prepend:input_type -> com.aerospike.daemon.AerospikePutRequest
-	67,  // 196: com.aerospike.daemon.KVS.Get:input_type -> com.aerospike.daemon.AerospikeGetRequest
-	68,  // 197: com.aerospike.daemon.KVS.GetHeader:input_type -> com.aerospike.daemon.AerospikeGetHeaderRequest
-	69,  // 198: com.aerospike.daemon.KVS.Exists:input_type -> com.aerospike.daemon.AerospikeExistsRequest
-	71,  // 199: com.aerospike.daemon.KVS.Delete:input_type -> com.aerospike.daemon.AerospikeDeleteRequest
-	73,  // 200: com.aerospike.daemon.KVS.Touch:input_type -> com.aerospike.daemon.AerospikeTouchRequest
-	76,  // 201: com.aerospike.daemon.KVS.BatchOperate:input_type -> com.aerospike.daemon.AerospikeBatchOperateRequest
-	78,  // 202: com.aerospike.daemon.KVS.CreateIndex:input_type -> com.aerospike.daemon.AerospikeCreateIndexRequest
-	80,  // 203: com.aerospike.daemon.KVS.DropIndex:input_type -> com.aerospike.daemon.AerospikeDropIndexRequest
-	82,  // 204: com.aerospike.daemon.KVS.Truncate:input_type -> com.aerospike.daemon.AerospikeTruncateRequest
-	84,  // 205: com.aerospike.daemon.KVS.RegisterUDF:input_type -> com.aerospike.daemon.AerospikeRegisterUDFRequest
-	86,  // 206: com.aerospike.daemon.KVS.DropUDF:input_type -> com.aerospike.daemon.AerospikeDropUDFRequest
-	88,  // 207: com.aerospike.daemon.KVS.ListUDF:input_type -> com.aerospike.daemon.AerospikeListUDFRequest
-	90,  // 208: com.aerospike.daemon.KVS.UDFExecute:input_type -> com.aerospike.daemon.AerospikeUDFExecuteRequest
-	92,  // 209: com.aerospike.daemon.KVS.CreateUser:input_type -> com.aerospike.daemon.AerospikeCreateUserRequest
-	94,  // 210: com.aerospike.daemon.KVS.DropUser:input_type -> com.aerospike.daemon.AerospikeDropUserRequest
-	96,  // 211: com.aerospike.daemon.KVS.ChangePassword:input_type -> com.aerospike.daemon.AerospikeChangePasswordRequest
-	98,  // 212: com.aerospike.daemon.KVS.GrantRoles:input_type -> com.aerospike.daemon.AerospikeGrantRolesRequest
-	100, // 213: com.aerospike.daemon.KVS.RevokeRoles:input_type -> com.aerospike.daemon.AerospikeRevokeRolesRequest
-	102, // 214: com.aerospike.daemon.KVS.QueryUsers:input_type -> com.aerospike.daemon.AerospikeQueryUsersRequest
-	104, // 215: com.aerospike.daemon.KVS.QueryRoles:input_type -> com.aerospike.daemon.AerospikeQueryRolesRequest
-	106, // 216: com.aerospike.daemon.KVS.CreateRole:input_type -> com.aerospike.daemon.AerospikeCreateRoleRequest
-	108, // 217: com.aerospike.daemon.KVS.DropRole:input_type -> com.aerospike.daemon.AerospikeDropRoleRequest
-	110, // 218: com.aerospike.daemon.KVS.GrantPrivileges:input_type -> com.aerospike.daemon.AerospikeGrantPrivilegesRequest
-	112, // 219: com.aerospike.daemon.KVS.RevokePrivileges:input_type -> com.aerospike.daemon.AerospikeRevokePrivilegesRequest
-	114, // 220: com.aerospike.daemon.KVS.SetAllowlist:input_type -> com.aerospike.daemon.AerospikeSetAllowlistRequest
-	116, // 221: com.aerospike.daemon.KVS.SetQuotas:input_type -> com.aerospike.daemon.AerospikeSetQuotasRequest
-	118, // 222: com.aerospike.daemon.KVS.Scan:input_type -> com.aerospike.daemon.AerospikeScanRequest
-	120, // 223: com.aerospike.daemon.KVS.Query:input_type -> com.aerospike.daemon.AerospikeQueryRequest
-	122, // 224: com.aerospike.daemon.KVS.Version:output_type -> com.aerospike.daemon.AerospikeVersionResponse
-	52,  // 225: com.aerospike.daemon.KVS.Put:output_type -> com.aerospike.daemon.Error
-	52,  // 226: com.aerospike.daemon.KVS.Add:output_type -> com.aerospike.daemon.Error
-	52,  // 227: com.aerospike.daemon.KVS.Append:output_type -> com.aerospike.daemon.Error
-	52,  // 228: com.aerospike.daemon.KVS.Prepend:output_type -> com.aerospike.daemon.Error
-	66,  // 229: com.aerospike.daemon.KVS.Get:output_type -> com.aerospike.daemon.AerospikeSingleResponse
-	66,  // 230: com.aerospike.daemon.KVS.GetHeader:output_type -> com.aerospike.daemon.AerospikeSingleResponse
-	70,  // 231: com.aerospike.daemon.KVS.Exists:output_type -> com.aerospike.daemon.AerospikeExistsResponse
-	72,  // 232: com.aerospike.daemon.KVS.Delete:output_type -> com.aerospike.daemon.AerospikeDeleteResponse
-	52,  // 233: com.aerospike.daemon.KVS.Touch:output_type -> com.aerospike.daemon.Error
-	77,  // 234: com.aerospike.daemon.KVS.BatchOperate:output_type -> com.aerospike.daemon.AerospikeBatchOperateResponse
-	79,  // 235: com.aerospike.daemon.KVS.CreateIndex:output_type -> com.aerospike.daemon.AerospikeCreateIndexResponse
-	81,  // 236: com.aerospike.daemon.KVS.DropIndex:output_type -> com.aerospike.daemon.AerospikeDropIndexResponse
-	83,  // 237: com.aerospike.daemon.KVS.Truncate:output_type -> com.aerospike.daemon.AerospikeTruncateResponse
-	85,  // 238: com.aerospike.daemon.KVS.RegisterUDF:output_type -> com.aerospike.daemon.AerospikeRegisterUDFResponse
-	87,  // 239: com.aerospike.daemon.KVS.DropUDF:output_type -> com.aerospike.daemon.AerospikeDropUDFResponse
-	89,  // 240: com.aerospike.daemon.KVS.ListUDF:output_type -> com.aerospike.daemon.AerospikeListUDFResponse
-	91,  // 241: com.aerospike.daemon.KVS.UDFExecute:output_type -> com.aerospike.daemon.AerospikeUDFExecuteResponse
-	93,  // 242: com.aerospike.daemon.KVS.CreateUser:output_type -> com.aerospike.daemon.AerospikeCreateUserResponse
-	95,  // 243: com.aerospike.daemon.KVS.DropUser:output_type -> com.aerospike.daemon.AerospikeDropUserResponse
-	97,  // 244: com.aerospike.daemon.KVS.ChangePassword:output_type -> com.aerospike.daemon.AerospikeChangePasswordResponse
-	99,  // 245: com.aerospike.daemon.KVS.GrantRoles:output_type -> com.aerospike.daemon.AerospikeGrantRolesResponse
-	101, // 246: com.aerospike.daemon.KVS.RevokeRoles:output_type -> com.aerospike.daemon.AerospikeRevokeRolesResponse
-	103, // 247: com.aerospike.daemon.KVS.QueryUsers:output_type -> com.aerospike.daemon.AerospikeQueryUsersResponse
-	105, // 248: com.aerospike.daemon.KVS.QueryRoles:output_type -> com.aerospike.daemon.AerospikeQueryRolesResponse
-	107, // 249: com.aerospike.daemon.KVS.CreateRole:output_type -> com.aerospike.daemon.AerospikeCreateRoleResponse
-	109, // 250: com.aerospike.daemon.KVS.DropRole:output_type -> com.aerospike.daemon.AerospikeDropRoleResponse
-	111, // 251: com.aerospike.daemon.KVS.GrantPrivileges:output_type -> com.aerospike.daemon.AerospikeGrantPrivilegesResponse
-	113, // 252: com.aerospike.daemon.KVS.RevokePrivileges:output_type -> com.aerospike.daemon.AerospikeRevokePrivilegesResponse
-	115, // 253: com.aerospike.daemon.KVS.SetAllowlist:output_type -> com.aerospike.daemon.AerospikeSetAllowlistResponse
-	117, // 254: com.aerospike.daemon.KVS.SetQuotas:output_type -> com.aerospike.daemon.AerospikeSetQuotasResponse
-	119, // 255: com.aerospike.daemon.KVS.Scan:output_type -> com.aerospike.daemon.AerospikeStreamResponse
-	119, // 256: com.aerospike.daemon.KVS.Query:output_type -> com.aerospike.daemon.AerospikeStreamResponse
-	224, // [224:257] is the sub-list for method output_type
-	191, // [191:224] is the sub-list for method input_type
-	191, // [191:191] is the sub-list for extension type_name
-	191, // [191:191] is the sub-list for extension extendee
-	0,   // [0:191] is the sub-list for field type_name
+	39,  // 4: com.aerospike.daemon.Expression.exps:type_name -> com.aerospike.daemon.Expression
+	61,  // 5: com.aerospike.daemon.Expression.ctx:type_name -> com.aerospike.daemon.CDTContext
+	1,   // 6: com.aerospike.daemon.Expression.valueType:type_name -> com.aerospike.daemon.ExpType
+	39,  // 7: com.aerospike.daemon.ReadPolicy.filterExpression:type_name -> com.aerospike.daemon.Expression
+	3,   // 8: com.aerospike.daemon.ReadPolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
+	4,   // 9: com.aerospike.daemon.ReadPolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
+	5,   // 10: com.aerospike.daemon.ReadPolicy.replicaPolicy:type_name -> com.aerospike.daemon.ReplicaPolicy
+	90,  // 11: com.aerospike.daemon.ReadPolicy.txn:type_name -> com.aerospike.daemon.Txn
+	40,  // 12: com.aerospike.daemon.WritePolicy.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	18,  // 13: com.aerospike.daemon.WritePolicy.recordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
+	19,  // 14: com.aerospike.daemon.WritePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
+	20,  // 15: com.aerospike.daemon.WritePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
+	39,  // 16: com.aerospike.daemon.BatchReadPolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
+	3,   // 17: com.aerospike.daemon.BatchReadPolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
+	4,   // 18: com.aerospike.daemon.BatchReadPolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
+	39,  // 19: com.aerospike.daemon.BatchWritePolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
+	18,  // 20: com.aerospike.daemon.BatchWritePolicy.RecordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
+	20,  // 21: com.aerospike.daemon.BatchWritePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
+	19,  // 22: com.aerospike.daemon.BatchWritePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
+	39,  // 23: com.aerospike.daemon.BatchDeletePolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
+	20,  // 24: com.aerospike.daemon.BatchDeletePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
+	19,  // 25: com.aerospike.daemon.BatchDeletePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
+	39,  // 26: com.aerospike.daemon.BatchUDFPolicy.filter_expression:type_name -> com.aerospike.daemon.Expression
+	20,  // 27: com.aerospike.daemon.BatchUDFPolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
+	40,  // 28: com.aerospike.daemon.BatchPolicy.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	56,  // 29: com.aerospike.daemon.Bin.value:type_name -> com.aerospike.daemon.Value
+	56,  // 30: com.aerospike.daemon.List.l:type_name -> com.aerospike.daemon.Value
+	56,  // 31: com.aerospike.daemon.MapEntry.k:type_name -> com.aerospike.daemon.Value
+	56,  // 32: com.aerospike.daemon.MapEntry.v:type_name -> com.aerospike.daemon.Value
+	51,  // 33: com.aerospike.daemon.Map.m:type_name -> com.aerospike.daemon.MapEntry
+	56,  // 34: com.aerospike.daemon.JsonEntry.v:type_name -> com.aerospike.daemon.Value
+	53,  // 35: com.aerospike.daemon.Json.j:type_name -> com.aerospike.daemon.JsonEntry
+	10,  // 36: com.aerospike.daemon.UDFMeta.language:type_name -> com.aerospike.daemon.UDFLanguage
+	50,  // 37: com.aerospike.daemon.Value.l:type_name -> com.aerospike.daemon.List
+	52,  // 38: com.aerospike.daemon.Value.m:type_name -> com.aerospike.daemon.Map
+	54,  // 39: com.aerospike.daemon.Value.json:type_name -> com.aerospike.daemon.Json
+	56,  // 40: com.aerospike.daemon.Key.value:type_name -> com.aerospike.daemon.Value
+	57,  // 41: com.aerospike.daemon.Record.key:type_name -> com.aerospike.daemon.Key
+	178, // 42: com.aerospike.daemon.Record.Bins:type_name -> com.aerospike.daemon.Record.BinsEntry
+	57,  // 43: com.aerospike.daemon.BatchRecord.key:type_name -> com.aerospike.daemon.Key
+	58,  // 44: com.aerospike.daemon.BatchRecord.record:type_name -> com.aerospike.daemon.Record
+	59,  // 45: com.aerospike.daemon.BatchRecord.error:type_name -> com.aerospike.daemon.Error
+	56,  // 46: com.aerospike.daemon.CDTContext.value:type_name -> com.aerospike.daemon.Value
+	63,  // 47: com.aerospike.daemon.Operation.std:type_name -> com.aerospike.daemon.StdOperation
+	170, // 48: com.aerospike.daemon.Operation.map:type_name -> com.aerospike.daemon.CdtMapOperation
+	172, // 49: com.aerospike.daemon.Operation.list:type_name -> com.aerospike.daemon.CdtListOperation
+	174, // 50: com.aerospike.daemon.Operation.hll:type_name -> com.aerospike.daemon.CdtHLLOperation
+	176, // 51: com.aerospike.daemon.Operation.bitwise:type_name -> com.aerospike.daemon.CdtBitwiseOperation
+	177, // 52: com.aerospike.daemon.Operation.exp:type_name -> com.aerospike.daemon.ExpOperation
+	17,  // 53: com.aerospike.daemon.StdOperation.opType:type_name -> com.aerospike.daemon.OperationType
+	61,  // 54: com.aerospike.daemon.StdOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
+	56,  // 55: com.aerospike.daemon.StdOperation.binValue:type_name -> com.aerospike.daemon.Value
+	60,  // 56: com.aerospike.daemon.BatchWrite.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
+	45,  // 57: com.aerospike.daemon.BatchWrite.policy:type_name -> com.aerospike.daemon.BatchWritePolicy
+	62,  // 58: com.aerospike.daemon.BatchWrite.ops:type_name -> com.aerospike.daemon.Operation
+	60,  // 59: com.aerospike.daemon.BatchRead.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
+	44,  // 60: com.aerospike.daemon.BatchRead.policy:type_name -> com.aerospike.daemon.BatchReadPolicy
+	62,  // 61: com.aerospike.daemon.BatchRead.ops:type_name -> com.aerospike.daemon.Operation
+	60,  // 62: com.aerospike.daemon.BatchDelete.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
+	46,  // 63: com.aerospike.daemon.BatchDelete.policy:type_name -> com.aerospike.daemon.BatchDeletePolicy
+	60,  // 64: com.aerospike.daemon.BatchUDF.batchRecord:type_name -> com.aerospike.daemon.BatchRecord
+	47,  // 65: com.aerospike.daemon.BatchUDF.policy:type_name -> com.aerospike.daemon.BatchUDFPolicy
+	56,  // 66: com.aerospike.daemon.BatchUDF.functionArgs:type_name -> com.aerospike.daemon.Value
+	70,  // 67: com.aerospike.daemon.Role.privileges:type_name -> com.aerospike.daemon.Privilege
+	71,  // 68: com.aerospike.daemon.PartitionFilter.partitions:type_name -> com.aerospike.daemon.PartitionStatus
+	59,  // 69: com.aerospike.daemon.AerospikeSingleResponse.error:type_name -> com.aerospike.daemon.Error
+	58,  // 70: com.aerospike.daemon.AerospikeSingleResponse.record:type_name -> com.aerospike.daemon.Record
+	40,  // 71: com.aerospike.daemon.AerospikeGetRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	57,  // 72: com.aerospike.daemon.AerospikeGetRequest.key:type_name -> com.aerospike.daemon.Key
+	40,  // 73: com.aerospike.daemon.AerospikeGetHeaderRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	57,  // 74: com.aerospike.daemon.AerospikeGetHeaderRequest.key:type_name -> com.aerospike.daemon.Key
+	40,  // 75: com.aerospike.daemon.AerospikeExistsRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	57,  // 76: com.aerospike.daemon.AerospikeExistsRequest.key:type_name -> com.aerospike.daemon.Key
+	59,  // 77: com.aerospike.daemon.AerospikeExistsResponse.error:type_name -> com.aerospike.daemon.Error
+	41,  // 78: com.aerospike.daemon.AerospikeDeleteRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	57,  // 79: com.aerospike.daemon.AerospikeDeleteRequest.key:type_name -> com.aerospike.daemon.Key
+	59,  // 80: com.aerospike.daemon.AerospikeDeleteResponse.error:type_name -> com.aerospike.daemon.Error
+	41,  // 81: com.aerospike.daemon.AerospikeTouchRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	57,  // 82: com.aerospike.daemon.AerospikeTouchRequest.key:type_name -> com.aerospike.daemon.Key
+	41,  // 83: com.aerospike.daemon.AerospikeOperateRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	57,  // 84: com.aerospike.daemon.AerospikeOperateRequest.key:type_name -> com.aerospike.daemon.Key
+	62,  // 85: com.aerospike.daemon.AerospikeOperateRequest.operations:type_name -> com.aerospike.daemon.Operation
+	41,  // 86: com.aerospike.daemon.AerospikePutRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	57,  // 87: com.aerospike.daemon.AerospikePutRequest.key:type_name -> com.aerospike.daemon.Key
+	49,  // 88: com.aerospike.daemon.AerospikePutRequest.Bins:type_name -> com.aerospike.daemon.Bin
+	65,  // 89: com.aerospike.daemon.BatchOperate.br:type_name -> com.aerospike.daemon.BatchRead
+	64,  // 90: com.aerospike.daemon.BatchOperate.bw:type_name -> com.aerospike.daemon.BatchWrite
+	66,  // 91: com.aerospike.daemon.BatchOperate.bd:type_name -> com.aerospike.daemon.BatchDelete
+	67,  // 92: com.aerospike.daemon.BatchOperate.bu:type_name -> com.aerospike.daemon.BatchUDF
+	48,  // 93: com.aerospike.daemon.AerospikeBatchOperateRequest.policy:type_name -> com.aerospike.daemon.BatchPolicy
+	83,  // 94: com.aerospike.daemon.AerospikeBatchOperateRequest.records:type_name -> com.aerospike.daemon.BatchOperate
+	59,  // 95: com.aerospike.daemon.AerospikeBatchOperateResponse.error:type_name -> com.aerospike.daemon.Error
+	60,  // 96: com.aerospike.daemon.AerospikeBatchOperateResponse.records:type_name -> com.aerospike.daemon.BatchRecord
+	41,  // 97: com.aerospike.daemon.AerospikeCreateIndexRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	7,   // 98: com.aerospike.daemon.AerospikeCreateIndexRequest.indexType:type_name -> com.aerospike.daemon.IndexType
+	8,   // 99: com.aerospike.daemon.AerospikeCreateIndexRequest.indexCollectionType:type_name -> com.aerospike.daemon.IndexCollectionType
+	61,  // 100: com.aerospike.daemon.AerospikeCreateIndexRequest.ctx:type_name -> com.aerospike.daemon.CDTContext
+	59,  // 101: com.aerospike.daemon.AerospikeCreateIndexResponse.error:type_name -> com.aerospike.daemon.Error
+	41,  // 102: com.aerospike.daemon.AerospikeDropIndexRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	59,  // 103: com.aerospike.daemon.AerospikeDropIndexResponse.error:type_name -> com.aerospike.daemon.Error
+	90,  // 104: com.aerospike.daemon.AerospikeCommitRequest.txn:type_name -> com.aerospike.daemon.Txn
+	59,  // 105: com.aerospike.daemon.AerospikeCommitResponse.error:type_name -> com.aerospike.daemon.Error
+	14,  // 106: com.aerospike.daemon.AerospikeCommitResponse.status:type_name -> com.aerospike.daemon.CommitStatus
+	13,  // 107: com.aerospike.daemon.AerospikeCommitResponse.state:type_name -> com.aerospike.daemon.TxnState
+	90,  // 108: com.aerospike.daemon.AerospikeAbortRequest.txn:type_name -> com.aerospike.daemon.Txn
+	59,  // 109: com.aerospike.daemon.AerospikeAbortResponse.error:type_name -> com.aerospike.daemon.Error
+	15,  // 110: com.aerospike.daemon.AerospikeAbortResponse.status:type_name -> com.aerospike.daemon.AbortStatus
+	13,  // 111: com.aerospike.daemon.AerospikeAbortResponse.state:type_name -> com.aerospike.daemon.TxnState
+	39,  // 112: com.aerospike.daemon.AerospikeExpressionToBase64Request.expression:type_name -> com.aerospike.daemon.Expression
+	59,  // 113: com.aerospike.daemon.AerospikeExpressionToBase64Response.error:type_name -> com.aerospike.daemon.Error
+	43,  // 114: com.aerospike.daemon.AerospikeTruncateRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 115: com.aerospike.daemon.AerospikeTruncateResponse.error:type_name -> com.aerospike.daemon.Error
+	41,  // 116: com.aerospike.daemon.AerospikeRegisterUDFRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	10,  // 117: com.aerospike.daemon.AerospikeRegisterUDFRequest.language:type_name -> com.aerospike.daemon.UDFLanguage
+	59,  // 118: com.aerospike.daemon.AerospikeRegisterUDFResponse.error:type_name -> com.aerospike.daemon.Error
+	41,  // 119: com.aerospike.daemon.AerospikeDropUDFRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	59,  // 120: com.aerospike.daemon.AerospikeDropUDFResponse.error:type_name -> com.aerospike.daemon.Error
+	40,  // 121: com.aerospike.daemon.AerospikeListUDFRequest.policy:type_name -> com.aerospike.daemon.ReadPolicy
+	59,  // 122: com.aerospike.daemon.AerospikeListUDFResponse.error:type_name -> com.aerospike.daemon.Error
+	55,  // 123: com.aerospike.daemon.AerospikeListUDFResponse.udfList:type_name -> com.aerospike.daemon.UDFMeta
+	41,  // 124: com.aerospike.daemon.AerospikeUDFExecuteRequest.policy:type_name -> com.aerospike.daemon.WritePolicy
+	57,  // 125: com.aerospike.daemon.AerospikeUDFExecuteRequest.key:type_name -> com.aerospike.daemon.Key
+	56,  // 126: com.aerospike.daemon.AerospikeUDFExecuteRequest.args:type_name -> com.aerospike.daemon.Value
+	59,  // 127: com.aerospike.daemon.AerospikeUDFExecuteResponse.error:type_name -> com.aerospike.daemon.Error
+	56,  // 128: com.aerospike.daemon.AerospikeUDFExecuteResponse.result:type_name -> com.aerospike.daemon.Value
+	42,  // 129: com.aerospike.daemon.AerospikeCreateUserRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 130: com.aerospike.daemon.AerospikeCreateUserResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 131: com.aerospike.daemon.AerospikeDropUserRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 132: com.aerospike.daemon.AerospikeDropUserResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 133: com.aerospike.daemon.AerospikeChangePasswordRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 134: com.aerospike.daemon.AerospikeChangePasswordResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 135: com.aerospike.daemon.AerospikeGrantRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 136: com.aerospike.daemon.AerospikeGrantRolesResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 137: com.aerospike.daemon.AerospikeRevokeRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 138: com.aerospike.daemon.AerospikeRevokeRolesResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 139: com.aerospike.daemon.AerospikeQueryUsersRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 140: com.aerospike.daemon.AerospikeQueryUsersResponse.error:type_name -> com.aerospike.daemon.Error
+	68,  // 141: com.aerospike.daemon.AerospikeQueryUsersResponse.userRoles:type_name -> com.aerospike.daemon.UserRole
+	42,  // 142: com.aerospike.daemon.AerospikeQueryRolesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 143: com.aerospike.daemon.AerospikeQueryRolesResponse.error:type_name -> com.aerospike.daemon.Error
+	69,  // 144: com.aerospike.daemon.AerospikeQueryRolesResponse.roles:type_name -> com.aerospike.daemon.Role
+	42,  // 145: com.aerospike.daemon.AerospikeCreateRoleRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	70,  // 146: com.aerospike.daemon.AerospikeCreateRoleRequest.privileges:type_name -> com.aerospike.daemon.Privilege
+	59,  // 147: com.aerospike.daemon.AerospikeCreateRoleResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 148: com.aerospike.daemon.AerospikeDropRoleRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 149: com.aerospike.daemon.AerospikeDropRoleResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 150: com.aerospike.daemon.AerospikeGrantPrivilegesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	70,  // 151: com.aerospike.daemon.AerospikeGrantPrivilegesRequest.privileges:type_name -> com.aerospike.daemon.Privilege
+	59,  // 152: com.aerospike.daemon.AerospikeGrantPrivilegesResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 153: com.aerospike.daemon.AerospikeRevokePrivilegesRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	70,  // 154: com.aerospike.daemon.AerospikeRevokePrivilegesRequest.privileges:type_name -> com.aerospike.daemon.Privilege
+	59,  // 155: com.aerospike.daemon.AerospikeRevokePrivilegesResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 156: com.aerospike.daemon.AerospikeSetAllowlistRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 157: com.aerospike.daemon.AerospikeSetAllowlistResponse.error:type_name -> com.aerospike.daemon.Error
+	42,  // 158: com.aerospike.daemon.AerospikeSetQuotasRequest.policy:type_name -> com.aerospike.daemon.AdminPolicy
+	59,  // 159: com.aerospike.daemon.AerospikeSetQuotasResponse.error:type_name -> com.aerospike.daemon.Error
+	164, // 160: com.aerospike.daemon.AerospikeScanRequest.policy:type_name -> com.aerospike.daemon.ScanPolicy
+	72,  // 161: com.aerospike.daemon.AerospikeScanRequest.partitionFilter:type_name -> com.aerospike.daemon.PartitionFilter
+	58,  // 162: com.aerospike.daemon.AerospikeStreamResponse.record:type_name -> com.aerospike.daemon.Record
+	59,  // 163: com.aerospike.daemon.AerospikeStreamResponse.error:type_name -> com.aerospike.daemon.Error
+	165, // 164: com.aerospike.daemon.AerospikeQueryRequest.policy:type_name -> com.aerospike.daemon.QueryPolicy
+	167, // 165: com.aerospike.daemon.AerospikeQueryRequest.statement:type_name -> com.aerospike.daemon.Statement
+	72,  // 166: com.aerospike.daemon.AerospikeQueryRequest.partitionFilter:type_name -> com.aerospike.daemon.PartitionFilter
+	165, // 167: com.aerospike.daemon.AerospikeQueryAggregateRequest.policy:type_name -> com.aerospike.daemon.QueryPolicy
+	167, // 168: com.aerospike.daemon.AerospikeQueryAggregateRequest.statement:type_name -> com.aerospike.daemon.Statement
+	56,  // 169: com.aerospike.daemon.AerospikeAggregateResponse.value:type_name -> com.aerospike.daemon.Value
+	59,  // 170: com.aerospike.daemon.AerospikeAggregateResponse.error:type_name -> com.aerospike.daemon.Error
+	162, // 171: com.aerospike.daemon.AerospikeQueryExecuteRequest.policy:type_name -> com.aerospike.daemon.BackgroundExecutePolicy
+	167, // 172: com.aerospike.daemon.AerospikeQueryExecuteRequest.statement:type_name -> com.aerospike.daemon.Statement
+	62,  // 173: com.aerospike.daemon.AerospikeQueryExecuteRequest.operations:type_name -> com.aerospike.daemon.Operation
+	59,  // 174: com.aerospike.daemon.AerospikeQueryExecuteResponse.error:type_name -> com.aerospike.daemon.Error
+	59,  // 175: com.aerospike.daemon.AerospikeTaskProgressResponse.error:type_name -> com.aerospike.daemon.Error
+	16,  // 176: com.aerospike.daemon.AerospikeTaskProgressResponse.status:type_name -> com.aerospike.daemon.TaskStatus
+	140, // 177: com.aerospike.daemon.AerospikeTaskProgressResponse.progress:type_name -> com.aerospike.daemon.NodeTaskProgress
+	43,  // 178: com.aerospike.daemon.AerospikeIndexTaskStatusRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	43,  // 179: com.aerospike.daemon.AerospikeUDFTaskStatusRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 180: com.aerospike.daemon.AerospikeExecuteTaskStatusResponse.error:type_name -> com.aerospike.daemon.Error
+	16,  // 181: com.aerospike.daemon.AerospikeExecuteTaskStatusResponse.status:type_name -> com.aerospike.daemon.TaskStatus
+	43,  // 182: com.aerospike.daemon.AerospikeInfoRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 183: com.aerospike.daemon.AerospikeInfoResponse.error:type_name -> com.aerospike.daemon.Error
+	179, // 184: com.aerospike.daemon.AerospikeInfoResponse.results:type_name -> com.aerospike.daemon.AerospikeInfoResponse.ResultsEntry
+	180, // 185: com.aerospike.daemon.Node.racks:type_name -> com.aerospike.daemon.Node.RacksEntry
+	59,  // 186: com.aerospike.daemon.AerospikeNodesResponse.error:type_name -> com.aerospike.daemon.Error
+	148, // 187: com.aerospike.daemon.AerospikeNodesResponse.nodes:type_name -> com.aerospike.daemon.Node
+	43,  // 188: com.aerospike.daemon.AerospikeNamespacesRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 189: com.aerospike.daemon.AerospikeNamespacesResponse.error:type_name -> com.aerospike.daemon.Error
+	151, // 190: com.aerospike.daemon.AerospikeNamespacesResponse.namespaces:type_name -> com.aerospike.daemon.NamespaceInfo
+	43,  // 191: com.aerospike.daemon.AerospikeSetsRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 192: com.aerospike.daemon.AerospikeSetsResponse.error:type_name -> com.aerospike.daemon.Error
+	154, // 193: com.aerospike.daemon.AerospikeSetsResponse.sets:type_name -> com.aerospike.daemon.SetInfo
+	7,   // 194: com.aerospike.daemon.IndexInfo.indexType:type_name -> com.aerospike.daemon.IndexType
+	8,   // 195: com.aerospike.daemon.IndexInfo.collectionType:type_name -> com.aerospike.daemon.IndexCollectionType
+	61,  // 196: com.aerospike.daemon.IndexInfo.ctx:type_name -> com.aerospike.daemon.CDTContext
+	43,  // 197: com.aerospike.daemon.AerospikeIndexesRequest.policy:type_name -> com.aerospike.daemon.InfoPolicy
+	59,  // 198: com.aerospike.daemon.AerospikeIndexesResponse.error:type_name -> com.aerospike.daemon.Error
+	157, // 199: com.aerospike.daemon.AerospikeIndexesResponse.indexes:type_name -> com.aerospike.daemon.IndexInfo
+	5,   // 200: com.aerospike.daemon.BackgroundExecutePolicy.replica:type_name -> com.aerospike.daemon.ReplicaPolicy
+	3,   // 201: com.aerospike.daemon.BackgroundExecutePolicy.readModeAP:type_name -> com.aerospike.daemon.ReadModeAP
+	4,   // 202: com.aerospike.daemon.BackgroundExecutePolicy.readModeSC:type_name -> com.aerospike.daemon.ReadModeSC
+	39,  // 203: com.aerospike.daemon.BackgroundExecutePolicy.filterExpression:type_name -> com.aerospike.daemon.Expression
+	18,  // 204: com.aerospike.daemon.BackgroundExecutePolicy.recordExistsAction:type_name -> com.aerospike.daemon.RecordExistsAction
+	19,  // 205: com.aerospike.daemon.BackgroundExecutePolicy.generationPolicy:type_name -> com.aerospike.daemon.GenerationPolicy
+	20,  // 206: com.aerospike.daemon.BackgroundExecutePolicy.commitLevel:type_name -> com.aerospike.daemon.CommitLevel
+	40,  // 207: com.aerospike.daemon.MultiPolicy.readPolicy:type_name -> com.aerospike.daemon.ReadPolicy
+	163, // 208: com.aerospike.daemon.ScanPolicy.multiPolicy:type_name -> com.aerospike.daemon.MultiPolicy
+	163, // 209: com.aerospike.daemon.QueryPolicy.multiPolicy:type_name -> com.aerospike.daemon.MultiPolicy
+	21,  // 210: com.aerospike.daemon.QueryPolicy.expectedDuration:type_name -> com.aerospike.daemon.QueryDuration
+	56,  // 211: com.aerospike.daemon.UDFCall.functionArgs:type_name -> com.aerospike.daemon.Value
+	168, // 212: com.aerospike.daemon.Statement.filter:type_name -> com.aerospike.daemon.QueryFilter
+	166, // 213: com.aerospike.daemon.Statement.udfCall:type_name -> com.aerospike.daemon.UDFCall
+	8,   // 214: com.aerospike.daemon.QueryFilter.idxType:type_name -> com.aerospike.daemon.IndexCollectionType
+	56,  // 215: com.aerospike.daemon.QueryFilter.begin:type_name -> com.aerospike.daemon.Value
+	56,  // 216: com.aerospike.daemon.QueryFilter.end:type_name -> com.aerospike.daemon.Value
+	61,  // 217: com.aerospike.daemon.QueryFilter.ctx:type_name -> com.aerospike.daemon.CDTContext
+	12,  // 218: com.aerospike.daemon.CdtMapPolicy.mapOrder:type_name -> com.aerospike.daemon.MapOrderType
+	25,  // 219: com.aerospike.daemon.CdtMapPolicy.flags:type_name -> com.aerospike.daemon.CdtMapWriteFlags
+	22,  // 220: com.aerospike.daemon.CdtMapOperation.op:type_name -> com.aerospike.daemon.CdtMapCommandOp
+	169, // 221: com.aerospike.daemon.CdtMapOperation.policy:type_name -> com.aerospike.daemon.CdtMapPolicy
+	56,  // 222: com.aerospike.daemon.CdtMapOperation.args:type_name -> com.aerospike.daemon.Value
+	23,  // 223: com.aerospike.daemon.CdtMapOperation.returnType:type_name -> com.aerospike.daemon.CdtMapReturnType
+	61,  // 224: com.aerospike.daemon.CdtMapOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
+	11,  // 225: com.aerospike.daemon.CdtListPolicy.order:type_name -> com.aerospike.daemon.ListOrderType
+	29,  // 226: com.aerospike.daemon.CdtListPolicy.flags:type_name -> com.aerospike.daemon.CdtListWriteFlags
+	26,  // 227: com.aerospike.daemon.CdtListOperation.op:type_name -> com.aerospike.daemon.CdtListCommandOp
+	171, // 228: com.aerospike.daemon.CdtListOperation.policy:type_name -> com.aerospike.daemon.CdtListPolicy
+	56,  // 229: com.aerospike.daemon.CdtListOperation.args:type_name -> com.aerospike.daemon.Value
+	27,  // 230: com.aerospike.daemon.CdtListOperation.returnType:type_name -> com.aerospike.daemon.CdtListReturnType
+	61,  // 231: com.aerospike.daemon.CdtListOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
+	31,  // 232: com.aerospike.daemon.CdtHLLPolicy.flags:type_name -> com.aerospike.daemon.CdtHLLWriteFlags
+	30,  // 233: com.aerospike.daemon.CdtHLLOperation.op:type_name -> com.aerospike.daemon.CdtHLLCommandOp
+	173, // 234: com.aerospike.daemon.CdtHLLOperation.policy:type_name -> com.aerospike.daemon.CdtHLLPolicy
+	56,  // 235: com.aerospike.daemon.CdtHLLOperation.args:type_name -> com.aerospike.daemon.Value
+	33,  // 236: com.aerospike.daemon.CdtBitwisePolicy.flags:type_name -> com.aerospike.daemon.CdtBitwiseWriteFlags
+	32,  // 237: com.aerospike.daemon.CdtBitwiseOperation.op:type_name -> com.aerospike.daemon.CdtBitwiseCommandOp
+	175, // 238: com.aerospike.daemon.CdtBitwiseOperation.policy:type_name -> com.aerospike.daemon.CdtBitwisePolicy
+	56,  // 239: com.aerospike.daemon.CdtBitwiseOperation.args:type_name -> com.aerospike.daemon.Value
+	61,  // 240: com.aerospike.daemon.CdtBitwiseOperation.ctx:type_name -> com.aerospike.daemon.CDTContext
+	38,  // 241: com.aerospike.daemon.ExpOperation.opType:type_name -> com.aerospike.daemon.ExpOperationType
+	39,  // 242: com.aerospike.daemon.ExpOperation.exp:type_name -> com.aerospike.daemon.Expression
+	56,  // 243: com.aerospike.daemon.Record.BinsEntry.value:type_name -> com.aerospike.daemon.Value
+	160, // 244: com.aerospike.daemon.KVS.Version:input_type -> com.aerospike.daemon.AerospikeVersionRequest
+	82,  // 245: com.aerospike.daemon.KVS.Put:input_type -> com.aerospike.daemon.AerospikePutRequest
+	82,  // 246: com.aerospike.daemon.KVS.Add:input_type -> com.aerospike.daemon.AerospikePutRequest
+	82,  // 247: com.aerospike.daemon.KVS.Append:input_type -> com.aerospike.daemon.AerospikePutRequest
+	82,  // 248: com.aerospike.daemon.KVS.Prepend:input_type -> com.aerospike.daemon.AerospikePutRequest
+	74,  // 249: com.aerospike.daemon.KVS.Get:input_type -> com.aerospike.daemon.AerospikeGetRequest
+	75,  // 250: com.aerospike.daemon.KVS.GetHeader:input_type -> com.aerospike.daemon.AerospikeGetHeaderRequest
+	76,  // 251: com.aerospike.daemon.KVS.Exists:input_type -> com.aerospike.daemon.AerospikeExistsRequest
+	78,  // 252: com.aerospike.daemon.KVS.Delete:input_type -> com.aerospike.daemon.AerospikeDeleteRequest
+	80,  // 253: com.aerospike.daemon.KVS.Touch:input_type -> com.aerospike.daemon.AerospikeTouchRequest
+	81,  // 254: com.aerospike.daemon.KVS.Operate:input_type -> com.aerospike.daemon.AerospikeOperateRequest
+	84,  // 255: com.aerospike.daemon.KVS.BatchOperate:input_type -> com.aerospike.daemon.AerospikeBatchOperateRequest
+	86,  // 256: com.aerospike.daemon.KVS.CreateIndex:input_type -> com.aerospike.daemon.AerospikeCreateIndexRequest
+	88,  // 257: com.aerospike.daemon.KVS.DropIndex:input_type -> com.aerospike.daemon.AerospikeDropIndexRequest
+	97,  // 258: com.aerospike.daemon.KVS.Truncate:input_type -> com.aerospike.daemon.AerospikeTruncateRequest
+	99,  // 259: com.aerospike.daemon.KVS.RegisterUDF:input_type -> com.aerospike.daemon.AerospikeRegisterUDFRequest
+	101, // 260: com.aerospike.daemon.KVS.DropUDF:input_type -> com.aerospike.daemon.AerospikeDropUDFRequest
+	103, // 261: com.aerospike.daemon.KVS.ListUDF:input_type -> com.aerospike.daemon.AerospikeListUDFRequest
+	105, // 262: com.aerospike.daemon.KVS.UDFExecute:input_type -> com.aerospike.daemon.AerospikeUDFExecuteRequest
+	107, // 263: com.aerospike.daemon.KVS.CreateUser:input_type -> com.aerospike.daemon.AerospikeCreateUserRequest
+	109, // 264: com.aerospike.daemon.KVS.DropUser:input_type -> com.aerospike.daemon.AerospikeDropUserRequest
+	111, // 265: com.aerospike.daemon.KVS.ChangePassword:input_type -> com.aerospike.daemon.AerospikeChangePasswordRequest
+	113, // 266: com.aerospike.daemon.KVS.GrantRoles:input_type -> com.aerospike.daemon.AerospikeGrantRolesRequest
+	115, // 267: com.aerospike.daemon.KVS.RevokeRoles:input_type -> com.aerospike.daemon.AerospikeRevokeRolesRequest
+	117, // 268: com.aerospike.daemon.KVS.QueryUsers:input_type -> com.aerospike.daemon.AerospikeQueryUsersRequest
+	119, // 269: com.aerospike.daemon.KVS.QueryRoles:input_type -> com.aerospike.daemon.AerospikeQueryRolesRequest
+	121, // 270: com.aerospike.daemon.KVS.CreateRole:input_type -> com.aerospike.daemon.AerospikeCreateRoleRequest
+	123, // 271: com.aerospike.daemon.KVS.DropRole:input_type -> com.aerospike.daemon.AerospikeDropRoleRequest
+	125, // 272: com.aerospike.daemon.KVS.GrantPrivileges:input_type -> com.aerospike.daemon.AerospikeGrantPrivilegesRequest
+	127, // 273: com.aerospike.daemon.KVS.RevokePrivileges:input_type -> com.aerospike.daemon.AerospikeRevokePrivilegesRequest
+	129, // 274: com.aerospike.daemon.KVS.SetAllowlist:input_type -> com.aerospike.daemon.AerospikeSetAllowlistRequest
+	131, // 275: com.aerospike.daemon.KVS.SetQuotas:input_type -> com.aerospike.daemon.AerospikeSetQuotasRequest
+	133, // 276: com.aerospike.daemon.KVS.Scan:input_type -> com.aerospike.daemon.AerospikeScanRequest
+	135, // 277: com.aerospike.daemon.KVS.Query:input_type -> com.aerospike.daemon.AerospikeQueryRequest
+	146, // 278: com.aerospike.daemon.KVS.Info:input_type -> com.aerospike.daemon.AerospikeInfoRequest
+	149, // 279: com.aerospike.daemon.KVS.Nodes:input_type -> com.aerospike.daemon.AerospikeNodesRequest
+	152, // 280: com.aerospike.daemon.KVS.Namespaces:input_type -> com.aerospike.daemon.AerospikeNamespacesRequest
+	155, // 281: com.aerospike.daemon.KVS.Sets:input_type -> com.aerospike.daemon.AerospikeSetsRequest
+	158, // 282: com.aerospike.daemon.KVS.Indexes:input_type -> com.aerospike.daemon.AerospikeIndexesRequest
+	136, // 283: com.aerospike.daemon.KVS.QueryAggregate:input_type -> com.aerospike.daemon.AerospikeQueryAggregateRequest
+	138, // 284: com.aerospike.daemon.KVS.QueryExecute:input_type -> com.aerospike.daemon.AerospikeQueryExecuteRequest
+	142, // 285: com.aerospike.daemon.KVS.IndexTaskStatus:input_type -> com.aerospike.daemon.AerospikeIndexTaskStatusRequest
+	143, // 286: com.aerospike.daemon.KVS.UDFTaskStatus:input_type -> com.aerospike.daemon.AerospikeUDFTaskStatusRequest
+	144, // 287: com.aerospike.daemon.KVS.ExecuteTaskStatus:input_type -> com.aerospike.daemon.AerospikeExecuteTaskStatusRequest
+	91,  // 288: com.aerospike.daemon.KVS.Commit:input_type -> com.aerospike.daemon.AerospikeCommitRequest
+	93,  // 289: com.aerospike.daemon.KVS.Abort:input_type -> com.aerospike.daemon.AerospikeAbortRequest
+	95,  // 290: com.aerospike.daemon.KVS.ExpressionToBase64:input_type -> com.aerospike.daemon.AerospikeExpressionToBase64Request
+	161, // 291: com.aerospike.daemon.KVS.Version:output_type -> com.aerospike.daemon.AerospikeVersionResponse
+	59,  // 292: com.aerospike.daemon.KVS.Put:output_type -> com.aerospike.daemon.Error
+	59,  // 293: com.aerospike.daemon.KVS.Add:output_type -> com.aerospike.daemon.Error
+	59,  // 294: com.aerospike.daemon.KVS.Append:output_type -> com.aerospike.daemon.Error
+	59,  // 295: com.aerospike.daemon.KVS.Prepend:output_type -> com.aerospike.daemon.Error
+	73,  // 296: com.aerospike.daemon.KVS.Get:output_type -> com.aerospike.daemon.AerospikeSingleResponse
+	73,  // 297: com.aerospike.daemon.KVS.GetHeader:output_type -> com.aerospike.daemon.AerospikeSingleResponse
+	77,  // 298: com.aerospike.daemon.KVS.Exists:output_type -> com.aerospike.daemon.AerospikeExistsResponse
+	79,  // 299: com.aerospike.daemon.KVS.Delete:output_type -> com.aerospike.daemon.AerospikeDeleteResponse
+	59,  // 300: com.aerospike.daemon.KVS.Touch:output_type -> com.aerospike.daemon.Error
+	73,  // 301: com.aerospike.daemon.KVS.Operate:output_type -> com.aerospike.daemon.AerospikeSingleResponse
+	85,  // 302: com.aerospike.daemon.KVS.BatchOperate:output_type -> com.aerospike.daemon.AerospikeBatchOperateResponse
+	87,  // 303: com.aerospike.daemon.KVS.CreateIndex:output_type -> com.aerospike.daemon.AerospikeCreateIndexResponse
+	89,  // 304: com.aerospike.daemon.KVS.DropIndex:output_type -> com.aerospike.daemon.AerospikeDropIndexResponse
+	98,  // 305: com.aerospike.daemon.KVS.Truncate:output_type -> com.aerospike.daemon.AerospikeTruncateResponse
+	100, // 306: com.aerospike.daemon.KVS.RegisterUDF:output_type -> com.aerospike.daemon.AerospikeRegisterUDFResponse
+	102, // 307: com.aerospike.daemon.KVS.DropUDF:output_type -> com.aerospike.daemon.AerospikeDropUDFResponse
+	104, // 308: com.aerospike.daemon.KVS.ListUDF:output_type -> com.aerospike.daemon.AerospikeListUDFResponse
+	106, // 309: com.aerospike.daemon.KVS.UDFExecute:output_type -> com.aerospike.daemon.AerospikeUDFExecuteResponse
+	108, // 310: com.aerospike.daemon.KVS.CreateUser:output_type -> com.aerospike.daemon.AerospikeCreateUserResponse
+	110, // 311: com.aerospike.daemon.KVS.DropUser:output_type -> com.aerospike.daemon.AerospikeDropUserResponse
+	112, // 312: com.aerospike.daemon.KVS.ChangePassword:output_type -> com.aerospike.daemon.AerospikeChangePasswordResponse
+	114, // 313: com.aerospike.daemon.KVS.GrantRoles:output_type -> com.aerospike.daemon.AerospikeGrantRolesResponse
+	116, // 314: com.aerospike.daemon.KVS.RevokeRoles:output_type -> com.aerospike.daemon.AerospikeRevokeRolesResponse
+	118, // 315: com.aerospike.daemon.KVS.QueryUsers:output_type -> com.aerospike.daemon.AerospikeQueryUsersResponse
+	120, // 316: com.aerospike.daemon.KVS.QueryRoles:output_type -> com.aerospike.daemon.AerospikeQueryRolesResponse
+	122, // 317: com.aerospike.daemon.KVS.CreateRole:output_type -> com.aerospike.daemon.AerospikeCreateRoleResponse
+	124, // 318: com.aerospike.daemon.KVS.DropRole:output_type -> com.aerospike.daemon.AerospikeDropRoleResponse
+	126, // 319: com.aerospike.daemon.KVS.GrantPrivileges:output_type -> com.aerospike.daemon.AerospikeGrantPrivilegesResponse
+	128, // 320: com.aerospike.daemon.KVS.RevokePrivileges:output_type -> com.aerospike.daemon.AerospikeRevokePrivilegesResponse
+	130, // 321: com.aerospike.daemon.KVS.SetAllowlist:output_type -> com.aerospike.daemon.AerospikeSetAllowlistResponse
+	132, // 322: com.aerospike.daemon.KVS.SetQuotas:output_type -> com.aerospike.daemon.AerospikeSetQuotasResponse
+	134, // 323: com.aerospike.daemon.KVS.Scan:output_type -> com.aerospike.daemon.AerospikeStreamResponse
+	134, // 324: com.aerospike.daemon.KVS.Query:output_type -> com.aerospike.daemon.AerospikeStreamResponse
+	147, // 325: com.aerospike.daemon.KVS.Info:output_type -> com.aerospike.daemon.AerospikeInfoResponse
+	150, // 326: com.aerospike.daemon.KVS.Nodes:output_type -> com.aerospike.daemon.AerospikeNodesResponse
+	153, // 327: com.aerospike.daemon.KVS.Namespaces:output_type -> com.aerospike.daemon.AerospikeNamespacesResponse
+	156, // 328: com.aerospike.daemon.KVS.Sets:output_type -> com.aerospike.daemon.AerospikeSetsResponse
+	159, // 329: com.aerospike.daemon.KVS.Indexes:output_type -> com.aerospike.daemon.AerospikeIndexesResponse
+	137, // 330: com.aerospike.daemon.KVS.QueryAggregate:output_type -> com.aerospike.daemon.AerospikeAggregateResponse
+	139, // 331: com.aerospike.daemon.KVS.QueryExecute:output_type -> com.aerospike.daemon.AerospikeQueryExecuteResponse
+	141, // 332: com.aerospike.daemon.KVS.IndexTaskStatus:output_type -> com.aerospike.daemon.AerospikeTaskProgressResponse
+	141, // 333: com.aerospike.daemon.KVS.UDFTaskStatus:output_type -> com.aerospike.daemon.AerospikeTaskProgressResponse
+	145, // 334: com.aerospike.daemon.KVS.ExecuteTaskStatus:output_type -> com.aerospike.daemon.AerospikeExecuteTaskStatusResponse
+	92,  // 335: com.aerospike.daemon.KVS.Commit:output_type -> com.aerospike.daemon.AerospikeCommitResponse
+	94,  // 336: com.aerospike.daemon.KVS.Abort:output_type -> com.aerospike.daemon.AerospikeAbortResponse
+	96,  // 337: com.aerospike.daemon.KVS.ExpressionToBase64:output_type -> com.aerospike.daemon.AerospikeExpressionToBase64Response
+	291, // [291:338] is the sub-list for method output_type
+	244, // [244:291] is the sub-list for method input_type
+	244, // [244:244] is the sub-list for extension type_name
+	244, // [244:244] is the sub-list for extension extendee
+	0,   // [0:244] is the sub-list for field type_name
 }
 
 func init() { file_asld_kvs_proto_init() }
@@ -12348,8 +15473,212 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ReadPolicy); i {
+		file_asld_kvs_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ReadPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*WritePolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AdminPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*InfoPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchReadPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchWritePolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchDeletePolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchUDFPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchPolicy); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Bin); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*List); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*MapEntry); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Map); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*JsonEntry); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Json); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UDFMeta); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Value); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Key); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12360,8 +15689,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*WritePolicy); i {
+		file_asld_kvs_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Record); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12372,8 +15701,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AdminPolicy); i {
+		file_asld_kvs_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Error); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12384,8 +15713,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*InfoPolicy); i {
+		file_asld_kvs_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchRecord); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12396,8 +15725,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchReadPolicy); i {
+		file_asld_kvs_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CDTContext); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12408,8 +15737,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchWritePolicy); i {
+		file_asld_kvs_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Operation); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12420,8 +15749,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchDeletePolicy); i {
+		file_asld_kvs_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StdOperation); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12432,8 +15761,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchUDFPolicy); i {
+		file_asld_kvs_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchWrite); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12444,8 +15773,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchPolicy); i {
+		file_asld_kvs_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchRead); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12456,8 +15785,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Bin); i {
+		file_asld_kvs_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchDelete); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12468,8 +15797,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*List); i {
+		file_asld_kvs_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchUDF); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12480,8 +15809,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*MapEntry); i {
+		file_asld_kvs_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UserRole); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12492,8 +15821,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Map); i {
+		file_asld_kvs_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Role); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12504,8 +15833,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*JsonEntry); i {
+		file_asld_kvs_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Privilege); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12516,8 +15845,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Json); i {
+		file_asld_kvs_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PartitionStatus); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12528,8 +15857,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UDFMeta); i {
+		file_asld_kvs_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PartitionFilter); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12540,8 +15869,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Value); i {
+		file_asld_kvs_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSingleResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12552,8 +15881,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Key); i {
+		file_asld_kvs_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGetRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12564,8 +15893,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Record); i {
+		file_asld_kvs_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGetHeaderRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12576,8 +15905,188 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Error); i {
+		file_asld_kvs_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExistsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExistsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDeleteRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDeleteResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeTouchRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeOperateRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikePutRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchOperate); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeBatchOperateRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeBatchOperateResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateIndexRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateIndexResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropIndexRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropIndexResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Txn); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_asld_kvs_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCommitRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12588,8 +16097,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchRecord); i {
+		file_asld_kvs_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCommitResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12600,8 +16109,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CDTContext); i {
+		file_asld_kvs_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeAbortRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12612,8 +16121,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Operation); i {
+		file_asld_kvs_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeAbortResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12624,8 +16133,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*StdOperation); i {
+		file_asld_kvs_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExpressionToBase64Request); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12636,8 +16145,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchWrite); i {
+		file_asld_kvs_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExpressionToBase64Response); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12648,8 +16157,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchRead); i {
+		file_asld_kvs_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeTruncateRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12660,8 +16169,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchDelete); i {
+		file_asld_kvs_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeTruncateResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12672,8 +16181,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchUDF); i {
+		file_asld_kvs_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRegisterUDFRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12684,8 +16193,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UserRole); i {
+		file_asld_kvs_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRegisterUDFResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12696,8 +16205,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Role); i {
+		file_asld_kvs_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropUDFRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12708,8 +16217,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Privilege); i {
+		file_asld_kvs_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropUDFResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12720,8 +16229,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PartitionStatus); i {
+		file_asld_kvs_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeListUDFRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12732,8 +16241,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PartitionFilter); i {
+		file_asld_kvs_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeListUDFResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12744,8 +16253,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeSingleResponse); i {
+		file_asld_kvs_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeUDFExecuteRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12756,8 +16265,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGetRequest); i {
+		file_asld_kvs_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeUDFExecuteResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12768,8 +16277,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGetHeaderRequest); i {
+		file_asld_kvs_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateUserRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12780,8 +16289,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeExistsRequest); i {
+		file_asld_kvs_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateUserResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12792,8 +16301,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeExistsResponse); i {
+		file_asld_kvs_proto_msgTypes[70].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropUserRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12804,8 +16313,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDeleteRequest); i {
+		file_asld_kvs_proto_msgTypes[71].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropUserResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12816,8 +16325,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDeleteResponse); i {
+		file_asld_kvs_proto_msgTypes[72].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeChangePasswordRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12828,8 +16337,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeTouchRequest); i {
+		file_asld_kvs_proto_msgTypes[73].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeChangePasswordResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12840,8 +16349,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikePutRequest); i {
+		file_asld_kvs_proto_msgTypes[74].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGrantRolesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12852,8 +16361,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchOperate); i {
+		file_asld_kvs_proto_msgTypes[75].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGrantRolesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12864,8 +16373,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeBatchOperateRequest); i {
+		file_asld_kvs_proto_msgTypes[76].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRevokeRolesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12876,8 +16385,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeBatchOperateResponse); i {
+		file_asld_kvs_proto_msgTypes[77].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRevokeRolesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12888,8 +16397,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateIndexRequest); i {
+		file_asld_kvs_proto_msgTypes[78].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryUsersRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12900,8 +16409,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateIndexResponse); i {
+		file_asld_kvs_proto_msgTypes[79].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryUsersResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12912,8 +16421,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropIndexRequest); i {
+		file_asld_kvs_proto_msgTypes[80].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryRolesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12924,8 +16433,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropIndexResponse); i {
+		file_asld_kvs_proto_msgTypes[81].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryRolesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12936,8 +16445,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeTruncateRequest); i {
+		file_asld_kvs_proto_msgTypes[82].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateRoleRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12948,8 +16457,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeTruncateResponse); i {
+		file_asld_kvs_proto_msgTypes[83].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeCreateRoleResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12960,8 +16469,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRegisterUDFRequest); i {
+		file_asld_kvs_proto_msgTypes[84].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropRoleRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12972,8 +16481,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRegisterUDFResponse); i {
+		file_asld_kvs_proto_msgTypes[85].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeDropRoleResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12984,8 +16493,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropUDFRequest); i {
+		file_asld_kvs_proto_msgTypes[86].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGrantPrivilegesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -12996,8 +16505,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropUDFResponse); i {
+		file_asld_kvs_proto_msgTypes[87].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeGrantPrivilegesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13008,8 +16517,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeListUDFRequest); i {
+		file_asld_kvs_proto_msgTypes[88].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRevokePrivilegesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13020,8 +16529,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeListUDFResponse); i {
+		file_asld_kvs_proto_msgTypes[89].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeRevokePrivilegesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13032,8 +16541,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeUDFExecuteRequest); i {
+		file_asld_kvs_proto_msgTypes[90].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetAllowlistRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13044,8 +16553,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeUDFExecuteResponse); i {
+		file_asld_kvs_proto_msgTypes[91].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetAllowlistResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13056,8 +16565,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateUserRequest); i {
+		file_asld_kvs_proto_msgTypes[92].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetQuotasRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13068,8 +16577,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateUserResponse); i {
+		file_asld_kvs_proto_msgTypes[93].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetQuotasResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13080,8 +16589,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropUserRequest); i {
+		file_asld_kvs_proto_msgTypes[94].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeScanRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13092,8 +16601,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropUserResponse); i {
+		file_asld_kvs_proto_msgTypes[95].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeStreamResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13104,8 +16613,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeChangePasswordRequest); i {
+		file_asld_kvs_proto_msgTypes[96].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13116,8 +16625,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeChangePasswordResponse); i {
+		file_asld_kvs_proto_msgTypes[97].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryAggregateRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13128,8 +16637,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGrantRolesRequest); i {
+		file_asld_kvs_proto_msgTypes[98].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeAggregateResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13140,8 +16649,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGrantRolesResponse); i {
+		file_asld_kvs_proto_msgTypes[99].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryExecuteRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13152,8 +16661,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRevokeRolesRequest); i {
+		file_asld_kvs_proto_msgTypes[100].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeQueryExecuteResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13164,8 +16673,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRevokeRolesResponse); i {
+		file_asld_kvs_proto_msgTypes[101].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*NodeTaskProgress); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13176,8 +16685,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[70].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeQueryUsersRequest); i {
+		file_asld_kvs_proto_msgTypes[102].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeTaskProgressResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13188,8 +16697,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[71].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeQueryUsersResponse); i {
+		file_asld_kvs_proto_msgTypes[103].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeIndexTaskStatusRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13200,8 +16709,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[72].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeQueryRolesRequest); i {
+		file_asld_kvs_proto_msgTypes[104].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeUDFTaskStatusRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13212,8 +16721,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[73].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeQueryRolesResponse); i {
+		file_asld_kvs_proto_msgTypes[105].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExecuteTaskStatusRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13224,8 +16733,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[74].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateRoleRequest); i {
+		file_asld_kvs_proto_msgTypes[106].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeExecuteTaskStatusResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13236,8 +16745,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[75].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeCreateRoleResponse); i {
+		file_asld_kvs_proto_msgTypes[107].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeInfoRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13248,8 +16757,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[76].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropRoleRequest); i {
+		file_asld_kvs_proto_msgTypes[108].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeInfoResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13260,8 +16769,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[77].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeDropRoleResponse); i {
+		file_asld_kvs_proto_msgTypes[109].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Node); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13272,8 +16781,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[78].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGrantPrivilegesRequest); i {
+		file_asld_kvs_proto_msgTypes[110].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeNodesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13284,8 +16793,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[79].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeGrantPrivilegesResponse); i {
+		file_asld_kvs_proto_msgTypes[111].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeNodesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13296,8 +16805,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[80].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRevokePrivilegesRequest); i {
+		file_asld_kvs_proto_msgTypes[112].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*NamespaceInfo); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13308,8 +16817,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[81].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeRevokePrivilegesResponse); i {
+		file_asld_kvs_proto_msgTypes[113].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeNamespacesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13320,8 +16829,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[82].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeSetAllowlistRequest); i {
+		file_asld_kvs_proto_msgTypes[114].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeNamespacesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13332,8 +16841,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[83].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeSetAllowlistResponse); i {
+		file_asld_kvs_proto_msgTypes[115].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SetInfo); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13344,8 +16853,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[84].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeSetQuotasRequest); i {
+		file_asld_kvs_proto_msgTypes[116].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13356,8 +16865,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[85].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeSetQuotasResponse); i {
+		file_asld_kvs_proto_msgTypes[117].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeSetsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13368,8 +16877,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[86].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeScanRequest); i {
+		file_asld_kvs_proto_msgTypes[118].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*IndexInfo); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13380,8 +16889,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[87].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeStreamResponse); i {
+		file_asld_kvs_proto_msgTypes[119].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeIndexesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13392,8 +16901,8 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[88].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AerospikeQueryRequest); i {
+		file_asld_kvs_proto_msgTypes[120].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AerospikeIndexesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -13404,7 +16913,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[89].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[121].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AerospikeVersionRequest); i {
 			case 0:
 				return &v.state
@@ -13416,7 +16925,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[90].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[122].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AerospikeVersionResponse); i {
 			case 0:
 				return &v.state
@@ -13428,7 +16937,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[91].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[123].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*BackgroundExecutePolicy); i {
 			case 0:
 				return &v.state
@@ -13440,7 +16949,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[92].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[124].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*MultiPolicy); i {
 			case 0:
 				return &v.state
@@ -13452,7 +16961,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[93].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[125].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*ScanPolicy); i {
 			case 0:
 				return &v.state
@@ -13464,7 +16973,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[94].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[126].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*QueryPolicy); i {
 			case 0:
 				return &v.state
@@ -13476,7 +16985,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[95].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[127].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*UDFCall); i {
 			case 0:
 				return &v.state
@@ -13488,7 +16997,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[96].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[128].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*Statement); i {
 			case 0:
 				return &v.state
@@ -13500,7 +17009,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[97].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[129].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*QueryFilter); i {
 			case 0:
 				return &v.state
@@ -13512,7 +17021,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[98].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[130].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtMapPolicy); i {
 			case 0:
 				return &v.state
@@ -13524,7 +17033,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[99].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[131].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtMapOperation); i {
 			case 0:
 				return &v.state
@@ -13536,7 +17045,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[100].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[132].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtListPolicy); i {
 			case 0:
 				return &v.state
@@ -13548,7 +17057,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[101].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[133].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtListOperation); i {
 			case 0:
 				return &v.state
@@ -13560,7 +17069,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[102].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[134].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtHLLPolicy); i {
 			case 0:
 				return &v.state
@@ -13572,7 +17081,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[103].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[135].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtHLLOperation); i {
 			case 0:
 				return &v.state
@@ -13584,7 +17093,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[104].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[136].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtBitwisePolicy); i {
 			case 0:
 				return &v.state
@@ -13596,7 +17105,7 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
-		file_asld_kvs_proto_msgTypes[105].Exporter = func(v interface{}, i int) interface{} {
+		file_asld_kvs_proto_msgTypes[137].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CdtBitwiseOperation); i {
 			case 0:
 				return &v.state
@@ -13608,6 +17117,18 @@ func file_asld_kvs_proto_init() {
 				return nil
 			}
 		}
+		file_asld_kvs_proto_msgTypes[138].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ExpOperation); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
 	}
 	file_asld_kvs_proto_msgTypes[0].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[1].OneofWrappers = []interface{}{}
@@ -13639,51 +17160,64 @@ func file_asld_kvs_proto_init() {
 		(*Operation_List)(nil),
 		(*Operation_Hll)(nil),
 		(*Operation_Bitwise)(nil),
+		(*Operation_Exp)(nil),
 	}
 	file_asld_kvs_proto_msgTypes[24].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[32].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[34].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[38].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[40].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[43].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[45].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[47].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[49].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[44].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[46].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[48].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[50].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[51].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[53].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[55].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[57].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[58].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[59].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[61].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[63].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[65].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[67].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[69].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[70].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[71].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[72].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[73].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[75].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[77].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[78].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[79].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[80].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[81].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[83].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[85].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[86].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[87].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[88].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[89].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[91].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[93].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[94].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[95].OneofWrappers = []interface{}{}
 	file_asld_kvs_proto_msgTypes[96].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[99].OneofWrappers = []interface{}{}
-	file_asld_kvs_proto_msgTypes[101].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[100].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[102].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[106].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[107].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[108].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[111].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[114].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[117].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[120].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[123].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[128].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[131].OneofWrappers = []interface{}{}
+	file_asld_kvs_proto_msgTypes[133].OneofWrappers = []interface{}{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_asld_kvs_proto_rawDesc,
-			NumEnums:      32,
-			NumMessages:   107,
+			NumEnums:      39,
+			NumMessages:   142,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -19,39 +19,53 @@ import (
 const _ = grpc.SupportPackageIsVersion7
 
 const (
-	KVS_Version_FullMethodName          = "/com.aerospike.daemon.KVS/Version"
-	KVS_Put_FullMethodName              = "/com.aerospike.daemon.KVS/Put"
-	KVS_Add_FullMethodName              = "/com.aerospike.daemon.KVS/Add"
-	KVS_Append_FullMethodName           = "/com.aerospike.daemon.KVS/Append"
-	KVS_Prepend_FullMethodName          = "/com.aerospike.daemon.KVS/Prepend"
-	KVS_Get_FullMethodName              = "/com.aerospike.daemon.KVS/Get"
-	KVS_GetHeader_FullMethodName        = "/com.aerospike.daemon.KVS/GetHeader"
-	KVS_Exists_FullMethodName           = "/com.aerospike.daemon.KVS/Exists"
-	KVS_Delete_FullMethodName           = "/com.aerospike.daemon.KVS/Delete"
-	KVS_Touch_FullMethodName            = "/com.aerospike.daemon.KVS/Touch"
-	KVS_BatchOperate_FullMethodName     = "/com.aerospike.daemon.KVS/BatchOperate"
-	KVS_CreateIndex_FullMethodName      = "/com.aerospike.daemon.KVS/CreateIndex"
-	KVS_DropIndex_FullMethodName        = "/com.aerospike.daemon.KVS/DropIndex"
-	KVS_Truncate_FullMethodName         = "/com.aerospike.daemon.KVS/Truncate"
-	KVS_RegisterUDF_FullMethodName      = "/com.aerospike.daemon.KVS/RegisterUDF"
-	KVS_DropUDF_FullMethodName          = "/com.aerospike.daemon.KVS/DropUDF"
-	KVS_ListUDF_FullMethodName          = "/com.aerospike.daemon.KVS/ListUDF"
-	KVS_UDFExecute_FullMethodName       = "/com.aerospike.daemon.KVS/UDFExecute"
-	KVS_CreateUser_FullMethodName       = "/com.aerospike.daemon.KVS/CreateUser"
-	KVS_DropUser_FullMethodName         = "/com.aerospike.daemon.KVS/DropUser"
-	KVS_ChangePassword_FullMethodName   = "/com.aerospike.daemon.KVS/ChangePassword"
-	KVS_GrantRoles_FullMethodName       = "/com.aerospike.daemon.KVS/GrantRoles"
-	KVS_RevokeRoles_FullMethodName      = "/com.aerospike.daemon.KVS/RevokeRoles"
-	KVS_QueryUsers_FullMethodName       = "/com.aerospike.daemon.KVS/QueryUsers"
-	KVS_QueryRoles_FullMethodName       = "/com.aerospike.daemon.KVS/QueryRoles"
-	KVS_CreateRole_FullMethodName       = "/com.aerospike.daemon.KVS/CreateRole"
-	KVS_DropRole_FullMethodName         = "/com.aerospike.daemon.KVS/DropRole"
-	KVS_GrantPrivileges_FullMethodName  = "/com.aerospike.daemon.KVS/GrantPrivileges"
-	KVS_RevokePrivileges_FullMethodName = "/com.aerospike.daemon.KVS/RevokePrivileges"
-	KVS_SetAllowlist_FullMethodName     = "/com.aerospike.daemon.KVS/SetAllowlist"
-	KVS_SetQuotas_FullMethodName        = "/com.aerospike.daemon.KVS/SetQuotas"
-	KVS_Scan_FullMethodName             = "/com.aerospike.daemon.KVS/Scan"
-	KVS_Query_FullMethodName            = "/com.aerospike.daemon.KVS/Query"
+	KVS_Version_FullMethodName            = "/com.aerospike.daemon.KVS/Version"
+	KVS_Put_FullMethodName                = "/com.aerospike.daemon.KVS/Put"
+	KVS_Add_FullMethodName                = "/com.aerospike.daemon.KVS/Add"
+	KVS_Append_FullMethodName             = "/com.aerospike.daemon.KVS/Append"
+	KVS_Prepend_FullMethodName            = "/com.aerospike.daemon.KVS/Prepend"
+	KVS_Get_FullMethodName                = "/com.aerospike.daemon.KVS/Get"
+	KVS_GetHeader_FullMethodName          = "/com.aerospike.daemon.KVS/GetHeader"
+	KVS_Exists_FullMethodName             = "/com.aerospike.daemon.KVS/Exists"
+	KVS_Delete_FullMethodName             = "/com.aerospike.daemon.KVS/Delete"
+	KVS_Touch_FullMethodName              = "/com.aerospike.daemon.KVS/Touch"
+	KVS_Operate_FullMethodName            = "/com.aerospike.daemon.KVS/Operate"
+	KVS_BatchOperate_FullMethodName       = "/com.aerospike.daemon.KVS/BatchOperate"
+	KVS_CreateIndex_FullMethodName        = "/com.aerospike.daemon.KVS/CreateIndex"
+	KVS_DropIndex_FullMethodName          = "/com.aerospike.daemon.KVS/DropIndex"
+	KVS_Truncate_FullMethodName           = "/com.aerospike.daemon.KVS/Truncate"
+	KVS_RegisterUDF_FullMethodName        = "/com.aerospike.daemon.KVS/RegisterUDF"
+	KVS_DropUDF_FullMethodName            = "/com.aerospike.daemon.KVS/DropUDF"
+	KVS_ListUDF_FullMethodName            = "/com.aerospike.daemon.KVS/ListUDF"
+	KVS_UDFExecute_FullMethodName         = "/com.aerospike.daemon.KVS/UDFExecute"
+	KVS_CreateUser_FullMethodName         = "/com.aerospike.daemon.KVS/CreateUser"
+	KVS_DropUser_FullMethodName           = "/com.aerospike.daemon.KVS/DropUser"
+	KVS_ChangePassword_FullMethodName     = "/com.aerospike.daemon.KVS/ChangePassword"
+	KVS_GrantRoles_FullMethodName         = "/com.aerospike.daemon.KVS/GrantRoles"
+	KVS_RevokeRoles_FullMethodName        = "/com.aerospike.daemon.KVS/RevokeRoles"
+	KVS_QueryUsers_FullMethodName         = "/com.aerospike.daemon.KVS/QueryUsers"
+	KVS_QueryRoles_FullMethodName         = "/com.aerospike.daemon.KVS/QueryRoles"
+	KVS_CreateRole_FullMethodName         = "/com.aerospike.daemon.KVS/CreateRole"
+	KVS_DropRole_FullMethodName           = "/com.aerospike.daemon.KVS/DropRole"
+	KVS_GrantPrivileges_FullMethodName    = "/com.aerospike.daemon.KVS/GrantPrivileges"
+	KVS_RevokePrivileges_FullMethodName   = "/com.aerospike.daemon.KVS/RevokePrivileges"
+	KVS_SetAllowlist_FullMethodName       = "/com.aerospike.daemon.KVS/SetAllowlist"
+	KVS_SetQuotas_FullMethodName          = "/com.aerospike.daemon.KVS/SetQuotas"
+	KVS_Scan_FullMethodName               = "/com.aerospike.daemon.KVS/Scan"
+	KVS_Query_FullMethodName              = "/com.aerospike.daemon.KVS/Query"
+	KVS_Info_FullMethodName               = "/com.aerospike.daemon.KVS/Info"
+	KVS_Nodes_FullMethodName              = "/com.aerospike.daemon.KVS/Nodes"
+	KVS_Namespaces_FullMethodName         = "/com.aerospike.daemon.KVS/Namespaces"
+	KVS_Sets_FullMethodName               = "/com.aerospike.daemon.KVS/Sets"
+	KVS_Indexes_FullMethodName            = "/com.aerospike.daemon.KVS/Indexes"
+	KVS_QueryAggregate_FullMethodName     = "/com.aerospike.daemon.KVS/QueryAggregate"
+	KVS_QueryExecute_FullMethodName       = "/com.aerospike.daemon.KVS/QueryExecute"
+	KVS_IndexTaskStatus_FullMethodName    = "/com.aerospike.daemon.KVS/IndexTaskStatus"
+	KVS_UDFTaskStatus_FullMethodName      = "/com.aerospike.daemon.KVS/UDFTaskStatus"
+	KVS_ExecuteTaskStatus_FullMethodName  = "/com.aerospike.daemon.KVS/ExecuteTaskStatus"
+	KVS_Commit_FullMethodName             = "/com.aerospike.daemon.KVS/Commit"
+	KVS_Abort_FullMethodName              = "/com.aerospike.daemon.KVS/Abort"
+	KVS_ExpressionToBase64_FullMethodName = "/com.aerospike.daemon.KVS/ExpressionToBase64"
 )
 
 // KVSClient is the client API for KVS service.
@@ -77,6 +91,8 @@ type KVSClient interface {
 	Delete(ctx context.Context, in *AerospikeDeleteRequest, opts ...grpc.CallOption) (*AerospikeDeleteResponse, error)
 	// Reset single record's time to expiration using the write policy's expiration.
 	Touch(ctx context.Context, in *AerospikeTouchRequest, opts ...grpc.CallOption) (*Error, error)
+	// Perform multiple read/write operations on a single key in one batch call.
+	Operate(ctx context.Context, in *AerospikeOperateRequest, opts ...grpc.CallOption) (*AerospikeSingleResponse, error)
 	// Process batch requests.
 	BatchOperate(ctx context.Context, in *AerospikeBatchOperateRequest, opts ...grpc.CallOption) (*AerospikeBatchOperateResponse, error)
 	// Process batch requests.
@@ -137,6 +153,33 @@ type KVSClient interface {
 	SetQuotas(ctx context.Context, in *AerospikeSetQuotasRequest, opts ...grpc.CallOption) (*AerospikeSetQuotasResponse, error)
 	Scan(ctx context.Context, in *AerospikeScanRequest, opts ...grpc.CallOption) (KVS_ScanClient, error)
 	Query(ctx context.Context, in *AerospikeQueryRequest, opts ...grpc.CallOption) (KVS_QueryClient, error)
+	// Send info commands to a node in the cluster.
+	Info(ctx context.Context, in *AerospikeInfoRequest, opts ...grpc.CallOption) (*AerospikeInfoResponse, error)
+	// List the nodes in the cluster.
+	Nodes(ctx context.Context, in *AerospikeNodesRequest, opts ...grpc.CallOption) (*AerospikeNodesResponse, error)
+	// List the namespaces in the cluster and their statistics.
+	Namespaces(ctx context.Context, in *AerospikeNamespacesRequest, opts ...grpc.CallOption) (*AerospikeNamespacesResponse, error)
+	// List the sets in a namespace and their statistics.
+	Sets(ctx context.Context, in *AerospikeSetsRequest, opts ...grpc.CallOption) (*AerospikeSetsResponse, error)
+	// List the secondary indexes in a namespace.
+	Indexes(ctx context.Context, in *AerospikeIndexesRequest, opts ...grpc.CallOption) (*AerospikeIndexesResponse, error)
+	// Run an aggregation (stream UDF) query and stream back the reduced values.
+	QueryAggregate(ctx context.Context, in *AerospikeQueryAggregateRequest, opts ...grpc.CallOption) (KVS_QueryAggregateClient, error)
+	// Apply a UDF or a list of write operations to all records matched by the statement
+	// on the server, in the background.
+	QueryExecute(ctx context.Context, in *AerospikeQueryExecuteRequest, opts ...grpc.CallOption) (*AerospikeQueryExecuteResponse, error)
+	// Check the status of a secondary index creation or removal on all nodes.
+	IndexTaskStatus(ctx context.Context, in *AerospikeIndexTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeTaskProgressResponse, error)
+	// Check the status of a UDF package registration or removal on all nodes.
+	UDFTaskStatus(ctx context.Context, in *AerospikeUDFTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeTaskProgressResponse, error)
+	// Check the status of a background query execution.
+	ExecuteTaskStatus(ctx context.Context, in *AerospikeExecuteTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeExecuteTaskStatusResponse, error)
+	// Commit a multi-record transaction and forget it on the connection manager.
+	Commit(ctx context.Context, in *AerospikeCommitRequest, opts ...grpc.CallOption) (*AerospikeCommitResponse, error)
+	// Abort a multi-record transaction and forget it on the connection manager.
+	Abort(ctx context.Context, in *AerospikeAbortRequest, opts ...grpc.CallOption) (*AerospikeAbortResponse, error)
+	// Encode an expression in the server wire format, as base64.
+	ExpressionToBase64(ctx context.Context, in *AerospikeExpressionToBase64Request, opts ...grpc.CallOption) (*AerospikeExpressionToBase64Response, error)
 }
 
 type kVSClient struct {
@@ -237,6 +280,15 @@ func (c *kVSClient) Touch(ctx context.Context, in *AerospikeTouchRequest, opts .
 	return out, nil
 }
 
+func (c *kVSClient) Operate(ctx context.Context, in *AerospikeOperateRequest, opts ...grpc.CallOption) (*AerospikeSingleResponse, error) {
+	out := new(AerospikeSingleResponse)
+	err := c.cc.Invoke(ctx, KVS_Operate_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *kVSClient) BatchOperate(ctx context.Context, in *AerospikeBatchOperateRequest, opts ...grpc.CallOption) (*AerospikeBatchOperateResponse, error) {
 	out := new(AerospikeBatchOperateResponse)
 	err := c.cc.Invoke(ctx, KVS_BatchOperate_FullMethodName, in, out, opts...)
@@ -490,6 +542,146 @@ func (x *kVSQueryClient) Recv() (*AerospikeStreamResponse, error) {
 	return m, nil
 }
 
+func (c *kVSClient) Info(ctx context.Context, in *AerospikeInfoRequest, opts ...grpc.CallOption) (*AerospikeInfoResponse, error) {
+	out := new(AerospikeInfoResponse)
+	err := c.cc.Invoke(ctx, KVS_Info_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Nodes(ctx context.Context, in *AerospikeNodesRequest, opts ...grpc.CallOption) (*AerospikeNodesResponse, error) {
+	out := new(AerospikeNodesResponse)
+	err := c.cc.Invoke(ctx, KVS_Nodes_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Namespaces(ctx context.Context, in *AerospikeNamespacesRequest, opts ...grpc.CallOption) (*AerospikeNamespacesResponse, error) {
+	out := new(AerospikeNamespacesResponse)
+	err := c.cc.Invoke(ctx, KVS_Namespaces_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Sets(ctx context.Context, in *AerospikeSetsRequest, opts ...grpc.CallOption) (*AerospikeSetsResponse, error) {
+	out := new(AerospikeSetsResponse)
+	err := c.cc.Invoke(ctx, KVS_Sets_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Indexes(ctx context.Context, in *AerospikeIndexesRequest, opts ...grpc.CallOption) (*AerospikeIndexesResponse, error) {
+	out := new(AerospikeIndexesResponse)
+	err := c.cc.Invoke(ctx, KVS_Indexes_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) QueryAggregate(ctx context.Context, in *AerospikeQueryAggregateRequest, opts ...grpc.CallOption) (KVS_QueryAggregateClient, error) {
+	stream, err := c.cc.NewStream(ctx, &KVS_ServiceDesc.Streams[2], KVS_QueryAggregate_FullMethodName, opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &kVSQueryAggregateClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type KVS_QueryAggregateClient interface {
+	Recv() (*AerospikeAggregateResponse, error)
+	grpc.ClientStream
+}
+
+type kVSQueryAggregateClient struct {
+	grpc.ClientStream
+}
+
+func (x *kVSQueryAggregateClient) Recv() (*AerospikeAggregateResponse, error) {
+	m := new(AerospikeAggregateResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *kVSClient) QueryExecute(ctx context.Context, in *AerospikeQueryExecuteRequest, opts ...grpc.CallOption) (*AerospikeQueryExecuteResponse, error) {
+	out := new(AerospikeQueryExecuteResponse)
+	err := c.cc.Invoke(ctx, KVS_QueryExecute_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) IndexTaskStatus(ctx context.Context, in *AerospikeIndexTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeTaskProgressResponse, error) {
+	out := new(AerospikeTaskProgressResponse)
+	err := c.cc.Invoke(ctx, KVS_IndexTaskStatus_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) UDFTaskStatus(ctx context.Context, in *AerospikeUDFTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeTaskProgressResponse, error) {
+	out := new(AerospikeTaskProgressResponse)
+	err := c.cc.Invoke(ctx, KVS_UDFTaskStatus_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) ExecuteTaskStatus(ctx context.Context, in *AerospikeExecuteTaskStatusRequest, opts ...grpc.CallOption) (*AerospikeExecuteTaskStatusResponse, error) {
+	out := new(AerospikeExecuteTaskStatusResponse)
+	err := c.cc.Invoke(ctx, KVS_ExecuteTaskStatus_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Commit(ctx context.Context, in *AerospikeCommitRequest, opts ...grpc.CallOption) (*AerospikeCommitResponse, error) {
+	out := new(AerospikeCommitResponse)
+	err := c.cc.Invoke(ctx, KVS_Commit_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) Abort(ctx context.Context, in *AerospikeAbortRequest, opts ...grpc.CallOption) (*AerospikeAbortResponse, error) {
+	out := new(AerospikeAbortResponse)
+	err := c.cc.Invoke(ctx, KVS_Abort_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *kVSClient) ExpressionToBase64(ctx context.Context, in *AerospikeExpressionToBase64Request, opts ...grpc.CallOption) (*AerospikeExpressionToBase64Response, error) {
+	out := new(AerospikeExpressionToBase64Response)
+	err := c.cc.Invoke(ctx, KVS_ExpressionToBase64_FullMethodName, in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // KVSServer is the server API for KVS service.
 // All implementations must embed UnimplementedKVSServer
 // for forward compatibility
@@ -513,6 +705,8 @@ type KVSServer interface {
 	Delete(context.Context, *AerospikeDeleteRequest) (*AerospikeDeleteResponse, error)
 	// Reset single record's time to expiration using the write policy's expiration.
 	Touch(context.Context, *AerospikeTouchRequest) (*Error, error)
+	// Perform multiple read/write operations on a single key in one batch call.
+	Operate(context.Context, *AerospikeOperateRequest) (*AerospikeSingleResponse, error)
 	// Process batch requests.
 	BatchOperate(context.Context, *AerospikeBatchOperateRequest) (*AerospikeBatchOperateResponse, error)
 	// Process batch requests.
@@ -573,6 +767,33 @@ type KVSServer interface {
 	SetQuotas(context.Context, *AerospikeSetQuotasRequest) (*AerospikeSetQuotasResponse, error)
 	Scan(*AerospikeScanRequest, KVS_ScanServer) error
 	Query(*AerospikeQueryRequest, KVS_QueryServer) error
+	// Send info commands to a node in the cluster.
+	Info(context.Context, *AerospikeInfoRequest) (*AerospikeInfoResponse, error)
+	// List the nodes in the cluster.
+	Nodes(context.Context, *AerospikeNodesRequest) (*AerospikeNodesResponse, error)
+	// List the namespaces in the cluster and their statistics.
+	Namespaces(context.Context, *AerospikeNamespacesRequest) (*AerospikeNamespacesResponse, error)
+	// List the sets in a namespace and their statistics.
+	Sets(context.Context, *AerospikeSetsRequest) (*AerospikeSetsResponse, error)
+	// List the secondary indexes in a namespace.
+	Indexes(context.Context, *AerospikeIndexesRequest) (*AerospikeIndexesResponse, error)
+	// Run an aggregation (stream UDF) query and stream back the reduced values.
+	QueryAggregate(*AerospikeQueryAggregateRequest, KVS_QueryAggregateServer) error
+	// Apply a UDF or a list of write operations to all records matched by the statement
+	// on the server, in the background.
+	QueryExecute(context.Context, *AerospikeQueryExecuteRequest) (*AerospikeQueryExecuteResponse, error)
+	// Check the status of a secondary index creation or removal on all nodes.
+	IndexTaskStatus(context.Context, *AerospikeIndexTaskStatusRequest) (*AerospikeTaskProgressResponse, error)
+	// Check the status of a UDF package registration or removal on all nodes.
+	UDFTaskStatus(context.Context, *AerospikeUDFTaskStatusRequest) (*AerospikeTaskProgressResponse, error)
+	// Check the status of a background query execution.
+	ExecuteTaskStatus(context.Context, *AerospikeExecuteTaskStatusRequest) (*AerospikeExecuteTaskStatusResponse, error)
+	// Commit a multi-record transaction and forget it on the connection manager.
+	Commit(context.Context, *AerospikeCommitRequest) (*AerospikeCommitResponse, error)
+	// Abort a multi-record transaction and forget it on the connection manager.
+	Abort(context.Context, *AerospikeAbortRequest) (*AerospikeAbortResponse, error)
+	// Encode an expression in the server wire format, as base64.
+	ExpressionToBase64(context.Context, *AerospikeExpressionToBase64Request) (*AerospikeExpressionToBase64Response, error)
 	mustEmbedUnimplementedKVSServer()
 }
 
@@ -610,6 +831,9 @@ func (UnimplementedKVSServer) Delete(context.Context, *AerospikeDeleteRequest) (
 func (UnimplementedKVSServer) Touch(context.Context, *AerospikeTouchRequest) (*Error, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Touch not implemented")
 }
+func (UnimplementedKVSServer) Operate(context.Context, *AerospikeOperateRequest) (*AerospikeSingleResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Operate not implemented")
+}
 func (UnimplementedKVSServer) BatchOperate(context.Context, *AerospikeBatchOperateRequest) (*AerospikeBatchOperateResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method BatchOperate not implemented")
 }
@@ -679,6 +903,45 @@ func (UnimplementedKVSServer) Scan(*AerospikeScanRequest, KVS_ScanServer) error
 func (UnimplementedKVSServer) Query(*AerospikeQueryRequest, KVS_QueryServer) error {
 	return status.Errorf(codes.Unimplemented, "method Query not implemented")
 }
+func (UnimplementedKVSServer) Info(context.Context, *AerospikeInfoRequest) (*AerospikeInfoResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Info not implemented")
+}
+func (UnimplementedKVSServer) Nodes(context.Context, *AerospikeNodesRequest) (*AerospikeNodesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Nodes not implemented")
+}
+func (UnimplementedKVSServer) Namespaces(context.Context, *AerospikeNamespacesRequest) (*AerospikeNamespacesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Namespaces not implemented")
+}
+func (UnimplementedKVSServer) Sets(context.Context, *AerospikeSetsRequest) (*AerospikeSetsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Sets not implemented")
+}
+func (UnimplementedKVSServer) Indexes(context.Context, *AerospikeIndexesRequest) (*AerospikeIndexesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Indexes not implemented")
+}
+func (UnimplementedKVSServer) QueryAggregate(*AerospikeQueryAggregateRequest, KVS_QueryAggregateServer) error {
+	return status.Errorf(codes.Unimplemented, "method QueryAggregate not implemented")
+}
+func (UnimplementedKVSServer) QueryExecute(context.Context, *AerospikeQueryExecuteRequest) (*AerospikeQueryExecuteResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method QueryExecute not implemented")
+}
+func (UnimplementedKVSServer) IndexTaskStatus(context.Context, *AerospikeIndexTaskStatusRequest) (*AerospikeTaskProgressResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method IndexTaskStatus not implemented")
+}
+func (UnimplementedKVSServer) UDFTaskStatus(context.Context, *AerospikeUDFTaskStatusRequest) (*AerospikeTaskProgressResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UDFTaskStatus not implemented")
+}
+func (UnimplementedKVSServer) ExecuteTaskStatus(context.Context, *AerospikeExecuteTaskStatusRequest) (*AerospikeExecuteTaskStatusResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ExecuteTaskStatus not implemented")
+}
+func (UnimplementedKVSServer) Commit(context.Context, *AerospikeCommitRequest) (*AerospikeCommitResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Commit not implemented")
+}
+func (UnimplementedKVSServer) Abort(context.Context, *AerospikeAbortRequest) (*AerospikeAbortResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Abort not implemented")
+}
+func (UnimplementedKVSServer) ExpressionToBase64(context.Context, *AerospikeExpressionToBase64Request) (*AerospikeExpressionToBase64Response, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ExpressionToBase64 not implemented")
+}
 func (UnimplementedKVSServer) mustEmbedUnimplementedKVSServer() {}
 
 // UnsafeKVSServer may be embedded to opt out of forward compatibility for this service.
@@ -872,6 +1135,24 @@ func _KVS_Touch_Handler(srv interface{}, ctx context.Context, dec func(interface
 	return interceptor(ctx, in, info, handler)
 }
 
+func _KVS_Operate_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeOperateRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Operate(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Operate_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Operate(ctx, req.(*AerospikeOperateRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _KVS_BatchOperate_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(AerospikeBatchOperateRequest)
 	if err := dec(in); err != nil {
@@ -1292,6 +1573,243 @@ func (x *kVSQueryServer) Send(m *AerospikeStreamResponse) error {
 	return x.ServerStream.SendMsg(m)
 }
 
+func _KVS_Info_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeInfoRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Info(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Info_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Info(ctx, req.(*AerospikeInfoRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Nodes_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeNodesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Nodes(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Nodes_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Nodes(ctx, req.(*AerospikeNodesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Namespaces_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeNamespacesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Namespaces(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Namespaces_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Namespaces(ctx, req.(*AerospikeNamespacesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Sets_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeSetsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Sets(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Sets_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Sets(ctx, req.(*AerospikeSetsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Indexes_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeIndexesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Indexes(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Indexes_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Indexes(ctx, req.(*AerospikeIndexesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_QueryAggregate_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(AerospikeQueryAggregateRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(KVSServer).QueryAggregate(m, &kVSQueryAggregateServer{stream})
+}
+
+type KVS_QueryAggregateServer interface {
+	Send(*AerospikeAggregateResponse) error
+	grpc.ServerStream
+}
+
+type kVSQueryAggregateServer struct {
+	grpc.ServerStream
+}
+
+func (x *kVSQueryAggregateServer) Send(m *AerospikeAggregateResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _KVS_QueryExecute_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeQueryExecuteRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).QueryExecute(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_QueryExecute_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).QueryExecute(ctx, req.(*AerospikeQueryExecuteRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_IndexTaskStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeIndexTaskStatusRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).IndexTaskStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_IndexTaskStatus_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).IndexTaskStatus(ctx, req.(*AerospikeIndexTaskStatusRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_UDFTaskStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeUDFTaskStatusRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).UDFTaskStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_UDFTaskStatus_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).UDFTaskStatus(ctx, req.(*AerospikeUDFTaskStatusRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_ExecuteTaskStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeExecuteTaskStatusRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).ExecuteTaskStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_ExecuteTaskStatus_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).ExecuteTaskStatus(ctx, req.(*AerospikeExecuteTaskStatusRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Commit_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeCommitRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Commit(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Commit_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Commit(ctx, req.(*AerospikeCommitRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_Abort_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeAbortRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).Abort(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_Abort_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).Abort(ctx, req.(*AerospikeAbortRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _KVS_ExpressionToBase64_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AerospikeExpressionToBase64Request)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(KVSServer).ExpressionToBase64(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: KVS_ExpressionToBase64_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(KVSServer).ExpressionToBase64(ctx, req.(*AerospikeExpressionToBase64Request))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // KVS_ServiceDesc is the grpc.ServiceDesc for KVS service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -1339,6 +1857,10 @@ var KVS_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Touch",
 			Handler:    _KVS_Touch_Handler,
 		},
+		{
+			MethodName: "Operate",
+			Handler:    _KVS_Operate_Handler,
+		},
 		{
 			MethodName: "BatchOperate",
 			Handler:    _KVS_BatchOperate_Handler,
@@ -1423,6 +1945,54 @@ var KVS_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "SetQuotas",
 			Handler:    _KVS_SetQuotas_Handler,
 		},
+		{
+			MethodName: "Info",
+			Handler:    _KVS_Info_Handler,
+		},
+		{
+			MethodName: "Nodes",
+			Handler:    _KVS_Nodes_Handler,
+		},
+		{
+			MethodName: "Namespaces",
+			Handler:    _KVS_Namespaces_Handler,
+		},
+		{
+			MethodName: "Sets",
+			Handler:    _KVS_Sets_Handler,
+		},
+		{
+			MethodName: "Indexes",
+			Handler:    _KVS_Indexes_Handler,
+		},
+		{
+			MethodName: "QueryExecute",
+			Handler:    _KVS_QueryExecute_Handler,
+		},
+		{
+			MethodName: "IndexTaskStatus",
+			Handler:    _KVS_IndexTaskStatus_Handler,
+		},
+		{
+			MethodName: "UDFTaskStatus",
+			Handler:    _KVS_UDFTaskStatus_Handler,
+		},
+		{
+			MethodName: "ExecuteTaskStatus",
+			Handler:    _KVS_ExecuteTaskStatus_Handler,
+		},
+		{
+			MethodName: "Commit",
+			Handler:    _KVS_Commit_Handler,
+		},
+		{
+			MethodName: "Abort",
+			Handler:    _KVS_Abort_Handler,
+		},
+		{
+			MethodName: "ExpressionToBase64",
+			Handler:    _KVS_ExpressionToBase64_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
@@ -1435,6 +2005,11 @@ var KVS_ServiceDesc = grpc.ServiceDesc{
 			Handler:       _KVS_Query_Handler,
 			ServerStreams: true,
 		},
+		{
+			StreamName:    "QueryAggregate",
+			Handler:       _KVS_QueryAggregate_Handler,
+			ServerStreams: true,
+		},
 	},
 	Metadata: "asld_kvs.proto",
 }
@@ -88,18 +88,22 @@ namespace Aerospike {
         /**
          * Connects to the Aerospike database using the provided socket address.
          *
-         * If a persisted client object is found for the given socket address, it is returned.
-         * Otherwise, a new client object is created, persisted, and returned.
+         * If a persisted client object is found for the given socket address and deadlines, it is
+         * returned. Otherwise, a new client object is created, persisted, and returned.
          *
          * # Arguments
          *
          * * `socket` - A string representing the socket address of the Aerospike database.
+         * * `timeout_margin` - The time in milliseconds added to the timeout of a command for the
+         *   deadline of its request to the connection manager. Default: 1000ms
+         * * `default_timeout` - The timeout in milliseconds used for the deadline of commands
+         *   without a total timeout of their own. Default: 30000ms
          *
          * # Returns
          *
          * * `Err("Error connecting to the database".into())` - If an error occurs during connection.
          */
-        public static function connect(string $socket): mixed {}
+        public static function connect(string $socket, ?int $timeout_margin = null, ?int $default_timeout = null): mixed {}
 
         /**
          * Retrieves the socket address associated with this client.
@@ -110,6 +114,26 @@ namespace Aerospike {
          */
         public function socket(): string {}
 
+        /**
+         * TimeoutMargin is added to the total timeout of a command's policy to compute the
+         * deadline of its request to the connection manager, leaving the connection manager the
+         * time to report the timeout itself. If the connection manager does not respond before
+         * the deadline, the command throws an `AerospikeException` with the
+         * `CONNECTION_MANAGER_TIMEOUT` result code. Set with `Client::connect`.
+         *
+         * Default: 1000ms
+         */
+        public function getTimeoutMargin(): int {}
+
+        /**
+         * DefaultTimeout is used in place of the total timeout for the deadline of commands which
+         * have no time limit of their own. For scans and queries, it limits the wait for each
+         * record. Set with `Client::connect`.
+         *
+         * Default: 30000ms
+         */
+        public function getDefaultTimeout(): int {}
+
         /**
          * Write record bin(s). The policy specifies the transaction timeout, record expiration and
          * how the transaction is handled when the record already exists.
@@ -163,6 +187,19 @@ namespace Aerospike {
          */
         public function touch(\Aerospike\WritePolicy $policy, \Aerospike\Key $key): mixed {}
 
+        /**
+         * Perform multiple read/write operations on a single key in one round trip. The operations
+         * are executed atomically and in the order given. Operations can be any mix of `Operation`,
+         * `ListOp`, `MapOp`, `HllOp` and `BitwiseOp` results.
+         *
+         * When the policy's `respondPerEachOp` is set, every operation returns a result, so when
+         * the same bin is used in more than one operation, the bin value will be a list of the
+         * results in the order of the operations.
+         *
+         * Returns null if the record does not exist and the operations do not create it.
+         */
+        public function operate(\Aerospike\WritePolicy $policy, \Aerospike\Key $key, array $ops): ?\Aerospike\Record {}
+
         /**
          * Determine if a record key exists. The policy can be used to specify timeouts.
          */
@@ -174,16 +211,127 @@ namespace Aerospike {
          * The returned records are located in the same list.
          *
          * BatchRecord can be *BatchRead, *BatchWrite, *BatchDelete or *BatchUDF.
+         * The commands are checked before the batch is sent, and an `AerospikeException` with the
+         * `PARAMETER_ERROR` result code is thrown if any of them is invalid.
          *
          * Requires server version 6.0+
          */
         public function batch(\Aerospike\BatchPolicy $policy, array $cmds): array {}
 
+        /**
+         * Same as `batch`, but returns a `BatchResults` with the records and a summary of the keys
+         * which failed.
+         *
+         * In strict mode, a `BatchException` carrying the results is thrown if any key failed.
+         * Records which were not found do not count as failures.
+         *
+         * Requires server version 6.0+
+         */
+        public function batchExecute(\Aerospike\BatchPolicy $policy, array $cmds, ?bool $strict): \Aerospike\BatchResults {}
+
+        /**
+         * Read multiple records for the specified keys in one batch call.
+         * The returned records are in the same order as the keys. If a key is not found, its
+         * record is null. If bins is null or empty, all the bins are read.
+         *
+         * If any key failed for a reason other than not being found, throws a `BatchException`
+         * carrying the result code and in doubt flag of every key.
+         */
+        public function batchGet(\Aerospike\BatchPolicy $policy, array $keys, ?array $bins): array {}
+
+        /**
+         * Read multiple record headers (generation and expiration) for the specified keys in one
+         * batch call. The returned records are in the same order as the keys. If a key is not found,
+         * its record is null.
+         *
+         * If any key failed for a reason other than not being found, throws a `BatchException`
+         * carrying the result code and in doubt flag of every key.
+         */
+        public function batchGetHeader(\Aerospike\BatchPolicy $policy, array $keys): array {}
+
+        /**
+         * Check if multiple record keys exist in one batch call.
+         * The returned flags are in the same order as the keys.
+         *
+         * If any key failed for a reason other than not being found, throws a `BatchException`
+         * carrying the result code and in doubt flag of every key.
+         */
+        public function batchExists(\Aerospike\BatchPolicy $policy, array $keys): array {}
+
+        /**
+         * Delete multiple records for the specified keys in one batch call.
+         * The returned records are in the same order as the keys, and carry the result code of
+         * each delete. The delete policy is optional.
+         *
+         * Requires server version 6.0+
+         */
+        public function batchDelete(\Aerospike\BatchPolicy $policy, array $keys, ?\Aerospike\BatchDeletePolicy $delete_policy): array {}
+
+        /**
+         * Perform read/write operations on multiple keys in one batch call.
+         * The returned records are in the same order as the keys, and carry the result code of
+         * each key and the results of the operations in `BatchRecord::$results`. The write policy
+         * is optional.
+         *
+         * Requires server version 6.0+
+         */
+        public function batchOperate(\Aerospike\BatchPolicy $policy, array $keys, array $ops, ?\Aerospike\BatchWritePolicy $write_policy): array {}
+
+        /**
+         * Commits the multi-record transaction. If the reads of the transaction cannot be verified,
+         * the transaction is aborted and an `AerospikeException` is thrown. The state of `txn` is
+         * updated either way. Committing a transaction that was never used, or that has already been
+         * committed, aborted or has expired, throws with the `PARAMETER_ERROR` result code.
+         */
+        public function commit(\Aerospike\Txn $txn): \Aerospike\CommitStatus {}
+
+        /**
+         * Aborts the multi-record transaction and rolls back its writes. The state of `txn` is
+         * updated either way. Aborting an unknown transaction throws like `commit()` does.
+         */
+        public function abort(\Aerospike\Txn $txn): \Aerospike\AbortStatus {}
+
+        /**
+         * Encodes the expression in the server wire format, as base64, to cache it or keep it in
+         * configuration files, and to share it with the other Aerospike clients. Restore it with
+         * `Expression::fromBase64()`.
+         * The encoding is done by the connection manager with the Go client's expression packer, so
+         * it always matches the wire format of the commands.
+         */
+        public function expressionToBase64(\Aerospike\Expression $expression): string {}
+
         /**
          * Removes all records in the specified namespace/set efficiently.
          */
         public function truncate(\Aerospike\InfoPolicy $policy, string $namespace, string $set_name, ?int $before_nanos): mixed {}
 
+        /**
+         * Sends the info commands to a node in the cluster and returns the responses keyed by the
+         * command. If `node` is not set, a random node will be used. Use `Client::nodes()` to get the
+         * names of the nodes in the cluster.
+         */
+        public function info(\Aerospike\InfoPolicy $policy, array $commands, ?string $node): array {}
+
+        /**
+         * Returns the nodes in the cluster.
+         */
+        public function nodes(): array {}
+
+        /**
+         * Returns the namespaces in the cluster with their configuration and statistics.
+         */
+        public function namespaces(\Aerospike\InfoPolicy $policy): array {}
+
+        /**
+         * Returns the sets in the namespace with their statistics.
+         */
+        public function sets(\Aerospike\InfoPolicy $policy, string $namespace): array {}
+
+        /**
+         * Returns the secondary indexes in the namespace.
+         */
+        public function indexes(\Aerospike\InfoPolicy $policy, string $namespace): array {}
+
         /**
          * Read all records in the specified namespace and set and return a record iterator. The scan
          * executor puts records on a queue in separate threads. The calling thread concurrently pops
@@ -200,6 +348,24 @@ namespace Aerospike {
          */
         public function query(\Aerospike\QueryPolicy $policy, mixed $partition_filter, \Aerospike\Statement $statement): \Aerospike\Recordset {}
 
+        /**
+         * Execute an aggregation query on all server nodes and return an iterator over the
+         * reduced values. The stream UDF is set on the statement via
+         * `Statement::setAggregateFunction`.
+         */
+        public function queryAggregate(\Aerospike\QueryPolicy $policy, \Aerospike\Statement $statement): ?\Aerospike\ResultSet {}
+
+        /**
+         * Apply operations or a UDF to all the records matched by the statement on the server,
+         * in the background. If the operations are empty, the UDF set on the statement
+         * via `Statement::setUdf` will be applied to each record. Passing operations together with
+         * a UDF throws with the `PARAMETER_ERROR` result code.
+         * This asynchronous server call will return before the command is complete.
+         * The user can optionally wait for command completion by using the returned
+         * ExecuteTask instance.
+         */
+        public function queryExecute(\Aerospike\BackgroundExecutePolicy $policy, \Aerospike\Statement $statement, ?array $ops): ?\Aerospike\ExecuteTask {}
+
         /**
          * CreateIndex creates a secondary index.
          * This asynchronous server call will return before the command is complete.
@@ -208,36 +374,36 @@ namespace Aerospike {
          * This method is only supported by Aerospike 3+ servers.
          * If the policy is nil, the default relevant policy will be used.
          */
-        public function createIndex(\Aerospike\WritePolicy $policy, string $namespace, string $set_name, string $bin_name, string $index_name, \Aerospike\IndexType $index_type, ?\Aerospike\IndexCollectionType $cit, ?array $ctx): mixed {}
+        public function createIndex(\Aerospike\WritePolicy $policy, string $namespace, string $set_name, string $bin_name, string $index_name, \Aerospike\IndexType $index_type, ?\Aerospike\IndexCollectionType $cit, ?array $ctx): ?\Aerospike\IndexTask {}
 
         /**
          * DropIndex deletes a secondary index. It will block until index is dropped on all nodes.
          * This method is only supported by Aerospike 3+ servers.
          * If the policy is nil, the default relevant policy will be used.
          */
-        public function dropIndex(\Aerospike\WritePolicy $policy, string $namespace, string $set_name, string $index_name): mixed {}
+        public function dropIndex(\Aerospike\WritePolicy $policy, string $namespace, string $set_name, string $index_name): ?\Aerospike\IndexTask {}
 
         /**
          * RegisterUDF registers a package containing user defined functions with server.
          * This asynchronous server call will return before command is complete.
          * The user can optionally wait for command completion by using the returned
-         * RegisterTask instance.
+         * UdfTask instance.
          *
          * This method is only supported by Aerospike 3+ servers.
          * If the policy is nil, the default relevant policy will be used.
          */
-        public function registerUdf(\Aerospike\WritePolicy $policy, string $udf_body, string $package_name, mixed $language): mixed {}
+        public function registerUdf(\Aerospike\WritePolicy $policy, string $udf_body, string $package_name, mixed $language): ?\Aerospike\UdfTask {}
 
         /**
          * DropUDF removes a package containing user defined functions in the server.
          * This asynchronous server call will return before command is complete.
          * The user can optionally wait for command completion by using the returned
-         * RemoveTask instance.
+         * UdfTask instance.
          *
          * This method is only supported by Aerospike 3+ servers.
          * If the policy is nil, the default relevant policy will be used.
          */
-        public function dropUdf(\Aerospike\WritePolicy $policy, string $package_name): mixed {}
+        public function dropUdf(\Aerospike\WritePolicy $policy, string $package_name): ?\Aerospike\UdfTask {}
 
         /**
          * ListUDF lists all packages containing user defined functions in the server.
@@ -402,6 +568,9 @@ namespace Aerospike {
 
         public $expiration;
 
+        public $replica;
+        public $txn;
+
         public function __construct() {}
 
         public function getRecordExistsAction(): \Aerospike\RecordExistsAction {}
@@ -498,6 +667,23 @@ namespace Aerospike {
 
         public function setReadModeSc(mixed $read_mode_sc) {}
 
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for a single record command.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * Txn is the multi-record transaction the command belongs to. The command is not part of a
+         * transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+         */
+        public function getTxn(): ?\Aerospike\Txn {}
+
+        public function setTxn(?\Aerospike\Txn $txn) {}
+
         public function getFilterExpression(): ?\Aerospike\Expression {}
 
         public function setFilterExpression(mixed $filter_expression) {}
@@ -528,6 +714,9 @@ namespace Aerospike {
 
         public $use_compression;
 
+        public $replica;
+        public $txn;
+
         public function __construct() {}
 
         /**
@@ -647,6 +836,23 @@ namespace Aerospike {
 
         public function setReadModeSc(mixed $read_mode_sc) {}
 
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for a single record command.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * Txn is the multi-record transaction the command belongs to. The command is not part of a
+         * transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+         */
+        public function getTxn(): ?\Aerospike\Txn {}
+
+        public function setTxn(?\Aerospike\Txn $txn) {}
+
         /**
          * FilterExpression is the optional Filter Expression. Supported on Server v5.2+
          */
@@ -690,6 +896,9 @@ namespace Aerospike {
 
         public $allow_partial_results;
 
+        public $replica;
+        public $txn;
+
         public function __construct() {}
 
         public function getMaxRetries(): int {}
@@ -728,6 +937,23 @@ namespace Aerospike {
 
         public function setReadModeSc(mixed $read_mode_sc) {}
 
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for each key in the batch.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * Txn is the multi-record transaction the command belongs to. The command is not part of a
+         * transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+         */
+        public function getTxn(): ?\Aerospike\Txn {}
+
+        public function setTxn(?\Aerospike\Txn $txn) {}
+
         public function getFilterExpression(): ?\Aerospike\Expression {}
 
         public function setFilterExpression(mixed $filter_expression) {}
@@ -928,6 +1154,10 @@ namespace Aerospike {
     /**
      * Filter expression, which can be applied to most commands, to control which records are
      * affected by the command.
+     *
+     * The types of the operands are checked when a filter expression is set on a policy. An
+     * `AerospikeException` with the `PARAMETER_ERROR` result code naming the offending
+     * sub-expression is thrown, for example for comparing an integer bin with a string.
      */
     class Expression {
         public static function new(?int $cmd, mixed $val, ?\Aerospike\Expression $bin, ?int $flags, mixed $module, array $exps): \Aerospike\Expression {}
@@ -1392,11 +1622,74 @@ namespace Aerospike {
 
         /**
          * Create unknown value. Used to intentionally fail an expression.
-         * The failure can be ignored with `ExpWriteFlags` `EVAL_NO_FAIL`
-         * or `ExpReadFlags` `EVAL_NO_FAIL`.
+         * The failure can be ignored with `ExpWriteFlags::EvalNoFail()`
+         * or `ExpReadFlags::EvalNoFail()`, in which case an `ExpOperation::write()`
+         * leaves the bin untouched.
          * Requires server version 5.6.0+.
+         * ```php
+         * // Set expired to true if the ttl is less than a day, else leave the bin untouched.
+         * ExpOperation::write(
+         *     "expired",
+         *     Expression::cond([
+         *         Expression::lt(Expression::ttl(), Expression::intVal(86400)),
+         *         Expression::boolVal(true),
+         *         Expression::unknown(),
+         *     ]),
+         *     [ExpWriteFlags::EvalNoFail()],
+         * );
+         * ```
          */
         public static function unknown(): \Aerospike\Expression {}
+
+        /**
+         * Create an expression which deletes the bin when used as the result of an
+         * `ExpOperation::write()` with `ExpWriteFlags::AllowDelete()`.
+         * Requires server version 5.6.0+.
+         * ```php
+         * // Delete the tmp bin if it is past the deadline, else leave it untouched.
+         * ExpOperation::write(
+         *     "tmp",
+         *     Expression::cond([
+         *         Expression::gt(Expression::lastUpdate(), Expression::intBin("deadline")),
+         *         Expression::removeResult(),
+         *         Expression::unknown(),
+         *     ]),
+         *     [ExpWriteFlags::AllowDelete(), ExpWriteFlags::EvalNoFail()],
+         * );
+         * ```
+         */
+        public static function removeResult(): \Aerospike\Expression {}
+
+        /**
+         * Restores an expression from the base64 encoded server wire format, as returned by
+         * `Client::expressionToBase64()` or the other Aerospike clients. The expression can be used
+         * on its own or inside other expressions, but its operations are not known to the client,
+         * so it is not type checked.
+         * Throws an `AerospikeException` with the `PARAMETER_ERROR` result code if the string is
+         * not base64 encoded.
+         */
+        public static function fromBase64(string $encoded): \Aerospike\Expression {}
+
+        /**
+         * Returns the expression in a readable infix form for logging, for example
+         * `($.a:int > 5) and ($.b:string == "x")`.
+         */
+        public function __toString(): string {}
+
+        /**
+         * Compiles a text expression into an expression.
+         * Bins are written as `$.name`, with their type inferred from the other operand or given
+         * with a suffix like `$.name:int`. Values can be passed in `placeholders` and referenced as
+         * `?0` or `?name`.
+         * Throws an `AerospikeException` with the `PARAMETER_ERROR` result code and the column of
+         * the error if the text is not a valid expression.
+         *
+         * ```php
+         * $exp = Expression::parse("$.age > 21 and $.country == 'NL'");
+         * $exp = Expression::parse('$.tags[0] == ?tag and ttl() < ?0', [86400, 'tag' => 'new']);
+         * ```
+         */
+        public static function parse(string $dsl, ?array $placeholders = null): \Aerospike\Expression {}
     }
 
     /**
@@ -1739,6 +2032,9 @@ namespace Aerospike {
 
         public $send_key;
 
+        public $replica;
+        public $txn;
+
         public function __construct() {}
 
         /**
@@ -1799,6 +2095,23 @@ namespace Aerospike {
 
         public function setReadModeSc(mixed $read_mode_sc) {}
 
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * Txn is the multi-record transaction the command belongs to. The command is not part of a
+         * transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+         */
+        public function getTxn(): ?\Aerospike\Txn {}
+
+        public function setTxn(?\Aerospike\Txn $txn) {}
+
         public function getFilterExpression(): ?\Aerospike\Expression {}
 
         public function setFilterExpression(mixed $filter_expression) {}
@@ -1871,6 +2184,9 @@ namespace Aerospike {
 
         public $socket_timeout;
 
+        public $replica;
+        public $txn;
+
         public function __construct() {}
 
         /**
@@ -1949,6 +2265,23 @@ namespace Aerospike {
 
         public function setReadModeSc(mixed $read_mode_sc) {}
 
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * Txn is the multi-record transaction the command belongs to. The command is not part of a
+         * transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+         */
+        public function getTxn(): ?\Aerospike\Txn {}
+
+        public function setTxn(?\Aerospike\Txn $txn) {}
+
         public function getFilterExpression(): ?\Aerospike\Expression {}
 
         public function setFilterExpression(mixed $filter_expression) {}
@@ -2005,149 +2338,416 @@ namespace Aerospike {
     }
 
     /**
-     * BitResizeFlags specifies the bitwise operation flags for resize.
+     * Node represents an Aerospike Database Server Node
      */
-    class BitwiseResizeFlags {
+    class Node {
+        public $name;
+
+        public $address;
+
+        public $rack_id;
+
+        public $racks;
+
+        public $active;
+
         /**
-         * BitResizeFlagsDefault specifies the defalt flag.
+         * Name of the node.
          */
-        public static function Default(): \Aerospike\BitwiseResizeFlags {}
+        public function getName(): string {}
 
         /**
-         * BitResizeFlagsFromFront Adds/removes bytes from the beginning instead of the end.
+         * Address of the node in the `host:port` format.
          */
-        public static function FromFront(): \Aerospike\BitwiseResizeFlags {}
+        public function getAddress(): string {}
 
         /**
-         * BitResizeFlagsGrowOnly will only allow the []byte size to increase.
+         * Rack id of the node. If the node belongs to different racks for different namespaces,
+         * the rack id of the first namespace in alphabetical order is returned.
+         * Returns 0 if no racks are configured on the server.
          */
-        public static function GrowOnly(): \Aerospike\BitwiseResizeFlags {}
+        public function getRackId(): int {}
 
         /**
-         * BitResizeFlagsShrinkOnly will only allow the []byte size to decrease.
+         * Rack ids of the node per namespace.
          */
-        public static function ShrinkOnly(): \Aerospike\BitwiseResizeFlags {}
+        public function getRacks(): array {}
+
+        /**
+         * Returns true if the node is active.
+         */
+        public function getActive(): bool {}
     }
 
     /**
-     * MapReturnType defines the map return type.
-     * Type of data to return when selecting or removing items from the map.
+     * NamespaceInfo contains the configuration and the statistics of a namespace.
+     * Counters are summed over all the nodes in the cluster.
      */
-    class MapReturnType {
-        /**
-         * NONE will will not return a result.
-         */
-        public static function None(): \Aerospike\MapReturnType {}
+    class NamespaceInfo {
+        public $name;
 
-        /**
-         * INDEX will return key index order.
-         *
-         * 0 = first key
-         * N = Nth key
-         * -1 = last key
-         */
-        public static function Index(): \Aerospike\MapReturnType {}
+        public $replication_factor;
+
+        public $storage_engine;
+
+        public $objects;
+
+        public $master_objects;
+
+        public $tombstones;
+
+        public $stop_writes;
 
         /**
-         * REVERSE_INDEX will return reverse key order.
-         *
-         * 0 = last key
-         * -1 = first key
+         * Name of the namespace.
          */
-        public static function ReverseIndex(): \Aerospike\MapReturnType {}
+        public function getName(): string {}
 
         /**
-         * RANK will return value order.
-         *
-         * 0 = smallest value
-         * N = Nth smallest value
-         * -1 = largest value
+         * Number of copies of each record in the cluster.
          */
-        public static function Rank(): \Aerospike\MapReturnType {}
+        public function getReplicationFactor(): int {}
 
         /**
-         * REVERSE_RANK will return reverse value order.
-         *
-         * 0 = largest value
-         * N = Nth largest value
-         * -1 = smallest value
+         * Storage engine of the namespace: memory, device or pmem.
          */
-        public static function ReverseRank(): \Aerospike\MapReturnType {}
+        public function getStorageEngine(): string {}
 
         /**
-         * COUNT will return count of items selected.
+         * Number of records in the namespace, including the replicas.
          */
-        public static function Count(): \Aerospike\MapReturnType {}
+        public function getObjects(): int {}
 
         /**
-         * KEY will return key for single key read and key list for range read.
+         * Number of master records in the namespace.
          */
-        public static function Key(): \Aerospike\MapReturnType {}
+        public function getMasterObjects(): int {}
 
         /**
-         * VALUE will return value for single key read and value list for range read.
+         * Number of tombstones in the namespace.
          */
-        public static function Value(): \Aerospike\MapReturnType {}
+        public function getTombstones(): int {}
 
         /**
-         * KEY_VALUE will return key/value items. The possible return types are:
-         *
-         * map[interface{}]interface{} : Returned for unordered maps
-         * []MapPair : Returned for range results where range order needs to be preserved.
+         * Returns true if any node in the cluster has stopped accepting writes for the namespace.
          */
-        public static function KeyValue(): \Aerospike\MapReturnType {}
+        public function getStopWrites(): bool {}
+    }
+
+    /**
+     * SetInfo contains the configuration and the statistics of a set.
+     * Counters are summed over all the nodes in the cluster.
+     */
+    class SetInfo {
+        public $namespace;
+
+        public $name;
+
+        public $objects;
+
+        public $tombstones;
+
+        public $memory_data_bytes;
+
+        public $device_data_bytes;
+
+        public $data_used_bytes;
+
+        public $disable_eviction;
 
         /**
-         * EXISTS returns true if count > 0.
+         * Namespace of the set.
          */
-        public static function Exists(): \Aerospike\MapReturnType {}
+        public function getNamespace(): string {}
 
         /**
-         * UNORDERED_MAP returns an unordered map.
+         * Name of the set.
          */
-        public static function UnorderedMap(): \Aerospike\MapReturnType {}
+        public function getName(): string {}
 
         /**
-         * ORDERED_MAP returns an ordered map.
+         * Number of records in the set, including the replicas.
          */
-        public static function OrderedMap(): \Aerospike\MapReturnType {}
+        public function getObjects(): int {}
 
         /**
-         * INVERTED will invert meaning of map command and return values.  For example:
-         * MapRemoveByKeyRange(binName, keyBegin, keyEnd, MapReturnType.KEY | MapReturnType.INVERTED)
-         * With the INVERTED flag enabled, the keys outside of the specified key range will be removed and returned.
+         * Number of tombstones in the set.
          */
-        public function Inverted(): \Aerospike\MapReturnType {}
-    }
+        public function getTombstones(): int {}
 
-    /**
-     * ReadModeSC is the read policy in SC (strong consistency) mode namespaces.
-     * Determines SC read consistency options.
-     */
-    class ReadModeSC {
         /**
-         * ReadModeSCSession ensures this client will only see an increasing sequence of record versions.
-         * Server only reads from master.  This is the default.
+         * Memory used by the set data. Only reported by servers prior to 7.0.
          */
-        public static function Session(): \Aerospike\ReadModeSC {}
+        public function getMemoryDataBytes(): int {}
 
         /**
-         * ReadModeSCLinearize ensures ALL clients will only see an increasing sequence of record versions.
-         * Server only reads from master.
+         * Device storage used by the set data. Only reported by servers prior to 7.0.
          */
-        public static function Linearize(): \Aerospike\ReadModeSC {}
+        public function getDeviceDataBytes(): int {}
 
         /**
-         * ReadModeSCAllowReplica indicates that the server may read from master or any full (non-migrating) replica.
-         * Increasing sequence of record versions is not guaranteed.
+         * Storage used by the set data. Only reported by servers 7.0+.
          */
-        public static function AllowReplica(): \Aerospike\ReadModeSC {}
+        public function getDataUsedBytes(): int {}
 
         /**
-         * ReadModeSCAllowUnavailable indicates that the server may read from master or any full (non-migrating) replica or from unavailable
-         * partitions.  Increasing sequence of record versions is not guaranteed.
+         * Returns true if the records in the set are not evicted.
          */
-        public static function AllowUnavailable(): \Aerospike\ReadModeSC {}
+        public function getDisableEviction(): bool {}
+    }
+
+    /**
+     * IndexInfo contains the definition and the state of a secondary index.
+     */
+    class IndexInfo {
+        public $namespace;
+
+        public $set_name;
+
+        public $name;
+
+        public $bin_name;
+
+        public $index_type;
+
+        public $collection_type;
+
+        public $ctx;
+
+        public $state;
+
+        /**
+         * Namespace of the index.
+         */
+        public function getNamespace(): string {}
+
+        /**
+         * Set of the index. Empty if the index is on the whole namespace.
+         */
+        public function getSetName(): string {}
+
+        /**
+         * Name of the index.
+         */
+        public function getName(): string {}
+
+        /**
+         * Name of the indexed bin.
+         */
+        public function getBinName(): string {}
+
+        /**
+         * Type of the indexed values.
+         */
+        public function getIndexType(): \Aerospike\IndexType {}
+
+        /**
+         * Collection type of the index.
+         */
+        public function getCollectionType(): \Aerospike\IndexCollectionType {}
+
+        /**
+         * CDT context of the indexed values in the bin. Empty if the bin itself is indexed.
+         */
+        public function getCtx(): array {}
+
+        /**
+         * State of the index. `RW` if the index is loaded on all the nodes in the cluster,
+         * otherwise the state reported by a node on which it is still loading, e.g. `WO`.
+         */
+        public function getState(): string {}
+
+        /**
+         * Returns true if the index is loaded on all the nodes and can be queried.
+         */
+        public function isLoaded(): bool {}
+    }
+
+    /**
+     * BitResizeFlags specifies the bitwise operation flags for resize.
+     */
+    class BitwiseResizeFlags {
+        /**
+         * BitResizeFlagsDefault specifies the defalt flag.
+         */
+        public static function Default(): \Aerospike\BitwiseResizeFlags {}
+
+        /**
+         * BitResizeFlagsFromFront Adds/removes bytes from the beginning instead of the end.
+         */
+        public static function FromFront(): \Aerospike\BitwiseResizeFlags {}
+
+        /**
+         * BitResizeFlagsGrowOnly will only allow the []byte size to increase.
+         */
+        public static function GrowOnly(): \Aerospike\BitwiseResizeFlags {}
+
+        /**
+         * BitResizeFlagsShrinkOnly will only allow the []byte size to decrease.
+         */
+        public static function ShrinkOnly(): \Aerospike\BitwiseResizeFlags {}
+    }
+
+    /**
+     * MapReturnType defines the map return type.
+     * Type of data to return when selecting or removing items from the map.
+     */
+    class MapReturnType {
+        /**
+         * NONE will will not return a result.
+         */
+        public static function None(): \Aerospike\MapReturnType {}
+
+        /**
+         * INDEX will return key index order.
+         *
+         * 0 = first key
+         * N = Nth key
+         * -1 = last key
+         */
+        public static function Index(): \Aerospike\MapReturnType {}
+
+        /**
+         * REVERSE_INDEX will return reverse key order.
+         *
+         * 0 = last key
+         * -1 = first key
+         */
+        public static function ReverseIndex(): \Aerospike\MapReturnType {}
+
+        /**
+         * RANK will return value order.
+         *
+         * 0 = smallest value
+         * N = Nth smallest value
+         * -1 = largest value
+         */
+        public static function Rank(): \Aerospike\MapReturnType {}
+
+        /**
+         * REVERSE_RANK will return reverse value order.
+         *
+         * 0 = largest value
+         * N = Nth largest value
+         * -1 = smallest value
+         */
+        public static function ReverseRank(): \Aerospike\MapReturnType {}
+
+        /**
+         * COUNT will return count of items selected.
+         */
+        public static function Count(): \Aerospike\MapReturnType {}
+
+        /**
+         * KEY will return key for single key read and key list for range read.
+         */
+        public static function Key(): \Aerospike\MapReturnType {}
+
+        /**
+         * VALUE will return value for single key read and value list for range read.
+         */
+        public static function Value(): \Aerospike\MapReturnType {}
+
+        /**
+         * KEY_VALUE will return key/value items. The possible return types are:
+         *
+         * map[interface{}]interface{} : Returned for unordered maps
+         * []MapPair : Returned for range results where range order needs to be preserved.
+         */
+        public static function KeyValue(): \Aerospike\MapReturnType {}
+
+        /**
+         * EXISTS returns true if count > 0.
+         */
+        public static function Exists(): \Aerospike\MapReturnType {}
+
+        /**
+         * UNORDERED_MAP returns an unordered map.
+         */
+        public static function UnorderedMap(): \Aerospike\MapReturnType {}
+
+        /**
+         * ORDERED_MAP returns an ordered map.
+         */
+        public static function OrderedMap(): \Aerospike\MapReturnType {}
+
+        /**
+         * INVERTED will invert meaning of map command and return values.  For example:
+         * MapRemoveByKeyRange(binName, keyBegin, keyEnd, MapReturnType.KEY | MapReturnType.INVERTED)
+         * With the INVERTED flag enabled, the keys outside of the specified key range will be removed and returned.
+         */
+        public function Inverted(): \Aerospike\MapReturnType {}
+    }
+
+    /**
+     * ReadModeSC is the read policy in SC (strong consistency) mode namespaces.
+     * Determines SC read consistency options.
+     */
+    class ReadModeSC {
+        /**
+         * ReadModeSCSession ensures this client will only see an increasing sequence of record versions.
+         * Server only reads from master.  This is the default.
+         */
+        public static function Session(): \Aerospike\ReadModeSC {}
+
+        /**
+         * ReadModeSCLinearize ensures ALL clients will only see an increasing sequence of record versions.
+         * Server only reads from master.
+         */
+        public static function Linearize(): \Aerospike\ReadModeSC {}
+
+        /**
+         * ReadModeSCAllowReplica indicates that the server may read from master or any full (non-migrating) replica.
+         * Increasing sequence of record versions is not guaranteed.
+         */
+        public static function AllowReplica(): \Aerospike\ReadModeSC {}
+
+        /**
+         * ReadModeSCAllowUnavailable indicates that the server may read from master or any full (non-migrating) replica or from unavailable
+         * partitions.  Increasing sequence of record versions is not guaranteed.
+         */
+        public static function AllowUnavailable(): \Aerospike\ReadModeSC {}
+    }
+
+    /**
+     * ReplicaPolicy defines the algorithm used to determine the target node for a command.
+     */
+    class ReplicaPolicy {
+        /**
+         * Sequence tries the node containing the master partition first.
+         * If the connection fails, all commands try nodes containing replicated partitions.
+         * If socketTimeout is reached, reads also try nodes containing replicated partitions,
+         * but writes remain on master node. This is the default.
+         */
+        public static function Sequence(): \Aerospike\ReplicaPolicy {}
+
+        /**
+         * Master uses the node containing the key's master partition.
+         */
+        public static function Master(): \Aerospike\ReplicaPolicy {}
+
+        /**
+         * MasterProles distributes reads across nodes containing the key's master and replicated partitions
+         * in round-robin fashion. Writes always use the node containing the key's master partition.
+         */
+        public static function MasterProles(): \Aerospike\ReplicaPolicy {}
+
+        /**
+         * PreferRack tries the node on the same rack as the client first. If timeout or there are no nodes on the
+         * same rack, uses Sequence instead.
+         *
+         * The connection manager must be configured with `rack-aware = true` and a `rack-id` or `rack-ids`
+         * for this to have any effect.
+         */
+        public static function PreferRack(): \Aerospike\ReplicaPolicy {}
+
+        /**
+         * Random distributes reads across all nodes in the cluster in round-robin fashion.
+         * Writes always use the node containing the key's master partition.
+         * This option is useful when the replication factor equals the number
+         * of nodes in the cluster and the overhead of requesting proles is not desired.
+         */
+        public static function Random(): \Aerospike\ReplicaPolicy {}
     }
 
     /**
@@ -2199,6 +2799,39 @@ namespace Aerospike {
         public static function regionsContainingPoint(string $bin_name, float $lat, float $lng, ?\Aerospike\IndexCollectionType $cit, ?array $ctx): \Aerospike\Filter {}
     }
 
+    /**
+     * BatchResults holds the records of a batch command, in the order of the commands, and a
+     * summary of the keys which failed. Records which were not found do not count as failures.
+     */
+    class BatchResults {
+        public $records;
+
+        public $success_count;
+
+        public $failure_count;
+
+        /**
+         * Records of the batch, in the order of the commands.
+         */
+        public function getRecords(): array {}
+
+        /**
+         * Number of keys for which the command succeeded or the record was not found.
+         */
+        public function getSuccessCount(): int {}
+
+        /**
+         * Number of keys for which the command failed.
+         */
+        public function getFailureCount(): int {}
+
+        /**
+         * Returns the records of the keys for which the command failed. Each carries the key, the
+         * result code and the in doubt flag of the failure.
+         */
+        public function failures(): array {}
+    }
+
     /**
      * BatchRead specifies the Key and bin names used in batch read commands
      * where variable bins are needed for each key.
@@ -2207,15 +2840,21 @@ namespace Aerospike {
         public function __construct(\Aerospike\BatchReadPolicy $policy, \Aerospike\Key $key, ?array $bins) {}
 
         /**
-         * Optional read policy.
+         * Creates a batch read command which runs the operations on the record.
+         * Any read operation is accepted, including the `ListOp`, `MapOp`, `HllOp` and `BitwiseOp`
+         * read operations. The results are available on `BatchRecord::$results`.
+         * Ops are mutually exclusive with bin names.
+         * Supported by server v5.6.0+.
+         */
+        public static function withOps(\Aerospike\BatchReadPolicy $policy, \Aerospike\Key $key, array $ops): \Aerospike\BatchRead {}
+
+        /**
+         * Same as `withOps`.
          */
         public static function ops(\Aerospike\BatchReadPolicy $policy, \Aerospike\Key $key, array $ops): \Aerospike\BatchRead {}
 
         /**
-         * Ops specifies the operations to perform for every key.
-         * Ops are mutually exclusive with BinNames.
-         * A binName can be emulated with `GetOp(binName)`
-         * Supported by server v5.6.0+.
+         * Creates a batch read command which only reads the record header (generation and expiration).
          */
         public static function header(\Aerospike\BatchReadPolicy $policy, \Aerospike\Key $key): \Aerospike\BatchRead {}
     }
@@ -2652,6 +3291,27 @@ namespace Aerospike {
         public $code;
     }
 
+    /**
+     * Thrown by batch commands in strict mode when one or more keys failed. Carries the results
+     * of the whole batch, including the keys which succeeded.
+     *
+     * It is not an `AerospikeException` and must be caught on its own.
+     */
+    class BatchException{
+        public $message;
+
+        public $in_doubt;
+
+        public $code;
+
+        public $results;
+
+        /**
+         * Results of the batch, including the keys which succeeded.
+         */
+        public function getResults(): \Aerospike\BatchResults {}
+    }
+
     /**
      * Virtual collection of records retrieved through queries and scans. During a query/scan,
      * multiple threads will retrieve records from the server nodes and put these records on an
@@ -2684,6 +3344,21 @@ namespace Aerospike {
          */
         public static function range(int $begin, int $count): \Aerospike\PartitionFilter {}
 
+        /**
+         * Serializes the state of the partition filter into a URL safe string token.
+         * After a scan/query with `max_records` set, the token can be carried over to another
+         * request and restored with `PartitionFilter::fromCursor()` to continue from where the
+         * previous scan/query stopped.
+         */
+        public function toCursor(): string {}
+
+        /**
+         * Restores a partition filter from a token created by `PartitionFilter::toCursor()`.
+         * Throws an `AerospikeException` with the `PARAMETER_ERROR` result code if the token is
+         * invalid or corrupted.
+         */
+        public static function fromCursor(string $cursor): ?\Aerospike\PartitionFilter {}
+
         public function initPartitionStatus() {}
     }
 
@@ -3640,6 +4315,12 @@ namespace Aerospike {
 
         public $record;
 
+        public $result_code;
+
+        public $in_doubt;
+
+        public $results;
+
         /**
          * Key.
          */
@@ -3650,17 +4331,45 @@ namespace Aerospike {
          * or an error occurred. See ResultCode.
          */
         public function getRecord(): ?\Aerospike\Record {}
-    }
 
-    /**
+        /**
+         * ResultCode of the command for this key. Zero (OK) if the command succeeded.
+         */
+        public function getResultCode(): int {}
+
+        /**
+         * InDoubt is set if the write command for this key may have completed even though an
+         * error was returned.
+         */
+        public function getInDoubt(): bool {}
+
+        /**
+         * Results of the operations of a `BatchRead::withOps` or `BatchWrite` command, in the order
+         * the operations were given. When more than one operation is run on the same bin, the server
+         * returns a list of their results in that bin, which is split so that each operation gets
+         * its own result. Operations without a bin name, such as `Operation::touch()`, return null.
+         *
+         * Null if the command had no operations, or if the record was not found or an error occurred.
+         */
+        public function getResults(): ?array {}
+    }
+
+    /**
      * Virtual collection of records retrieved through queries and scans. During a query/scan,
      * multiple threads will retrieve records from the server nodes and put these records on an
      * internal queue managed by the recordset. The single user thread consumes these records from the
      * queue.
+     *
+     * The recordset can be iterated with `foreach`, in which case the keys are the record `Key`s.
+     * Since they are objects, use `iterator_to_array($recordset, false)` to collect the records.
+     * The stream can only be consumed once; a new iteration continues from where the previous one
+     * stopped.
      */
-    class Recordset {
+    class Recordset implements \IteratorAggregate {
         public $active;
 
+        public $error;
+
         /**
          * Drop the stream, which will signal the server and close the recordset
          */
@@ -3671,10 +4380,61 @@ namespace Aerospike {
          */
         public function getActive(): bool {}
 
+        /**
+         * The error which ended the stream, or null if the stream ended normally or is still active.
+         */
+        public function getError(): ?\Aerospike\AerospikeException {}
+
         /**
          * Records is a channel on which the resulting records will be sent back.
+         * Returns null when the stream is exhausted. If the stream ends with an error, the error is
+         * thrown and is also available through the `error` property.
          */
         public function next(): ?\Aerospike\Record {}
+
+        /**
+         * Returns up to `n` records from the stream. Fewer records are returned when the stream is
+         * exhausted, and an empty array once there are no more records. If the stream ends with an
+         * error, the records read before the error are returned first, and the error is thrown on
+         * the next call.
+         */
+        public function fetchBatch(int $n): array {}
+
+        /**
+         * Returns an iterator over the records in the recordset. Used by `foreach`.
+         */
+        public function getIterator(): \Aerospike\RecordsetIterator {}
+    }
+
+    /**
+     * RecordsetIterator implements the PHP `Iterator` interface over a `Recordset`.
+     * The keys are the record `Key`s, and the values are the `Record`s.
+     */
+    class RecordsetIterator implements \Iterator {
+        /**
+         * Returns the current record.
+         */
+        public function current(): ?\Aerospike\Record {}
+
+        /**
+         * Returns the key of the current record.
+         */
+        public function key(): ?\Aerospike\Key {}
+
+        /**
+         * Moves forward to the next record.
+         */
+        public function next(): void {}
+
+        /**
+         * Returns true if the iterator is positioned on a record.
+         */
+        public function valid(): bool {}
+
+        /**
+         * Streams can not be rewound. Positions the iterator on the first record not yet consumed.
+         */
+        public function rewind(): void {}
     }
 
     /**
@@ -3903,6 +4663,95 @@ namespace Aerospike {
         public function setSendKey(bool $send_key) {}
     }
 
+    /**
+     * Map is an ordered Aerospike map. It is returned instead of a PHP array for maps with keys
+     * that can not be used as PHP array keys, such as lists, blobs, floats or booleans, and can be
+     * used anywhere a map value is accepted so that these maps can be written back without loss.
+     *
+     * Maps with list keys can be written, but not read back from the database: the Go client of
+     * the connection manager can not decode them.
+     *
+     * Entries are looked up by scanning the map, so prefer PHP arrays for large maps with integer
+     * or string keys.
+     */
+    class Map implements \ArrayAccess, \IteratorAggregate, \Countable {
+        /**
+         * Creates a map from the entries of a PHP array, keeping their order.
+         */
+        public function __construct(?array $entries) {}
+
+        /**
+         * Returns true if the key exists in the map.
+         */
+        public function offsetExists(mixed $key): bool {}
+
+        /**
+         * Returns the value for the key, or null if the key does not exist in the map.
+         */
+        public function offsetGet(mixed $key): mixed {}
+
+        /**
+         * Sets the value for the key. New keys are added to the end of the map.
+         */
+        public function offsetSet(mixed $key, mixed $value): void {}
+
+        /**
+         * Removes the key from the map.
+         */
+        public function offsetUnset(mixed $key): void {}
+
+        /**
+         * Returns the number of entries in the map.
+         */
+        public function count(): int {}
+
+        /**
+         * Returns the keys of the map in order.
+         */
+        public function keys(): array {}
+
+        /**
+         * Returns the values of the map in order.
+         */
+        public function values(): array {}
+
+        /**
+         * Returns an iterator over the entries of the map. Used by `foreach`, in which case the
+         * keys can be of any type supported by Aerospike.
+         */
+        public function getIterator(): \Aerospike\MapIterator {}
+    }
+
+    /**
+     * MapIterator implements the PHP `Iterator` interface over a snapshot of a `Map`.
+     */
+    class MapIterator implements \Iterator {
+        /**
+         * Returns the current value.
+         */
+        public function current(): mixed {}
+
+        /**
+         * Returns the current key.
+         */
+        public function key(): mixed {}
+
+        /**
+         * Moves forward to the next entry.
+         */
+        public function next(): void {}
+
+        /**
+         * Returns true if the iterator is positioned on an entry.
+         */
+        public function valid(): bool {}
+
+        /**
+         * Moves back to the first entry.
+         */
+        public function rewind(): void {}
+    }
+
     /**
      *  Value interface is used to efficiently serialize objects into the wire protocol.
      */
@@ -4230,6 +5079,12 @@ namespace Aerospike {
      * Statement encapsulates query statement parameters.
      */
     class Statement {
+        public $package_name;
+
+        public $function_name;
+
+        public $function_args;
+
         public $bin_names;
 
         public $index_name;
@@ -4279,6 +5134,40 @@ namespace Aerospike {
         public function getSetname(): string {}
 
         public function setSetname(string $set_name) {}
+
+        /**
+         * Set the Lua UDF to apply to every record matched by the statement in a
+         * background query execution (`Client::queryExecute`).
+         * The package must already be registered on the server.
+         */
+        public function setUdf(string $package_name, string $function_name, ?array $function_args) {}
+
+        /**
+         * Remove the UDF set on the statement.
+         */
+        public function clearUdf() {}
+
+        /**
+         * PackageName is the name of the UDF package set on the statement, if any.
+         */
+        public function getPackageName(): ?string {}
+
+        /**
+         * FunctionName is the name of the UDF function set on the statement, if any.
+         */
+        public function getFunctionName(): ?string {}
+
+        /**
+         * FunctionArgs are the arguments passed to the UDF function set on the statement.
+         */
+        public function getFunctionArgs(): ?array {}
+
+        /**
+         * Set the stream UDF to use for an aggregation query (`Client::queryAggregate`).
+         * The package must be registered on the server, and also be available in the
+         * `lua-path` directory of the connection manager, which runs the final reduce phase.
+         */
+        public function setAggregateFunction(string $package_name, string $function_name, ?array $function_args) {}
     }
 
     /**
@@ -4359,6 +5248,12 @@ namespace Aerospike {
      * The positive numbers align with the server side file kvs.h.
      */
     class ResultCode {
+        /**
+         * CONNECTION_MANAGER_TIMEOUT means the connection manager did not respond before the
+         * deadline of the call.
+         */
+        const CONNECTION_MANAGER_TIMEOUT = null;
+
         /**
          * GRPC_ERROR is wrapped and directly returned from the grpc library
          */
@@ -4859,4 +5754,859 @@ namespace Aerospike {
          */
         public static function Partial(): \Aerospike\ListWriteFlags {}
     }
+
+    /**
+     * `BackgroundExecutePolicy` encapsulates parameters for background query executions,
+     * which apply a UDF or a list of write operations to all records matched by a `Statement`
+     * on the server.
+     */
+    class BackgroundExecutePolicy {
+        public $filter_expression;
+
+        public $total_timeout;
+
+        public $send_key;
+
+        public $use_compression;
+
+        public $record_exists_action;
+
+        public $generation_policy;
+
+        public $commit_level;
+
+        public $generation;
+
+        public $expiration;
+
+        public $durable_delete;
+
+        public $replica;
+
+        public function __construct() {}
+
+        /**
+         * FilterExpression is the optional Filter Expression. Supported on Server v5.2+
+         */
+        public function getFilterExpression(): ?\Aerospike\Expression {}
+
+        public function setFilterExpression(mixed $filter_expression) {}
+
+        /**
+         * ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+         *
+         * Default: ReplicaPolicy::Sequence()
+         */
+        public function getReplica(): \Aerospike\ReplicaPolicy {}
+
+        public function setReplica(mixed $replica) {}
+
+        /**
+         * TotalTimeout specifies total transaction timeout in milliseconds.
+         */
+        public function getTotalTimeout(): int {}
+
+        public function setTotalTimeout(int $timeout_millis) {}
+
+        /**
+         * SendKey determines if the user defined key should be sent to the server and stored
+         * with the written records.
+         */
+        public function getSendKey(): bool {}
+
+        public function setSendKey(bool $send_key) {}
+
+        /**
+         * UseCompression uses zlib compression on command buffers sent to the server and responses received
+         * from the server when the buffer size is greater than 128 bytes.
+         */
+        public function getUseCompression(): bool {}
+
+        public function setUseCompression(bool $use_compression) {}
+
+        /**
+         * RecordExistsAction qualifies how to handle writes where the record already exists.
+         */
+        public function getRecordExistsAction(): \Aerospike\RecordExistsAction {}
+
+        public function setRecordExistsAction(mixed $record_exists_action) {}
+
+        /**
+         * GenerationPolicy qualifies how to handle record writes based on record generation. The default (NONE)
+         * indicates that the generation is not used to restrict writes.
+         */
+        public function getGenerationPolicy(): \Aerospike\GenerationPolicy {}
+
+        public function setGenerationPolicy(mixed $generation_policy) {}
+
+        /**
+         * Desired consistency guarantee when committing a transaction on the server. The default
+         * (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
+         * be successful before returning success to the client.
+         */
+        public function getCommitLevel(): \Aerospike\CommitLevel {}
+
+        public function setCommitLevel(mixed $commit_level) {}
+
+        /**
+         * Generation determines expected generation.
+         * This field is only relevant when generationPolicy is not NONE.
+         */
+        public function getGeneration(): int {}
+
+        public function setGeneration(int $generation) {}
+
+        /**
+         * Expiration determines the expiration of the records written by the background execution.
+         * See `WritePolicy::expiration` for the possible values.
+         */
+        public function getExpiration(): \Aerospike\Expiration {}
+
+        public function setExpiration(mixed $expiration) {}
+
+        /**
+         * DurableDelete leaves a tombstone for the record if the transaction results in a record deletion.
+         * This prevents deleted records from reappearing after node failures.
+         * Valid for Aerospike Server Enterprise Edition 3.10+ only.
+         */
+        public function getDurableDelete(): bool {}
+
+        public function setDurableDelete(bool $durable_delete) {}
+    }
+
+    /**
+     * TaskStatus is the status of an asynchronous task on the server.
+     */
+    class TaskStatus {
+        /**
+         * The task is still running on at least one node.
+         */
+        public static function InProgress(): \Aerospike\TaskStatus {}
+
+        /**
+         * The task has finished on all nodes.
+         */
+        public static function Complete(): \Aerospike\TaskStatus {}
+
+        /**
+         * Answers true only if the task is still running.
+         */
+        public function isInProgress(): bool {}
+
+        /**
+         * Answers true only if the task has finished on all nodes.
+         */
+        public function isComplete(): bool {}
+    }
+
+    /**
+     * ExecuteTask is used to poll for the completion of a background query execution
+     * started with `Client::queryExecute`.
+     */
+    class ExecuteTask {
+        public $task_id;
+
+        /**
+         * TaskId is the id of the background task on the server.
+         */
+        public function getTaskId(): int {}
+
+        /**
+         * Query the status of the task on all nodes of the cluster.
+         */
+        public function status(): \Aerospike\TaskStatus {}
+
+        /**
+         * Answers true only if the task has finished on all nodes.
+         */
+        public function isDone(): bool {}
+
+        /**
+         * Wait until the task has finished on all nodes. If a timeout in milliseconds is
+         * given and the task does not complete in time, an `AerospikeException` with the
+         * `TIMEOUT` result code is thrown.
+         */
+        public function waitUntilComplete(?int $timeout_ms) {}
+    }
+
+    /**
+     * ResultSet is the iterator over the values returned by an aggregation query.
+     */
+    class ResultSet {
+        public $active;
+
+        /**
+         * Drop the stream, which will signal the server and close the result set
+         */
+        public function close() {}
+
+        /**
+         * IsActive returns true if the operation hasn't been finished or cancelled.
+         */
+        public function getActive(): bool {}
+
+        /**
+         * Returns the next value produced by the aggregation, or null when the result set is
+         * exhausted. Errors are thrown as `AerospikeException`.
+         * The aggregation can also produce null values. The result set is exhausted only once
+         * `active` is false: `while (($v = $rs->next()) !== null || $rs->active)`.
+         */
+        public function next(): mixed {}
+    }
+
+    /**
+     * IndexTask is used to poll for the completion of a secondary index creation
+     * or removal on all nodes of the cluster.
+     */
+    class IndexTask {
+        public $namespace;
+
+        public $index_name;
+
+        /**
+         * Namespace of the index.
+         */
+        public function getNamespace(): string {}
+
+        /**
+         * Name of the index.
+         */
+        public function getIndexName(): string {}
+
+        /**
+         * Answers true only if the index has been built, or removed, on all nodes.
+         */
+        public function isDone(): bool {}
+
+        /**
+         * Returns the percentage of the index built on each node, keyed by the node name.
+         * For an index removal, a node reports 100 once the index is removed on it.
+         */
+        public function progress(): array {}
+
+        /**
+         * Wait until the task has finished on all nodes. If a timeout in milliseconds is
+         * given and the task does not complete in time, an `AerospikeException` with the
+         * `TIMEOUT` result code is thrown.
+         */
+        public function waitUntilComplete(?int $timeout_ms) {}
+    }
+
+    /**
+     * UdfTask is used to poll for the completion of a UDF package registration
+     * or removal on all nodes of the cluster.
+     */
+    class UdfTask {
+        public $package_name;
+
+        /**
+         * Name of the UDF package.
+         */
+        public function getPackageName(): string {}
+
+        /**
+         * Answers true only if the package has been registered, or removed, on all nodes.
+         */
+        public function isDone(): bool {}
+
+        /**
+         * Returns the progress of the task on each node, keyed by the node name.
+         * A node reports 100 once the package is registered, or removed, on it, and 0 otherwise.
+         */
+        public function progress(): array {}
+
+        /**
+         * Wait until the task has finished on all nodes. If a timeout in milliseconds is
+         * given and the task does not complete in time, an `AerospikeException` with the
+         * `TIMEOUT` result code is thrown.
+         */
+        public function waitUntilComplete(?int $timeout_ms) {}
+    }
+
+    /**
+     * Txn is a multi-record transaction. Set it on the policies of the commands which belong to
+     * the transaction, then finish it with `Client::commit()` or `Client::abort()`.
+     *
+     * The connection manager keeps the transaction between requests, so the commands can be sent
+     * through any client connected to the same connection manager.
+     *
+     * Requires server version 8.0+ and a namespace configured with strong consistency.
+     */
+    class Txn {
+        public $id;
+        public $timeout;
+        public $state;
+
+        /**
+         * Creates a new transaction with a random id. The timeout is in seconds; if not set or 0,
+         * the server's mrt-duration config is used.
+         */
+        public function __construct(?int $timeout) {}
+
+        /**
+         * Id of the transaction.
+         */
+        public function getId(): int {}
+
+        /**
+         * Timeout of the transaction in seconds, counted from the first write in the transaction.
+         * 0 means the server's mrt-duration config is used. Changing the timeout after the first
+         * command of the transaction has no effect.
+         */
+        public function getTimeout(): int {}
+
+        public function setTimeout(int $timeout) {}
+
+        /**
+         * State of the transaction, as of its last commit or abort.
+         */
+        public function getState(): \Aerospike\TxnState {}
+    }
+
+    /**
+     * TxnState is the state of a multi-record transaction.
+     */
+    class TxnState {
+        /**
+         * The transaction is open and accepts commands.
+         */
+        public static function Open(): \Aerospike\TxnState {}
+
+        /**
+         * The reads of the transaction have been verified, but it is not committed yet.
+         */
+        public static function Verified(): \Aerospike\TxnState {}
+
+        /**
+         * The transaction has been committed.
+         */
+        public static function Committed(): \Aerospike\TxnState {}
+
+        /**
+         * The transaction has been aborted.
+         */
+        public static function Aborted(): \Aerospike\TxnState {}
+
+        /**
+         * Answers true only if the transaction is open.
+         */
+        public function isOpen(): bool {}
+
+        /**
+         * Answers true only if the transaction has been committed.
+         */
+        public function isCommitted(): bool {}
+
+        /**
+         * Answers true only if the transaction has been aborted.
+         */
+        public function isAborted(): bool {}
+    }
+
+    /**
+     * CommitStatus is the result of a successful `Client::commit()`.
+     */
+    class CommitStatus {
+        /**
+         * The commit succeeded.
+         */
+        public static function Ok(): \Aerospike\CommitStatus {}
+
+        /**
+         * The transaction had already been committed.
+         */
+        public static function AlreadyCommitted(): \Aerospike\CommitStatus {}
+
+        /**
+         * The reads were verified, but the commit failed on the client side.
+         * The server will eventually roll the transaction forward.
+         */
+        public static function RollForwardAbandoned(): \Aerospike\CommitStatus {}
+
+        /**
+         * The transaction was rolled forward, but the client failed to close it.
+         * The server will eventually close the transaction.
+         */
+        public static function CloseAbandoned(): \Aerospike\CommitStatus {}
+
+        /**
+         * Answers true only if the commit succeeded.
+         */
+        public function isOk(): bool {}
+    }
+
+    /**
+     * AbortStatus is the result of a successful `Client::abort()`.
+     */
+    class AbortStatus {
+        /**
+         * The abort succeeded.
+         */
+        public static function Ok(): \Aerospike\AbortStatus {}
+
+        /**
+         * The transaction had already been aborted.
+         */
+        public static function AlreadyAborted(): \Aerospike\AbortStatus {}
+
+        /**
+         * The client failed to roll back the transaction.
+         * The server will eventually abort the transaction.
+         */
+        public static function RollBackAbandoned(): \Aerospike\AbortStatus {}
+
+        /**
+         * The transaction was rolled back, but the client failed to close it.
+         * The server will eventually close the transaction.
+         */
+        public static function CloseAbandoned(): \Aerospike\AbortStatus {}
+
+        /**
+         * Answers true only if the abort succeeded.
+         */
+        public function isOk(): bool {}
+    }
+
+    /**
+     * List expression generator. The expressions read list bins, or lists nested in other
+     * lists and maps when a context is given, and can be used in filter expressions.
+     *
+     * Index, rank and count arguments are integer expressions. The `bin` argument is usually
+     * `Expression::listBin()`, but can be any expression that returns a list.
+     */
+    class ListExp {
+        /**
+         * Create expression that returns list size.
+         */
+        public static function size(\Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items identified by value and returns selected
+         * data specified by return_type.
+         */
+        public static function getByValue(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items identified by value range (value_begin inclusive,
+         * value_end exclusive). If value_begin is null, the range is less than value_end. If value_end
+         * is null, the range is greater than equal to value_begin.
+         */
+        public static function getByValueRange(mixed $return_type, ?\Aerospike\Expression $value_begin, ?\Aerospike\Expression $value_end, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items identified by values and returns selected data
+         * specified by return_type. `values` is a list expression.
+         */
+        public static function getByValueList(mixed $return_type, \Aerospike\Expression $values, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items nearest to value and greater by relative rank
+         * and returns selected data specified by return_type.
+         *
+         * Examples for ordered list \[0, 4, 5, 9, 11, 15\]:
+         *
+         * (value,rank) = [selected items]
+         * (5,0) = [5,9,11,15]
+         * (5,1) = [9,11,15]
+         * (5,-1) = [4,5,9,11,15]
+         * (3,0) = [4,5,9,11,15]
+         * (3,3) = [11,15]
+         * (3,-3) = [0,4,5,9,11,15]
+         */
+        public static function getByValueRelativeRankRange(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items nearest to value and greater by relative rank
+         * with a count limit and returns selected data specified by return_type.
+         *
+         * Examples for ordered list \[0, 4, 5, 9, 11, 15\]:
+         *
+         * (value,rank,count) = [selected items]
+         * (5,0,2) = [5,9]
+         * (5,1,1) = [9]
+         * (5,-1,2) = [4,5]
+         * (3,0,1) = [4]
+         * (3,3,7) = [11,15]
+         * (3,-3,2) = []
+         */
+        public static function getByValueRelativeRankRangeCount(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $rank, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list item identified by index and returns selected data
+         * specified by return_type. `value_type` is the type of the item when the return type
+         * is `ListReturnType::Value()`.
+         *
+         * ```php
+         * // a[3] == 5
+         * Expression::eq(
+         *   ListExp::getByIndex(ListReturnType::Value(), ExpType::Int(), Expression::intVal(3), Expression::listBin("a")),
+         *   Expression::intVal(5));
+         * ```
+         */
+        public static function getByIndex(mixed $return_type, mixed $value_type, \Aerospike\Expression $index, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items starting at specified index to the end of list
+         * and returns selected data specified by return_type.
+         */
+        public static function getByIndexRange(mixed $return_type, \Aerospike\Expression $index, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects "count" list items starting at specified index
+         * and returns selected data specified by return_type.
+         */
+        public static function getByIndexRangeCount(mixed $return_type, \Aerospike\Expression $index, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list item identified by rank and returns selected
+         * data specified by return_type. `value_type` is the type of the item when the return type
+         * is `ListReturnType::Value()`.
+         *
+         * ```php
+         * // Player with lowest score.
+         * ListExp::getByRank(ListReturnType::Value(), ExpType::String(), Expression::intVal(0), Expression::listBin("a"));
+         * ```
+         */
+        public static function getByRank(mixed $return_type, mixed $value_type, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects list items starting at specified rank to the last ranked item
+         * and returns selected data specified by return_type.
+         */
+        public static function getByRankRange(mixed $return_type, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects "count" list items starting at specified rank and returns
+         * selected data specified by return_type.
+         */
+        public static function getByRankRangeCount(mixed $return_type, \Aerospike\Expression $rank, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+    }
+
+    /**
+     * Map expression generator. The expressions read map bins, or maps nested in other
+     * lists and maps when a context is given, and can be used in filter expressions.
+     *
+     * Index, rank and count arguments are integer expressions. The `bin` argument is usually
+     * `Expression::mapBin()`, but can be any expression that returns a map.
+     */
+    class MapExp {
+        /**
+         * Create expression that returns map size.
+         */
+        public static function size(\Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map item identified by key and returns selected data
+         * specified by return_type. `value_type` is the type of the item when the return type
+         * is `MapReturnType::Value()`.
+         *
+         * ```php
+         * // attrs["status"] == "active"
+         * Expression::eq(
+         *   MapExp::getByKey(MapReturnType::Value(), ExpType::String(), Expression::stringVal("status"), Expression::mapBin("attrs")),
+         *   Expression::stringVal("active"));
+         * ```
+         */
+        public static function getByKey(mixed $return_type, mixed $value_type, \Aerospike\Expression $key, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items identified by key range (key_begin inclusive,
+         * key_end exclusive). If key_begin is null, the range is less than key_end. If key_end is
+         * null, the range is greater than equal to key_begin.
+         */
+        public static function getByKeyRange(mixed $return_type, ?\Aerospike\Expression $key_begin, ?\Aerospike\Expression $key_end, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items identified by keys and returns selected data
+         * specified by return_type. `keys` is a list expression.
+         */
+        public static function getByKeyList(mixed $return_type, \Aerospike\Expression $keys, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items nearest to key and greater by index.
+         *
+         * Examples for ordered map [{0=17},{4=2},{5=15},{9=10}]:
+         *
+         * (key,index) = [selected items]
+         * (5,0) = [{5=15},{9=10}]
+         * (5,1) = [{9=10}]
+         * (5,-1) = [{4=2},{5=15},{9=10}]
+         * (3,2) = [{9=10}]
+         * (3,-2) = [{0=17},{4=2},{5=15},{9=10}]
+         */
+        public static function getByKeyRelativeIndexRange(mixed $return_type, \Aerospike\Expression $key, \Aerospike\Expression $index, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items nearest to key and greater by index with a count limit.
+         *
+         * Examples for ordered map [{0=17},{4=2},{5=15},{9=10}]:
+         *
+         * (key,index,count) = [selected items]
+         * (5,0,1) = [{5=15}]
+         * (5,1,2) = [{9=10}]
+         * (5,-1,1) = [{4=2}]
+         * (3,2,1) = [{9=10}]
+         * (3,-2,2) = [{0=17}]
+         */
+        public static function getByKeyRelativeIndexRangeCount(mixed $return_type, \Aerospike\Expression $key, \Aerospike\Expression $index, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items identified by value and returns selected data
+         * specified by return_type.
+         */
+        public static function getByValue(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items identified by value range (value_begin inclusive,
+         * value_end exclusive). If value_begin is null, the range is less than value_end. If value_end
+         * is null, the range is greater than equal to value_begin.
+         */
+        public static function getByValueRange(mixed $return_type, ?\Aerospike\Expression $value_begin, ?\Aerospike\Expression $value_end, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items identified by values and returns selected data
+         * specified by return_type. `values` is a list expression.
+         */
+        public static function getByValueList(mixed $return_type, \Aerospike\Expression $values, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items nearest to value and greater by relative rank.
+         *
+         * Examples for map [{4=2},{9=10},{5=15},{0=17}]:
+         *
+         * (value,rank) = [selected items]
+         * (11,1) = [{0=17}]
+         * (11,-1) = [{9=10},{5=15},{0=17}]
+         */
+        public static function getByValueRelativeRankRange(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items nearest to value and greater by relative rank with a
+         * count limit.
+         *
+         * Examples for map [{4=2},{9=10},{5=15},{0=17}]:
+         *
+         * (value,rank,count) = [selected items]
+         * (11,1,1) = [{0=17}]
+         * (11,-1,1) = [{9=10}]
+         */
+        public static function getByValueRelativeRankRangeCount(mixed $return_type, \Aerospike\Expression $value, \Aerospike\Expression $rank, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map item identified by index and returns selected data
+         * specified by return_type. `value_type` is the type of the item when the return type
+         * is `MapReturnType::Value()`.
+         */
+        public static function getByIndex(mixed $return_type, mixed $value_type, \Aerospike\Expression $index, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items starting at specified index to the end of map and
+         * returns selected data specified by return_type.
+         */
+        public static function getByIndexRange(mixed $return_type, \Aerospike\Expression $index, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects "count" map items starting at specified index and returns
+         * selected data specified by return_type.
+         */
+        public static function getByIndexRangeCount(mixed $return_type, \Aerospike\Expression $index, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map item identified by rank and returns selected data
+         * specified by return_type. `value_type` is the type of the item when the return type
+         * is `MapReturnType::Value()`.
+         */
+        public static function getByRank(mixed $return_type, mixed $value_type, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects map items starting at specified rank to the last ranked item
+         * and returns selected data specified by return_type.
+         */
+        public static function getByRankRange(mixed $return_type, \Aerospike\Expression $rank, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+
+        /**
+         * Create expression that selects "count" map items starting at specified rank and returns
+         * selected data specified by return_type.
+         */
+        public static function getByRankRangeCount(mixed $return_type, \Aerospike\Expression $rank, \Aerospike\Expression $count, \Aerospike\Expression $bin, ?array $ctx): \Aerospike\Expression {}
+    }
+
+    /**
+     * HyperLogLog (HLL) expression generator. The expressions read HLL bins and can be used in
+     * filter expressions. Requires server version 5.6.0+.
+     *
+     * The `bin` argument is usually `Expression::hllBin()`. `list` arguments are list expressions
+     * of HLL values.
+     */
+    class HllExp {
+        /**
+         * Create expression that returns estimated number of elements in the HLL bin.
+         *
+         * ```php
+         * // Campaigns with more than 1000 unique visitors.
+         * Expression::gt(HllExp::getCount(Expression::hllBin("visitors")), Expression::intVal(1000));
+         * ```
+         */
+        public static function getCount(\Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns an HLL object that is the union of all specified HLL objects
+         * in the list with the HLL bin.
+         */
+        public static function getUnion(\Aerospike\Expression $list, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns estimated number of elements that would be contained by
+         * the union of these HLL objects.
+         */
+        public static function getUnionCount(\Aerospike\Expression $list, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns estimated number of elements that would be contained by
+         * the intersection of these HLL objects.
+         */
+        public static function getIntersectCount(\Aerospike\Expression $list, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns estimated similarity of these HLL objects as a
+         * 64 bit float.
+         */
+        public static function getSimilarity(\Aerospike\Expression $list, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns index_bit_count and min_hash_bit_count used to create
+         * the HLL bin in a list of longs. list[0] is index_bit_count and list[1] is min_hash_bit_count.
+         */
+        public static function describe(\Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns one if the HLL bin may contain all items in the list.
+         */
+        public static function mayContain(\Aerospike\Expression $list, \Aerospike\Expression $bin): \Aerospike\Expression {}
+    }
+
+    /**
+     * Bit expression generator. The expressions read blob bins as bitmaps and can be used in
+     * filter expressions. Requires server version 5.6.0+.
+     *
+     * Offset orientation is left-to-right. Negative offsets are supported. If the offset is
+     * negative, the offset starts backwards from end of the bitmap. Offset and size arguments are
+     * integer expressions, and the `bin` argument is usually `Expression::blobBin()`.
+     */
+    class BitExp {
+        /**
+         * Create expression that returns bits from the blob bin starting at bit_offset for bit_size.
+         *
+         * ```php
+         * // Bin "a" bits = 0b10000000
+         * Expression::eq(
+         *   BitExp::get(Expression::intVal(0), Expression::intVal(1), Expression::blobBin("a")),
+         *   Expression::blobVal([0b10000000]));
+         * ```
+         */
+        public static function get(\Aerospike\Expression $bit_offset, \Aerospike\Expression $bit_size, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns the integer count of set bits from the blob bin starting
+         * at bit_offset for bit_size.
+         */
+        public static function count(\Aerospike\Expression $bit_offset, \Aerospike\Expression $bit_size, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns the integer bit offset of the first specified value bit
+         * in the blob bin starting at bit_offset for bit_size, or -1 if not found.
+         * `value` is a boolean expression.
+         */
+        public static function lscan(\Aerospike\Expression $bit_offset, \Aerospike\Expression $bit_size, \Aerospike\Expression $value, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns the integer bit offset of the last specified value bit
+         * in the blob bin starting at bit_offset for bit_size, or -1 if not found.
+         * `value` is a boolean expression.
+         */
+        public static function rscan(\Aerospike\Expression $bit_offset, \Aerospike\Expression $bit_size, \Aerospike\Expression $value, \Aerospike\Expression $bin): \Aerospike\Expression {}
+
+        /**
+         * Create expression that returns the integer from the blob bin starting at bit_offset for
+         * bit_size. Signed indicates if bits should be treated as a signed number.
+         */
+        public static function getInt(\Aerospike\Expression $bit_offset, \Aerospike\Expression $bit_size, bool $signed, \Aerospike\Expression $bin): \Aerospike\Expression {}
+    }
+
+    /**
+     * ExpReadFlags specifies the expression read operation flags.
+     */
+    class ExpReadFlags {
+        /**
+         * ExpReadFlagDefault is the default.
+         */
+        public static function Default(): \Aerospike\ExpReadFlags {}
+
+        /**
+         * ExpReadFlagEvalNoFail ignores failures caused by the expression resolving to unknown or a
+         * non-bin type.
+         */
+        public static function EvalNoFail(): \Aerospike\ExpReadFlags {}
+    }
+
+    /**
+     * ExpWriteFlags specifies the expression write operation flags.
+     */
+    class ExpWriteFlags {
+        /**
+         * ExpWriteFlagDefault is the default. Allows create or update.
+         */
+        public static function Default(): \Aerospike\ExpWriteFlags {}
+
+        /**
+         * ExpWriteFlagCreateOnly behaves like the following:
+         * If bin does not exist, a new bin will be created.
+         * If bin exists, the operation will be denied.
+         */
+        public static function CreateOnly(): \Aerospike\ExpWriteFlags {}
+
+        /**
+         * ExpWriteFlagUpdateOnly behaves like the following:
+         * If bin exists, the bin will be overwritten.
+         * If bin does not exist, the operation will be denied.
+         */
+        public static function UpdateOnly(): \Aerospike\ExpWriteFlags {}
+
+        /**
+         * ExpWriteFlagAllowDelete deletes the bin if the expression results in nil value,
+         * like `Expression::removeResult()`.
+         */
+        public static function AllowDelete(): \Aerospike\ExpWriteFlags {}
+
+        /**
+         * ExpWriteFlagPolicyNoFail does not raise error if operation is denied.
+         */
+        public static function PolicyNoFail(): \Aerospike\ExpWriteFlags {}
+
+        /**
+         * ExpWriteFlagEvalNoFail ignores failures caused by the expression resolving to unknown or a
+         * non-bin type. Combined with `Expression::unknown()` it leaves the bin untouched.
+         */
+        public static function EvalNoFail(): \Aerospike\ExpWriteFlags {}
+    }
+
+    /**
+     * Expression operations evaluate an expression on the server and either return
+     * the result or write it to a bin. They can be used wherever an Operation is accepted.
+     * Requires server version 5.6.0+.
+     */
+    class ExpOperation {
+        /**
+         * ExpReadOp creates an operation with an expression that reads from a record.
+         * The result is returned under the given name, which does not have to be an existing bin.
+         * Flags are ExpReadFlags. You can specify multiple by `or`ing them together.
+         */
+        public static function read(string $name, \Aerospike\Expression $exp, ?array $flags): \Aerospike\Operation {}
+
+        /**
+         * ExpWriteOp creates an operation with an expression that writes to record bin.
+         * Flags are ExpWriteFlags. You can specify multiple by `or`ing them together.
+         */
+        public static function write(string $bin_name, \Aerospike\Expression $exp, ?array $flags): \Aerospike\Operation {}
+    }
 }
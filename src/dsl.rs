@@ -0,0 +1,1441 @@
+//! Text expression language, compiled into filter expressions by `Expression::parse()`.
+//!
+//! ```text
+//! $.age > 21 and $.country == 'NL'
+//! $.name =~ /^jo/i or $.tags[0] == ?tag
+//! ttl() < 86400 and ($.price:float * $.qty) >= 100.0
+//! $.address.city == 'Amsterdam' and set_name() == 'users'
+//! ```
+//!
+//! - Bins are written as `$.name`, or `$."name"` for names which are not identifiers. The type
+//!   of a bin is inferred from the other operand of a comparison or an arithmetic operation,
+//!   or given explicitly with a type suffix like `$.age:int`. The types are `bool`, `int`,
+//!   `float`, `string`, `list`, `map`, `blob`, `geo` and `hll`.
+//! - List items and map values are selected with paths: `$.list[0]`, `$.map.key`,
+//!   `$.map['key']` and `$.map.items[-1]`. The type of the selected value is inferred like the
+//!   type of a bin. The last step can be any expression, the steps before it must be literals.
+//! - Literals are integers, floats, strings in single or double quotes, `true`, `false`,
+//!   `nil`, lists `[1, 2]`, maps `{'a': 1}`, blobs `x'0aff'` and `geo('{...}')`.
+//! - Placeholders `?0` or `?name` are replaced by the value with that index or key in the
+//!   placeholders array.
+//! - Operators, from the lowest precedence: `or`, `and`, `not`, comparisons (`==`, `!=`, `<`,
+//!   `<=`, `>`, `>=` and `=~ /regex/flags`), `|`, `^`, `&`, shifts (`<<`, `>>`, `>>>`), `+`
+//!   and `-`, `*`, `/` and `%`, unary `-` and `~`, and `**`.
+//! - Functions: the record metadata functions `ttl()`, `void_time()`, `last_update()`,
+//!   `since_update()`, `set_name()`, `key_exists()`, `is_tombstone()`, `device_size()`,
+//!   `memory_size()`, `record_size()`, `digest_modulo(n)`, `key(type)`, `bin_exists('name')`
+//!   and `bin_type('name')`, and `abs`, `floor`, `ceil`, `log`, `min`, `max`, `to_int`,
+//!   `to_float`, `int_not`, `count`, `lscan`, `rscan`, `size`, `geo_compare`, `exclusive`,
+//!   `cond`, `let(x = ..., body)`, `var(x)` and `unknown()`.
+
+use crate::{
+    proto, AerospikeException, AsResult, CDTContext, CdtListReturnType, CdtMapReturnType, ExpType,
+    Expression, ListExp, MapExp, PHPValue, ResultCode,
+};
+
+/// Compiles the text expression `dsl` into an expression. `placeholders` is the PHP array
+/// with the values of the `?0`/`?name` placeholders.
+pub(crate) fn parse(dsl: &str, placeholders: Option<PHPValue>) -> AsResult<Expression> {
+    let placeholders = match placeholders {
+        None | Some(PHPValue::Nil) => vec![],
+        Some(PHPValue::List(l)) => l
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (PHPValue::Int(i as i64), v))
+            .collect(),
+        Some(PHPValue::OrderedMap(m)) => m,
+        Some(PHPValue::HashMap(m)) => m.into_iter().collect(),
+        Some(_) => {
+            return Err(AerospikeException {
+                message: "Expression placeholders must be an array".into(),
+                code: ResultCode::PARAMETER_ERROR,
+                in_doubt: false,
+            })
+        }
+    };
+
+    let mut parser = Parser {
+        chars: dsl.chars().collect(),
+        pos: 0,
+        depth: 0,
+        placeholders,
+    };
+    let node = parser.parse().map_err(|e| e.into_exception())?;
+    Compiler {
+        vars: vec![],
+        depth: 0,
+    }
+    .compile(&node, None)
+    .map_err(|e| e.into_exception())
+}
+
+struct ParseError {
+    col: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn into_exception(self) -> AerospikeException {
+        AerospikeException {
+            message: format!(
+                "Invalid expression at column {}: {}",
+                self.col, self.message
+            ),
+            code: ResultCode::PARAMETER_ERROR,
+            in_doubt: false,
+        }
+    }
+}
+
+type ParseResult<T> = std::result::Result<T, ParseError>;
+
+fn error<T>(col: usize, message: impl Into<String>) -> ParseResult<T> {
+    Err(ParseError {
+        col,
+        message: message.into(),
+    })
+}
+
+/// The deepest nesting of expressions which is parsed and compiled, so that the recursion
+/// stays well within the stack.
+const MAX_DEPTH: usize = 256;
+
+fn too_deep<T>(col: usize) -> ParseResult<T> {
+    error(
+        col,
+        format!("expressions can not be nested more than {MAX_DEPTH} levels deep"),
+    )
+}
+
+/// The magnitude of the lowest integer, which is only valid negated.
+const MIN_INT_MAGNITUDE: &str = "9223372036854775808";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Type {
+    Bool,
+    Int,
+    Float,
+    String,
+    List,
+    Map,
+    Blob,
+    Geo,
+    Hll,
+}
+
+impl Type {
+    fn from_name(name: &str) -> Option<Type> {
+        match name {
+            "bool" => Some(Type::Bool),
+            "int" => Some(Type::Int),
+            "float" => Some(Type::Float),
+            "string" => Some(Type::String),
+            "list" => Some(Type::List),
+            "map" => Some(Type::Map),
+            "blob" => Some(Type::Blob),
+            "geo" => Some(Type::Geo),
+            "hll" => Some(Type::Hll),
+            _ => None,
+        }
+    }
+
+    fn of_value(v: &PHPValue) -> Option<Type> {
+        match v {
+            PHPValue::Bool(_) => Some(Type::Bool),
+            PHPValue::Int(_) | PHPValue::UInt(_) => Some(Type::Int),
+            PHPValue::Float(_) => Some(Type::Float),
+            PHPValue::String(_) => Some(Type::String),
+            PHPValue::Blob(_) => Some(Type::Blob),
+            PHPValue::List(_) => Some(Type::List),
+            PHPValue::HashMap(_) | PHPValue::OrderedMap(_) | PHPValue::Json(_) => Some(Type::Map),
+            PHPValue::GeoJSON(_) => Some(Type::Geo),
+            PHPValue::HLL(_) => Some(Type::Hll),
+            PHPValue::Nil | PHPValue::Infinity | PHPValue::Wildcard => None,
+        }
+    }
+
+    fn exp_type(self) -> ExpType {
+        let t = match self {
+            Type::Bool => proto::ExpType::Bool,
+            Type::Int => proto::ExpType::Int,
+            Type::Float => proto::ExpType::Float,
+            Type::String => proto::ExpType::String,
+            Type::List => proto::ExpType::List,
+            Type::Map => proto::ExpType::Map,
+            Type::Blob => proto::ExpType::Blob,
+            Type::Geo => proto::ExpType::Geo,
+            Type::Hll => proto::ExpType::Hll,
+        };
+        ExpType { _as: t }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Or,
+    And,
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    BitOr,
+    BitXor,
+    BitAnd,
+    BitNot,
+    LShift,
+    RShift,
+    ARShift,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Pow,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Index(Node),
+    Key(Node),
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Value(PHPValue),
+    Bin {
+        name: String,
+        path: Vec<Step>,
+        ty: Option<Type>,
+    },
+    Op(Op, Vec<Node>),
+    Regex(Box<Node>, String, i64),
+    Call(String, Vec<Node>),
+    TypeName(Type),
+    Let(Vec<(String, Node)>, Box<Node>),
+    Var(String),
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    col: usize,
+    kind: Kind,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// The nesting depth of the expression being parsed.
+    depth: usize,
+    placeholders: Vec<(PHPValue, PHPValue)>,
+}
+
+impl Parser {
+    fn parse(&mut self) -> ParseResult<Node> {
+        let node = self.parse_or()?;
+        self.skip_ws();
+        if let Some(c) = self.peek() {
+            return error(self.col(), format!("unexpected `{c}`"));
+        }
+        Ok(node)
+    }
+
+    fn col(&self) -> usize {
+        self.pos + 1
+    }
+
+    /// Runs `parse` one level of nesting deeper.
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        if self.depth >= MAX_DEPTH {
+            return too_deep(self.col());
+        }
+        self.depth += 1;
+        let res = parse(self);
+        self.depth -= 1;
+        res
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, n: usize) -> Option<char> {
+        self.chars.get(self.pos + n).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn looking_at(&self, s: &str) -> bool {
+        let mut chars = self.chars[self.pos..].iter();
+        s.chars().all(|c| chars.next() == Some(&c))
+    }
+
+    /// Consumes `s` if the input continues with it after whitespace.
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_ws();
+        if self.looking_at(s) {
+            self.pos += s.chars().count();
+            return true;
+        }
+        false
+    }
+
+    /// Consumes the keyword `kw` if it is not the prefix of a longer identifier.
+    fn eat_keyword(&mut self, kw: &str) -> bool {
+        self.skip_ws();
+        let start = self.pos;
+        if self.eat(kw) && !self.peek().is_some_and(is_ident_char) {
+            return true;
+        }
+        self.pos = start;
+        false
+    }
+
+    fn expect(&mut self, s: &str) -> ParseResult<()> {
+        if self.eat(s) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(c) => error(self.col(), format!("expected `{s}`, found `{c}`")),
+            None => error(
+                self.col(),
+                format!("expected `{s}`, found the end of the expression"),
+            ),
+        }
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        self.skip_ws();
+        let start = self.pos;
+        if !self
+            .peek()
+            .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+        while self.peek().is_some_and(is_ident_char) {
+            self.pos += 1;
+        }
+        Some(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn binary(
+        &mut self,
+        ops: &[(&str, Op)],
+        next: fn(&mut Self) -> ParseResult<Node>,
+    ) -> ParseResult<Node> {
+        let mut left = next(self)?;
+        // the depth of the chain of operators nested in `left`
+        let mut nesting = 0;
+        'outer: loop {
+            for (token, op) in ops {
+                let matched = if token.chars().all(char::is_alphabetic) {
+                    self.eat_keyword(token)
+                } else {
+                    self.eat_operator(token)
+                };
+                if matched {
+                    let right = next(self)?;
+                    // chains of the same n-ary operator become one expression
+                    left = match left.kind {
+                        Kind::Op(o, mut args) if o == *op && is_n_ary(o) && args.len() > 1 => {
+                            args.push(right);
+                            Node {
+                                col: left.col,
+                                kind: Kind::Op(o, args),
+                            }
+                        }
+                        kind => {
+                            nesting += 1;
+                            if self.depth + nesting > MAX_DEPTH {
+                                return too_deep(left.col);
+                            }
+                            Node {
+                                col: left.col,
+                                kind: Kind::Op(
+                                    *op,
+                                    vec![
+                                        Node {
+                                            col: left.col,
+                                            kind,
+                                        },
+                                        right,
+                                    ],
+                                ),
+                            }
+                        }
+                    };
+                    continue 'outer;
+                }
+            }
+            return Ok(left);
+        }
+    }
+
+    /// Consumes the operator `op` unless it is the prefix of a longer operator.
+    fn eat_operator(&mut self, op: &str) -> bool {
+        let start = self.pos;
+        if !self.eat(op) {
+            return false;
+        }
+        let longer = match op {
+            "<" | ">" | "=" => matches!(self.peek(), Some('=' | '<' | '>' | '~')),
+            ">>" => self.peek() == Some('>'),
+            "*" => self.peek() == Some('*'),
+            _ => false,
+        };
+        if longer {
+            self.pos = start;
+            return false;
+        }
+        true
+    }
+
+    fn parse_or(&mut self) -> ParseResult<Node> {
+        self.binary(&[("or", Op::Or)], Self::parse_and)
+    }
+
+    fn parse_and(&mut self) -> ParseResult<Node> {
+        self.binary(&[("and", Op::And)], Self::parse_not)
+    }
+
+    fn parse_not(&mut self) -> ParseResult<Node> {
+        self.skip_ws();
+        let col = self.col();
+        if self.eat_keyword("not") {
+            let arg = self.nested(Self::parse_not)?;
+            return Ok(Node {
+                col,
+                kind: Kind::Op(Op::Not, vec![arg]),
+            });
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> ParseResult<Node> {
+        let left = self.parse_bit_or()?;
+        if self.eat("=~") {
+            let (pattern, flags) = self.parse_regex()?;
+            return Ok(Node {
+                col: left.col,
+                kind: Kind::Regex(Box::new(left), pattern, flags),
+            });
+        }
+
+        let ops = [
+            ("==", Op::Eq),
+            ("!=", Op::Ne),
+            ("<=", Op::Le),
+            (">=", Op::Ge),
+            ("<", Op::Lt),
+            (">", Op::Gt),
+        ];
+        for (token, op) in ops {
+            if self.eat_operator(token) {
+                let right = self.parse_bit_or()?;
+                self.skip_ws();
+                if ops.iter().any(|(t, _)| self.looking_at(t)) {
+                    return error(self.col(), "comparisons can not be chained, use `and`");
+                }
+                return Ok(Node {
+                    col: left.col,
+                    kind: Kind::Op(op, vec![left, right]),
+                });
+            }
+        }
+        Ok(left)
+    }
+
+    fn parse_regex(&mut self) -> ParseResult<(String, i64)> {
+        self.skip_ws();
+        let col = self.col();
+        let pattern = match self.peek() {
+            Some('/') => {
+                self.pos += 1;
+                let mut pattern = String::new();
+                loop {
+                    match self.peek() {
+                        None => return error(col, "unterminated regular expression"),
+                        Some('/') => break,
+                        Some('\\') if self.peek_at(1) == Some('/') => {
+                            pattern.push('/');
+                            self.pos += 2;
+                        }
+                        Some(c) => {
+                            pattern.push(c);
+                            self.pos += 1;
+                        }
+                    }
+                }
+                self.pos += 1;
+                pattern
+            }
+            Some('\'' | '"') => self.parse_string()?,
+            _ => return error(col, "expected a regular expression like `/^a.*/i`"),
+        };
+
+        let mut flags = 0;
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_alphabetic()) {
+            flags |= match c {
+                'x' => 1,
+                'i' => 2,
+                'n' => 4,
+                'm' => 8,
+                _ => return error(self.col(), format!("unknown regular expression flag `{c}`")),
+            };
+            self.pos += 1;
+        }
+        Ok((pattern, flags))
+    }
+
+    fn parse_bit_or(&mut self) -> ParseResult<Node> {
+        self.binary(&[("|", Op::BitOr)], Self::parse_bit_xor)
+    }
+
+    fn parse_bit_xor(&mut self) -> ParseResult<Node> {
+        self.binary(&[("^", Op::BitXor)], Self::parse_bit_and)
+    }
+
+    fn parse_bit_and(&mut self) -> ParseResult<Node> {
+        self.binary(&[("&", Op::BitAnd)], Self::parse_shift)
+    }
+
+    fn parse_shift(&mut self) -> ParseResult<Node> {
+        self.binary(
+            &[("<<", Op::LShift), (">>>", Op::RShift), (">>", Op::ARShift)],
+            Self::parse_additive,
+        )
+    }
+
+    fn parse_additive(&mut self) -> ParseResult<Node> {
+        self.binary(
+            &[("+", Op::Add), ("-", Op::Sub)],
+            Self::parse_multiplicative,
+        )
+    }
+
+    fn parse_multiplicative(&mut self) -> ParseResult<Node> {
+        self.binary(
+            &[("*", Op::Mul), ("/", Op::Div), ("%", Op::Mod)],
+            Self::parse_unary,
+        )
+    }
+
+    fn parse_unary(&mut self) -> ParseResult<Node> {
+        self.skip_ws();
+        let col = self.col();
+        if self.eat("-") {
+            if self.eat_min_int_magnitude() {
+                return Ok(Node {
+                    col,
+                    kind: Kind::Value(PHPValue::Int(i64::MIN)),
+                });
+            }
+            let arg = self.nested(Self::parse_unary)?;
+            return Ok(match arg.kind {
+                Kind::Value(PHPValue::Int(i)) => match i.checked_neg() {
+                    Some(i) => Node {
+                        col,
+                        kind: Kind::Value(PHPValue::Int(i)),
+                    },
+                    None => return error(col, "integer out of range"),
+                },
+                Kind::Value(PHPValue::Float(f)) => Node {
+                    col,
+                    kind: Kind::Value(PHPValue::Float(-f)),
+                },
+                kind => Node {
+                    col,
+                    kind: Kind::Op(Op::Sub, vec![Node { col: arg.col, kind }]),
+                },
+            });
+        }
+        if self.eat("~") {
+            let arg = self.nested(Self::parse_unary)?;
+            return Ok(Node {
+                col,
+                kind: Kind::Op(Op::BitNot, vec![arg]),
+            });
+        }
+        self.parse_power()
+    }
+
+    /// Consumes the magnitude of the lowest integer if it is the whole operand of a unary `-`.
+    fn eat_min_int_magnitude(&mut self) -> bool {
+        self.skip_ws();
+        let start = self.pos;
+        if !self.looking_at(MIN_INT_MAGNITUDE) {
+            return false;
+        }
+        self.pos += MIN_INT_MAGNITUDE.len();
+        let number_continues = self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+        if number_continues || self.looking_at_power() {
+            self.pos = start;
+            return false;
+        }
+        true
+    }
+
+    fn looking_at_power(&mut self) -> bool {
+        let start = self.pos;
+        let power = self.eat("**");
+        self.pos = start;
+        power
+    }
+
+    fn parse_power(&mut self) -> ParseResult<Node> {
+        let base = self.parse_primary()?;
+        if self.eat("**") {
+            // right associative
+            let exponent = self.nested(Self::parse_unary)?;
+            return Ok(Node {
+                col: base.col,
+                kind: Kind::Op(Op::Pow, vec![base, exponent]),
+            });
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<Node> {
+        self.skip_ws();
+        let col = self.col();
+        let value = |v: PHPValue| {
+            Ok(Node {
+                col,
+                kind: Kind::Value(v),
+            })
+        };
+
+        match self.peek() {
+            None => error(col, "unexpected end of the expression"),
+            Some('(') => {
+                self.pos += 1;
+                let node = self.nested(Self::parse_or)?;
+                self.expect(")")?;
+                Ok(node)
+            }
+            Some('$') => self.nested(Self::parse_bin),
+            Some('?') => self.parse_placeholder(),
+            Some('\'' | '"') => value(PHPValue::String(self.parse_string()?)),
+            Some('[' | '{') => value(self.parse_literal()?),
+            Some(c) if c.is_ascii_digit() => value(self.parse_number()?),
+            Some('x') if matches!(self.peek_at(1), Some('\'' | '"')) => value(self.parse_blob()?),
+            Some(c) if c == '_' || c.is_ascii_alphabetic() => {
+                let name = self.ident().unwrap_or_default();
+                match name.as_str() {
+                    "true" => return value(PHPValue::Bool(true)),
+                    "false" => return value(PHPValue::Bool(false)),
+                    "nil" | "null" => return value(PHPValue::Nil),
+                    _ => (),
+                }
+                if !self.eat("(") {
+                    return error(col, format!("unknown identifier `{name}`"));
+                }
+                self.nested(|p| p.parse_call(name, col))
+            }
+            Some(c) => error(col, format!("unexpected `{c}`")),
+        }
+    }
+
+    fn parse_call(&mut self, name: String, col: usize) -> ParseResult<Node> {
+        match name.as_str() {
+            "let" => {
+                let mut defs = vec![];
+                loop {
+                    let start = self.pos;
+                    if let Some(var) = self.ident() {
+                        if self.eat_operator("=") {
+                            defs.push((var, self.parse_or()?));
+                            self.expect(",")?;
+                            continue;
+                        }
+                    }
+                    self.pos = start;
+                    let body = self.parse_or()?;
+                    self.expect(")")?;
+                    if defs.is_empty() {
+                        return error(col, "`let()` needs at least one `name = value` definition");
+                    }
+                    return Ok(Node {
+                        col,
+                        kind: Kind::Let(defs, Box::new(body)),
+                    });
+                }
+            }
+            "var" => {
+                let var = match self.ident() {
+                    Some(var) => var,
+                    None => return error(self.col(), "expected a variable name"),
+                };
+                self.expect(")")?;
+                Ok(Node {
+                    col,
+                    kind: Kind::Var(var),
+                })
+            }
+            "key" => {
+                self.skip_ws();
+                let type_col = self.col();
+                let ty = self.ident().and_then(|t| Type::from_name(&t));
+                let ty = match ty {
+                    Some(ty) => ty,
+                    None => {
+                        return error(type_col, "expected a type like `int`, `string` or `blob`")
+                    }
+                };
+                self.expect(")")?;
+                Ok(Node {
+                    col,
+                    kind: Kind::Call(
+                        name,
+                        vec![Node {
+                            col: type_col,
+                            kind: Kind::TypeName(ty),
+                        }],
+                    ),
+                })
+            }
+            _ => {
+                let mut args = vec![];
+                if !self.eat(")") {
+                    loop {
+                        args.push(self.parse_or()?);
+                        if self.eat(")") {
+                            break;
+                        }
+                        self.expect(",")?;
+                    }
+                }
+                Ok(Node {
+                    col,
+                    kind: Kind::Call(name, args),
+                })
+            }
+        }
+    }
+
+    fn parse_bin(&mut self) -> ParseResult<Node> {
+        let col = self.col();
+        self.pos += 1;
+        if self.peek() != Some('.') {
+            return error(
+                self.col(),
+                "expected `.` after `$`, bins are written as `$.name`",
+            );
+        }
+        self.pos += 1;
+        let name = self.parse_name()?;
+
+        let mut path = vec![];
+        loop {
+            let step_col = self.col();
+            if self.peek() == Some('[') {
+                self.pos += 1;
+                let step = self.parse_or()?;
+                self.expect("]")?;
+                match step.kind {
+                    Kind::Value(PHPValue::String(_)) => path.push(Step::Key(step)),
+                    _ => path.push(Step::Index(step)),
+                }
+            } else if self.peek() == Some('.') {
+                self.pos += 1;
+                let key = self.parse_name()?;
+                path.push(Step::Key(Node {
+                    col: step_col + 1,
+                    kind: Kind::Value(PHPValue::String(key)),
+                }));
+            } else {
+                break;
+            }
+        }
+
+        let mut ty = None;
+        if self.peek() == Some(':') {
+            self.pos += 1;
+            let type_col = self.col();
+            ty = match self.ident().and_then(|t| Type::from_name(&t)) {
+                Some(ty) => Some(ty),
+                None => return error(type_col, "expected a type like `int`, `string` or `list`"),
+            };
+        }
+
+        Ok(Node {
+            col,
+            kind: Kind::Bin { name, path, ty },
+        })
+    }
+
+    fn parse_name(&mut self) -> ParseResult<String> {
+        match self.peek() {
+            Some('\'' | '"') => self.parse_string(),
+            Some(c) if is_ident_char(c) => {
+                let start = self.pos;
+                while self.peek().is_some_and(is_ident_char) {
+                    self.pos += 1;
+                }
+                Ok(self.chars[start..self.pos].iter().collect())
+            }
+            _ => error(self.col(), "expected a name"),
+        }
+    }
+
+    fn parse_placeholder(&mut self) -> ParseResult<Node> {
+        let col = self.col();
+        self.pos += 1;
+        let start = self.pos;
+        while self.peek().is_some_and(is_ident_char) {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().collect();
+        let key = match name.parse::<i64>() {
+            Ok(i) => PHPValue::Int(i),
+            Err(_) if !name.is_empty() => PHPValue::String(name.clone()),
+            Err(_) => return error(self.col(), "expected a placeholder index or name"),
+        };
+        match self.placeholders.iter().find(|(k, _)| *k == key) {
+            Some((_, v)) => Ok(Node {
+                col,
+                kind: Kind::Value(v.clone()),
+            }),
+            None => error(col, format!("no value for the placeholder `?{name}`")),
+        }
+    }
+
+    fn parse_string(&mut self) -> ParseResult<String> {
+        let col = self.col();
+        let quote = self.peek().unwrap_or('\'');
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                None => return error(col, "unterminated string"),
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    let c = match self.peek() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('0') => '\0',
+                        Some('u') if self.peek_at(1) == Some('{') => {
+                            let start = self.pos + 2;
+                            let end = match self.chars[start..].iter().position(|c| *c == '}') {
+                                Some(end) => start + end,
+                                None => return error(self.col(), "unterminated unicode escape"),
+                            };
+                            let hex: String = self.chars[start..end].iter().collect();
+                            match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                                Some(c) => {
+                                    self.pos = end;
+                                    c
+                                }
+                                None => return error(self.col(), "invalid unicode escape"),
+                            }
+                        }
+                        Some(c) => c,
+                        None => return error(col, "unterminated string"),
+                    };
+                    s.push(c);
+                    self.pos += 1;
+                }
+                Some(c) => {
+                    s.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> ParseResult<PHPValue> {
+        let col = self.col();
+        let start = self.pos;
+        if self.peek() == Some('0') && matches!(self.peek_at(1), Some('x' | 'X')) {
+            self.pos += 2;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.pos += 1;
+            }
+            let hex: String = self.chars[start + 2..self.pos].iter().collect();
+            return match u64::from_str_radix(&hex, 16) {
+                Ok(i) => Ok(PHPValue::Int(i as i64)),
+                Err(_) => error(col, "invalid hexadecimal integer"),
+            };
+        }
+
+        let mut float = false;
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => (),
+                '.' if self.peek_at(1).is_some_and(|c| c.is_ascii_digit()) => float = true,
+                'e' | 'E' => {
+                    float = true;
+                    if matches!(self.peek_at(1), Some('+' | '-')) {
+                        self.pos += 1;
+                    }
+                }
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        let s: String = self.chars[start..self.pos].iter().collect();
+        if float {
+            match s.parse::<f64>() {
+                Ok(f) => Ok(PHPValue::Float(ordered_float::OrderedFloat(f))),
+                Err(_) => error(col, format!("invalid number `{s}`")),
+            }
+        } else {
+            match s.parse::<i64>() {
+                Ok(i) => Ok(PHPValue::Int(i)),
+                Err(_) if s.len() >= MIN_INT_MAGNITUDE.len() => {
+                    error(col, format!("integer `{s}` out of range"))
+                }
+                Err(_) => error(col, format!("invalid integer `{s}`")),
+            }
+        }
+    }
+
+    fn parse_blob(&mut self) -> ParseResult<PHPValue> {
+        let col = self.col();
+        self.pos += 1;
+        match hex::decode(self.parse_string()?) {
+            Ok(b) => Ok(PHPValue::Blob(b)),
+            Err(_) => error(col, "invalid hexadecimal blob"),
+        }
+    }
+
+    /// Parses a literal value, used for list and map literals and their items.
+    fn parse_literal(&mut self) -> ParseResult<PHPValue> {
+        self.skip_ws();
+        let col = self.col();
+        match self.peek() {
+            Some('[') => {
+                self.pos += 1;
+                let mut l = vec![];
+                if !self.eat("]") {
+                    loop {
+                        l.push(self.nested(Self::parse_literal)?);
+                        if self.eat("]") {
+                            break;
+                        }
+                        self.expect(",")?;
+                    }
+                }
+                Ok(PHPValue::List(l))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let mut m = vec![];
+                if !self.eat("}") {
+                    loop {
+                        let k = self.nested(Self::parse_literal)?;
+                        self.expect(":")?;
+                        m.push((k, self.nested(Self::parse_literal)?));
+                        if self.eat("}") {
+                            break;
+                        }
+                        self.expect(",")?;
+                    }
+                }
+                Ok(PHPValue::OrderedMap(m))
+            }
+            _ => {
+                let node = self.parse_unary()?;
+                match node.kind {
+                    Kind::Value(v) => Ok(v),
+                    _ => error(col, "list and map literals can only contain literal values"),
+                }
+            }
+        }
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+fn is_n_ary(op: Op) -> bool {
+    matches!(
+        op,
+        Op::Or
+            | Op::And
+            | Op::Add
+            | Op::Sub
+            | Op::Mul
+            | Op::Div
+            | Op::BitOr
+            | Op::BitXor
+            | Op::BitAnd
+    )
+}
+
+struct Compiler {
+    /// The variables defined by the enclosing `let` expressions, with their types.
+    vars: Vec<(String, Option<Type>)>,
+    /// The nesting depth of the expression being compiled.
+    depth: usize,
+}
+
+impl Compiler {
+    /// Returns the type of the expression if it does not depend on where it is used.
+    fn type_of(&self, node: &Node) -> Option<Type> {
+        match &node.kind {
+            Kind::Value(v) => Type::of_value(v),
+            Kind::Bin { ty, .. } => *ty,
+            Kind::Regex(..) => Some(Type::Bool),
+            Kind::TypeName(_) => None,
+            Kind::Op(op, args) => match op {
+                Op::Or | Op::And | Op::Not => Some(Type::Bool),
+                Op::Eq | Op::Ne | Op::Lt | Op::Le | Op::Gt | Op::Ge => Some(Type::Bool),
+                Op::BitOr | Op::BitXor | Op::BitAnd | Op::BitNot => Some(Type::Int),
+                Op::LShift | Op::RShift | Op::ARShift | Op::Mod => Some(Type::Int),
+                Op::Pow => Some(Type::Float),
+                Op::Add | Op::Sub | Op::Mul | Op::Div => args.iter().find_map(|a| self.type_of(a)),
+            },
+            Kind::Call(name, args) => match name.as_str() {
+                "ttl" | "void_time" | "last_update" | "since_update" | "device_size"
+                | "memory_size" | "record_size" | "digest_modulo" | "bin_type" | "to_int"
+                | "int_not" | "count" | "lscan" | "rscan" | "size" => Some(Type::Int),
+                "set_name" => Some(Type::String),
+                "key_exists" | "is_tombstone" | "bin_exists" | "geo_compare" | "exclusive" => {
+                    Some(Type::Bool)
+                }
+                "to_float" | "floor" | "ceil" | "log" => Some(Type::Float),
+                "key" => match args.first().map(|a| &a.kind) {
+                    Some(Kind::TypeName(ty)) => Some(*ty),
+                    _ => None,
+                },
+                "abs" | "min" | "max" => args.iter().find_map(|a| self.type_of(a)),
+                "geo" => Some(Type::Geo),
+                "cond" => args
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| i % 2 == 1 || *i == args.len() - 1)
+                    .find_map(|(_, a)| self.type_of(a)),
+                _ => None,
+            },
+            Kind::Let(defs, body) => {
+                let mut scope = Compiler {
+                    vars: self.vars.clone(),
+                    depth: self.depth,
+                };
+                for (var, value) in defs {
+                    let ty = scope.type_of(value);
+                    scope.vars.push((var.clone(), ty));
+                }
+                scope.type_of(body)
+            }
+            Kind::Var(var) => self
+                .vars
+                .iter()
+                .rev()
+                .find(|(name, _)| name == var)
+                .and_then(|(_, ty)| *ty),
+        }
+    }
+
+    fn compile(&mut self, node: &Node, expected: Option<Type>) -> ParseResult<Expression> {
+        if self.depth >= MAX_DEPTH {
+            return too_deep(node.col);
+        }
+        self.depth += 1;
+        let res = self.compile_node(node, expected);
+        self.depth -= 1;
+        res
+    }
+
+    fn compile_node(&mut self, node: &Node, expected: Option<Type>) -> ParseResult<Expression> {
+        let col = node.col;
+        match &node.kind {
+            Kind::Value(v) => compile_value(v, col),
+            Kind::TypeName(_) => error(col, "unexpected type name"),
+            Kind::Bin { name, path, ty } => {
+                let ty = match ty.or(expected) {
+                    Some(ty) => ty,
+                    None => {
+                        return error(
+                            col,
+                            format!(
+                            "can not infer the type of `$.{name}`, add a type like `$.{name}:int`"
+                        ),
+                        )
+                    }
+                };
+                self.compile_bin(name, path, ty)
+            }
+            Kind::Regex(arg, pattern, flags) => {
+                let arg = self.compile(arg, Some(Type::String))?;
+                Ok(Expression::regex_compare(pattern.clone(), *flags, &arg))
+            }
+            Kind::Op(op, args) => self.compile_op(*op, args, expected, col),
+            Kind::Call(name, args) => self.compile_call(name, args, expected, col),
+            Kind::Let(defs, body) => {
+                let depth = self.vars.len();
+                let mut exps = vec![];
+                for (var, value) in defs {
+                    let ty = self.type_of(value);
+                    exps.push(Expression::def(var.clone(), &self.compile(value, ty)?));
+                    self.vars.push((var.clone(), ty));
+                }
+                exps.push(self.compile(body, expected)?);
+                self.vars.truncate(depth);
+                Ok(Expression::exp_let(exps.iter().collect()))
+            }
+            Kind::Var(var) => {
+                if !self.vars.iter().any(|(name, _)| name == var) {
+                    return error(col, format!("unknown variable `{var}`"));
+                }
+                Ok(Expression::var(var.clone()))
+            }
+        }
+    }
+
+    fn compile_all(&mut self, args: &[Node], ty: Option<Type>) -> ParseResult<Vec<Expression>> {
+        args.iter().map(|a| self.compile(a, ty)).collect()
+    }
+
+    /// Returns the common type of the operands, which is used for the operands without a type.
+    fn operand_type(&self, args: &[Node], expected: Option<Type>, col: usize) -> ParseResult<Type> {
+        match args.iter().find_map(|a| self.type_of(a)).or(expected) {
+            Some(ty) => Ok(ty),
+            None => error(
+                col,
+                "can not infer the type of the operands, add a type to a bin like `$.name:int`",
+            ),
+        }
+    }
+
+    fn compile_op(
+        &mut self,
+        op: Op,
+        args: &[Node],
+        expected: Option<Type>,
+        col: usize,
+    ) -> ParseResult<Expression> {
+        let numeric = |ty: Option<Type>| ty.filter(|t| matches!(t, Type::Int | Type::Float));
+        let exps = match op {
+            Op::Or | Op::And | Op::Not => self.compile_all(args, Some(Type::Bool))?,
+            Op::Eq | Op::Ne | Op::Lt | Op::Le | Op::Gt | Op::Ge => {
+                let ty = self.operand_type(args, None, col)?;
+                self.compile_all(args, Some(ty))?
+            }
+            Op::Add | Op::Sub | Op::Mul | Op::Div => {
+                let ty = self.operand_type(args, numeric(expected), col)?;
+                self.compile_all(args, Some(ty))?
+            }
+            Op::Pow => self.compile_all(args, Some(Type::Float))?,
+            _ => self.compile_all(args, Some(Type::Int))?,
+        };
+        let refs: Vec<&Expression> = exps.iter().collect();
+
+        Ok(match op {
+            Op::Or => Expression::or(refs),
+            Op::And => Expression::and(refs),
+            Op::Not => Expression::not(refs[0]),
+            Op::Eq => Expression::eq(refs[0], refs[1]),
+            Op::Ne => Expression::ne(refs[0], refs[1]),
+            Op::Lt => Expression::lt(refs[0], refs[1]),
+            Op::Le => Expression::le(refs[0], refs[1]),
+            Op::Gt => Expression::gt(refs[0], refs[1]),
+            Op::Ge => Expression::ge(refs[0], refs[1]),
+            Op::BitOr => Expression::int_or(refs),
+            Op::BitXor => Expression::int_xor(refs),
+            Op::BitAnd => Expression::int_and(refs),
+            Op::BitNot => Expression::int_not(refs[0]),
+            Op::LShift => Expression::int_lshift(refs[0], refs[1]),
+            Op::RShift => Expression::int_rshift(refs[0], refs[1]),
+            Op::ARShift => Expression::int_arshift(refs[0], refs[1]),
+            Op::Add => Expression::num_add(refs),
+            Op::Sub => Expression::num_sub(refs),
+            Op::Mul => Expression::num_mul(refs),
+            Op::Div => Expression::num_div(refs),
+            Op::Mod => Expression::num_mod(refs[0], refs[1]),
+            Op::Pow => Expression::num_pow(refs[0], refs[1]),
+        })
+    }
+
+    fn compile_call(
+        &mut self,
+        name: &str,
+        args: &[Node],
+        expected: Option<Type>,
+        col: usize,
+    ) -> ParseResult<Expression> {
+        let arity = |n: usize| -> ParseResult<()> {
+            if args.len() != n {
+                return error(
+                    col,
+                    format!("`{name}()` takes {n} argument(s), {} given", args.len()),
+                );
+            }
+            Ok(())
+        };
+
+        let metadata = match name {
+            "ttl" => Some(Expression::ttl as fn() -> Expression),
+            "void_time" => Some(Expression::void_time as fn() -> Expression),
+            "last_update" => Some(Expression::last_update as fn() -> Expression),
+            "since_update" => Some(Expression::since_update as fn() -> Expression),
+            "set_name" => Some(Expression::set_name as fn() -> Expression),
+            "key_exists" => Some(Expression::key_exists as fn() -> Expression),
+            "is_tombstone" => Some(Expression::is_tombstone as fn() -> Expression),
+            "device_size" => Some(Expression::device_size as fn() -> Expression),
+            "memory_size" => Some(Expression::memory_size as fn() -> Expression),
+            "record_size" => Some(record_size as fn() -> Expression),
+            "unknown" => Some(Expression::unknown as fn() -> Expression),
+            _ => None,
+        };
+        if let Some(f) = metadata {
+            arity(0)?;
+            return Ok(f());
+        }
+
+        match name {
+            "digest_modulo" => {
+                arity(1)?;
+                match literal_arg(name, &args[0])? {
+                    PHPValue::Int(i) => Ok(Expression::digest_modulo(*i)),
+                    _ => error(args[0].col, "`digest_modulo()` takes an integer"),
+                }
+            }
+            "bin_exists" | "bin_type" => {
+                arity(1)?;
+                match literal_arg(name, &args[0])? {
+                    PHPValue::String(bin) if name == "bin_exists" => {
+                        Ok(Expression::bin_exists(bin.clone()))
+                    }
+                    PHPValue::String(bin) => Ok(Expression::bin_type(bin.clone())),
+                    _ => error(args[0].col, format!("`{name}()` takes a bin name string")),
+                }
+            }
+            "key" => match args.first().map(|a| &a.kind) {
+                Some(Kind::TypeName(ty)) => Ok(Expression::key(ty.exp_type())),
+                _ => error(col, "`key()` takes a type"),
+            },
+            "geo" => {
+                arity(1)?;
+                match literal_arg(name, &args[0])? {
+                    PHPValue::String(geo) => Ok(Expression::geo_val(geo.clone())),
+                    _ => error(args[0].col, "`geo()` takes a GeoJSON string"),
+                }
+            }
+            "abs" | "min" | "max" => {
+                if name == "abs" {
+                    arity(1)?;
+                } else if args.is_empty() {
+                    return error(col, format!("`{name}()` takes at least one argument"));
+                }
+                let numeric = expected.filter(|t| matches!(t, Type::Int | Type::Float));
+                let ty = self.operand_type(args, numeric, col)?;
+                let exps = self.compile_all(args, Some(ty))?;
+                let refs: Vec<&Expression> = exps.iter().collect();
+                Ok(match name {
+                    "abs" => Expression::num_abs(refs[0]),
+                    "min" => Expression::min(refs),
+                    _ => Expression::max(refs),
+                })
+            }
+            "floor" | "ceil" | "to_int" => {
+                arity(1)?;
+                let arg = self.compile(&args[0], Some(Type::Float))?;
+                Ok(match name {
+                    "floor" => Expression::num_floor(&arg),
+                    "ceil" => Expression::num_ceil(&arg),
+                    _ => Expression::to_int(&arg),
+                })
+            }
+            "to_float" | "int_not" | "count" => {
+                arity(1)?;
+                let arg = self.compile(&args[0], Some(Type::Int))?;
+                Ok(match name {
+                    "to_float" => Expression::to_float(&arg),
+                    "int_not" => Expression::int_not(&arg),
+                    _ => Expression::int_count(&arg),
+                })
+            }
+            "log" => {
+                arity(2)?;
+                let exps = self.compile_all(args, Some(Type::Float))?;
+                Ok(Expression::num_log(&exps[0], &exps[1]))
+            }
+            "lscan" | "rscan" => {
+                arity(2)?;
+                let value = self.compile(&args[0], Some(Type::Int))?;
+                let search = self.compile(&args[1], Some(Type::Bool))?;
+                Ok(match name {
+                    "lscan" => Expression::int_lscan(&value, &search),
+                    _ => Expression::int_rscan(&value, &search),
+                })
+            }
+            "geo_compare" => {
+                arity(2)?;
+                let exps = self.compile_all(args, Some(Type::Geo))?;
+                Ok(Expression::geo_compare(&exps[0], &exps[1]))
+            }
+            "exclusive" => {
+                let exps = self.compile_all(args, Some(Type::Bool))?;
+                Ok(Expression::xor(exps.iter().collect()))
+            }
+            "size" => {
+                arity(1)?;
+                match self.type_of(&args[0]) {
+                    Some(Type::List) => {
+                        let bin = self.compile(&args[0], Some(Type::List))?;
+                        Ok(ListExp::size(&bin, None))
+                    }
+                    Some(Type::Map) => {
+                        let bin = self.compile(&args[0], Some(Type::Map))?;
+                        Ok(MapExp::size(&bin, None))
+                    }
+                    _ => error(
+                        args[0].col,
+                        "`size()` takes a list or a map, add a type like `$.name:list`",
+                    ),
+                }
+            }
+            "cond" => {
+                if args.len() < 3 || args.len() % 2 != 1 {
+                    return error(
+                        col,
+                        "`cond()` takes pairs of a condition and a value, followed by a default value",
+                    );
+                }
+                let ty = args
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| i % 2 == 1 || *i == args.len() - 1)
+                    .find_map(|(_, a)| self.type_of(a))
+                    .or(expected);
+                let mut exps = vec![];
+                for (i, arg) in args.iter().enumerate() {
+                    let is_condition = i % 2 == 0 && i != args.len() - 1;
+                    exps.push(self.compile(arg, if is_condition { Some(Type::Bool) } else { ty })?);
+                }
+                Ok(Expression::cond(exps.iter().collect()))
+            }
+            _ => error(col, format!("unknown function `{name}()`")),
+        }
+    }
+
+    fn compile_bin(&mut self, name: &str, path: &[Step], ty: Type) -> ParseResult<Expression> {
+        let bin_of = |ty: Type| {
+            Expression::new(
+                Some(proto::ExpOp::Bin.into()),
+                Some(PHPValue::String(name.to_string())),
+                None,
+                None,
+                Some(ty.exp_type()),
+                vec![],
+            )
+        };
+
+        let (last, steps) = match path.split_last() {
+            Some(split) => split,
+            None => return Ok(bin_of(ty)),
+        };
+
+        // the steps before the last one are the context of the last one
+        let mut ctx = vec![];
+        for step in steps {
+            let (Step::Index(node) | Step::Key(node)) = step;
+            let value = match &node.kind {
+                Kind::Value(v) => v.clone(),
+                _ => {
+                    return error(
+                        node.col,
+                        "only the last step of a path can be an expression",
+                    )
+                }
+            };
+            ctx.push(match (step, value) {
+                (Step::Index(_), PHPValue::Int(i)) => match i32::try_from(i) {
+                    Ok(i) => CDTContext::ListIndex(i),
+                    Err(_) => return error(node.col, "list index out of range"),
+                },
+                (Step::Index(_), _) => return error(node.col, "list indexes must be integers"),
+                (Step::Key(_), key) => CDTContext::MapKey(key),
+            });
+        }
+        let ctx: Option<Vec<&CDTContext>> = (!ctx.is_empty()).then(|| ctx.iter().collect());
+
+        let container = match path.first() {
+            Some(Step::Index(_)) => Type::List,
+            _ => Type::Map,
+        };
+        let bin = bin_of(container);
+
+        match last {
+            Step::Index(index) => {
+                let index = self.compile(index, Some(Type::Int))?;
+                Ok(ListExp::get_by_index(
+                    CdtListReturnType::Value(),
+                    ty.exp_type(),
+                    &index,
+                    &bin,
+                    ctx,
+                ))
+            }
+            Step::Key(key) => {
+                let key_type = self.type_of(key).unwrap_or(Type::String);
+                let key = self.compile(key, Some(key_type))?;
+                Ok(MapExp::get_by_key(
+                    CdtMapReturnType::Value(),
+                    ty.exp_type(),
+                    &key,
+                    &bin,
+                    ctx,
+                ))
+            }
+        }
+    }
+}
+
+fn literal_arg<'a>(name: &str, arg: &'a Node) -> ParseResult<&'a PHPValue> {
+    match &arg.kind {
+        Kind::Value(v) => Ok(v),
+        _ => error(
+            arg.col,
+            format!("the arguments of `{name}()` must be literals"),
+        ),
+    }
+}
+
+fn record_size() -> Expression {
+    Expression::new(
+        Some(proto::ExpOp::RecordSize.into()),
+        None,
+        None,
+        None,
+        None,
+        vec![],
+    )
+}
+
+fn compile_value(v: &PHPValue, col: usize) -> ParseResult<Expression> {
+    Ok(match v {
+        PHPValue::Nil => Expression::nil(),
+        PHPValue::Bool(b) => Expression::bool_val(*b),
+        PHPValue::Int(i) => Expression::int_val(*i),
+        PHPValue::UInt(i) => Expression::int_val(*i as i64),
+        PHPValue::Float(f) => Expression::float_val(f.into_inner()),
+        PHPValue::String(s) => Expression::string_val(s.clone()),
+        PHPValue::Blob(b) => Expression::blob_val(b.clone()),
+        PHPValue::List(l) => Expression::list_val(l.clone()),
+        PHPValue::HashMap(_) | PHPValue::OrderedMap(_) | PHPValue::Json(_) => {
+            Expression::new(None, Some(v.clone()), None, None, None, vec![])
+        }
+        PHPValue::GeoJSON(g) => Expression::geo_val(g.clone()),
+        PHPValue::Infinity => Expression::infinity(),
+        PHPValue::Wildcard => Expression::wildcard(),
+        PHPValue::HLL(_) => return error(col, "HLL values are not supported in expressions"),
+    })
+}
@@ -1,6 +1,8 @@
 use tokio::runtime::{Builder, Runtime};
 
 use std::convert::TryFrom;
+use std::future::Future;
+use std::time::{Duration, Instant};
 use tokio::net::UnixStream;
 use tonic::transport::{Endpoint, Uri};
 
@@ -16,6 +18,158 @@ use crate::proto::kvs_client::KvsClient;
 type StdError = Box<dyn std::error::Error + Send + Sync + 'static>;
 type Result<T, E = StdError> = ::std::result::Result<T, E>;
 
+/// The time added to the total timeout of a request's policy for the deadline of the call, to
+/// leave the connection manager the time to report its own timeout first.
+pub const DEFAULT_TIMEOUT_MARGIN: Duration = Duration::from_millis(1000);
+
+/// The timeout used for the deadline of requests which have no time limit of their own.
+pub const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deadlines of the calls to the connection manager. They are set when connecting, and are the
+/// same for all the clients sharing the connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deadlines {
+    /// Added to the timeout of a request for the deadline of its call.
+    pub timeout_margin: Duration,
+    /// Used in place of the total timeout of requests which have no time limit.
+    pub default_timeout: Duration,
+}
+
+impl Default for Deadlines {
+    fn default() -> Self {
+        Deadlines {
+            timeout_margin: DEFAULT_TIMEOUT_MARGIN,
+            default_timeout: DEFAULT_CALL_TIMEOUT,
+        }
+    }
+}
+
+/// A stream of responses from the connection manager. Each response must arrive before the
+/// deadline of the call that opened the stream, counted from the previous one.
+pub struct Stream<T> {
+    inner: tonic::Streaming<T>,
+    deadline: Duration,
+}
+
+/// Requests which carry a policy with a timeout.
+pub trait PolicyTimeout {
+    /// Returns the total timeout of the request's policy, or `None` if it has no time limit.
+    fn total_timeout(&self) -> Option<Duration>;
+}
+
+// Implements `PolicyTimeout` for each request with the path to the timeout of its policy, in
+// milliseconds. A zero timeout means no time limit.
+macro_rules! policy_timeout {
+    ($($request:ident => |$r:ident| $timeout:expr,)*) => {
+        $(
+            impl PolicyTimeout for proto::$request {
+                fn total_timeout(&self) -> Option<Duration> {
+                    let $r = self;
+                    let millis: Option<u64> = $timeout;
+                    millis.filter(|&t| t > 0).map(Duration::from_millis)
+                }
+            }
+        )*
+    };
+}
+
+policy_timeout! {
+    AerospikeVersionRequest => |_r| None,
+    AerospikeGetRequest => |r| r.policy.as_ref().map(|p| p.total_timeout),
+    AerospikeGetHeaderRequest => |r| r.policy.as_ref().map(|p| p.total_timeout),
+    AerospikeExistsRequest => |r| r.policy.as_ref().map(|p| p.total_timeout),
+    AerospikeListUdfRequest => |r| r.policy.as_ref().map(|p| p.total_timeout),
+    AerospikePutRequest => |r| write_timeout(&r.policy),
+    AerospikeDeleteRequest => |r| write_timeout(&r.policy),
+    AerospikeTouchRequest => |r| write_timeout(&r.policy),
+    AerospikeOperateRequest => |r| write_timeout(&r.policy),
+    AerospikeCreateIndexRequest => |r| write_timeout(&r.policy),
+    AerospikeDropIndexRequest => |r| write_timeout(&r.policy),
+    AerospikeRegisterUdfRequest => |r| write_timeout(&r.policy),
+    AerospikeDropUdfRequest => |r| write_timeout(&r.policy),
+    AerospikeUdfExecuteRequest => |r| write_timeout(&r.policy),
+    AerospikeBatchOperateRequest => |r| {
+        r.policy.as_ref().and_then(|p| p.policy.as_ref()).map(|p| p.total_timeout)
+    },
+    AerospikeScanRequest => |r| {
+        multi_timeout(r.policy.as_ref().and_then(|p| p.multi_policy.as_ref()))
+    },
+    AerospikeQueryRequest => |r| {
+        multi_timeout(r.policy.as_ref().and_then(|p| p.multi_policy.as_ref()))
+    },
+    AerospikeQueryAggregateRequest => |r| {
+        multi_timeout(r.policy.as_ref().and_then(|p| p.multi_policy.as_ref()))
+    },
+    AerospikeQueryExecuteRequest => |r| {
+        r.policy.as_ref().and_then(|p| p.total_timeout).map(u64::from)
+    },
+    AerospikeTruncateRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeIndexTaskStatusRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeUdfTaskStatusRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeInfoRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeNamespacesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeSetsRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeIndexesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeCreateUserRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeDropUserRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeChangePasswordRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeGrantRolesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeRevokeRolesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeQueryUsersRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeQueryRolesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeCreateRoleRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeDropRoleRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeGrantPrivilegesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeRevokePrivilegesRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeSetAllowlistRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    AerospikeSetQuotasRequest => |r| r.policy.as_ref().map(|p| u64::from(p.timeout)),
+    // requests without a policy use the default timeout
+    AerospikeNodesRequest => |_r| None,
+    AerospikeCommitRequest => |_r| None,
+    AerospikeAbortRequest => |_r| None,
+    AerospikeExpressionToBase64Request => |_r| None,
+    AerospikeExecuteTaskStatusRequest => |_r| None,
+}
+
+fn write_timeout(policy: &Option<proto::WritePolicy>) -> Option<u64> {
+    policy
+        .as_ref()
+        .and_then(|p| p.policy.as_ref())
+        .map(|p| p.total_timeout)
+}
+
+fn multi_timeout(policy: Option<&proto::MultiPolicy>) -> Option<u64> {
+    policy
+        .and_then(|p| p.read_policy.as_ref())
+        .map(|p| p.total_timeout)
+}
+
+// Runs a call to the connection manager, failing with `DEADLINE_EXCEEDED` if it does not
+// complete before its deadline. The deadline of requests with a timeout is also enforced by
+// the channel from the `grpc-timeout` header, which cancels the call instead.
+async fn with_deadline<R>(
+    deadline: Duration,
+    call: impl Future<Output = Result<R, tonic::Status>>,
+) -> Result<R, tonic::Status> {
+    let expired = || {
+        tonic::Status::deadline_exceeded(format!(
+            "no response from the connection manager within {}ms",
+            deadline.as_millis()
+        ))
+    };
+
+    let start = Instant::now();
+    match tokio::time::timeout(deadline, call).await {
+        Err(_) => Err(expired()),
+        Ok(Err(status))
+            if status.code() == tonic::Code::Cancelled && start.elapsed() >= deadline =>
+        {
+            Err(expired())
+        }
+        Ok(res) => res,
+    }
+}
+
 // The order of the fields in this struct is important. They must be ordered
 // such that when `BlockingClient` is dropped the client is dropped
 // before the runtime. Not doing this will result in a deadlock when dropped.
@@ -23,10 +177,11 @@ type Result<T, E = StdError> = ::std::result::Result<T, E>;
 pub struct BlockingClient {
     client: KvsClient<tonic::transport::Channel>,
     rt: Runtime,
+    deadlines: Deadlines,
 }
 
 impl BlockingClient {
-    pub fn connect(path: String) -> Result<Self, tonic::transport::Error> {
+    pub fn connect(path: String, deadlines: Deadlines) -> Result<Self, tonic::transport::Error> {
         // let rt = Builder::new_multi_thread().enable_all().build().unwrap();
         let rt = Builder::new_current_thread().enable_all().build().unwrap();
 
@@ -44,246 +199,514 @@ impl BlockingClient {
         // set the maximum message size possible for a record: 128MiB for memory namespaces, with overhead
         let client = KvsClient::new(channel).max_decoding_message_size(130 * 1024 * 1024);
 
-        Ok(Self { client, rt })
+        Ok(Self {
+            client,
+            rt,
+            deadlines,
+        })
+    }
+
+    pub fn deadlines(&self) -> Deadlines {
+        self.deadlines
+    }
+
+    // Computes the deadline of a request: the total timeout of its policy, or the default timeout
+    // if it has no time limit, plus the timeout margin. The deadline is enforced here, in case
+    // the connection manager or its socket hangs. The deadline of a request with a timeout is
+    // also sent to the connection manager in the `grpc-timeout` header; requests without one
+    // are left to run to completion there.
+    fn deadline<T: PolicyTimeout>(
+        &self,
+        request: impl tonic::IntoRequest<T>,
+    ) -> (tonic::Request<T>, Duration) {
+        let mut request = request.into_request();
+        let deadline = match request.get_ref().total_timeout() {
+            Some(timeout) => {
+                let deadline = timeout + self.deadlines.timeout_margin;
+                request.set_timeout(deadline);
+                deadline
+            }
+            None => self.deadlines.default_timeout + self.deadlines.timeout_margin,
+        };
+        (request, deadline)
+    }
+
+    // Waits for the next response of a stream, failing with `DEADLINE_EXCEEDED` if it does not
+    // arrive before the deadline of the stream.
+    fn next_message<T>(&mut self, rs: &mut Stream<T>) -> Option<Result<T, tonic::Status>> {
+        let deadline = rs.deadline;
+        let inner = &mut rs.inner;
+        self.rt
+            .block_on(with_deadline(deadline, async {
+                inner.next().await.transpose()
+            }))
+            .transpose()
     }
 
     pub fn version(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeVersionRequest>,
     ) -> Result<tonic::Response<proto::AerospikeVersionResponse>, tonic::Status> {
-        self.rt.block_on(self.client.version(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.version(request)))
     }
 
     pub fn get(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeGetRequest>,
     ) -> Result<tonic::Response<proto::AerospikeSingleResponse>, tonic::Status> {
-        self.rt.block_on(self.client.get(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.get(request)))
     }
 
     pub fn get_header(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeGetHeaderRequest>,
     ) -> Result<tonic::Response<proto::AerospikeSingleResponse>, tonic::Status> {
-        self.rt.block_on(self.client.get_header(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.get_header(request)))
     }
 
     pub fn exists(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeExistsRequest>,
     ) -> Result<tonic::Response<proto::AerospikeExistsResponse>, tonic::Status> {
-        self.rt.block_on(self.client.exists(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.exists(request)))
     }
 
     pub fn put(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikePutRequest>,
     ) -> Result<tonic::Response<proto::Error>, tonic::Status> {
-        self.rt.block_on(self.client.put(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.put(request)))
     }
 
     pub fn add(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikePutRequest>,
     ) -> Result<tonic::Response<proto::Error>, tonic::Status> {
-        self.rt.block_on(self.client.add(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.add(request)))
     }
 
     pub fn append(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikePutRequest>,
     ) -> Result<tonic::Response<proto::Error>, tonic::Status> {
-        self.rt.block_on(self.client.append(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.append(request)))
     }
 
     pub fn prepend(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikePutRequest>,
     ) -> Result<tonic::Response<proto::Error>, tonic::Status> {
-        self.rt.block_on(self.client.prepend(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.prepend(request)))
     }
 
     pub fn delete(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeDeleteRequest>,
     ) -> Result<tonic::Response<proto::AerospikeDeleteResponse>, tonic::Status> {
-        self.rt.block_on(self.client.delete(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.delete(request)))
     }
 
     pub fn touch(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeTouchRequest>,
     ) -> Result<tonic::Response<proto::Error>, tonic::Status> {
-        self.rt.block_on(self.client.touch(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.touch(request)))
+    }
+
+    pub fn operate(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeOperateRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeSingleResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.operate(request)))
     }
 
     pub fn batch_operate(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeBatchOperateRequest>,
     ) -> Result<tonic::Response<proto::AerospikeBatchOperateResponse>, tonic::Status> {
-        self.rt.block_on(self.client.batch_operate(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.batch_operate(request)))
     }
 
     pub fn create_index(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeCreateIndexRequest>,
     ) -> Result<tonic::Response<proto::AerospikeCreateIndexResponse>, tonic::Status> {
-        self.rt.block_on(self.client.create_index(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.create_index(request)))
     }
 
     pub fn drop_index(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeDropIndexRequest>,
     ) -> Result<tonic::Response<proto::AerospikeDropIndexResponse>, tonic::Status> {
-        self.rt.block_on(self.client.drop_index(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.drop_index(request)))
     }
 
     pub fn truncate(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeTruncateRequest>,
     ) -> Result<tonic::Response<proto::AerospikeTruncateResponse>, tonic::Status> {
-        self.rt.block_on(self.client.truncate(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.truncate(request)))
     }
 
     pub fn register_udf(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeRegisterUdfRequest>,
     ) -> Result<tonic::Response<proto::AerospikeRegisterUdfResponse>, tonic::Status> {
-        self.rt.block_on(self.client.register_udf(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.register_udf(request)))
     }
 
     pub fn drop_udf(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeDropUdfRequest>,
     ) -> Result<tonic::Response<proto::AerospikeDropUdfResponse>, tonic::Status> {
-        self.rt.block_on(self.client.drop_udf(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.drop_udf(request)))
     }
 
     pub fn list_udf(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeListUdfRequest>,
     ) -> Result<tonic::Response<proto::AerospikeListUdfResponse>, tonic::Status> {
-        self.rt.block_on(self.client.list_udf(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.list_udf(request)))
     }
 
     pub fn udf_execute(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeUdfExecuteRequest>,
     ) -> Result<tonic::Response<proto::AerospikeUdfExecuteResponse>, tonic::Status> {
-        self.rt.block_on(self.client.udf_execute(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.udf_execute(request)))
     }
 
     pub fn create_user(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeCreateUserRequest>,
     ) -> Result<tonic::Response<proto::AerospikeCreateUserResponse>, tonic::Status> {
-        self.rt.block_on(self.client.create_user(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.create_user(request)))
     }
 
     pub fn drop_user(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeDropUserRequest>,
     ) -> Result<tonic::Response<proto::AerospikeDropUserResponse>, tonic::Status> {
-        self.rt.block_on(self.client.drop_user(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.drop_user(request)))
     }
 
     pub fn change_password(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeChangePasswordRequest>,
     ) -> Result<tonic::Response<proto::AerospikeChangePasswordResponse>, tonic::Status> {
-        self.rt.block_on(self.client.change_password(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.change_password(request),
+        ))
     }
 
     pub fn grant_roles(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeGrantRolesRequest>,
     ) -> Result<tonic::Response<proto::AerospikeGrantRolesResponse>, tonic::Status> {
-        self.rt.block_on(self.client.grant_roles(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.grant_roles(request)))
     }
 
     pub fn revoke_roles(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeRevokeRolesRequest>,
     ) -> Result<tonic::Response<proto::AerospikeRevokeRolesResponse>, tonic::Status> {
-        self.rt.block_on(self.client.revoke_roles(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.revoke_roles(request)))
     }
 
     pub fn query_users(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeQueryUsersRequest>,
     ) -> Result<tonic::Response<proto::AerospikeQueryUsersResponse>, tonic::Status> {
-        self.rt.block_on(self.client.query_users(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.query_users(request)))
     }
 
     pub fn query_roles(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeQueryRolesRequest>,
     ) -> Result<tonic::Response<proto::AerospikeQueryRolesResponse>, tonic::Status> {
-        self.rt.block_on(self.client.query_roles(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.query_roles(request)))
     }
 
     pub fn create_role(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeCreateRoleRequest>,
     ) -> Result<tonic::Response<proto::AerospikeCreateRoleResponse>, tonic::Status> {
-        self.rt.block_on(self.client.create_role(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.create_role(request)))
     }
 
     pub fn drop_role(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeDropRoleRequest>,
     ) -> Result<tonic::Response<proto::AerospikeDropRoleResponse>, tonic::Status> {
-        self.rt.block_on(self.client.drop_role(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.drop_role(request)))
     }
 
     pub fn grant_privileges(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeGrantPrivilegesRequest>,
     ) -> Result<tonic::Response<proto::AerospikeGrantPrivilegesResponse>, tonic::Status> {
-        self.rt.block_on(self.client.grant_privileges(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.grant_privileges(request),
+        ))
     }
 
     pub fn revoke_privileges(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeRevokePrivilegesRequest>,
     ) -> Result<tonic::Response<proto::AerospikeRevokePrivilegesResponse>, tonic::Status> {
-        self.rt.block_on(self.client.revoke_privileges(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.revoke_privileges(request),
+        ))
     }
 
     pub fn set_allowlist(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeSetAllowlistRequest>,
     ) -> Result<tonic::Response<proto::AerospikeSetAllowlistResponse>, tonic::Status> {
-        self.rt.block_on(self.client.set_allowlist(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.set_allowlist(request)))
     }
 
     pub fn set_quotas(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeSetQuotasRequest>,
     ) -> Result<tonic::Response<proto::AerospikeSetQuotasResponse>, tonic::Status> {
-        self.rt.block_on(self.client.set_quotas(request))
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.set_quotas(request)))
     }
 
     pub fn scan(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeScanRequest>,
-    ) -> Result<tonic::Response<tonic::Streaming<proto::AerospikeStreamResponse>>, tonic::Status>
-    {
-        self.rt.block_on(self.client.scan(request))
+    ) -> Result<Stream<proto::AerospikeStreamResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        let res = self
+            .rt
+            .block_on(with_deadline(deadline, self.client.scan(request)))?;
+        Ok(Stream {
+            inner: res.into_inner(),
+            deadline,
+        })
     }
 
     pub fn query(
         &mut self,
         request: impl tonic::IntoRequest<proto::AerospikeQueryRequest>,
-    ) -> Result<tonic::Response<tonic::Streaming<proto::AerospikeStreamResponse>>, tonic::Status>
-    {
-        self.rt.block_on(self.client.query(request))
+    ) -> Result<Stream<proto::AerospikeStreamResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        let res = self
+            .rt
+            .block_on(with_deadline(deadline, self.client.query(request)))?;
+        Ok(Stream {
+            inner: res.into_inner(),
+            deadline,
+        })
+    }
+
+    pub fn info(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeInfoRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeInfoResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.info(request)))
+    }
+
+    pub fn nodes(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeNodesRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeNodesResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.nodes(request)))
+    }
+
+    pub fn namespaces(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeNamespacesRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeNamespacesResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.namespaces(request)))
+    }
+
+    pub fn sets(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeSetsRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeSetsResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.sets(request)))
+    }
+
+    pub fn indexes(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeIndexesRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeIndexesResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.indexes(request)))
+    }
+
+    pub fn query_aggregate(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeQueryAggregateRequest>,
+    ) -> Result<Stream<proto::AerospikeAggregateResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        let res = self.rt.block_on(with_deadline(
+            deadline,
+            self.client.query_aggregate(request),
+        ))?;
+        Ok(Stream {
+            inner: res.into_inner(),
+            deadline,
+        })
+    }
+
+    pub fn query_execute(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeQueryExecuteRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeQueryExecuteResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.query_execute(request)))
+    }
+
+    pub fn index_task_status(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeIndexTaskStatusRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeTaskProgressResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.index_task_status(request),
+        ))
+    }
+
+    pub fn udf_task_status(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeUdfTaskStatusRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeTaskProgressResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.udf_task_status(request),
+        ))
+    }
+
+    pub fn execute_task_status(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeExecuteTaskStatusRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeExecuteTaskStatusResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.execute_task_status(request),
+        ))
+    }
+
+    pub fn commit(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeCommitRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeCommitResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.commit(request)))
+    }
+
+    pub fn abort(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeAbortRequest>,
+    ) -> Result<tonic::Response<proto::AerospikeAbortResponse>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt
+            .block_on(with_deadline(deadline, self.client.abort(request)))
+    }
+
+    pub fn expression_to_base64(
+        &mut self,
+        request: impl tonic::IntoRequest<proto::AerospikeExpressionToBase64Request>,
+    ) -> Result<tonic::Response<proto::AerospikeExpressionToBase64Response>, tonic::Status> {
+        let (request, deadline) = self.deadline(request);
+        self.rt.block_on(with_deadline(
+            deadline,
+            self.client.expression_to_base64(request),
+        ))
     }
 
     pub fn next_record(
         &mut self,
-        rs: &mut tonic::Streaming<proto::AerospikeStreamResponse>,
+        rs: &mut Stream<proto::AerospikeStreamResponse>,
     ) -> Option<Result<proto::AerospikeStreamResponse, tonic::Status>> {
-        self.rt.block_on(rs.next())
+        self.next_message(rs)
+    }
+
+    pub fn next_aggregate_value(
+        &mut self,
+        rs: &mut Stream<proto::AerospikeAggregateResponse>,
+    ) -> Option<Result<proto::AerospikeAggregateResponse, tonic::Status>> {
+        self.next_message(rs)
     }
 }
@@ -18,6 +18,7 @@
 #![cfg_attr(windows, feature(abi_vectorcall))]
 #![allow(non_snake_case)]
 
+mod dsl;
 mod grpc;
 
 use grpc::proto::{self};
@@ -27,7 +28,10 @@ use std::hash::{Hash, Hasher};
 use std::io::Cursor;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine as _;
 use byteorder::{ByteOrder, NetworkEndian};
 use ripemd160::digest::Digest;
 use ripemd160::Ripemd160;
@@ -58,7 +62,7 @@ use lazy_static::lazy_static;
 use log::trace;
 
 lazy_static! {
-    static ref CLIENTS: Mutex<HashMap<String, Arc<Mutex<grpc::BlockingClient>>>> =
+    static ref CLIENTS: Mutex<HashMap<(String, grpc::Deadlines), Arc<Mutex<grpc::BlockingClient>>>> =
         Mutex::new(HashMap::new());
 }
 
@@ -188,6 +192,10 @@ impl From<ExpType> for i32 {
 
 /// Filter expression, which can be applied to most commands, to control which records are
 /// affected by the command.
+///
+/// The types of the operands are checked when a filter expression is set on a policy. An
+/// `AerospikeException` with the `PARAMETER_ERROR` result code naming the offending
+/// sub-expression is thrown, for example for comparing an integer bin with a string.
 #[php_class(name = "Aerospike\\Expression")]
 pub struct Expression {
     _as: proto::Expression,
@@ -222,6 +230,9 @@ impl Expression {
                 flags: flags,
                 module: module.map(|v| v.into()),
                 exps: exps.iter().map(|e| e._as.clone()).collect(),
+                ctx: vec![],
+                value_type: None,
+                base64: None,
             },
         }
     }
@@ -1121,9 +1132,22 @@ impl Expression {
     }
 
     /// Create unknown value. Used to intentionally fail an expression.
-    /// The failure can be ignored with `ExpWriteFlags` `EVAL_NO_FAIL`
-    /// or `ExpReadFlags` `EVAL_NO_FAIL`.
+    /// The failure can be ignored with `ExpWriteFlags::EvalNoFail()`
+    /// or `ExpReadFlags::EvalNoFail()`, in which case an `ExpOperation::write()`
+    /// leaves the bin untouched.
     /// Requires server version 5.6.0+.
+    /// ```php
+    /// // Set expired to true if the ttl is less than a day, else leave the bin untouched.
+    /// ExpOperation::write(
+    ///     "expired",
+    ///     Expression::cond([
+    ///         Expression::lt(Expression::ttl(), Expression::intVal(86400)),
+    ///         Expression::boolVal(true),
+    ///         Expression::unknown(),
+    ///     ]),
+    ///     [ExpWriteFlags::EvalNoFail()],
+    /// );
+    /// ```
     pub fn unknown() -> Self {
         Expression::new(
             Some(proto::ExpOp::Unknown.into()),
@@ -1134,1498 +1158,2877 @@ impl Expression {
             vec![],
         )
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  ReadModeAP
-//
-////////////////////////////////////////////////////////////////////////////////////////////
-
-/// ReadModeAP is the read policy in AP (availability) mode namespaces.
-/// It indicates how duplicates should be consulted in a read operation.
-/// Only makes a difference during migrations and only applicable in AP mode.
-#[php_class(name = "Aerospike\\ReadModeAP")]
-pub struct ReadModeAP {
-    _as: proto::ReadModeAp,
-}
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl ReadModeAP {
-    /// ReadModeAPOne indicates that a single node should be involved in the read operation.
-    pub fn One() -> Self {
-        ReadModeAP {
-            _as: proto::ReadModeAp::One,
+    /// Create an expression which deletes the bin when used as the result of an
+    /// `ExpOperation::write()` with `ExpWriteFlags::AllowDelete()`.
+    /// Requires server version 5.6.0+.
+    /// ```php
+    /// // Delete the tmp bin if it is past the deadline, else leave it untouched.
+    /// ExpOperation::write(
+    ///     "tmp",
+    ///     Expression::cond([
+    ///         Expression::gt(Expression::lastUpdate(), Expression::intBin("deadline")),
+    ///         Expression::removeResult(),
+    ///         Expression::unknown(),
+    ///     ]),
+    ///     [ExpWriteFlags::AllowDelete(), ExpWriteFlags::EvalNoFail()],
+    /// );
+    /// ```
+    pub fn remove_result() -> Self {
+        Expression::nil()
+    }
+
+    /// Restores an expression from the base64 encoded server wire format, as returned by
+    /// `Client::expressionToBase64()` or the other Aerospike clients. The expression can be used
+    /// on its own or inside other expressions, but its operations are not known to the client,
+    /// so it is not type checked.
+    /// Throws an `AerospikeException` with the `PARAMETER_ERROR` result code if the string is
+    /// not base64 encoded.
+    pub fn from_base64(encoded: &str) -> PhpResult<Self> {
+        let encoded = encoded.trim();
+        if encoded.is_empty() || STANDARD.decode(encoded).is_err() {
+            return Err(AerospikeException {
+                message: "Invalid serialized expression: not a base64 string".into(),
+                code: ResultCode::PARAMETER_ERROR,
+                in_doubt: false,
+            }
+            .into());
         }
+
+        Ok(Expression {
+            _as: proto::Expression {
+                base64: Some(encoded.into()),
+                ..proto::Expression::default()
+            },
+        })
     }
 
-    /// ReadModeAPAll indicates that all duplicates should be consulted in
-    /// the read operation.
-    pub fn All() -> Self {
-        ReadModeAP {
-            _as: proto::ReadModeAp::All,
-        }
+    /// Returns the expression in a readable infix form for logging, for example
+    /// `($.a:int > 5) and ($.b:string == "x")`.
+    pub fn __to_string(&self) -> String {
+        self.to_string()
     }
-}
 
-impl From<&ReadModeAP> for i32 {
-    fn from(v: &ReadModeAP) -> i32 {
-        match v._as {
-            proto::ReadModeAp::One => 0,
-            proto::ReadModeAp::All => 1,
-        }
+    /// Compiles a text expression into an expression.
+    /// Bins are written as `$.name`, with their type inferred from the other operand or given
+    /// with a suffix like `$.name:int`. Values can be passed in `placeholders` and referenced as
+    /// `?0` or `?name`.
+    /// Throws an `AerospikeException` with the `PARAMETER_ERROR` result code and the column of
+    /// the error if the text is not a valid expression.
+    ///
+    /// ```php
+    /// $exp = Expression::parse("$.age > 21 and $.country == 'NL'");
+    /// $exp = Expression::parse('$.tags[0] == ?tag and ttl() < ?0', [86400, 'tag' => 'new']);
+    /// ```
+    pub fn parse(dsl: &str, placeholders: Option<PHPValue>) -> PhpResult<Self> {
+        Ok(dsl::parse(dsl, placeholders)?)
     }
 }
 
-impl From<i32> for ReadModeAP {
-    fn from(v: i32) -> ReadModeAP {
-        match v {
-            0 => ReadModeAP {
-                _as: proto::ReadModeAp::One,
-            },
-            1 => ReadModeAP {
-                _as: proto::ReadModeAp::All,
+impl Expression {
+    /// Create a call to a CDT module (list, map, HLL or bit) on the `bin` expression.
+    /// The connection manager compiles the call with the module's read function for `op`.
+    /// `flags` is the return type of list and map calls.
+    fn module_call(
+        module: proto::ExpType,
+        op: i32,
+        flags: Option<i32>,
+        value_type: Option<ExpType>,
+        args: Vec<&Expression>,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        Expression {
+            _as: proto::Expression {
+                cmd: Some(proto::ExpOp::Call.into()),
+                val: Some(PHPValue::Int(op as i64).into()),
+                bin: Some(Box::new(bin._as.clone())),
+                flags: flags.map(|f| f as i64),
+                module: Some(module.into()),
+                exps: args.iter().map(|e| e._as.clone()).collect(),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+                value_type: value_type.map(|v| v.into()),
+                base64: None,
             },
-            _ => unreachable!(),
         }
     }
 }
 
-impl FromZval<'_> for ReadModeAP {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &ReadModeAP = zval.extract()?;
-
-        Some(ReadModeAP { _as: f._as.clone() })
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
+        fmt_expression(&self._as, f, false)
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  ReadModeSC
-//
-////////////////////////////////////////////////////////////////////////////////////////////
-
-/// ReadModeSC is the read policy in SC (strong consistency) mode namespaces.
-/// Determines SC read consistency options.
-#[php_class(name = "Aerospike\\ReadModeSC")]
-pub struct ReadModeSC {
-    _as: proto::ReadModeSc,
-}
+// Renders an expression in infix form, for example `($.a:int > 5) and ($.b:string == "x")`.
+// Bins are rendered as `$.name:type`, binary operations are parenthesized when nested, and
+// CDT module calls are rendered like the PHP call that built them.
+fn fmt_expression(exp: &proto::Expression, f: &mut fmt::Formatter, nested: bool) -> fmt::Result {
+    let val = || -> PHPValue { exp.val.clone().map(|v| v.into()).unwrap_or(PHPValue::Nil) };
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl ReadModeSC {
-    /// ReadModeSCSession ensures this client will only see an increasing sequence of record versions.
-    /// Server only reads from master.  This is the default.
-    pub fn Session() -> Self {
-        ReadModeSC {
-            _as: proto::ReadModeSc::Session,
-        }
+    if let Some(encoded) = &exp.base64 {
+        return write!(f, "Expression::fromBase64(\"{encoded}\")");
     }
 
-    /// ReadModeSCLinearize ensures ALL clients will only see an increasing sequence of record versions.
-    /// Server only reads from master.
-    pub fn Linearize() -> Self {
-        ReadModeSC {
-            _as: proto::ReadModeSc::Linearize,
+    let cmd = match exp.cmd.map(proto::ExpOp::try_from) {
+        Some(Ok(cmd)) => cmd,
+        Some(Err(_)) => return write!(f, "<invalid>"),
+        // variable definitions of a let are values with a sub expression
+        None if exp.exps.len() == 1 => {
+            write!(f, "{} = ", val().as_string())?;
+            return fmt_expression(&exp.exps[0], f, false);
         }
-    }
+        None => return fmt_exp_value(&val(), f),
+    };
 
-    /// ReadModeSCAllowReplica indicates that the server may read from master or any full (non-migrating) replica.
-    /// Increasing sequence of record versions is not guaranteed.
-    pub fn AllowReplica() -> Self {
-        ReadModeSC {
-            _as: proto::ReadModeSc::AllowReplica,
+    let infix = match cmd {
+        proto::ExpOp::Eq => Some("=="),
+        proto::ExpOp::Ne => Some("!="),
+        proto::ExpOp::Gt => Some(">"),
+        proto::ExpOp::Ge => Some(">="),
+        proto::ExpOp::Lt => Some("<"),
+        proto::ExpOp::Le => Some("<="),
+        proto::ExpOp::And => Some("and"),
+        proto::ExpOp::Or => Some("or"),
+        proto::ExpOp::Add => Some("+"),
+        proto::ExpOp::Sub => Some("-"),
+        proto::ExpOp::Mul => Some("*"),
+        proto::ExpOp::Div => Some("/"),
+        proto::ExpOp::Pow => Some("**"),
+        proto::ExpOp::Mod => Some("%"),
+        proto::ExpOp::IntAnd => Some("&"),
+        proto::ExpOp::IntOr => Some("|"),
+        proto::ExpOp::IntXor => Some("^"),
+        proto::ExpOp::IntLShift => Some("<<"),
+        proto::ExpOp::IntRShift => Some(">>>"),
+        proto::ExpOp::IntArShift => Some(">>"),
+        _ => None,
+    };
+    if let Some(op) = infix {
+        if exp.exps.len() >= 2 {
+            if nested {
+                write!(f, "(")?;
+            }
+            for (i, e) in exp.exps.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {op} ")?;
+                }
+                fmt_expression(e, f, true)?;
+            }
+            if nested {
+                write!(f, ")")?;
+            }
+            return Ok(());
         }
     }
 
-    /// ReadModeSCAllowUnavailable indicates that the server may read from master or any full (non-migrating) replica or from unavailable
-    /// partitions.  Increasing sequence of record versions is not guaranteed.
-    pub fn AllowUnavailable() -> Self {
-        ReadModeSC {
-            _as: proto::ReadModeSc::AllowUnavailable,
+    let name = match cmd {
+        proto::ExpOp::Bin => {
+            let exp_type = exp.module.and_then(|m| proto::ExpType::try_from(m).ok());
+            fmt_exp_bin_name(&val().as_string(), f)?;
+            return write!(f, ":{}", exp_type_name(exp_type));
         }
-    }
-}
-
-impl From<&ReadModeSC> for i32 {
-    fn from(v: &ReadModeSC) -> i32 {
-        match &v._as {
-            proto::ReadModeSc::Session => 0,
-            proto::ReadModeSc::Linearize => 1,
-            proto::ReadModeSc::AllowReplica => 2,
-            proto::ReadModeSc::AllowUnavailable => 3,
+        proto::ExpOp::Regex => {
+            if nested {
+                write!(f, "(")?;
+            }
+            match exp.bin.as_ref() {
+                Some(bin) => fmt_expression(bin, f, true)?,
+                None => write!(f, "<nil>")?,
+            }
+            let flags = exp.flags.unwrap_or(0);
+            write!(f, " =~ /{}/", val().as_string().replace('/', "\\/"))?;
+            for (flag, c) in [(1, 'x'), (2, 'i'), (4, 'n'), (8, 'm')] {
+                if flags & flag != 0 {
+                    write!(f, "{c}")?;
+                }
+            }
+            if nested {
+                write!(f, ")")?;
+            }
+            return Ok(());
         }
-    }
-}
+        proto::ExpOp::Call => return fmt_exp_module_call(exp, f),
+        proto::ExpOp::Key => {
+            let exp_type = match val() {
+                PHPValue::Int(t) => proto::ExpType::try_from(t as i32).ok(),
+                _ => None,
+            };
+            return write!(f, "key({})", exp_type_name(exp_type));
+        }
+        proto::ExpOp::BinType => {
+            write!(f, "bin_type(")?;
+            fmt_exp_value(&val(), f)?;
+            return write!(f, ")");
+        }
+        proto::ExpOp::Var => return write!(f, "var({})", val().as_string()),
+        proto::ExpOp::Quoted => return fmt_exp_value(&val(), f),
+        proto::ExpOp::DigestModulo => return write!(f, "digest_modulo({})", val().as_string()),
+        proto::ExpOp::Unknown => "unknown",
+        proto::ExpOp::Geo => "geo_compare",
+        proto::ExpOp::Not => "not",
+        proto::ExpOp::Exclusive => "exclusive",
+        proto::ExpOp::Log => "log",
+        proto::ExpOp::Abs => "abs",
+        proto::ExpOp::Floor => "floor",
+        proto::ExpOp::Ceil => "ceil",
+        proto::ExpOp::ToInt => "to_int",
+        proto::ExpOp::ToFloat => "to_float",
+        proto::ExpOp::IntNot => "int_not",
+        proto::ExpOp::IntCount => "count",
+        proto::ExpOp::IntLScan => "lscan",
+        proto::ExpOp::IntRScan => "rscan",
+        proto::ExpOp::Min => "min",
+        proto::ExpOp::Max => "max",
+        proto::ExpOp::DeviceSize => "device_size",
+        proto::ExpOp::LastUpdate => "last_update",
+        proto::ExpOp::SinceUpdate => "since_update",
+        proto::ExpOp::VoidTime => "void_time",
+        proto::ExpOp::Ttl => "ttl",
+        proto::ExpOp::SetName => "set_name",
+        proto::ExpOp::KeyExists => "key_exists",
+        proto::ExpOp::IsTombstone => "is_tombstone",
+        proto::ExpOp::MemorySize => "memory_size",
+        proto::ExpOp::RecordSize => "record_size",
+        proto::ExpOp::Cond => "cond",
+        proto::ExpOp::Let => "let",
+        // operators with less than two operands
+        _ => infix.unwrap_or("<invalid>"),
+    };
 
-impl From<i32> for ReadModeSC {
-    fn from(v: i32) -> ReadModeSC {
-        match v {
-            0 => ReadModeSC {
-                _as: proto::ReadModeSc::Session,
-            },
-            1 => ReadModeSC {
-                _as: proto::ReadModeSc::Linearize,
-            },
-            2 => ReadModeSC {
-                _as: proto::ReadModeSc::AllowReplica,
-            },
-            3 => ReadModeSC {
-                _as: proto::ReadModeSc::AllowUnavailable,
-            },
-            _ => unreachable!(),
+    write!(f, "{name}(")?;
+    for (i, e) in exp.exps.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt_expression(e, f, false)?;
+    }
+    write!(f, ")")
+}
+
+fn fmt_exp_module_call(exp: &proto::Expression, f: &mut fmt::Formatter) -> fmt::Result {
+    let op = exp.val.clone().and_then(|v| match v.v {
+        Some(proto::value::V::I(op)) => Some(op as i32),
+        _ => None,
+    });
+    let module = exp.module.and_then(|m| proto::ExpType::try_from(m).ok());
+    let (class, name, return_type) = match (module, op) {
+        (Some(proto::ExpType::List), Some(op)) => (
+            "ListExp",
+            proto::CdtListCommandOp::try_from(op)
+                .map(|op| op.as_str_name().trim_start_matches("CdtListCommandOp")),
+            exp.flags.map(|rt| {
+                let name = proto::CdtListReturnType::try_from(rt as i32 & !0x10000)
+                    .map(|rt| rt.as_str_name().trim_start_matches("CdtListReturnType"));
+                (name, rt & 0x10000 != 0)
+            }),
+        ),
+        (Some(proto::ExpType::Map), Some(op)) => (
+            "MapExp",
+            proto::CdtMapCommandOp::try_from(op)
+                .map(|op| op.as_str_name().trim_start_matches("CdtMapCommandOp")),
+            exp.flags.map(|rt| {
+                let name = proto::CdtMapReturnType::try_from(rt as i32 & !0x10000)
+                    .map(|rt| rt.as_str_name().trim_start_matches("CdtMapReturnType"));
+                (name, rt & 0x10000 != 0)
+            }),
+        ),
+        (Some(proto::ExpType::Hll), Some(op)) => (
+            "HllExp",
+            proto::CdtHllCommandOp::try_from(op)
+                .map(|op| op.as_str_name().trim_start_matches("CdtHLLCommandOp")),
+            None,
+        ),
+        (Some(proto::ExpType::Blob), Some(op)) => (
+            "BitExp",
+            proto::CdtBitwiseCommandOp::try_from(op)
+                .map(|op| op.as_str_name().trim_start_matches("CdtBitwiseCommandOp")),
+            None,
+        ),
+        _ => return write!(f, "<invalid>"),
+    };
+
+    let name = match name {
+        Ok("LScan") => "lscan".to_string(),
+        Ok("RScan") => "rscan".to_string(),
+        Ok(name) => name[..1].to_lowercase() + &name[1..],
+        Err(_) => return write!(f, "<invalid>"),
+    };
+    write!(f, "{class}::{name}(")?;
+
+    let mut args: Vec<String> = vec![];
+    if let Some((rt, inverted)) = return_type {
+        let rt = rt.unwrap_or("<invalid>");
+        args.push(if inverted {
+            format!("{rt}|Inverted")
+        } else {
+            rt.to_string()
+        });
+    }
+    if let Some(value_type) = exp.value_type {
+        args.push(exp_type_name(proto::ExpType::try_from(value_type).ok()).to_string());
+    }
+    args.extend(
+        exp.exps
+            .iter()
+            .map(|e| Expression { _as: e.clone() }.to_string()),
+    );
+    // the signed flag of BitExp::getInt
+    if module == Some(proto::ExpType::Blob) {
+        if let Some(signed) = exp.flags {
+            args.push((signed != 0).to_string());
         }
     }
-}
-
-impl FromZval<'_> for ReadModeSC {
-    const TYPE: DataType = DataType::Mixed;
+    if let Some(bin) = exp.bin.as_ref() {
+        args.push(
+            Expression {
+                _as: (**bin).clone(),
+            }
+            .to_string(),
+        );
+    }
+    if !exp.ctx.is_empty() {
+        let ctx: Vec<String> = exp.ctx.iter().map(ctx_name).collect();
+        args.push(format!("[{}]", ctx.join(", ")));
+    }
 
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &ReadModeSC = zval.extract()?;
+    write!(f, "{})", args.join(", "))
+}
 
-        Some(ReadModeSC { _as: f._as.clone() })
+fn fmt_exp_bin_name(name: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    let is_ident = name
+        .chars()
+        .enumerate()
+        .all(|(i, c)| c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()));
+    if is_ident && !name.is_empty() {
+        write!(f, "$.{name}")
+    } else {
+        write!(f, "$.{name:?}")
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  RecordExistsAction
-//
-////////////////////////////////////////////////////////////////////////////////////////////
+// Wraps a value to render it as an expression literal.
+struct ExpValue<'a>(&'a PHPValue);
 
-/// RecordExistsAction determines how to handle writes when
-/// the record already exists.
-#[php_class(name = "Aerospike\\RecordExistsAction")]
-pub struct RecordExistsAction {
-    _as: proto::RecordExistsAction,
+impl fmt::Display for ExpValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
+        fmt_exp_value(self.0, f)
+    }
 }
 
-impl FromZval<'_> for RecordExistsAction {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &RecordExistsAction = zval.extract()?;
+fn fmt_exp_value(val: &PHPValue, f: &mut fmt::Formatter) -> fmt::Result {
+    let entry = |(k, v): (&PHPValue, &PHPValue)| format!("{}: {}", ExpValue(k), ExpValue(v));
 
-        Some(RecordExistsAction { _as: f._as.clone() })
+    match val {
+        PHPValue::Nil => write!(f, "nil"),
+        PHPValue::Float(v) => write!(f, "{:?}", v.into_inner()),
+        PHPValue::String(v) => write!(f, "{v:?}"),
+        PHPValue::Blob(v) => write!(f, "x\"{}\"", hex::encode(v)),
+        PHPValue::HLL(v) => write!(f, "hll(x\"{}\")", hex::encode(v)),
+        PHPValue::GeoJSON(v) => write!(f, "geo({v:?})"),
+        PHPValue::Infinity => write!(f, "inf"),
+        PHPValue::Wildcard => write!(f, "*"),
+        PHPValue::List(l) => {
+            let items: Vec<String> = l.iter().map(|v| ExpValue(v).to_string()).collect();
+            write!(f, "[{}]", items.join(", "))
+        }
+        PHPValue::OrderedMap(m) => {
+            let items: Vec<String> = m.iter().map(|(k, v)| entry((k, v))).collect();
+            write!(f, "{{{}}}", items.join(", "))
+        }
+        PHPValue::HashMap(m) => {
+            let items: Vec<String> = m.iter().map(entry).collect();
+            write!(f, "{{{}}}", items.join(", "))
+        }
+        PHPValue::Json(m) => {
+            let items: Vec<String> = m
+                .iter()
+                .map(|(k, v)| entry((&PHPValue::String(k.clone()), v)))
+                .collect();
+            write!(f, "{{{}}}", items.join(", "))
+        }
+        v => write!(f, "{}", v.as_string()),
+    }
+}
+
+fn exp_type_name(exp_type: Option<proto::ExpType>) -> &'static str {
+    match exp_type {
+        Some(proto::ExpType::Nil) => "nil",
+        Some(proto::ExpType::Bool) => "bool",
+        Some(proto::ExpType::Int) => "int",
+        Some(proto::ExpType::String) => "string",
+        Some(proto::ExpType::List) => "list",
+        Some(proto::ExpType::Map) => "map",
+        Some(proto::ExpType::Blob) => "blob",
+        Some(proto::ExpType::Float) => "float",
+        Some(proto::ExpType::Geo) => "geo",
+        Some(proto::ExpType::Hll) => "hll",
+        None => "<invalid>",
+    }
+}
+
+fn ctx_name(ctx: &proto::CdtContext) -> String {
+    let name = match ctx.id & 0x3f {
+        0x10 => "ListIndex",
+        0x11 => "ListRank",
+        0x13 => "ListValue",
+        0x20 => "MapIndex",
+        0x21 => "MapRank",
+        0x22 => "MapKey",
+        0x23 => "MapValue",
+        _ => "<invalid>",
+    };
+    let create = if ctx.id & 0xc0 != 0 { "Create" } else { "" };
+    let value: PHPValue = ctx.value.clone().map(|v| v.into()).unwrap_or(PHPValue::Nil);
+    format!("{name}{create}({})", ExpValue(&value))
+}
+
+// Type checks a filter expression the way the server does, so that mistakes are reported with
+// the offending sub expression instead of a bare `PARAMETER_ERROR` from the server.
+fn check_filter_expression(exp: &proto::Expression) -> AsResult<()> {
+    match check_expression_type(exp, &mut vec![])? {
+        Some(proto::ExpType::Bool) | None => Ok(()),
+        Some(t) => Err(AerospikeException {
+            message: format!(
+                "Invalid filter expression `{}`: returns {}, filter expressions must return bool",
+                Expression { _as: exp.clone() },
+                exp_type_name(Some(t)),
+            ),
+            code: ResultCode::PARAMETER_ERROR,
+            in_doubt: false,
+        }),
     }
 }
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl RecordExistsAction {
-    /// Update means: Create or update record.
-    /// Merge write command bins with existing bins.
-    pub fn Update() -> Self {
-        RecordExistsAction {
-            _as: proto::RecordExistsAction::Update,
+// Type checks the filter expression given to a policy setter and returns the expression to
+// set. Setters can not return errors, so if the expression is invalid the `AerospikeException`
+// is thrown directly and `None` is returned, leaving the policy unchanged.
+fn checked_filter_expression(exp: Option<Expression>) -> Option<Option<proto::Expression>> {
+    fn check(exp: Option<Expression>) -> PhpResult<Option<proto::Expression>> {
+        match exp {
+            Some(exp) => {
+                check_filter_expression(&exp._as)?;
+                Ok(Some(exp._as))
+            }
+            None => Ok(None),
         }
     }
 
-    /// UpdateOnly means: Update record only. Fail if record does not exist.
-    /// Merge write command bins with existing bins.
-    pub fn Update_Only() -> Self {
-        RecordExistsAction {
-            _as: proto::RecordExistsAction::UpdateOnly,
-        }
+    check(exp)
+        .map_err(|error| {
+            let _ = error.throw();
+        })
+        .ok()
+}
+
+// Returns the type of the result of an expression after checking the types of its operands.
+// The type is `None` when it is only known when the expression runs, like for nil values and
+// `Expression::unknown()`; those operands are accepted everywhere.
+fn check_expression_type(
+    exp: &proto::Expression,
+    vars: &mut Vec<(String, Option<proto::ExpType>)>,
+) -> AsResult<Option<proto::ExpType>> {
+    use proto::ExpType as T;
+
+    let invalid = |problem: String| AerospikeException {
+        message: format!(
+            "Invalid expression `{}`: {problem}",
+            Expression { _as: exp.clone() }
+        ),
+        code: ResultCode::PARAMETER_ERROR,
+        in_doubt: false,
+    };
+    let val = || -> PHPValue { exp.val.clone().map(|v| v.into()).unwrap_or(PHPValue::Nil) };
+    let name = |t: T| exp_type_name(Some(t));
+
+    if exp.base64.is_some() {
+        // the operations of an expression restored from base64 are not known
+        return Ok(None);
     }
 
-    /// Replace means: Create or replace record.
-    /// Delete existing bins not referenced by write command bins.
-    /// Supported by Aerospike 2 server versions >= 2.7.5 and
-    /// Aerospike 3 server versions >= 3.1.6.
-    pub fn Replace() -> Self {
-        RecordExistsAction {
-            _as: proto::RecordExistsAction::Replace,
+    let cmd = match exp.cmd.map(proto::ExpOp::try_from) {
+        Some(Ok(cmd)) => cmd,
+        Some(Err(_)) => return Err(invalid("unknown operation".into())),
+        None => return Ok(value_exp_type(&val())),
+    };
+
+    if cmd == proto::ExpOp::Let {
+        let depth = vars.len();
+        let mut result = None;
+        for e in &exp.exps {
+            match (e.cmd, e.exps.first()) {
+                // variable definition
+                (None, Some(value)) => {
+                    let t = check_expression_type(value, vars)?;
+                    let var = e.val.clone().map(PHPValue::from).unwrap_or(PHPValue::Nil);
+                    vars.push((var.as_string(), t));
+                }
+                _ => result = check_expression_type(e, vars)?,
+            }
         }
+        vars.truncate(depth);
+        return Ok(result);
     }
 
-    /// ReplaceOnly means: Replace record only. Fail if record does not exist.
-    /// Delete existing bins not referenced by write command bins.
-    /// Supported by Aerospike 2 server versions >= 2.7.5 and
-    /// Aerospike 3 server versions >= 3.1.6.
-    pub fn Replace_Only() -> Self {
-        RecordExistsAction {
-            _as: proto::RecordExistsAction::ReplaceOnly,
+    let types = exp
+        .exps
+        .iter()
+        .map(|e| check_expression_type(e, vars))
+        .collect::<AsResult<Vec<_>>>()?;
+    let bin_type = match exp.bin.as_ref() {
+        Some(bin) => check_expression_type(bin, vars)?,
+        None => None,
+    };
+
+    // all the operands must have the type `t`
+    let expect = |types: &[Option<T>], t: T| -> AsResult<()> {
+        match types.iter().flatten().find(|&&found| found != t) {
+            Some(&found) => Err(invalid(format!(
+                "operands must be {}, not {}",
+                name(t),
+                name(found)
+            ))),
+            None => Ok(()),
         }
-    }
+    };
+    // all the operands must have the same type, which is returned
+    let same = |types: &[Option<T>], verb: &str| -> AsResult<Option<T>> {
+        let mut known = types.iter().flatten();
+        let first = known.next().copied();
+        match (first, known.find(|&&t| Some(t) != first)) {
+            (Some(first), Some(&other)) => Err(invalid(format!(
+                "{verb} {} and {}",
+                name(first),
+                name(other)
+            ))),
+            _ => Ok(first),
+        }
+    };
 
-    /// CreateOnly means: Create only. Fail if record exists.
-    pub fn Create_Only() -> Self {
-        RecordExistsAction {
-            _as: proto::RecordExistsAction::CreateOnly,
+    Ok(match cmd {
+        proto::ExpOp::Eq
+        | proto::ExpOp::Ne
+        | proto::ExpOp::Gt
+        | proto::ExpOp::Ge
+        | proto::ExpOp::Lt
+        | proto::ExpOp::Le => {
+            same(&types, "compares")?;
+            Some(T::Bool)
+        }
+        proto::ExpOp::And | proto::ExpOp::Or | proto::ExpOp::Not | proto::ExpOp::Exclusive => {
+            expect(&types, T::Bool)?;
+            Some(T::Bool)
+        }
+        proto::ExpOp::Regex => {
+            expect(&[bin_type], T::String)?;
+            Some(T::Bool)
+        }
+        proto::ExpOp::Geo => {
+            expect(&types, T::Geo)?;
+            Some(T::Bool)
+        }
+        proto::ExpOp::Add
+        | proto::ExpOp::Sub
+        | proto::ExpOp::Mul
+        | proto::ExpOp::Div
+        | proto::ExpOp::Abs
+        | proto::ExpOp::Min
+        | proto::ExpOp::Max => match same(&types, "mixes")? {
+            Some(t) if t != T::Int && t != T::Float => {
+                return Err(invalid(format!(
+                    "operands must be int or float, not {}",
+                    name(t)
+                )))
+            }
+            t => t,
+        },
+        proto::ExpOp::Pow | proto::ExpOp::Log | proto::ExpOp::Floor | proto::ExpOp::Ceil => {
+            expect(&types, T::Float)?;
+            Some(T::Float)
+        }
+        proto::ExpOp::ToInt => {
+            expect(&types, T::Float)?;
+            Some(T::Int)
+        }
+        proto::ExpOp::ToFloat => {
+            expect(&types, T::Int)?;
+            Some(T::Float)
+        }
+        proto::ExpOp::Mod
+        | proto::ExpOp::IntAnd
+        | proto::ExpOp::IntOr
+        | proto::ExpOp::IntXor
+        | proto::ExpOp::IntNot
+        | proto::ExpOp::IntLShift
+        | proto::ExpOp::IntRShift
+        | proto::ExpOp::IntArShift
+        | proto::ExpOp::IntCount => {
+            expect(&types, T::Int)?;
+            Some(T::Int)
+        }
+        proto::ExpOp::IntLScan | proto::ExpOp::IntRScan => {
+            expect(&types[..types.len().min(1)], T::Int)?;
+            expect(types.get(1..).unwrap_or_default(), T::Bool)?;
+            Some(T::Int)
+        }
+        proto::ExpOp::DigestModulo
+        | proto::ExpOp::DeviceSize
+        | proto::ExpOp::MemorySize
+        | proto::ExpOp::RecordSize
+        | proto::ExpOp::LastUpdate
+        | proto::ExpOp::SinceUpdate
+        | proto::ExpOp::VoidTime
+        | proto::ExpOp::Ttl
+        | proto::ExpOp::BinType => Some(T::Int),
+        proto::ExpOp::SetName => Some(T::String),
+        proto::ExpOp::KeyExists | proto::ExpOp::IsTombstone => Some(T::Bool),
+        proto::ExpOp::Key => match val() {
+            PHPValue::Int(t) => T::try_from(t as i32).ok(),
+            _ => None,
+        },
+        proto::ExpOp::Bin => exp.module.and_then(|m| T::try_from(m).ok()),
+        proto::ExpOp::Var => {
+            let var = val().as_string();
+            vars.iter()
+                .rev()
+                .find(|(name, _)| *name == var)
+                .and_then(|(_, t)| *t)
+        }
+        proto::ExpOp::Cond => {
+            // pairs of a condition and an action, followed by the default action
+            let (conditions, actions): (Vec<_>, Vec<_>) = types
+                .iter()
+                .enumerate()
+                .partition(|(i, _)| i % 2 == 0 && i + 1 < types.len());
+            let conditions: Vec<_> = conditions.into_iter().map(|(_, t)| *t).collect();
+            let actions: Vec<_> = actions.into_iter().map(|(_, t)| *t).collect();
+            expect(&conditions, T::Bool)?;
+            same(&actions, "actions return")?
+        }
+        proto::ExpOp::Call => return module_call_type(exp, bin_type).map_err(invalid),
+        proto::ExpOp::Quoted => Some(T::List),
+        proto::ExpOp::Unknown | proto::ExpOp::Let => None,
+    })
+}
+
+// Returns the type of the result of a CDT module call, after checking that its bin expression
+// has the type the module reads.
+fn module_call_type(
+    exp: &proto::Expression,
+    bin_type: Option<proto::ExpType>,
+) -> std::result::Result<Option<proto::ExpType>, String> {
+    use proto::ExpType as T;
+
+    // with a context, the module reads an element nested in the bin, which can be of any type
+    let module = exp.module.and_then(|m| T::try_from(m).ok());
+    if let (Some(module), Some(bin_type), true) = (module, bin_type, exp.ctx.is_empty()) {
+        if module != bin_type {
+            return Err(format!(
+                "reads a {} but the bin expression returns {}",
+                exp_type_name(Some(module)),
+                exp_type_name(Some(bin_type))
+            ));
+        }
+    }
+
+    let op = exp.val.clone().and_then(|v| match v.v {
+        Some(proto::value::V::I(op)) => Some(op as i32),
+        _ => None,
+    });
+    // single item reads return the value type they were given
+    if let Some(value_type) = exp.value_type {
+        return Ok(T::try_from(value_type).ok());
+    }
+    let return_type = exp.flags.map(|rt| rt as i32 & !0x10000);
+
+    Ok(match module {
+        Some(T::List) => match return_type.map(proto::CdtListReturnType::try_from) {
+            None if op == Some(proto::CdtListCommandOp::Size.into()) => Some(T::Int),
+            Some(Ok(proto::CdtListReturnType::Count)) => Some(T::Int),
+            Some(Ok(proto::CdtListReturnType::Exists)) => Some(T::Bool),
+            Some(Ok(proto::CdtListReturnType::None)) | Some(Err(_)) | None => None,
+            Some(Ok(_)) => Some(T::List),
+        },
+        Some(T::Map) => match return_type.map(proto::CdtMapReturnType::try_from) {
+            None if op == Some(proto::CdtMapCommandOp::Size.into()) => Some(T::Int),
+            Some(Ok(proto::CdtMapReturnType::Count)) => Some(T::Int),
+            Some(Ok(proto::CdtMapReturnType::Exists)) => Some(T::Bool),
+            Some(Ok(
+                proto::CdtMapReturnType::KeyValue
+                | proto::CdtMapReturnType::UnorderedMap
+                | proto::CdtMapReturnType::OrderedMap,
+            )) => Some(T::Map),
+            Some(Ok(proto::CdtMapReturnType::None | proto::CdtMapReturnType::Inverted))
+            | Some(Err(_))
+            | None => None,
+            Some(Ok(_)) => Some(T::List),
+        },
+        Some(T::Hll) => match op.map(proto::CdtHllCommandOp::try_from) {
+            Some(Ok(
+                proto::CdtHllCommandOp::GetCount
+                | proto::CdtHllCommandOp::GetUnionCount
+                | proto::CdtHllCommandOp::GetIntersectCount,
+            )) => Some(T::Int),
+            Some(Ok(proto::CdtHllCommandOp::GetUnion)) => Some(T::Hll),
+            Some(Ok(proto::CdtHllCommandOp::GetSimilarity)) => Some(T::Float),
+            Some(Ok(proto::CdtHllCommandOp::Describe)) => Some(T::List),
+            _ => None,
+        },
+        Some(T::Blob) => match op.map(proto::CdtBitwiseCommandOp::try_from) {
+            Some(Ok(proto::CdtBitwiseCommandOp::Get)) => Some(T::Blob),
+            Some(Ok(
+                proto::CdtBitwiseCommandOp::Count
+                | proto::CdtBitwiseCommandOp::LScan
+                | proto::CdtBitwiseCommandOp::RScan
+                | proto::CdtBitwiseCommandOp::GetInt,
+            )) => Some(T::Int),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+fn value_exp_type(val: &PHPValue) -> Option<proto::ExpType> {
+    match val {
+        PHPValue::Bool(_) => Some(proto::ExpType::Bool),
+        PHPValue::Int(_) | PHPValue::UInt(_) => Some(proto::ExpType::Int),
+        PHPValue::Float(_) => Some(proto::ExpType::Float),
+        PHPValue::String(_) => Some(proto::ExpType::String),
+        PHPValue::Blob(_) => Some(proto::ExpType::Blob),
+        PHPValue::List(_) => Some(proto::ExpType::List),
+        PHPValue::HashMap(_) | PHPValue::OrderedMap(_) | PHPValue::Json(_) => {
+            Some(proto::ExpType::Map)
         }
+        PHPValue::GeoJSON(_) => Some(proto::ExpType::Geo),
+        PHPValue::HLL(_) => Some(proto::ExpType::Hll),
+        PHPValue::Nil | PHPValue::Infinity | PHPValue::Wildcard => None,
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  QueryDuration
+//  ListExp
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// QueryDuration represents the expected duration for a query operation in the Aerospike database.
-#[php_class(name = "Aerospike\\QueryDuration")]
-pub struct QueryDuration {
-    _as: proto::QueryDuration,
-}
-
-impl FromZval<'_> for QueryDuration {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &QueryDuration = zval.extract()?;
-
-        Some(QueryDuration { _as: f._as.clone() })
-    }
-}
+/// List expression generator. The expressions read list bins, or lists nested in other
+/// lists and maps when a context is given, and can be used in filter expressions.
+///
+/// Index, rank and count arguments are integer expressions. The `bin` argument is usually
+/// `Expression::listBin()`, but can be any expression that returns a list.
+#[php_class(name = "Aerospike\\ListExp")]
+pub struct ListExp {}
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl QueryDuration {
-    /// LONG specifies that the query is expected to return more than 100 records per node. The server optimizes for a large record set in
-    /// the following ways:
-    ///
-    /// Allow query to be run in multiple threads using the server's query threading configuration.
-    /// Do not relax read consistency for AP namespaces.
-    /// Add the query to the server's query monitor.
-    /// Do not add the overall latency to the server's latency histogram.
-    /// Do not allow server timeouts.    
-    pub fn Long() -> Self {
-        QueryDuration {
-            _as: proto::QueryDuration::Long,
-        }
+impl ListExp {
+    /// Create expression that returns list size.
+    pub fn size(bin: &Expression, ctx: Option<Vec<&CDTContext>>) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::Size.into(),
+            None,
+            None,
+            vec![],
+            bin,
+            ctx,
+        )
     }
 
-    /// Short specifies that the query is expected to return less than 100 records per node. The server optimizes for a small record set in
-    /// the following ways:
-    /// Always run the query in one thread and ignore the server's query threading configuration.
-    /// Allow query to be inlined directly on the server's service thread.
-    /// Relax read consistency for AP namespaces.
-    /// Do not add the query to the server's query monitor.
-    /// Add the overall latency to the server's latency histogram.
-    /// Allow server timeouts. The default server timeout for a short query is 1 second.
-    pub fn Short() -> Self {
-        QueryDuration {
-            _as: proto::QueryDuration::Short,
-        }
+    /// Create expression that selects list items identified by value and returns selected
+    /// data specified by return_type.
+    pub fn get_by_value(
+        return_type: CdtListReturnType,
+        value: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByValue.into(),
+            Some(return_type._as),
+            None,
+            vec![value],
+            bin,
+            ctx,
+        )
     }
 
-    /// LongRelaxAP will treat query as a LONG query, but relax read consistency for AP namespaces.
-    /// This value is treated exactly like LONG for server versions < 7.1.
-    pub fn LongRelaxAP() -> Self {
-        QueryDuration {
-            _as: proto::QueryDuration::LongRelaxAp,
-        }
+    /// Create expression that selects list items identified by value range (value_begin inclusive,
+    /// value_end exclusive). If value_begin is null, the range is less than value_end. If value_end
+    /// is null, the range is greater than equal to value_begin.
+    pub fn get_by_value_range(
+        return_type: CdtListReturnType,
+        value_begin: Option<&Expression>,
+        value_end: Option<&Expression>,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        // a missing end is left out, for a range without an upper bound
+        let nil = Expression::nil();
+        let mut args = vec![value_begin.unwrap_or(&nil)];
+        args.extend(value_end);
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByValueRange.into(),
+            Some(return_type._as),
+            None,
+            args,
+            bin,
+            ctx,
+        )
     }
-}
 
-impl From<&proto::QueryDuration> for QueryDuration {
-    fn from(input: &proto::QueryDuration) -> Self {
-        QueryDuration { _as: input.clone() }
+    /// Create expression that selects list items identified by values and returns selected data
+    /// specified by return_type. `values` is a list expression.
+    pub fn get_by_value_list(
+        return_type: CdtListReturnType,
+        values: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByValueList.into(),
+            Some(return_type._as),
+            None,
+            vec![values],
+            bin,
+            ctx,
+        )
     }
-}
 
-impl From<i32> for QueryDuration {
-    fn from(input: i32) -> Self {
-        match input {
-            0 => QueryDuration::Long(),
-            1 => QueryDuration::Short(),
-            2 => QueryDuration::LongRelaxAP(),
-            _ => unreachable!(),
-        }
+    /// Create expression that selects list items nearest to value and greater by relative rank
+    /// and returns selected data specified by return_type.
+    ///
+    /// Examples for ordered list \[0, 4, 5, 9, 11, 15\]:
+    ///
+    /// (value,rank) = [selected items]
+    /// (5,0) = [5,9,11,15]
+    /// (5,1) = [9,11,15]
+    /// (5,-1) = [4,5,9,11,15]
+    /// (3,0) = [4,5,9,11,15]
+    /// (3,3) = [11,15]
+    /// (3,-3) = [0,4,5,9,11,15]
+    pub fn get_by_value_relative_rank_range(
+        return_type: CdtListReturnType,
+        value: &Expression,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByValueRelativeRankRange.into(),
+            Some(return_type._as),
+            None,
+            vec![value, rank],
+            bin,
+            ctx,
+        )
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  CommitLevel
-//
-////////////////////////////////////////////////////////////////////////////////////////////
+    /// Create expression that selects list items nearest to value and greater by relative rank
+    /// with a count limit and returns selected data specified by return_type.
+    ///
+    /// Examples for ordered list \[0, 4, 5, 9, 11, 15\]:
+    ///
+    /// (value,rank,count) = [selected items]
+    /// (5,0,2) = [5,9]
+    /// (5,1,1) = [9]
+    /// (5,-1,2) = [4,5]
+    /// (3,0,1) = [4]
+    /// (3,3,7) = [11,15]
+    /// (3,-3,2) = []
+    pub fn get_by_value_relative_rank_range_count(
+        return_type: CdtListReturnType,
+        value: &Expression,
+        rank: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByValueRelativeRankRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![value, rank, count],
+            bin,
+            ctx,
+        )
+    }
 
-/// CommitLevel indicates the desired consistency guarantee when committing a transaction on the server.
-#[php_class(name = "Aerospike\\CommitLevel")]
-pub struct CommitLevel {
-    _as: proto::CommitLevel,
-}
+    /// Create expression that selects list item identified by index and returns selected data
+    /// specified by return_type. `value_type` is the type of the item when the return type
+    /// is `ListReturnType::Value()`.
+    ///
+    /// ```php
+    /// // a[3] == 5
+    /// Expression::eq(
+    ///   ListExp::getByIndex(ListReturnType::Value(), ExpType::Int(), Expression::intVal(3), Expression::listBin("a")),
+    ///   Expression::intVal(5));
+    /// ```
+    pub fn get_by_index(
+        return_type: CdtListReturnType,
+        value_type: ExpType,
+        index: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByIndex.into(),
+            Some(return_type._as),
+            Some(value_type),
+            vec![index],
+            bin,
+            ctx,
+        )
+    }
 
-impl FromZval<'_> for CommitLevel {
-    const TYPE: DataType = DataType::Mixed;
+    /// Create expression that selects list items starting at specified index to the end of list
+    /// and returns selected data specified by return_type.
+    pub fn get_by_index_range(
+        return_type: CdtListReturnType,
+        index: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByIndexRange.into(),
+            Some(return_type._as),
+            None,
+            vec![index],
+            bin,
+            ctx,
+        )
+    }
 
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &CommitLevel = zval.extract()?;
+    /// Create expression that selects "count" list items starting at specified index
+    /// and returns selected data specified by return_type.
+    pub fn get_by_index_range_count(
+        return_type: CdtListReturnType,
+        index: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByIndexRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![index, count],
+            bin,
+            ctx,
+        )
+    }
 
-        Some(CommitLevel { _as: f._as.clone() })
+    /// Create expression that selects list item identified by rank and returns selected
+    /// data specified by return_type. `value_type` is the type of the item when the return type
+    /// is `ListReturnType::Value()`.
+    ///
+    /// ```php
+    /// // Player with lowest score.
+    /// ListExp::getByRank(ListReturnType::Value(), ExpType::String(), Expression::intVal(0), Expression::listBin("a"));
+    /// ```
+    pub fn get_by_rank(
+        return_type: CdtListReturnType,
+        value_type: ExpType,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByRank.into(),
+            Some(return_type._as),
+            Some(value_type),
+            vec![rank],
+            bin,
+            ctx,
+        )
     }
-}
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl CommitLevel {
-    /// CommitAll indicates the server should wait until successfully committing master and all
-    /// replicas.
-    pub fn Commit_All() -> Self {
-        CommitLevel {
-            _as: proto::CommitLevel::CommitAll,
-        }
+    /// Create expression that selects list items starting at specified rank to the last ranked item
+    /// and returns selected data specified by return_type.
+    pub fn get_by_rank_range(
+        return_type: CdtListReturnType,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByRankRange.into(),
+            Some(return_type._as),
+            None,
+            vec![rank],
+            bin,
+            ctx,
+        )
     }
 
-    /// CommitMaster indicates the server should wait until successfully committing master only.
-    pub fn Commit_Master() -> Self {
-        CommitLevel {
-            _as: proto::CommitLevel::CommitMaster,
-        }
+    /// Create expression that selects "count" list items starting at specified rank and returns
+    /// selected data specified by return_type.
+    pub fn get_by_rank_range_count(
+        return_type: CdtListReturnType,
+        rank: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::List,
+            proto::CdtListCommandOp::GetByRankRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![rank, count],
+            bin,
+            ctx,
+        )
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  ConsistencyLevel
+//  MapExp
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// `ConsistencyLevel` indicates how replicas should be consulted in a read
-/// operation to provide the desired consistency guarantee.
-#[derive(Debug, Clone, Copy)]
-pub enum _ConsistencyLevel {
-    ConsistencyOne,
-    ConsistencyAll,
-}
-
-#[php_class(name = "Aerospike\\ConsistencyLevel")]
-pub struct ConsistencyLevel {
-    v: _ConsistencyLevel,
-}
-
-impl FromZval<'_> for ConsistencyLevel {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &ConsistencyLevel = zval.extract()?;
-
-        Some(ConsistencyLevel { v: f.v })
-    }
-}
+/// Map expression generator. The expressions read map bins, or maps nested in other
+/// lists and maps when a context is given, and can be used in filter expressions.
+///
+/// Index, rank and count arguments are integer expressions. The `bin` argument is usually
+/// `Expression::mapBin()`, but can be any expression that returns a map.
+#[php_class(name = "Aerospike\\MapExp")]
+pub struct MapExp {}
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl ConsistencyLevel {
-    /// ConsistencyOne indicates only a single replica should be consulted in
-    /// the read operation.
-    pub fn Consistency_One() -> Self {
-        ConsistencyLevel {
-            v: _ConsistencyLevel::ConsistencyOne,
-        }
+impl MapExp {
+    /// Create expression that returns map size.
+    pub fn size(bin: &Expression, ctx: Option<Vec<&CDTContext>>) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::Size.into(),
+            None,
+            None,
+            vec![],
+            bin,
+            ctx,
+        )
     }
 
-    /// ConsistencyAll indicates that all replicas should be consulted in
-    /// the read operation.
-    pub fn Consistency_All() -> Self {
-        ConsistencyLevel {
-            v: _ConsistencyLevel::ConsistencyAll,
-        }
+    /// Create expression that selects map item identified by key and returns selected data
+    /// specified by return_type. `value_type` is the type of the item when the return type
+    /// is `MapReturnType::Value()`.
+    ///
+    /// ```php
+    /// // attrs["status"] == "active"
+    /// Expression::eq(
+    ///   MapExp::getByKey(MapReturnType::Value(), ExpType::String(), Expression::stringVal("status"), Expression::mapBin("attrs")),
+    ///   Expression::stringVal("active"));
+    /// ```
+    pub fn get_by_key(
+        return_type: CdtMapReturnType,
+        value_type: ExpType,
+        key: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByKey.into(),
+            Some(return_type._as),
+            Some(value_type),
+            vec![key],
+            bin,
+            ctx,
+        )
     }
-}
 
-impl From<&ConsistencyLevel> for proto::ConsistencyLevel {
-    fn from(input: &ConsistencyLevel) -> Self {
-        match &input.v {
-            _ConsistencyLevel::ConsistencyOne => proto::ConsistencyLevel::ConsistencyOne,
-            _ConsistencyLevel::ConsistencyAll => proto::ConsistencyLevel::ConsistencyAll,
-        }
+    /// Create expression that selects map items identified by key range (key_begin inclusive,
+    /// key_end exclusive). If key_begin is null, the range is less than key_end. If key_end is
+    /// null, the range is greater than equal to key_begin.
+    pub fn get_by_key_range(
+        return_type: CdtMapReturnType,
+        key_begin: Option<&Expression>,
+        key_end: Option<&Expression>,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        // a missing end is left out, for a range without an upper bound
+        let nil = Expression::nil();
+        let mut args = vec![key_begin.unwrap_or(&nil)];
+        args.extend(key_end);
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByKeyRange.into(),
+            Some(return_type._as),
+            None,
+            args,
+            bin,
+            ctx,
+        )
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////////////////
-///
-///  GenerationPolicy
-///
-////////////////////////////////////////////////////////////////////////////////////////////
+    /// Create expression that selects map items identified by keys and returns selected data
+    /// specified by return_type. `keys` is a list expression.
+    pub fn get_by_key_list(
+        return_type: CdtMapReturnType,
+        keys: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByKeyList.into(),
+            Some(return_type._as),
+            None,
+            vec![keys],
+            bin,
+            ctx,
+        )
+    }
 
-/// `GenerationPolicy` determines how to handle record writes based on record generation.
-#[php_class(name = "Aerospike\\GenerationPolicy")]
-pub struct GenerationPolicy {
-    _as: proto::GenerationPolicy,
-}
+    /// Create expression that selects map items nearest to key and greater by index.
+    ///
+    /// Examples for ordered map [{0=17},{4=2},{5=15},{9=10}]:
+    ///
+    /// (key,index) = [selected items]
+    /// (5,0) = [{5=15},{9=10}]
+    /// (5,1) = [{9=10}]
+    /// (5,-1) = [{4=2},{5=15},{9=10}]
+    /// (3,2) = [{9=10}]
+    /// (3,-2) = [{0=17},{4=2},{5=15},{9=10}]
+    pub fn get_by_key_relative_index_range(
+        return_type: CdtMapReturnType,
+        key: &Expression,
+        index: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByKeyRelativeIndexRange.into(),
+            Some(return_type._as),
+            None,
+            vec![key, index],
+            bin,
+            ctx,
+        )
+    }
 
-impl FromZval<'_> for GenerationPolicy {
-    const TYPE: DataType = DataType::Mixed;
+    /// Create expression that selects map items nearest to key and greater by index with a count limit.
+    ///
+    /// Examples for ordered map [{0=17},{4=2},{5=15},{9=10}]:
+    ///
+    /// (key,index,count) = [selected items]
+    /// (5,0,1) = [{5=15}]
+    /// (5,1,2) = [{9=10}]
+    /// (5,-1,1) = [{4=2}]
+    /// (3,2,1) = [{9=10}]
+    /// (3,-2,2) = [{0=17}]
+    pub fn get_by_key_relative_index_range_count(
+        return_type: CdtMapReturnType,
+        key: &Expression,
+        index: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByKeyRelativeIndexRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![key, index, count],
+            bin,
+            ctx,
+        )
+    }
 
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &GenerationPolicy = zval.extract()?;
+    /// Create expression that selects map items identified by value and returns selected data
+    /// specified by return_type.
+    pub fn get_by_value(
+        return_type: CdtMapReturnType,
+        value: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByValue.into(),
+            Some(return_type._as),
+            None,
+            vec![value],
+            bin,
+            ctx,
+        )
+    }
 
-        Some(GenerationPolicy { _as: f._as.clone() })
+    /// Create expression that selects map items identified by value range (value_begin inclusive,
+    /// value_end exclusive). If value_begin is null, the range is less than value_end. If value_end
+    /// is null, the range is greater than equal to value_begin.
+    pub fn get_by_value_range(
+        return_type: CdtMapReturnType,
+        value_begin: Option<&Expression>,
+        value_end: Option<&Expression>,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        // a missing end is left out, for a range without an upper bound
+        let nil = Expression::nil();
+        let mut args = vec![value_begin.unwrap_or(&nil)];
+        args.extend(value_end);
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByValueRange.into(),
+            Some(return_type._as),
+            None,
+            args,
+            bin,
+            ctx,
+        )
     }
-}
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl GenerationPolicy {
-    /// None means: Do not use record generation to restrict writes.
-    pub fn None() -> Self {
-        GenerationPolicy {
-            _as: proto::GenerationPolicy::None,
-        }
+    /// Create expression that selects map items identified by values and returns selected data
+    /// specified by return_type. `values` is a list expression.
+    pub fn get_by_value_list(
+        return_type: CdtMapReturnType,
+        values: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByValueList.into(),
+            Some(return_type._as),
+            None,
+            vec![values],
+            bin,
+            ctx,
+        )
     }
 
-    /// ExpectGenEqual means: Update/delete record if expected generation is equal to server
-    /// generation. Otherwise, fail.
-    pub fn Expect_Gen_Equal() -> Self {
-        GenerationPolicy {
-            _as: proto::GenerationPolicy::ExpectGenEqual,
-        }
+    /// Create expression that selects map items nearest to value and greater by relative rank.
+    ///
+    /// Examples for map [{4=2},{9=10},{5=15},{0=17}]:
+    ///
+    /// (value,rank) = [selected items]
+    /// (11,1) = [{0=17}]
+    /// (11,-1) = [{9=10},{5=15},{0=17}]
+    pub fn get_by_value_relative_rank_range(
+        return_type: CdtMapReturnType,
+        value: &Expression,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByValueRelativeRankRange.into(),
+            Some(return_type._as),
+            None,
+            vec![value, rank],
+            bin,
+            ctx,
+        )
     }
 
-    /// ExpectGenGreater means: Update/delete record if expected generation greater than the server
-    /// generation. Otherwise, fail. This is useful for restore after backup.
-    pub fn Expect_Gen_Greater() -> Self {
-        GenerationPolicy {
-            _as: proto::GenerationPolicy::ExpectGenGt,
-        }
+    /// Create expression that selects map items nearest to value and greater by relative rank with a
+    /// count limit.
+    ///
+    /// Examples for map [{4=2},{9=10},{5=15},{0=17}]:
+    ///
+    /// (value,rank,count) = [selected items]
+    /// (11,1,1) = [{0=17}]
+    /// (11,-1,1) = [{9=10}]
+    pub fn get_by_value_relative_rank_range_count(
+        return_type: CdtMapReturnType,
+        value: &Expression,
+        rank: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByValueRelativeRankRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![value, rank, count],
+            bin,
+            ctx,
+        )
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  Expiration
-//
-////////////////////////////////////////////////////////////////////////////////////////////
 
-const NAMESPACE_DEFAULT: u32 = 0x0000_0000;
-const NEVER_EXPIRE: u32 = 0xFFFF_FFFF; // -1 as i32
-const DONT_UPDATE: u32 = 0xFFFF_FFFE;
+    /// Create expression that selects map item identified by index and returns selected data
+    /// specified by return_type. `value_type` is the type of the item when the return type
+    /// is `MapReturnType::Value()`.
+    pub fn get_by_index(
+        return_type: CdtMapReturnType,
+        value_type: ExpType,
+        index: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByIndex.into(),
+            Some(return_type._as),
+            Some(value_type),
+            vec![index],
+            bin,
+            ctx,
+        )
+    }
 
-/// Record expiration, also known as time-to-live (TTL).
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum _Expiration {
-    Seconds(u32),
-    NamespaceDefault,
-    Never,
-    DontUpdate,
-}
+    /// Create expression that selects map items starting at specified index to the end of map and
+    /// returns selected data specified by return_type.
+    pub fn get_by_index_range(
+        return_type: CdtMapReturnType,
+        index: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByIndexRange.into(),
+            Some(return_type._as),
+            None,
+            vec![index],
+            bin,
+            ctx,
+        )
+    }
 
-#[php_class(name = "Aerospike\\Expiration")]
-pub struct Expiration {
-    _as: _Expiration,
-}
+    /// Create expression that selects "count" map items starting at specified index and returns
+    /// selected data specified by return_type.
+    pub fn get_by_index_range_count(
+        return_type: CdtMapReturnType,
+        index: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByIndexRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![index, count],
+            bin,
+            ctx,
+        )
+    }
 
-impl FromZval<'_> for Expiration {
-    const TYPE: DataType = DataType::Mixed;
+    /// Create expression that selects map item identified by rank and returns selected data
+    /// specified by return_type. `value_type` is the type of the item when the return type
+    /// is `MapReturnType::Value()`.
+    pub fn get_by_rank(
+        return_type: CdtMapReturnType,
+        value_type: ExpType,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByRank.into(),
+            Some(return_type._as),
+            Some(value_type),
+            vec![rank],
+            bin,
+            ctx,
+        )
+    }
 
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &Expiration = zval.extract()?;
+    /// Create expression that selects map items starting at specified rank to the last ranked item
+    /// and returns selected data specified by return_type.
+    pub fn get_by_rank_range(
+        return_type: CdtMapReturnType,
+        rank: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByRankRange.into(),
+            Some(return_type._as),
+            None,
+            vec![rank],
+            bin,
+            ctx,
+        )
+    }
 
-        Some(Expiration { _as: f._as.clone() })
+    /// Create expression that selects "count" map items starting at specified rank and returns
+    /// selected data specified by return_type.
+    pub fn get_by_rank_range_count(
+        return_type: CdtMapReturnType,
+        rank: &Expression,
+        count: &Expression,
+        bin: &Expression,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Map,
+            proto::CdtMapCommandOp::GetByRankRangeCount.into(),
+            Some(return_type._as),
+            None,
+            vec![rank, count],
+            bin,
+            ctx,
+        )
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  HllExp
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// HyperLogLog (HLL) expression generator. The expressions read HLL bins and can be used in
+/// filter expressions. Requires server version 5.6.0+.
+///
+/// The `bin` argument is usually `Expression::hllBin()`. `list` arguments are list expressions
+/// of HLL values.
+#[php_class(name = "Aerospike\\HllExp")]
+pub struct HllExp {}
+
 #[php_impl]
 #[derive(ZvalConvert)]
-impl Expiration {
-    /// Set the record to expire X seconds from now.  See also `getTtl()`.
-    pub fn Seconds(seconds: u32) -> Self {
-        Expiration {
-            _as: _Expiration::Seconds(seconds),
-        }
+impl HllExp {
+    /// Create expression that returns estimated number of elements in the HLL bin.
+    ///
+    /// ```php
+    /// // Campaigns with more than 1000 unique visitors.
+    /// Expression::gt(HllExp::getCount(Expression::hllBin("visitors")), Expression::intVal(1000));
+    /// ```
+    pub fn get_count(bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::GetCount.into(),
+            None,
+            None,
+            vec![],
+            bin,
+            None,
+        )
     }
 
-    /// Answers with the expiration's current time to live in units of
-    /// seconds, excluding any special values.  If the expiration is set to
-    /// the namespace default, is configured to never update, or is configured
-    /// to never expire, this method returns null.  See also `Seconds()`.
-    #[getter]
-    pub fn get_ttl(&self) -> Option<u32> {
-        match self._as {
-            _Expiration::Seconds(secs) => Some(secs),
-            _ => None,
-        }
+    /// Create expression that returns an HLL object that is the union of all specified HLL objects
+    /// in the list with the HLL bin.
+    pub fn get_union(list: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::GetUnion.into(),
+            None,
+            None,
+            vec![list],
+            bin,
+            None,
+        )
     }
 
-    /// Set the record's expiry time using the default time-to-live (TTL) value
-    /// for the namespace.  See also `isNamespaceDefault()`.
-    pub fn Namespace_Default() -> Self {
-        Expiration {
-            _as: _Expiration::NamespaceDefault,
-        }
+    /// Create expression that returns estimated number of elements that would be contained by
+    /// the union of these HLL objects.
+    pub fn get_union_count(list: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::GetUnionCount.into(),
+            None,
+            None,
+            vec![list],
+            bin,
+            None,
+        )
     }
 
-    /// Answers true only if the expiration is set to use the namespace default.
-    /// See also `NamespaceDefault()`.
-    #[getter]
-    pub fn is_namespace_default(&self) -> bool {
-        self._as == _Expiration::NamespaceDefault
+    /// Create expression that returns estimated number of elements that would be contained by
+    /// the intersection of these HLL objects.
+    pub fn get_intersect_count(list: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::GetIntersectCount.into(),
+            None,
+            None,
+            vec![list],
+            bin,
+            None,
+        )
     }
 
-    /// Set the record to never expire. Requires Aerospike 2 server version 2.7.2 or later or
-    /// Aerospike 3 server version 3.1.4 or later. Do not use with older servers.
-    /// See also `willNeverExpire()`.
-    pub fn Never() -> Self {
-        Expiration {
-            _as: _Expiration::Never,
-        }
+    /// Create expression that returns estimated similarity of these HLL objects as a
+    /// 64 bit float.
+    pub fn get_similarity(list: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::GetSimilarity.into(),
+            None,
+            None,
+            vec![list],
+            bin,
+            None,
+        )
     }
 
-    /// Answers true only if the expiration is set to never expire.
-    /// See also `Never()`.
-    pub fn will_never_expire(&self) -> bool {
-        self._as == _Expiration::Never
+    /// Create expression that returns index_bit_count and min_hash_bit_count used to create
+    /// the HLL bin in a list of longs. list[0] is index_bit_count and list[1] is min_hash_bit_count.
+    pub fn describe(bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::Describe.into(),
+            None,
+            None,
+            vec![],
+            bin,
+            None,
+        )
     }
 
-    /// Do not change the record's expiry time when updating the record;
-    /// requires Aerospike server version 3.10.1 or later.
-    /// See also `willUpdateExpiration()`.
-    pub fn Dont_Update() -> Self {
-        Expiration {
-            _as: _Expiration::DontUpdate,
-        }
+    /// Create expression that returns one if the HLL bin may contain all items in the list.
+    pub fn may_contain(list: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Hll,
+            proto::CdtHllCommandOp::MayContain.into(),
+            None,
+            None,
+            vec![list],
+            bin,
+            None,
+        )
     }
+}
 
-    /// Answers *true* if the expiration is configured to somehow change during
-    /// a record update.  This can be as simple as an explicit time-to-live, or
-    /// an instruction to use the namespace's default expiration, etc.  Answers
-    /// *false* if the expiration will *not* be changed during a record update
-    /// (e.g., the expiration was constructed with DontUpdate().)
-    #[getter]
-    pub fn will_update_expiration(&self) -> bool {
-        self._as != _Expiration::DontUpdate
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  BitExp
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Bit expression generator. The expressions read blob bins as bitmaps and can be used in
+/// filter expressions. Requires server version 5.6.0+.
+///
+/// Offset orientation is left-to-right. Negative offsets are supported. If the offset is
+/// negative, the offset starts backwards from end of the bitmap. Offset and size arguments are
+/// integer expressions, and the `bin` argument is usually `Expression::blobBin()`.
+#[php_class(name = "Aerospike\\BitExp")]
+pub struct BitExp {}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl BitExp {
+    /// Create expression that returns bits from the blob bin starting at bit_offset for bit_size.
+    ///
+    /// ```php
+    /// // Bin "a" bits = 0b10000000
+    /// Expression::eq(
+    ///   BitExp::get(Expression::intVal(0), Expression::intVal(1), Expression::blobBin("a")),
+    ///   Expression::blobVal([0b10000000]));
+    /// ```
+    pub fn get(bit_offset: &Expression, bit_size: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Blob,
+            proto::CdtBitwiseCommandOp::Get.into(),
+            None,
+            None,
+            vec![bit_offset, bit_size],
+            bin,
+            None,
+        )
     }
-}
 
-impl From<&Expiration> for u32 {
-    fn from(exp: &Expiration) -> u32 {
-        match &exp._as {
-            _Expiration::Seconds(secs) => *secs,
-            _Expiration::NamespaceDefault => NAMESPACE_DEFAULT,
-            _Expiration::Never => NEVER_EXPIRE,
-            _Expiration::DontUpdate => DONT_UPDATE,
-        }
+    /// Create expression that returns the integer count of set bits from the blob bin starting
+    /// at bit_offset for bit_size.
+    pub fn count(bit_offset: &Expression, bit_size: &Expression, bin: &Expression) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Blob,
+            proto::CdtBitwiseCommandOp::Count.into(),
+            None,
+            None,
+            vec![bit_offset, bit_size],
+            bin,
+            None,
+        )
     }
-}
 
-impl From<u32> for Expiration {
-    fn from(exp: u32) -> Expiration {
-        match exp {
-            NAMESPACE_DEFAULT => Expiration::Namespace_Default(),
-            NEVER_EXPIRE => Expiration::Never(),
-            DONT_UPDATE => Expiration::Dont_Update(),
-            secs => Expiration::Seconds(secs),
-        }
+    /// Create expression that returns the integer bit offset of the first specified value bit
+    /// in the blob bin starting at bit_offset for bit_size, or -1 if not found.
+    /// `value` is a boolean expression.
+    pub fn lscan(
+        bit_offset: &Expression,
+        bit_size: &Expression,
+        value: &Expression,
+        bin: &Expression,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Blob,
+            proto::CdtBitwiseCommandOp::LScan.into(),
+            None,
+            None,
+            vec![bit_offset, bit_size, value],
+            bin,
+            None,
+        )
+    }
+
+    /// Create expression that returns the integer bit offset of the last specified value bit
+    /// in the blob bin starting at bit_offset for bit_size, or -1 if not found.
+    /// `value` is a boolean expression.
+    pub fn rscan(
+        bit_offset: &Expression,
+        bit_size: &Expression,
+        value: &Expression,
+        bin: &Expression,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Blob,
+            proto::CdtBitwiseCommandOp::RScan.into(),
+            None,
+            None,
+            vec![bit_offset, bit_size, value],
+            bin,
+            None,
+        )
+    }
+
+    /// Create expression that returns the integer from the blob bin starting at bit_offset for
+    /// bit_size. Signed indicates if bits should be treated as a signed number.
+    pub fn get_int(
+        bit_offset: &Expression,
+        bit_size: &Expression,
+        signed: bool,
+        bin: &Expression,
+    ) -> Expression {
+        Expression::module_call(
+            proto::ExpType::Blob,
+            proto::CdtBitwiseCommandOp::GetInt.into(),
+            Some(signed as i32),
+            None,
+            vec![bit_offset, bit_size],
+            bin,
+            None,
+        )
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  Concurrency
+//  ReadModeAP
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
-/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
-/// thread pool.
-#[derive(Debug, Clone, Copy)]
-pub enum _Concurrency {
-    Sequential,
-    Parallel,
-    MaxThreads(u32),
-}
-
-#[php_class(name = "Aerospike\\Concurrency")]
-pub struct Concurrency {
-    v: _Concurrency,
-}
-
-impl FromZval<'_> for Concurrency {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &Concurrency = zval.extract()?;
-
-        Some(Concurrency { v: f.v })
-    }
+/// ReadModeAP is the read policy in AP (availability) mode namespaces.
+/// It indicates how duplicates should be consulted in a read operation.
+/// Only makes a difference during migrations and only applicable in AP mode.
+#[php_class(name = "Aerospike\\ReadModeAP")]
+pub struct ReadModeAP {
+    _as: proto::ReadModeAp,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl Concurrency {
-    /// Issue commands sequentially. This mode has a performance advantage for small to
-    /// medium sized batch sizes because requests can be issued in the main transaction thread.
-    /// This is the default.
-    pub fn Sequential() -> Self {
-        Concurrency {
-            v: _Concurrency::Sequential,
+impl ReadModeAP {
+    /// ReadModeAPOne indicates that a single node should be involved in the read operation.
+    pub fn One() -> Self {
+        ReadModeAP {
+            _as: proto::ReadModeAp::One,
         }
     }
 
-    /// Issue all commands in parallel threads. This mode has a performance advantage for
-    /// extremely large batch sizes because each node can process the request immediately. The
-    /// downside is extra threads will need to be created (or takedn from a thread pool).
-    pub fn Parallel() -> Self {
-        Concurrency {
-            v: _Concurrency::Parallel,
+    /// ReadModeAPAll indicates that all duplicates should be consulted in
+    /// the read operation.
+    pub fn All() -> Self {
+        ReadModeAP {
+            _as: proto::ReadModeAp::All,
         }
     }
+}
 
-    /// Issue up to N commands in parallel threads. When a request completes, a new request
-    /// will be issued until all threads are complete. This mode prevents too many parallel threads
-    /// being created for large cluster implementations. The downside is extra threads will still
-    /// need to be created (or taken from a thread pool).
-    ///
-    /// E.g. if there are 16 nodes/namespace combinations requested and concurrency is set to
-    /// `MaxThreads(8)`, then batch requests will be made for 8 node/namespace combinations in
-    /// parallel threads. When a request completes, a new request will be issued until all 16
-    /// requests are complete.
-    pub fn Max_Threads(threads: u32) -> Self {
-        Concurrency {
-            v: _Concurrency::MaxThreads(threads),
+impl From<&ReadModeAP> for i32 {
+    fn from(v: &ReadModeAP) -> i32 {
+        match v._as {
+            proto::ReadModeAp::One => 0,
+            proto::ReadModeAp::All => 1,
         }
     }
 }
 
-impl From<&Concurrency> for u32 {
-    fn from(input: &Concurrency) -> Self {
-        match &input.v {
-            _Concurrency::Sequential => 1,
-            _Concurrency::Parallel => 0,
-            _Concurrency::MaxThreads(threads) => *threads,
+impl From<i32> for ReadModeAP {
+    fn from(v: i32) -> ReadModeAP {
+        match v {
+            0 => ReadModeAP {
+                _as: proto::ReadModeAp::One,
+            },
+            1 => ReadModeAP {
+                _as: proto::ReadModeAp::All,
+            },
+            _ => unreachable!(),
         }
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  ListOrderType
-//
-////////////////////////////////////////////////////////////////////////////////////////////
-
-/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
-/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
-/// thread pool.
-#[php_class(name = "Aerospike\\ListOrderType")]
-#[derive(PartialEq)]
-pub struct ListOrderType {
-    _as: proto::ListOrderType,
-}
-
-impl FromZval<'_> for ListOrderType {
+impl FromZval<'_> for ReadModeAP {
     const TYPE: DataType = DataType::Mixed;
 
     fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &ListOrderType = zval.extract()?;
+        let f: &ReadModeAP = zval.extract()?;
 
-        Some(ListOrderType { _as: f._as })
+        Some(ReadModeAP { _as: f._as.clone() })
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ReadModeSC
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ReadModeSC is the read policy in SC (strong consistency) mode namespaces.
+/// Determines SC read consistency options.
+#[php_class(name = "Aerospike\\ReadModeSC")]
+pub struct ReadModeSC {
+    _as: proto::ReadModeSc,
+}
+
 #[php_impl]
 #[derive(ZvalConvert)]
-impl ListOrderType {
-    fn flag(&self) -> i32 {
-        match self._as {
-            proto::ListOrderType::Unordered => 0,
-            proto::ListOrderType::Ordered => 1,
+impl ReadModeSC {
+    /// ReadModeSCSession ensures this client will only see an increasing sequence of record versions.
+    /// Server only reads from master.  This is the default.
+    pub fn Session() -> Self {
+        ReadModeSC {
+            _as: proto::ReadModeSc::Session,
         }
     }
 
-    /// ListOrderOrdered signifies that list is Ordered.
-    pub fn Ordered() -> Self {
-        ListOrderType {
-            _as: proto::ListOrderType::Ordered,
+    /// ReadModeSCLinearize ensures ALL clients will only see an increasing sequence of record versions.
+    /// Server only reads from master.
+    pub fn Linearize() -> Self {
+        ReadModeSC {
+            _as: proto::ReadModeSc::Linearize,
         }
     }
 
-    /// ListOrderUnordered signifies that list is not ordered. This is the default.
-    pub fn Unordered() -> Self {
-        ListOrderType {
-            _as: proto::ListOrderType::Unordered,
+    /// ReadModeSCAllowReplica indicates that the server may read from master or any full (non-migrating) replica.
+    /// Increasing sequence of record versions is not guaranteed.
+    pub fn AllowReplica() -> Self {
+        ReadModeSC {
+            _as: proto::ReadModeSc::AllowReplica,
+        }
+    }
+
+    /// ReadModeSCAllowUnavailable indicates that the server may read from master or any full (non-migrating) replica or from unavailable
+    /// partitions.  Increasing sequence of record versions is not guaranteed.
+    pub fn AllowUnavailable() -> Self {
+        ReadModeSC {
+            _as: proto::ReadModeSc::AllowUnavailable,
         }
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  MapOrderType
-//
-////////////////////////////////////////////////////////////////////////////////////////////
+impl From<&ReadModeSC> for i32 {
+    fn from(v: &ReadModeSC) -> i32 {
+        match &v._as {
+            proto::ReadModeSc::Session => 0,
+            proto::ReadModeSc::Linearize => 1,
+            proto::ReadModeSc::AllowReplica => 2,
+            proto::ReadModeSc::AllowUnavailable => 3,
+        }
+    }
+}
 
-/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
-/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
-/// thread pool.
-#[php_class(name = "Aerospike\\MapOrderType")]
-pub struct MapOrderType {
-    _as: proto::MapOrderType,
+impl From<i32> for ReadModeSC {
+    fn from(v: i32) -> ReadModeSC {
+        match v {
+            0 => ReadModeSC {
+                _as: proto::ReadModeSc::Session,
+            },
+            1 => ReadModeSC {
+                _as: proto::ReadModeSc::Linearize,
+            },
+            2 => ReadModeSC {
+                _as: proto::ReadModeSc::AllowReplica,
+            },
+            3 => ReadModeSC {
+                _as: proto::ReadModeSc::AllowUnavailable,
+            },
+            _ => unreachable!(),
+        }
+    }
 }
 
-impl FromZval<'_> for MapOrderType {
+impl FromZval<'_> for ReadModeSC {
     const TYPE: DataType = DataType::Mixed;
 
     fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &MapOrderType = zval.extract()?;
+        let f: &ReadModeSC = zval.extract()?;
 
-        Some(MapOrderType { _as: f._as })
+        Some(ReadModeSC { _as: f._as.clone() })
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ReplicaPolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ReplicaPolicy defines the algorithm used to determine the target node for a command.
+#[php_class(name = "Aerospike\\ReplicaPolicy")]
+pub struct ReplicaPolicy {
+    _as: proto::ReplicaPolicy,
+}
+
 #[php_impl]
 #[derive(ZvalConvert)]
-impl MapOrderType {
-    fn attr(&self) -> i32 {
-        match self._as {
-            proto::MapOrderType::Unordered => 0,
-            proto::MapOrderType::KeyOrdered => 1,
-            proto::MapOrderType::KeyValueOrdered => 3,
+impl ReplicaPolicy {
+    /// Sequence tries the node containing the master partition first.
+    /// If the connection fails, all commands try nodes containing replicated partitions.
+    /// If socketTimeout is reached, reads also try nodes containing replicated partitions,
+    /// but writes remain on master node. This is the default.
+    pub fn Sequence() -> Self {
+        ReplicaPolicy {
+            _as: proto::ReplicaPolicy::Sequence,
         }
     }
 
-    fn flag(&self) -> i32 {
-        match self._as {
-            proto::MapOrderType::Unordered => 0x40,
-            proto::MapOrderType::KeyOrdered => 0x80,
-            proto::MapOrderType::KeyValueOrdered => 0xc0,
+    /// Master uses the node containing the key's master partition.
+    pub fn Master() -> Self {
+        ReplicaPolicy {
+            _as: proto::ReplicaPolicy::Master,
         }
     }
 
-    /// Map is not ordered. This is the default.
-    pub fn Unordered() -> Self {
-        MapOrderType {
-            _as: proto::MapOrderType::Unordered,
+    /// MasterProles distributes reads across nodes containing the key's master and replicated partitions
+    /// in round-robin fashion. Writes always use the node containing the key's master partition.
+    pub fn MasterProles() -> Self {
+        ReplicaPolicy {
+            _as: proto::ReplicaPolicy::MasterProles,
         }
     }
 
-    /// Order map by key.
-    pub fn Key_Ordered() -> Self {
-        MapOrderType {
-            _as: proto::MapOrderType::KeyOrdered,
+    /// PreferRack tries the node on the same rack as the client first. If timeout or there are no nodes on the
+    /// same rack, uses Sequence instead.
+    ///
+    /// The connection manager must be configured with `rack-aware = true` and a `rack-id` or `rack-ids`
+    /// for this to have any effect.
+    pub fn PreferRack() -> Self {
+        ReplicaPolicy {
+            _as: proto::ReplicaPolicy::PreferRack,
         }
     }
 
-    /// Order map by key, then value.
-    pub fn Key_Value_Ordered() -> Self {
-        MapOrderType {
-            _as: proto::MapOrderType::KeyValueOrdered,
+    /// Random distributes reads across all nodes in the cluster in round-robin fashion.
+    /// Writes always use the node containing the key's master partition.
+    /// This option is useful when the replication factor equals the number
+    /// of nodes in the cluster and the overhead of requesting proles is not desired.
+    pub fn Random() -> Self {
+        ReplicaPolicy {
+            _as: proto::ReplicaPolicy::Random,
+        }
+    }
+}
+
+impl From<&ReplicaPolicy> for i32 {
+    fn from(v: &ReplicaPolicy) -> i32 {
+        match &v._as {
+            proto::ReplicaPolicy::Sequence => 0,
+            proto::ReplicaPolicy::Master => 1,
+            proto::ReplicaPolicy::MasterProles => 2,
+            proto::ReplicaPolicy::PreferRack => 3,
+            proto::ReplicaPolicy::Random => 4,
+        }
+    }
+}
+
+impl From<i32> for ReplicaPolicy {
+    fn from(v: i32) -> ReplicaPolicy {
+        match v {
+            0 => ReplicaPolicy {
+                _as: proto::ReplicaPolicy::Sequence,
+            },
+            1 => ReplicaPolicy {
+                _as: proto::ReplicaPolicy::Master,
+            },
+            2 => ReplicaPolicy {
+                _as: proto::ReplicaPolicy::MasterProles,
+            },
+            3 => ReplicaPolicy {
+                _as: proto::ReplicaPolicy::PreferRack,
+            },
+            4 => ReplicaPolicy {
+                _as: proto::ReplicaPolicy::Random,
+            },
+            _ => unreachable!(),
         }
     }
 }
 
+impl FromZval<'_> for ReplicaPolicy {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &ReplicaPolicy = zval.extract()?;
+
+        Some(ReplicaPolicy { _as: f._as.clone() })
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  CDTContext
+//  RecordExistsAction
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-enum CDTContextType {
-    ListIndex = 0x10,
-    ListRank = 0x11,
-    ListValue = 0x13,
-    MapIndex = 0x20,
-    MapRank = 0x21,
-    MapKey = 0x22,
-    MapValue = 0x23,
+/// RecordExistsAction determines how to handle writes when
+/// the record already exists.
+#[php_class(name = "Aerospike\\RecordExistsAction")]
+pub struct RecordExistsAction {
+    _as: proto::RecordExistsAction,
 }
 
-/// CDTContext defines Nested CDT context. Identifies the location of nested list/map to apply the operation.
-/// for the current level.
-/// An array of CTX identifies location of the list/map on multiple
-/// levels on nesting.
-#[php_class(name = "Aerospike\\Context")]
-pub struct CDTContext {
-    _as: proto::CdtContext,
-}
+impl FromZval<'_> for RecordExistsAction {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &RecordExistsAction = zval.extract()?;
+
+        Some(RecordExistsAction { _as: f._as.clone() })
+    }
+}
 
-/// `CDTContext` excapsulates parameters for transaction policy attributes
-/// used in all database operation calls.
 #[php_impl]
 #[derive(ZvalConvert)]
-impl CDTContext {
-    pub fn __construct() -> Self {
-        CDTContext {
-            _as: proto::CdtContext::default(),
+impl RecordExistsAction {
+    /// Update means: Create or update record.
+    /// Merge write command bins with existing bins.
+    pub fn Update() -> Self {
+        RecordExistsAction {
+            _as: proto::RecordExistsAction::Update,
         }
     }
 
-    fn list_order_flag(order: ListOrderType, pad: bool) -> i32 {
-        if order.flag() == 1 {
-            return 0xc0;
-        }
-
-        if pad {
-            return 0x80;
+    /// UpdateOnly means: Update record only. Fail if record does not exist.
+    /// Merge write command bins with existing bins.
+    pub fn Update_Only() -> Self {
+        RecordExistsAction {
+            _as: proto::RecordExistsAction::UpdateOnly,
         }
-
-        return 0x40;
     }
 
-    /// CtxListIndex defines Lookup list by index offset.
-    /// If the index is negative, the resolved index starts backwards from end of list.
-    /// If an index is out of bounds, a parameter error will be returned.
-    /// Examples:
-    /// 0: First item.
-    /// 4: Fifth item.
-    /// -1: Last item.
-    /// -3: Third to last item.
-    pub fn ListIndex(index: i32) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::ListIndex as i32,
-                value: Some(PHPValue::Int(index.into()).into()),
-            },
+    /// Replace means: Create or replace record.
+    /// Delete existing bins not referenced by write command bins.
+    /// Supported by Aerospike 2 server versions >= 2.7.5 and
+    /// Aerospike 3 server versions >= 3.1.6.
+    pub fn Replace() -> Self {
+        RecordExistsAction {
+            _as: proto::RecordExistsAction::Replace,
         }
     }
 
-    /// CtxListIndexCreate list with given type at index offset, given an order and pad.
-    pub fn ListIndexCreate(index: i32, order: ListOrderType, pad: bool) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::ListIndex as i32 | Self::list_order_flag(order, pad),
-                value: Some(PHPValue::Int(index.into()).into()),
-            },
+    /// ReplaceOnly means: Replace record only. Fail if record does not exist.
+    /// Delete existing bins not referenced by write command bins.
+    /// Supported by Aerospike 2 server versions >= 2.7.5 and
+    /// Aerospike 3 server versions >= 3.1.6.
+    pub fn Replace_Only() -> Self {
+        RecordExistsAction {
+            _as: proto::RecordExistsAction::ReplaceOnly,
         }
     }
 
-    /// CtxListRank defines Lookup list by rank.
-    /// 0 = smallest value
-    /// N = Nth smallest value
-    /// -1 = largest value
-    pub fn ListRank(rank: i32) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::ListRank as i32,
-                value: Some(PHPValue::Int(rank.into()).into()),
-            },
+    /// CreateOnly means: Create only. Fail if record exists.
+    pub fn Create_Only() -> Self {
+        RecordExistsAction {
+            _as: proto::RecordExistsAction::CreateOnly,
         }
     }
+}
 
-    /// CtxListValue defines Lookup list by value.
-    pub fn ListValue(key: PHPValue) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::ListValue as i32,
-                value: Some(key.into()),
-            },
-        }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  QueryDuration
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// QueryDuration represents the expected duration for a query operation in the Aerospike database.
+#[php_class(name = "Aerospike\\QueryDuration")]
+pub struct QueryDuration {
+    _as: proto::QueryDuration,
+}
+
+impl FromZval<'_> for QueryDuration {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &QueryDuration = zval.extract()?;
+
+        Some(QueryDuration { _as: f._as.clone() })
     }
+}
 
-    /// CtxMapIndex defines Lookup map by index offset.
-    /// If the index is negative, the resolved index starts backwards from end of list.
-    /// If an index is out of bounds, a parameter error will be returned.
-    /// Examples:
-    /// 0: First item.
-    /// 4: Fifth item.
-    /// -1: Last item.
-    /// -3: Third to last item.
-    pub fn MapIndex(index: i32) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::MapIndex as i32,
-                value: Some(PHPValue::Int(index.into()).into()),
-            },
+#[php_impl]
+#[derive(ZvalConvert)]
+impl QueryDuration {
+    /// LONG specifies that the query is expected to return more than 100 records per node. The server optimizes for a large record set in
+    /// the following ways:
+    ///
+    /// Allow query to be run in multiple threads using the server's query threading configuration.
+    /// Do not relax read consistency for AP namespaces.
+    /// Add the query to the server's query monitor.
+    /// Do not add the overall latency to the server's latency histogram.
+    /// Do not allow server timeouts.    
+    pub fn Long() -> Self {
+        QueryDuration {
+            _as: proto::QueryDuration::Long,
         }
     }
 
-    /// CtxMapRank defines Lookup map by rank.
-    /// 0 = smallest value
-    /// N = Nth smallest value
-    /// -1 = largest value
-    pub fn MapRank(rank: i32) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::MapRank as i32,
-                value: Some(PHPValue::Int(rank.into()).into()),
-            },
+    /// Short specifies that the query is expected to return less than 100 records per node. The server optimizes for a small record set in
+    /// the following ways:
+    /// Always run the query in one thread and ignore the server's query threading configuration.
+    /// Allow query to be inlined directly on the server's service thread.
+    /// Relax read consistency for AP namespaces.
+    /// Do not add the query to the server's query monitor.
+    /// Add the overall latency to the server's latency histogram.
+    /// Allow server timeouts. The default server timeout for a short query is 1 second.
+    pub fn Short() -> Self {
+        QueryDuration {
+            _as: proto::QueryDuration::Short,
         }
     }
 
-    /// CtxMapKey defines Lookup map by key.
-    pub fn MapKey(key: PHPValue) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::MapKey as i32,
-                value: Some(key.into()),
-            },
+    /// LongRelaxAP will treat query as a LONG query, but relax read consistency for AP namespaces.
+    /// This value is treated exactly like LONG for server versions < 7.1.
+    pub fn LongRelaxAP() -> Self {
+        QueryDuration {
+            _as: proto::QueryDuration::LongRelaxAp,
         }
     }
+}
 
-    /// CtxMapKeyCreate creates map with given type at map key.
-    pub fn MapKeyCreate(key: PHPValue, order: MapOrderType) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::MapKey as i32 | order.flag(),
-                value: Some(key.into()),
-            },
-        }
+impl From<&proto::QueryDuration> for QueryDuration {
+    fn from(input: &proto::QueryDuration) -> Self {
+        QueryDuration { _as: input.clone() }
     }
+}
 
-    /// CtxMapValue defines Lookup map by value.
-    pub fn MapValue(key: PHPValue) -> Self {
-        CDTContext {
-            _as: proto::CdtContext {
-                id: CDTContextType::MapValue as i32,
-                value: Some(key.into()),
-            },
+impl From<i32> for QueryDuration {
+    fn from(input: i32) -> Self {
+        match input {
+            0 => QueryDuration::Long(),
+            1 => QueryDuration::Short(),
+            2 => QueryDuration::LongRelaxAP(),
+            _ => unreachable!(),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  ReadPolicy
+//  CommitLevel
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// `ReadPolicy` encapsulates parameters for transaction policy attributes
-/// used in all database operation calls.
-#[php_class(name = "Aerospike\\ReadPolicy")]
-pub struct ReadPolicy {
-    _as: proto::ReadPolicy,
+/// CommitLevel indicates the desired consistency guarantee when committing a transaction on the server.
+#[php_class(name = "Aerospike\\CommitLevel")]
+pub struct CommitLevel {
+    _as: proto::CommitLevel,
+}
+
+impl FromZval<'_> for CommitLevel {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &CommitLevel = zval.extract()?;
+
+        Some(CommitLevel { _as: f._as.clone() })
+    }
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl ReadPolicy {
-    pub fn __construct() -> Self {
-        ReadPolicy::default()
+impl CommitLevel {
+    /// CommitAll indicates the server should wait until successfully committing master and all
+    /// replicas.
+    pub fn Commit_All() -> Self {
+        CommitLevel {
+            _as: proto::CommitLevel::CommitAll,
+        }
     }
 
-    /// MaxRetries determines the maximum number of retries before aborting the current transaction.
-    /// The initial attempt is not counted as a retry.
-    ///
-    /// If MaxRetries is exceeded, the transaction will abort with an error.
-    ///
-    /// WARNING: Database writes that are not idempotent (such as AddOp)
-    /// should not be retried because the write operation may be performed
-    /// multiple times if the client timed out previous transaction attempts.
-    /// It's important to use a distinct WritePolicy for non-idempotent
-    /// writes which sets maxRetries = 0;
-    ///
-    /// Default for read: 2 (initial attempt + 2 retries = 3 attempts)
-    ///
-    /// Default for write: 0 (no retries)
-    ///
-    /// Default for partition scan or query with nil filter: 5
-    /// (6 attempts. See ScanPolicy comments.)
-    #[getter]
-    pub fn get_max_retries(&self) -> u32 {
-        self._as.max_retries
+    /// CommitMaster indicates the server should wait until successfully committing master only.
+    pub fn Commit_Master() -> Self {
+        CommitLevel {
+            _as: proto::CommitLevel::CommitMaster,
+        }
     }
+}
 
-    #[setter]
-    pub fn set_max_retries(&mut self, max_retries: u32) {
-        self._as.max_retries = max_retries;
-    }
-
-    /// SleepMultiplier specifies the multiplying factor to be used for exponential backoff during retries.
-    /// Default to (1.0); Only values greater than 1 are valid.
-    #[getter]
-    pub fn get_sleep_multiplier(&self) -> f64 {
-        self._as.sleep_multiplier
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ConsistencyLevel
+//
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    #[setter]
-    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
-        self._as.sleep_multiplier = sleep_multiplier;
-    }
+/// `ConsistencyLevel` indicates how replicas should be consulted in a read
+/// operation to provide the desired consistency guarantee.
+#[derive(Debug, Clone, Copy)]
+pub enum _ConsistencyLevel {
+    ConsistencyOne,
+    ConsistencyAll,
+}
 
-    /// TotalTimeout specifies total transaction timeout.
-    ///
-    /// The TotalTimeout is tracked on the client and also sent to the server along
-    /// with the transaction in the wire protocol. The client will most likely
-    /// timeout first, but the server has the capability to Timeout the transaction.
-    ///
-    /// If TotalTimeout is not zero and TotalTimeout is reached before the transaction
-    /// completes, the transaction will abort with TotalTimeout error.
-    ///
-    /// If TotalTimeout is zero, there will be no time limit and the transaction will retry
-    /// on network timeouts/errors until MaxRetries is exceeded. If MaxRetries is exceeded, the
-    /// transaction also aborts with Timeout error.
-    ///
-    /// Default for scan/query: 0 (no time limit and rely on MaxRetries)
-    ///
-    /// Default for all other commands: 1000ms
-    #[getter]
-    pub fn get_total_timeout(&self) -> u64 {
-        self._as.total_timeout
-    }
+#[php_class(name = "Aerospike\\ConsistencyLevel")]
+pub struct ConsistencyLevel {
+    v: _ConsistencyLevel,
+}
 
-    #[setter]
-    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
-        self._as.total_timeout = timeout_millis;
-    }
+impl FromZval<'_> for ConsistencyLevel {
+    const TYPE: DataType = DataType::Mixed;
 
-    /// SocketTimeout determines network timeout for each attempt.
-    ///
-    /// If SocketTimeout is not zero and SocketTimeout is reached before an attempt completes,
-    /// the Timeout above is checked. If Timeout is not exceeded, the transaction
-    /// is retried. If both SocketTimeout and Timeout are non-zero, SocketTimeout must be less
-    /// than or equal to Timeout, otherwise Timeout will also be used for SocketTimeout.
-    ///
-    /// Default: 30s
-    #[getter]
-    pub fn get_socket_timeout(&self) -> u64 {
-        self._as.socket_timeout
-    }
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &ConsistencyLevel = zval.extract()?;
 
-    #[setter]
-    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
-        self._as.socket_timeout = timeout_millis;
+        Some(ConsistencyLevel { v: f.v })
     }
+}
 
-    /// ReadTouchTTLPercent determines how record TTL (time to live) is affected on reads. When enabled, the server can
-    /// efficiently operate as a read-based LRU cache where the least recently used records are expired.
-    /// The value is expressed as a percentage of the TTL sent on the most recent write such that a read
-    /// within this interval of the record’s end of life will generate a touch.
-    ///
-    /// For example, if the most recent write had a TTL of 10 hours and read_touch_ttl_percent is set to
-    /// 80, the next read within 8 hours of the record's end of life (equivalent to 2 hours after the most
-    /// recent write) will result in a touch, resetting the TTL to another 10 hours.
-    ///
-    /// Values:
-    ///
-    /// 0 : Use server config default-read-touch-ttl-pct for the record's namespace/set.
-    /// -1 : Do not reset record TTL on reads.
-    /// 1 - 100 : Reset record TTL on reads when within this percentage of the most recent write TTL.
-    /// Default: 0
-    #[getter]
-    pub fn get_read_touch_ttl_percent(&self) -> i32 {
-        self._as.read_touch_ttl_percent
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ConsistencyLevel {
+    /// ConsistencyOne indicates only a single replica should be consulted in
+    /// the read operation.
+    pub fn Consistency_One() -> Self {
+        ConsistencyLevel {
+            v: _ConsistencyLevel::ConsistencyOne,
+        }
     }
 
-    #[setter]
-    pub fn set_read_touch_ttl_percent(&mut self, percent: i32) {
-        self._as.read_touch_ttl_percent = percent;
+    /// ConsistencyAll indicates that all replicas should be consulted in
+    /// the read operation.
+    pub fn Consistency_All() -> Self {
+        ConsistencyLevel {
+            v: _ConsistencyLevel::ConsistencyAll,
+        }
     }
+}
 
-    /// SendKey determines to whether send user defined key in addition to hash digest on both reads and writes.
-    /// If the key is sent on a write, the key will be stored with the record on
-    /// the server.
-    /// The default is to not send the user defined key.
-    #[getter]
-    pub fn get_send_key(&self) -> bool {
-        self._as.send_key
+impl From<&ConsistencyLevel> for proto::ConsistencyLevel {
+    fn from(input: &ConsistencyLevel) -> Self {
+        match &input.v {
+            _ConsistencyLevel::ConsistencyOne => proto::ConsistencyLevel::ConsistencyOne,
+            _ConsistencyLevel::ConsistencyAll => proto::ConsistencyLevel::ConsistencyAll,
+        }
     }
+}
 
-    #[setter]
-    pub fn set_send_key(&mut self, send_key: bool) {
-        self._as.send_key = send_key;
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+///
+///  GenerationPolicy
+///
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    /// UseCompression uses zlib compression on command buffers sent to the server and responses received
-    /// from the server when the buffer size is greater than 128 bytes.
-    ///
-    /// This option will increase cpu and memory usage (for extra compressed buffers),but
-    /// decrease the size of data sent over the network.
-    ///
-    /// Default: false
-    #[getter]
-    pub fn get_use_compression(&self) -> bool {
-        self._as.use_compression
-    }
+/// `GenerationPolicy` determines how to handle record writes based on record generation.
+#[php_class(name = "Aerospike\\GenerationPolicy")]
+pub struct GenerationPolicy {
+    _as: proto::GenerationPolicy,
+}
 
-    #[setter]
-    pub fn set_use_compression(&mut self, use_compression: bool) {
-        self._as.use_compression = use_compression;
-    }
+impl FromZval<'_> for GenerationPolicy {
+    const TYPE: DataType = DataType::Mixed;
 
-    /// ExitFastOnExhaustedConnectionPool determines if a command that tries to get a
-    /// connection from the connection pool will wait and retry in case the pool is
-    /// exhausted until a connection becomes available (or the TotalTimeout is reached).
-    /// If set to true, an error will be return immediately.
-    /// If set to false, getting a connection will be retried.
-    /// This only applies if LimitConnectionsToQueueSize is set to true and the number of open connections to a node has reached ConnectionQueueSize.
-    /// The default is false
-    #[getter]
-    pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
-        self._as.exit_fast_on_exhausted_connection_pool
-    }
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &GenerationPolicy = zval.extract()?;
 
-    #[setter]
-    pub fn set_exit_fast_on_exhausted_connection_pool(
-        &mut self,
-        exit_fast_on_exhausted_connection_pool: bool,
-    ) {
-        self._as.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool;
+        Some(GenerationPolicy { _as: f._as.clone() })
     }
+}
 
-    /// ReadModeAP indicates read policy for AP (availability) namespaces.
-    #[getter]
-    pub fn get_read_mode_ap(&self) -> ReadModeAP {
-        ReadModeAP {
-            _as: match self._as.read_mode_ap {
-                0 => proto::ReadModeAp::One,
-                1 => proto::ReadModeAp::All,
-                _ => unreachable!(),
-            },
+#[php_impl]
+#[derive(ZvalConvert)]
+impl GenerationPolicy {
+    /// None means: Do not use record generation to restrict writes.
+    pub fn None() -> Self {
+        GenerationPolicy {
+            _as: proto::GenerationPolicy::None,
         }
     }
 
-    #[setter]
-    pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
-        self._as.read_mode_ap = read_mode_ap._as.into();
+    /// ExpectGenEqual means: Update/delete record if expected generation is equal to server
+    /// generation. Otherwise, fail.
+    pub fn Expect_Gen_Equal() -> Self {
+        GenerationPolicy {
+            _as: proto::GenerationPolicy::ExpectGenEqual,
+        }
     }
 
-    /// ReadModeSC indicates read policy for SC (strong consistency) namespaces.
-    #[getter]
-    pub fn get_read_mode_sc(&self) -> ReadModeSC {
-        ReadModeSC {
-            _as: match self._as.read_mode_ap {
-                0 => proto::ReadModeSc::Session,
-                1 => proto::ReadModeSc::Linearize,
-                2 => proto::ReadModeSc::AllowReplica,
-                3 => proto::ReadModeSc::AllowUnavailable,
-                _ => unreachable!(),
-            },
+    /// ExpectGenGreater means: Update/delete record if expected generation greater than the server
+    /// generation. Otherwise, fail. This is useful for restore after backup.
+    pub fn Expect_Gen_Greater() -> Self {
+        GenerationPolicy {
+            _as: proto::GenerationPolicy::ExpectGenGt,
         }
     }
+}
 
-    #[setter]
-    pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
-        self._as.read_mode_sc = read_mode_sc._as.into();
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Expiration
+//
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    /// FilterExpression is the optional Filter Expression. Supported on Server v5.2+
-    #[getter]
-    pub fn get_filter_expression(&self) -> Option<Expression> {
-        self._as
-            .filter_expression
-            .clone()
-            .map(|fe| Expression { _as: fe })
-    }
+const NAMESPACE_DEFAULT: u32 = 0x0000_0000;
+const NEVER_EXPIRE: u32 = 0xFFFF_FFFF; // -1 as i32
+const DONT_UPDATE: u32 = 0xFFFF_FFFE;
 
-    #[setter]
-    pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self._as.filter_expression = Some(fe._as),
-            None => self._as.filter_expression = None,
-        }
-    }
+/// Record expiration, also known as time-to-live (TTL).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum _Expiration {
+    Seconds(u32),
+    NamespaceDefault,
+    Never,
+    DontUpdate,
 }
 
-impl Default for ReadPolicy {
-    fn default() -> Self {
-        ReadPolicy {
-            _as: proto::ReadPolicy {
-                max_retries: 3,
-                sleep_multiplier: 1.0,
-                total_timeout: 1000,
-                socket_timeout: 500,
-                send_key: false,
-                use_compression: false,
-                exit_fast_on_exhausted_connection_pool: false,
-                read_mode_ap: proto::ReadModeAp::One.into(),
-                read_mode_sc: proto::ReadModeSc::Session.into(),
-                filter_expression: None,
-                sleep_between_retries: 1,
-                replica_policy: 1,
-                read_touch_ttl_percent: 0,
-            },
-        }
-    }
+#[php_class(name = "Aerospike\\Expiration")]
+pub struct Expiration {
+    _as: _Expiration,
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  AdminPolicy
-//
-////////////////////////////////////////////////////////////////////////////////////////////
+impl FromZval<'_> for Expiration {
+    const TYPE: DataType = DataType::Mixed;
 
-/// `AdminPolicy` encapsulates parameters for all admin operations.
-#[php_class(name = "Aerospike\\AdminPolicy")]
-pub struct AdminPolicy {
-    _as: proto::AdminPolicy,
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &Expiration = zval.extract()?;
+
+        Some(Expiration { _as: f._as.clone() })
+    }
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl AdminPolicy {
-    pub fn __construct() -> Self {
-        AdminPolicy::default()
+impl Expiration {
+    /// Set the record to expire X seconds from now.  See also `getTtl()`.
+    pub fn Seconds(seconds: u32) -> Self {
+        Expiration {
+            _as: _Expiration::Seconds(seconds),
+        }
     }
 
-    /// User administration command socket timeout.
-    /// Default is 2 seconds.
+    /// Answers with the expiration's current time to live in units of
+    /// seconds, excluding any special values.  If the expiration is set to
+    /// the namespace default, is configured to never update, or is configured
+    /// to never expire, this method returns null.  See also `Seconds()`.
     #[getter]
-    pub fn get_timeout(&self) -> u32 {
-        self._as.timeout
+    pub fn get_ttl(&self) -> Option<u32> {
+        match self._as {
+            _Expiration::Seconds(secs) => Some(secs),
+            _ => None,
+        }
     }
 
-    #[setter]
-    pub fn set_timeout(&mut self, timeout_millis: u32) {
-        self._as.timeout = timeout_millis;
+    /// Set the record's expiry time using the default time-to-live (TTL) value
+    /// for the namespace.  See also `isNamespaceDefault()`.
+    pub fn Namespace_Default() -> Self {
+        Expiration {
+            _as: _Expiration::NamespaceDefault,
+        }
+    }
+
+    /// Answers true only if the expiration is set to use the namespace default.
+    /// See also `NamespaceDefault()`.
+    #[getter]
+    pub fn is_namespace_default(&self) -> bool {
+        self._as == _Expiration::NamespaceDefault
+    }
+
+    /// Set the record to never expire. Requires Aerospike 2 server version 2.7.2 or later or
+    /// Aerospike 3 server version 3.1.4 or later. Do not use with older servers.
+    /// See also `willNeverExpire()`.
+    pub fn Never() -> Self {
+        Expiration {
+            _as: _Expiration::Never,
+        }
+    }
+
+    /// Answers true only if the expiration is set to never expire.
+    /// See also `Never()`.
+    pub fn will_never_expire(&self) -> bool {
+        self._as == _Expiration::Never
+    }
+
+    /// Do not change the record's expiry time when updating the record;
+    /// requires Aerospike server version 3.10.1 or later.
+    /// See also `willUpdateExpiration()`.
+    pub fn Dont_Update() -> Self {
+        Expiration {
+            _as: _Expiration::DontUpdate,
+        }
+    }
+
+    /// Answers *true* if the expiration is configured to somehow change during
+    /// a record update.  This can be as simple as an explicit time-to-live, or
+    /// an instruction to use the namespace's default expiration, etc.  Answers
+    /// *false* if the expiration will *not* be changed during a record update
+    /// (e.g., the expiration was constructed with DontUpdate().)
+    #[getter]
+    pub fn will_update_expiration(&self) -> bool {
+        self._as != _Expiration::DontUpdate
     }
 }
 
-impl Default for AdminPolicy {
-    fn default() -> Self {
-        AdminPolicy {
-            _as: proto::AdminPolicy { timeout: 3000 },
+impl From<&Expiration> for u32 {
+    fn from(exp: &Expiration) -> u32 {
+        match &exp._as {
+            _Expiration::Seconds(secs) => *secs,
+            _Expiration::NamespaceDefault => NAMESPACE_DEFAULT,
+            _Expiration::Never => NEVER_EXPIRE,
+            _Expiration::DontUpdate => DONT_UPDATE,
+        }
+    }
+}
+
+impl From<u32> for Expiration {
+    fn from(exp: u32) -> Expiration {
+        match exp {
+            NAMESPACE_DEFAULT => Expiration::Namespace_Default(),
+            NEVER_EXPIRE => Expiration::Never(),
+            DONT_UPDATE => Expiration::Dont_Update(),
+            secs => Expiration::Seconds(secs),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  InfoPolicy
+//  Concurrency
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// `InfoPolicy` encapsulates parameters for all info operations.
-#[php_class(name = "Aerospike\\InfoPolicy")]
-pub struct InfoPolicy {
-    _as: proto::InfoPolicy,
+/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
+/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
+/// thread pool.
+#[derive(Debug, Clone, Copy)]
+pub enum _Concurrency {
+    Sequential,
+    Parallel,
+    MaxThreads(u32),
+}
+
+#[php_class(name = "Aerospike\\Concurrency")]
+pub struct Concurrency {
+    v: _Concurrency,
+}
+
+impl FromZval<'_> for Concurrency {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &Concurrency = zval.extract()?;
+
+        Some(Concurrency { v: f.v })
+    }
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl InfoPolicy {
-    pub fn __construct() -> Self {
-        InfoPolicy::default()
+impl Concurrency {
+    /// Issue commands sequentially. This mode has a performance advantage for small to
+    /// medium sized batch sizes because requests can be issued in the main transaction thread.
+    /// This is the default.
+    pub fn Sequential() -> Self {
+        Concurrency {
+            v: _Concurrency::Sequential,
+        }
+    }
+
+    /// Issue all commands in parallel threads. This mode has a performance advantage for
+    /// extremely large batch sizes because each node can process the request immediately. The
+    /// downside is extra threads will need to be created (or takedn from a thread pool).
+    pub fn Parallel() -> Self {
+        Concurrency {
+            v: _Concurrency::Parallel,
+        }
+    }
+
+    /// Issue up to N commands in parallel threads. When a request completes, a new request
+    /// will be issued until all threads are complete. This mode prevents too many parallel threads
+    /// being created for large cluster implementations. The downside is extra threads will still
+    /// need to be created (or taken from a thread pool).
+    ///
+    /// E.g. if there are 16 nodes/namespace combinations requested and concurrency is set to
+    /// `MaxThreads(8)`, then batch requests will be made for 8 node/namespace combinations in
+    /// parallel threads. When a request completes, a new request will be issued until all 16
+    /// requests are complete.
+    pub fn Max_Threads(threads: u32) -> Self {
+        Concurrency {
+            v: _Concurrency::MaxThreads(threads),
+        }
     }
 }
 
-impl Default for InfoPolicy {
-    fn default() -> Self {
-        InfoPolicy {
-            _as: proto::InfoPolicy { timeout: 3000 },
+impl From<&Concurrency> for u32 {
+    fn from(input: &Concurrency) -> Self {
+        match &input.v {
+            _Concurrency::Sequential => 1,
+            _Concurrency::Parallel => 0,
+            _Concurrency::MaxThreads(threads) => *threads,
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  WritePolicy
+//  ListOrderType
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// `WritePolicy` encapsulates parameters for all write operations.
-#[php_class(name = "Aerospike\\WritePolicy")]
-pub struct WritePolicy {
-    _as: proto::WritePolicy,
+/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
+/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
+/// thread pool.
+#[php_class(name = "Aerospike\\ListOrderType")]
+#[derive(PartialEq)]
+pub struct ListOrderType {
+    _as: proto::ListOrderType,
 }
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl WritePolicy {
-    pub fn __construct() -> Self {
-        WritePolicy::default()
+impl FromZval<'_> for ListOrderType {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &ListOrderType = zval.extract()?;
+
+        Some(ListOrderType { _as: f._as })
     }
+}
 
-    /// RecordExistsAction qualifies how to handle writes where the record already exists.
-    #[getter]
-    pub fn get_record_exists_action(&self) -> RecordExistsAction {
-        RecordExistsAction {
-            _as: match &self._as.record_exists_action {
-                0 => proto::RecordExistsAction::Update,
-                1 => proto::RecordExistsAction::UpdateOnly,
-                2 => proto::RecordExistsAction::Replace,
-                3 => proto::RecordExistsAction::ReplaceOnly,
-                4 => proto::RecordExistsAction::CreateOnly,
-                _ => unreachable!(),
-            },
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ListOrderType {
+    fn flag(&self) -> i32 {
+        match self._as {
+            proto::ListOrderType::Unordered => 0,
+            proto::ListOrderType::Ordered => 1,
         }
     }
 
-    #[setter]
-    pub fn set_record_exists_action(&mut self, record_exists_action: RecordExistsAction) {
-        self._as.record_exists_action = record_exists_action._as.into();
+    /// ListOrderOrdered signifies that list is Ordered.
+    pub fn Ordered() -> Self {
+        ListOrderType {
+            _as: proto::ListOrderType::Ordered,
+        }
     }
 
-    /// GenerationPolicy qualifies how to handle record writes based on record generation. The default (NONE)
-    /// indicates that the generation is not used to restrict writes.
-    #[getter]
-    pub fn get_generation_policy(&self) -> GenerationPolicy {
-        GenerationPolicy {
-            _as: match &self._as.generation_policy {
-                0 => proto::GenerationPolicy::None,
-                1 => proto::GenerationPolicy::ExpectGenEqual,
-                2 => proto::GenerationPolicy::ExpectGenGt,
-                _ => unreachable!(),
-            },
+    /// ListOrderUnordered signifies that list is not ordered. This is the default.
+    pub fn Unordered() -> Self {
+        ListOrderType {
+            _as: proto::ListOrderType::Unordered,
         }
     }
+}
 
-    #[setter]
-    pub fn set_generation_policy(&mut self, generation_policy: GenerationPolicy) {
-        self._as.generation_policy = generation_policy._as.into();
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  MapOrderType
+//
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    /// Desired consistency guarantee when committing a transaction on the server. The default
-    /// (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
-    /// be successful before returning success to the client.
-    #[getter]
-    pub fn get_commit_level(&self) -> CommitLevel {
-        CommitLevel {
-            _as: match &self._as.commit_level {
-                0 => proto::CommitLevel::CommitAll,
-                1 => proto::CommitLevel::CommitMaster,
-                _ => unreachable!(),
-            },
-        }
-    }
+/// Specifies whether a command, that needs to be executed on multiple cluster nodes, should be
+/// executed sequentially, one node at a time, or in parallel on multiple nodes using the client's
+/// thread pool.
+#[php_class(name = "Aerospike\\MapOrderType")]
+pub struct MapOrderType {
+    _as: proto::MapOrderType,
+}
 
-    #[setter]
-    pub fn set_commit_level(&mut self, commit_level: CommitLevel) {
-        self._as.commit_level = commit_level._as.into();
-    }
+impl FromZval<'_> for MapOrderType {
+    const TYPE: DataType = DataType::Mixed;
 
-    /// Generation determines expected generation.
-    /// Generation is the number of times a record has been
-    /// modified (including creation) on the server.
-    /// If a write operation is creating a record, the expected generation would be 0.
-    #[getter]
-    pub fn get_generation(&self) -> u32 {
-        self._as.generation
-    }
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &MapOrderType = zval.extract()?;
 
-    #[setter]
-    pub fn set_generation(&mut self, generation: u32) {
-        self._as.generation = generation;
+        Some(MapOrderType { _as: f._as })
     }
+}
 
-    /// Expiration determines record expiration in seconds. Also known as TTL (Time-To-Live).
-    /// Seconds record will live before being removed by the server.
-    /// Expiration values:
-    /// TTLServerDefault (0): Default to namespace configuration variable "default-ttl" on the server.
-    /// TTLDontExpire (MaxUint32): Never expire for Aerospike 2 server versions >= 2.7.2 and Aerospike 3+ server
-    /// TTLDontUpdate (MaxUint32 - 1): Do not change ttl when record is written. Supported by Aerospike server versions >= 3.10.1
-    /// > 0: Actual expiration in seconds.
-    #[getter]
-    pub fn get_expiration(&self) -> Expiration {
-        match self._as.expiration {
-            NAMESPACE_DEFAULT => Expiration::Namespace_Default(),
-            NEVER_EXPIRE => Expiration::Never(),
-            DONT_UPDATE => Expiration::Dont_Update(),
-            secs => Expiration::Seconds(secs),
+#[php_impl]
+#[derive(ZvalConvert)]
+impl MapOrderType {
+    fn attr(&self) -> i32 {
+        match self._as {
+            proto::MapOrderType::Unordered => 0,
+            proto::MapOrderType::KeyOrdered => 1,
+            proto::MapOrderType::KeyValueOrdered => 3,
         }
     }
 
-    #[setter]
-    pub fn set_expiration(&mut self, expiration: Expiration) {
-        self._as.expiration = (&expiration).into();
+    fn flag(&self) -> i32 {
+        match self._as {
+            proto::MapOrderType::Unordered => 0x40,
+            proto::MapOrderType::KeyOrdered => 0x80,
+            proto::MapOrderType::KeyValueOrdered => 0xc0,
+        }
     }
 
-    /// RespondPerEachOp defines for client.Operate() method, return a result for every operation.
-    /// Some list operations do not return results by default (ListClearOp() for example).
-    /// This can sometimes make it difficult to determine the desired result offset in the returned
-    /// bin's result list.
-    ///
-    /// Setting RespondPerEachOp to true makes it easier to identify the desired result offset
-    /// (result offset equals bin's operate sequence). This only makes sense when multiple list
-    /// operations are used in one operate call and some of those operations do not return results
-    /// by default.
-    #[getter]
-    pub fn get_respond_per_each_op(&self) -> bool {
-        self._as.respond_per_each_op
+    /// Map is not ordered. This is the default.
+    pub fn Unordered() -> Self {
+        MapOrderType {
+            _as: proto::MapOrderType::Unordered,
+        }
     }
 
-    #[setter]
-    pub fn set_respond_per_each_op(&mut self, respond_per_each_op: bool) {
-        self._as.respond_per_each_op = respond_per_each_op;
+    /// Order map by key.
+    pub fn Key_Ordered() -> Self {
+        MapOrderType {
+            _as: proto::MapOrderType::KeyOrdered,
+        }
     }
 
-    /// DurableDelete leaves a tombstone for the record if the transaction results in a record deletion.
-    /// This prevents deleted records from reappearing after node failures.
-    /// Valid for Aerospike Server Enterprise Edition 3.10+ only.
-    #[getter]
-    pub fn get_durable_delete(&self) -> bool {
-        self._as.respond_per_each_op
+    /// Order map by key, then value.
+    pub fn Key_Value_Ordered() -> Self {
+        MapOrderType {
+            _as: proto::MapOrderType::KeyValueOrdered,
+        }
     }
+}
 
-    #[setter]
-    pub fn set_durable_delete(&mut self, durable_delete: bool) {
-        self._as.durable_delete = durable_delete;
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  CDTContext
+//
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    /// ***************************************************************************
-    /// ReadPolicy Attrs
-    /// ***************************************************************************
+enum CDTContextType {
+    ListIndex = 0x10,
+    ListRank = 0x11,
+    ListValue = 0x13,
+    MapIndex = 0x20,
+    MapRank = 0x21,
+    MapKey = 0x22,
+    MapValue = 0x23,
+}
 
-    #[getter]
-    pub fn get_max_retries(&self) -> u32 {
-        self._as.policy.as_ref().unwrap().max_retries
-    }
+/// CDTContext defines Nested CDT context. Identifies the location of nested list/map to apply the operation.
+/// for the current level.
+/// An array of CTX identifies location of the list/map on multiple
+/// levels on nesting.
+#[php_class(name = "Aerospike\\Context")]
+pub struct CDTContext {
+    _as: proto::CdtContext,
+}
 
-    #[setter]
-    pub fn set_max_retries(&mut self, max_retries: u32) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.max_retries = max_retries);
+/// `CDTContext` excapsulates parameters for transaction policy attributes
+/// used in all database operation calls.
+#[php_impl]
+#[derive(ZvalConvert)]
+impl CDTContext {
+    pub fn __construct() -> Self {
+        CDTContext {
+            _as: proto::CdtContext::default(),
+        }
     }
 
-    #[getter]
-    pub fn get_sleep_multiplier(&self) -> f64 {
-        self._as.policy.as_ref().unwrap().sleep_multiplier
-    }
+    fn list_order_flag(order: ListOrderType, pad: bool) -> i32 {
+        if order.flag() == 1 {
+            return 0xc0;
+        }
 
-    #[setter]
-    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
-    }
+        if pad {
+            return 0x80;
+        }
 
-    #[getter]
-    pub fn get_total_timeout(&self) -> u64 {
-        self._as.policy.as_ref().unwrap().total_timeout
+        return 0x40;
     }
 
-    #[setter]
-    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.total_timeout = timeout_millis);
+    /// CtxListIndex defines Lookup list by index offset.
+    /// If the index is negative, the resolved index starts backwards from end of list.
+    /// If an index is out of bounds, a parameter error will be returned.
+    /// Examples:
+    /// 0: First item.
+    /// 4: Fifth item.
+    /// -1: Last item.
+    /// -3: Third to last item.
+    pub fn ListIndex(index: i32) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::ListIndex as i32,
+                value: Some(PHPValue::Int(index.into()).into()),
+            },
+        }
     }
 
-    #[getter]
-    pub fn get_socket_timeout(&self) -> u64 {
-        self._as.policy.as_ref().unwrap().socket_timeout
+    /// CtxListIndexCreate list with given type at index offset, given an order and pad.
+    pub fn ListIndexCreate(index: i32, order: ListOrderType, pad: bool) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::ListIndex as i32 | Self::list_order_flag(order, pad),
+                value: Some(PHPValue::Int(index.into()).into()),
+            },
+        }
     }
 
-    #[setter]
-    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.socket_timeout = timeout_millis);
+    /// CtxListRank defines Lookup list by rank.
+    /// 0 = smallest value
+    /// N = Nth smallest value
+    /// -1 = largest value
+    pub fn ListRank(rank: i32) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::ListRank as i32,
+                value: Some(PHPValue::Int(rank.into()).into()),
+            },
+        }
     }
 
-    #[getter]
-    pub fn get_send_key(&self) -> bool {
-        self._as.policy.as_ref().unwrap().send_key
-    }
+    /// CtxListValue defines Lookup list by value.
+    pub fn ListValue(key: PHPValue) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::ListValue as i32,
+                value: Some(key.into()),
+            },
+        }
+    }
+
+    /// CtxMapIndex defines Lookup map by index offset.
+    /// If the index is negative, the resolved index starts backwards from end of list.
+    /// If an index is out of bounds, a parameter error will be returned.
+    /// Examples:
+    /// 0: First item.
+    /// 4: Fifth item.
+    /// -1: Last item.
+    /// -3: Third to last item.
+    pub fn MapIndex(index: i32) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::MapIndex as i32,
+                value: Some(PHPValue::Int(index.into()).into()),
+            },
+        }
+    }
+
+    /// CtxMapRank defines Lookup map by rank.
+    /// 0 = smallest value
+    /// N = Nth smallest value
+    /// -1 = largest value
+    pub fn MapRank(rank: i32) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::MapRank as i32,
+                value: Some(PHPValue::Int(rank.into()).into()),
+            },
+        }
+    }
+
+    /// CtxMapKey defines Lookup map by key.
+    pub fn MapKey(key: PHPValue) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::MapKey as i32,
+                value: Some(key.into()),
+            },
+        }
+    }
+
+    /// CtxMapKeyCreate creates map with given type at map key.
+    pub fn MapKeyCreate(key: PHPValue, order: MapOrderType) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::MapKey as i32 | order.flag(),
+                value: Some(key.into()),
+            },
+        }
+    }
+
+    /// CtxMapValue defines Lookup map by value.
+    pub fn MapValue(key: PHPValue) -> Self {
+        CDTContext {
+            _as: proto::CdtContext {
+                id: CDTContextType::MapValue as i32,
+                value: Some(key.into()),
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ReadPolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// `ReadPolicy` encapsulates parameters for transaction policy attributes
+/// used in all database operation calls.
+#[php_class(name = "Aerospike\\ReadPolicy")]
+pub struct ReadPolicy {
+    _as: proto::ReadPolicy,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ReadPolicy {
+    pub fn __construct() -> Self {
+        ReadPolicy::default()
+    }
+
+    /// MaxRetries determines the maximum number of retries before aborting the current transaction.
+    /// The initial attempt is not counted as a retry.
+    ///
+    /// If MaxRetries is exceeded, the transaction will abort with an error.
+    ///
+    /// WARNING: Database writes that are not idempotent (such as AddOp)
+    /// should not be retried because the write operation may be performed
+    /// multiple times if the client timed out previous transaction attempts.
+    /// It's important to use a distinct WritePolicy for non-idempotent
+    /// writes which sets maxRetries = 0;
+    ///
+    /// Default for read: 2 (initial attempt + 2 retries = 3 attempts)
+    ///
+    /// Default for write: 0 (no retries)
+    ///
+    /// Default for partition scan or query with nil filter: 5
+    /// (6 attempts. See ScanPolicy comments.)
+    #[getter]
+    pub fn get_max_retries(&self) -> u32 {
+        self._as.max_retries
+    }
+
+    #[setter]
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self._as.max_retries = max_retries;
+    }
+
+    /// SleepMultiplier specifies the multiplying factor to be used for exponential backoff during retries.
+    /// Default to (1.0); Only values greater than 1 are valid.
+    #[getter]
+    pub fn get_sleep_multiplier(&self) -> f64 {
+        self._as.sleep_multiplier
+    }
+
+    #[setter]
+    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
+        self._as.sleep_multiplier = sleep_multiplier;
+    }
+
+    /// TotalTimeout specifies total transaction timeout.
+    ///
+    /// The TotalTimeout is tracked on the client and also sent to the server along
+    /// with the transaction in the wire protocol. The client will most likely
+    /// timeout first, but the server has the capability to Timeout the transaction.
+    ///
+    /// If TotalTimeout is not zero and TotalTimeout is reached before the transaction
+    /// completes, the transaction will abort with TotalTimeout error.
+    ///
+    /// If TotalTimeout is zero, there will be no time limit and the transaction will retry
+    /// on network timeouts/errors until MaxRetries is exceeded. If MaxRetries is exceeded, the
+    /// transaction also aborts with Timeout error.
+    ///
+    /// Default for scan/query: 0 (no time limit and rely on MaxRetries)
+    ///
+    /// Default for all other commands: 1000ms
+    #[getter]
+    pub fn get_total_timeout(&self) -> u64 {
+        self._as.total_timeout
+    }
+
+    #[setter]
+    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
+        self._as.total_timeout = timeout_millis;
+    }
+
+    /// SocketTimeout determines network timeout for each attempt.
+    ///
+    /// If SocketTimeout is not zero and SocketTimeout is reached before an attempt completes,
+    /// the Timeout above is checked. If Timeout is not exceeded, the transaction
+    /// is retried. If both SocketTimeout and Timeout are non-zero, SocketTimeout must be less
+    /// than or equal to Timeout, otherwise Timeout will also be used for SocketTimeout.
+    ///
+    /// Default: 30s
+    #[getter]
+    pub fn get_socket_timeout(&self) -> u64 {
+        self._as.socket_timeout
+    }
+
+    #[setter]
+    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
+        self._as.socket_timeout = timeout_millis;
+    }
+
+    /// ReadTouchTTLPercent determines how record TTL (time to live) is affected on reads. When enabled, the server can
+    /// efficiently operate as a read-based LRU cache where the least recently used records are expired.
+    /// The value is expressed as a percentage of the TTL sent on the most recent write such that a read
+    /// within this interval of the record’s end of life will generate a touch.
+    ///
+    /// For example, if the most recent write had a TTL of 10 hours and read_touch_ttl_percent is set to
+    /// 80, the next read within 8 hours of the record's end of life (equivalent to 2 hours after the most
+    /// recent write) will result in a touch, resetting the TTL to another 10 hours.
+    ///
+    /// Values:
+    ///
+    /// 0 : Use server config default-read-touch-ttl-pct for the record's namespace/set.
+    /// -1 : Do not reset record TTL on reads.
+    /// 1 - 100 : Reset record TTL on reads when within this percentage of the most recent write TTL.
+    /// Default: 0
+    #[getter]
+    pub fn get_read_touch_ttl_percent(&self) -> i32 {
+        self._as.read_touch_ttl_percent
+    }
+
+    #[setter]
+    pub fn set_read_touch_ttl_percent(&mut self, percent: i32) {
+        self._as.read_touch_ttl_percent = percent;
+    }
+
+    /// SendKey determines to whether send user defined key in addition to hash digest on both reads and writes.
+    /// If the key is sent on a write, the key will be stored with the record on
+    /// the server.
+    /// The default is to not send the user defined key.
+    #[getter]
+    pub fn get_send_key(&self) -> bool {
+        self._as.send_key
+    }
 
     #[setter]
     pub fn set_send_key(&mut self, send_key: bool) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.send_key = send_key);
+        self._as.send_key = send_key;
     }
 
+    /// UseCompression uses zlib compression on command buffers sent to the server and responses received
+    /// from the server when the buffer size is greater than 128 bytes.
+    ///
+    /// This option will increase cpu and memory usage (for extra compressed buffers),but
+    /// decrease the size of data sent over the network.
+    ///
+    /// Default: false
     #[getter]
     pub fn get_use_compression(&self) -> bool {
-        self._as.policy.as_ref().unwrap().use_compression
+        self._as.use_compression
     }
 
     #[setter]
     pub fn set_use_compression(&mut self, use_compression: bool) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.use_compression = use_compression);
+        self._as.use_compression = use_compression;
     }
 
+    /// ExitFastOnExhaustedConnectionPool determines if a command that tries to get a
+    /// connection from the connection pool will wait and retry in case the pool is
+    /// exhausted until a connection becomes available (or the TotalTimeout is reached).
+    /// If set to true, an error will be return immediately.
+    /// If set to false, getting a connection will be retried.
+    /// This only applies if LimitConnectionsToQueueSize is set to true and the number of open connections to a node has reached ConnectionQueueSize.
+    /// The default is false
     #[getter]
     pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
-        self._as
-            .policy
-            .as_ref()
-            .unwrap()
-            .exit_fast_on_exhausted_connection_pool
+        self._as.exit_fast_on_exhausted_connection_pool
     }
 
     #[setter]
@@ -2633,15 +4036,14 @@ impl WritePolicy {
         &mut self,
         exit_fast_on_exhausted_connection_pool: bool,
     ) {
-        self._as.policy.as_mut().map(|ref mut p| {
-            p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
-        });
+        self._as.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool;
     }
 
+    /// ReadModeAP indicates read policy for AP (availability) namespaces.
     #[getter]
     pub fn get_read_mode_ap(&self) -> ReadModeAP {
         ReadModeAP {
-            _as: match self._as.policy.as_ref().unwrap().read_mode_ap {
+            _as: match self._as.read_mode_ap {
                 0 => proto::ReadModeAp::One,
                 1 => proto::ReadModeAp::All,
                 _ => unreachable!(),
@@ -2651,16 +4053,14 @@ impl WritePolicy {
 
     #[setter]
     pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.read_mode_ap = read_mode_ap._as.into());
+        self._as.read_mode_ap = read_mode_ap._as.into();
     }
 
+    /// ReadModeSC indicates read policy for SC (strong consistency) namespaces.
     #[getter]
     pub fn get_read_mode_sc(&self) -> ReadModeSC {
         ReadModeSC {
-            _as: match self._as.policy.as_ref().unwrap().read_mode_ap {
+            _as: match self._as.read_mode_ap {
                 0 => proto::ReadModeSc::Session,
                 1 => proto::ReadModeSc::Linearize,
                 2 => proto::ReadModeSc::AllowReplica,
@@ -2672,18 +4072,38 @@ impl WritePolicy {
 
     #[setter]
     pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
-        self._as
-            .policy
-            .as_mut()
-            .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
+        self._as.read_mode_sc = read_mode_sc._as.into();
+    }
+
+    /// ReplicaPolicy determines the algorithm used to pick the target node for a single record command.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
+    #[getter]
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as.replica_policy.into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
+        self._as.replica_policy = (&replica).into();
+    }
+
+    /// Txn is the multi-record transaction the command belongs to. The command is not part of a
+    /// transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+    #[getter]
+    pub fn get_txn(&self) -> Option<Txn> {
+        self._as.txn.clone().map(|t| t.into())
+    }
+
+    #[setter]
+    pub fn set_txn(&mut self, txn: Option<&Txn>) {
+        self._as.txn = txn.map(|t| t._as.clone());
     }
 
+    /// FilterExpression is the optional Filter Expression. Supported on Server v5.2+
     #[getter]
     pub fn get_filter_expression(&self) -> Option<Expression> {
         self._as
-            .policy
-            .as_ref()
-            .unwrap()
             .filter_expression
             .clone()
             .map(|fe| Expression { _as: fe })
@@ -2691,33 +4111,30 @@ impl WritePolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self
-                ._as
-                .policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = Some(fe._as)),
-            None => self
-                ._as
-                .policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = None),
-        };
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
+        }
     }
 }
 
-impl Default for WritePolicy {
+impl Default for ReadPolicy {
     fn default() -> Self {
-        WritePolicy {
-            _as: proto::WritePolicy {
-                policy: Some(proto::ReadPolicy::default()),
-                record_exists_action: proto::RecordExistsAction::Update.into(),
-                generation_policy: proto::GenerationPolicy::None.into(),
-                commit_level: proto::CommitLevel::CommitAll.into(),
-                generation: 0,
-                expiration: 0,
-                respond_per_each_op: false,
-                durable_delete: false,
+        ReadPolicy {
+            _as: proto::ReadPolicy {
+                max_retries: 3,
+                sleep_multiplier: 1.0,
+                total_timeout: 1000,
+                socket_timeout: 500,
+                send_key: false,
+                use_compression: false,
+                exit_fast_on_exhausted_connection_pool: false,
+                read_mode_ap: proto::ReadModeAp::One.into(),
+                read_mode_sc: proto::ReadModeSc::Session.into(),
+                filter_expression: None,
+                sleep_between_retries: 1,
+                replica_policy: proto::ReplicaPolicy::Sequence.into(),
+                read_touch_ttl_percent: 0,
+                txn: None,
             },
         }
     }
@@ -2725,238 +4142,295 @@ impl Default for WritePolicy {
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  MultiPolicy
+//  AdminPolicy
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// MultiPolicy contains parameters for policy attributes used in
-/// query and scan operations.
-pub struct MultiPolicy {
-    _as: proto::MultiPolicy,
+/// `AdminPolicy` encapsulates parameters for all admin operations.
+#[php_class(name = "Aerospike\\AdminPolicy")]
+pub struct AdminPolicy {
+    _as: proto::AdminPolicy,
 }
 
-impl Default for MultiPolicy {
+#[php_impl]
+#[derive(ZvalConvert)]
+impl AdminPolicy {
+    pub fn __construct() -> Self {
+        AdminPolicy::default()
+    }
+
+    /// User administration command socket timeout.
+    /// Default is 2 seconds.
+    #[getter]
+    pub fn get_timeout(&self) -> u32 {
+        self._as.timeout
+    }
+
+    #[setter]
+    pub fn set_timeout(&mut self, timeout_millis: u32) {
+        self._as.timeout = timeout_millis;
+    }
+}
+
+impl Default for AdminPolicy {
     fn default() -> Self {
-        let mut rp = ReadPolicy::default();
-        rp.set_total_timeout(0);
-        MultiPolicy {
-            _as: proto::MultiPolicy {
-                read_policy: Some(rp._as),
-                max_concurrent_nodes: 0,
-                records_per_second: 0,
-                record_queue_size: 50,
-                include_bin_data: true,
-                max_records: 0,
-            },
+        AdminPolicy {
+            _as: proto::AdminPolicy { timeout: 3000 },
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  QueryPolicy
+//  InfoPolicy
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// QueryPolicy encapsulates parameters for policy attributes used in query operations.
-#[php_class(name = "Aerospike\\QueryPolicy")]
-pub struct QueryPolicy {
-    _as: proto::QueryPolicy,
+/// `InfoPolicy` encapsulates parameters for all info operations.
+#[php_class(name = "Aerospike\\InfoPolicy")]
+pub struct InfoPolicy {
+    _as: proto::InfoPolicy,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl QueryPolicy {
+impl InfoPolicy {
     pub fn __construct() -> Self {
-        QueryPolicy::default()
+        InfoPolicy::default()
     }
+}
 
-    /// QueryDuration represents the expected duration for a query operation in the Aerospike database.
-    /// It provides options for specifying whether a query is expected to return a large number of records per node (Long),
-    /// a small number of records per node (Short), or a long query with relaxed read consistency for AP namespaces (LongRelaxAP).
-    /// These options influence how the server optimizes query execution to meet the expected duration requirements.
-    #[getter]
-    pub fn get_expected_duration(&self) -> QueryDuration {
-        self._as.expected_duration.into()
+impl Default for InfoPolicy {
+    fn default() -> Self {
+        InfoPolicy {
+            _as: proto::InfoPolicy { timeout: 3000 },
+        }
     }
+}
 
-    #[setter]
-    pub fn set_expected_duration(&mut self, expected_duration: QueryDuration) {
-        self._as.expected_duration = expected_duration._as.into();
-    }
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  WritePolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
 
-    /// ***************************************************************************
-    /// MultiPolicy Attrs
-    /// ***************************************************************************
+/// `WritePolicy` encapsulates parameters for all write operations.
+#[php_class(name = "Aerospike\\WritePolicy")]
+pub struct WritePolicy {
+    _as: proto::WritePolicy,
+}
 
-    /// Maximum number of concurrent requests to server nodes at any point in time.
-    /// If there are 16 nodes in the cluster and maxConcurrentNodes is 8, then queries
-    /// will be made to 8 nodes in parallel.  When a query completes, a new query will
-    /// be issued until all 16 nodes have been queried.
-    /// Default (0) is to issue requests to all server nodes in parallel.
-    /// 1 will to issue requests to server nodes one by one avoiding parallel queries.
+#[php_impl]
+#[derive(ZvalConvert)]
+impl WritePolicy {
+    pub fn __construct() -> Self {
+        WritePolicy::default()
+    }
+
+    /// RecordExistsAction qualifies how to handle writes where the record already exists.
     #[getter]
-    pub fn get_max_concurrent_nodes(&self) -> u32 {
-        self._as.multi_policy.as_ref().unwrap().max_concurrent_nodes
+    pub fn get_record_exists_action(&self) -> RecordExistsAction {
+        RecordExistsAction {
+            _as: match &self._as.record_exists_action {
+                0 => proto::RecordExistsAction::Update,
+                1 => proto::RecordExistsAction::UpdateOnly,
+                2 => proto::RecordExistsAction::Replace,
+                3 => proto::RecordExistsAction::ReplaceOnly,
+                4 => proto::RecordExistsAction::CreateOnly,
+                _ => unreachable!(),
+            },
+        }
     }
 
     #[setter]
-    pub fn set_max_concurrent_nodes(&mut self, max_concurrent_nodes: u32) {
-        self._as.multi_policy.as_mut().unwrap().max_concurrent_nodes = max_concurrent_nodes;
+    pub fn set_record_exists_action(&mut self, record_exists_action: RecordExistsAction) {
+        self._as.record_exists_action = record_exists_action._as.into();
     }
 
-    /// Number of records to place in queue before blocking.
-    /// Records received from multiple server nodes will be placed in a queue.
-    /// A separate goroutine consumes these records in parallel.
-    /// If the queue is full, the producer goroutines will block until records are consumed.
+    /// GenerationPolicy qualifies how to handle record writes based on record generation. The default (NONE)
+    /// indicates that the generation is not used to restrict writes.
     #[getter]
-    pub fn get_record_queue_size(&self) -> u32 {
-        self._as.multi_policy.as_ref().unwrap().record_queue_size
+    pub fn get_generation_policy(&self) -> GenerationPolicy {
+        GenerationPolicy {
+            _as: match &self._as.generation_policy {
+                0 => proto::GenerationPolicy::None,
+                1 => proto::GenerationPolicy::ExpectGenEqual,
+                2 => proto::GenerationPolicy::ExpectGenGt,
+                _ => unreachable!(),
+            },
+        }
     }
 
     #[setter]
-    pub fn set_record_queue_size(&mut self, record_queue_size: u32) {
-        self._as.multi_policy.as_mut().unwrap().record_queue_size = record_queue_size;
+    pub fn set_generation_policy(&mut self, generation_policy: GenerationPolicy) {
+        self._as.generation_policy = generation_policy._as.into();
     }
 
-    /// ***************************************************************************
-    /// ReadPolicy Attrs
-    /// ***************************************************************************
-
+    /// Desired consistency guarantee when committing a transaction on the server. The default
+    /// (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
+    /// be successful before returning success to the client.
     #[getter]
-    pub fn get_max_retries(&self) -> u32 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .max_retries
+    pub fn get_commit_level(&self) -> CommitLevel {
+        CommitLevel {
+            _as: match &self._as.commit_level {
+                0 => proto::CommitLevel::CommitAll,
+                1 => proto::CommitLevel::CommitMaster,
+                _ => unreachable!(),
+            },
+        }
     }
 
     #[setter]
-    pub fn set_max_retries(&mut self, max_retries: u32) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.max_retries = max_retries);
+    pub fn set_commit_level(&mut self, commit_level: CommitLevel) {
+        self._as.commit_level = commit_level._as.into();
     }
 
+    /// Generation determines expected generation.
+    /// Generation is the number of times a record has been
+    /// modified (including creation) on the server.
+    /// If a write operation is creating a record, the expected generation would be 0.
     #[getter]
-    pub fn get_sleep_multiplier(&self) -> f64 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .sleep_multiplier
+    pub fn get_generation(&self) -> u32 {
+        self._as.generation
     }
 
     #[setter]
-    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
+    pub fn set_generation(&mut self, generation: u32) {
+        self._as.generation = generation;
     }
 
-    #[getter]
-    pub fn get_total_timeout(&self) -> u64 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .total_timeout
+    /// Expiration determines record expiration in seconds. Also known as TTL (Time-To-Live).
+    /// Seconds record will live before being removed by the server.
+    /// Expiration values:
+    /// TTLServerDefault (0): Default to namespace configuration variable "default-ttl" on the server.
+    /// TTLDontExpire (MaxUint32): Never expire for Aerospike 2 server versions >= 2.7.2 and Aerospike 3+ server
+    /// TTLDontUpdate (MaxUint32 - 1): Do not change ttl when record is written. Supported by Aerospike server versions >= 3.10.1
+    /// > 0: Actual expiration in seconds.
+    #[getter]
+    pub fn get_expiration(&self) -> Expiration {
+        match self._as.expiration {
+            NAMESPACE_DEFAULT => Expiration::Namespace_Default(),
+            NEVER_EXPIRE => Expiration::Never(),
+            DONT_UPDATE => Expiration::Dont_Update(),
+            secs => Expiration::Seconds(secs),
+        }
     }
 
     #[setter]
-    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
+    pub fn set_expiration(&mut self, expiration: Expiration) {
+        self._as.expiration = (&expiration).into();
+    }
+
+    /// RespondPerEachOp defines for client.Operate() method, return a result for every operation.
+    /// Some list operations do not return results by default (ListClearOp() for example).
+    /// This can sometimes make it difficult to determine the desired result offset in the returned
+    /// bin's result list.
+    ///
+    /// Setting RespondPerEachOp to true makes it easier to identify the desired result offset
+    /// (result offset equals bin's operate sequence). This only makes sense when multiple list
+    /// operations are used in one operate call and some of those operations do not return results
+    /// by default.
+    #[getter]
+    pub fn get_respond_per_each_op(&self) -> bool {
+        self._as.respond_per_each_op
+    }
+
+    #[setter]
+    pub fn set_respond_per_each_op(&mut self, respond_per_each_op: bool) {
+        self._as.respond_per_each_op = respond_per_each_op;
+    }
+
+    /// DurableDelete leaves a tombstone for the record if the transaction results in a record deletion.
+    /// This prevents deleted records from reappearing after node failures.
+    /// Valid for Aerospike Server Enterprise Edition 3.10+ only.
+    #[getter]
+    pub fn get_durable_delete(&self) -> bool {
+        self._as.respond_per_each_op
+    }
+
+    #[setter]
+    pub fn set_durable_delete(&mut self, durable_delete: bool) {
+        self._as.durable_delete = durable_delete;
+    }
+
+    /// ***************************************************************************
+    /// ReadPolicy Attrs
+    /// ***************************************************************************
+
+    #[getter]
+    pub fn get_max_retries(&self) -> u32 {
+        self._as.policy.as_ref().unwrap().max_retries
+    }
+
+    #[setter]
+    pub fn set_max_retries(&mut self, max_retries: u32) {
         self._as
-            .multi_policy
+            .policy
             .as_mut()
-            .unwrap()
-            .read_policy
+            .map(|ref mut p| p.max_retries = max_retries);
+    }
+
+    #[getter]
+    pub fn get_sleep_multiplier(&self) -> f64 {
+        self._as.policy.as_ref().unwrap().sleep_multiplier
+    }
+
+    #[setter]
+    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
+        self._as
+            .policy
+            .as_mut()
+            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
+    }
+
+    #[getter]
+    pub fn get_total_timeout(&self) -> u64 {
+        self._as.policy.as_ref().unwrap().total_timeout
+    }
+
+    #[setter]
+    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
+        self._as
+            .policy
             .as_mut()
             .map(|ref mut p| p.total_timeout = timeout_millis);
     }
 
     #[getter]
     pub fn get_socket_timeout(&self) -> u64 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .socket_timeout
+        self._as.policy.as_ref().unwrap().socket_timeout
     }
 
     #[setter]
     pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
         self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
+            .policy
             .as_mut()
             .map(|ref mut p| p.socket_timeout = timeout_millis);
     }
 
     #[getter]
     pub fn get_send_key(&self) -> bool {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .send_key
+        self._as.policy.as_ref().unwrap().send_key
     }
 
     #[setter]
     pub fn set_send_key(&mut self, send_key: bool) {
         self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
+            .policy
             .as_mut()
             .map(|ref mut p| p.send_key = send_key);
     }
 
     #[getter]
     pub fn get_use_compression(&self) -> bool {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .use_compression
+        self._as.policy.as_ref().unwrap().use_compression
     }
 
     #[setter]
     pub fn set_use_compression(&mut self, use_compression: bool) {
         self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
+            .policy
             .as_mut()
             .map(|ref mut p| p.use_compression = use_compression);
     }
@@ -2964,10 +4438,7 @@ impl QueryPolicy {
     #[getter]
     pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
         self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
+            .policy
             .as_ref()
             .unwrap()
             .exit_fast_on_exhausted_connection_pool
@@ -2978,30 +4449,15 @@ impl QueryPolicy {
         &mut self,
         exit_fast_on_exhausted_connection_pool: bool,
     ) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| {
-                p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
-            });
+        self._as.policy.as_mut().map(|ref mut p| {
+            p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
+        });
     }
 
     #[getter]
     pub fn get_read_mode_ap(&self) -> ReadModeAP {
         ReadModeAP {
-            _as: match self
-                ._as
-                .multi_policy
-                .as_ref()
-                .unwrap()
-                .read_policy
-                .as_ref()
-                .unwrap()
-                .read_mode_ap
-            {
+            _as: match self._as.policy.as_ref().unwrap().read_mode_ap {
                 0 => proto::ReadModeAp::One,
                 1 => proto::ReadModeAp::All,
                 _ => unreachable!(),
@@ -3012,10 +4468,7 @@ impl QueryPolicy {
     #[setter]
     pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
         self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
+            .policy
             .as_mut()
             .map(|ref mut p| p.read_mode_ap = read_mode_ap._as.into());
     }
@@ -3023,16 +4476,7 @@ impl QueryPolicy {
     #[getter]
     pub fn get_read_mode_sc(&self) -> ReadModeSC {
         ReadModeSC {
-            _as: match self
-                ._as
-                .multi_policy
-                .as_ref()
-                .unwrap()
-                .read_policy
-                .as_ref()
-                .unwrap()
-                .read_mode_ap
-            {
+            _as: match self._as.policy.as_ref().unwrap().read_mode_ap {
                 0 => proto::ReadModeSc::Session,
                 1 => proto::ReadModeSc::Linearize,
                 2 => proto::ReadModeSc::AllowReplica,
@@ -3045,21 +4489,50 @@ impl QueryPolicy {
     #[setter]
     pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
         self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
+            .policy
             .as_mut()
             .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
     }
 
+    /// ReplicaPolicy determines the algorithm used to pick the target node for a single record command.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
     #[getter]
-    pub fn get_filter_expression(&self) -> Option<Expression> {
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as.policy.as_ref().unwrap().replica_policy.into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
         self._as
-            .multi_policy
+            .policy
+            .as_mut()
+            .map(|ref mut p| p.replica_policy = (&replica).into());
+    }
+
+    /// Txn is the multi-record transaction the command belongs to. The command is not part of a
+    /// transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+    #[getter]
+    pub fn get_txn(&self) -> Option<Txn> {
+        self._as
+            .policy
             .as_ref()
-            .unwrap()
-            .read_policy
+            .and_then(|p| p.txn.clone())
+            .map(|t| t.into())
+    }
+
+    #[setter]
+    pub fn set_txn(&mut self, txn: Option<&Txn>) {
+        self._as
+            .policy
+            .as_mut()
+            .map(|ref mut p| p.txn = txn.map(|t| t._as.clone()));
+    }
+
+    #[getter]
+    pub fn get_filter_expression(&self) -> Option<Expression> {
+        self._as
+            .policy
             .as_ref()
             .unwrap()
             .filter_expression
@@ -3069,33 +4542,26 @@ impl QueryPolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self
-                ._as
-                .multi_policy
-                .as_mut()
-                .unwrap()
-                .read_policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = Some(fe._as)),
-            None => self
-                ._as
-                .multi_policy
-                .as_mut()
-                .unwrap()
-                .read_policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = None),
-        };
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            if let Some(p) = self._as.policy.as_mut() {
+                p.filter_expression = fe;
+            }
+        }
     }
 }
 
-impl Default for QueryPolicy {
+impl Default for WritePolicy {
     fn default() -> Self {
-        QueryPolicy {
-            _as: proto::QueryPolicy {
-                multi_policy: Some(MultiPolicy::default()._as),
-                expected_duration: QueryDuration::Long()._as.into(),
+        WritePolicy {
+            _as: proto::WritePolicy {
+                policy: Some(proto::ReadPolicy::default()),
+                record_exists_action: proto::RecordExistsAction::Update.into(),
+                generation_policy: proto::GenerationPolicy::None.into(),
+                commit_level: proto::CommitLevel::CommitAll.into(),
+                generation: 0,
+                expiration: 0,
+                respond_per_each_op: false,
+                durable_delete: false,
             },
         }
     }
@@ -3103,37 +4569,76 @@ impl Default for QueryPolicy {
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  ScanPolicy
+//  MultiPolicy
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// `ScanPolicy` encapsulates optional parameters used in scan operations.
-#[php_class(name = "Aerospike\\ScanPolicy")]
-pub struct ScanPolicy {
-    _as: proto::ScanPolicy,
+/// MultiPolicy contains parameters for policy attributes used in
+/// query and scan operations.
+pub struct MultiPolicy {
+    _as: proto::MultiPolicy,
 }
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl ScanPolicy {
-    pub fn __construct() -> Self {
-        ScanPolicy::default()
+impl Default for MultiPolicy {
+    fn default() -> Self {
+        let mut rp = ReadPolicy::default();
+        rp.set_total_timeout(0);
+        MultiPolicy {
+            _as: proto::MultiPolicy {
+                read_policy: Some(rp._as),
+                max_concurrent_nodes: 0,
+                records_per_second: 0,
+                record_queue_size: 50,
+                include_bin_data: true,
+                max_records: 0,
+            },
+        }
     }
+}
 
-    /// ***************************************************************************
-    /// MultiPolicy Attrs
-    /// ***************************************************************************
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  QueryPolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// QueryPolicy encapsulates parameters for policy attributes used in query operations.
+#[php_class(name = "Aerospike\\QueryPolicy")]
+pub struct QueryPolicy {
+    _as: proto::QueryPolicy,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl QueryPolicy {
+    pub fn __construct() -> Self {
+        QueryPolicy::default()
+    }
 
+    /// QueryDuration represents the expected duration for a query operation in the Aerospike database.
+    /// It provides options for specifying whether a query is expected to return a large number of records per node (Long),
+    /// a small number of records per node (Short), or a long query with relaxed read consistency for AP namespaces (LongRelaxAP).
+    /// These options influence how the server optimizes query execution to meet the expected duration requirements.
     #[getter]
-    pub fn get_max_records(&self) -> u64 {
-        self._as.multi_policy.as_ref().unwrap().max_records
+    pub fn get_expected_duration(&self) -> QueryDuration {
+        self._as.expected_duration.into()
     }
 
     #[setter]
-    pub fn set_max_records(&mut self, max_records: u64) {
-        self._as.multi_policy.as_mut().unwrap().max_records = max_records;
+    pub fn set_expected_duration(&mut self, expected_duration: QueryDuration) {
+        self._as.expected_duration = expected_duration._as.into();
     }
 
+    /// ***************************************************************************
+    /// MultiPolicy Attrs
+    /// ***************************************************************************
+
+    /// Maximum number of concurrent requests to server nodes at any point in time.
+    /// If there are 16 nodes in the cluster and maxConcurrentNodes is 8, then queries
+    /// will be made to 8 nodes in parallel.  When a query completes, a new query will
+    /// be issued until all 16 nodes have been queried.
+    /// Default (0) is to issue requests to all server nodes in parallel.
+    /// 1 will to issue requests to server nodes one by one avoiding parallel queries.
     #[getter]
     pub fn get_max_concurrent_nodes(&self) -> u32 {
         self._as.multi_policy.as_ref().unwrap().max_concurrent_nodes
@@ -3144,6 +4649,10 @@ impl ScanPolicy {
         self._as.multi_policy.as_mut().unwrap().max_concurrent_nodes = max_concurrent_nodes;
     }
 
+    /// Number of records to place in queue before blocking.
+    /// Records received from multiple server nodes will be placed in a queue.
+    /// A separate goroutine consumes these records in parallel.
+    /// If the queue is full, the producer goroutines will block until records are consumed.
     #[getter]
     pub fn get_record_queue_size(&self) -> u32 {
         self._as.multi_policy.as_ref().unwrap().record_queue_size
@@ -3193,993 +4702,2404 @@ impl ScanPolicy {
             .sleep_multiplier
     }
 
-    #[setter]
-    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
-    }
+    #[setter]
+    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
+    }
+
+    #[getter]
+    pub fn get_total_timeout(&self) -> u64 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .total_timeout
+    }
+
+    #[setter]
+    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.total_timeout = timeout_millis);
+    }
+
+    #[getter]
+    pub fn get_socket_timeout(&self) -> u64 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .socket_timeout
+    }
+
+    #[setter]
+    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.socket_timeout = timeout_millis);
+    }
+
+    #[getter]
+    pub fn get_send_key(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .send_key
+    }
+
+    #[setter]
+    pub fn set_send_key(&mut self, send_key: bool) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.send_key = send_key);
+    }
+
+    #[getter]
+    pub fn get_use_compression(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .use_compression
+    }
+
+    #[setter]
+    pub fn set_use_compression(&mut self, use_compression: bool) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.use_compression = use_compression);
+    }
+
+    #[getter]
+    pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .exit_fast_on_exhausted_connection_pool
+    }
+
+    #[setter]
+    pub fn set_exit_fast_on_exhausted_connection_pool(
+        &mut self,
+        exit_fast_on_exhausted_connection_pool: bool,
+    ) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| {
+                p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
+            });
+    }
+
+    #[getter]
+    pub fn get_read_mode_ap(&self) -> ReadModeAP {
+        ReadModeAP {
+            _as: match self
+                ._as
+                .multi_policy
+                .as_ref()
+                .unwrap()
+                .read_policy
+                .as_ref()
+                .unwrap()
+                .read_mode_ap
+            {
+                0 => proto::ReadModeAp::One,
+                1 => proto::ReadModeAp::All,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.read_mode_ap = read_mode_ap._as.into());
+    }
+
+    #[getter]
+    pub fn get_read_mode_sc(&self) -> ReadModeSC {
+        ReadModeSC {
+            _as: match self
+                ._as
+                .multi_policy
+                .as_ref()
+                .unwrap()
+                .read_policy
+                .as_ref()
+                .unwrap()
+                .read_mode_ap
+            {
+                0 => proto::ReadModeSc::Session,
+                1 => proto::ReadModeSc::Linearize,
+                2 => proto::ReadModeSc::AllowReplica,
+                3 => proto::ReadModeSc::AllowUnavailable,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
+    }
+
+    /// ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
+    #[getter]
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .replica_policy
+            .into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.replica_policy = (&replica).into());
+    }
+
+    /// Txn is the multi-record transaction the command belongs to. The command is not part of a
+    /// transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+    #[getter]
+    pub fn get_txn(&self) -> Option<Txn> {
+        self._as
+            .multi_policy
+            .as_ref()
+            .and_then(|mp| mp.read_policy.as_ref())
+            .and_then(|p| p.txn.clone())
+            .map(|t| t.into())
+    }
+
+    #[setter]
+    pub fn set_txn(&mut self, txn: Option<&Txn>) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.txn = txn.map(|t| t._as.clone()));
+    }
+
+    #[getter]
+    pub fn get_filter_expression(&self) -> Option<Expression> {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .filter_expression
+            .clone()
+            .map(|fe| Expression { _as: fe })
+    }
+
+    #[setter]
+    pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            let mp = self._as.multi_policy.as_mut().unwrap();
+            if let Some(p) = mp.read_policy.as_mut() {
+                p.filter_expression = fe;
+            }
+        }
+    }
+}
+
+impl Default for QueryPolicy {
+    fn default() -> Self {
+        QueryPolicy {
+            _as: proto::QueryPolicy {
+                multi_policy: Some(MultiPolicy::default()._as),
+                expected_duration: QueryDuration::Long()._as.into(),
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ScanPolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// `ScanPolicy` encapsulates optional parameters used in scan operations.
+#[php_class(name = "Aerospike\\ScanPolicy")]
+pub struct ScanPolicy {
+    _as: proto::ScanPolicy,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ScanPolicy {
+    pub fn __construct() -> Self {
+        ScanPolicy::default()
+    }
+
+    /// ***************************************************************************
+    /// MultiPolicy Attrs
+    /// ***************************************************************************
+
+    #[getter]
+    pub fn get_max_records(&self) -> u64 {
+        self._as.multi_policy.as_ref().unwrap().max_records
+    }
+
+    #[setter]
+    pub fn set_max_records(&mut self, max_records: u64) {
+        self._as.multi_policy.as_mut().unwrap().max_records = max_records;
+    }
+
+    #[getter]
+    pub fn get_max_concurrent_nodes(&self) -> u32 {
+        self._as.multi_policy.as_ref().unwrap().max_concurrent_nodes
+    }
+
+    #[setter]
+    pub fn set_max_concurrent_nodes(&mut self, max_concurrent_nodes: u32) {
+        self._as.multi_policy.as_mut().unwrap().max_concurrent_nodes = max_concurrent_nodes;
+    }
+
+    #[getter]
+    pub fn get_record_queue_size(&self) -> u32 {
+        self._as.multi_policy.as_ref().unwrap().record_queue_size
+    }
+
+    #[setter]
+    pub fn set_record_queue_size(&mut self, record_queue_size: u32) {
+        self._as.multi_policy.as_mut().unwrap().record_queue_size = record_queue_size;
+    }
+
+    /// ***************************************************************************
+    /// ReadPolicy Attrs
+    /// ***************************************************************************
+
+    #[getter]
+    pub fn get_max_retries(&self) -> u32 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .max_retries
+    }
+
+    #[setter]
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.max_retries = max_retries);
+    }
+
+    #[getter]
+    pub fn get_sleep_multiplier(&self) -> f64 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .sleep_multiplier
+    }
+
+    #[setter]
+    pub fn set_sleep_multiplier(&mut self, sleep_multiplier: f64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.sleep_multiplier = sleep_multiplier);
+    }
+
+    #[getter]
+    pub fn get_total_timeout(&self) -> u64 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .total_timeout
+    }
+
+    #[setter]
+    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.total_timeout = timeout_millis);
+    }
+
+    #[getter]
+    pub fn get_socket_timeout(&self) -> u64 {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .socket_timeout
+    }
+
+    #[setter]
+    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.socket_timeout = timeout_millis);
+    }
+
+    #[getter]
+    pub fn get_send_key(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .send_key
+    }
+
+    #[setter]
+    pub fn set_send_key(&mut self, send_key: bool) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.send_key = send_key);
+    }
+
+    #[getter]
+    pub fn get_use_compression(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .use_compression
+    }
+
+    #[setter]
+    pub fn set_use_compression(&mut self, use_compression: bool) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.use_compression = use_compression);
+    }
+
+    #[getter]
+    pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .exit_fast_on_exhausted_connection_pool
+    }
+
+    #[setter]
+    pub fn set_exit_fast_on_exhausted_connection_pool(
+        &mut self,
+        exit_fast_on_exhausted_connection_pool: bool,
+    ) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| {
+                p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
+            });
+    }
+
+    #[getter]
+    pub fn get_read_mode_ap(&self) -> ReadModeAP {
+        ReadModeAP {
+            _as: match self
+                ._as
+                .multi_policy
+                .as_ref()
+                .unwrap()
+                .read_policy
+                .as_ref()
+                .unwrap()
+                .read_mode_ap
+            {
+                0 => proto::ReadModeAp::One,
+                1 => proto::ReadModeAp::All,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.read_mode_ap = read_mode_ap._as.into());
+    }
+
+    #[getter]
+    pub fn get_read_mode_sc(&self) -> ReadModeSC {
+        ReadModeSC {
+            _as: match self
+                ._as
+                .multi_policy
+                .as_ref()
+                .unwrap()
+                .read_policy
+                .as_ref()
+                .unwrap()
+                .read_mode_ap
+            {
+                0 => proto::ReadModeSc::Session,
+                1 => proto::ReadModeSc::Linearize,
+                2 => proto::ReadModeSc::AllowReplica,
+                3 => proto::ReadModeSc::AllowUnavailable,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
+    }
+
+    /// ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
+    #[getter]
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .replica_policy
+            .into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.replica_policy = (&replica).into());
+    }
+
+    /// Txn is the multi-record transaction the command belongs to. The command is not part of a
+    /// transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+    #[getter]
+    pub fn get_txn(&self) -> Option<Txn> {
+        self._as
+            .multi_policy
+            .as_ref()
+            .and_then(|mp| mp.read_policy.as_ref())
+            .and_then(|p| p.txn.clone())
+            .map(|t| t.into())
+    }
+
+    #[setter]
+    pub fn set_txn(&mut self, txn: Option<&Txn>) {
+        self._as
+            .multi_policy
+            .as_mut()
+            .unwrap()
+            .read_policy
+            .as_mut()
+            .map(|ref mut p| p.txn = txn.map(|t| t._as.clone()));
+    }
+
+    #[getter]
+    pub fn get_filter_expression(&self) -> Option<Expression> {
+        self._as
+            .multi_policy
+            .as_ref()
+            .unwrap()
+            .read_policy
+            .as_ref()
+            .unwrap()
+            .filter_expression
+            .clone()
+            .map(|fe| Expression { _as: fe })
+    }
+
+    #[setter]
+    pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            let mp = self._as.multi_policy.as_mut().unwrap();
+            if let Some(p) = mp.read_policy.as_mut() {
+                p.filter_expression = fe;
+            }
+        }
+    }
+}
+
+impl Default for ScanPolicy {
+    fn default() -> Self {
+        ScanPolicy {
+            _as: proto::ScanPolicy {
+                multi_policy: Some(MultiPolicy::default()._as),
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  BackgroundExecutePolicy
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// `BackgroundExecutePolicy` encapsulates parameters for background query executions,
+/// which apply a UDF or a list of write operations to all records matched by a `Statement`
+/// on the server.
+#[php_class(name = "Aerospike\\BackgroundExecutePolicy")]
+pub struct BackgroundExecutePolicy {
+    _as: proto::BackgroundExecutePolicy,
+}
+
+impl FromZval<'_> for BackgroundExecutePolicy {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &BackgroundExecutePolicy = zval.extract()?;
+
+        Some(BackgroundExecutePolicy { _as: f._as.clone() })
+    }
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl BackgroundExecutePolicy {
+    pub fn __construct() -> Self {
+        BackgroundExecutePolicy::default()
+    }
+
+    /// FilterExpression is the optional Filter Expression. Supported on Server v5.2+
+    #[getter]
+    pub fn get_filter_expression(&self) -> Option<Expression> {
+        self._as
+            .filter_expression
+            .clone()
+            .map(|fe| Expression { _as: fe })
+    }
+
+    #[setter]
+    pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
+        }
+    }
+
+    /// ReplicaPolicy determines the algorithm used to pick the target node for each partition.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
+    #[getter]
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as.replica.into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
+        self._as.replica = (&replica).into();
+    }
+
+    /// TotalTimeout specifies total transaction timeout in milliseconds.
+    #[getter]
+    pub fn get_total_timeout(&self) -> u32 {
+        self._as.total_timeout.unwrap_or_default()
+    }
+
+    #[setter]
+    pub fn set_total_timeout(&mut self, timeout_millis: u32) {
+        self._as.total_timeout = Some(timeout_millis);
+    }
+
+    /// SendKey determines if the user defined key should be sent to the server and stored
+    /// with the written records.
+    #[getter]
+    pub fn get_send_key(&self) -> bool {
+        self._as.send_key.unwrap_or_default()
+    }
+
+    #[setter]
+    pub fn set_send_key(&mut self, send_key: bool) {
+        self._as.send_key = Some(send_key);
+    }
+
+    /// UseCompression uses zlib compression on command buffers sent to the server and responses received
+    /// from the server when the buffer size is greater than 128 bytes.
+    #[getter]
+    pub fn get_use_compression(&self) -> bool {
+        self._as.compress
+    }
+
+    #[setter]
+    pub fn set_use_compression(&mut self, use_compression: bool) {
+        self._as.compress = use_compression;
+    }
+
+    /// RecordExistsAction qualifies how to handle writes where the record already exists.
+    #[getter]
+    pub fn get_record_exists_action(&self) -> RecordExistsAction {
+        RecordExistsAction {
+            _as: match self._as.record_exists_action.unwrap_or_default() {
+                0 => proto::RecordExistsAction::Update,
+                1 => proto::RecordExistsAction::UpdateOnly,
+                2 => proto::RecordExistsAction::Replace,
+                3 => proto::RecordExistsAction::ReplaceOnly,
+                4 => proto::RecordExistsAction::CreateOnly,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_record_exists_action(&mut self, record_exists_action: RecordExistsAction) {
+        self._as.record_exists_action = Some(record_exists_action._as.into());
+    }
+
+    /// GenerationPolicy qualifies how to handle record writes based on record generation. The default (NONE)
+    /// indicates that the generation is not used to restrict writes.
+    #[getter]
+    pub fn get_generation_policy(&self) -> GenerationPolicy {
+        GenerationPolicy {
+            _as: match self._as.generation_policy.unwrap_or_default() {
+                0 => proto::GenerationPolicy::None,
+                1 => proto::GenerationPolicy::ExpectGenEqual,
+                2 => proto::GenerationPolicy::ExpectGenGt,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_generation_policy(&mut self, generation_policy: GenerationPolicy) {
+        self._as.generation_policy = Some(generation_policy._as.into());
+    }
+
+    /// Desired consistency guarantee when committing a transaction on the server. The default
+    /// (COMMIT_ALL) indicates that the server should wait for master and all replica commits to
+    /// be successful before returning success to the client.
+    #[getter]
+    pub fn get_commit_level(&self) -> CommitLevel {
+        CommitLevel {
+            _as: match self._as.commit_level.unwrap_or_default() {
+                0 => proto::CommitLevel::CommitAll,
+                1 => proto::CommitLevel::CommitMaster,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    #[setter]
+    pub fn set_commit_level(&mut self, commit_level: CommitLevel) {
+        self._as.commit_level = Some(commit_level._as.into());
+    }
+
+    /// Generation determines expected generation.
+    /// This field is only relevant when generationPolicy is not NONE.
+    #[getter]
+    pub fn get_generation(&self) -> u32 {
+        self._as.generation.unwrap_or_default()
+    }
+
+    #[setter]
+    pub fn set_generation(&mut self, generation: u32) {
+        self._as.generation = Some(generation);
+    }
+
+    /// Expiration determines the expiration of the records written by the background execution.
+    /// See `WritePolicy::expiration` for the possible values.
+    #[getter]
+    pub fn get_expiration(&self) -> Expiration {
+        match self._as.expiration.unwrap_or_default() {
+            NAMESPACE_DEFAULT => Expiration::Namespace_Default(),
+            NEVER_EXPIRE => Expiration::Never(),
+            DONT_UPDATE => Expiration::Dont_Update(),
+            secs => Expiration::Seconds(secs),
+        }
+    }
+
+    #[setter]
+    pub fn set_expiration(&mut self, expiration: Expiration) {
+        self._as.expiration = Some((&expiration).into());
+    }
+
+    /// DurableDelete leaves a tombstone for the record if the transaction results in a record deletion.
+    /// This prevents deleted records from reappearing after node failures.
+    /// Valid for Aerospike Server Enterprise Edition 3.10+ only.
+    #[getter]
+    pub fn get_durable_delete(&self) -> bool {
+        self._as.durable_delete.unwrap_or_default()
+    }
+
+    #[setter]
+    pub fn set_durable_delete(&mut self, durable_delete: bool) {
+        self._as.durable_delete = Some(durable_delete);
+    }
+}
+
+impl Default for BackgroundExecutePolicy {
+    fn default() -> Self {
+        BackgroundExecutePolicy {
+            _as: proto::BackgroundExecutePolicy {
+                replica: proto::ReplicaPolicy::Sequence.into(),
+                read_mode_ap: proto::ReadModeAp::One.into(),
+                read_mode_sc: proto::ReadModeSc::Session.into(),
+                compress: false,
+                filter_expression: None,
+                total_timeout: None,
+                send_key: None,
+                record_exists_action: None,
+                generation_policy: None,
+                commit_level: None,
+                generation: None,
+                expiration: None,
+                respond_all_ops: None,
+                durable_delete: None,
+                xdr: None,
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  IndexCollectionType
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// IndexCollectionType is the secondary index collection type.
+#[php_class(name = "Aerospike\\IndexCollectionType")]
+pub struct IndexCollectionType {
+    _as: proto::IndexCollectionType,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl IndexCollectionType {
+    /// ICT_DEFAULT is the Normal scalar index.
+    pub fn Default() -> Self {
+        IndexCollectionType {
+            _as: proto::IndexCollectionType::Default,
+        }
+    }
+
+    /// ICT_LIST is Index list elements.
+    pub fn List() -> Self {
+        IndexCollectionType {
+            _as: proto::IndexCollectionType::List,
+        }
+    }
+
+    /// ICT_MAPKEYS is Index map keys.
+    pub fn MapKeys() -> Self {
+        IndexCollectionType {
+            _as: proto::IndexCollectionType::MapKeys,
+        }
+    }
+
+    /// ICT_MAPVALUES is Index map values.
+    pub fn MapValues() -> Self {
+        IndexCollectionType {
+            _as: proto::IndexCollectionType::MapValues,
+        }
+    }
+}
+
+impl From<i32> for IndexCollectionType {
+    fn from(input: i32) -> Self {
+        match input {
+            1 => Self::List(),
+            2 => Self::MapKeys(),
+            3 => Self::MapValues(),
+            _ => Self::Default(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ParticleType
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Server particle types. Unsupported types are commented out.
+#[php_class(name = "Aerospike\\ParticleType")]
+pub struct ParticleType {
+    _as: proto::ParticleType,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ParticleType {
+    pub fn Null() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Null,
+        }
+    }
+
+    pub fn Integer() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Integer,
+        }
+    }
+
+    pub fn Float() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Float,
+        }
+    }
+
+    pub fn String() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::String,
+        }
+    }
+
+    pub fn Blob() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Blob,
+        }
+    }
+
+    pub fn Digest() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Digest,
+        }
+    }
+
+    pub fn Bool() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Bool,
+        }
+    }
+
+    pub fn Hll() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Hll,
+        }
+    }
+
+    pub fn Map() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::Map,
+        }
+    }
+
+    pub fn List() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::List,
+        }
+    }
+
+    pub fn Geo_Json() -> Self {
+        ParticleType {
+            _as: proto::ParticleType::GeoJson,
+        }
+    }
+}
+
+impl From<ParticleType> for i64 {
+    fn from(input: ParticleType) -> Self {
+        match &input._as {
+            proto::ParticleType::Null => 0,
+            proto::ParticleType::Integer => 1,
+            proto::ParticleType::Float => 2,
+            proto::ParticleType::String => 3,
+            proto::ParticleType::Blob => 4,
+            proto::ParticleType::Digest => 6,
+            proto::ParticleType::Bool => 17,
+            proto::ParticleType::Hll => 18,
+            proto::ParticleType::Map => 19,
+            proto::ParticleType::List => 20,
+            proto::ParticleType::Ldt => 21,
+            proto::ParticleType::GeoJson => 23,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  IndexType
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// IndexType the type of the secondary index.
+#[php_class(name = "Aerospike\\IndexType")]
+pub struct IndexType {
+    _as: proto::IndexType,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl IndexType {
+    /// NUMERIC specifies an index on numeric values.
+    pub fn Numeric() -> Self {
+        IndexType {
+            _as: proto::IndexType::Numeric,
+        }
+    }
+
+    /// STRING specifies an index on string values.
+    pub fn String() -> Self {
+        IndexType {
+            _as: proto::IndexType::String,
+        }
+    }
+
+    /// BLOB specifies a []byte index. Requires server version 7.0+.
+    pub fn Blob() -> Self {
+        IndexType {
+            _as: proto::IndexType::Blob,
+        }
+    }
+
+    /// GEO2DSPHERE specifies 2-dimensional spherical geospatial index.
+    pub fn Geo2DSphere() -> Self {
+        IndexType {
+            _as: proto::IndexType::Geo2DSphere,
+        }
+    }
+}
+
+impl From<i32> for IndexType {
+    fn from(input: i32) -> Self {
+        match input {
+            1 => Self::String(),
+            2 => Self::Blob(),
+            3 => Self::Geo2DSphere(),
+            _ => Self::Numeric(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Filter
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Query filter definition. Currently, only one filter is allowed in a Statement, and must be on a
+/// bin which has a secondary index defined.
+/// Filter instances should be instantiated using one of the provided macros.
+#[php_class(name = "Aerospike\\Filter")]
+pub struct Filter {
+    _as: proto::QueryFilter,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl Filter {
+    /// NewEqualFilter creates a new equality filter instance for query.
+    /// Value can be an integer, string or a blob (byte array). Byte arrays are only supported on server v7+.
+    pub fn equal(bin_name: &str, value: PHPValue, ctx: Option<Vec<&CDTContext>>) -> Self {
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: proto::IndexCollectionType::Default.into(),
+                value_particle_type: value.particle_type() as u32,
+                begin: Some(value.clone().into()),
+                end: Some(value.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewRangeFilter creates a range filter for query.
+    /// Range arguments must be int64 values.
+    /// String ranges are not supported.
+    pub fn range(
+        bin_name: &str,
+        begin: PHPValue,
+        end: PHPValue,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: proto::IndexCollectionType::Default.into(),
+                value_particle_type: begin.particle_type() as u32,
+                begin: Some(begin.clone().into()),
+                end: Some(end.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewContainsFilter creates a contains filter for query on collection index.
+    /// Value can be an integer, string or a blob (byte array). Byte arrays are only supported on server v7+.
+    pub fn contains(
+        bin_name: &str,
+        value: PHPValue,
+        cit: Option<&IndexCollectionType>,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        let default = IndexCollectionType::Default();
+        let cit = cit.unwrap_or(&default);
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: cit._as.into(),
+                value_particle_type: value.particle_type() as u32,
+                begin: Some(value.clone().into()),
+                end: Some(value.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewContainsRangeFilter creates a contains filter for query on ranges of data in a collection index.
+    pub fn contains_range(
+        bin_name: &str,
+        begin: PHPValue,
+        end: PHPValue,
+        cit: Option<&IndexCollectionType>,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        let default = IndexCollectionType::Default();
+        let cit = cit.unwrap_or(&default);
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: cit._as.into(),
+                value_particle_type: begin.particle_type() as u32,
+                begin: Some(begin.clone().into()),
+                end: Some(end.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewGeoWithinRegionFilter creates a geospatial "within region" filter for query.
+    /// Argument must be a valid GeoJSON region.
+    pub fn within_region(
+        bin_name: &str,
+        region: &str,
+        cit: Option<&IndexCollectionType>,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        let default = IndexCollectionType::Default();
+        let cit = cit.unwrap_or(&default);
+        let region = Value::string(region.into());
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: cit._as.into(),
+                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
+                begin: Some(region.clone().into()),
+                end: Some(region.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewGeoWithinRegionForCollectionFilter creates a geospatial "within region" filter for query on collection index.
+    /// Argument must be a valid GeoJSON region.
+    pub fn within_radius(
+        bin_name: &str,
+        lat: f64,
+        lng: f64,
+        radius: f64,
+        cit: Option<&IndexCollectionType>,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        let default = IndexCollectionType::Default();
+        let cit = cit.unwrap_or(&default);
+        let rgnStr = format!(
+            r#"{{ "type": "AeroCircle", "coordinates": [[{:.8}, {:.8}], {}] }}"#,
+            lng, lat, radius
+        );
+        let value = Value::string(rgnStr);
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: cit._as.into(),
+                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
+                begin: Some(value.clone().into()),
+                end: Some(value.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+
+    /// NewGeoRegionsContainingPointFilter creates a geospatial "containing point" filter for query.
+    /// Argument must be a valid GeoJSON point.
+    pub fn regions_containing_point(
+        bin_name: &str,
+        lat: f64,
+        lng: f64,
+        cit: Option<&IndexCollectionType>,
+        ctx: Option<Vec<&CDTContext>>,
+    ) -> Self {
+        let default = IndexCollectionType::Default();
+        let cit = cit.unwrap_or(&default);
+        let point = format!(
+            r#"{{"type":"Point","coordinates":[{:.8},{:.8}]}}"#,
+            lng, lat
+        );
+        let value = Value::string(point);
+        Filter {
+            _as: proto::QueryFilter {
+                name: bin_name.into(),
+                idx_type: cit._as.into(),
+                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
+                begin: Some(value.clone().into()),
+                end: Some(value.clone().into()),
+                ctx: ctx
+                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
+                    .unwrap_or(vec![]),
+            },
+        }
+    }
+}
+
+impl FromZval<'_> for Filter {
+    const TYPE: DataType = DataType::Mixed;
 
-    #[getter]
-    pub fn get_total_timeout(&self) -> u64 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .total_timeout
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &Filter = zval.extract()?;
+
+        Some(Filter { _as: f._as.clone() })
     }
+}
 
-    #[setter]
-    pub fn set_total_timeout(&mut self, timeout_millis: u64) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.total_timeout = timeout_millis);
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Statement
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Statement encapsulates query statement parameters.
+#[php_class(name = "Aerospike\\Statement")]
+pub struct Statement {
+    _as: proto::Statement,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl Statement {
+    pub fn __construct(
+        namespace: &str,
+        set_name: &str,
+        filter: Option<Filter>,
+        bin_names: Option<Vec<String>>,
+    ) -> Self {
+        let mut rng = rand::thread_rng();
+        let filter_proto = filter.map(|f| f._as.clone());
+        Statement {
+            _as: proto::Statement {
+                namespace: namespace.into(),
+                set_name: set_name.into(),
+                bin_names: bin_names.unwrap_or_default(),
+                return_data: true,
+                task_id: rng.gen(),
+                filter: filter_proto,
+                index_name: None,
+                udf_call: None,
+            },
+        }
     }
 
+    /// Filter determines query index filter (Optional).
+    /// This filter is applied to the secondary index on query.
+    /// Query index filters must reference a bin which has a secondary index defined.
     #[getter]
-    pub fn get_socket_timeout(&self) -> u64 {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .socket_timeout
+    pub fn get_filter(&self) -> Option<Filter> {
+        self._as.filter.as_ref().map(|f| Filter { _as: f.clone() })
     }
 
     #[setter]
-    pub fn set_socket_timeout(&mut self, timeout_millis: u64) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.socket_timeout = timeout_millis);
+    pub fn set_filter(&mut self, filter: Option<Filter>) {
+        self._as.filter = filter.map(|f| f._as.clone());
     }
 
+    /// IndexName determines query index name (Optional)
+    /// If not set, the server will determine the index from the filter's bin name.
     #[getter]
-    pub fn get_send_key(&self) -> bool {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .send_key
+    pub fn get_index_name(&self) -> Option<String> {
+        self._as.index_name.clone()
     }
 
     #[setter]
-    pub fn set_send_key(&mut self, send_key: bool) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.send_key = send_key);
+    pub fn set_index_name(&mut self, index_name: Option<String>) {
+        self._as.index_name = index_name;
     }
 
+    /// BinNames detemines bin names (optional)
     #[getter]
-    pub fn get_use_compression(&self) -> bool {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .use_compression
+    pub fn get_bin_names(&self) -> Vec<String> {
+        self._as.bin_names.clone()
     }
 
     #[setter]
-    pub fn set_use_compression(&mut self, use_compression: bool) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.use_compression = use_compression);
+    pub fn set_bin_names(&mut self, bin_names: Vec<String>) {
+        self._as.bin_names = bin_names;
     }
 
+    /// Namespace determines query Namespace
     #[getter]
-    pub fn get_exit_fast_on_exhausted_connection_pool(&self) -> bool {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .exit_fast_on_exhausted_connection_pool
+    pub fn get_namespace(&self) -> String {
+        self._as.namespace.clone()
     }
 
     #[setter]
-    pub fn set_exit_fast_on_exhausted_connection_pool(
-        &mut self,
-        exit_fast_on_exhausted_connection_pool: bool,
-    ) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| {
-                p.exit_fast_on_exhausted_connection_pool = exit_fast_on_exhausted_connection_pool
-            });
+    pub fn set_namespace(&mut self, namespace: String) {
+        self._as.namespace = namespace;
     }
 
+    /// SetName determines query Set name (Optional)
     #[getter]
-    pub fn get_read_mode_ap(&self) -> ReadModeAP {
-        ReadModeAP {
-            _as: match self
-                ._as
-                .multi_policy
-                .as_ref()
-                .unwrap()
-                .read_policy
-                .as_ref()
-                .unwrap()
-                .read_mode_ap
-            {
-                0 => proto::ReadModeAp::One,
-                1 => proto::ReadModeAp::All,
-                _ => unreachable!(),
-            },
-        }
+    pub fn get_setname(&self) -> String {
+        self._as.set_name.clone()
     }
 
     #[setter]
-    pub fn set_read_mode_ap(&mut self, read_mode_ap: ReadModeAP) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.read_mode_ap = read_mode_ap._as.into());
+    pub fn set_setname(&mut self, set_name: String) {
+        self._as.set_name = set_name;
     }
 
-    #[getter]
-    pub fn get_read_mode_sc(&self) -> ReadModeSC {
-        ReadModeSC {
-            _as: match self
-                ._as
-                .multi_policy
-                .as_ref()
-                .unwrap()
-                .read_policy
-                .as_ref()
-                .unwrap()
-                .read_mode_ap
-            {
-                0 => proto::ReadModeSc::Session,
-                1 => proto::ReadModeSc::Linearize,
-                2 => proto::ReadModeSc::AllowReplica,
-                3 => proto::ReadModeSc::AllowUnavailable,
-                _ => unreachable!(),
-            },
-        }
+    /// Set the Lua UDF to apply to every record matched by the statement in a
+    /// background query execution (`Client::queryExecute`).
+    /// The package must already be registered on the server.
+    pub fn set_udf(
+        &mut self,
+        package_name: String,
+        function_name: String,
+        function_args: Option<Vec<PHPValue>>,
+    ) {
+        self._as.return_data = false;
+        self._as.udf_call = Some(proto::UdfCall {
+            package_name: package_name,
+            function_name: function_name,
+            function_args: function_args
+                .unwrap_or_default()
+                .into_iter()
+                .map(|v| v.into())
+                .collect(),
+        });
     }
 
-    #[setter]
-    pub fn set_read_mode_sc(&mut self, read_mode_sc: ReadModeSC) {
-        self._as
-            .multi_policy
-            .as_mut()
-            .unwrap()
-            .read_policy
-            .as_mut()
-            .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
+    /// Set the stream UDF to use for an aggregation query (`Client::queryAggregate`).
+    /// The package must be registered on the server, and also be available in the
+    /// `lua-path` directory of the connection manager, which runs the final reduce phase.
+    pub fn set_aggregate_function(
+        &mut self,
+        package_name: String,
+        function_name: String,
+        function_args: Option<Vec<PHPValue>>,
+    ) {
+        self.set_udf(package_name, function_name, function_args);
+        self._as.return_data = true;
     }
 
-    #[getter]
-    pub fn get_filter_expression(&self) -> Option<Expression> {
-        self._as
-            .multi_policy
-            .as_ref()
-            .unwrap()
-            .read_policy
-            .as_ref()
-            .unwrap()
-            .filter_expression
-            .clone()
-            .map(|fe| Expression { _as: fe })
+    /// Remove the UDF set on the statement.
+    pub fn clear_udf(&mut self) {
+        self._as.udf_call = None;
+        self._as.return_data = true;
+    }
+
+    /// PackageName is the name of the UDF package set on the statement, if any.
+    #[getter]
+    pub fn get_package_name(&self) -> Option<String> {
+        self._as.udf_call.as_ref().map(|u| u.package_name.clone())
     }
 
-    #[setter]
-    pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self
-                ._as
-                .multi_policy
-                .as_mut()
-                .unwrap()
-                .read_policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = Some(fe._as)),
-            None => self
-                ._as
-                .multi_policy
-                .as_mut()
-                .unwrap()
-                .read_policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = None),
-        };
+    /// FunctionName is the name of the UDF function set on the statement, if any.
+    #[getter]
+    pub fn get_function_name(&self) -> Option<String> {
+        self._as.udf_call.as_ref().map(|u| u.function_name.clone())
     }
-}
 
-impl Default for ScanPolicy {
-    fn default() -> Self {
-        ScanPolicy {
-            _as: proto::ScanPolicy {
-                multi_policy: Some(MultiPolicy::default()._as),
-            },
-        }
+    /// FunctionArgs are the arguments passed to the UDF function set on the statement.
+    #[getter]
+    pub fn get_function_args(&self) -> Option<Vec<PHPValue>> {
+        self._as
+            .udf_call
+            .as_ref()
+            .map(|u| u.function_args.iter().map(|v| v.clone().into()).collect())
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  IndexCollectionType
+//  PartitionStatus
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// IndexCollectionType is the secondary index collection type.
-#[php_class(name = "Aerospike\\IndexCollectionType")]
-pub struct IndexCollectionType {
-    _as: proto::IndexCollectionType,
+/// Virtual collection of records retrieved through queries and scans. During a query/scan,
+/// multiple threads will retrieve records from the server nodes and put these records on an
+/// internal queue managed by the recordset. The single user thread consumes these records from the
+/// queue.
+#[php_class(name = "Aerospike\\PartitionStatus")]
+pub struct PartitionStatus {
+    _as: proto::PartitionStatus,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl IndexCollectionType {
-    /// ICT_DEFAULT is the Normal scalar index.
-    pub fn Default() -> Self {
-        IndexCollectionType {
-            _as: proto::IndexCollectionType::Default,
+impl PartitionStatus {
+    pub fn __construct(id: u32) -> Self {
+        PartitionStatus {
+            _as: proto::PartitionStatus {
+                bval: None,
+                id: id,
+                retry: false,
+                digest: vec![],
+            },
         }
     }
 
-    /// ICT_LIST is Index list elements.
-    pub fn List() -> Self {
-        IndexCollectionType {
-            _as: proto::IndexCollectionType::List,
-        }
+    /// get BVal
+    #[getter]
+    pub fn get_bval(&self) -> Option<i64> {
+        self._as.bval
     }
 
-    /// ICT_MAPKEYS is Index map keys.
-    pub fn MapKeys() -> Self {
-        IndexCollectionType {
-            _as: proto::IndexCollectionType::MapKeys,
-        }
+    /// Id shows the partition Id.
+    #[getter]
+    pub fn get_partition_id(&self) -> u32 {
+        self._as.id
     }
 
-    /// ICT_MAPVALUES is Index map values.
-    pub fn MapValues() -> Self {
-        IndexCollectionType {
-            _as: proto::IndexCollectionType::MapValues,
-        }
+    /// Digest records the digest of the last key digest received from the server
+    /// for this partition.
+    #[getter]
+    pub fn get_digest(&self) -> Vec<u8> {
+        self._as.digest.clone()
+    }
+
+    /// Retry signifies if the partition requires a retry.
+    #[getter]
+    pub fn get_retry(&self) -> bool {
+        self._as.retry
+    }
+}
+
+impl From<&proto::PartitionStatus> for PartitionStatus {
+    fn from(input: &proto::PartitionStatus) -> Self {
+        PartitionStatus { _as: input.clone() }
+    }
+}
+
+impl FromZval<'_> for PartitionStatus {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &PartitionStatus = zval.extract()?;
+
+        Some(PartitionStatus { _as: f._as.clone() })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  ParticleType
+//  PartitionFilter
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Server particle types. Unsupported types are commented out.
-#[php_class(name = "Aerospike\\ParticleType")]
-pub struct ParticleType {
-    _as: proto::ParticleType,
+/// Virtual collection of records retrieved through queries and scans. During a query/scan,
+/// multiple threads will retrieve records from the server nodes and put these records on an
+/// internal queue managed by the recordset. The single user thread consumes these records from the
+/// queue.
+#[php_class(name = "Aerospike\\PartitionFilter")]
+pub struct PartitionFilter {
+    _as: Arc<Mutex<proto::PartitionFilter>>,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl ParticleType {
-    pub fn Null() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Null,
-        }
+impl PartitionFilter {
+    pub fn __construct() -> Self {
+        Self::all()
     }
 
-    pub fn Integer() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Integer,
-        }
+    #[getter]
+    pub fn get_partition_status(&self) -> Vec<PartitionStatus> {
+        let p = self._as.lock().unwrap();
+        p.partitions.iter().map(|ps| ps.into()).collect()
     }
 
-    pub fn Float() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Float,
+    /// NewPartitionFilterAll creates a partition filter that
+    /// reads all the partitions.
+    pub fn all() -> Self {
+        PartitionFilter {
+            _as: Arc::new(Mutex::new(proto::PartitionFilter {
+                begin: 0,
+                count: PARTITIONS as u32,
+                digest: vec![],
+                partitions: vec![],
+                done: false,
+                retry: false,
+            })),
         }
     }
 
-    pub fn String() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::String,
+    /// NewPartitionFilterById creates a partition filter by partition id.
+    /// Partition id is between 0 - 4095
+    pub fn partition(id: u32) -> Self {
+        PartitionFilter {
+            _as: Arc::new(Mutex::new(proto::PartitionFilter {
+                begin: id,
+                count: 1,
+                digest: vec![],
+                partitions: vec![],
+                done: false,
+                retry: false,
+            })),
         }
     }
 
-    pub fn Blob() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Blob,
+    /// NewPartitionFilterByRange creates a partition filter by partition range.
+    /// begin partition id is between 0 - 4095
+    /// count is the number of partitions, in the range of 1 - 4096 inclusive.
+    pub fn range(begin: u32, count: u32) -> Self {
+        PartitionFilter {
+            _as: Arc::new(Mutex::new(proto::PartitionFilter {
+                begin: begin,
+                count: count,
+                digest: vec![],
+                partitions: vec![],
+                done: false,
+                retry: false,
+            })),
         }
     }
 
-    pub fn Digest() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Digest,
-        }
+    /// Serializes the state of the partition filter into a URL safe string token.
+    /// After a scan/query with `max_records` set, the token can be carried over to another
+    /// request and restored with `PartitionFilter::fromCursor()` to continue from where the
+    /// previous scan/query stopped.
+    pub fn to_cursor(&self) -> String {
+        let p = self._as.lock().unwrap();
+        URL_SAFE_NO_PAD.encode(encode_cursor(&p))
     }
 
-    pub fn Bool() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Bool,
+    /// Restores a partition filter from a token created by `PartitionFilter::toCursor()`.
+    /// Throws an `AerospikeException` with the `PARAMETER_ERROR` result code if the token is
+    /// invalid or corrupted.
+    pub fn from_cursor(cursor: &str) -> PhpResult<Option<Self>> {
+        match decode_cursor(cursor) {
+            Ok(pf) => Ok(Some(PartitionFilter {
+                _as: Arc::new(Mutex::new(pf)),
+            })),
+            Err(error) => {
+                throw_object(error.into_zval(true)?)?;
+                Ok(None)
+            }
         }
     }
 
-    pub fn Hll() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Hll,
+    fn init_partition_status(&mut self) {
+        let mut p = self._as.lock().unwrap();
+        if p.partitions.len() > 0 {
+            return;
         }
+
+        p.partitions = (0..PARTITIONS)
+            .map(|id| PartitionStatus::__construct(id as u32)._as)
+            .collect();
     }
+}
 
-    pub fn Map() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::Map,
-        }
+// Partition filter cursor format, all integers in network byte order:
+//
+//  version: u8
+//  flags: u8 (CURSOR_DONE | CURSOR_RETRY | CURSOR_PARTITIONS)
+//  begin: u16, count: u16
+//  digest_len: u8, digest: [u8; digest_len]
+//  status_count: u16, then for each partition that has made progress or needs a retry:
+//      id: u16, flags: u8 (CURSOR_RETRY | CURSOR_DIGEST | CURSOR_BVAL)
+//      digest_len: u8, digest: [u8; digest_len] if CURSOR_DIGEST
+//      bval: i64 if CURSOR_BVAL
+//  checksum: the first 4 bytes of the RIPEMD-160 hash of all the preceding bytes
+//
+// The bytes are then encoded to base64 with the URL safe alphabet and without padding.
+const CURSOR_VERSION: u8 = 1;
+const CURSOR_CHECKSUM_SIZE: usize = 4;
+
+const CURSOR_DONE: u8 = 1 << 0;
+const CURSOR_RETRY: u8 = 1 << 1;
+const CURSOR_PARTITIONS: u8 = 1 << 2;
+const CURSOR_DIGEST: u8 = 1 << 3;
+const CURSOR_BVAL: u8 = 1 << 4;
+
+fn cursor_checksum(buf: &[u8]) -> Vec<u8> {
+    let mut hash = Ripemd160::new();
+    hash.input(buf);
+    hash.result()[..CURSOR_CHECKSUM_SIZE].to_vec()
+}
+
+fn encode_cursor(pf: &proto::PartitionFilter) -> Vec<u8> {
+    let mut buf: Vec<u8> = vec![CURSOR_VERSION];
+
+    let mut flags = 0;
+    if pf.done {
+        flags |= CURSOR_DONE;
+    }
+    if pf.retry {
+        flags |= CURSOR_RETRY;
     }
+    if pf.partitions.len() > 0 {
+        flags |= CURSOR_PARTITIONS;
+    }
+    buf.push(flags);
 
-    pub fn List() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::List,
+    buf.extend_from_slice(&(pf.begin as u16).to_be_bytes());
+    buf.extend_from_slice(&(pf.count as u16).to_be_bytes());
+    buf.push(pf.digest.len() as u8);
+    buf.extend_from_slice(&pf.digest);
+
+    // only the partitions with a state are stored, the rest are recreated when decoding
+    let statuses: Vec<&proto::PartitionStatus> = pf
+        .partitions
+        .iter()
+        .filter(|ps| ps.retry || ps.digest.len() > 0 || ps.bval.is_some())
+        .collect();
+    buf.extend_from_slice(&(statuses.len() as u16).to_be_bytes());
+    for ps in statuses {
+        buf.extend_from_slice(&(ps.id as u16).to_be_bytes());
+
+        let mut flags = 0;
+        if ps.retry {
+            flags |= CURSOR_RETRY;
         }
-    }
+        if ps.digest.len() > 0 {
+            flags |= CURSOR_DIGEST;
+        }
+        if ps.bval.is_some() {
+            flags |= CURSOR_BVAL;
+        }
+        buf.push(flags);
 
-    pub fn Geo_Json() -> Self {
-        ParticleType {
-            _as: proto::ParticleType::GeoJson,
+        if ps.digest.len() > 0 {
+            buf.push(ps.digest.len() as u8);
+            buf.extend_from_slice(&ps.digest);
+        }
+        if let Some(bval) = ps.bval {
+            buf.extend_from_slice(&bval.to_be_bytes());
         }
     }
+
+    let checksum = cursor_checksum(&buf);
+    buf.extend_from_slice(&checksum);
+    buf
 }
 
-impl From<ParticleType> for i64 {
-    fn from(input: ParticleType) -> Self {
-        match &input._as {
-            proto::ParticleType::Null => 0,
-            proto::ParticleType::Integer => 1,
-            proto::ParticleType::Float => 2,
-            proto::ParticleType::String => 3,
-            proto::ParticleType::Blob => 4,
-            proto::ParticleType::Digest => 6,
-            proto::ParticleType::Bool => 17,
-            proto::ParticleType::Hll => 18,
-            proto::ParticleType::Map => 19,
-            proto::ParticleType::List => 20,
-            proto::ParticleType::Ldt => 21,
-            proto::ParticleType::GeoJson => 23,
+fn decode_cursor(cursor: &str) -> AsResult<proto::PartitionFilter> {
+    let invalid = |reason: &str| AerospikeException {
+        message: format!("Invalid partition filter cursor: {reason}"),
+        code: ResultCode::PARAMETER_ERROR,
+        in_doubt: false,
+    };
+
+    let buf = URL_SAFE_NO_PAD
+        .decode(cursor.trim())
+        .map_err(|_| invalid("not a base64 string"))?;
+    if buf.len() < CURSOR_CHECKSUM_SIZE + 1 {
+        return Err(invalid("too short"));
+    }
+
+    let (payload, checksum) = buf.split_at(buf.len() - CURSOR_CHECKSUM_SIZE);
+    if cursor_checksum(payload) != checksum {
+        return Err(invalid("checksum mismatch"));
+    }
+    if payload[0] != CURSOR_VERSION {
+        return Err(invalid(&format!("unsupported version {}", payload[0])));
+    }
+
+    let truncated = |_: std::io::Error| invalid("truncated");
+    let mut rdr = Cursor::new(&payload[1..]);
+    let read_bytes = |rdr: &mut Cursor<&[u8]>| -> AsResult<Vec<u8>> {
+        let len = rdr.read_u8().map_err(truncated)? as usize;
+        let mut bytes = vec![0; len];
+        std::io::Read::read_exact(rdr, &mut bytes).map_err(truncated)?;
+        Ok(bytes)
+    };
+
+    let flags = rdr.read_u8().map_err(truncated)?;
+    let begin = rdr.read_u16::<NetworkEndian>().map_err(truncated)? as u32;
+    let count = rdr.read_u16::<NetworkEndian>().map_err(truncated)? as u32;
+    let digest = read_bytes(&mut rdr)?;
+
+    // partitions are indexed by their id
+    let mut partitions: Vec<proto::PartitionStatus> = vec![];
+    if flags & CURSOR_PARTITIONS != 0 {
+        partitions = (0..PARTITIONS)
+            .map(|id| PartitionStatus::__construct(id as u32)._as)
+            .collect();
+    }
+
+    let status_count = rdr.read_u16::<NetworkEndian>().map_err(truncated)?;
+    for _ in 0..status_count {
+        let id = rdr.read_u16::<NetworkEndian>().map_err(truncated)? as usize;
+        let ps_flags = rdr.read_u8().map_err(truncated)?;
+
+        let ps = partitions
+            .get_mut(id)
+            .ok_or_else(|| invalid(&format!("invalid partition id {id}")))?;
+        ps.retry = ps_flags & CURSOR_RETRY != 0;
+        if ps_flags & CURSOR_DIGEST != 0 {
+            ps.digest = read_bytes(&mut rdr)?;
         }
+        if ps_flags & CURSOR_BVAL != 0 {
+            ps.bval = Some(rdr.read_i64::<NetworkEndian>().map_err(truncated)?);
+        }
+    }
+
+    if (rdr.position() as usize) < payload.len() - 1 {
+        return Err(invalid("trailing bytes"));
+    }
+
+    Ok(proto::PartitionFilter {
+        begin: begin,
+        count: count,
+        digest: digest,
+        partitions: partitions,
+        done: flags & CURSOR_DONE != 0,
+        retry: flags & CURSOR_RETRY != 0,
+    })
+}
+
+impl FromZval<'_> for PartitionFilter {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &PartitionFilter = zval.extract()?;
+
+        Some(PartitionFilter { _as: f._as.clone() })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  IndexType
+//  Recordset
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// IndexType the type of the secondary index.
-#[php_class(name = "Aerospike\\IndexType")]
-pub struct IndexType {
-    _as: proto::IndexType,
+/// Virtual collection of records retrieved through queries and scans. During a query/scan,
+/// multiple threads will retrieve records from the server nodes and put these records on an
+/// internal queue managed by the recordset. The single user thread consumes these records from the
+/// queue.
+///
+/// The recordset can be iterated with `foreach`, in which case the keys are the record `Key`s.
+/// Since they are objects, use `iterator_to_array($recordset, false)` to collect the records.
+/// The stream can only be consumed once; a new iteration continues from where the previous one
+/// stopped.
+#[php_class(name = "Aerospike\\Recordset")]
+#[implements(ext_php_rs::zend::ce::aggregate())]
+pub struct Recordset {
+    stream: Arc<Mutex<RecordStream>>,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl IndexType {
-    /// NUMERIC specifies an index on numeric values.
-    pub fn Numeric() -> Self {
-        IndexType {
-            _as: proto::IndexType::Numeric,
-        }
+impl Recordset {
+    /// Drop the stream, which will signal the server and close the recordset
+    pub fn close(&mut self) {
+        self.stream.lock().unwrap().close();
     }
 
-    /// STRING specifies an index on string values.
-    pub fn String() -> Self {
-        IndexType {
-            _as: proto::IndexType::String,
+    /// IsActive returns true if the operation hasn't been finished or cancelled.
+    #[getter]
+    pub fn get_active(&self) -> bool {
+        self.stream.lock().unwrap().is_active()
+    }
+
+    /// The error which ended the stream, or null if the stream ended normally or is still active.
+    #[getter]
+    pub fn get_error(&self) -> Option<AerospikeException> {
+        self.stream.lock().unwrap().error.clone()
+    }
+
+    /// Records is a channel on which the resulting records will be sent back.
+    /// Returns null when the stream is exhausted. If the stream ends with an error, the error is
+    /// thrown and is also available through the `error` property.
+    pub fn next(&mut self) -> PhpResult<Option<Record>> {
+        let mut stream = self.stream.lock().unwrap();
+        let rec = stream.fetch();
+        stream.throw_error()?;
+        Ok(rec.map(|rec| Record { _as: rec }))
+    }
+
+    /// Returns up to `n` records from the stream. Fewer records are returned when the stream is
+    /// exhausted, and an empty array once there are no more records. If the stream ends with an
+    /// error, the records read before the error are returned first, and the error is thrown on
+    /// the next call.
+    pub fn fetch_batch(&mut self, n: u32) -> PhpResult<Vec<Record>> {
+        let mut stream = self.stream.lock().unwrap();
+        let mut records = Vec::with_capacity(n as usize);
+        while records.len() < n as usize {
+            match stream.fetch() {
+                Some(rec) => records.push(Record { _as: rec }),
+                None => break,
+            }
+        }
+
+        if records.is_empty() {
+            stream.throw_error()?;
         }
+        Ok(records)
     }
 
-    /// BLOB specifies a []byte index. Requires server version 7.0+.
-    pub fn Blob() -> Self {
-        IndexType {
-            _as: proto::IndexType::Blob,
+    /// Returns an iterator over the records in the recordset. Used by `foreach`.
+    pub fn get_iterator(&self) -> RecordsetIterator {
+        RecordsetIterator {
+            stream: self.stream.clone(),
         }
     }
+}
 
-    /// GEO2DSPHERE specifies 2-dimensional spherical geospatial index.
-    pub fn Geo2DSphere() -> Self {
-        IndexType {
-            _as: proto::IndexType::Geo2DSphere,
+impl Recordset {
+    fn new(
+        stream: grpc::Stream<proto::AerospikeStreamResponse>,
+        client: Arc<Mutex<grpc::BlockingClient>>,
+        partition_filter: PartitionFilter,
+    ) -> Self {
+        Recordset {
+            stream: Arc::new(Mutex::new(RecordStream {
+                stream: Some(stream),
+                client: client,
+                partition_filter: partition_filter,
+                current: None,
+                error: None,
+                error_thrown: false,
+            })),
         }
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////
-//
-//  Filter
-//
-////////////////////////////////////////////////////////////////////////////////////////////
+/// The state of a query/scan stream, shared by a `Recordset` and its iterators.
+struct RecordStream {
+    stream: Option<grpc::Stream<proto::AerospikeStreamResponse>>,
+    client: Arc<Mutex<grpc::BlockingClient>>,
+    partition_filter: PartitionFilter,
 
-/// Query filter definition. Currently, only one filter is allowed in a Statement, and must be on a
-/// bin which has a secondary index defined.
-/// Filter instances should be instantiated using one of the provided macros.
-#[php_class(name = "Aerospike\\Filter")]
-pub struct Filter {
-    _as: proto::QueryFilter,
+    // the record the iterators are positioned on
+    current: Option<proto::Record>,
+
+    // the error which ended the stream, and whether it was already thrown to the user
+    error: Option<AerospikeException>,
+    error_thrown: bool,
 }
 
-#[php_impl]
-#[derive(ZvalConvert)]
-impl Filter {
-    /// NewEqualFilter creates a new equality filter instance for query.
-    /// Value can be an integer, string or a blob (byte array). Byte arrays are only supported on server v7+.
-    pub fn equal(bin_name: &str, value: PHPValue, ctx: Option<Vec<&CDTContext>>) -> Self {
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: proto::IndexCollectionType::Default.into(),
-                value_particle_type: value.particle_type() as u32,
-                begin: Some(value.clone().into()),
-                end: Some(value.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
-        }
+impl RecordStream {
+    fn close(&mut self) {
+        self.stream = None;
     }
 
-    /// NewRangeFilter creates a range filter for query.
-    /// Range arguments must be int64 values.
-    /// String ranges are not supported.
-    pub fn range(
-        bin_name: &str,
-        begin: PHPValue,
-        end: PHPValue,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: proto::IndexCollectionType::Default.into(),
-                value_particle_type: begin.particle_type() as u32,
-                begin: Some(begin.clone().into()),
-                end: Some(end.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
-        }
+    fn is_active(&self) -> bool {
+        self.stream.is_some()
     }
 
-    /// NewContainsFilter creates a contains filter for query on collection index.
-    /// Value can be an integer, string or a blob (byte array). Byte arrays are only supported on server v7+.
-    pub fn contains(
-        bin_name: &str,
-        value: PHPValue,
-        cit: Option<&IndexCollectionType>,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        let default = IndexCollectionType::Default();
-        let cit = cit.unwrap_or(&default);
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: cit._as.into(),
-                value_particle_type: value.particle_type() as u32,
-                begin: Some(value.clone().into()),
-                end: Some(value.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
-        }
-    }
+    /// Reads the next record from the stream and moves the partition filter cursor past it.
+    /// Returns None when the stream is closed, exhausted or has failed.
+    fn fetch(&mut self) -> Option<proto::Record> {
+        let stream = self.stream.as_mut()?;
+        let res = {
+            let mut client = self.client.lock().unwrap();
+            client.next_record(stream)
+        };
 
-    /// NewContainsRangeFilter creates a contains filter for query on ranges of data in a collection index.
-    pub fn contains_range(
-        bin_name: &str,
-        begin: PHPValue,
-        end: PHPValue,
-        cit: Option<&IndexCollectionType>,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        let default = IndexCollectionType::Default();
-        let cit = cit.unwrap_or(&default);
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: cit._as.into(),
-                value_particle_type: begin.particle_type() as u32,
-                begin: Some(begin.clone().into()),
-                end: Some(end.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
+        match res {
+            None => {
+                self.close();
+                None
+            }
+            Some(Err(pe)) => {
+                self.fail(pe.into());
+                None
+            }
+            Some(Ok(proto::AerospikeStreamResponse {
+                record: Some(rec),
+                bval,
+                ..
+            })) => {
+                // update partition_filter
+                if let Some(key) = rec.key.as_ref() {
+                    if let Some(pid) = (Key { _as: key.clone() }).partition_id() {
+                        let mut p = self.partition_filter._as.lock().unwrap();
+                        let begin = p.begin as usize;
+                        let ps = &mut p.partitions[pid - begin];
+                        ps.bval = bval;
+                        ps.digest = key.digest.clone();
+                    }
+                }
+                Some(rec)
+            }
+            Some(Ok(proto::AerospikeStreamResponse {
+                error: Some(ref pe),
+                ..
+            })) => {
+                self.fail(pe.into());
+                None
+            }
+            _ => None,
         }
     }
 
-    /// NewGeoWithinRegionFilter creates a geospatial "within region" filter for query.
-    /// Argument must be a valid GeoJSON region.
-    pub fn within_region(
-        bin_name: &str,
-        region: &str,
-        cit: Option<&IndexCollectionType>,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        let default = IndexCollectionType::Default();
-        let cit = cit.unwrap_or(&default);
-        let region = Value::string(region.into());
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: cit._as.into(),
-                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
-                begin: Some(region.clone().into()),
-                end: Some(region.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
-        }
+    fn fail(&mut self, error: AerospikeException) {
+        self.error = Some(error);
+        self.close();
     }
 
-    /// NewGeoWithinRegionForCollectionFilter creates a geospatial "within region" filter for query on collection index.
-    /// Argument must be a valid GeoJSON region.
-    pub fn within_radius(
-        bin_name: &str,
-        lat: f64,
-        lng: f64,
-        radius: f64,
-        cit: Option<&IndexCollectionType>,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        let default = IndexCollectionType::Default();
-        let cit = cit.unwrap_or(&default);
-        let rgnStr = format!(
-            r#"{{ "type": "AeroCircle", "coordinates": [[{:.8}, {:.8}], {}] }}"#,
-            lng, lat, radius
-        );
-        let value = Value::string(rgnStr);
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: cit._as.into(),
-                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
-                begin: Some(value.clone().into()),
-                end: Some(value.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
+    /// Throws the error which ended the stream, if it was not thrown already.
+    fn throw_error(&mut self) -> PhpResult<()> {
+        if self.error_thrown {
+            return Ok(());
         }
-    }
 
-    /// NewGeoRegionsContainingPointFilter creates a geospatial "containing point" filter for query.
-    /// Argument must be a valid GeoJSON point.
-    pub fn regions_containing_point(
-        bin_name: &str,
-        lat: f64,
-        lng: f64,
-        cit: Option<&IndexCollectionType>,
-        ctx: Option<Vec<&CDTContext>>,
-    ) -> Self {
-        let default = IndexCollectionType::Default();
-        let cit = cit.unwrap_or(&default);
-        let point = format!(
-            r#"{{"type":"Point","coordinates":[{:.8},{:.8}]}}"#,
-            lng, lat
-        );
-        let value = Value::string(point);
-        Filter {
-            _as: proto::QueryFilter {
-                name: bin_name.into(),
-                idx_type: cit._as.into(),
-                value_particle_type: PHPValue::GeoJSON("".into()).particle_type() as u32,
-                begin: Some(value.clone().into()),
-                end: Some(value.clone().into()),
-                ctx: ctx
-                    .map(|ctx| ctx.iter().map(|ctx| ctx._as.clone()).collect())
-                    .unwrap_or(vec![]),
-            },
+        if let Some(error) = self.error.clone() {
+            self.error_thrown = true;
+            throw_object(error.into_zval(true)?)?;
         }
+        Ok(())
     }
 }
 
-impl FromZval<'_> for Filter {
-    const TYPE: DataType = DataType::Mixed;
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  RecordsetIterator
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// RecordsetIterator implements the PHP `Iterator` interface over a `Recordset`.
+/// The keys are the record `Key`s, and the values are the `Record`s.
+#[php_class(name = "Aerospike\\RecordsetIterator")]
+#[implements(ext_php_rs::zend::ce::iterator())]
+pub struct RecordsetIterator {
+    stream: Arc<Mutex<RecordStream>>,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl RecordsetIterator {
+    /// Returns the current record.
+    pub fn current(&self) -> Option<Record> {
+        let stream = self.stream.lock().unwrap();
+        stream.current.as_ref().map(|rec| rec.into())
+    }
+
+    /// Returns the key of the current record.
+    pub fn key(&self) -> Option<Key> {
+        let stream = self.stream.lock().unwrap();
+        stream
+            .current
+            .as_ref()
+            .and_then(|rec| rec.key.clone())
+            .map(|key| Key { _as: key })
+    }
 
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &Filter = zval.extract()?;
+    /// Moves forward to the next record.
+    pub fn next(&mut self) -> PhpResult<()> {
+        let mut stream = self.stream.lock().unwrap();
+        stream.current = stream.fetch();
+        stream.throw_error()
+    }
 
-        Some(Filter { _as: f._as.clone() })
+    /// Returns true if the iterator is positioned on a record.
+    pub fn valid(&self) -> bool {
+        self.stream.lock().unwrap().current.is_some()
+    }
+
+    /// Streams can not be rewound. Positions the iterator on the first record not yet consumed.
+    pub fn rewind(&mut self) -> PhpResult<()> {
+        let mut stream = self.stream.lock().unwrap();
+        if stream.current.is_none() {
+            stream.current = stream.fetch();
+        }
+        stream.throw_error()
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  Statement
+//  ResultSet
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Statement encapsulates query statement parameters.
-#[php_class(name = "Aerospike\\Statement")]
-pub struct Statement {
-    _as: proto::Statement,
+/// ResultSet is the iterator over the values returned by an aggregation query.
+#[php_class(name = "Aerospike\\ResultSet")]
+pub struct ResultSet {
+    _as: Option<grpc::Stream<proto::AerospikeAggregateResponse>>,
+    client: Arc<Mutex<grpc::BlockingClient>>,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl Statement {
-    pub fn __construct(
-        namespace: &str,
-        set_name: &str,
-        filter: Option<Filter>,
-        bin_names: Option<Vec<String>>,
-    ) -> Self {
-        let mut rng = rand::thread_rng();
-        let filter_proto = filter.map(|f| f._as.clone());
-        Statement {
-            _as: proto::Statement {
-                namespace: namespace.into(),
-                set_name: set_name.into(),
-                bin_names: bin_names.unwrap_or_default(),
-                return_data: true,
-                task_id: rng.gen(),
-                filter: filter_proto,
-                index_name: None,
-                udf_call: None,
-            },
-        }
+impl ResultSet {
+    /// Drop the stream, which will signal the server and close the result set
+    pub fn close(&mut self) {
+        self._as = None;
     }
 
-    /// Filter determines query index filter (Optional).
-    /// This filter is applied to the secondary index on query.
-    /// Query index filters must reference a bin which has a secondary index defined.
+    /// IsActive returns true if the operation hasn't been finished or cancelled.
     #[getter]
-    pub fn get_filter(&self) -> Option<Filter> {
-        self._as.filter.as_ref().map(|f| Filter { _as: f.clone() })
+    pub fn get_active(&self) -> bool {
+        self._as.is_some()
     }
 
-    #[setter]
-    pub fn set_filter(&mut self, filter: Option<Filter>) {
-        self._as.filter = filter.map(|f| f._as.clone());
+    /// Returns the next value produced by the aggregation, or null when the result set is
+    /// exhausted. Errors are thrown as `AerospikeException`.
+    /// The aggregation can also produce null values. The result set is exhausted only once
+    /// `active` is false: `while (($v = $rs->next()) !== null || $rs->active)`.
+    pub fn next(&mut self) -> PhpResult<Option<PHPValue>> {
+        let stream = match self._as.as_mut() {
+            Some(stream) => stream,
+            None => return Ok(None),
+        };
+        let res = {
+            let mut client = self.client.lock().unwrap();
+            client.next_aggregate_value(stream)
+        };
+
+        match res {
+            None => {
+                // close the result set
+                self.close();
+                Ok(None)
+            }
+            Some(Err(status)) => Err(AerospikeException::from(status).into()),
+            Some(Ok(proto::AerospikeAggregateResponse {
+                error: Some(ref pe),
+                ..
+            })) => Err(AerospikeException::from(pe).into()),
+            Some(Ok(proto::AerospikeAggregateResponse { value: Some(v), .. })) => {
+                Ok(Some(v.into()))
+            }
+            _ => {
+                // a response without a value is not expected; end the result set
+                self.close();
+                Ok(None)
+            }
+        }
     }
+}
 
-    /// IndexName determines query index name (Optional)
-    /// If not set, the server will determine the index from the filter's bin name.
-    #[getter]
-    pub fn get_index_name(&self) -> Option<String> {
-        self._as.index_name.clone()
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  TaskStatus
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// TaskStatus is the status of an asynchronous task on the server.
+#[php_class(name = "Aerospike\\TaskStatus")]
+pub struct TaskStatus {
+    _as: proto::TaskStatus,
+}
+
+impl FromZval<'_> for TaskStatus {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &TaskStatus = zval.extract()?;
+
+        Some(TaskStatus { _as: f._as.clone() })
     }
+}
 
-    #[setter]
-    pub fn set_index_name(&mut self, index_name: Option<String>) {
-        self._as.index_name = index_name;
+#[php_impl]
+#[derive(ZvalConvert)]
+impl TaskStatus {
+    /// The task is still running on at least one node.
+    pub fn InProgress() -> Self {
+        TaskStatus {
+            _as: proto::TaskStatus::InProgress,
+        }
     }
 
-    /// BinNames detemines bin names (optional)
-    #[getter]
-    pub fn get_bin_names(&self) -> Vec<String> {
-        self._as.bin_names.clone()
+    /// The task has finished on all nodes.
+    pub fn Complete() -> Self {
+        TaskStatus {
+            _as: proto::TaskStatus::Complete,
+        }
     }
 
-    #[setter]
-    pub fn set_bin_names(&mut self, bin_names: Vec<String>) {
-        self._as.bin_names = bin_names;
+    /// Answers true only if the task is still running.
+    pub fn is_in_progress(&self) -> bool {
+        self._as == proto::TaskStatus::InProgress
     }
 
-    /// Namespace determines query Namespace
-    #[getter]
-    pub fn get_namespace(&self) -> String {
-        self._as.namespace.clone()
+    /// Answers true only if the task has finished on all nodes.
+    pub fn is_complete(&self) -> bool {
+        self._as == proto::TaskStatus::Complete
     }
+}
 
-    #[setter]
-    pub fn set_namespace(&mut self, namespace: String) {
-        self._as.namespace = namespace;
+/// Interval between two status checks while waiting for a task to complete.
+const TASK_POLL_INTERVAL_MS: u64 = 200;
+
+/// A task on the server which is polled for its completion on all nodes of the cluster.
+trait ServerTask {
+    /// Returns whether the task has finished on all nodes, and its progress in percent on
+    /// each node, keyed by the node name.
+    fn query_progress(&self) -> AsResult<(bool, HashMap<String, u32>)>;
+
+    fn poll_done(&self) -> PhpResult<bool> {
+        Ok(self.query_progress()?.0)
     }
 
-    /// SetName determines query Set name (Optional)
-    #[getter]
-    pub fn get_setname(&self) -> String {
-        self._as.set_name.clone()
+    fn poll_progress(&self) -> PhpResult<HashMap<String, u32>> {
+        Ok(self.query_progress()?.1)
     }
 
-    #[setter]
-    pub fn set_setname(&mut self, set_name: String) {
-        self._as.set_name = set_name;
+    /// Poll the task until it is done. A timeout of `None` or `0` waits forever.
+    /// Throws an `AerospikeException` with the `TIMEOUT` result code if the task does not
+    /// complete in time.
+    fn poll_until_done(&self, timeout_ms: Option<u64>) -> PhpResult<()> {
+        let deadline = timeout_ms
+            .filter(|t| *t > 0)
+            .map(|t| Instant::now() + Duration::from_millis(t));
+
+        while !self.poll_done()? {
+            if deadline.map_or(false, |d| Instant::now() >= d) {
+                let error = AerospikeException {
+                    message: "Timeout waiting for the task to complete".into(),
+                    code: ResultCode::TIMEOUT,
+                    in_doubt: false,
+                };
+                return Err(error.into());
+            }
+
+            std::thread::sleep(Duration::from_millis(TASK_POLL_INTERVAL_MS));
+        }
+        Ok(())
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  PartitionStatus
+//  ExecuteTask
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Virtual collection of records retrieved through queries and scans. During a query/scan,
-/// multiple threads will retrieve records from the server nodes and put these records on an
-/// internal queue managed by the recordset. The single user thread consumes these records from the
-/// queue.
-#[php_class(name = "Aerospike\\PartitionStatus")]
-pub struct PartitionStatus {
-    _as: proto::PartitionStatus,
+/// ExecuteTask is used to poll for the completion of a background query execution
+/// started with `Client::queryExecute`.
+#[php_class(name = "Aerospike\\ExecuteTask")]
+pub struct ExecuteTask {
+    task_id: u64,
+    scan: bool,
+    client: Arc<Mutex<grpc::BlockingClient>>,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl PartitionStatus {
-    pub fn __construct(id: u32) -> Self {
-        PartitionStatus {
-            _as: proto::PartitionStatus {
-                bval: None,
-                id: id,
-                retry: false,
-                digest: vec![],
-            },
-        }
-    }
-
-    /// get BVal
+impl ExecuteTask {
+    /// TaskId is the id of the background task on the server.
     #[getter]
-    pub fn get_bval(&self) -> Option<i64> {
-        self._as.bval
+    pub fn get_task_id(&self) -> u64 {
+        self.task_id
     }
 
-    /// Id shows the partition Id.
-    #[getter]
-    pub fn get_partition_id(&self) -> u32 {
-        self._as.id
+    /// Query the status of the task on all nodes of the cluster.
+    pub fn status(&self) -> PhpResult<TaskStatus> {
+        let status = match self.poll_done()? {
+            true => proto::TaskStatus::Complete,
+            false => proto::TaskStatus::InProgress,
+        };
+        Ok(TaskStatus { _as: status })
     }
 
-    /// Digest records the digest of the last key digest received from the server
-    /// for this partition.
-    #[getter]
-    pub fn get_digest(&self) -> Vec<u8> {
-        self._as.digest.clone()
+    /// Answers true only if the task has finished on all nodes.
+    pub fn is_done(&self) -> PhpResult<bool> {
+        self.poll_done()
     }
 
-    /// Retry signifies if the partition requires a retry.
-    #[getter]
-    pub fn get_retry(&self) -> bool {
-        self._as.retry
+    /// Wait until the task has finished on all nodes. If a timeout in milliseconds is
+    /// given and the task does not complete in time, an `AerospikeException` with the
+    /// `TIMEOUT` result code is thrown.
+    pub fn wait_until_complete(&self, timeout_ms: Option<u64>) -> PhpResult<()> {
+        self.poll_until_done(timeout_ms)
     }
 }
 
-impl From<&proto::PartitionStatus> for PartitionStatus {
-    fn from(input: &proto::PartitionStatus) -> Self {
-        PartitionStatus { _as: input.clone() }
+impl ServerTask for ExecuteTask {
+    // the server does not report the progress of background queries
+    fn query_progress(&self) -> AsResult<(bool, HashMap<String, u32>)> {
+        let request = tonic::Request::new(proto::AerospikeExecuteTaskStatusRequest {
+            task_id: self.task_id,
+            scan: self.scan,
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .execute_task_status(request)
+            .map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeExecuteTaskStatusResponse {
+                error: None,
+                status,
+            } => Ok((
+                *status == proto::TaskStatus::Complete as i32,
+                HashMap::new(),
+            )),
+            proto::AerospikeExecuteTaskStatusResponse {
+                error: Some(pe), ..
+            } => Err(pe.into()),
+        }
     }
 }
 
-impl FromZval<'_> for PartitionStatus {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &PartitionStatus = zval.extract()?;
-
-        Some(PartitionStatus { _as: f._as.clone() })
+/// Convert the response of a task status command to the completion state of the task
+/// and its progress in percent on each node.
+fn into_task_progress(
+    res: std::result::Result<tonic::Response<proto::AerospikeTaskProgressResponse>, tonic::Status>,
+) -> AsResult<(bool, HashMap<String, u32>)> {
+    let res = res.map_err(AerospikeException::from)?;
+    match res.into_inner() {
+        proto::AerospikeTaskProgressResponse {
+            error: Some(pe), ..
+        } => Err((&pe).into()),
+        proto::AerospikeTaskProgressResponse {
+            status, progress, ..
+        } => Ok((
+            status == proto::TaskStatus::Complete as i32,
+            progress.into_iter().map(|p| (p.node, p.percent)).collect(),
+        )),
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  PartitionFilter
+//  IndexTask
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Virtual collection of records retrieved through queries and scans. During a query/scan,
-/// multiple threads will retrieve records from the server nodes and put these records on an
-/// internal queue managed by the recordset. The single user thread consumes these records from the
-/// queue.
-#[php_class(name = "Aerospike\\PartitionFilter")]
-pub struct PartitionFilter {
-    _as: Arc<Mutex<proto::PartitionFilter>>,
+/// IndexTask is used to poll for the completion of a secondary index creation
+/// or removal on all nodes of the cluster.
+#[php_class(name = "Aerospike\\IndexTask")]
+pub struct IndexTask {
+    namespace: String,
+    index_name: String,
+    drop: bool,
+    client: Arc<Mutex<grpc::BlockingClient>>,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl PartitionFilter {
-    pub fn __construct() -> Self {
-        Self::all()
-    }
-
+impl IndexTask {
+    /// Namespace of the index.
     #[getter]
-    pub fn get_partition_status(&self) -> Vec<PartitionStatus> {
-        let p = self._as.lock().unwrap();
-        p.partitions.iter().map(|ps| ps.into()).collect()
+    pub fn get_namespace(&self) -> String {
+        self.namespace.clone()
     }
 
-    /// NewPartitionFilterAll creates a partition filter that
-    /// reads all the partitions.
-    pub fn all() -> Self {
-        PartitionFilter {
-            _as: Arc::new(Mutex::new(proto::PartitionFilter {
-                begin: 0,
-                count: PARTITIONS as u32,
-                digest: vec![],
-                partitions: vec![],
-                done: false,
-                retry: false,
-            })),
-        }
+    /// Name of the index.
+    #[getter]
+    pub fn get_index_name(&self) -> String {
+        self.index_name.clone()
     }
 
-    /// NewPartitionFilterById creates a partition filter by partition id.
-    /// Partition id is between 0 - 4095
-    pub fn partition(id: u32) -> Self {
-        PartitionFilter {
-            _as: Arc::new(Mutex::new(proto::PartitionFilter {
-                begin: id,
-                count: 1,
-                digest: vec![],
-                partitions: vec![],
-                done: false,
-                retry: false,
-            })),
-        }
+    /// Answers true only if the index has been built, or removed, on all nodes.
+    pub fn is_done(&self) -> PhpResult<bool> {
+        self.poll_done()
     }
 
-    /// NewPartitionFilterByRange creates a partition filter by partition range.
-    /// begin partition id is between 0 - 4095
-    /// count is the number of partitions, in the range of 1 - 4096 inclusive.
-    pub fn range(begin: u32, count: u32) -> Self {
-        PartitionFilter {
-            _as: Arc::new(Mutex::new(proto::PartitionFilter {
-                begin: begin,
-                count: count,
-                digest: vec![],
-                partitions: vec![],
-                done: false,
-                retry: false,
-            })),
-        }
+    /// Returns the percentage of the index built on each node, keyed by the node name.
+    /// For an index removal, a node reports 100 once the index is removed on it.
+    pub fn progress(&self) -> PhpResult<HashMap<String, u32>> {
+        self.poll_progress()
     }
 
-    fn init_partition_status(&mut self) {
-        let mut p = self._as.lock().unwrap();
-        if p.partitions.len() > 0 {
-            return;
-        }
-
-        p.partitions = (0..PARTITIONS)
-            .map(|id| PartitionStatus::__construct(id as u32)._as)
-            .collect();
+    /// Wait until the task has finished on all nodes. If a timeout in milliseconds is
+    /// given and the task does not complete in time, an `AerospikeException` with the
+    /// `TIMEOUT` result code is thrown.
+    pub fn wait_until_complete(&self, timeout_ms: Option<u64>) -> PhpResult<()> {
+        self.poll_until_done(timeout_ms)
     }
 }
 
-impl FromZval<'_> for PartitionFilter {
-    const TYPE: DataType = DataType::Mixed;
-
-    fn from_zval(zval: &Zval) -> Option<Self> {
-        let f: &PartitionFilter = zval.extract()?;
+impl ServerTask for IndexTask {
+    fn query_progress(&self) -> AsResult<(bool, HashMap<String, u32>)> {
+        let request = tonic::Request::new(proto::AerospikeIndexTaskStatusRequest {
+            policy: Some(InfoPolicy::default()._as),
+            namespace: self.namespace.clone(),
+            index_name: self.index_name.clone(),
+            drop: self.drop,
+        });
 
-        Some(PartitionFilter { _as: f._as.clone() })
+        let mut client = self.client.lock().unwrap();
+        into_task_progress(client.index_task_status(request))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
-//  Recordset
+//  UdfTask
 //
 ////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Virtual collection of records retrieved through queries and scans. During a query/scan,
-/// multiple threads will retrieve records from the server nodes and put these records on an
-/// internal queue managed by the recordset. The single user thread consumes these records from the
-/// queue.
-#[php_class(name = "Aerospike\\Recordset")]
-pub struct Recordset {
-    _as: Option<tonic::Streaming<proto::AerospikeStreamResponse>>,
+/// UdfTask is used to poll for the completion of a UDF package registration
+/// or removal on all nodes of the cluster.
+#[php_class(name = "Aerospike\\UdfTask")]
+pub struct UdfTask {
+    package_name: String,
+    drop: bool,
     client: Arc<Mutex<grpc::BlockingClient>>,
-    partition_filter: PartitionFilter,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl Recordset {
-    /// Drop the stream, which will signal the server and close the recordset
-    pub fn close(&mut self) {
-        self._as = None;
+impl UdfTask {
+    /// Name of the UDF package.
+    #[getter]
+    pub fn get_package_name(&self) -> String {
+        self.package_name.clone()
     }
 
-    /// IsActive returns true if the operation hasn't been finished or cancelled.
-    #[getter]
-    pub fn get_active(&self) -> bool {
-        self._as.is_some()
+    /// Answers true only if the package has been registered, or removed, on all nodes.
+    pub fn is_done(&self) -> PhpResult<bool> {
+        self.poll_done()
     }
 
-    /// Records is a channel on which the resulting records will be sent back.
-    pub fn next(&mut self) -> Option<Result<Record>> {
-        let mut pid: Option<usize> = None;
-        let mut digest: Option<Vec<u8>> = None;
-        let mut bval: Option<i64> = None;
-        let mut close: Option<bool> = None;
+    /// Returns the progress of the task on each node, keyed by the node name.
+    /// A node reports 100 once the package is registered, or removed, on it, and 0 otherwise.
+    pub fn progress(&self) -> PhpResult<HashMap<String, u32>> {
+        self.poll_progress()
+    }
 
-        let rec = self._as.as_mut().map(|mut stream| {
-            let mut client = self.client.lock().unwrap();
-            let res = client.next_record(&mut stream);
-            match res {
-                None => {
-                    close = Some(true);
-                    None
-                }
-                Some(Err(pe)) => {
-                    let e = format!("{pe}");
-                    let error = AerospikeException::new(&e);
-                    let _ = throw_object(error.into_zval(true).unwrap());
-                    None
-                }
-                Some(Ok(proto::AerospikeStreamResponse {
-                    record: Some(ref rec),
-                    bval: bv,
-                    ..
-                })) => {
-                    pid = rec
-                        .key
-                        .as_ref()
-                        .map(|k| Key { _as: k.clone() }.partition_id())?;
-                    digest = rec.key.as_ref().map(|k| k.digest.clone())?;
-                    bval = bv;
-
-                    Some(Ok(rec.into()))
-                }
-                Some(Ok(proto::AerospikeStreamResponse {
-                    error: Some(ref pe),
-                    ..
-                })) => {
-                    let error: AerospikeException = pe.into();
-                    let _ = throw_object(error.into_zval(true).unwrap());
-                    None
-                }
-                _ => None,
-            }
-        })?;
+    /// Wait until the task has finished on all nodes. If a timeout in milliseconds is
+    /// given and the task does not complete in time, an `AerospikeException` with the
+    /// `TIMEOUT` result code is thrown.
+    pub fn wait_until_complete(&self, timeout_ms: Option<u64>) -> PhpResult<()> {
+        self.poll_until_done(timeout_ms)
+    }
+}
 
-        // update partition_filter
-        pid.map(|pid| {
-            let mut p = self.partition_filter._as.lock().unwrap();
-            let begin = p.begin as usize;
-            let ps = &mut p.partitions[pid - begin];
-            ps.bval = bval;
-            digest.map(|digest| ps.digest = digest);
+impl ServerTask for UdfTask {
+    fn query_progress(&self) -> AsResult<(bool, HashMap<String, u32>)> {
+        let request = tonic::Request::new(proto::AerospikeUdfTaskStatusRequest {
+            policy: Some(InfoPolicy::default()._as),
+            package_name: self.package_name.clone(),
+            drop: self.drop,
         });
 
-        // close the recordset
-        close.map(|_| self.close());
-
-        rec
+        let mut client = self.client.lock().unwrap();
+        into_task_progress(client.udf_task_status(request))
     }
 }
 
@@ -4200,17 +7120,12 @@ pub struct Bin {
 #[derive(ZvalConvert)]
 impl Bin {
     pub fn __construct(name: &str, value: &Zval) -> PhpResult<Self> {
-        let v_op: Option<PHPValue> = from_zval(value);
-        match v_op {
-            Some(v) => {
-                let _as = proto::Bin {
-                    name: name.into(),
-                    value: Some(v.into()),
-                };
-                Ok(Bin { _as: _as })
-            }
-            _ => Err(format!("Invalid input for argument `value`").into()),
-        }
+        let v = try_from_zval(value, "").map_err(PhpException::from)?;
+        let _as = proto::Bin {
+            name: name.into(),
+            value: Some(v.into()),
+        };
+        Ok(Bin { _as: _as })
     }
 }
 
@@ -4448,6 +7363,41 @@ impl BatchPolicy {
             .map(|ref mut p| p.read_mode_sc = read_mode_sc._as.into());
     }
 
+    /// ReplicaPolicy determines the algorithm used to pick the target node for each key in the batch.
+    ///
+    /// Default: ReplicaPolicy::Sequence()
+    #[getter]
+    pub fn get_replica(&self) -> ReplicaPolicy {
+        self._as.policy.as_ref().unwrap().replica_policy.into()
+    }
+
+    #[setter]
+    pub fn set_replica(&mut self, replica: ReplicaPolicy) {
+        self._as
+            .policy
+            .as_mut()
+            .map(|ref mut p| p.replica_policy = (&replica).into());
+    }
+
+    /// Txn is the multi-record transaction the command belongs to. The command is not part of a
+    /// transaction if not set. The getter returns a copy of the transaction whose state is not tracked.
+    #[getter]
+    pub fn get_txn(&self) -> Option<Txn> {
+        self._as
+            .policy
+            .as_ref()
+            .and_then(|p| p.txn.clone())
+            .map(|t| t.into())
+    }
+
+    #[setter]
+    pub fn set_txn(&mut self, txn: Option<&Txn>) {
+        self._as
+            .policy
+            .as_mut()
+            .map(|ref mut p| p.txn = txn.map(|t| t._as.clone()));
+    }
+
     #[getter]
     pub fn get_filter_expression(&self) -> Option<Expression> {
         self._as
@@ -4461,18 +7411,11 @@ impl BatchPolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self
-                ._as
-                .policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = Some(fe._as)),
-            None => self
-                ._as
-                .policy
-                .as_mut()
-                .map(|ref mut p| p.filter_expression = None),
-        };
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            if let Some(p) = self._as.policy.as_mut() {
+                p.filter_expression = fe;
+            }
+        }
     }
 
     #[getter]
@@ -4578,9 +7521,8 @@ impl BatchReadPolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self._as.filter_expression = Some(fe._as),
-            None => self._as.filter_expression = None,
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
         }
     }
 
@@ -4692,9 +7634,8 @@ impl BatchWritePolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self._as.filter_expression = Some(fe._as),
-            None => self._as.filter_expression = None,
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
         }
     }
 
@@ -4878,9 +7819,8 @@ impl BatchDeletePolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self._as.filter_expression = Some(fe._as),
-            None => self._as.filter_expression = None,
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
         }
     }
 
@@ -4994,9 +7934,8 @@ impl BatchUdfPolicy {
 
     #[setter]
     pub fn set_filter_expression(&mut self, filter_expression: Option<Expression>) {
-        match filter_expression {
-            Some(fe) => self._as.filter_expression = Some(fe._as),
-            None => self._as.filter_expression = None,
+        if let Some(fe) = checked_filter_expression(filter_expression) {
+            self._as.filter_expression = fe;
         }
     }
     /// Desired consistency guarantee when committing a transaction on the server. The default
@@ -5200,6 +8139,7 @@ impl Operation {
 #[derive(Debug, PartialEq, Clone)]
 pub struct BatchRecord {
     _as: proto::BatchRecord,
+    ops: Vec<proto::Operation>,
 }
 
 #[php_impl]
@@ -5220,6 +8160,134 @@ impl BatchRecord {
         let r: proto::Record = self._as.record.clone()?;
         Some(Record { _as: r })
     }
+
+    /// ResultCode of the command for this key. Zero (OK) if the command succeeded.
+    #[getter]
+    pub fn get_result_code(&self) -> i32 {
+        self._as.error.as_ref().map(|e| e.result_code).unwrap_or(0)
+    }
+
+    /// InDoubt is set if the write command for this key may have completed even though an
+    /// error was returned.
+    #[getter]
+    pub fn get_in_doubt(&self) -> bool {
+        self._as.error.as_ref().map(|e| e.in_doubt).unwrap_or(false)
+    }
+
+    /// Results of the operations of a `BatchRead::withOps` or `BatchWrite` command, in the order
+    /// the operations were given. When more than one operation is run on the same bin, the server
+    /// returns a list of their results in that bin, which is split so that each operation gets
+    /// its own result. Operations without a bin name, such as `Operation::touch()`, return null.
+    ///
+    /// Null if the command had no operations, or if the record was not found or an error occurred.
+    #[getter]
+    pub fn get_results(&self) -> Option<Vec<PHPValue>> {
+        if self.ops.is_empty() {
+            return None;
+        }
+        let record = self._as.record.as_ref()?;
+
+        let names: Vec<Option<&str>> = self.ops.iter().map(operation_bin_name).collect();
+        let mut counts = HashMap::<&str, usize>::new();
+        for name in names.iter().flatten() {
+            *counts.entry(name).or_default() += 1;
+        }
+
+        let mut seen = HashMap::<&str, usize>::new();
+        Some(
+            names
+                .into_iter()
+                .map(|name| {
+                    let name = match name {
+                        Some(name) => name,
+                        None => return PHPValue::Nil,
+                    };
+                    let value: PHPValue = match record.bins.get(name) {
+                        Some(v) => v.clone().into(),
+                        None => return PHPValue::Nil,
+                    };
+                    if counts[name] == 1 {
+                        return value;
+                    }
+
+                    let i = seen.entry(name).or_default();
+                    let index = *i;
+                    *i += 1;
+                    match value {
+                        PHPValue::List(l) => l.get(index).cloned().unwrap_or(PHPValue::Nil),
+                        _ => PHPValue::Nil,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Returns the name of the bin the operation is run on, if any.
+fn operation_bin_name(op: &proto::Operation) -> Option<&str> {
+    match op.op.as_ref()? {
+        proto::operation::Op::Std(op) => op.bin_name.as_deref(),
+        proto::operation::Op::Map(op) => Some(&op.bin_name),
+        proto::operation::Op::List(op) => Some(&op.bin_name),
+        proto::operation::Op::Hll(op) => Some(&op.bin_name),
+        proto::operation::Op::Bitwise(op) => Some(&op.bin_name),
+        proto::operation::Op::Exp(op) => Some(&op.name),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  BatchResults
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// BatchResults holds the records of a batch command, in the order of the commands, and a
+/// summary of the keys which failed. Records which were not found do not count as failures.
+#[php_class(name = "Aerospike\\BatchResults")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct BatchResults {
+    records: Vec<BatchRecord>,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl BatchResults {
+    /// Records of the batch, in the order of the commands.
+    #[getter]
+    pub fn get_records(&self) -> Vec<BatchRecord> {
+        self.records.clone()
+    }
+
+    /// Number of keys for which the command succeeded or the record was not found.
+    #[getter]
+    pub fn get_success_count(&self) -> u64 {
+        self.records.len() as u64 - self.get_failure_count()
+    }
+
+    /// Number of keys for which the command failed.
+    #[getter]
+    pub fn get_failure_count(&self) -> u64 {
+        self.records.iter().filter(|r| r.is_failure()).count() as u64
+    }
+
+    /// Returns the records of the keys for which the command failed. Each carries the key, the
+    /// result code and the in doubt flag of the failure.
+    pub fn failures(&self) -> Vec<BatchRecord> {
+        self.records
+            .iter()
+            .filter(|r| r.is_failure())
+            .cloned()
+            .collect()
+    }
+}
+
+impl BatchRecord {
+    fn is_failure(&self) -> bool {
+        !matches!(
+            self.get_result_code(),
+            ResultCode::OK | ResultCode::KEY_NOT_FOUND_ERROR
+        )
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
@@ -5260,8 +8328,12 @@ impl BatchRead {
         }
     }
 
-    /// Optional read policy.
-    pub fn ops(policy: &BatchReadPolicy, key: &Key, ops: Vec<&Operation>) -> Self {
+    /// Creates a batch read command which runs the operations on the record.
+    /// Any read operation is accepted, including the `ListOp`, `MapOp`, `HllOp` and `BitwiseOp`
+    /// read operations. The results are available on `BatchRecord::$results`.
+    /// Ops are mutually exclusive with bin names.
+    /// Supported by server v5.6.0+.
+    pub fn with_ops(policy: &BatchReadPolicy, key: &Key, ops: Vec<&Operation>) -> Self {
         BatchRead {
             _as: proto::BatchRead {
                 batch_record: Some(proto::BatchRecord {
@@ -5282,10 +8354,12 @@ impl BatchRead {
         }
     }
 
-    /// Ops specifies the operations to perform for every key.
-    /// Ops are mutually exclusive with BinNames.
-    /// A binName can be emulated with `GetOp(binName)`
-    /// Supported by server v5.6.0+.
+    /// Same as `withOps`.
+    pub fn ops(policy: &BatchReadPolicy, key: &Key, ops: Vec<&Operation>) -> Self {
+        Self::with_ops(policy, key, ops)
+    }
+
+    /// Creates a batch read command which only reads the record header (generation and expiration).
     pub fn header(policy: &BatchReadPolicy, key: &Key) -> Self {
         BatchRead {
             _as: proto::BatchRead {
@@ -5473,29 +8547,279 @@ impl From<UdfLanguage> for i32 {
 /// Represents UDF (User-Defined Function) metadata for Aerospike.
 #[php_class(name = "Aerospike\\UdfMeta")]
 #[derive(Debug, PartialEq, Clone)]
-pub struct UdfMeta {
-    _as: proto::UdfMeta,
+pub struct UdfMeta {
+    _as: proto::UdfMeta,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl UdfMeta {
+    /// Getter method to retrieve the package name of the UDF.
+    #[getter]
+    pub fn get_package_name(&self) -> String {
+        self._as.package_name.clone()
+    }
+
+    /// Getter method to retrieve the hash of the UDF.
+    #[getter]
+    pub fn get_hash(&self) -> String {
+        self._as.hash.clone()
+    }
+
+    /// Getter method to retrieve the language of the UDF.
+    #[getter]
+    pub fn get_language(&self) -> UdfLanguage {
+        self._as.language.into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Node
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Node represents an Aerospike Database Server Node
+#[php_class(name = "Aerospike\\Node")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Node {
+    _as: proto::Node,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl Node {
+    /// Name of the node.
+    #[getter]
+    pub fn get_name(&self) -> String {
+        self._as.name.clone()
+    }
+
+    /// Address of the node in the `host:port` format.
+    #[getter]
+    pub fn get_address(&self) -> String {
+        self._as.address.clone()
+    }
+
+    /// Rack id of the node. If the node belongs to different racks for different namespaces,
+    /// the rack id of the first namespace in alphabetical order is returned.
+    /// Returns 0 if no racks are configured on the server.
+    #[getter]
+    pub fn get_rack_id(&self) -> i32 {
+        self._as.rack_id
+    }
+
+    /// Rack ids of the node per namespace.
+    #[getter]
+    pub fn get_racks(&self) -> HashMap<String, i32> {
+        self._as.racks.clone()
+    }
+
+    /// Returns true if the node is active.
+    #[getter]
+    pub fn get_active(&self) -> bool {
+        self._as.active
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  NamespaceInfo
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// NamespaceInfo contains the configuration and the statistics of a namespace.
+/// Counters are summed over all the nodes in the cluster.
+#[php_class(name = "Aerospike\\NamespaceInfo")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct NamespaceInfo {
+    _as: proto::NamespaceInfo,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl NamespaceInfo {
+    /// Name of the namespace.
+    #[getter]
+    pub fn get_name(&self) -> String {
+        self._as.name.clone()
+    }
+
+    /// Number of copies of each record in the cluster.
+    #[getter]
+    pub fn get_replication_factor(&self) -> i32 {
+        self._as.replication_factor
+    }
+
+    /// Storage engine of the namespace: memory, device or pmem.
+    #[getter]
+    pub fn get_storage_engine(&self) -> String {
+        self._as.storage_engine.clone()
+    }
+
+    /// Number of records in the namespace, including the replicas.
+    #[getter]
+    pub fn get_objects(&self) -> u64 {
+        self._as.objects
+    }
+
+    /// Number of master records in the namespace.
+    #[getter]
+    pub fn get_master_objects(&self) -> u64 {
+        self._as.master_objects
+    }
+
+    /// Number of tombstones in the namespace.
+    #[getter]
+    pub fn get_tombstones(&self) -> u64 {
+        self._as.tombstones
+    }
+
+    /// Returns true if any node in the cluster has stopped accepting writes for the namespace.
+    #[getter]
+    pub fn get_stop_writes(&self) -> bool {
+        self._as.stop_writes
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  SetInfo
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// SetInfo contains the configuration and the statistics of a set.
+/// Counters are summed over all the nodes in the cluster.
+#[php_class(name = "Aerospike\\SetInfo")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetInfo {
+    _as: proto::SetInfo,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl SetInfo {
+    /// Namespace of the set.
+    #[getter]
+    pub fn get_namespace(&self) -> String {
+        self._as.namespace.clone()
+    }
+
+    /// Name of the set.
+    #[getter]
+    pub fn get_name(&self) -> String {
+        self._as.name.clone()
+    }
+
+    /// Number of records in the set, including the replicas.
+    #[getter]
+    pub fn get_objects(&self) -> u64 {
+        self._as.objects
+    }
+
+    /// Number of tombstones in the set.
+    #[getter]
+    pub fn get_tombstones(&self) -> u64 {
+        self._as.tombstones
+    }
+
+    /// Memory used by the set data. Only reported by servers prior to 7.0.
+    #[getter]
+    pub fn get_memory_data_bytes(&self) -> u64 {
+        self._as.memory_data_bytes
+    }
+
+    /// Device storage used by the set data. Only reported by servers prior to 7.0.
+    #[getter]
+    pub fn get_device_data_bytes(&self) -> u64 {
+        self._as.device_data_bytes
+    }
+
+    /// Storage used by the set data. Only reported by servers 7.0+.
+    #[getter]
+    pub fn get_data_used_bytes(&self) -> u64 {
+        self._as.data_used_bytes
+    }
+
+    /// Returns true if the records in the set are not evicted.
+    #[getter]
+    pub fn get_disable_eviction(&self) -> bool {
+        self._as.disable_eviction
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  IndexInfo
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// IndexInfo contains the definition and the state of a secondary index.
+#[php_class(name = "Aerospike\\IndexInfo")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndexInfo {
+    _as: proto::IndexInfo,
 }
 
 #[php_impl]
 #[derive(ZvalConvert)]
-impl UdfMeta {
-    /// Getter method to retrieve the package name of the UDF.
+impl IndexInfo {
+    /// Namespace of the index.
     #[getter]
-    pub fn get_package_name(&self) -> String {
-        self._as.package_name.clone()
+    pub fn get_namespace(&self) -> String {
+        self._as.namespace.clone()
     }
 
-    /// Getter method to retrieve the hash of the UDF.
+    /// Set of the index. Empty if the index is on the whole namespace.
     #[getter]
-    pub fn get_hash(&self) -> String {
-        self._as.hash.clone()
+    pub fn get_set_name(&self) -> String {
+        self._as.set_name.clone()
     }
 
-    /// Getter method to retrieve the language of the UDF.
+    /// Name of the index.
     #[getter]
-    pub fn get_language(&self) -> UdfLanguage {
-        self._as.language.into()
+    pub fn get_name(&self) -> String {
+        self._as.name.clone()
+    }
+
+    /// Name of the indexed bin.
+    #[getter]
+    pub fn get_bin_name(&self) -> String {
+        self._as.bin_name.clone()
+    }
+
+    /// Type of the indexed values.
+    #[getter]
+    pub fn get_index_type(&self) -> IndexType {
+        self._as.index_type.into()
+    }
+
+    /// Collection type of the index.
+    #[getter]
+    pub fn get_collection_type(&self) -> IndexCollectionType {
+        self._as.collection_type.into()
+    }
+
+    /// CDT context of the indexed values in the bin. Empty if the bin itself is indexed.
+    #[getter]
+    pub fn get_ctx(&self) -> Vec<CDTContext> {
+        self._as
+            .ctx
+            .iter()
+            .map(|ctx| CDTContext { _as: ctx.clone() })
+            .collect()
+    }
+
+    /// State of the index. `RW` if the index is loaded on all the nodes in the cluster,
+    /// otherwise the state reported by a node on which it is still loading, e.g. `WO`.
+    #[getter]
+    pub fn get_state(&self) -> String {
+        self._as.state.clone()
+    }
+
+    /// Returns true if the index is loaded on all the nodes and can be queried.
+    pub fn is_loaded(&self) -> bool {
+        self._as.state == "RW"
     }
 }
 
@@ -9669,6 +12993,438 @@ impl CdtBitwiseOperation {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ExpReadFlags
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ExpReadFlags specifies the expression read operation flags.
+#[php_class(name = "Aerospike\\ExpReadFlags")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExpReadFlags {
+    _as: proto::ExpReadFlags,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ExpReadFlags {
+    /// ExpReadFlagDefault is the default.
+    pub fn Default() -> Self {
+        Self {
+            _as: proto::ExpReadFlags::Default,
+        }
+    }
+
+    /// ExpReadFlagEvalNoFail ignores failures caused by the expression resolving to unknown or a
+    /// non-bin type.
+    pub fn Eval_No_Fail() -> Self {
+        Self {
+            _as: proto::ExpReadFlags::EvalNoFail,
+        }
+    }
+}
+
+impl FromZval<'_> for ExpReadFlags {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &ExpReadFlags = zval.extract()?;
+
+        Some(ExpReadFlags { _as: f._as.clone() })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ExpWriteFlags
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ExpWriteFlags specifies the expression write operation flags.
+#[php_class(name = "Aerospike\\ExpWriteFlags")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExpWriteFlags {
+    _as: proto::ExpWriteFlags,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ExpWriteFlags {
+    /// ExpWriteFlagDefault is the default. Allows create or update.
+    pub fn Default() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::Default,
+        }
+    }
+
+    /// ExpWriteFlagCreateOnly behaves like the following:
+    /// If bin does not exist, a new bin will be created.
+    /// If bin exists, the operation will be denied.
+    pub fn Create_Only() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::CreateOnly,
+        }
+    }
+
+    /// ExpWriteFlagUpdateOnly behaves like the following:
+    /// If bin exists, the bin will be overwritten.
+    /// If bin does not exist, the operation will be denied.
+    pub fn Update_Only() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::UpdateOnly,
+        }
+    }
+
+    /// ExpWriteFlagAllowDelete deletes the bin if the expression results in nil value,
+    /// like `Expression::removeResult()`.
+    pub fn Allow_Delete() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::AllowDelete,
+        }
+    }
+
+    /// ExpWriteFlagPolicyNoFail does not raise error if operation is denied.
+    pub fn Policy_No_Fail() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::PolicyNoFail,
+        }
+    }
+
+    /// ExpWriteFlagEvalNoFail ignores failures caused by the expression resolving to unknown or a
+    /// non-bin type. Combined with `Expression::unknown()` it leaves the bin untouched.
+    pub fn Eval_No_Fail() -> Self {
+        Self {
+            _as: proto::ExpWriteFlags::EvalNoFail,
+        }
+    }
+}
+
+impl FromZval<'_> for ExpWriteFlags {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &ExpWriteFlags = zval.extract()?;
+
+        Some(ExpWriteFlags { _as: f._as.clone() })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  ExpOperation
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Expression operations evaluate an expression on the server and either return
+/// the result or write it to a bin. They can be used wherever an Operation is accepted.
+/// Requires server version 5.6.0+.
+#[php_class(name = "Aerospike\\ExpOperation")]
+pub struct ExpOperation {}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl ExpOperation {
+    /// ExpReadOp creates an operation with an expression that reads from a record.
+    /// The result is returned under the given name, which does not have to be an existing bin.
+    /// Flags are ExpReadFlags. You can specify multiple by `or`ing them together.
+    pub fn read(name: String, exp: &Expression, flags: Option<Vec<ExpReadFlags>>) -> Operation {
+        let flags: i32 = flags.unwrap_or(vec![]).iter().fold(0 as i32, |acc, f| {
+            let f: i32 = f._as.into();
+            acc | f
+        });
+
+        Operation {
+            _as: proto::operation::Op::Exp(proto::ExpOperation {
+                op_type: proto::ExpOperationType::Read.into(),
+                name: name,
+                exp: Some(exp._as.clone()),
+                flags: flags,
+            }),
+        }
+    }
+
+    /// ExpWriteOp creates an operation with an expression that writes to record bin.
+    /// Flags are ExpWriteFlags. You can specify multiple by `or`ing them together.
+    pub fn write(
+        bin_name: String,
+        exp: &Expression,
+        flags: Option<Vec<ExpWriteFlags>>,
+    ) -> Operation {
+        let flags: i32 = flags.unwrap_or(vec![]).iter().fold(0 as i32, |acc, f| {
+            let f: i32 = f._as.into();
+            acc | f
+        });
+
+        Operation {
+            _as: proto::operation::Op::Exp(proto::ExpOperation {
+                op_type: proto::ExpOperationType::Write.into(),
+                name: bin_name,
+                exp: Some(exp._as.clone()),
+                flags: flags,
+            }),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Txn
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Txn is a multi-record transaction. Set it on the policies of the commands which belong to
+/// the transaction, then finish it with `Client::commit()` or `Client::abort()`.
+///
+/// The connection manager keeps the transaction between requests, so the commands can be sent
+/// through any client connected to the same connection manager.
+///
+/// Requires server version 8.0+ and a namespace configured with strong consistency.
+#[php_class(name = "Aerospike\\Txn")]
+#[derive(Debug, Clone)]
+pub struct Txn {
+    _as: proto::Txn,
+    state: proto::TxnState,
+}
+
+impl FromZval<'_> for Txn {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &Txn = zval.extract()?;
+
+        Some(f.clone())
+    }
+}
+
+impl From<proto::Txn> for Txn {
+    fn from(input: proto::Txn) -> Self {
+        Txn {
+            _as: input,
+            state: proto::TxnState::Open,
+        }
+    }
+}
+
+impl Txn {
+    // The connection manager does not know transactions that were never used or that have
+    // already been committed, aborted or expired; their state is left alone.
+    fn update_state(&mut self, error: Option<&proto::Error>, state: i32) {
+        match error {
+            Some(pe) if pe.result_code == ResultCode::PARAMETER_ERROR => (),
+            _ => self.state = proto::TxnState::try_from(state).unwrap_or(self.state),
+        }
+    }
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl Txn {
+    /// Creates a new transaction with a random id. The timeout is in seconds; if not set or 0,
+    /// the server's mrt-duration config is used.
+    pub fn __construct(timeout: Option<u32>) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut id: i64 = 0;
+        while id == 0 {
+            id = rng.gen();
+        }
+
+        Txn {
+            _as: proto::Txn {
+                id: id,
+                timeout: timeout.unwrap_or_default(),
+            },
+            state: proto::TxnState::Open,
+        }
+    }
+
+    /// Id of the transaction.
+    #[getter]
+    pub fn get_id(&self) -> i64 {
+        self._as.id
+    }
+
+    /// Timeout of the transaction in seconds, counted from the first write in the transaction.
+    /// 0 means the server's mrt-duration config is used. Changing the timeout after the first
+    /// command of the transaction has no effect.
+    #[getter]
+    pub fn get_timeout(&self) -> u32 {
+        self._as.timeout
+    }
+
+    #[setter]
+    pub fn set_timeout(&mut self, timeout: u32) {
+        self._as.timeout = timeout;
+    }
+
+    /// State of the transaction, as of its last commit or abort.
+    #[getter]
+    pub fn get_state(&self) -> TxnState {
+        TxnState { _as: self.state }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  TxnState
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// TxnState is the state of a multi-record transaction.
+#[php_class(name = "Aerospike\\TxnState")]
+pub struct TxnState {
+    _as: proto::TxnState,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl TxnState {
+    /// The transaction is open and accepts commands.
+    pub fn Open() -> Self {
+        TxnState {
+            _as: proto::TxnState::Open,
+        }
+    }
+
+    /// The reads of the transaction have been verified, but it is not committed yet.
+    pub fn Verified() -> Self {
+        TxnState {
+            _as: proto::TxnState::Verified,
+        }
+    }
+
+    /// The transaction has been committed.
+    pub fn Committed() -> Self {
+        TxnState {
+            _as: proto::TxnState::Committed,
+        }
+    }
+
+    /// The transaction has been aborted.
+    pub fn Aborted() -> Self {
+        TxnState {
+            _as: proto::TxnState::Aborted,
+        }
+    }
+
+    /// Answers true only if the transaction is open.
+    pub fn is_open(&self) -> bool {
+        self._as == proto::TxnState::Open
+    }
+
+    /// Answers true only if the transaction has been committed.
+    pub fn is_committed(&self) -> bool {
+        self._as == proto::TxnState::Committed
+    }
+
+    /// Answers true only if the transaction has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self._as == proto::TxnState::Aborted
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  CommitStatus
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// CommitStatus is the result of a successful `Client::commit()`.
+#[php_class(name = "Aerospike\\CommitStatus")]
+pub struct CommitStatus {
+    _as: proto::CommitStatus,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl CommitStatus {
+    /// The commit succeeded.
+    pub fn Ok() -> Self {
+        CommitStatus {
+            _as: proto::CommitStatus::Ok,
+        }
+    }
+
+    /// The transaction had already been committed.
+    pub fn AlreadyCommitted() -> Self {
+        CommitStatus {
+            _as: proto::CommitStatus::AlreadyCommitted,
+        }
+    }
+
+    /// The reads were verified, but the commit failed on the client side.
+    /// The server will eventually roll the transaction forward.
+    pub fn RollForwardAbandoned() -> Self {
+        CommitStatus {
+            _as: proto::CommitStatus::RollForwardAbandoned,
+        }
+    }
+
+    /// The transaction was rolled forward, but the client failed to close it.
+    /// The server will eventually close the transaction.
+    pub fn CloseAbandoned() -> Self {
+        CommitStatus {
+            _as: proto::CommitStatus::CloseAbandoned,
+        }
+    }
+
+    /// Answers true only if the commit succeeded.
+    pub fn is_ok(&self) -> bool {
+        self._as == proto::CommitStatus::Ok
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  AbortStatus
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// AbortStatus is the result of a successful `Client::abort()`.
+#[php_class(name = "Aerospike\\AbortStatus")]
+pub struct AbortStatus {
+    _as: proto::AbortStatus,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl AbortStatus {
+    /// The abort succeeded.
+    pub fn Ok() -> Self {
+        AbortStatus {
+            _as: proto::AbortStatus::Ok,
+        }
+    }
+
+    /// The transaction had already been aborted.
+    pub fn AlreadyAborted() -> Self {
+        AbortStatus {
+            _as: proto::AbortStatus::AlreadyAborted,
+        }
+    }
+
+    /// The client failed to roll back the transaction.
+    /// The server will eventually abort the transaction.
+    pub fn RollBackAbandoned() -> Self {
+        AbortStatus {
+            _as: proto::AbortStatus::RollBackAbandoned,
+        }
+    }
+
+    /// The transaction was rolled back, but the client failed to close it.
+    /// The server will eventually close the transaction.
+    pub fn CloseAbandoned() -> Self {
+        AbortStatus {
+            _as: proto::AbortStatus::CloseAbandoned,
+        }
+    }
+
+    /// Answers true only if the abort succeeded.
+    pub fn is_ok(&self) -> bool {
+        self._as == proto::AbortStatus::Ok
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
 //  Client
@@ -9688,8 +13444,12 @@ impl CdtBitwiseOperation {
 /// Each record may have multiple bins, unless the Aerospike server nodes are configured as
 /// "single-bin". In "multi-bin" mode, partial records may be written or read by specifying the
 /// relevant subset of bins.
-fn new_aerospike_client(socket: &str) -> PhpResult<grpc::BlockingClient> {
-    let client = grpc::BlockingClient::connect(socket.into()).map_err(|e| e.to_string())?;
+fn new_aerospike_client(
+    socket: &str,
+    deadlines: grpc::Deadlines,
+) -> PhpResult<grpc::BlockingClient> {
+    let client =
+        grpc::BlockingClient::connect(socket.into(), deadlines).map_err(|e| e.to_string())?;
     Ok(client)
 }
 
@@ -9713,18 +13473,36 @@ impl Drop for Client {
 impl Client {
     /// Connects to the Aerospike database using the provided socket address.
     ///
-    /// If a persisted client object is found for the given socket address, it is returned.
-    /// Otherwise, a new client object is created, persisted, and returned.
+    /// If a persisted client object is found for the given socket address and deadlines, it is
+    /// returned. Otherwise, a new client object is created, persisted, and returned.
     ///
     /// # Arguments
     ///
     /// * `socket` - A string representing the socket address of the Aerospike database.
+    /// * `timeout_margin` - The time in milliseconds added to the timeout of a command for the
+    ///   deadline of its request to the connection manager. Default: 1000ms
+    /// * `default_timeout` - The timeout in milliseconds used for the deadline of commands
+    ///   without a total timeout of their own. Default: 30000ms
     ///
     /// # Returns
     ///
     /// * `Err("Error connecting to the database".into())` - If an error occurs during connection.
-    pub fn connect(socket: &str) -> PhpResult<Zval> {
-        match get_persisted_client(socket) {
+    pub fn connect(
+        socket: &str,
+        timeout_margin: Option<u64>,
+        default_timeout: Option<u64>,
+    ) -> PhpResult<Zval> {
+        let defaults = grpc::Deadlines::default();
+        let deadlines = grpc::Deadlines {
+            timeout_margin: timeout_margin
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.timeout_margin),
+            default_timeout: default_timeout
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.default_timeout),
+        };
+
+        match get_persisted_client(socket, deadlines) {
             Some(c) => {
                 trace!("Found Aerospike Client object for {}", socket);
                 return Ok(c);
@@ -9734,13 +13512,13 @@ impl Client {
 
         trace!("Creating a new Aerospike Client object for {}", socket);
 
-        let c = Arc::new(Mutex::new(new_aerospike_client(&socket)?));
+        let c = Arc::new(Mutex::new(new_aerospike_client(&socket, deadlines)?));
 
         // check if version numbers match
         let request = tonic::Request::new(proto::AerospikeVersionRequest {});
         let grpcClient = c.clone();
         let mut client = grpcClient.lock().unwrap();
-        let res = client.version(request).map_err(|e| e.to_string())?;
+        let res = client.version(request).map_err(AerospikeException::from)?;
         // Or match the comparison operators
         let vClient = Version::from(VERSION).unwrap();
         let vServer = Version::from(&res.get_ref().version).unwrap();
@@ -9752,9 +13530,9 @@ impl Client {
             .into());
         };
 
-        persist_client(socket, c)?;
+        persist_client(socket, deadlines, c)?;
 
-        match get_persisted_client(socket) {
+        match get_persisted_client(socket, deadlines) {
             Some(c) => {
                 return Ok(c);
             }
@@ -9772,6 +13550,30 @@ impl Client {
         self.socket.clone()
     }
 
+    /// TimeoutMargin is added to the total timeout of a command's policy to compute the
+    /// deadline of its request to the connection manager, leaving the connection manager the
+    /// time to report the timeout itself. If the connection manager does not respond before
+    /// the deadline, the command throws an `AerospikeException` with the
+    /// `CONNECTION_MANAGER_TIMEOUT` result code. Set with `Client::connect`.
+    ///
+    /// Default: 1000ms
+    #[getter]
+    pub fn get_timeout_margin(&self) -> u64 {
+        let client = self.client.lock().unwrap();
+        client.deadlines().timeout_margin.as_millis() as u64
+    }
+
+    /// DefaultTimeout is used in place of the total timeout for the deadline of commands which
+    /// have no time limit of their own. For scans and queries, it limits the wait for each
+    /// record. Set with `Client::connect`.
+    ///
+    /// Default: 30000ms
+    #[getter]
+    pub fn get_default_timeout(&self) -> u64 {
+        let client = self.client.lock().unwrap();
+        client.deadlines().default_timeout.as_millis() as u64
+    }
+
     /// Write record bin(s). The policy specifies the transaction timeout, record expiration and
     /// how the transaction is handled when the record already exists.
     pub fn put(&self, policy: &WritePolicy, key: &Key, bins: Vec<&Bin>) -> PhpResult<()> {
@@ -9784,7 +13586,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.put(request).map_err(|e| e.to_string())?;
+        let res = client
+            .put(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::Error {
                 result_code: 0,
@@ -9814,7 +13618,7 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.get(request).map_err(|e| e.to_string())?;
+        let res = client.get(request).map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeSingleResponse {
                 error: None,
@@ -9853,7 +13657,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.get_header(request).map_err(|e| e.to_string())?;
+        let res = client
+            .get_header(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeSingleResponse {
                 error: None,
@@ -9895,7 +13701,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.add(request).map_err(|e| e.to_string())?;
+        let res = client
+            .add(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::Error {
                 result_code: 0,
@@ -9922,7 +13730,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.append(request).map_err(|e| e.to_string())?;
+        let res = client
+            .append(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::Error {
                 result_code: 0,
@@ -9949,7 +13759,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.prepend(request).map_err(|e| e.to_string())?;
+        let res = client
+            .prepend(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::Error { result_code: 0, .. } => Ok(()),
             pe => {
@@ -9968,7 +13780,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.delete(request).map_err(|e| e.to_string())?;
+        let res = client
+            .delete(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::AerospikeDeleteResponse {
                 error: None,
@@ -9993,7 +13807,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.touch(request).map_err(|e| e.to_string())?;
+        let res = client
+            .touch(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::Error { result_code: 0, .. } => Ok(()),
             pe => {
@@ -10004,6 +13820,66 @@ impl Client {
         }
     }
 
+    /// Perform multiple read/write operations on a single key in one round trip. The operations
+    /// are executed atomically and in the order given. Operations can be any mix of `Operation`,
+    /// `ListOp`, `MapOp`, `HllOp` and `BitwiseOp` results.
+    ///
+    /// When the policy's `respondPerEachOp` is set, every operation returns a result, so when
+    /// the same bin is used in more than one operation, the bin value will be a list of the
+    /// results in the order of the operations.
+    ///
+    /// Returns null if the record does not exist and the operations do not create it.
+    pub fn operate(
+        &self,
+        policy: &WritePolicy,
+        key: &Key,
+        ops: Vec<&Operation>,
+    ) -> PhpResult<Option<Record>> {
+        let request = tonic::Request::new(proto::AerospikeOperateRequest {
+            policy: Some(policy._as.clone()),
+            key: Some(key._as.clone()),
+            operations: ops
+                .into_iter()
+                .map(|v| proto::Operation {
+                    op: Some(v._as.clone()),
+                })
+                .collect(),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .operate(request)
+            .map_err(AerospikeException::from_write_status)?;
+        match res.get_ref() {
+            proto::AerospikeSingleResponse {
+                error: None,
+                record: Some(rec),
+            } => Ok(Some(Record {
+                _as: (*rec).clone(),
+            })),
+            proto::AerospikeSingleResponse {
+                error: None,
+                record: None,
+            } => Ok(None),
+            // Not found: Do not throw an exception
+            proto::AerospikeSingleResponse {
+                error:
+                    Some(proto::Error {
+                        result_code: ResultCode::KEY_NOT_FOUND_ERROR,
+                        in_doubt: false,
+                    }),
+                ..
+            } => Ok(None),
+            proto::AerospikeSingleResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(None)
+            }
+        }
+    }
+
     /// Determine if a record key exists. The policy can be used to specify timeouts.
     pub fn exists(&self, policy: &ReadPolicy, key: &Key) -> PhpResult<bool> {
         let request = tonic::Request::new(proto::AerospikeExistsRequest {
@@ -10012,7 +13888,7 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.exists(request).map_err(|e| e.to_string())?;
+        let res = client.exists(request).map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeExistsResponse {
                 error: None,
@@ -10033,59 +13909,297 @@ impl Client {
     /// The returned records are located in the same list.
     ///
     /// BatchRecord can be *BatchRead, *BatchWrite, *BatchDelete or *BatchUDF.
+    /// The commands are checked before the batch is sent, and an `AerospikeException` with the
+    /// `PARAMETER_ERROR` result code is thrown if any of them is invalid.
     ///
     /// Requires server version 6.0+
     pub fn batch(&self, policy: &BatchPolicy, cmds: Vec<&Zval>) -> PhpResult<Vec<BatchRecord>> {
+        Ok(self.batch_execute(policy, cmds, None)?.records)
+    }
+
+    /// Same as `batch`, but returns a `BatchResults` with the records and a summary of the keys
+    /// which failed.
+    ///
+    /// In strict mode, a `BatchException` carrying the results is thrown if any key failed.
+    /// Records which were not found do not count as failures.
+    ///
+    /// Requires server version 6.0+
+    pub fn batch_execute(
+        &self,
+        policy: &BatchPolicy,
+        cmds: Vec<&Zval>,
+        strict: Option<bool>,
+    ) -> PhpResult<BatchResults> {
         let mut res = Vec::<proto::BatchOperate>::with_capacity(cmds.len());
-        cmds.into_iter().for_each(|v| {
+        let mut ops = Vec::<Vec<proto::Operation>>::with_capacity(cmds.len());
+        for (i, v) in cmds.into_iter().enumerate() {
             if let Some(&BatchRead { ref _as }) = v.extract() {
+                ops.push(_as.ops.clone());
                 res.push(proto::BatchOperate {
                     br: Some((*_as).clone()),
                     ..proto::BatchOperate::default()
                 });
             } else if let Some(&BatchWrite { ref _as }) = v.extract() {
+                ops.push(_as.ops.clone());
                 res.push(proto::BatchOperate {
                     bw: Some((*_as).clone()),
                     ..proto::BatchOperate::default()
                 });
             } else if let Some(&BatchDelete { ref _as }) = v.extract() {
+                ops.push(vec![]);
                 res.push(proto::BatchOperate {
                     bd: Some((*_as).clone()),
                     ..proto::BatchOperate::default()
                 });
             } else if let Some(&BatchUdf { ref _as }) = v.extract() {
+                ops.push(vec![]);
                 res.push(proto::BatchOperate {
                     bu: Some((*_as).clone()),
                     ..proto::BatchOperate::default()
                 });
             } else {
-                let error = AerospikeException::new("Invalid Batch command".into());
-                let _ = throw_object(error.into_zval(true).unwrap());
+                return Err(AerospikeException {
+                    message: format!("Invalid batch command at index {i}"),
+                    code: ResultCode::PARAMETER_ERROR,
+                    in_doubt: false,
+                }
+                .into());
             }
+        }
+
+        let records = self.send_batch(policy, res)?;
+        let results = BatchResults {
+            records: records
+                .into_iter()
+                .zip(ops)
+                .map(|(v, ops)| BatchRecord { _as: v, ops })
+                .collect(),
+        };
+
+        if strict.unwrap_or(false) && results.get_failure_count() > 0 {
+            return Err(BatchException::new(results).into());
+        }
+        Ok(results)
+    }
+
+    /// Read multiple records for the specified keys in one batch call.
+    /// The returned records are in the same order as the keys. If a key is not found, its
+    /// record is null. If bins is null or empty, all the bins are read.
+    ///
+    /// If any key failed for a reason other than not being found, throws a `BatchException`
+    /// carrying the result code and in doubt flag of every key.
+    pub fn batch_get(
+        &self,
+        policy: &BatchPolicy,
+        keys: Vec<&Key>,
+        bins: Option<Vec<String>>,
+    ) -> PhpResult<Vec<Option<Record>>> {
+        let bin_names = bins.unwrap_or_default();
+        let read_all_bins = bin_names.is_empty();
+        let cmds = keys
+            .into_iter()
+            .map(|key| proto::BatchOperate {
+                br: Some(proto::BatchRead {
+                    batch_record: Some(batch_record_for(key)),
+                    policy: Some(BatchReadPolicy::default()._as),
+                    bin_names: bin_names.clone(),
+                    read_all_bins: read_all_bins,
+                    ops: vec![],
+                }),
+                ..proto::BatchOperate::default()
+            })
+            .collect();
+
+        let records = self.send_batch(policy, cmds)?;
+        batch_records_or_not_found(records)
+    }
+
+    /// Read multiple record headers (generation and expiration) for the specified keys in one
+    /// batch call. The returned records are in the same order as the keys. If a key is not found,
+    /// its record is null.
+    ///
+    /// If any key failed for a reason other than not being found, throws a `BatchException`
+    /// carrying the result code and in doubt flag of every key.
+    pub fn batch_get_header(
+        &self,
+        policy: &BatchPolicy,
+        keys: Vec<&Key>,
+    ) -> PhpResult<Vec<Option<Record>>> {
+        let cmds = keys
+            .into_iter()
+            .map(|key| proto::BatchOperate {
+                br: Some(BatchRead::header(&BatchReadPolicy::default(), key)._as),
+                ..proto::BatchOperate::default()
+            })
+            .collect();
+
+        let records = self.send_batch(policy, cmds)?;
+        batch_records_or_not_found(records)
+    }
+
+    /// Check if multiple record keys exist in one batch call.
+    /// The returned flags are in the same order as the keys.
+    ///
+    /// If any key failed for a reason other than not being found, throws a `BatchException`
+    /// carrying the result code and in doubt flag of every key.
+    pub fn batch_exists(&self, policy: &BatchPolicy, keys: Vec<&Key>) -> PhpResult<Vec<bool>> {
+        let records = self.batch_get_header(policy, keys)?;
+        Ok(records.iter().map(|r| r.is_some()).collect())
+    }
+
+    /// Delete multiple records for the specified keys in one batch call.
+    /// The returned records are in the same order as the keys, and carry the result code of
+    /// each delete. The delete policy is optional.
+    ///
+    /// Requires server version 6.0+
+    pub fn batch_delete(
+        &self,
+        policy: &BatchPolicy,
+        keys: Vec<&Key>,
+        delete_policy: Option<&BatchDeletePolicy>,
+    ) -> PhpResult<Vec<BatchRecord>> {
+        let delete_policy = delete_policy
+            .map(|p| p._as.clone())
+            .unwrap_or(BatchDeletePolicy::default()._as);
+        let cmds = keys
+            .into_iter()
+            .map(|key| proto::BatchOperate {
+                bd: Some(proto::BatchDelete {
+                    batch_record: Some(batch_record_for(key)),
+                    policy: Some(delete_policy.clone()),
+                }),
+                ..proto::BatchOperate::default()
+            })
+            .collect();
+
+        let records = self.send_batch(policy, cmds)?;
+        Ok(records
+            .into_iter()
+            .map(|v| BatchRecord {
+                _as: v,
+                ops: vec![],
+            })
+            .collect())
+    }
+
+    /// Perform read/write operations on multiple keys in one batch call.
+    /// The returned records are in the same order as the keys, and carry the result code of
+    /// each key and the results of the operations in `BatchRecord::$results`. The write policy
+    /// is optional.
+    ///
+    /// Requires server version 6.0+
+    pub fn batch_operate(
+        &self,
+        policy: &BatchPolicy,
+        keys: Vec<&Key>,
+        ops: Vec<&Operation>,
+        write_policy: Option<&BatchWritePolicy>,
+    ) -> PhpResult<Vec<BatchRecord>> {
+        let write_policy = write_policy
+            .map(|p| p._as.clone())
+            .unwrap_or(BatchWritePolicy::default()._as);
+        let ops: Vec<proto::Operation> = ops
+            .into_iter()
+            .map(|v| proto::Operation {
+                op: Some(v._as.clone()),
+            })
+            .collect();
+        let cmds = keys
+            .into_iter()
+            .map(|key| proto::BatchOperate {
+                bw: Some(proto::BatchWrite {
+                    batch_record: Some(batch_record_for(key)),
+                    policy: Some(write_policy.clone()),
+                    ops: ops.clone(),
+                }),
+                ..proto::BatchOperate::default()
+            })
+            .collect();
+
+        let records = self.send_batch(policy, cmds)?;
+        Ok(records
+            .into_iter()
+            .map(|v| BatchRecord {
+                _as: v,
+                ops: ops.clone(),
+            })
+            .collect())
+    }
+
+    /// Commits the multi-record transaction. If the reads of the transaction cannot be verified,
+    /// the transaction is aborted and an `AerospikeException` is thrown. The state of `txn` is
+    /// updated either way. Committing a transaction that was never used, or that has already been
+    /// committed, aborted or has expired, throws with the `PARAMETER_ERROR` result code.
+    pub fn commit(&self, txn: &mut Txn) -> PhpResult<CommitStatus> {
+        let request = tonic::Request::new(proto::AerospikeCommitRequest {
+            txn: Some(txn._as.clone()),
         });
 
-        let request = tonic::Request::new(proto::AerospikeBatchOperateRequest {
-            policy: Some(policy._as.clone()),
-            records: res,
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .commit(request)
+            .map_err(AerospikeException::from_write_status)?;
+        let res = res.into_inner();
+        txn.update_state(res.error.as_ref(), res.state);
+        match res {
+            proto::AerospikeCommitResponse {
+                error: None,
+                status,
+                ..
+            } => Ok(CommitStatus {
+                _as: proto::CommitStatus::try_from(status).unwrap_or(proto::CommitStatus::Ok),
+            }),
+            proto::AerospikeCommitResponse {
+                error: Some(pe), ..
+            } => Err(AerospikeException::from(&pe).into()),
+        }
+    }
+
+    /// Aborts the multi-record transaction and rolls back its writes. The state of `txn` is
+    /// updated either way. Aborting an unknown transaction throws like `commit()` does.
+    pub fn abort(&self, txn: &mut Txn) -> PhpResult<AbortStatus> {
+        let request = tonic::Request::new(proto::AerospikeAbortRequest {
+            txn: Some(txn._as.clone()),
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.batch_operate(request).map_err(|e| e.to_string())?;
-        match res.get_ref() {
-            proto::AerospikeBatchOperateResponse {
+        let res = client.abort(request).map_err(AerospikeException::from)?;
+        let res = res.into_inner();
+        txn.update_state(res.error.as_ref(), res.state);
+        match res {
+            proto::AerospikeAbortResponse {
                 error: None,
-                records,
-            } => Ok(records
-                .into_iter()
-                .map(|v| BatchRecord { _as: (*v).clone() })
-                .collect()),
-            proto::AerospikeBatchOperateResponse {
+                status,
+                ..
+            } => Ok(AbortStatus {
+                _as: proto::AbortStatus::try_from(status).unwrap_or(proto::AbortStatus::Ok),
+            }),
+            proto::AerospikeAbortResponse {
                 error: Some(pe), ..
-            } => {
-                let error: AerospikeException = pe.into();
-                throw_object(error.into_zval(true)?)?;
-                Ok(vec![])
-            }
+            } => Err(AerospikeException::from(&pe).into()),
+        }
+    }
+
+    /// Encodes the expression in the server wire format, as base64, to cache it or keep it in
+    /// configuration files, and to share it with the other Aerospike clients. Restore it with
+    /// `Expression::fromBase64()`.
+    pub fn expression_to_base64(&self, expression: &Expression) -> PhpResult<String> {
+        let request = tonic::Request::new(proto::AerospikeExpressionToBase64Request {
+            expression: Some(expression._as.clone()),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .expression_to_base64(request)
+            .map_err(AerospikeException::from)?;
+        match res.into_inner() {
+            proto::AerospikeExpressionToBase64Response {
+                error: None,
+                base64,
+            } => Ok(base64),
+            proto::AerospikeExpressionToBase64Response {
+                error: Some(pe), ..
+            } => Err(AerospikeException::from(&pe).into()),
         }
     }
 
@@ -10105,7 +14219,7 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.truncate(request).map_err(|e| e.to_string())?;
+        let res = client.truncate(request).map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeTruncateResponse { error: None } => Ok(()),
             proto::AerospikeTruncateResponse { error: Some(pe) } => {
@@ -10116,6 +14230,136 @@ impl Client {
         }
     }
 
+    /// Sends the info commands to a node in the cluster and returns the responses keyed by the
+    /// command. If `node` is not set, a random node will be used. Use `Client::nodes()` to get the
+    /// names of the nodes in the cluster.
+    pub fn info(
+        &self,
+        policy: &InfoPolicy,
+        commands: Vec<String>,
+        node: Option<String>,
+    ) -> PhpResult<HashMap<String, String>> {
+        let request = tonic::Request::new(proto::AerospikeInfoRequest {
+            policy: Some(policy._as.clone()),
+            commands: commands,
+            node: node,
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client.info(request).map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeInfoResponse {
+                error: None,
+                results,
+            } => Ok(results.clone()),
+            proto::AerospikeInfoResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(HashMap::new())
+            }
+        }
+    }
+
+    /// Returns the nodes in the cluster.
+    pub fn nodes(&self) -> PhpResult<Vec<Node>> {
+        let request = tonic::Request::new(proto::AerospikeNodesRequest {});
+
+        let mut client = self.client.lock().unwrap();
+        let res = client.nodes(request).map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeNodesResponse { error: None, nodes } => {
+                Ok(nodes.iter().map(|v| Node { _as: v.clone() }).collect())
+            }
+            proto::AerospikeNodesResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Returns the namespaces in the cluster with their configuration and statistics.
+    pub fn namespaces(&self, policy: &InfoPolicy) -> PhpResult<Vec<NamespaceInfo>> {
+        let request = tonic::Request::new(proto::AerospikeNamespacesRequest {
+            policy: Some(policy._as.clone()),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .namespaces(request)
+            .map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeNamespacesResponse {
+                error: None,
+                namespaces,
+            } => Ok(namespaces
+                .iter()
+                .map(|v| NamespaceInfo { _as: v.clone() })
+                .collect()),
+            proto::AerospikeNamespacesResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Returns the sets in the namespace with their statistics.
+    pub fn sets(&self, policy: &InfoPolicy, namespace: &str) -> PhpResult<Vec<SetInfo>> {
+        let request = tonic::Request::new(proto::AerospikeSetsRequest {
+            policy: Some(policy._as.clone()),
+            namespace: namespace.into(),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client.sets(request).map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeSetsResponse { error: None, sets } => {
+                Ok(sets.iter().map(|v| SetInfo { _as: v.clone() }).collect())
+            }
+            proto::AerospikeSetsResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Returns the secondary indexes in the namespace.
+    pub fn indexes(&self, policy: &InfoPolicy, namespace: &str) -> PhpResult<Vec<IndexInfo>> {
+        let request = tonic::Request::new(proto::AerospikeIndexesRequest {
+            policy: Some(policy._as.clone()),
+            namespace: namespace.into(),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client.indexes(request).map_err(AerospikeException::from)?;
+        match res.get_ref() {
+            proto::AerospikeIndexesResponse {
+                error: None,
+                indexes,
+            } => Ok(indexes
+                .iter()
+                .map(|v| IndexInfo { _as: v.clone() })
+                .collect()),
+            proto::AerospikeIndexesResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(vec![])
+            }
+        }
+    }
+
     /// Read all records in the specified namespace and set and return a record iterator. The scan
     /// executor puts records on a queue in separate threads. The calling thread concurrently pops
     /// records off the queue through the record iterator. Up to `policy.max_concurrent_nodes`
@@ -10140,7 +14384,7 @@ impl Client {
             });
 
             let mut client = self.client.lock().unwrap();
-            client.scan(request).map_err(|e| e.to_string())?
+            client.scan(request).map_err(AerospikeException::from)?
         };
 
         // init the partition_status status
@@ -10148,11 +14392,7 @@ impl Client {
         // since it will be initialized there anyway
         partition_filter.init_partition_status();
 
-        Ok(Recordset {
-            _as: Some(res.into_inner()),
-            client: self.client.clone(),
-            partition_filter: partition_filter,
-        })
+        Ok(Recordset::new(res, self.client.clone(), partition_filter))
     }
 
     /// Execute a query on all server nodes and return a record iterator. The query executor puts
@@ -10173,19 +14413,104 @@ impl Client {
             });
 
             let mut client = self.client.lock().unwrap();
-            client.query(request).map_err(|e| e.to_string())?
+            client.query(request).map_err(AerospikeException::from)?
         };
 
-        // init the partition_status status
-        // we late init it to avoid sending it to the server when the value is default
-        // since it will be initialized there anyway
-        partition_filter.init_partition_status();
+        // init the partition_status status
+        // we late init it to avoid sending it to the server when the value is default
+        // since it will be initialized there anyway
+        partition_filter.init_partition_status();
+
+        Ok(Recordset::new(res, self.client.clone(), partition_filter))
+    }
+
+    /// Execute an aggregation query on all server nodes and return an iterator over the
+    /// reduced values. The stream UDF is set on the statement via
+    /// `Statement::setAggregateFunction`.
+    pub fn query_aggregate(
+        &self,
+        policy: &QueryPolicy,
+        statement: &Statement,
+    ) -> PhpResult<Option<ResultSet>> {
+        if statement._as.udf_call.is_none() {
+            let error = AerospikeException::new(
+                "An aggregate function must be set on the statement for aggregation queries",
+            );
+            throw_object(error.into_zval(true)?)?;
+            return Ok(None);
+        }
+
+        let request = tonic::Request::new(proto::AerospikeQueryAggregateRequest {
+            policy: Some(policy._as.clone()),
+            statement: Some(statement._as.clone()),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .query_aggregate(request)
+            .map_err(AerospikeException::from)?;
 
-        Ok(Recordset {
-            _as: Some(res.into_inner()),
+        Ok(Some(ResultSet {
+            _as: Some(res),
             client: self.client.clone(),
-            partition_filter: partition_filter,
-        })
+        }))
+    }
+
+    /// Apply operations or a UDF to all the records matched by the statement on the server,
+    /// in the background. If the operations are empty, the UDF set on the statement
+    /// via `Statement::setUdf` will be applied to each record. Passing operations together with
+    /// a UDF throws with the `PARAMETER_ERROR` result code.
+    /// This asynchronous server call will return before the command is complete.
+    /// The user can optionally wait for command completion by using the returned
+    /// ExecuteTask instance.
+    pub fn query_execute(
+        &self,
+        policy: &BackgroundExecutePolicy,
+        statement: &Statement,
+        ops: Option<Vec<&Operation>>,
+    ) -> PhpResult<Option<ExecuteTask>> {
+        let ops = ops.unwrap_or_default();
+        if ops.is_empty() && statement._as.udf_call.is_none() {
+            let error = AerospikeException::new(
+                "Either a list of operations or a UDF on the statement is required",
+            );
+            throw_object(error.into_zval(true)?)?;
+            return Ok(None);
+        }
+
+        let request = tonic::Request::new(proto::AerospikeQueryExecuteRequest {
+            policy: Some(policy._as.clone()),
+            statement: Some(statement._as.clone()),
+            operations: ops
+                .into_iter()
+                .map(|v| proto::Operation {
+                    op: Some(v._as.clone()),
+                })
+                .collect(),
+        });
+
+        let mut client = self.client.lock().unwrap();
+        let res = client
+            .query_execute(request)
+            .map_err(AerospikeException::from_write_status)?;
+        match res.get_ref() {
+            proto::AerospikeQueryExecuteResponse {
+                error: None,
+                task_id,
+                scan,
+            } => Ok(Some(ExecuteTask {
+                task_id: *task_id,
+                scan: *scan,
+                client: self.client.clone(),
+            })),
+            proto::AerospikeQueryExecuteResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = pe.into();
+                throw_object(error.into_zval(true)?)?;
+                Ok(None)
+            }
+        }
     }
 
     /// CreateIndex creates a secondary index.
@@ -10204,7 +14529,7 @@ impl Client {
         index_type: &IndexType,
         cit: Option<&IndexCollectionType>,
         ctx: Option<Vec<&CDTContext>>,
-    ) -> PhpResult<()> {
+    ) -> PhpResult<Option<IndexTask>> {
         let ictDefault = &IndexCollectionType::Default();
         let cit = cit.unwrap_or(ictDefault);
         let request = tonic::Request::new(proto::AerospikeCreateIndexRequest {
@@ -10221,13 +14546,20 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.create_index(request).map_err(|e| e.to_string())?;
+        let res = client
+            .create_index(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
-            proto::AerospikeCreateIndexResponse { error: None } => Ok(()),
+            proto::AerospikeCreateIndexResponse { error: None } => Ok(Some(IndexTask {
+                namespace: namespace.into(),
+                index_name: index_name.into(),
+                drop: false,
+                client: self.client.clone(),
+            })),
             proto::AerospikeCreateIndexResponse { error: Some(pe) } => {
                 let error: AerospikeException = pe.into();
                 throw_object(error.into_zval(true)?)?;
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -10241,7 +14573,7 @@ impl Client {
         namespace: &str,
         set_name: &str,
         index_name: &str,
-    ) -> PhpResult<()> {
+    ) -> PhpResult<Option<IndexTask>> {
         let request = tonic::Request::new(proto::AerospikeDropIndexRequest {
             policy: Some(policy._as.clone()),
             namespace: namespace.into(),
@@ -10250,13 +14582,20 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.drop_index(request).map_err(|e| e.to_string())?;
+        let res = client
+            .drop_index(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
-            proto::AerospikeDropIndexResponse { error: None } => Ok(()),
+            proto::AerospikeDropIndexResponse { error: None } => Ok(Some(IndexTask {
+                namespace: namespace.into(),
+                index_name: index_name.into(),
+                drop: true,
+                client: self.client.clone(),
+            })),
             proto::AerospikeDropIndexResponse { error: Some(pe) } => {
                 let error: AerospikeException = pe.into();
                 throw_object(error.into_zval(true)?)?;
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -10264,7 +14603,7 @@ impl Client {
     /// RegisterUDF registers a package containing user defined functions with server.
     /// This asynchronous server call will return before command is complete.
     /// The user can optionally wait for command completion by using the returned
-    /// RegisterTask instance.
+    /// UdfTask instance.
     ///
     /// This method is only supported by Aerospike 3+ servers.
     /// If the policy is nil, the default relevant policy will be used.
@@ -10274,7 +14613,7 @@ impl Client {
         udf_body: &str,
         package_name: &str,
         language: Option<UdfLanguage>,
-    ) -> PhpResult<()> {
+    ) -> PhpResult<Option<UdfTask>> {
         let request = tonic::Request::new(proto::AerospikeRegisterUdfRequest {
             policy: Some(policy._as.clone()),
             udf_body: udf_body.into(),
@@ -10283,13 +14622,19 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.register_udf(request).map_err(|e| e.to_string())?;
+        let res = client
+            .register_udf(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
-            proto::AerospikeRegisterUdfResponse { error: None } => Ok(()),
+            proto::AerospikeRegisterUdfResponse { error: None } => Ok(Some(UdfTask {
+                package_name: package_name.into(),
+                drop: false,
+                client: self.client.clone(),
+            })),
             proto::AerospikeRegisterUdfResponse { error: Some(pe) } => {
                 let error: AerospikeException = pe.into();
                 throw_object(error.into_zval(true)?)?;
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -10297,24 +14642,28 @@ impl Client {
     /// DropUDF removes a package containing user defined functions in the server.
     /// This asynchronous server call will return before command is complete.
     /// The user can optionally wait for command completion by using the returned
-    /// RemoveTask instance.
+    /// UdfTask instance.
     ///
     /// This method is only supported by Aerospike 3+ servers.
     /// If the policy is nil, the default relevant policy will be used.
-    pub fn drop_udf(&self, policy: &WritePolicy, package_name: &str) -> PhpResult<()> {
+    pub fn drop_udf(&self, policy: &WritePolicy, package_name: &str) -> PhpResult<Option<UdfTask>> {
         let request = tonic::Request::new(proto::AerospikeDropUdfRequest {
             policy: Some(policy._as.clone()),
             package_name: package_name.into(),
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.drop_udf(request).map_err(|e| e.to_string())?;
+        let res = client.drop_udf(request).map_err(AerospikeException::from)?;
         match res.get_ref() {
-            proto::AerospikeDropUdfResponse { error: None } => Ok(()),
+            proto::AerospikeDropUdfResponse { error: None } => Ok(Some(UdfTask {
+                package_name: package_name.into(),
+                drop: true,
+                client: self.client.clone(),
+            })),
             proto::AerospikeDropUdfResponse { error: Some(pe) } => {
                 let error: AerospikeException = pe.into();
                 throw_object(error.into_zval(true)?)?;
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -10328,7 +14677,7 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.list_udf(request).map_err(|e| e.to_string())?;
+        let res = client.list_udf(request).map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeListUdfResponse {
                 error: None,
@@ -10374,7 +14723,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.udf_execute(request).map_err(|e| e.to_string())?;
+        let res = client
+            .udf_execute(request)
+            .map_err(AerospikeException::from_write_status)?;
         match res.get_ref() {
             proto::AerospikeUdfExecuteResponse {
                 error: None,
@@ -10414,7 +14765,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.create_user(request).map_err(|e| e.to_string())?;
+        let res = client
+            .create_user(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeCreateUserResponse { error: None } => Ok(()),
             proto::AerospikeCreateUserResponse { error: Some(pe) } => {
@@ -10433,7 +14786,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.drop_user(request).map_err(|e| e.to_string())?;
+        let res = client
+            .drop_user(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeDropUserResponse { error: None } => Ok(()),
             proto::AerospikeDropUserResponse { error: Some(pe) } => {
@@ -10458,7 +14813,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.change_password(request).map_err(|e| e.to_string())?;
+        let res = client
+            .change_password(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeChangePasswordResponse { error: None } => Ok(()),
             proto::AerospikeChangePasswordResponse { error: Some(pe) } => {
@@ -10483,7 +14840,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.grant_roles(request).map_err(|e| e.to_string())?;
+        let res = client
+            .grant_roles(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeGrantRolesResponse { error: None } => Ok(()),
             proto::AerospikeGrantRolesResponse { error: Some(pe) } => {
@@ -10508,7 +14867,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.revoke_roles(request).map_err(|e| e.to_string())?;
+        let res = client
+            .revoke_roles(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeRevokeRolesResponse { error: None } => Ok(()),
             proto::AerospikeRevokeRolesResponse { error: Some(pe) } => {
@@ -10531,7 +14892,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.query_users(request).map_err(|e| e.to_string())?;
+        let res = client
+            .query_users(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeQueryUsersResponse {
                 error: None,
@@ -10559,7 +14922,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.query_roles(request).map_err(|e| e.to_string())?;
+        let res = client
+            .query_roles(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeQueryRolesResponse { error: None, roles } => {
                 Ok(roles.iter().map(|v| v.into()).collect())
@@ -10596,7 +14961,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.create_role(request).map_err(|e| e.to_string())?;
+        let res = client
+            .create_role(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeCreateRoleResponse { error: None } => Ok(()),
             proto::AerospikeCreateRoleResponse { error: Some(pe) } => {
@@ -10615,7 +14982,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.drop_role(request).map_err(|e| e.to_string())?;
+        let res = client
+            .drop_role(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeDropRoleResponse { error: None } => Ok(()),
             proto::AerospikeDropRoleResponse { error: Some(pe) } => {
@@ -10642,7 +15011,7 @@ impl Client {
         let mut client = self.client.lock().unwrap();
         let res = client
             .grant_privileges(request)
-            .map_err(|e| e.to_string())?;
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeGrantPrivilegesResponse { error: None } => Ok(()),
             proto::AerospikeGrantPrivilegesResponse { error: Some(pe) } => {
@@ -10669,7 +15038,7 @@ impl Client {
         let mut client = self.client.lock().unwrap();
         let res = client
             .revoke_privileges(request)
-            .map_err(|e| e.to_string())?;
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeRevokePrivilegesResponse { error: None } => Ok(()),
             proto::AerospikeRevokePrivilegesResponse { error: Some(pe) } => {
@@ -10694,7 +15063,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.set_allowlist(request).map_err(|e| e.to_string())?;
+        let res = client
+            .set_allowlist(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeSetAllowlistResponse { error: None } => Ok(()),
             proto::AerospikeSetAllowlistResponse { error: Some(pe) } => {
@@ -10723,7 +15094,9 @@ impl Client {
         });
 
         let mut client = self.client.lock().unwrap();
-        let res = client.set_quotas(request).map_err(|e| e.to_string())?;
+        let res = client
+            .set_quotas(request)
+            .map_err(AerospikeException::from)?;
         match res.get_ref() {
             proto::AerospikeSetQuotasResponse { error: None } => Ok(()),
             proto::AerospikeSetQuotasResponse { error: Some(pe) } => {
@@ -10735,6 +15108,79 @@ impl Client {
     }
 }
 
+impl Client {
+    /// Sends the commands in a single `BatchOperate` request, and returns the records in the
+    /// order of the commands.
+    fn send_batch(
+        &self,
+        policy: &BatchPolicy,
+        cmds: Vec<proto::BatchOperate>,
+    ) -> PhpResult<Vec<proto::BatchRecord>> {
+        let request = tonic::Request::new(proto::AerospikeBatchOperateRequest {
+            policy: Some(policy._as.clone()),
+            records: cmds,
+        });
+
+        let writes = request.get_ref().records.iter().any(|cmd| cmd.br.is_none());
+        let mut client = self.client.lock().unwrap();
+        let res = client.batch_operate(request).map_err(|status| {
+            if writes {
+                AerospikeException::from_write_status(status)
+            } else {
+                AerospikeException::from(status)
+            }
+        })?;
+        match res.into_inner() {
+            proto::AerospikeBatchOperateResponse {
+                error: None,
+                records,
+            } => Ok(records),
+            proto::AerospikeBatchOperateResponse {
+                error: Some(pe), ..
+            } => {
+                let error: AerospikeException = (&pe).into();
+                Err(error.into())
+            }
+        }
+    }
+}
+
+/// Returns an empty batch record for the key, to be filled in by the server.
+fn batch_record_for(key: &Key) -> proto::BatchRecord {
+    proto::BatchRecord {
+        key: Some(key._as.clone()),
+        record: None,
+        error: None,
+    }
+}
+
+/// Returns the records of a batch read, with None for the keys which were not found.
+/// If any key failed for another reason, a `BatchException` carrying the result code and
+/// in doubt flag of every key is returned instead, so that no result is lost.
+fn batch_records_or_not_found(records: Vec<proto::BatchRecord>) -> PhpResult<Vec<Option<Record>>> {
+    let results = BatchResults {
+        records: records
+            .into_iter()
+            .map(|v| BatchRecord {
+                _as: v,
+                ops: vec![],
+            })
+            .collect(),
+    };
+    if results.get_failure_count() > 0 {
+        return Err(BatchException::new(results).into());
+    }
+
+    Ok(results
+        .records
+        .into_iter()
+        .map(|br| match br._as.error {
+            None => br._as.record.map(|r| Record { _as: r }),
+            Some(_) => None,
+        })
+        .collect())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
 //  AerospikeException
@@ -10771,6 +15217,14 @@ impl AerospikeException {
             in_doubt: false,
         }
     }
+
+    /// Converts the status of a failed call for a command which writes to the database.
+    /// If the deadline expired, the write may still have been applied on the server.
+    fn from_write_status(status: tonic::Status) -> Self {
+        let mut error = Self::from(status);
+        error.in_doubt = error.code == ResultCode::CONNECTION_MANAGER_TIMEOUT;
+        error
+    }
 }
 
 impl From<&proto::Error> for AerospikeException {
@@ -10784,9 +15238,97 @@ impl From<&proto::Error> for AerospikeException {
     }
 }
 
+impl From<tonic::Status> for AerospikeException {
+    fn from(status: tonic::Status) -> AerospikeException {
+        match status.code() {
+            // the deadline of the call expired before the connection manager responded
+            tonic::Code::DeadlineExceeded => AerospikeException {
+                message: format!(
+                    "{}: {}",
+                    ResultCode::to_string(ResultCode::CONNECTION_MANAGER_TIMEOUT),
+                    status.message()
+                ),
+                code: ResultCode::CONNECTION_MANAGER_TIMEOUT,
+                in_doubt: false,
+            },
+            // the connection manager could not convert the request for the Go client
+            tonic::Code::InvalidArgument => AerospikeException {
+                message: status.message().into(),
+                code: ResultCode::PARAMETER_ERROR,
+                in_doubt: false,
+            },
+            _ => AerospikeException {
+                message: status.to_string(),
+                code: ResultCode::GRPC_ERROR,
+                in_doubt: false,
+            },
+        }
+    }
+}
+
 impl From<AerospikeException> for PhpException {
     fn from(error: AerospikeException) -> PhpException {
-        PhpException::default(error.message)
+        let mut ex = PhpException::default(error.message.clone());
+        // throw the AerospikeException itself, so that the result code is not lost
+        ex.set_object(error.into_zval(false).ok());
+        ex
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  BatchException
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Thrown by batch commands in strict mode when one or more keys failed. Carries the results
+/// of the whole batch, including the keys which succeeded.
+///
+/// It is not an `AerospikeException` and must be caught on its own. It can not extend it, since
+/// the classes of the extension are registered in no particular order.
+#[php_class(name = "Aerospike\\BatchException")]
+#[extends(ext_php_rs::zend::ce::exception())]
+#[derive(Debug, Clone)]
+pub struct BatchException {
+    #[prop(flags = ext_php_rs::flags::PropertyFlags::Public)]
+    message: String,
+    #[prop(flags = ext_php_rs::flags::PropertyFlags::Public)]
+    code: i32,
+    #[prop(flags = ext_php_rs::flags::PropertyFlags::Public)]
+    in_doubt: bool,
+    results: BatchResults,
+}
+
+#[php_impl]
+impl BatchException {
+    /// Results of the batch, including the keys which succeeded.
+    #[getter]
+    pub fn get_results(&self) -> BatchResults {
+        self.results.clone()
+    }
+}
+
+impl BatchException {
+    fn new(results: BatchResults) -> Self {
+        let failures = results.failures();
+        BatchException {
+            message: format!(
+                "{} of {} keys failed in the batch",
+                failures.len(),
+                results.records.len()
+            ),
+            code: ResultCode::BATCH_FAILED,
+            in_doubt: failures.iter().any(|r| r.get_in_doubt()),
+            results,
+        }
+    }
+}
+
+impl From<BatchException> for PhpException {
+    fn from(error: BatchException) -> PhpException {
+        let mut ex = PhpException::default(error.message.clone());
+        ex.set_object(error.into_zval(false).ok());
+        ex
     }
 }
 
@@ -11170,8 +15712,11 @@ pub enum PHPValue {
     /// Map data type is a collection of key-value pairs. Each key can only appear once in a
     /// collection and is associated with a value. Map keys and values can be any supported data
     /// type.
-    /// TODO: Implement the ordered map and remove hashmap completely
     HashMap(HashMap<PHPValue, PHPValue>),
+    /// Map data type which preserves the order of its entries. Maps read from the database keep
+    /// the order they were returned in by the server (key order for K-ordered and KV-ordered
+    /// maps), and maps converted from PHP arrays keep the insertion order of the array.
+    OrderedMap(Vec<(PHPValue, PHPValue)>),
     /// Map data type is a collection of key-value pairs. Each key can only appear once in a
     /// collection and is associated with a value. Map keys and values can be any supported data
     /// type.
@@ -11208,6 +15753,10 @@ impl Hash for PHPValue {
                 let error = AerospikeException::new("HashMaps cannot be used as map keys.");
                 let _ = throw_object(error.into_zval(true).unwrap());
             }
+            PHPValue::OrderedMap(_) => {
+                let error = AerospikeException::new("OrderedMaps cannot be used as map keys.");
+                let _ = throw_object(error.into_zval(true).unwrap());
+            }
             PHPValue::Json(_) => {
                 let error = AerospikeException::new("Jsons cannot be used as map keys.");
                 let _ = throw_object(error.into_zval(true).unwrap());
@@ -11219,7 +15768,7 @@ impl Hash for PHPValue {
             PHPValue::Wildcard => {
                 let error = AerospikeException::new("Infinity cannot be used as map keys.");
                 let _ = throw_object(error.into_zval(true).unwrap());
-            } // PHPValue::OrderedMap(_) => panic!("OrderedMaps cannot be used as map keys."),
+            }
         }
     }
 }
@@ -11239,10 +15788,10 @@ impl PHPValue {
             PHPValue::HLL(ref val) => format!("HLL('{:?}')", val),
             PHPValue::List(ref val) => format!("{:?}", val),
             PHPValue::HashMap(ref val) => format!("{:?}", val),
+            PHPValue::OrderedMap(ref val) => format!("{:?}", val),
             PHPValue::Json(ref val) => format!("{:?}", val),
             PHPValue::Infinity => "<infinity>".to_string(),
             PHPValue::Wildcard => "<wildcard>".to_string(),
-            // PHPValue::OrderedMap(ref val) => format!("{:?}", val),
         }
     }
 
@@ -11257,12 +15806,12 @@ impl PHPValue {
             PHPValue::Bool(_) => 17,
             PHPValue::HLL(_) => 18,
             PHPValue::HashMap(_) => 19,
+            PHPValue::OrderedMap(_) => 19,
             PHPValue::Json(_) => 19,
             PHPValue::List(_) => 20,
             PHPValue::GeoJSON(_) => 23,
             PHPValue::Infinity => unreachable!(),
             PHPValue::Wildcard => unreachable!(),
-            // PHPValue::OrderedMap(_) => format!("{:?}", val),
         }
     }
 
@@ -11317,6 +15866,13 @@ impl IntoZval for PHPValue {
 
                 zv.set_hashtable(arr)
             }
+            PHPValue::HashMap(h) if !h.keys().all(is_php_array_key) => {
+                let map = Map {
+                    entries: h.into_iter().collect(),
+                };
+                let zo: ZBox<ZendObject> = map.into_zend_object()?;
+                zo.set_zval(zv, persistent)?;
+            }
             PHPValue::HashMap(h) => {
                 let mut arr = ZendHashTable::with_capacity(h.len() as u32);
                 h.iter().for_each(|(k, v)| {
@@ -11326,6 +15882,24 @@ impl IntoZval for PHPValue {
 
                 zv.set_hashtable(arr)
             }
+            PHPValue::OrderedMap(m) if !m.iter().all(|(k, _)| is_php_array_key(k)) => {
+                let map = Map { entries: m };
+                let zo: ZBox<ZendObject> = map.into_zend_object()?;
+                zo.set_zval(zv, persistent)?;
+            }
+            PHPValue::OrderedMap(m) => {
+                let mut arr = ZendHashTable::with_capacity(m.len() as u32);
+                for (k, v) in m {
+                    match k {
+                        PHPValue::Int(i) => arr.insert_at_index(i as u64, v)?,
+                        PHPValue::UInt(i) => arr.insert_at_index(i, v)?,
+                        PHPValue::String(s) => arr.insert(&s, v)?,
+                        k => arr.insert(&k.to_string(), v)?,
+                    }
+                }
+
+                zv.set_hashtable(arr)
+            }
             PHPValue::GeoJSON(s) => {
                 let geo = GeoJSON { v: s };
                 let zo: ZBox<ZendObject> = geo.into_zend_object()?;
@@ -11358,67 +15932,100 @@ impl IntoZval for PHPValue {
 }
 
 fn from_zval(zval: &Zval) -> Option<PHPValue> {
+    match try_from_zval(zval, "") {
+        Ok(v) => Some(v),
+        Err(error) => {
+            let _ = throw_object(error.into_zval(true).unwrap());
+            None
+        }
+    }
+}
+
+/// Converts a PHP value into a `PHPValue`. `path` locates the value inside the top level value
+/// (e.g. `[2]["name"]`) and is used in the error message if the value is not supported.
+fn try_from_zval(zval: &Zval, path: &str) -> AsResult<PHPValue> {
+    let unsupported = |what: String| {
+        let at = if path.is_empty() {
+            "".to_string()
+        } else {
+            format!(" at `{path}`")
+        };
+        AerospikeException {
+            message: format!("Unsupported value{at}: {what}"),
+            code: ResultCode::PARAMETER_ERROR,
+            in_doubt: false,
+        }
+    };
+
     match zval.get_type() {
         DataType::Object(_) => {
             if let Some(o) = zval.extract::<BLOB>() {
-                return Some(PHPValue::Blob(o.v));
+                return Ok(PHPValue::Blob(o.v));
             } else if let Some(o) = zval.extract::<HLL>() {
-                return Some(PHPValue::HLL(o.v));
+                return Ok(PHPValue::HLL(o.v));
             } else if let Some(o) = zval.extract::<GeoJSON>() {
-                return Some(PHPValue::GeoJSON(o.v));
+                return Ok(PHPValue::GeoJSON(o.v));
             } else if let Some(_) = zval.extract::<Infinity>() {
-                return Some(PHPValue::Infinity);
+                return Ok(PHPValue::Infinity);
             } else if let Some(_) = zval.extract::<Wildcard>() {
-                return Some(PHPValue::Wildcard);
+                return Ok(PHPValue::Wildcard);
+            } else if let Some(o) = zval.extract::<Map>() {
+                return Ok(PHPValue::OrderedMap(o.entries));
             }
-            let error = AerospikeException::new("Invalid Object");
-            let _ = throw_object(error.into_zval(true).unwrap());
-            None
+
+            let class_name = zval
+                .object()
+                .and_then(|o| o.get_class_name().ok())
+                .unwrap_or_default();
+            Err(unsupported(format!("object of class `{class_name}`")))
         }
         // DataType::Undef => Some(PHPValue::Nil),
-        DataType::Null => Some(PHPValue::Nil),
-        DataType::False => Some(PHPValue::Bool(false)),
-        DataType::True => Some(PHPValue::Bool(true)),
-        DataType::Bool => zval.bool().map(|v| PHPValue::Bool(v)),
-        DataType::Long => zval.long().map(|v| PHPValue::Int(v)),
-        DataType::Double => zval
-            .double()
-            .map(|v| PHPValue::Float(ordered_float::OrderedFloat(v))),
-        DataType::String => zval.string().map(|v| PHPValue::String(v)),
+        DataType::Null => Ok(PHPValue::Nil),
+        DataType::False => Ok(PHPValue::Bool(false)),
+        DataType::True => Ok(PHPValue::Bool(true)),
+        DataType::Bool => Ok(PHPValue::Bool(zval.bool().unwrap_or_default())),
+        DataType::Long => Ok(PHPValue::Int(zval.long().unwrap_or_default())),
+        DataType::Double => Ok(PHPValue::Float(ordered_float::OrderedFloat(
+            zval.double().unwrap_or_default(),
+        ))),
+        DataType::String => zval
+            .string()
+            .map(|v| PHPValue::String(v))
+            .ok_or_else(|| unsupported("invalid string".into())),
+        DataType::Reference => match zval.reference() {
+            Some(v) => try_from_zval(v, path),
+            None => Err(unsupported("invalid reference".into())),
+        },
         DataType::Array => {
-            zval.array().map(|arr| {
-                if arr.has_sequential_keys() {
-                    // it's an array
-                    let val_arr: Vec<PHPValue> =
-                        arr.iter().map(|(_, v)| from_zval(v).unwrap()).collect();
-                    PHPValue::List(val_arr)
-                } else if arr.has_numerical_keys() {
-                    // it's a hashmap with numerical keys
-                    let mut h = HashMap::<PHPValue, PHPValue>::with_capacity(arr.len());
-                    arr.iter().for_each(|(i, v)| match i {
-                        ArrayKey::Long(index) => {
-                            h.insert(PHPValue::UInt(index as u64), from_zval(v).unwrap());
-                        }
-                        ArrayKey::String(_) => {}
-                    });
-                    PHPValue::HashMap(h)
-                } else {
-                    // it's a hashmap with string keys
-                    let mut h = HashMap::with_capacity(arr.len());
-                    arr.iter().for_each(|(k, v)| match k {
-                        ArrayKey::Long(_) => {}
-                        ArrayKey::String(index) => {
-                            h.insert(
-                                PHPValue::String(index),
-                                from_zval(v).expect("Invalid value in hashmap".into()),
-                            );
-                        }
-                    });
-                    PHPValue::HashMap(h)
+            let arr = zval
+                .array()
+                .ok_or_else(|| unsupported("invalid array".into()))?;
+
+            if arr.has_sequential_keys() {
+                // it's a list
+                let mut l = Vec::<PHPValue>::with_capacity(arr.len());
+                for (i, (_, v)) in arr.iter().enumerate() {
+                    l.push(try_from_zval(v, &format!("{path}[{i}]"))?);
                 }
-            })
+                return Ok(PHPValue::List(l));
+            }
+
+            // it's a map; integer and string keys can be mixed.
+            // keep the order of the array
+            let mut m = Vec::<(PHPValue, PHPValue)>::with_capacity(arr.len());
+            for (k, v) in arr.iter() {
+                let (key, path) = match k {
+                    ArrayKey::Long(index) => (PHPValue::Int(index), format!("{path}[{index}]")),
+                    ArrayKey::String(index) => {
+                        let path = format!("{path}[{index:?}]");
+                        (PHPValue::String(index), path)
+                    }
+                };
+                m.push((key, try_from_zval(v, &path)?));
+            }
+            Ok(PHPValue::OrderedMap(m))
         }
-        _ => unreachable!(),
+        t => Err(unsupported(format!("type `{t:?}`"))),
     }
 }
 
@@ -11489,6 +16096,18 @@ impl From<PHPValue> for proto::Value {
                     v: Some(proto::value::V::M(proto::Map { m: arr })),
                 }
             }
+            PHPValue::OrderedMap(m) => {
+                let arr = m
+                    .into_iter()
+                    .map(|(k, v)| proto::MapEntry {
+                        k: Some(k.into()),
+                        v: Some(v.into()),
+                    })
+                    .collect();
+                proto::Value {
+                    v: Some(proto::value::V::M(proto::Map { m: arr })),
+                }
+            }
             PHPValue::Json(h) => {
                 let mut arr = Vec::with_capacity(h.len());
                 h.iter().for_each(|(k, v)| {
@@ -11539,16 +16158,17 @@ impl From<proto::Value> for PHPValue {
                 PHPValue::Json(arr)
             }
             proto::value::V::M(h) => {
-                let mut arr = HashMap::<PHPValue, PHPValue>::with_capacity(h.m.len());
-                h.m.iter().for_each(|me| {
-                    if me.k.is_some() {
-                        arr.insert(
-                            (me.k.clone().unwrap()).into(),
-                            (me.v.clone().unwrap()).into(),
-                        );
-                    };
-                });
-                PHPValue::HashMap(arr)
+                // keep the order the entries were returned in by the server
+                let arr =
+                    h.m.into_iter()
+                        .filter_map(|me| match me {
+                            proto::MapEntry { k: Some(k), v } => {
+                                Some((k.into(), v.map(|v| v.into()).unwrap_or(PHPValue::Nil)))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                PHPValue::OrderedMap(arr)
             }
             proto::value::V::Geo(gj) => PHPValue::GeoJSON(gj.into()),
             proto::value::V::Hll(b) => PHPValue::HLL(b.to_vec()),
@@ -11558,6 +16178,193 @@ impl From<proto::Value> for PHPValue {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  Map
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Map is an ordered Aerospike map. It is returned instead of a PHP array for maps with keys
+/// that can not be used as PHP array keys, such as lists, blobs, floats or booleans, and can be
+/// used anywhere a map value is accepted so that these maps can be written back without loss.
+///
+/// Maps with list keys can be written, but not read back from the database: the Go client of
+/// the connection manager can not decode them.
+///
+/// Entries are looked up by scanning the map, so prefer PHP arrays for large maps with integer
+/// or string keys.
+#[php_class(name = "Aerospike\\Map")]
+#[implements(ext_php_rs::zend::ce::arrayaccess())]
+#[implements(ext_php_rs::zend::ce::aggregate())]
+#[implements(ext_php_rs::zend::ce::countable())]
+pub struct Map {
+    entries: Vec<(PHPValue, PHPValue)>,
+}
+
+impl FromZval<'_> for Map {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        let f: &Map = zval.extract()?;
+
+        Some(Map {
+            entries: f.entries.clone(),
+        })
+    }
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl Map {
+    /// Creates a map from the entries of a PHP array, keeping their order.
+    pub fn __construct(entries: Option<&Zval>) -> PhpResult<Self> {
+        let entries = match entries.map(|v| try_from_zval(v, "")).transpose()? {
+            None | Some(PHPValue::Nil) => vec![],
+            Some(PHPValue::OrderedMap(m)) => m,
+            Some(PHPValue::HashMap(m)) => m.into_iter().collect(),
+            Some(PHPValue::List(l)) => l
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| (PHPValue::Int(i as i64), v))
+                .collect(),
+            Some(v) => {
+                return Err(AerospikeException {
+                    message: format!("Invalid map entries: {v}"),
+                    code: ResultCode::PARAMETER_ERROR,
+                    in_doubt: false,
+                }
+                .into())
+            }
+        };
+        Ok(Map { entries: entries })
+    }
+
+    /// Returns true if the key exists in the map.
+    pub fn offset_exists(&self, key: &Zval) -> PhpResult<bool> {
+        let key = map_key(key)?;
+        Ok(self.entries.iter().any(|(k, _)| *k == key))
+    }
+
+    /// Returns the value for the key, or null if the key does not exist in the map.
+    pub fn offset_get(&self, key: &Zval) -> PhpResult<PHPValue> {
+        let key = map_key(key)?;
+        Ok(self
+            .entries
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.clone())
+            .unwrap_or(PHPValue::Nil))
+    }
+
+    /// Sets the value for the key. New keys are added to the end of the map.
+    pub fn offset_set(&mut self, key: &Zval, value: &Zval) -> PhpResult<()> {
+        let key = map_key(key)?;
+        let value = try_from_zval(value, &format!("[{key}]"))?;
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key, value)),
+        }
+        Ok(())
+    }
+
+    /// Removes the key from the map.
+    pub fn offset_unset(&mut self, key: &Zval) -> PhpResult<()> {
+        let key = map_key(key)?;
+        self.entries.retain(|(k, _)| *k != key);
+        Ok(())
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn count(&self) -> i64 {
+        self.entries.len() as i64
+    }
+
+    /// Returns the keys of the map in order.
+    pub fn keys(&self) -> Vec<PHPValue> {
+        self.entries.iter().map(|(k, _)| k.clone()).collect()
+    }
+
+    /// Returns the values of the map in order.
+    pub fn values(&self) -> Vec<PHPValue> {
+        self.entries.iter().map(|(_, v)| v.clone()).collect()
+    }
+
+    /// Returns an iterator over the entries of the map. Used by `foreach`, in which case the
+    /// keys can be of any type supported by Aerospike.
+    pub fn get_iterator(&self) -> MapIterator {
+        MapIterator {
+            entries: self.entries.clone(),
+            pos: 0,
+        }
+    }
+}
+
+/// Converts a PHP value into a map key. Maps can not be used as map keys.
+fn map_key(key: &Zval) -> AsResult<PHPValue> {
+    match try_from_zval(key, "")? {
+        PHPValue::Nil | PHPValue::HashMap(_) | PHPValue::OrderedMap(_) | PHPValue::Json(_) => {
+            Err(AerospikeException {
+                message: "Invalid map key: keys must not be null or maps".into(),
+                code: ResultCode::PARAMETER_ERROR,
+                in_doubt: false,
+            })
+        }
+        key => Ok(key),
+    }
+}
+
+/// Returns true if the value can be used as a PHP array key without loss.
+fn is_php_array_key(key: &PHPValue) -> bool {
+    match key {
+        PHPValue::Int(_) | PHPValue::String(_) => true,
+        PHPValue::UInt(ui) => *ui <= i64::MAX as u64,
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////
+//
+//  MapIterator
+//
+////////////////////////////////////////////////////////////////////////////////////////////
+
+/// MapIterator implements the PHP `Iterator` interface over a snapshot of a `Map`.
+#[php_class(name = "Aerospike\\MapIterator")]
+#[implements(ext_php_rs::zend::ce::iterator())]
+pub struct MapIterator {
+    entries: Vec<(PHPValue, PHPValue)>,
+    pos: usize,
+}
+
+#[php_impl]
+#[derive(ZvalConvert)]
+impl MapIterator {
+    /// Returns the current value.
+    pub fn current(&self) -> Option<PHPValue> {
+        self.entries.get(self.pos).map(|(_, v)| v.clone())
+    }
+
+    /// Returns the current key.
+    pub fn key(&self) -> Option<PHPValue> {
+        self.entries.get(self.pos).map(|(k, _)| k.clone())
+    }
+
+    /// Moves forward to the next entry.
+    pub fn next(&mut self) {
+        self.pos += 1;
+    }
+
+    /// Returns true if the iterator is positioned on an entry.
+    pub fn valid(&self) -> bool {
+        self.pos < self.entries.len()
+    }
+
+    /// Moves back to the first entry.
+    pub fn rewind(&mut self) {
+        self.pos = 0;
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////
 //
 //  Value
@@ -11602,6 +16409,9 @@ impl Value {
     pub fn map(val: &Zval) -> PHPValue {
         match from_zval(val) {
             Some(PHPValue::HashMap(hm)) => PHPValue::HashMap(hm),
+            Some(PHPValue::OrderedMap(m)) => PHPValue::OrderedMap(m),
+            // the conversion error was already thrown
+            None => PHPValue::Nil,
             _ => {
                 let error = AerospikeException::new("Invalid value".into());
                 let _ = throw_object(error.into_zval(true).unwrap());
@@ -11727,6 +16537,10 @@ struct ResultCode {}
 #[php_impl]
 #[derive(ZvalConvert)]
 impl ResultCode {
+    /// CONNECTION_MANAGER_TIMEOUT means the connection manager did not respond before the
+    /// deadline of the call.
+    const CONNECTION_MANAGER_TIMEOUT: i32 = -22;
+
     /// GRPC_ERROR is wrapped and directly returned from the grpc library
     const GRPC_ERROR: i32 = -21;
 
@@ -12008,6 +16822,7 @@ impl ResultCode {
 
     pub fn to_string(code: i32) -> String {
         match code {
+             ResultCode::CONNECTION_MANAGER_TIMEOUT => "the connection manager did not respond before the deadline of the call".into(),
              ResultCode::GRPC_ERROR => "wrapped and directly returned from the grpc library".into(),
              ResultCode::BATCH_FAILED => "one or more keys failed in a batch".into(),
              ResultCode::NO_RESPONSE => "no response was received from the server".into(),
@@ -12115,7 +16930,7 @@ impl ResultCode {
 /// Utility methods
 fn assert_map(val: &PHPValue) -> bool {
     match val {
-        PHPValue::HashMap(_) => true,
+        PHPValue::HashMap(_) | PHPValue::OrderedMap(_) => true,
         _ => {
             let error = AerospikeException::new("Invalid type");
             throw_object(error.into_zval(true).unwrap()).unwrap();
@@ -12138,19 +16953,23 @@ fn assert_hll_list(val: &Vec<PHPValue>) -> bool {
         .is_none()
 }
 
-fn persist_client(key: &str, c: Arc<Mutex<grpc::BlockingClient>>) -> Result<()> {
+fn persist_client(
+    socket: &str,
+    deadlines: grpc::Deadlines,
+    c: Arc<Mutex<grpc::BlockingClient>>,
+) -> Result<()> {
     trace!("Persisting Client pointer: {:p}", &c);
     let mut clients = CLIENTS.lock().unwrap();
-    clients.insert(key.into(), c);
+    clients.insert((socket.into(), deadlines), c);
     Ok(())
 }
 
-fn get_persisted_client(key: &str) -> Option<Zval> {
+fn get_persisted_client(socket: &str, deadlines: grpc::Deadlines) -> Option<Zval> {
     let clients = CLIENTS.lock().unwrap();
-    let grpc_client = clients.get(key.into())?;
+    let grpc_client = clients.get(&(socket.to_string(), deadlines))?;
     let client = Client {
         client: grpc_client.clone(),
-        socket: key.into(),
+        socket: socket.into(),
     };
 
     let mut zval = Zval::new();
@@ -102,6 +102,58 @@ final class BatchOpsTest extends TestCase
         self::$client->batch($bp, [$batchOp]);
     }
 
+    public function testInvalidBatchCmdIsNotSent(){
+        $wp = new WritePolicy();
+        $key = new Key(self::$namespace, self::$set, "batch_invalid_key");
+        self::$client->delete($wp, $key);
+
+        $batchWrite = new BatchWrite(new BatchWritePolicy(), $key, [Operation::put(new Bin("bin", 1))]);
+        try {
+            self::$client->batch(new BatchPolicy(), [$batchWrite, "not a command"]);
+            $this->fail("expected an exception");
+        } catch (AerospikeException $e) {
+            $this->assertEquals(ResultCode::PARAMETER_ERROR, $e->code);
+        }
+        $this->assertFalse(self::$client->exists(new ReadPolicy(), $key));
+    }
+
+    public function testBatchExecuteResults(){
+        $wp = new WritePolicy();
+        $okKey = new Key(self::$namespace, self::$set, "batch_results_ok");
+        $badKey = new Key(self::$namespace, self::$set, "batch_results_bad");
+        $missingKey = new Key(self::$namespace, self::$set, "batch_results_missing");
+        self::$client->put($wp, $okKey, [new Bin("count", 1)]);
+        self::$client->put($wp, $badKey, [new Bin("count", "not a number")]);
+        self::$client->delete($wp, $missingKey);
+
+        $bwp = new BatchWritePolicy();
+        $cmds = [
+            new BatchWrite($bwp, $okKey, [Operation::add(new Bin("count", 1))]),
+            new BatchWrite($bwp, $badKey, [Operation::add(new Bin("count", 1))]),
+            new BatchRead(new BatchReadPolicy(), $missingKey, []),
+        ];
+
+        $bp = new BatchPolicy();
+        $results = self::$client->batchExecute($bp, $cmds);
+        $this->assertCount(3, $results->records);
+        $this->assertEquals(2, $results->success_count);
+        $this->assertEquals(1, $results->failure_count);
+        $failures = $results->failures();
+        $this->assertCount(1, $failures);
+        $this->assertEquals("batch_results_bad", $failures[0]->key->value);
+        $this->assertEquals(ResultCode::BIN_TYPE_ERROR, $failures[0]->result_code);
+        $this->assertFalse($failures[0]->in_doubt);
+
+        try {
+            self::$client->batchExecute($bp, $cmds, true);
+            $this->fail("expected a BatchException");
+        } catch (BatchException $e) {
+            $this->assertEquals(ResultCode::BATCH_FAILED, $e->code);
+            $this->assertCount(3, $e->results->records);
+            $this->assertEquals(1, $e->results->failure_count);
+        }
+    }
+
     public function testBatchReadWrite(){
         $brp = new BatchReadPolicy();
         $bwp = new BatchWritePolicy();
@@ -115,6 +167,131 @@ final class BatchOpsTest extends TestCase
         $this->assertIsArray($batchRecords);
     }
 
+    public function testBatchReadWithOps(){
+        $wp = new WritePolicy();
+        $keys = [];
+        for ($i = 0; $i < 3; $i++) {
+            $key = new Key(self::$namespace, self::$set, "batch_ops_key_" . $i);
+            self::$client->put($wp, $key, [new Bin("scores", [5 + $i, 1, 9, 3]), new Bin("name", "player" . $i)]);
+            $keys[] = $key;
+        }
+
+        $brp = new BatchReadPolicy();
+        $cmds = [];
+        foreach ($keys as $key) {
+            $cmds[] = BatchRead::withOps($brp, $key, [
+                ListOp::getByRankRange("scores", -2, ListReturnType::Value()),
+                ListOp::size("scores"),
+                Operation::get("name"),
+            ]);
+        }
+
+        $bp = new BatchPolicy();
+        $batchRecords = self::$client->batch($bp, $cmds);
+        $this->assertCount(3, $batchRecords);
+        foreach ($batchRecords as $i => $br) {
+            $results = $br->results;
+            $this->assertCount(3, $results);
+            $this->assertEquals([5 + $i, 9], $results[0]);
+            $this->assertEquals(4, $results[1]);
+            $this->assertEquals("player" . $i, $results[2]);
+        }
+    }
+
+    public function testBatchWriteOpResults(){
+        $bwp = new BatchWritePolicy();
+        $batchKey = new Key(self::$namespace, self::$set, "batch_op_results_key");
+        $wp = new WritePolicy();
+        self::$client->put($wp, $batchKey, [new Bin("counter", 1)]);
+
+        $ops = [
+            Operation::add(new Bin("counter", 2)),
+            Operation::get("counter"),
+            Operation::touch(),
+        ];
+        $batchWrite = new BatchWrite($bwp, $batchKey, $ops);
+
+        $bp = new BatchPolicy();
+        $batchRecords = self::$client->batch($bp, [$batchWrite]);
+        $results = $batchRecords[0]->results;
+        $this->assertCount(3, $results);
+        $this->assertNull($results[0]);
+        $this->assertEquals(3, $results[1]);
+        $this->assertNull($results[2]);
+    }
+
+    public function testBatchGet(){
+        $wp = new WritePolicy();
+        $keys = [];
+        for ($i = 0; $i < 3; $i++) {
+            $key = new Key(self::$namespace, self::$set, "batch_get_key_" . $i);
+            self::$client->put($wp, $key, [new Bin("a", $i), new Bin("b", "b" . $i)]);
+            $keys[] = $key;
+        }
+        $missing = new Key(self::$namespace, self::$set, "batch_get_missing_key");
+        self::$client->delete($wp, $missing);
+        array_splice($keys, 1, 0, [$missing]);
+
+        $bp = new BatchPolicy();
+        $records = self::$client->batchGet($bp, $keys);
+        $this->assertCount(4, $records);
+        $this->assertEquals(["a" => 0, "b" => "b0"], $records[0]->bins);
+        $this->assertNull($records[1]);
+        $this->assertEquals(["a" => 1, "b" => "b1"], $records[2]->bins);
+        $this->assertEquals(["a" => 2, "b" => "b2"], $records[3]->bins);
+
+        $records = self::$client->batchGet($bp, $keys, ["b"]);
+        $this->assertEquals(["b" => "b2"], $records[3]->bins);
+
+        $headers = self::$client->batchGetHeader($bp, $keys);
+        $this->assertNull($headers[1]);
+        $this->assertGreaterThan(0, $headers[0]->generation);
+
+        $this->assertEquals([true, false, true, true], self::$client->batchExists($bp, $keys));
+
+        // a key which fails does not hide the results of the other keys
+        $keys[] = new Key("batch_invalid_namespace", self::$set, "batch_get_key_0");
+        try {
+            self::$client->batchExists($bp, $keys);
+            $this->fail("expected a BatchException");
+        } catch (BatchException $e) {
+            $this->assertEquals(ResultCode::BATCH_FAILED, $e->code);
+            $this->assertCount(5, $e->results->records);
+            $this->assertEquals(1, $e->results->failure_count);
+            $this->assertEquals(ResultCode::OK, $e->results->records[0]->result_code);
+            $this->assertEquals(ResultCode::KEY_NOT_FOUND_ERROR, $e->results->records[1]->result_code);
+            $this->assertNotEquals(ResultCode::OK, $e->results->records[4]->result_code);
+        }
+    }
+
+    public function testBatchDeleteAndOperate(){
+        $wp = new WritePolicy();
+        $keys = [];
+        for ($i = 0; $i < 2; $i++) {
+            $key = new Key(self::$namespace, self::$set, "batch_operate_key_" . $i);
+            self::$client->put($wp, $key, [new Bin("count", $i)]);
+            $keys[] = $key;
+        }
+
+        $bp = new BatchPolicy();
+        $records = self::$client->batchOperate($bp, $keys, [
+            Operation::add(new Bin("count", 10)),
+            Operation::get("count"),
+        ]);
+        $this->assertCount(2, $records);
+        $this->assertEquals(0, $records[0]->result_code);
+        $this->assertEquals(10, $records[0]->results[1]);
+        $this->assertEquals(11, $records[1]->results[1]);
+
+        $records = self::$client->batchDelete($bp, $keys);
+        $this->assertCount(2, $records);
+        $this->assertEquals(0, $records[0]->result_code);
+        $this->assertEquals([false, false], self::$client->batchExists($bp, $keys));
+
+        $records = self::$client->batchDelete($bp, $keys);
+        $this->assertEquals(ResultCode::KEY_NOT_FOUND_ERROR, $records[0]->result_code);
+    }
+
     public function testBatchWriteMultipleOpsAppend(){
         $bwp = new BatchWritePolicy();
         $batchKey = new Key(self::$namespace, self::$set, "batch_key");
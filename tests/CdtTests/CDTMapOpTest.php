@@ -77,4 +77,36 @@ class CDTMapOpTest extends TestCase{
         $this->assertEquals($recs[0]->record->bins[self::$cdtBinName][1], "v1.1");
     }
 
-}
\ No newline at end of file
+    public function testShouldKeepKeyOrderOfOrderedCDTMap(){
+        $wp = new WritePolicy();
+        $mp = new MapPolicy(MapOrderType::KeyOrdered());
+        $map = ["c" => 3, "a" => 1, "e" => 5, "b" => 2, "d" => 4];
+
+        self::$client->operate($wp, self::$key, [MapOp::put($mp, self::$cdtBinName, $map)]);
+
+        $record = self::$client->get(new ReadPolicy(), self::$key);
+        $this->assertSame(["a", "b", "c", "d", "e"], array_keys($record->bins[self::$cdtBinName]));
+        $this->assertSame(["a" => 1, "b" => 2, "c" => 3, "d" => 4, "e" => 5], $record->bins[self::$cdtBinName]);
+    }
+
+    public function testShouldKeepOrderOfUnorderedMap(){
+        $wp = new WritePolicy();
+        $map = ["c" => 3, "a" => 1, "e" => 5, "b" => 2, "d" => 4];
+
+        self::$client->put($wp, self::$key, [new Bin(self::$cdtBinName, $map)]);
+
+        $record = self::$client->get(new ReadPolicy(), self::$key);
+        $this->assertSame(["c", "a", "e", "b", "d"], array_keys($record->bins[self::$cdtBinName]));
+        $this->assertSame($map, $record->bins[self::$cdtBinName]);
+    }
+
+    public function testShouldKeepIntegerMapKeys(){
+        $wp = new WritePolicy();
+        $mp = new MapPolicy(MapOrderType::KeyOrdered());
+
+        self::$client->operate($wp, self::$key, [MapOp::put($mp, self::$cdtBinName, [30 => "c", 10 => "a", 20 => "b"])]);
+
+        $record = self::$client->get(new ReadPolicy(), self::$key);
+        $this->assertSame([10 => "a", 20 => "b", 30 => "c"], $record->bins[self::$cdtBinName]);
+    }
+}
@@ -304,6 +304,175 @@ final class ClientTest extends TestCase
         $this->assertEquals($record->getTtl(), 1);
     }
 
+    public function testReadWithReplicaPolicy()
+    {
+        $key = new Key(self::$namespace, self::$set, "replica_key");
+        self::$client->put(new WritePolicy(), $key, [new Bin("bin", "value")]);
+
+        $replicas = [
+            ReplicaPolicy::Sequence(),
+            ReplicaPolicy::Master(),
+            ReplicaPolicy::MasterProles(),
+            ReplicaPolicy::PreferRack(),
+            ReplicaPolicy::Random(),
+        ];
+        foreach ($replicas as $replica) {
+            $rp = new ReadPolicy();
+            $rp->setReplica($replica);
+            $record = self::$client->get($rp, $key);
+            $this->assertEquals("value", $record->bins["bin"]);
+
+            $bp = new BatchPolicy();
+            $bp->setReplica($replica);
+            $records = self::$client->batch($bp, [new BatchRead(new BatchReadPolicy(), $key, null)]);
+            $this->assertEquals("value", $records[0]->record->bins["bin"]);
+        }
+
+        self::$client->delete(new WritePolicy(), $key);
+    }
+
+    public function testTimeoutMargin()
+    {
+        $this->assertEquals(1000, self::$client->getTimeoutMargin());
+        $this->assertEquals(30000, self::$client->getDefaultTimeout());
+
+        $client = Client::connect(self::$socket, 200, 5000);
+        $this->assertEquals(200, $client->getTimeoutMargin());
+        $this->assertEquals(5000, $client->getDefaultTimeout());
+
+        // clients with other deadlines are not affected
+        $this->assertEquals(1000, self::$client->getTimeoutMargin());
+
+        $key = new Key(self::$namespace, self::$set, "timeout_margin_key");
+        $client->put(new WritePolicy(), $key, [new Bin("bin", "value")]);
+
+        // the deadline is sent with the request; a command which completes in time is not affected
+        $rp = new ReadPolicy();
+        $rp->setTotalTimeout(500);
+        $record = $client->get($rp, $key);
+        $this->assertEquals("value", $record->bins["bin"]);
+
+        // a zero total timeout uses the default timeout
+        $rp->setTotalTimeout(0);
+        $record = $client->get($rp, $key);
+        $this->assertEquals("value", $record->bins["bin"]);
+
+        $client->delete(new WritePolicy(), $key);
+    }
+
+    public function testTimeoutMarginExpired()
+    {
+        // a socket which accepts connections but never answers, like a hung connection manager
+        $path = sys_get_temp_dir() . "/asld_silent_" . getmypid() . ".sock";
+        @unlink($path);
+        $server = stream_socket_server("unix://$path", $errno, $errstr);
+        $this->assertNotFalse($server, $errstr);
+
+        try {
+            // the version check on connecting has no timeout of its own, so its deadline is the
+            // default timeout plus the margin
+            $start = microtime(true);
+            Client::connect($path, 200, 100);
+            $this->fail("Connecting to a connection manager which does not answer was expected to time out");
+        } catch (AerospikeException $e) {
+            $this->assertSame(ResultCode::CONNECTION_MANAGER_TIMEOUT, $e->code);
+            $this->assertFalse($e->in_doubt);
+            $this->assertGreaterThanOrEqual(0.3, microtime(true) - $start);
+        } finally {
+            fclose($server);
+            @unlink($path);
+        }
+    }
+
+    public function testTxnProperties()
+    {
+        $txn = new Txn(15);
+        $this->assertNotEquals(0, $txn->id);
+        $this->assertEquals(15, $txn->timeout);
+        $this->assertTrue($txn->state->isOpen());
+        $this->assertNotEquals($txn->id, (new Txn(null))->id);
+
+        $policies = [new ReadPolicy(), new WritePolicy(), new BatchPolicy(), new QueryPolicy(), new ScanPolicy()];
+        foreach ($policies as $policy) {
+            $this->assertNull($policy->txn);
+            $policy->setTxn($txn);
+            $this->assertEquals($txn->id, $policy->txn->id);
+            $this->assertEquals(15, $policy->txn->timeout);
+            $policy->setTxn(null);
+            $this->assertNull($policy->txn);
+        }
+    }
+
+    public function testCommitAndAbortUnknownTxn()
+    {
+        // transactions without any commands are not known to the connection manager
+        foreach (["commit", "abort"] as $method) {
+            $txn = new Txn(null);
+            try {
+                self::$client->$method($txn);
+                $this->fail("expected an AerospikeException");
+            } catch (AerospikeException $e) {
+                $this->assertEquals(ResultCode::PARAMETER_ERROR, $e->code);
+            }
+            $this->assertTrue($txn->state->isOpen());
+        }
+    }
+
+    public function testCommitAndAbortTxnWrites()
+    {
+        // transactions need a namespace configured with strong consistency
+        $namespace = getenv("ASLD_SC_NAMESPACE");
+        if ($namespace === false) {
+            $this->markTestSkipped("ASLD_SC_NAMESPACE is not set");
+        }
+
+        $keys = [];
+        for ($i = 0; $i < 5; $i++) {
+            $keys[] = new Key($namespace, self::$set, "txn_" . $i);
+        }
+        foreach ($keys as $key) {
+            self::$client->delete(new WritePolicy(), $key);
+        }
+
+        $txn = new Txn(null);
+        $wp = new WritePolicy();
+        $wp->setTxn($txn);
+        foreach ($keys as $i => $key) {
+            self::$client->put($wp, $key, [new Bin("bin", $i)]);
+        }
+        $status = self::$client->commit($txn);
+        $this->assertTrue($status->isOk());
+        $this->assertTrue($txn->state->isCommitted());
+
+        $rp = new ReadPolicy();
+        foreach ($keys as $i => $key) {
+            $this->assertEquals($i, self::$client->get($rp, $key, ["bin"])->getBins()["bin"]);
+        }
+
+        // a committed transaction is forgotten by the connection manager
+        try {
+            self::$client->commit($txn);
+            $this->fail("expected an AerospikeException");
+        } catch (AerospikeException $e) {
+            $this->assertEquals(ResultCode::PARAMETER_ERROR, $e->code);
+        }
+        $this->assertTrue($txn->state->isCommitted());
+
+        $txn = new Txn(null);
+        $wp->setTxn($txn);
+        foreach ($keys as $i => $key) {
+            self::$client->put($wp, $key, [new Bin("bin", $i + 100)]);
+        }
+        $status = self::$client->abort($txn);
+        $this->assertTrue($status->isOk());
+        $this->assertTrue($txn->state->isAborted());
+
+        foreach ($keys as $i => $key) {
+            $this->assertEquals($i, self::$client->get($rp, $key, ["bin"])->getBins()["bin"]);
+            self::$client->delete(new WritePolicy(), $key);
+        }
+    }
+
     public function testPutGetBinary()
     {
         $binary = Value::blob("\x41\x42\xFF\x43\x00\x7F\x80\xE2\x98\x85");
@@ -319,6 +488,82 @@ final class ClientTest extends TestCase
         $this->assertEquals($binary, $binGet["binaryBin"]);
     }
 
+    public function testPutGetMixedKeyArray()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "mixed_keys");
+        $value = [0 => "a", "x" => "b", 5 => ["y" => 1, 2 => 2]];
+        self::$client->put(new WritePolicy(), $newKey, [new Bin("mixed", $value)]);
+
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertEquals($value, $record->bins["mixed"]);
+
+        self::$client->delete(new WritePolicy(), $newKey);
+    }
+
+    public function testPutGetMapWithNonArrayKeys()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "map_object");
+
+        $map = new Map(["a" => 1]);
+        $map[1.5] = "float";
+        $map[Value::blob("k")] = "blob";
+        $map[true] = "bool";
+        $this->assertCount(4, $map);
+
+        self::$client->put(new WritePolicy(), $newKey, [new Bin("map", $map)]);
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+
+        $res = $record->bins["map"];
+        $this->assertInstanceOf(Map::class, $res);
+        $this->assertCount(4, $res);
+        $this->assertEquals(1, $res["a"]);
+        $this->assertEquals("float", $res[1.5]);
+        $this->assertEquals("blob", $res[Value::blob("k")]);
+        $this->assertEquals("bool", $res[true]);
+        $this->assertTrue(isset($res[Value::blob("k")]));
+        $this->assertFalse(isset($res["missing"]));
+
+        $keys = [];
+        foreach ($res as $k => $v) {
+            $keys[] = $k;
+        }
+        $this->assertCount(4, $keys);
+
+        // write it back unchanged
+        self::$client->put(new WritePolicy(), $newKey, [new Bin("map", $res)]);
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertEquals("blob", $record->bins["map"][Value::blob("k")]);
+
+        self::$client->delete(new WritePolicy(), $newKey);
+    }
+
+    public function testMapWithListKeys()
+    {
+        // maps with list keys can be written, but not read back from the database
+        $map = new Map();
+        $map[[1, 2]] = "list";
+        $this->assertEquals("list", $map[[1, 2]]);
+        $this->assertTrue(isset($map[[1, 2]]));
+        $this->assertFalse(isset($map[[2, 1]]));
+
+        $newKey = new Key(self::$namespace, self::$set, "map_list_keys");
+        self::$client->put(new WritePolicy(), $newKey, [new Bin("map", $map)]);
+        $this->assertTrue(self::$client->exists(new ReadPolicy(), $newKey));
+        self::$client->delete(new WritePolicy(), $newKey);
+    }
+
+    public function testUnsupportedNestedValue()
+    {
+        try {
+            new Bin("bin", [1, ["name" => [new \stdClass()]]]);
+            $this->fail("expected an AerospikeException");
+        } catch (AerospikeException $e) {
+            $this->assertEquals(ResultCode::PARAMETER_ERROR, $e->code);
+            $this->assertStringContainsString('[1]["name"][0]', $e->message);
+            $this->assertStringContainsString("stdClass", $e->message);
+        }
+    }
+
     public function testReadTtlExpires()
     {
         $stringKey = new Key(self::$namespace, self::$set, "new_key");
@@ -369,4 +614,189 @@ final class ClientTest extends TestCase
         $this->assertEquals($record->getTtl(), null);
         $this->assertTrue($record->getExpiration()->willNeverExpire());
     }
+
+    public function testOperate()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "operate_key");
+        $wp = new WritePolicy();
+        self::$client->delete($wp, $newKey);
+
+        $lp = new ListPolicy(ListOrderType::Unordered());
+        $ops = [
+            Operation::put(new Bin("ibin", 1)),
+            Operation::add(new Bin("ibin", 2)),
+            ListOp::append($lp, "lbin", [1, 2, 3]),
+            ListOp::size("lbin"),
+            Operation::get("ibin"),
+        ];
+        $record = self::$client->operate($wp, $newKey, $ops);
+        $this->assertEquals($record->bins["ibin"], 3);
+        $this->assertEquals($record->bins["lbin"], 3);
+
+        self::$client->delete($wp, $newKey);
+    }
+
+    public function testOperateRespondPerEachOp()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "operate_key_each_op");
+        $wp = new WritePolicy();
+        self::$client->put($wp, $newKey, [new Bin("ibin", 1)]);
+
+        $wp->setRespondPerEachOp(true);
+        $ops = [
+            Operation::add(new Bin("ibin", 2)),
+            Operation::get("ibin"),
+        ];
+        $record = self::$client->operate($wp, $newKey, $ops);
+        $this->assertEquals($record->bins["ibin"], [null, 3]);
+
+        self::$client->delete(new WritePolicy(), $newKey);
+    }
+
+    public function testOperateExpOperations()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "operate_exp_key");
+        $wp = new WritePolicy();
+        self::$client->put($wp, $newKey, [new Bin("price", 5), new Bin("qty", 3)]);
+
+        $total = Expression::numMul([Expression::intBin("price"), Expression::intBin("qty")]);
+        $ops = [
+            ExpOperation::write("total", $total, [ExpWriteFlags::Default()]),
+            ExpOperation::read("doubled", Expression::numMul([$total, Expression::intVal(2)])),
+        ];
+        $record = self::$client->operate($wp, $newKey, $ops);
+        $this->assertEquals($record->bins["doubled"], 30);
+
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertEquals($record->bins["total"], 15);
+
+        // the same operations in a batch
+        self::$client->put($wp, $newKey, [new Bin("price", 7), new Bin("qty", 2)]);
+        $records = self::$client->batch(new BatchPolicy(), [new BatchWrite(new BatchWritePolicy(), $newKey, $ops)]);
+        $this->assertEquals(ResultCode::OK, $records[0]->result_code);
+        $this->assertEquals(28, $records[0]->results[1]);
+
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertEquals($record->bins["total"], 14);
+
+        // and on every record of a set in the background
+        $set = "operate_exp_set";
+        $keys = [];
+        for ($i = 0; $i < 5; $i++) {
+            $keys[] = new Key(self::$namespace, $set, $i);
+            self::$client->put($wp, $keys[$i], [new Bin("price", $i), new Bin("qty", 3)]);
+        }
+
+        $task = self::$client->queryExecute(new BackgroundExecutePolicy(), new Statement(self::$namespace, $set), [
+            ExpOperation::write("total", $total, [ExpWriteFlags::Default()]),
+        ]);
+        $task->waitUntilComplete(10000);
+        $this->assertTrue($task->isDone());
+
+        foreach ($keys as $i => $key) {
+            $record = self::$client->get(new ReadPolicy(), $key);
+            $this->assertEquals($record->bins["total"], $i * 3);
+            self::$client->delete($wp, $key);
+        }
+
+        self::$client->delete($wp, $newKey);
+    }
+
+    public function testOperateExpWriteFlags()
+    {
+        $newKey = new Key(self::$namespace, self::$set, "operate_exp_flags_key");
+        $wp = new WritePolicy();
+        self::$client->put($wp, $newKey, [new Bin("status", 0), new Bin("tmp", "x")]);
+
+        // sets expired only when status is 1, else leaves the record untouched
+        $expire = ExpOperation::write(
+            "expired",
+            Expression::cond([
+                Expression::eq(Expression::intBin("status"), Expression::intVal(1)),
+                Expression::boolVal(true),
+                Expression::unknown(),
+            ]),
+            [ExpWriteFlags::EvalNoFail()]
+        );
+        self::$client->operate($wp, $newKey, [$expire]);
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertArrayNotHasKey("expired", $record->bins);
+
+        self::$client->put($wp, $newKey, [new Bin("status", 1)]);
+        self::$client->operate($wp, $newKey, [$expire]);
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertTrue($record->bins["expired"]);
+
+        // the bin already exists, so the write is denied but does not fail
+        self::$client->operate($wp, $newKey, [
+            ExpOperation::write("status", Expression::intVal(2), [ExpWriteFlags::CreateOnly(), ExpWriteFlags::PolicyNoFail()]),
+            ExpOperation::write("tmp", Expression::removeResult(), [ExpWriteFlags::AllowDelete()]),
+        ]);
+        $record = self::$client->get(new ReadPolicy(), $newKey);
+        $this->assertEquals($record->bins["status"], 1);
+        $this->assertArrayNotHasKey("tmp", $record->bins);
+
+        self::$client->delete($wp, $newKey);
+    }
+
+    public function testInfo()
+    {
+        $ip = new InfoPolicy();
+        $res = self::$client->info($ip, ["build", "namespaces"]);
+        $this->assertArrayHasKey("build", $res);
+        $this->assertNotEmpty($res["build"]);
+        $this->assertContains(self::$namespace, explode(";", $res["namespaces"]));
+    }
+
+    public function testInfoOnNode()
+    {
+        $nodes = self::$client->nodes();
+        $this->assertNotEmpty($nodes);
+
+        $node = $nodes[0];
+        $this->assertNotEmpty($node->name);
+        $this->assertNotEmpty($node->address);
+        $this->assertTrue($node->active);
+
+        $res = self::$client->info(new InfoPolicy(), ["node"], $node->name);
+        $this->assertEquals($node->name, $res["node"]);
+    }
+
+    public function testNamespaces()
+    {
+        $namespaces = self::$client->namespaces(new InfoPolicy());
+
+        $found = null;
+        foreach ($namespaces as $ns) {
+            if ($ns->name == self::$namespace) {
+                $found = $ns;
+            }
+        }
+
+        $this->assertNotNull($found);
+        $this->assertGreaterThan(0, $found->replication_factor);
+        $this->assertNotEmpty($found->storage_engine);
+        $this->assertFalse($found->stop_writes);
+    }
+
+    public function testSets()
+    {
+        $key = new Key(self::$namespace, self::$set, "sets_key");
+        self::$client->put(new WritePolicy(), $key, [new Bin("bin1", 1)]);
+
+        $sets = self::$client->sets(new InfoPolicy(), self::$namespace);
+
+        $found = null;
+        foreach ($sets as $set) {
+            if ($set->name == self::$set) {
+                $found = $set;
+            }
+        }
+
+        $this->assertNotNull($found);
+        $this->assertEquals(self::$namespace, $found->namespace);
+        $this->assertGreaterThan(0, $found->objects);
+
+        self::$client->delete(new WritePolicy(), $key);
+    }
 }
@@ -207,4 +207,264 @@ final class FilterExpTest extends TestCase
         $this->assertEquals(3, count($recs->bins));
     }
 
-}
\ No newline at end of file
+    public function testListExp()
+    {
+        $key = new Key(self::$namespace, self::$set, "list_exp");
+        self::$client->put(new WritePolicy(), $key, [new Bin("events", ["login", "view", "buy"]), new Bin("scores", [[1, 2], [30, 40, 50]])]);
+        $bin = Expression::listBin("events");
+
+        $this->assertFilter($key, Expression::eq(ListExp::size($bin), Expression::intVal(3)), true);
+        $this->assertFilter($key, Expression::eq(
+            ListExp::getByIndex(ListReturnType::Value(), ExpType::String(), Expression::intVal(-1), $bin),
+            Expression::stringVal("buy")
+        ), true);
+        $this->assertFilter($key, ListExp::getByValue(ListReturnType::Exists(), Expression::stringVal("view"), $bin), true);
+        $this->assertFilter($key, ListExp::getByValue(ListReturnType::Exists(), Expression::stringVal("logout"), $bin), false);
+        $this->assertFilter($key, Expression::eq(
+            ListExp::getByValueRange(ListReturnType::Count(), Expression::stringVal("c"), null, $bin),
+            Expression::intVal(2)
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            ListExp::getByRank(ListReturnType::Value(), ExpType::String(), Expression::intVal(0), $bin),
+            Expression::stringVal("buy")
+        ), true);
+
+        // nested list
+        $this->assertFilter($key, Expression::eq(
+            ListExp::size(Expression::listBin("scores"), [Context::listIndex(1)]),
+            Expression::intVal(3)
+        ), true);
+    }
+
+    public function testMapExp()
+    {
+        $key = new Key(self::$namespace, self::$set, "map_exp");
+        self::$client->put(new WritePolicy(), $key, [new Bin("attrs", ["status" => "active", "visits" => 12, "tags" => ["a" => 1, "b" => 2]])]);
+        $bin = Expression::mapBin("attrs");
+
+        $this->assertFilter($key, Expression::eq(
+            MapExp::getByKey(MapReturnType::Value(), ExpType::String(), Expression::stringVal("status"), $bin),
+            Expression::stringVal("active")
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            MapExp::getByKey(MapReturnType::Value(), ExpType::String(), Expression::stringVal("status"), $bin),
+            Expression::stringVal("disabled")
+        ), false);
+        $this->assertFilter($key, Expression::gt(MapExp::size($bin), Expression::intVal(10)), false);
+        $this->assertFilter($key, Expression::eq(MapExp::size($bin), Expression::intVal(3)), true);
+        $this->assertFilter($key, MapExp::getByValue(MapReturnType::Exists(), Expression::intVal(12), $bin), true);
+        $this->assertFilter($key, Expression::eq(
+            MapExp::getByKeyRange(MapReturnType::Count(), Expression::stringVal("t"), null, $bin),
+            Expression::intVal(2)
+        ), true);
+
+        // nested map
+        $this->assertFilter($key, Expression::eq(
+            MapExp::getByKey(MapReturnType::Value(), ExpType::Int(), Expression::stringVal("b"), $bin, [Context::mapKey("tags")]),
+            Expression::intVal(2)
+        ), true);
+    }
+
+    public function testHllExp()
+    {
+        $key = new Key(self::$namespace, self::$set, "hll_exp");
+        self::$client->delete(new WritePolicy(), $key);
+        $visitors = [];
+        for ($i = 0; $i < 100; $i++) {
+            $visitors[] = "visitor" . $i;
+        }
+        self::$client->operate(new WritePolicy(), $key, [
+            HllOp::add(new HllPolicy(HllWriteFlags::Default()), "visitors", $visitors, 10, 0),
+        ]);
+        $bin = Expression::hllBin("visitors");
+
+        $this->assertFilter($key, Expression::gt(HllExp::getCount($bin), Expression::intVal(90)), true);
+        $this->assertFilter($key, Expression::gt(HllExp::getCount($bin), Expression::intVal(1000)), false);
+        $this->assertFilter($key, Expression::eq(
+            ListExp::getByIndex(ListReturnType::Value(), ExpType::Int(), Expression::intVal(0), HllExp::describe($bin)),
+            Expression::intVal(10)
+        ), true);
+    }
+
+    public function testBitExp()
+    {
+        $key = new Key(self::$namespace, self::$set, "bit_exp");
+        self::$client->put(new WritePolicy(), $key, [new Bin("flags", Value::blob([0b10000001, 0b00000010]))]);
+        $bin = Expression::blobBin("flags");
+
+        $this->assertFilter($key, Expression::eq(
+            BitExp::count(Expression::intVal(0), Expression::intVal(16), $bin),
+            Expression::intVal(3)
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            BitExp::get(Expression::intVal(0), Expression::intVal(8), $bin),
+            Expression::blobVal([0b10000001])
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            BitExp::lscan(Expression::intVal(8), Expression::intVal(8), Expression::boolVal(true), $bin),
+            Expression::intVal(6)
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            BitExp::getInt(Expression::intVal(0), Expression::intVal(8), false, $bin),
+            Expression::intVal(129)
+        ), true);
+        $this->assertFilter($key, Expression::eq(
+            BitExp::getInt(Expression::intVal(0), Expression::intVal(8), true, $bin),
+            Expression::intVal(-127)
+        ), true);
+    }
+
+    public function testExpressionBase64()
+    {
+        $key = new Key(self::$namespace, self::$set, "exp_base64");
+        self::$client->put(new WritePolicy(), $key, [new Bin("a", 10), new Bin("b", "x")]);
+
+        $exp = Expression::and([
+            Expression::gt(Expression::intBin("a"), Expression::intVal(5)),
+            Expression::eq(Expression::stringBin("b"), Expression::stringVal("x")),
+        ]);
+        $encoded = self::$client->expressionToBase64($exp);
+        $restored = Expression::fromBase64($encoded);
+        $this->assertSame($encoded, self::$client->expressionToBase64($restored));
+        $this->assertFilter($key, $restored, true);
+
+        // restored expressions can be nested in other expressions
+        $this->assertFilter($key, Expression::not($restored), false);
+
+        // the encoding is shared with the other clients, which encode `a > 5` like this
+        $this->assertSame(
+            "kwOTUQKhYQU=",
+            self::$client->expressionToBase64(Expression::gt(Expression::intBin("a"), Expression::intVal(5)))
+        );
+
+        try {
+            Expression::fromBase64("not an expression");
+            $this->fail("Expected an invalid expression to throw");
+        } catch (AerospikeException $e) {
+            $this->assertSame(ResultCode::PARAMETER_ERROR, $e->code);
+        }
+    }
+
+    public function testExpressionToString()
+    {
+        $exp = Expression::and([
+            Expression::gt(Expression::intBin("a"), Expression::intVal(5)),
+            Expression::not(Expression::eq(Expression::stringBin("b"), Expression::stringVal("x"))),
+        ]);
+        $this->assertSame('($.a:int > 5) and not($.b:string == "x")', (string)$exp);
+
+        $exp = ListExp::size(Expression::listBin("l"));
+        $this->assertSame('ListExp::size($.l:list)', (string)$exp);
+    }
+
+    public function testExpressionParse()
+    {
+        $key = new Key(self::$namespace, self::$set, "exp_parse");
+        self::$client->put(new WritePolicy(), $key, [
+            new Bin("age", 30),
+            new Bin("country", "NL"),
+            new Bin("price", 2.5),
+            new Bin("tags", ["new", "sale"]),
+            new Bin("address", ["city" => "Amsterdam", "zip" => "1011"]),
+        ]);
+
+        $this->assertFilter($key, Expression::parse("$.age > 21 and $.country == 'NL'"), true);
+        $this->assertFilter($key, Expression::parse("$.age > 21 and not ($.country == 'NL')"), false);
+        $this->assertFilter($key, Expression::parse('$.price * to_float($.age) >= 75.0'), true);
+        $this->assertFilter($key, Expression::parse('($.age + 5) % 7 == 0 and $.price ** 2.0 < 100.0'), true);
+        $this->assertFilter($key, Expression::parse('$.country =~ /^nl$/i'), true);
+        $this->assertFilter($key, Expression::parse("$.tags[1] == 'sale' and $.address.city == 'Amsterdam'"), true);
+        $this->assertFilter($key, Expression::parse("size($.tags:list) == 2 and $.address['zip'] != '1012'"), true);
+        $this->assertFilter($key, Expression::parse('ttl() != 0 and set_name() == ?0', [self::$set]), true);
+        $this->assertFilter($key, Expression::parse('$.age >= ?min and $.age < ?max', ['min' => 18, 'max' => 30]), false);
+        $this->assertFilter($key, Expression::parse('let(x = $.age:int * 2, var(x) == 60)'), true);
+
+        // the lowest integer can only be written negated
+        $this->assertSame('-9223372036854775808 == $.a:int', (string)Expression::parse('-9223372036854775808 == $.a'));
+
+        $exp = Expression::parse("$.age:int > 5 and not($.country == 'x')");
+        $this->assertSame('($.age:int > 5) and not($.country:string == "x")', (string)$exp);
+        $this->assertSame(
+            self::$client->expressionToBase64($exp),
+            self::$client->expressionToBase64(Expression::parse((string)$exp))
+        );
+    }
+
+    public function testExpressionParseErrors()
+    {
+        $cases = [
+            '$.age > 21 and' => 'column 15',
+            '$.a == $.b' => 'column 1',
+            "$.age > 21 and $.country == 'NL" => 'column 29',
+            '$.age > ?1' => 'column 9',
+            str_repeat('(', 300) . '1' . str_repeat(')', 300) . ' == 1' => 'column 258',
+            str_repeat('not ', 300) . 'true' => 'nested more than 256 levels',
+            '9223372036854775808 == $.a' => 'column 1',
+            '$.l[4294967296][0] == 1' => 'column 5',
+        ];
+        foreach ($cases as $dsl => $message) {
+            try {
+                Expression::parse($dsl, [21]);
+                $this->fail("Expected `$dsl` to throw");
+            } catch (AerospikeException $e) {
+                $this->assertSame(ResultCode::PARAMETER_ERROR, $e->code);
+                $this->assertStringContainsString($message, $e->getMessage());
+            }
+        }
+    }
+
+    public function testExpressionTypeCheck()
+    {
+        $cases = [
+            '`$.a:int == "x"`' => Expression::eq(Expression::intBin("a"), Expression::stringVal("x")),
+            '`$.a:int + 1.5`' => Expression::gt(
+                Expression::numAdd([Expression::intBin("a"), Expression::floatVal(1.5)]),
+                Expression::intVal(1)
+            ),
+            '`ttl()`' => Expression::ttl(),
+            '`ListExp::size($.m:map)`' => Expression::eq(ListExp::size(Expression::mapBin("m")), Expression::intVal(1)),
+        ];
+        foreach ($cases as $name => $exp) {
+            $rp = new ReadPolicy();
+            try {
+                $rp->setFilterExpression($exp);
+                $this->fail("Expected $name to be rejected");
+            } catch (AerospikeException $e) {
+                $this->assertSame(ResultCode::PARAMETER_ERROR, $e->code);
+                $this->assertStringContainsString($name, $e->getMessage());
+            }
+            $this->assertNull($rp->getFilterExpression());
+        }
+
+        $qp = new QueryPolicy();
+        $qp->setFilterExpression(Expression::eq(Expression::intBin("a"), Expression::intVal(1)));
+        $this->assertNotNull($qp->getFilterExpression());
+
+        // with a context, the module reads an element nested in a bin of another type
+        $qp = new QueryPolicy();
+        $qp->setFilterExpression(Expression::eq(
+            ListExp::size(Expression::mapBin("m"), [Context::mapKey("l")]),
+            Expression::intVal(1)
+        ));
+        $this->assertNotNull($qp->getFilterExpression());
+
+        foreach (["$.address.zips[0] == '1011'", "$.tags[0].name == 'sale'"] as $dsl) {
+            $qp = new QueryPolicy();
+            $qp->setFilterExpression(Expression::parse($dsl));
+            $this->assertNotNull($qp->getFilterExpression(), $dsl);
+        }
+    }
+
+    private function assertFilter(Key $key, Expression $exp, bool $matches)
+    {
+        $rp = new ReadPolicy();
+        $rp->setFilterExpression($exp);
+        try {
+            self::$client->get($rp, $key);
+            $this->assertTrue($matches, "Expected the record to be filtered out");
+        } catch (AerospikeException $e) {
+            $this->assertSame(ResultCode::FILTERED_OUT, $e->code);
+            $this->assertFalse($matches, "Expected the record to match the filter");
+        }
+    }
+}
@@ -60,17 +60,20 @@ class QueryTest extends TestCase
             self::$bins[7] = new Bin("AerospikeBin7", $i % 3);
             self::$client->put($wp, $key, self::$bins);
         }
+        $tasks = [];
         self::$indexName = self::$set . "AerospikeBin3";
-        self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin3", self::$indexName, IndexType::Numeric());
+        $tasks[] = self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin3", self::$indexName, IndexType::Numeric());
 
         self::$indexName2 = self::$set . "AerospikeBin6";
-        self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin6", self::$indexName2, IndexType::Numeric());
+        $tasks[] = self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin6", self::$indexName2, IndexType::Numeric());
 
         self::$indexName3 = self::$set . "AerospikeBin7";
-        self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin7", self::$indexName3, IndexType::Numeric());
+        $tasks[] = self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin7", self::$indexName3, IndexType::Numeric());
 
         //wait for setup to complete...
-        sleep(3); // in seconds
+        foreach ($tasks as $task) {
+            $task->waitUntilComplete(30000);
+        }
     }
 
     protected function tearDown(): void
@@ -235,4 +238,43 @@ class QueryTest extends TestCase
         }
         $this->assertGreaterThan(0, $counter);
     }
+
+    public function testIndexTaskProgress()
+    {
+        $wp = new WritePolicy();
+        $indexName = self::$set . "AerospikeBin5";
+        $task = self::$client->createIndex($wp, self::$namespace, self::$set, "AerospikeBin5", $indexName, IndexType::Numeric());
+        $task->waitUntilComplete(30000);
+        $this->assertTrue($task->isDone());
+
+        $progress = $task->progress();
+        $this->assertGreaterThan(0, count($progress));
+        foreach ($progress as $node => $pct) {
+            $this->assertEquals(100, $pct);
+        }
+
+        $task = self::$client->dropIndex($wp, self::$namespace, self::$set, $indexName);
+        $task->waitUntilComplete(30000);
+        $this->assertTrue($task->isDone());
+    }
+
+    public function testIndexes()
+    {
+        $indexes = self::$client->indexes(new InfoPolicy(), self::$namespace);
+
+        $found = null;
+        foreach ($indexes as $index) {
+            if ($index->name == self::$indexName) {
+                $found = $index;
+            }
+        }
+
+        $this->assertNotNull($found);
+        $this->assertEquals(self::$namespace, $found->namespace);
+        $this->assertEquals(self::$set, $found->set_name);
+        $this->assertEquals("AerospikeBin3", $found->bin_name);
+        $this->assertInstanceOf(IndexType::class, $found->index_type);
+        $this->assertEmpty($found->ctx);
+        $this->assertTrue($found->isLoaded());
+    }
 }
@@ -114,6 +114,100 @@ class ScanTest extends TestCase
         }
     }
 
+    public function testScanAndPaginateWithCursor()
+    {
+        $sp = new ScanPolicy();
+        $sp->maxRecords = 20;
+
+        // every page starts from a fresh partition filter restored from the previous cursor,
+        // as it would in a separate request
+        $cursor = PartitionFilter::all()->toCursor();
+        $received = 0;
+        while ($received < self::$keyCount) {
+            $pf = PartitionFilter::fromCursor($cursor);
+            $recordset = self::$client->scan($sp, $pf, self::$namespace, self::$set);
+
+            $recs = self::checkResults($recordset, 0);
+            $this->assertLessThanOrEqual($sp->maxRecords, $recs);
+            $received += $recs;
+
+            $cursor = $pf->toCursor();
+        }
+        $this->assertEquals(self::$keyCount, $received);
+        $this->assertEquals(count(self::$keys), 0);
+    }
+
+    public function testScanForeach()
+    {
+        $recordset = self::$client->scan(new ScanPolicy(), PartitionFilter::all(), self::$namespace, self::$set);
+
+        $counter = 0;
+        foreach ($recordset as $key => $rec) {
+            $this->assertInstanceOf(Key::class, $key);
+            $this->assertEquals($key->digest, $rec->key->digest);
+            $this->assertEquals($rec->bins['AerospikeBin1'], 23);
+            unset(self::$keys[$key->digest]);
+            $counter++;
+        }
+
+        $this->assertEquals(self::$keyCount, $counter);
+        $this->assertEquals(count(self::$keys), 0);
+        $this->assertFalse($recordset->active);
+        $this->assertNull($recordset->error);
+    }
+
+    public function testScanIteratorToArray()
+    {
+        $recordset = self::$client->scan(new ScanPolicy(), PartitionFilter::all(), self::$namespace, self::$set);
+
+        // the keys are `Key` objects, which can not be array keys
+        $records = iterator_to_array($recordset, false);
+        $this->assertCount(self::$keyCount, $records);
+        foreach ($records as $rec) {
+            unset(self::$keys[$rec->key->digest]);
+        }
+        $this->assertEquals(count(self::$keys), 0);
+    }
+
+    public function testScanYieldFrom()
+    {
+        $recordset = self::$client->scan(new ScanPolicy(), PartitionFilter::all(), self::$namespace, self::$set);
+        $generator = (function () use ($recordset) {
+            yield from $recordset;
+        })();
+
+        $counter = 0;
+        foreach ($generator as $key => $rec) {
+            $this->assertEquals($key->digest, $rec->key->digest);
+            $counter++;
+        }
+        $this->assertEquals(self::$keyCount, $counter);
+        $this->assertNull($recordset->error);
+    }
+
+    public function testScanFetchBatch()
+    {
+        $recordset = self::$client->scan(new ScanPolicy(), PartitionFilter::all(), self::$namespace, self::$set);
+
+        $counter = 0;
+        while ($batch = $recordset->fetchBatch(30)) {
+            $this->assertLessThanOrEqual(30, count($batch));
+            $counter += count($batch);
+        }
+
+        $this->assertEquals(self::$keyCount, $counter);
+        $this->assertNull($recordset->error);
+    }
+
+    public function testInvalidCursor()
+    {
+        $cursor = PartitionFilter::range(10, 20)->toCursor();
+        $this->assertEquals($cursor, PartitionFilter::fromCursor($cursor)->toCursor());
+
+        $this->expectException(\Aerospike\AerospikeException::class);
+        PartitionFilter::fromCursor(substr($cursor, 1));
+    }
+
     public function testScanAllPartitions()
     {
         $pf = PartitionFilter::range(0, 4096);
@@ -45,7 +45,8 @@ end';
 
     public function testRunUdfOnASingleRecord(){
         $wp = new WritePolicy();
-        self::$client->registerUdf($wp, self::$udfBody, "udf1.lua", UdfLanguage::lua());
+        $task = self::$client->registerUdf($wp, self::$udfBody, "udf1.lua", UdfLanguage::lua());
+        $task->waitUntilComplete(10000);
         self::$key = new Key(self::$namespace, self::randomString(random_int(5, 10)), self::randomString(random_int(5, 10)));
 
         $bin1 = new Bin("bin1", 20);
@@ -74,4 +75,113 @@ end';
         self::$client->registerUdf($wp, self::$udfBody, "udfToBeDropped.lua", UdfLanguage::lua());
         self::$client->dropUdf($wp, "udfToBeDropped.lua");
     }
-}
\ No newline at end of file
+
+    public function testUdfTask(){
+        $wp = new WritePolicy();
+        $task = self::$client->registerUdf($wp, self::$udfBody, "udfTask.lua", UdfLanguage::lua());
+        $task->waitUntilComplete(10000);
+        $this->assertTrue($task->isDone());
+        foreach ($task->progress() as $node => $pct) {
+            $this->assertEquals(100, $pct);
+        }
+
+        $task = self::$client->dropUdf($wp, "udfTask.lua");
+        $task->waitUntilComplete(10000);
+        $this->assertTrue($task->isDone());
+    }
+
+    public function testQueryExecuteUdf(){
+        $wp = new WritePolicy();
+        $task = self::$client->registerUdf($wp, self::$udfBody, "udf1.lua", UdfLanguage::lua());
+        $task->waitUntilComplete(10000);
+
+        $set = self::randomString(random_int(5, 10));
+        $keys = [];
+        for ($i = 0; $i < 10; $i++) {
+            $keys[] = new Key(self::$namespace, $set, $i);
+            self::$client->put($wp, $keys[$i], [new Bin("bin1", $i * 10)]);
+        }
+
+        $stmt = new Statement(self::$namespace, $set);
+        $stmt->setUdf("udf1", "testFunc1", [2]);
+        $task = self::$client->queryExecute(new BackgroundExecutePolicy(), $stmt, []);
+        $task->waitUntilComplete(10000);
+        $this->assertTrue($task->status()->isComplete());
+
+        $rp = new ReadPolicy();
+        foreach ($keys as $i => $key) {
+            $rec = self::$client->get($rp, $key);
+            $this->assertEquals($rec->bins["bin2"], $i * 5);
+        }
+    }
+
+    public function testQueryExecuteOperations(){
+        $wp = new WritePolicy();
+        $set = self::randomString(random_int(5, 10));
+        $keys = [];
+        for ($i = 0; $i < 10; $i++) {
+            $keys[] = new Key(self::$namespace, $set, $i);
+            self::$client->put($wp, $keys[$i], [new Bin("bin1", $i)]);
+        }
+
+        $stmt = new Statement(self::$namespace, $set);
+        $ops = [Operation::add(new Bin("bin1", 100))];
+        $task = self::$client->queryExecute(new BackgroundExecutePolicy(), $stmt, $ops);
+        $task->waitUntilComplete(10000);
+        $this->assertTrue($task->isDone());
+
+        $rp = new ReadPolicy();
+        foreach ($keys as $i => $key) {
+            $rec = self::$client->get($rp, $key);
+            $this->assertEquals($rec->bins["bin1"], $i + 100);
+        }
+
+        // the operations and a UDF can not be combined
+        $stmt->setUdf("udf1", "testFunc1", [2]);
+        try {
+            self::$client->queryExecute(new BackgroundExecutePolicy(), $stmt, $ops);
+            $this->fail("Expected operations with a UDF to throw");
+        } catch (AerospikeException $e) {
+            $this->assertSame(ResultCode::PARAMETER_ERROR, $e->code);
+        }
+    }
+
+    public function testQueryAggregate(){
+        // the reduce phase runs in the connection manager, which needs the package in its `lua-path`
+        $luaPath = getenv("ASLD_LUA_PATH");
+        if ($luaPath === false) {
+            $this->markTestSkipped("ASLD_LUA_PATH is not set");
+        }
+
+        $udfBody = 'local function reducer(a, b)
+    return a + b
+end
+
+function sum_bin(stream, bin)
+    local function mapper(rec)
+        return rec[bin]
+    end
+    return stream : map(mapper) : reduce(reducer)
+end';
+        file_put_contents($luaPath . "/aggr1.lua", $udfBody);
+
+        $wp = new WritePolicy();
+        $task = self::$client->registerUdf($wp, $udfBody, "aggr1.lua", UdfLanguage::lua());
+        $task->waitUntilComplete(10000);
+
+        $set = self::randomString(random_int(5, 10));
+        for ($i = 1; $i <= 10; $i++) {
+            self::$client->put($wp, new Key(self::$namespace, $set, $i), [new Bin("bin1", $i)]);
+        }
+
+        $stmt = new Statement(self::$namespace, $set);
+        $stmt->setAggregateFunction("aggr1", "sum_bin", ["bin1"]);
+        $rs = self::$client->queryAggregate(new QueryPolicy(), $stmt);
+
+        $results = [];
+        while (($v = $rs->next()) !== null || $rs->active) {
+            $results[] = $v;
+        }
+        $this->assertEquals([55], $results);
+    }
+}